@@ -0,0 +1,173 @@
+// All phrases of every language, for looking them up by key and for
+// auditing which translations are missing.
+
+use std::collections::BTreeSet;
+
+use english;
+use japanese;
+use message::{self, Args, FormatError};
+
+// (key, template) pairs, as each language module lists them
+pub type Phrases = Vec<(&'static str, &'static str)>;
+
+struct Language {
+    tag: &'static str,
+    phrases: fn() -> Phrases,
+}
+
+// Every shipped language, the one list `languages` and `catalog` both
+// read.
+const LANGUAGES: [Language; 2] = [
+    Language { tag: english::LOCALE, phrases: english::phrases },
+    Language { tag: japanese::LOCALE, phrases: japanese::phrases },
+];
+
+pub struct Catalog {
+    // The full tag, "en-GB" rather than just "en", so dates and numbers
+    // follow the region
+    tag: String,
+    phrases: Phrases,
+}
+
+// Locale tags of every language the library ships.
+pub fn languages() -> Vec<&'static str> {
+    LANGUAGES.iter().map(|l| l.tag).collect()
+}
+
+// The catalog for a locale tag, matched by its language alone: "en-GB"
+// and "en_US" both get the English phrases.
+pub fn catalog(tag: &str) -> Option<Catalog> {
+    let language = message::language_of(tag);
+    LANGUAGES
+        .iter()
+        .find(|l| l.tag == language)
+        .map(|l| Catalog::new(tag, (l.phrases)()))
+}
+
+impl Catalog {
+    // A catalog of any phrases, for languages kept outside the library.
+    pub fn new(tag: &str, phrases: Phrases) -> Catalog {
+        Catalog { tag: tag.to_string(), phrases }
+    }
+
+    pub fn tag(&self) -> &str {
+        &self.tag
+    }
+
+    pub fn keys(&self) -> Vec<&'static str> {
+        self.phrases.iter().map(|p| p.0).collect()
+    }
+
+    pub fn template(&self, key: &str) -> Option<&'static str> {
+        self.phrases.iter().find(|p| p.0 == key).map(|p| p.1)
+    }
+
+    // Renders the phrase `key` with this catalog's locale, or `None` when
+    // the language has no such phrase.
+    pub fn render(&self, key: &str, args: &Args) -> Option<Result<String, FormatError>> {
+        self.template(key).map(|t| message::format(&self.tag, t, args))
+    }
+
+    // Keys present in `other` but not in `self`.
+    pub fn missing_from(&self, other: &Catalog) -> Vec<&'static str> {
+        other.keys().into_iter().filter(|k| self.template(k).is_none()).collect()
+    }
+}
+
+pub struct LanguageCoverage {
+    pub language: String,
+    pub translated: usize,
+    pub missing: Vec<&'static str>,
+}
+
+impl LanguageCoverage {
+    pub fn percent(&self) -> f64 {
+        let total = self.translated + self.missing.len();
+        if total == 0 {
+            100.0
+        } else {
+            100.0 * self.translated as f64 / total as f64
+        }
+    }
+}
+
+// How many of all known keys each language translates. A key counts as
+// known when at least one language has it.
+pub struct Coverage {
+    pub total: usize,
+    pub languages: Vec<LanguageCoverage>,
+}
+
+pub fn coverage() -> Coverage {
+    let catalogs: Vec<Catalog> = languages().into_iter().filter_map(catalog).collect();
+    coverage_of(&catalogs)
+}
+
+// `coverage` for any set of catalogs.
+pub fn coverage_of(catalogs: &[Catalog]) -> Coverage {
+    let all: BTreeSet<&'static str> = catalogs.iter().flat_map(|c| c.keys()).collect();
+
+    let languages = catalogs
+        .iter()
+        .map(|c| {
+            let missing: Vec<_> = all.iter().cloned().filter(|k| c.template(k).is_none()).collect();
+            LanguageCoverage {
+                language: c.tag.clone(),
+                translated: all.len() - missing.len(),
+                missing,
+            }
+        })
+        .collect();
+
+    Coverage { total: all.len(), languages }
+}
+
+impl Coverage {
+    pub fn to_csv(&self) -> String {
+        let mut out = "language,translated,total,percent,missing\n".to_string();
+        for l in &self.languages {
+            out.push_str(&format!(
+                "{},{},{},{:.1},\"{}\"\n",
+                l.language,
+                l.translated,
+                self.total,
+                l.percent(),
+                l.missing.join(" ")
+            ));
+        }
+        out
+    }
+
+    pub fn to_json(&self) -> String {
+        let languages: Vec<String> = self
+            .languages
+            .iter()
+            .map(|l| {
+                let missing: Vec<String> = l.missing.iter().map(|k| json_string(k)).collect();
+                format!(
+                    "    {{\"language\": {}, \"translated\": {}, \"percent\": {:.1}, \"missing\": [{}]}}",
+                    json_string(&l.language),
+                    l.translated,
+                    l.percent(),
+                    missing.join(", ")
+                )
+            })
+            .collect();
+        format!("{{\n  \"total\": {},\n  \"languages\": [\n{}\n  ]\n}}\n", self.total, languages.join(",\n"))
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = "\"".to_string();
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
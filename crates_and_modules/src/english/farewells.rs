@@ -0,0 +1,25 @@
+use message::{Args, Date};
+
+pub const GOODBYE: &str = "Goodbye";
+pub const SEE_YOU_IN: &str = "See you in {days, plural, one {# day} other {# days}}!";
+pub const SEE_YOU_ON: &str = "See you on {date, date}.";
+
+// Rust allows you to precisely control which
+// aspects of your interface are public,
+// and so private is the default.
+// To make things public, you use the pub keyword.
+pub fn cya() -> String {
+    goodbye()
+}
+
+fn goodbye() -> String {
+    GOODBYE.to_string()
+}
+
+pub fn see_you_in(days: u32) -> String {
+    super::render(SEE_YOU_IN, &Args::new().with("days", days))
+}
+
+pub fn see_you_on(date: Date) -> String {
+    super::render(SEE_YOU_ON, &Args::new().with("date", date))
+}
@@ -0,0 +1,25 @@
+use message::{Args, Gender};
+
+pub const HELLO: &str = "Hello !";
+pub const HELLO_TO: &str = "Hello, {name}!";
+pub const WELCOME_BACK: &str = "{gender, select, \
+    female {Welcome back, Ms. {name}} \
+    male {Welcome back, Mr. {name}} \
+    other {Welcome back, {name}}}! \
+    You have {unread, plural, =0 {no new messages} one {# new message} other {# new messages}}.";
+
+pub fn hello() -> String {
+    HELLO.to_string()
+}
+
+pub fn hello_to(name: &str) -> String {
+    super::render(HELLO_TO, &Args::new().with("name", name))
+}
+
+pub fn welcome_back(name: &str, gender: Gender, unread: u32) -> String {
+    let args = Args::new()
+        .with("name", name)
+        .with("gender", gender)
+        .with("unread", unread);
+    super::render(WELCOME_BACK, &args)
+}
@@ -0,0 +1,25 @@
+use message::{self, Args};
+
+pub mod greetings;
+pub mod farewells;
+
+pub const LOCALE: &str = "en";
+
+// Every template of this language keyed as `<module>.<phrase>`, used by
+// `catalog` to compare languages.
+pub fn phrases() -> Vec<(&'static str, &'static str)> {
+    vec![
+        ("greetings.hello", greetings::HELLO),
+        ("greetings.hello_to", greetings::HELLO_TO),
+        ("greetings.welcome_back", greetings::WELCOME_BACK),
+        ("farewells.goodbye", farewells::GOODBYE),
+        ("farewells.see_you_in", farewells::SEE_YOU_IN),
+        ("farewells.see_you_on", farewells::SEE_YOU_ON),
+    ]
+}
+
+// The templates in this module are fixed, so a formatting error is a bug
+// in the template rather than something callers can handle.
+fn render(template: &str, args: &Args) -> String {
+    message::format(LOCALE, template, args).expect("invalid English template")
+}
@@ -0,0 +1,17 @@
+use message::{Args, Date};
+
+pub const GOODBYE: &str = "さようなら";
+pub const SEE_YOU_IN: &str = "{days, plural, other {#日後}}にまた会いましょう！";
+pub const SEE_YOU_ON: &str = "{date, date}にまた会いましょう。";
+
+pub fn goodbye() -> String {
+    GOODBYE.to_string()
+}
+
+pub fn see_you_in(days: u32) -> String {
+    super::render(SEE_YOU_IN, &Args::new().with("days", days))
+}
+
+pub fn see_you_on(date: Date) -> String {
+    super::render(SEE_YOU_ON, &Args::new().with("date", date))
+}
@@ -0,0 +1,24 @@
+use message::{Args, Gender};
+
+pub const HELLO: &str = "こんにちは";
+pub const HELLO_TO: &str = "{name}さん、こんにちは！";
+// Japanese has neither grammatical gender nor plural forms, but the
+// arguments are kept so every language takes the same inputs.
+pub const WELCOME_BACK: &str = "{gender, select, other {{name}さん}}、おかえりなさい！\
+    新着メッセージは{unread, plural, =0 {ありません} other {#件です}}。";
+
+pub fn hello() -> String {
+    HELLO.to_string()
+}
+
+pub fn hello_to(name: &str) -> String {
+    super::render(HELLO_TO, &Args::new().with("name", name))
+}
+
+pub fn welcome_back(name: &str, gender: Gender, unread: u32) -> String {
+    let args = Args::new()
+        .with("name", name)
+        .with("gender", gender)
+        .with("unread", unread);
+    super::render(WELCOME_BACK, &args)
+}
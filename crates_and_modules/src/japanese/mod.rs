@@ -0,0 +1,22 @@
+use message::{self, Args};
+
+pub mod greetings;
+pub mod farewells;
+
+pub const LOCALE: &str = "ja";
+
+// Keyed the same way as `english::phrases`.
+pub fn phrases() -> Vec<(&'static str, &'static str)> {
+    vec![
+        ("greetings.hello", greetings::HELLO),
+        ("greetings.hello_to", greetings::HELLO_TO),
+        ("greetings.welcome_back", greetings::WELCOME_BACK),
+        ("farewells.goodbye", farewells::GOODBYE),
+        ("farewells.see_you_in", farewells::SEE_YOU_IN),
+        ("farewells.see_you_on", farewells::SEE_YOU_ON),
+    ]
+}
+
+fn render(template: &str, args: &Args) -> String {
+    message::format(LOCALE, template, args).expect("invalid Japanese template")
+}
@@ -0,0 +1,7 @@
+// The phrases library: greetings and farewells in several languages,
+// personalised through the templates in `message`.
+
+pub mod catalog;
+pub mod english;
+pub mod japanese;
+pub mod message;
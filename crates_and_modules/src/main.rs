@@ -1,36 +1,129 @@
-mod english {
-    mod greetings {
-        fn hello() -> String {
-            return "Hello !".to_string();
-        }
+// Module names follow the conventions
+// for other Rust identifiers: lower_snake_case.
+
+// The english and japanese modules live in src/lib.rs as the `phrases`
+// library. A module declared with `mod english;` is looked up in
+// src/english.rs or src/english/mod.rs.
+
+// To import
+extern crate phrases;
+
+use std::env;
+use std::process;
+
+use phrases::catalog::{self, Catalog};
+use phrases::message::{Args, ArgumentKind, Date, Locale, Message};
+
+const USAGE: &str = "usage: phrases <command> [arguments]
+
+commands:
+    languages                       list available languages
+    show <locale> <key> [arg=value] print a phrase, e.g. `show en greetings.hello_to name=Steve`
+    diff <base> <other>             list keys of <base> missing from <other>, exits 1 if any
+    coverage [--format csv|json]    translation coverage of every language";
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(|a| &a[..]).collect();
+
+    let result = match args.split_first() {
+        Some((&"languages", [])) => languages(),
+        Some((&"show", rest)) if rest.len() >= 2 => show(rest[0], rest[1], &rest[2..]),
+        Some((&"diff", [base, other])) => diff(base, other),
+        Some((&"coverage", rest)) => coverage(rest),
+        _ => Err(USAGE.to_string()),
+    };
+
+    if let Err(message) = result {
+        eprintln!("{}", message);
+        process::exit(2);
     }
-    mod farewells {
+}
+
+fn load(language: &str) -> Result<Catalog, String> {
+    catalog::catalog(language).ok_or_else(|| {
+        format!("unknown language `{}`, available: {}", language, catalog::languages().join(", "))
+    })
+}
 
-        // Rust allows you to precisely control which
-        // aspects of your interface are public,
-        // and so private is the default.
-        // To make things public, you use the pub keyword.
-        pub fn cya() -> String {
-            return goodbye();
-        }
+fn languages() -> Result<(), String> {
+    for language in catalog::languages() {
+        let phrases = load(language)?.keys().len();
+        println!("{}\t{} phrases", language, phrases);
+    }
+    Ok(())
+}
 
-        fn goodbye() -> String {
-            return "Goodbye".to_string();
-        }
+// Arguments stay strings, so `name=007` shows as typed, except where
+// `message` uses them as a number or a date (written as yyyy-mm-dd).
+fn parse_args(pairs: &[&str], message: &Message) -> Result<Args, String> {
+    let mut args = Args::new();
+    for pair in pairs {
+        let mut split = pair.splitn(2, '=');
+        let (name, value) = match (split.next(), split.next()) {
+            (Some(name), Some(value)) if !name.is_empty() => (name, value),
+            _ => return Err(format!("expected `name=value`, got `{}`", pair)),
+        };
+        args = match message.argument_kind(name) {
+            ArgumentKind::Text => args.with(name, value),
+            ArgumentKind::Number => match value.parse::<f64>() {
+                Ok(n) if n.is_finite() => args.with(name, n),
+                _ => return Err(format!("`{}` must be a number, got `{}`", name, value)),
+            },
+            ArgumentKind::Date => match parse_date(value) {
+                Some(date) => args.with(name, date),
+                None => return Err(format!("`{}` must be a yyyy-mm-dd date, got `{}`", name, value)),
+            },
+        };
     }
+    Ok(args)
 }
 
-fn main() {
-    // Module names follow the conventions
-    // for other Rust identifiers: lower_snake_case.
+fn parse_date(value: &str) -> Option<Date> {
+    let fields: Vec<&str> = value.split('-').collect();
+    if fields.len() != 3 {
+        return None;
+    }
+    match (fields[0].parse(), fields[1].parse(), fields[2].parse()) {
+        (Ok(year), Ok(month), Ok(day)) => Date::new(year, month, day),
+        _ => None,
+    }
+}
 
-    // We can refer to sub-modules with double-colon (::)
-    // notation: our four nested modules
+fn show(language: &str, key: &str, pairs: &[&str]) -> Result<(), String> {
+    let catalog = load(language)?;
+    let template = match catalog.template(key) {
+        Some(template) => template,
+        None => return Err(format!("`{}` has no phrase `{}`", language, key)),
+    };
+    let message = Message::parse(template).map_err(|e| format!("{}: {}", key, e))?;
+    let args = parse_args(pairs, &message)?;
+    let text = message
+        .format(&Locale::new(catalog.tag()), &args)
+        .map_err(|e| format!("{}: {}", key, e))?;
+    println!("{}", text);
+    Ok(())
+}
 
-    // To import
-    // use phrases::english::{greetings, farewells};
-    // extern crate phrases;
+fn diff(base: &str, other: &str) -> Result<(), String> {
+    let base = load(base)?;
+    let other = load(other)?;
+    let missing = other.missing_from(&base);
+    for key in &missing {
+        println!("{}", key);
+    }
+    if !missing.is_empty() {
+        process::exit(1);
+    }
+    Ok(())
+}
 
-    // use phrases::english::{greetings,farewells};
-    // use phrases::japanese;
+fn coverage(options: &[&str]) -> Result<(), String> {
+    let report = catalog::coverage();
+    match options {
+        [] | ["--format", "csv"] => print!("{}", report.to_csv()),
+        ["--format", "json"] => print!("{}", report.to_json()),
+        _ => return Err(USAGE.to_string()),
+    }
+    Ok(())
 }
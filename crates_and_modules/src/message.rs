@@ -0,0 +1,716 @@
+// Message formatting shared by every language module.
+//
+// Templates use a small subset of the ICU MessageFormat syntax:
+//
+//     Hello, {name}!
+//     You have {count, plural, =0 {no messages} one {# message} other {# messages}}.
+//     {gender, select, female {She} male {He} other {They}} left on {when, date}.
+//
+// A template is parsed once into a `Message` and can then be rendered
+// many times for different locales and arguments.
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+
+// A calendar date, always a real one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Date {
+    year: i32,
+    month: u32,
+    day: u32,
+}
+
+impl Date {
+    // `None` unless `month` is 1 to 12 and `day` exists in that month,
+    // counting February 29 in leap years only.
+    pub fn new(year: i32, month: u32, day: u32) -> Option<Date> {
+        let leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+        let days = match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if leap => 29,
+            2 => 28,
+            _ => return None,
+        };
+        if (1..=days).contains(&day) {
+            Some(Date { year, month, day })
+        } else {
+            None
+        }
+    }
+
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    pub fn month(&self) -> u32 {
+        self.month
+    }
+
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Gender {
+    Female,
+    Male,
+    Other,
+}
+
+impl Gender {
+    // The keyword a `select` branch has to use to match this gender.
+    pub fn keyword(&self) -> &'static str {
+        match *self {
+            Gender::Female => "female",
+            Gender::Male => "male",
+            Gender::Other => "other",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Str(String),
+    Num(f64),
+    Date(Date),
+}
+
+impl<'a> From<&'a str> for Value {
+    fn from(s: &'a str) -> Value {
+        Value::Str(s.to_string())
+    }
+}
+
+impl From<String> for Value {
+    fn from(s: String) -> Value {
+        Value::Str(s)
+    }
+}
+
+impl From<Gender> for Value {
+    fn from(g: Gender) -> Value {
+        Value::Str(g.keyword().to_string())
+    }
+}
+
+impl From<i32> for Value {
+    fn from(n: i32) -> Value {
+        Value::Num(n as f64)
+    }
+}
+
+impl From<u32> for Value {
+    fn from(n: u32) -> Value {
+        Value::Num(n as f64)
+    }
+}
+
+impl From<f64> for Value {
+    fn from(n: f64) -> Value {
+        Value::Num(n)
+    }
+}
+
+impl From<Date> for Value {
+    fn from(d: Date) -> Value {
+        Value::Date(d)
+    }
+}
+
+// Named arguments handed to `Message::format`.
+#[derive(Debug, Clone, Default)]
+pub struct Args {
+    values: HashMap<String, Value>,
+}
+
+impl Args {
+    pub fn new() -> Args {
+        Args { values: HashMap::new() }
+    }
+
+    pub fn with<V: Into<Value>>(mut self, name: &str, value: V) -> Args {
+        self.values.insert(name.to_string(), value.into());
+        self
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Value> {
+        self.values.get(name)
+    }
+}
+
+// CLDR plural categories. Every language uses `Other`, most use only
+// a few of the rest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PluralCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+impl PluralCategory {
+    pub fn keyword(&self) -> &'static str {
+        match *self {
+            PluralCategory::Zero => "zero",
+            PluralCategory::One => "one",
+            PluralCategory::Two => "two",
+            PluralCategory::Few => "few",
+            PluralCategory::Many => "many",
+            PluralCategory::Other => "other",
+        }
+    }
+}
+
+// Language part of a locale tag: "en-US" and "en_GB" both give "en".
+pub fn language_of(tag: &str) -> String {
+    tag.split(['-', '_']).next().unwrap_or("").to_lowercase()
+}
+
+// Cardinal plural rules from CLDR for the languages we ship or expect to.
+// `i` is the integer part and `v` tells whether there are visible
+// fraction digits, as in the CLDR operands.
+pub fn plural_category(tag: &str, n: f64) -> PluralCategory {
+    use self::PluralCategory::*;
+
+    let n = n.abs();
+    let i = n.trunc() as u64;
+    let v = n.fract() != 0.0;
+    let i10 = i % 10;
+    let i100 = i % 100;
+
+    match &language_of(tag)[..] {
+        "ja" | "zh" | "ko" | "th" | "vi" => Other,
+        "fr" | "pt" => {
+            if i <= 1 { One } else { Other }
+        }
+        "ru" | "uk" | "be" => {
+            if v {
+                Other
+            } else if i10 == 1 && i100 != 11 {
+                One
+            } else if (2..=4).contains(&i10) && !(12..=14).contains(&i100) {
+                Few
+            } else {
+                Many
+            }
+        }
+        "pl" => {
+            if v {
+                Other
+            } else if i == 1 {
+                One
+            } else if (2..=4).contains(&i10) && !(12..=14).contains(&i100) {
+                Few
+            } else {
+                Many
+            }
+        }
+        "cs" | "sk" => {
+            if v {
+                Many
+            } else if i == 1 {
+                One
+            } else if (2..=4).contains(&i) {
+                Few
+            } else {
+                Other
+            }
+        }
+        "lt" => {
+            if v {
+                Many
+            } else if i10 == 1 && !(11..=19).contains(&i100) {
+                One
+            } else if i10 >= 2 && !(11..=19).contains(&i100) {
+                Few
+            } else {
+                Other
+            }
+        }
+        "ar" => {
+            if v {
+                Other
+            } else if i == 0 {
+                Zero
+            } else if i == 1 {
+                One
+            } else if i == 2 {
+                Two
+            } else if (3..=10).contains(&i100) {
+                Few
+            } else if (11..=99).contains(&i100) {
+                Many
+            } else {
+                Other
+            }
+        }
+        // English and the other Germanic languages
+        _ => {
+            if i == 1 && !v { One } else { Other }
+        }
+    }
+}
+
+// Number and date conventions of a locale.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Locale {
+    tag: String,
+    decimal_separator: &'static str,
+    group_separator: &'static str,
+    // `yyyy`, `MM`/`M` and `dd`/`d` are replaced, everything else is copied
+    date_pattern: &'static str,
+}
+
+impl Locale {
+    pub fn new(tag: &str) -> Locale {
+        let region = tag.split(['-', '_']).nth(1).unwrap_or("");
+        let (decimal, group, date) = match &language_of(tag)[..] {
+            "ja" | "zh" | "ko" => (".", ",", "yyyy/MM/dd"),
+            "de" => (",", ".", "dd.MM.yyyy"),
+            "fr" => (",", "\u{202f}", "dd/MM/yyyy"),
+            "ru" | "uk" | "pl" | "cs" | "sk" => (",", "\u{a0}", "dd.MM.yyyy"),
+            "lt" => (",", "\u{a0}", "yyyy-MM-dd"),
+            "en" if !region.is_empty() && !region.eq_ignore_ascii_case("US") => {
+                (".", ",", "dd/MM/yyyy")
+            }
+            _ => (".", ",", "M/d/yyyy"),
+        };
+
+        Locale {
+            tag: tag.to_string(),
+            decimal_separator: decimal,
+            group_separator: group,
+            date_pattern: date,
+        }
+    }
+
+    pub fn tag(&self) -> &str {
+        &self.tag
+    }
+
+    pub fn plural_category(&self, n: f64) -> PluralCategory {
+        plural_category(&self.tag, n)
+    }
+
+    // Formats `n` with digit grouping. Without `fraction_digits` up to
+    // three fraction digits are shown and trailing zeros dropped.
+    pub fn format_number(&self, n: f64, fraction_digits: Option<usize>) -> String {
+        let digits = fraction_digits.unwrap_or(3);
+        let fixed = format!("{:.*}", digits, n.abs());
+        let mut parts = fixed.splitn(2, '.');
+        let integer = parts.next().unwrap_or("0");
+        let mut fraction = parts.next().unwrap_or("").to_string();
+        if fraction_digits.is_none() {
+            while fraction.ends_with('0') {
+                fraction.pop();
+            }
+        }
+
+        let mut out = String::new();
+        if n < 0.0 && (integer != "0" || !fraction.is_empty()) {
+            out.push('-');
+        }
+        for (idx, c) in integer.chars().enumerate() {
+            if idx > 0 && (integer.len() - idx) % 3 == 0 {
+                out.push_str(self.group_separator);
+            }
+            out.push(c);
+        }
+        if !fraction.is_empty() {
+            out.push_str(self.decimal_separator);
+            out.push_str(&fraction);
+        }
+        out
+    }
+
+    pub fn format_date(&self, date: &Date) -> String {
+        let mut out = String::new();
+        let mut rest = self.date_pattern;
+        while !rest.is_empty() {
+            let (text, len) = if rest.starts_with("yyyy") {
+                (format!("{:04}", date.year), 4)
+            } else if rest.starts_with("MM") {
+                (format!("{:02}", date.month), 2)
+            } else if rest.starts_with('M') {
+                (date.month.to_string(), 1)
+            } else if rest.starts_with("dd") {
+                (format!("{:02}", date.day), 2)
+            } else if rest.starts_with('d') {
+                (date.day.to_string(), 1)
+            } else {
+                let c = rest.chars().next().unwrap();
+                (c.to_string(), c.len_utf8())
+            };
+            out.push_str(&text);
+            rest = &rest[len..];
+        }
+        out
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FormatError {
+    // The template itself is malformed; `position` is a byte offset.
+    Syntax { position: usize, reason: &'static str },
+    MissingArgument(String),
+    WrongType { argument: String, expected: &'static str },
+}
+
+impl fmt::Display for FormatError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            FormatError::Syntax { position, reason } => {
+                write!(f, "syntax error at byte {}: {}", position, reason)
+            }
+            FormatError::MissingArgument(ref name) => {
+                write!(f, "no value given for argument `{}`", name)
+            }
+            FormatError::WrongType { ref argument, expected } => {
+                write!(f, "argument `{}` must be a {}", argument, expected)
+            }
+        }
+    }
+}
+
+impl Error for FormatError {}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Selector {
+    Exact(f64),
+    Keyword(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Part {
+    Text(String),
+    // `#` inside a plural branch
+    Count,
+    Plain(String),
+    Number { name: String, integer: bool },
+    Date(String),
+    Plural { name: String, branches: Vec<(Selector, Message)> },
+    Select { name: String, branches: Vec<(String, Message)> },
+}
+
+// What a message needs an argument to be, for callers that only have
+// its text, such as a command line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArgumentKind {
+    Text,
+    Number,
+    Date,
+}
+
+// A parsed template, ready to be rendered.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Message {
+    parts: Vec<Part>,
+}
+
+struct Parser<'a> {
+    src: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<char> {
+        self.src[self.pos..].chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek();
+        if let Some(c) = c {
+            self.pos += c.len_utf8();
+        }
+        c
+    }
+
+    fn error(&self, reason: &'static str) -> FormatError {
+        FormatError::Syntax { position: self.pos, reason }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.bump();
+        }
+    }
+
+    fn expect(&mut self, c: char, reason: &'static str) -> Result<(), FormatError> {
+        self.skip_whitespace();
+        if self.peek() == Some(c) {
+            self.bump();
+            Ok(())
+        } else {
+            Err(self.error(reason))
+        }
+    }
+
+    fn word(&mut self) -> String {
+        self.skip_whitespace();
+        let start = self.pos;
+        while self.peek().is_some_and(|c| c.is_alphanumeric() || c == '_' || c == '=' || c == '.') {
+            self.bump();
+        }
+        self.src[start..self.pos].to_string()
+    }
+
+    // Parses text and placeholders until the closing brace of the
+    // enclosing branch, or the end of input at the top level.
+    fn message(&mut self, in_plural: bool, nested: bool) -> Result<Message, FormatError> {
+        let mut parts = Vec::new();
+        let mut text = String::new();
+
+        loop {
+            match self.peek() {
+                None => {
+                    if nested {
+                        return Err(self.error("unclosed branch"));
+                    }
+                    break;
+                }
+                Some('}') => {
+                    if nested {
+                        break;
+                    }
+                    return Err(self.error("unmatched `}`"));
+                }
+                Some('{') => {
+                    if !text.is_empty() {
+                        parts.push(Part::Text(text.clone()));
+                        text.clear();
+                    }
+                    self.bump();
+                    parts.push(self.placeholder(in_plural)?);
+                }
+                Some('#') if in_plural => {
+                    if !text.is_empty() {
+                        parts.push(Part::Text(text.clone()));
+                        text.clear();
+                    }
+                    self.bump();
+                    parts.push(Part::Count);
+                }
+                Some('\'') => {
+                    self.bump();
+                    match self.peek() {
+                        // `''` is a literal apostrophe
+                        Some('\'') => {
+                            self.bump();
+                            text.push('\'');
+                        }
+                        // `'{...}'` quotes syntax characters
+                        Some('{') | Some('}') | Some('#') => {
+                            loop {
+                                match self.bump() {
+                                    None => return Err(self.error("unclosed quote")),
+                                    Some('\'') => break,
+                                    Some(c) => text.push(c),
+                                }
+                            }
+                        }
+                        _ => text.push('\''),
+                    }
+                }
+                Some(c) => {
+                    self.bump();
+                    text.push(c);
+                }
+            }
+        }
+
+        if !text.is_empty() {
+            parts.push(Part::Text(text));
+        }
+        Ok(Message { parts })
+    }
+
+    // Everything after an opening `{` up to and including its `}`.
+    // `in_plural` carries into `select` branches, so `#` still means the
+    // count of a `plural` further out.
+    fn placeholder(&mut self, in_plural: bool) -> Result<Part, FormatError> {
+        let name = self.word();
+        if name.is_empty() {
+            return Err(self.error("expected argument name"));
+        }
+        self.skip_whitespace();
+        if self.peek() == Some('}') {
+            self.bump();
+            return Ok(Part::Plain(name));
+        }
+        self.expect(',', "expected `,` or `}` after argument name")?;
+
+        let kind = self.word();
+        self.skip_whitespace();
+        let part = match &kind[..] {
+            "number" => {
+                let mut integer = false;
+                if self.peek() == Some(',') {
+                    self.bump();
+                    match &self.word()[..] {
+                        "integer" => integer = true,
+                        _ => return Err(self.error("unknown number style")),
+                    }
+                }
+                Part::Number { name, integer }
+            }
+            "date" => Part::Date(name),
+            "plural" => {
+                self.expect(',', "expected `,` before plural branches")?;
+                let mut branches = Vec::new();
+                for (key, message) in self.branches(true)? {
+                    let selector = match key.strip_prefix('=') {
+                        Some(exact) => match exact.parse() {
+                            Ok(n) => Selector::Exact(n),
+                            Err(_) => return Err(self.error("invalid exact plural value")),
+                        },
+                        None => Selector::Keyword(key),
+                    };
+                    branches.push((selector, message));
+                }
+                Part::Plural { name, branches }
+            }
+            "select" => {
+                self.expect(',', "expected `,` before select branches")?;
+                Part::Select { name, branches: self.branches(in_plural)? }
+            }
+            _ => return Err(self.error("expected `number`, `date`, `plural` or `select`")),
+        };
+        self.expect('}', "expected `}` to close the argument")?;
+        Ok(part)
+    }
+
+    fn branches(&mut self, in_plural: bool) -> Result<Vec<(String, Message)>, FormatError> {
+        let mut branches = Vec::new();
+        loop {
+            self.skip_whitespace();
+            if self.peek() == Some('}') || self.peek().is_none() {
+                break;
+            }
+            let key = self.word();
+            if key.is_empty() {
+                return Err(self.error("expected branch keyword"));
+            }
+            self.expect('{', "expected `{` to open the branch")?;
+            let message = self.message(in_plural, true)?;
+            self.expect('}', "expected `}` to close the branch")?;
+            branches.push((key, message));
+        }
+        if !branches.iter().any(|(key, _)| key == "other") {
+            return Err(self.error("an `other` branch is required"));
+        }
+        Ok(branches)
+    }
+}
+
+impl Message {
+    pub fn parse(template: &str) -> Result<Message, FormatError> {
+        let mut parser = Parser { src: template, pos: 0 };
+        parser.message(false, false)
+    }
+
+    // `Number` where `name` is formatted as a number or picks a plural
+    // branch, `Date` where it is formatted as a date, and `Text` where it
+    // is only ever shown as it is, selected on, or not used at all.
+    pub fn argument_kind(&self, name: &str) -> ArgumentKind {
+        for part in &self.parts {
+            let kind = match *part {
+                Part::Number { name: ref n, .. } if n == name => ArgumentKind::Number,
+                Part::Plural { name: ref n, .. } if n == name => ArgumentKind::Number,
+                Part::Date(ref n) if n == name => ArgumentKind::Date,
+                Part::Plural { ref branches, .. } => kind_in(branches.iter().map(|b| &b.1), name),
+                Part::Select { ref branches, .. } => kind_in(branches.iter().map(|b| &b.1), name),
+                _ => ArgumentKind::Text,
+            };
+            if kind != ArgumentKind::Text {
+                return kind;
+            }
+        }
+        ArgumentKind::Text
+    }
+
+    pub fn format(&self, locale: &Locale, args: &Args) -> Result<String, FormatError> {
+        let mut out = String::new();
+        self.render(locale, args, None, &mut out)?;
+        Ok(out)
+    }
+
+    fn render(&self, locale: &Locale, args: &Args, count: Option<f64>, out: &mut String) -> Result<(), FormatError> {
+        for part in &self.parts {
+            match *part {
+                Part::Text(ref text) => out.push_str(text),
+                Part::Count => {
+                    if let Some(n) = count {
+                        out.push_str(&locale.format_number(n, None));
+                    }
+                }
+                Part::Plain(ref name) => match *lookup(args, name)? {
+                    Value::Str(ref s) => out.push_str(s),
+                    Value::Num(n) => out.push_str(&locale.format_number(n, None)),
+                    Value::Date(ref d) => out.push_str(&locale.format_date(d)),
+                },
+                Part::Number { ref name, integer } => {
+                    let n = number(args, name)?;
+                    let digits = if integer { Some(0) } else { None };
+                    out.push_str(&locale.format_number(n, digits));
+                }
+                Part::Date(ref name) => match *lookup(args, name)? {
+                    Value::Date(ref d) => out.push_str(&locale.format_date(d)),
+                    _ => return Err(wrong_type(name, "date")),
+                },
+                Part::Plural { ref name, ref branches } => {
+                    let n = number(args, name)?;
+                    let category = locale.plural_category(n).keyword();
+                    let exact = branches.iter().find(|b| b.0 == Selector::Exact(n));
+                    let keyword = branches.iter().find(|b| b.0 == Selector::Keyword(category.to_string()));
+                    let other = branches.iter().find(|b| b.0 == Selector::Keyword("other".to_string()));
+                    if let Some(branch) = exact.or(keyword).or(other) {
+                        branch.1.render(locale, args, Some(n), out)?;
+                    }
+                }
+                Part::Select { ref name, ref branches } => {
+                    let key = match *lookup(args, name)? {
+                        Value::Str(ref s) => s.clone(),
+                        _ => return Err(wrong_type(name, "string")),
+                    };
+                    let chosen = branches.iter().find(|b| b.0 == key).or_else(|| branches.iter().find(|b| b.0 == "other"));
+                    if let Some(branch) = chosen {
+                        branch.1.render(locale, args, count, out)?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn kind_in<'a, I: Iterator<Item = &'a Message>>(messages: I, name: &str) -> ArgumentKind {
+    messages
+        .map(|m| m.argument_kind(name))
+        .find(|&k| k != ArgumentKind::Text)
+        .unwrap_or(ArgumentKind::Text)
+}
+
+fn lookup<'a>(args: &'a Args, name: &str) -> Result<&'a Value, FormatError> {
+    args.get(name).ok_or_else(|| FormatError::MissingArgument(name.to_string()))
+}
+
+fn number(args: &Args, name: &str) -> Result<f64, FormatError> {
+    match *lookup(args, name)? {
+        Value::Num(n) => Ok(n),
+        _ => Err(wrong_type(name, "number")),
+    }
+}
+
+fn wrong_type(name: &str, expected: &'static str) -> FormatError {
+    FormatError::WrongType { argument: name.to_string(), expected }
+}
+
+// Parses and renders `template` in one go.
+pub fn format(tag: &str, template: &str, args: &Args) -> Result<String, FormatError> {
+    Message::parse(template)?.format(&Locale::new(tag), args)
+}
@@ -0,0 +1,97 @@
+extern crate phrases;
+
+use phrases::catalog::{self, Catalog};
+use phrases::message::{Args, Date};
+
+// German with only some of the English phrases translated.
+fn partial() -> Catalog {
+    Catalog::new(
+        "de-AT",
+        vec![
+            ("greetings.hello", "Hallo!"),
+            ("farewells.see_you_on", "Bis {date, date}."),
+            ("farewells.extra", "Servus"),
+        ],
+    )
+}
+
+#[test]
+fn catalogs_are_found_by_language_and_keep_the_region() {
+    let british = catalog::catalog("en-GB").unwrap();
+    assert_eq!(british.tag(), "en-GB");
+    let args = Args::new().with("date", Date::new(2024, 3, 9).unwrap());
+    let text = british.render("farewells.see_you_on", &args).unwrap();
+    assert_eq!(text.unwrap(), "See you on 09/03/2024.");
+    let american = catalog::catalog("en_US").unwrap();
+    let text = american.render("farewells.see_you_on", &args).unwrap();
+    assert_eq!(text.unwrap(), "See you on 3/9/2024.");
+    assert_eq!(catalog::catalog("ja-JP").unwrap().tag(), "ja-JP");
+    assert!(catalog::catalog("fr").is_none());
+    assert!(catalog::catalog("eng").is_none());
+}
+
+#[test]
+fn every_shipped_language_has_a_catalog() {
+    assert_eq!(catalog::languages(), ["en", "ja"]);
+    for language in catalog::languages() {
+        assert_eq!(catalog::catalog(language).unwrap().keys().len(), 6);
+    }
+    let coverage = catalog::coverage();
+    assert_eq!(coverage.total, 6);
+    assert!(coverage.languages.iter().all(|l| l.missing.is_empty()));
+}
+
+#[test]
+fn missing_keys_are_listed_in_order() {
+    let english = catalog::catalog("en").unwrap();
+    let german = partial();
+    assert_eq!(
+        german.missing_from(&english),
+        [
+            "greetings.hello_to",
+            "greetings.welcome_back",
+            "farewells.goodbye",
+            "farewells.see_you_in",
+        ]
+    );
+    assert_eq!(english.missing_from(&german), ["farewells.extra"]);
+    assert!(english.missing_from(&english).is_empty());
+    assert!(german.render("greetings.hello_to", &Args::new()).is_none());
+}
+
+#[test]
+fn coverage_counts_keys_known_to_any_language() {
+    let catalogs = vec![catalog::catalog("en").unwrap(), partial()];
+    let coverage = catalog::coverage_of(&catalogs);
+    assert_eq!(coverage.total, 7);
+
+    let english = &coverage.languages[0];
+    assert_eq!(english.language, "en");
+    assert_eq!(english.translated, 6);
+    assert_eq!(english.missing, ["farewells.extra"]);
+
+    let german = &coverage.languages[1];
+    assert_eq!(german.language, "de-AT");
+    assert_eq!(german.translated, 3);
+    assert_eq!(german.missing.len(), 4);
+    assert!((german.percent() - 300.0 / 7.0).abs() < 1e-9);
+
+    let csv = coverage.to_csv();
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(lines[0], "language,translated,total,percent,missing");
+    assert_eq!(lines[1], "en,6,7,85.7,\"farewells.extra\"");
+    assert!(lines[2].starts_with("de-AT,3,7,42.9,\"farewells.goodbye "));
+
+    let json = coverage.to_json();
+    assert!(json.starts_with("{\n  \"total\": 7,"));
+    assert!(json.contains(
+        "{\"language\": \"en\", \"translated\": 6, \"percent\": 85.7, \"missing\": [\"farewells.extra\"]}"
+    ));
+}
+
+#[test]
+fn empty_catalogs_are_fully_covered() {
+    let coverage = catalog::coverage_of(&[Catalog::new("xx", vec![])]);
+    assert_eq!(coverage.total, 0);
+    assert_eq!(coverage.languages[0].percent(), 100.0);
+}
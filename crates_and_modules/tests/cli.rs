@@ -0,0 +1,68 @@
+// Runs the `phrases` binary the way a user would.
+
+use std::process::{Command, Output};
+
+fn phrases(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_phrases"))
+        .args(args)
+        .output()
+        .expect("failed to run phrases")
+}
+
+fn show(args: &[&str]) -> String {
+    let mut all = vec!["show"];
+    all.extend_from_slice(args);
+    let output = phrases(&all);
+    assert!(
+        output.status.success(),
+        "{:?}: {}",
+        args,
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn numeric_looking_strings_are_shown_as_typed() {
+    assert_eq!(
+        show(&["en", "greetings.hello_to", "name=007"]),
+        "Hello, 007!\n"
+    );
+    assert_eq!(
+        show(&["en", "greetings.hello_to", "name=inf"]),
+        "Hello, inf!\n"
+    );
+    assert_eq!(
+        show(&["en", "greetings.hello_to", "name=2024-03-09"]),
+        "Hello, 2024-03-09!\n"
+    );
+}
+
+#[test]
+fn numbers_and_dates_are_parsed_where_the_phrase_needs_them() {
+    assert_eq!(
+        show(&[
+            "en",
+            "greetings.welcome_back",
+            "gender=female",
+            "name=007",
+            "unread=1"
+        ]),
+        "Welcome back, Ms. 007! You have 1 new message.\n"
+    );
+    assert_eq!(
+        show(&["en", "farewells.see_you_in", "days=03"]),
+        "See you in 3 days!\n"
+    );
+    assert_eq!(
+        show(&["en", "farewells.see_you_on", "date=2024-03-09"]),
+        "See you on 3/9/2024.\n"
+    );
+
+    let output = phrases(&["show", "en", "farewells.see_you_in", "days=soon"]);
+    assert_eq!(output.status.code(), Some(2));
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        "`days` must be a number, got `soon`\n"
+    );
+}
@@ -0,0 +1,177 @@
+extern crate phrases;
+
+use phrases::message::{
+    self, Args, ArgumentKind, Date, FormatError, Gender, Locale, Message, PluralCategory,
+};
+
+fn en(template: &str, args: &Args) -> String {
+    message::format("en", template, args).unwrap()
+}
+
+fn syntax_error(template: &str) -> (usize, &'static str) {
+    match Message::parse(template) {
+        Err(FormatError::Syntax { position, reason }) => (position, reason),
+        other => panic!("{:?} should not parse, got {:?}", template, other),
+    }
+}
+
+#[test]
+fn placeholders_are_filled_in() {
+    let args = Args::new()
+        .with("name", "Ann")
+        .with("total", 1234.56)
+        .with("count", 1234567)
+        .with("when", Date::new(2024, 3, 9).unwrap());
+    assert_eq!(en("Hello, {name}!", &args), "Hello, Ann!");
+    assert_eq!(en("{total, number}", &args), "1,234.56");
+    assert_eq!(en("{total, number, integer}", &args), "1,235");
+    assert_eq!(en("{count}", &args), "1,234,567");
+    assert_eq!(en("{when, date}", &args), "3/9/2024");
+    assert_eq!(
+        message::format("en-GB", "{when}", &args).unwrap(),
+        "09/03/2024"
+    );
+    assert_eq!(
+        message::format("ja", "{when, date}", &args).unwrap(),
+        "2024/03/09"
+    );
+}
+
+#[test]
+fn missing_and_mistyped_arguments_are_errors() {
+    let args = Args::new().with("name", "Ann");
+    assert_eq!(
+        message::format("en", "{count, number}", &args),
+        Err(FormatError::MissingArgument("count".to_string()))
+    );
+    assert_eq!(
+        message::format("en", "{name, date}", &args),
+        Err(FormatError::WrongType {
+            argument: "name".to_string(),
+            expected: "date",
+        })
+    );
+}
+
+#[test]
+fn plural_categories_follow_the_language() {
+    assert_eq!(message::plural_category("en", 1.0), PluralCategory::One);
+    assert_eq!(message::plural_category("en-GB", 1.0), PluralCategory::One);
+    assert_eq!(message::plural_category("en", 0.0), PluralCategory::Other);
+    assert_eq!(message::plural_category("en", 1.5), PluralCategory::Other);
+    assert_eq!(message::plural_category("en", 2.0), PluralCategory::Other);
+    for &n in &[0.0, 1.0, 2.0, 1.5] {
+        assert_eq!(message::plural_category("ja", n), PluralCategory::Other);
+    }
+    assert_eq!(
+        Locale::new("ja-JP").plural_category(1.0),
+        PluralCategory::Other
+    );
+}
+
+#[test]
+fn plural_branches_pick_exact_then_category_then_other() {
+    let template = "{n, plural, =0 {none} one {# file} other {# files}}";
+    let count = |n: i32| Args::new().with("n", n);
+    assert_eq!(en(template, &count(0)), "none");
+    assert_eq!(en(template, &count(1)), "1 file");
+    assert_eq!(en(template, &count(1000)), "1,000 files");
+    assert_eq!(
+        message::format("ja", template, &count(1)).unwrap(),
+        "1 files"
+    );
+    assert_eq!(message::format("ja", template, &count(0)).unwrap(), "none");
+}
+
+#[test]
+fn select_falls_back_to_other() {
+    let template = "{who, select, female {She} male {He} other {They}} left";
+    assert_eq!(
+        en(template, &Args::new().with("who", Gender::Female)),
+        "She left"
+    );
+    assert_eq!(
+        en(template, &Args::new().with("who", Gender::Male)),
+        "He left"
+    );
+    assert_eq!(en(template, &Args::new().with("who", "robot")), "They left");
+    assert_eq!(
+        message::format("en", template, &Args::new().with("who", 3)),
+        Err(FormatError::WrongType {
+            argument: "who".to_string(),
+            expected: "string",
+        })
+    );
+}
+
+#[test]
+fn hash_is_the_count_only_inside_a_plural() {
+    assert_eq!(en("# {n}", &Args::new().with("n", 2)), "# 2");
+    let template = "{n, plural, one {{who, select, female {her # cat} other {their # cat}}} \
+                    other {{who, select, female {her # cats} other {their # cats}}}}";
+    let args = Args::new().with("n", 3).with("who", Gender::Female);
+    assert_eq!(en(template, &args), "her 3 cats");
+    let args = Args::new().with("n", 1).with("who", "x");
+    assert_eq!(en(template, &args), "their 1 cat");
+    // Outside a plural a select branch keeps `#` as text
+    let args = Args::new().with("who", "x");
+    assert_eq!(en("{who, select, other {#1}}", &args), "#1");
+}
+
+#[test]
+fn quotes_escape_syntax_characters() {
+    let args = Args::new().with("n", 2);
+    assert_eq!(en("it''s '{n}'", &args), "it's {n}");
+    assert_eq!(en("don't", &args), "don't");
+    assert_eq!(en("{n, plural, other {'#' #}}", &args), "# 2");
+}
+
+#[test]
+fn malformed_templates_report_where() {
+    assert_eq!(syntax_error("a }").0, 2);
+    assert_eq!(syntax_error("{}").1, "expected argument name");
+    assert_eq!(
+        syntax_error("{n x}").1,
+        "expected `,` or `}` after argument name"
+    );
+    assert_eq!(syntax_error("{n, number, money}").1, "unknown number style");
+    assert_eq!(
+        syntax_error("{n, plural, one {x}}").1,
+        "an `other` branch is required"
+    );
+    assert_eq!(
+        syntax_error("{n, plural, =x {a} other {b}}").1,
+        "invalid exact plural value"
+    );
+    assert_eq!(syntax_error("{n, plural, other {x").1, "unclosed branch");
+    assert_eq!(syntax_error("'{x").1, "unclosed quote");
+    let error = Message::parse("ab}").unwrap_err();
+    assert_eq!(error.to_string(), "syntax error at byte 2: unmatched `}`");
+}
+
+#[test]
+fn dates_must_exist() {
+    assert!(Date::new(2024, 13, 1).is_none());
+    assert!(Date::new(2024, 0, 1).is_none());
+    assert!(Date::new(2024, 1, 45).is_none());
+    assert!(Date::new(2024, 4, 31).is_none());
+    assert!(Date::new(2023, 2, 29).is_none());
+    assert!(Date::new(1900, 2, 29).is_none());
+    assert!(Date::new(2000, 2, 29).is_some());
+    let date = Date::new(2024, 2, 29).unwrap();
+    assert_eq!((date.year(), date.month(), date.day()), (2024, 2, 29));
+}
+
+#[test]
+fn argument_kinds_follow_how_the_message_uses_them() {
+    let message = Message::parse(
+        "{name} {gender, select, other {{total, number} {n, plural, other {{when, date}}}}}",
+    )
+    .unwrap();
+    assert_eq!(message.argument_kind("name"), ArgumentKind::Text);
+    assert_eq!(message.argument_kind("gender"), ArgumentKind::Text);
+    assert_eq!(message.argument_kind("total"), ArgumentKind::Number);
+    assert_eq!(message.argument_kind("n"), ArgumentKind::Number);
+    assert_eq!(message.argument_kind("when"), ArgumentKind::Date);
+    assert_eq!(message.argument_kind("unused"), ArgumentKind::Text);
+}
@@ -0,0 +1,4 @@
+// Shapes grown out of the `Circle` used to explain method syntax in
+// main.rs.
+
+pub mod shapes;
@@ -1,3 +1,7 @@
+extern crate method_syntax;
+
+use method_syntax::shapes::{self, Shape};
+
 fn main() {
     println!("Method syntax");
 
@@ -78,7 +82,7 @@ fn main() {
         }
 
         fn y(&mut self, coordinate: f64) -> &mut CircleBuilder {
-          self.x = coordinate;
+          self.y = coordinate;
           self
         }
 
@@ -91,4 +95,43 @@ fn main() {
           Circle { x: self.x, y: self.y, radius: self.radius }
         }
     }
+
+    // The builders in the shapes module also validate what they were given
+    // and return a Result instead of quietly building a broken Circle.
+    match shapes::CircleBuilder::new().x(1.0).y(f64::NAN).radius(-2.0).finalize() {
+        Ok(circle) => println!("{:?}", circle),
+        Err(e) => println!("{}", e),
+    }
+
+    // Traits
+    // A trait lists methods a type promises to have. The shapes module
+    // grows Circle into a family of shapes sharing the Shape trait, so
+    // different shapes can live in one collection behind Box<dyn Shape>.
+    // The floor plan is kept as a scene file and parsed through the
+    // builders above, see shapes::scene for the format.
+    let mut plan: Vec<Box<dyn Shape>> = match shapes::scene::parse(include_str!("../scenes/floor_plan.scene")) {
+        Ok(plan) => plan,
+        Err(e) => panic!("floor_plan.scene {}", e),
+    };
+
+    println!("total area {}", shapes::total_area(&plan));
+    shapes::sort_by_area(&mut plan);
+    for shape in &plan {
+        println!("{:?} area {} perimeter {}", shape, shape.area(), shape.perimeter());
+    }
+
+    // Most of the floor plan overlaps around the origin
+    for (i, j) in shapes::collision::colliding_pairs(&plan) {
+        println!("{:?} collides with {:?}", plan[i].kind(), plan[j].kind());
+    }
+
+    // Instead of printing numbers, the floor plan can be drawn as SVG
+    let mut drawing = shapes::svg::Document::new(400.0, 300.0);
+    drawing.viewport = shapes::svg::Viewport::FitToContent(1.0);
+    let style = shapes::svg::Style::default().fill(shapes::svg::Color(200, 220, 255)).opacity(0.5);
+    let rooms = drawing.layer("rooms");
+    for shape in plan {
+        rooms.add(shape, style);
+    }
+    print!("{}", drawing);
 }
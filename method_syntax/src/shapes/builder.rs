@@ -0,0 +1,429 @@
+// Builders that check their inputs. `finalize` reports every invalid
+// field at once instead of stopping at the first one.
+
+use std::error::Error;
+use std::fmt;
+use std::marker::PhantomData;
+
+use super::{Circle, Ellipse, Point, Polygon, Rectangle, Shape, Triangle};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldError {
+    pub field: &'static str,
+    pub reason: &'static str,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BuildError {
+    pub shape: &'static str,
+    pub errors: Vec<FieldError>,
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid {}: ", self.shape)?;
+        for (i, e) in self.errors.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{} {}", e.field, e.reason)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for BuildError {}
+
+// Collects field errors while a builder validates itself.
+struct Checker {
+    errors: Vec<FieldError>,
+}
+
+impl Checker {
+    fn new() -> Checker {
+        Checker { errors: Vec::new() }
+    }
+
+    fn fail(&mut self, field: &'static str, reason: &'static str) {
+        self.errors.push(FieldError { field, reason });
+    }
+
+    fn finite(&mut self, field: &'static str, value: f64) {
+        if !value.is_finite() {
+            self.fail(field, "must be finite");
+        }
+    }
+
+    fn positive(&mut self, field: &'static str, value: f64) {
+        if !value.is_finite() {
+            self.fail(field, "must be finite");
+        } else if value <= 0.0 {
+            self.fail(field, "must be positive");
+        }
+    }
+
+    fn required(&mut self, field: &'static str, value: Option<f64>) -> f64 {
+        match value {
+            Some(v) => {
+                self.positive(field, v);
+                v
+            }
+            None => {
+                self.fail(field, "is missing");
+                0.0
+            }
+        }
+    }
+
+    fn point(&mut self, field: &'static str, value: Option<Point>) -> Point {
+        match value {
+            Some(p) => {
+                if !p.x.is_finite() || !p.y.is_finite() {
+                    self.fail(field, "must be finite");
+                }
+                p
+            }
+            None => {
+                self.fail(field, "is missing");
+                Point::new(0.0, 0.0)
+            }
+        }
+    }
+
+    fn finish<T>(self, shape: &'static str, value: T) -> Result<T, BuildError> {
+        if self.errors.is_empty() {
+            Ok(value)
+        } else {
+            Err(BuildError { shape, errors: self.errors })
+        }
+    }
+}
+
+// Coordinates default to the origin and the radius to 1.0.
+#[derive(Debug, Clone)]
+pub struct CircleBuilder {
+    x: f64,
+    y: f64,
+    radius: f64,
+}
+
+impl Default for CircleBuilder {
+    fn default() -> CircleBuilder {
+        CircleBuilder::new()
+    }
+}
+
+impl CircleBuilder {
+    pub fn new() -> CircleBuilder {
+        CircleBuilder { x: 0.0, y: 0.0, radius: 1.0 }
+    }
+
+    pub fn x(&mut self, coordinate: f64) -> &mut CircleBuilder {
+        self.x = coordinate;
+        self
+    }
+
+    pub fn y(&mut self, coordinate: f64) -> &mut CircleBuilder {
+        self.y = coordinate;
+        self
+    }
+
+    pub fn radius(&mut self, radius: f64) -> &mut CircleBuilder {
+        self.radius = radius;
+        self
+    }
+
+    pub fn finalize(&self) -> Result<Circle, BuildError> {
+        let mut check = Checker::new();
+        check.finite("x", self.x);
+        check.finite("y", self.y);
+        check.positive("radius", self.radius);
+        check.finish("circle", Circle::new(self.x, self.y, self.radius))
+    }
+}
+
+// Marker types for `TypedCircleBuilder`.
+#[derive(Debug, Clone, Copy)]
+pub struct NoRadius;
+#[derive(Debug, Clone, Copy)]
+pub struct HasRadius;
+
+/// Circle builder whose `finalize` only exists once `radius` was called,
+/// so forgetting the radius is a compile error rather than a default:
+///
+/// ```
+/// use method_syntax::shapes::{Circle, TypedCircleBuilder};
+///
+/// let circle = TypedCircleBuilder::new().x(1.0).radius(2.0).finalize();
+/// assert_eq!(circle, Ok(Circle::new(1.0, 0.0, 2.0)));
+/// ```
+///
+/// ```compile_fail
+/// use method_syntax::shapes::TypedCircleBuilder;
+///
+/// TypedCircleBuilder::new().x(1.0).finalize();
+/// ```
+///
+/// Nor can the radius be set twice:
+///
+/// ```compile_fail
+/// use method_syntax::shapes::TypedCircleBuilder;
+///
+/// TypedCircleBuilder::new().radius(1.0).radius(2.0);
+/// ```
+///
+/// Values are still validated when finalizing.
+#[derive(Debug, Clone)]
+pub struct TypedCircleBuilder<R> {
+    x: f64,
+    y: f64,
+    radius: f64,
+    state: PhantomData<R>,
+}
+
+impl Default for TypedCircleBuilder<NoRadius> {
+    fn default() -> TypedCircleBuilder<NoRadius> {
+        TypedCircleBuilder::new()
+    }
+}
+
+impl TypedCircleBuilder<NoRadius> {
+    pub fn new() -> TypedCircleBuilder<NoRadius> {
+        TypedCircleBuilder { x: 0.0, y: 0.0, radius: 0.0, state: PhantomData }
+    }
+
+    pub fn radius(self, radius: f64) -> TypedCircleBuilder<HasRadius> {
+        TypedCircleBuilder { x: self.x, y: self.y, radius, state: PhantomData }
+    }
+}
+
+impl<R> TypedCircleBuilder<R> {
+    pub fn x(self, coordinate: f64) -> TypedCircleBuilder<R> {
+        TypedCircleBuilder { x: coordinate, ..self }
+    }
+
+    pub fn y(self, coordinate: f64) -> TypedCircleBuilder<R> {
+        TypedCircleBuilder { y: coordinate, ..self }
+    }
+}
+
+impl TypedCircleBuilder<HasRadius> {
+    pub fn finalize(self) -> Result<Circle, BuildError> {
+        CircleBuilder::new().x(self.x).y(self.y).radius(self.radius).finalize()
+    }
+}
+
+// `x` and `y` default to the origin, `width` and `height` are required.
+#[derive(Debug, Clone, Default)]
+pub struct RectangleBuilder {
+    x: f64,
+    y: f64,
+    width: Option<f64>,
+    height: Option<f64>,
+}
+
+impl RectangleBuilder {
+    pub fn new() -> RectangleBuilder {
+        RectangleBuilder::default()
+    }
+
+    pub fn x(&mut self, coordinate: f64) -> &mut RectangleBuilder {
+        self.x = coordinate;
+        self
+    }
+
+    pub fn y(&mut self, coordinate: f64) -> &mut RectangleBuilder {
+        self.y = coordinate;
+        self
+    }
+
+    pub fn width(&mut self, width: f64) -> &mut RectangleBuilder {
+        self.width = Some(width);
+        self
+    }
+
+    pub fn height(&mut self, height: f64) -> &mut RectangleBuilder {
+        self.height = Some(height);
+        self
+    }
+
+    pub fn finalize(&self) -> Result<Rectangle, BuildError> {
+        let mut check = Checker::new();
+        check.finite("x", self.x);
+        check.finite("y", self.y);
+        let width = check.required("width", self.width);
+        let height = check.required("height", self.height);
+        check.finish("rectangle", Rectangle::new(self.x, self.y, width, height))
+    }
+}
+
+// All three vertices are required and may not lie on one line.
+#[derive(Debug, Clone, Default)]
+pub struct TriangleBuilder {
+    a: Option<Point>,
+    b: Option<Point>,
+    c: Option<Point>,
+}
+
+impl TriangleBuilder {
+    pub fn new() -> TriangleBuilder {
+        TriangleBuilder::default()
+    }
+
+    pub fn a(&mut self, x: f64, y: f64) -> &mut TriangleBuilder {
+        self.a = Some(Point::new(x, y));
+        self
+    }
+
+    pub fn b(&mut self, x: f64, y: f64) -> &mut TriangleBuilder {
+        self.b = Some(Point::new(x, y));
+        self
+    }
+
+    pub fn c(&mut self, x: f64, y: f64) -> &mut TriangleBuilder {
+        self.c = Some(Point::new(x, y));
+        self
+    }
+
+    pub fn finalize(&self) -> Result<Triangle, BuildError> {
+        let mut check = Checker::new();
+        let a = check.point("a", self.a);
+        let b = check.point("b", self.b);
+        let c = check.point("c", self.c);
+        let triangle = Triangle::new(a, b, c);
+        if check.errors.is_empty() && triangle.area() == 0.0 {
+            check.fail("vertices", "are collinear");
+        }
+        check.finish("triangle", triangle)
+    }
+}
+
+// Needs at least three vertices, not all on one line, with edges that
+// only meet where consecutive ones share a vertex.
+#[derive(Debug, Clone, Default)]
+pub struct PolygonBuilder {
+    vertices: Vec<Point>,
+}
+
+impl PolygonBuilder {
+    pub fn new() -> PolygonBuilder {
+        PolygonBuilder::default()
+    }
+
+    pub fn vertex(&mut self, x: f64, y: f64) -> &mut PolygonBuilder {
+        self.vertices.push(Point::new(x, y));
+        self
+    }
+
+    pub fn finalize(&self) -> Result<Polygon, BuildError> {
+        let mut check = Checker::new();
+        if self.vertices.len() < 3 {
+            check.fail("vertices", "must be at least three");
+        }
+        if self.vertices.iter().any(|p| !p.x.is_finite() || !p.y.is_finite()) {
+            check.fail("vertices", "must be finite");
+        }
+        if check.errors.is_empty() {
+            if collinear(&self.vertices) {
+                check.fail("vertices", "are collinear");
+            } else if self_intersecting(&self.vertices) {
+                check.fail("edges", "cross each other");
+            }
+        }
+        check.finish("polygon", Polygon::new(self.vertices.clone()))
+    }
+}
+
+// Twice the signed area of triangle `a b c`; zero when they are on one
+// line.
+fn orientation(a: Point, b: Point, c: Point) -> f64 {
+    (b.x - a.x) * (c.y - a.y) - (b.y - a.y) * (c.x - a.x)
+}
+
+fn collinear(points: &[Point]) -> bool {
+    let a = points[0];
+    match points.iter().find(|&&b| b != a) {
+        Some(&b) => points.iter().all(|&c| orientation(a, b, c) == 0.0),
+        None => true,
+    }
+}
+
+// Whether `p`, on the line through `a` and `b`, lies between them.
+fn between(p: Point, a: Point, b: Point) -> bool {
+    p.x >= a.x.min(b.x) && p.x <= a.x.max(b.x) && p.y >= a.y.min(b.y) && p.y <= a.y.max(b.y)
+}
+
+// Segments `a b` and `c d` cross or touch, end points included.
+fn segments_touch(a: Point, b: Point, c: Point, d: Point) -> bool {
+    let (d1, d2) = (orientation(c, d, a), orientation(c, d, b));
+    let (d3, d4) = (orientation(a, b, c), orientation(a, b, d));
+    if d1 * d2 < 0.0 && d3 * d4 < 0.0 {
+        return true;
+    }
+    (d1 == 0.0 && between(a, c, d))
+        || (d2 == 0.0 && between(b, c, d))
+        || (d3 == 0.0 && between(c, a, b))
+        || (d4 == 0.0 && between(d, a, b))
+}
+
+// Consecutive edges `a b` and `b c` may only meet at `b`, so they may
+// not double back over each other. Other edges may not meet at all.
+fn self_intersecting(vertices: &[Point]) -> bool {
+    let n = vertices.len();
+    let edge = |i: usize| (vertices[i], vertices[(i + 1) % n]);
+    (0..n).any(|i| {
+        let (a, b) = edge(i);
+        let c = vertices[(i + 2) % n];
+        let back = (a.x - b.x) * (c.x - b.x) + (a.y - b.y) * (c.y - b.y);
+        let doubles_back = orientation(a, b, c) == 0.0 && back > 0.0;
+        doubles_back
+            || (i + 2..n).filter(|&j| (j + 1) % n != i).any(|j| {
+                let (c, d) = edge(j);
+                segments_touch(a, b, c, d)
+            })
+    })
+}
+
+// The center defaults to the origin, both radii are required.
+#[derive(Debug, Clone, Default)]
+pub struct EllipseBuilder {
+    x: f64,
+    y: f64,
+    rx: Option<f64>,
+    ry: Option<f64>,
+}
+
+impl EllipseBuilder {
+    pub fn new() -> EllipseBuilder {
+        EllipseBuilder::default()
+    }
+
+    pub fn x(&mut self, coordinate: f64) -> &mut EllipseBuilder {
+        self.x = coordinate;
+        self
+    }
+
+    pub fn y(&mut self, coordinate: f64) -> &mut EllipseBuilder {
+        self.y = coordinate;
+        self
+    }
+
+    pub fn rx(&mut self, radius: f64) -> &mut EllipseBuilder {
+        self.rx = Some(radius);
+        self
+    }
+
+    pub fn ry(&mut self, radius: f64) -> &mut EllipseBuilder {
+        self.ry = Some(radius);
+        self
+    }
+
+    pub fn finalize(&self) -> Result<Ellipse, BuildError> {
+        let mut check = Checker::new();
+        check.finite("x", self.x);
+        check.finite("y", self.y);
+        let rx = check.required("rx", self.rx);
+        let ry = check.required("ry", self.ry);
+        check.finish("ellipse", Ellipse::new(self.x, self.y, rx, ry))
+    }
+}
@@ -0,0 +1,57 @@
+use std::f64::consts::PI;
+
+use super::{BoundingBox, Point, Shape, ShapeRef};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Circle {
+    pub x: f64,
+    pub y: f64,
+    pub radius: f64,
+}
+
+impl Circle {
+    pub fn new(x: f64, y: f64, radius: f64) -> Circle {
+        Circle { x, y, radius }
+    }
+
+    pub fn center(&self) -> Point {
+        Point::new(self.x, self.y)
+    }
+
+    pub fn grow(&self, increment: f64) -> Circle {
+        Circle {
+            x: self.x,
+            y: self.y,
+            radius: self.radius + increment,
+        }
+    }
+}
+
+impl Shape for Circle {
+    fn area(&self) -> f64 {
+        PI * (self.radius * self.radius)
+    }
+
+    fn perimeter(&self) -> f64 {
+        2.0 * PI * self.radius
+    }
+
+    fn centroid(&self) -> Point {
+        self.center()
+    }
+
+    fn bounding_box(&self) -> BoundingBox {
+        BoundingBox::new(
+            Point::new(self.x - self.radius, self.y - self.radius),
+            Point::new(self.x + self.radius, self.y + self.radius),
+        )
+    }
+
+    fn contains(&self, p: Point) -> bool {
+        self.center().distance(p) <= self.radius
+    }
+
+    fn kind(&self) -> ShapeRef<'_> {
+        ShapeRef::Circle(self)
+    }
+}
@@ -0,0 +1,298 @@
+// Intersection tests between shapes and a sweep-and-prune broad phase
+// for finding colliding pairs among many shapes.
+//
+// Circles are handled exactly, everything else as a polygon. Ellipses
+// are approximated by a polygon with `ELLIPSE_SEGMENTS` sides, shapes
+// from outside this module by their `Shape::boundary`.
+
+use super::{BoundingBox, Circle, Point, Polygon, Rectangle, Shape, ShapeRef};
+
+pub const ELLIPSE_SEGMENTS: usize = 64;
+
+// What the narrow phase works with.
+enum Collider {
+    Circle(Point, f64),
+    Polygon(Vec<Point>),
+}
+
+fn collider(shape: &dyn Shape) -> Collider {
+    match shape.kind() {
+        ShapeRef::Circle(c) => Collider::Circle(c.center(), c.radius),
+        ShapeRef::Ellipse(e) => Collider::Polygon(e.outline(ELLIPSE_SEGMENTS)),
+        ShapeRef::Polygon(p) => Collider::Polygon(p.vertices().to_vec()),
+        ShapeRef::Rectangle(r) => Collider::Polygon(r.corners().to_vec()),
+        ShapeRef::Triangle(t) => Collider::Polygon(vec![t.a, t.b, t.c]),
+        ShapeRef::Other => Collider::Polygon(shape.boundary()),
+    }
+}
+
+fn cross(o: Point, a: Point, b: Point) -> f64 {
+    (a.x - o.x) * (b.y - o.y) - (a.y - o.y) * (b.x - o.x)
+}
+
+fn edges(vertices: &[Point]) -> Vec<(Point, Point)> {
+    let n = vertices.len();
+    (0..n)
+        .map(|i| (vertices[i], vertices[(i + 1) % n]))
+        .collect()
+}
+
+fn is_convex(vertices: &[Point]) -> bool {
+    let n = vertices.len();
+    let mut sign = 0.0;
+    for i in 0..n {
+        let turn = cross(vertices[i], vertices[(i + 1) % n], vertices[(i + 2) % n]);
+        if turn != 0.0 {
+            if sign != 0.0 && turn.signum() != sign {
+                return false;
+            }
+            sign = turn.signum();
+        }
+    }
+    true
+}
+
+fn closest_on_segment(p: Point, a: Point, b: Point) -> Point {
+    let (dx, dy) = (b.x - a.x, b.y - a.y);
+    let length = dx * dx + dy * dy;
+    if length == 0.0 {
+        return a;
+    }
+    let t = (((p.x - a.x) * dx + (p.y - a.y) * dy) / length).clamp(0.0, 1.0);
+    Point::new(a.x + t * dx, a.y + t * dy)
+}
+
+pub fn circles_intersect(a: &Circle, b: &Circle) -> bool {
+    a.center().distance(b.center()) <= a.radius + b.radius
+}
+
+pub fn circle_rectangle_intersect(c: &Circle, r: &Rectangle) -> bool {
+    // Not `clamp`, which panics on NaN bounds
+    let b = r.bounding_box();
+    let nearest = Point::new(
+        c.x.max(b.min.x).min(b.max.x),
+        c.y.max(b.min.y).min(b.max.y),
+    );
+    nearest.distance(c.center()) <= c.radius
+}
+
+fn circle_polygon_intersect(center: Point, radius: f64, vertices: &[Point]) -> bool {
+    Polygon::new(vertices.to_vec()).contains(center)
+        || edges(vertices)
+            .iter()
+            .any(|&(a, b)| closest_on_segment(center, a, b).distance(center) <= radius)
+}
+
+// Projection of `vertices` onto `axis` as (min, max).
+fn project(vertices: &[Point], axis: Point) -> (f64, f64) {
+    vertices
+        .iter()
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), p| {
+            let d = p.x * axis.x + p.y * axis.y;
+            (lo.min(d), hi.max(d))
+        })
+}
+
+// Separating axis test. Both polygons have to be convex.
+pub fn convex_polygons_intersect(a: &[Point], b: &[Point]) -> bool {
+    for vertices in &[a, b] {
+        for (p, q) in edges(vertices) {
+            let axis = Point::new(p.y - q.y, q.x - p.x);
+            let (a_min, a_max) = project(a, axis);
+            let (b_min, b_max) = project(b, axis);
+            if a_max < b_min || b_max < a_min {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+// Works for concave polygons too: they touch when an edge of one crosses
+// an edge of the other, or when one lies completely inside the other.
+pub fn polygons_intersect(a: &[Point], b: &[Point]) -> bool {
+    if a.is_empty() || b.is_empty() {
+        return false;
+    }
+    if is_convex(a) && is_convex(b) {
+        return convex_polygons_intersect(a, b);
+    }
+    let (ea, eb) = (edges(a), edges(b));
+    ea.iter()
+        .any(|&(p, q)| eb.iter().any(|&(r, s)| segments_touch(p, q, r, s)))
+        || Polygon::new(b.to_vec()).contains(a[0])
+        || Polygon::new(a.to_vec()).contains(b[0])
+}
+
+fn segments_touch(p: Point, q: Point, r: Point, s: Point) -> bool {
+    let on_segment = |a: Point, b: Point, c: Point| {
+        c.x >= a.x.min(b.x) && c.x <= a.x.max(b.x) && c.y >= a.y.min(b.y) && c.y <= a.y.max(b.y)
+    };
+    let d1 = cross(r, s, p);
+    let d2 = cross(r, s, q);
+    let d3 = cross(p, q, r);
+    let d4 = cross(p, q, s);
+    if ((d1 > 0.0 && d2 < 0.0) || (d1 < 0.0 && d2 > 0.0))
+        && ((d3 > 0.0 && d4 < 0.0) || (d3 < 0.0 && d4 > 0.0))
+    {
+        return true;
+    }
+    (d1 == 0.0 && on_segment(r, s, p))
+        || (d2 == 0.0 && on_segment(r, s, q))
+        || (d3 == 0.0 && on_segment(p, q, r))
+        || (d4 == 0.0 && on_segment(p, q, s))
+}
+
+// Whether two shapes overlap, touching boundaries included.
+pub fn intersects(a: &dyn Shape, b: &dyn Shape) -> bool {
+    if !a.bounding_box().intersects(&b.bounding_box()) {
+        return false;
+    }
+    match (a.kind(), b.kind()) {
+        (ShapeRef::Circle(c), ShapeRef::Rectangle(r))
+        | (ShapeRef::Rectangle(r), ShapeRef::Circle(c)) => {
+            return circle_rectangle_intersect(c, r);
+        }
+        _ => {}
+    }
+    match (collider(a), collider(b)) {
+        (Collider::Circle(p, r1), Collider::Circle(q, r2)) => p.distance(q) <= r1 + r2,
+        (Collider::Circle(c, r), Collider::Polygon(v))
+        | (Collider::Polygon(v), Collider::Circle(c, r)) => circle_polygon_intersect(c, r, &v),
+        (Collider::Polygon(v), Collider::Polygon(w)) => polygons_intersect(&v, &w),
+    }
+}
+
+// Distance from `p` to the nearest point of `shape`, 0.0 inside it.
+pub fn distance(shape: &dyn Shape, p: Point) -> f64 {
+    if shape.contains(p) {
+        return 0.0;
+    }
+    match collider(shape) {
+        Collider::Circle(c, r) => (c.distance(p) - r).max(0.0),
+        Collider::Polygon(v) => edges(&v)
+            .iter()
+            .map(|&(a, b)| closest_on_segment(p, a, b).distance(p))
+            .fold(f64::INFINITY, f64::min),
+    }
+}
+
+// Where the boundaries of two circles cross: none, one when they touch,
+// or two. Identical circles have infinitely many and give none.
+pub fn circle_circle_points(a: &Circle, b: &Circle) -> Vec<Point> {
+    circle_points(a.center(), a.radius, b.center(), b.radius)
+}
+
+fn circle_points(p: Point, r1: f64, q: Point, r2: f64) -> Vec<Point> {
+    let d = p.distance(q);
+    if d == 0.0 || d > r1 + r2 || d < (r1 - r2).abs() {
+        return Vec::new();
+    }
+    let a = (r1 * r1 - r2 * r2 + d * d) / (2.0 * d);
+    let h = (r1 * r1 - a * a).max(0.0).sqrt();
+    let mid = Point::new(p.x + a * (q.x - p.x) / d, p.y + a * (q.y - p.y) / d);
+    if h == 0.0 {
+        return vec![mid];
+    }
+    let (ox, oy) = (h * (q.y - p.y) / d, h * (q.x - p.x) / d);
+    vec![
+        Point::new(mid.x + ox, mid.y - oy),
+        Point::new(mid.x - ox, mid.y + oy),
+    ]
+}
+
+// Crossing point of segments p-q and r-s. Parallel segments give `None`
+// even when they overlap.
+pub fn segment_intersection(p: Point, q: Point, r: Point, s: Point) -> Option<Point> {
+    let (dx1, dy1) = (q.x - p.x, q.y - p.y);
+    let (dx2, dy2) = (s.x - r.x, s.y - r.y);
+    let denominator = dx1 * dy2 - dy1 * dx2;
+    if denominator == 0.0 {
+        return None;
+    }
+    let t = ((r.x - p.x) * dy2 - (r.y - p.y) * dx2) / denominator;
+    let u = ((r.x - p.x) * dy1 - (r.y - p.y) * dx1) / denominator;
+    if (0.0..=1.0).contains(&t) && (0.0..=1.0).contains(&u) {
+        Some(Point::new(p.x + t * dx1, p.y + t * dy1))
+    } else {
+        None
+    }
+}
+
+fn segment_circle_points(a: Point, b: Point, center: Point, radius: f64) -> Vec<Point> {
+    let (dx, dy) = (b.x - a.x, b.y - a.y);
+    let (fx, fy) = (a.x - center.x, a.y - center.y);
+    let qa = dx * dx + dy * dy;
+    let qb = 2.0 * (fx * dx + fy * dy);
+    let qc = fx * fx + fy * fy - radius * radius;
+    let discriminant = qb * qb - 4.0 * qa * qc;
+    if qa == 0.0 || discriminant < 0.0 {
+        return Vec::new();
+    }
+    let root = discriminant.sqrt();
+    let mut ts = vec![(-qb - root) / (2.0 * qa)];
+    if root > 0.0 {
+        ts.push((-qb + root) / (2.0 * qa));
+    }
+    ts.into_iter()
+        .filter(|t| (0.0..=1.0).contains(t))
+        .map(|t| Point::new(a.x + t * dx, a.y + t * dy))
+        .collect()
+}
+
+// Points where the boundaries of two shapes cross. Shapes nested inside
+// each other intersect but have no such points.
+pub fn intersection_points(a: &dyn Shape, b: &dyn Shape) -> Vec<Point> {
+    if !a.bounding_box().intersects(&b.bounding_box()) {
+        return Vec::new();
+    }
+    match (collider(a), collider(b)) {
+        (Collider::Circle(p, r1), Collider::Circle(q, r2)) => circle_points(p, r1, q, r2),
+        (Collider::Circle(c, r), Collider::Polygon(v))
+        | (Collider::Polygon(v), Collider::Circle(c, r)) => edges(&v)
+            .into_iter()
+            .flat_map(|(p, q)| segment_circle_points(p, q, c, r))
+            .collect(),
+        (Collider::Polygon(v), Collider::Polygon(w)) => {
+            let ew = edges(&w);
+            edges(&v)
+                .into_iter()
+                .flat_map(|(p, q)| {
+                    ew.iter()
+                        .filter_map(move |&(r, s)| segment_intersection(p, q, r, s))
+                })
+                .collect()
+        }
+    }
+}
+
+// Broad phase: pairs of indices whose bounding boxes overlap, found by
+// sorting the boxes along x and only comparing boxes whose x ranges
+// overlap. Each pair is reported once with the smaller index first.
+pub fn sweep_and_prune(shapes: &[Box<dyn Shape>]) -> Vec<(usize, usize)> {
+    let boxes: Vec<BoundingBox> = shapes.iter().map(|s| s.bounding_box()).collect();
+    let mut order: Vec<usize> = (0..boxes.len()).collect();
+    order.sort_by(|&i, &j| boxes[i].min.x.total_cmp(&boxes[j].min.x));
+
+    let mut active: Vec<usize> = Vec::new();
+    let mut pairs = Vec::new();
+    for i in order {
+        active.retain(|&j| boxes[j].max.x >= boxes[i].min.x);
+        for &j in &active {
+            if boxes[i].intersects(&boxes[j]) {
+                pairs.push((i.min(j), i.max(j)));
+            }
+        }
+        active.push(i);
+    }
+    pairs.sort();
+    pairs
+}
+
+// Broad phase followed by an exact test of every candidate pair.
+pub fn colliding_pairs(shapes: &[Box<dyn Shape>]) -> Vec<(usize, usize)> {
+    sweep_and_prune(shapes)
+        .into_iter()
+        .filter(|&(i, j)| intersects(&*shapes[i], &*shapes[j]))
+        .collect()
+}
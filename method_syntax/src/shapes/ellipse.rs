@@ -0,0 +1,74 @@
+use std::f64::consts::PI;
+
+use super::{BoundingBox, Point, Shape, ShapeRef};
+
+// Axis-aligned ellipse with semi-axes `rx` and `ry`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ellipse {
+    pub x: f64,
+    pub y: f64,
+    pub rx: f64,
+    pub ry: f64,
+}
+
+impl Ellipse {
+    pub fn new(x: f64, y: f64, rx: f64, ry: f64) -> Ellipse {
+        Ellipse { x, y, rx, ry }
+    }
+
+    pub fn center(&self) -> Point {
+        Point::new(self.x, self.y)
+    }
+
+    // `segments` points evenly spaced by angle around the boundary,
+    // counter-clockwise, for code that only handles polygons.
+    pub fn outline(&self, segments: usize) -> Vec<Point> {
+        (0..segments)
+            .map(|i| {
+                let t = 2.0 * PI * i as f64 / segments as f64;
+                Point::new(self.x + self.rx * t.cos(), self.y + self.ry * t.sin())
+            })
+            .collect()
+    }
+}
+
+impl Shape for Ellipse {
+    fn area(&self) -> f64 {
+        PI * self.rx * self.ry
+    }
+
+    // There is no closed form; Ramanujan's second approximation is
+    // exact for circles and within 0.04% for any other ellipse.
+    fn perimeter(&self) -> f64 {
+        let (a, b) = (self.rx, self.ry);
+        if a + b == 0.0 {
+            return 0.0;
+        }
+        let h = ((a - b) * (a - b)) / ((a + b) * (a + b));
+        PI * (a + b) * (1.0 + 3.0 * h / (10.0 + (4.0 - 3.0 * h).sqrt()))
+    }
+
+    fn centroid(&self) -> Point {
+        self.center()
+    }
+
+    fn bounding_box(&self) -> BoundingBox {
+        BoundingBox::new(
+            Point::new(self.x - self.rx, self.y - self.ry),
+            Point::new(self.x + self.rx, self.y + self.ry),
+        )
+    }
+
+    fn contains(&self, p: Point) -> bool {
+        if self.rx == 0.0 || self.ry == 0.0 {
+            return self.bounding_box().contains(p);
+        }
+        let dx = (p.x - self.x) / self.rx;
+        let dy = (p.y - self.y) / self.ry;
+        dx * dx + dy * dy <= 1.0
+    }
+
+    fn kind(&self) -> ShapeRef<'_> {
+        ShapeRef::Ellipse(self)
+    }
+}
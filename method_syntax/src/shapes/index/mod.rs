@@ -0,0 +1,81 @@
+// Spatial indexes over shapes, keyed by their bounding boxes. Both
+// answer the same queries; the quadtree needs the covered area up front,
+// the R-tree adapts to wherever the shapes are.
+
+use std::cmp::Ordering;
+
+use super::{BoundingBox, Point, Shape};
+
+mod quadtree;
+mod rtree;
+
+pub use self::quadtree::QuadTree;
+pub use self::rtree::RTree;
+
+pub trait SpatialIndex<T: Shape> {
+    fn insert(&mut self, shape: T);
+
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    // Shapes whose bounding box overlaps `area`.
+    fn query_range(&self, area: &BoundingBox) -> Vec<&T>;
+
+    // Shapes containing `p`.
+    fn query_point(&self, p: Point) -> Vec<&T> {
+        self.query_range(&BoundingBox::new(p, p))
+            .into_iter()
+            .filter(|s| s.contains(p))
+            .collect()
+    }
+
+    // Up to `k` shapes closest to `p`, nearest first. Shapes containing
+    // `p` are at distance 0.
+    fn nearest(&self, p: Point, k: usize) -> Vec<&T>;
+}
+
+// Either a tree node or a stored shape, waiting in the best-first search
+// used by `nearest`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Entry {
+    Node(usize),
+    Item(usize),
+}
+
+// Min-heap element ordered by distance.
+struct Candidate {
+    distance: f64,
+    entry: Entry,
+}
+
+impl PartialEq for Candidate {
+    fn eq(&self, other: &Candidate) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Candidate {}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Candidate) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Candidate {
+    // Reversed, so `BinaryHeap` pops the closest candidate first. Items
+    // win ties against nodes so they are reported as early as possible.
+    fn cmp(&self, other: &Candidate) -> Ordering {
+        other
+            .distance
+            .total_cmp(&self.distance)
+            .then_with(|| match (self.entry, other.entry) {
+                (Entry::Item(_), Entry::Node(_)) => Ordering::Greater,
+                (Entry::Node(_), Entry::Item(_)) => Ordering::Less,
+                _ => Ordering::Equal,
+            })
+    }
+}
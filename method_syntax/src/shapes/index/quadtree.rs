@@ -0,0 +1,178 @@
+use std::collections::BinaryHeap;
+
+use super::{Candidate, Entry, SpatialIndex};
+use shapes::collision;
+use shapes::{BoundingBox, Point, Shape};
+
+// A node is split once it holds more than `capacity` shapes, unless it
+// is already `max_depth` levels deep.
+const DEFAULT_CAPACITY: usize = 8;
+const DEFAULT_MAX_DEPTH: usize = 16;
+
+#[derive(Debug)]
+struct Node {
+    bounds: BoundingBox,
+    depth: usize,
+    // Indexes into `QuadTree::items`
+    items: Vec<usize>,
+    children: Option<[usize; 4]>,
+}
+
+// Region quadtree. Each shape is kept in the deepest node whose quadrant
+// fully encloses its bounding box, so shapes straddling a split line stay
+// in the parent. Shapes outside the tree's bounds are kept in the root.
+#[derive(Debug)]
+pub struct QuadTree<T> {
+    items: Vec<T>,
+    nodes: Vec<Node>,
+    capacity: usize,
+    max_depth: usize,
+}
+
+impl<T: Shape> QuadTree<T> {
+    pub fn new(bounds: BoundingBox) -> QuadTree<T> {
+        QuadTree::with_capacity(bounds, DEFAULT_CAPACITY, DEFAULT_MAX_DEPTH)
+    }
+
+    pub fn with_capacity(bounds: BoundingBox, capacity: usize, max_depth: usize) -> QuadTree<T> {
+        QuadTree {
+            items: Vec::new(),
+            nodes: vec![Node {
+                bounds,
+                depth: 0,
+                items: Vec::new(),
+                children: None,
+            }],
+            capacity: capacity.max(1),
+            max_depth,
+        }
+    }
+
+    pub fn iter(&self) -> ::std::slice::Iter<'_, T> {
+        self.items.iter()
+    }
+
+    // The child of `node` able to hold a shape with bounding box `b`.
+    fn child_for(&self, node: usize, b: &BoundingBox) -> Option<usize> {
+        self.nodes[node]
+            .children?
+            .iter()
+            .cloned()
+            .find(|&c| self.nodes[c].bounds.encloses(b))
+    }
+
+    fn split(&mut self, node: usize) {
+        let bounds = self.nodes[node].bounds;
+        let depth = self.nodes[node].depth + 1;
+        let mid = bounds.center();
+        let quadrants = [
+            BoundingBox::new(bounds.min, mid),
+            BoundingBox::new(
+                Point::new(mid.x, bounds.min.y),
+                Point::new(bounds.max.x, mid.y),
+            ),
+            BoundingBox::new(
+                Point::new(bounds.min.x, mid.y),
+                Point::new(mid.x, bounds.max.y),
+            ),
+            BoundingBox::new(mid, bounds.max),
+        ];
+        let first = self.nodes.len();
+        for q in &quadrants {
+            self.nodes.push(Node {
+                bounds: *q,
+                depth,
+                items: Vec::new(),
+                children: None,
+            });
+        }
+        self.nodes[node].children = Some([first, first + 1, first + 2, first + 3]);
+
+        let items = ::std::mem::take(&mut self.nodes[node].items);
+        for item in items {
+            let b = self.items[item].bounding_box();
+            let target = self.child_for(node, &b).unwrap_or(node);
+            self.nodes[target].items.push(item);
+        }
+    }
+}
+
+impl<T: Shape> SpatialIndex<T> for QuadTree<T> {
+    fn insert(&mut self, shape: T) {
+        let b = shape.bounding_box();
+        let item = self.items.len();
+        self.items.push(shape);
+
+        let mut node = 0;
+        while let Some(child) = self.child_for(node, &b) {
+            node = child;
+        }
+        self.nodes[node].items.push(item);
+
+        let n = &self.nodes[node];
+        if n.children.is_none() && n.items.len() > self.capacity && n.depth < self.max_depth {
+            self.split(node);
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    fn query_range(&self, area: &BoundingBox) -> Vec<&T> {
+        let mut found = Vec::new();
+        let mut stack = vec![0];
+        while let Some(node) = stack.pop() {
+            let n = &self.nodes[node];
+            // The root may hold shapes outside its bounds, so it is always visited.
+            if node != 0 && !n.bounds.intersects(area) {
+                continue;
+            }
+            for &item in &n.items {
+                if self.items[item].bounding_box().intersects(area) {
+                    found.push(&self.items[item]);
+                }
+            }
+            if let Some(children) = n.children {
+                stack.extend_from_slice(&children);
+            }
+        }
+        found
+    }
+
+    fn nearest(&self, p: Point, k: usize) -> Vec<&T> {
+        let mut found = Vec::new();
+        let mut heap = BinaryHeap::new();
+        heap.push(Candidate {
+            distance: 0.0,
+            entry: Entry::Node(0),
+        });
+
+        while let Some(Candidate { entry, .. }) = heap.pop() {
+            if found.len() == k {
+                break;
+            }
+            match entry {
+                Entry::Item(item) => found.push(&self.items[item]),
+                Entry::Node(node) => {
+                    let n = &self.nodes[node];
+                    for &item in &n.items {
+                        let distance = collision::distance(&self.items[item], p);
+                        heap.push(Candidate {
+                            distance,
+                            entry: Entry::Item(item),
+                        });
+                    }
+                    for &child in n.children.iter().flat_map(|c| c.iter()) {
+                        let distance = self.nodes[child].bounds.distance(p);
+                        heap.push(Candidate {
+                            distance,
+                            entry: Entry::Node(child),
+                        });
+                    }
+                }
+            }
+        }
+        found
+    }
+}
@@ -0,0 +1,321 @@
+use std::collections::BinaryHeap;
+
+use super::{Candidate, Entry, SpatialIndex};
+use shapes::collision;
+use shapes::{BoundingBox, Point, Shape};
+
+const MAX_ENTRIES: usize = 8;
+const MIN_ENTRIES: usize = 3;
+
+#[derive(Debug)]
+struct Node {
+    bounds: BoundingBox,
+    leaf: bool,
+    // Indexes into `RTree::items` for leaves, into `RTree::nodes` otherwise
+    entries: Vec<usize>,
+}
+
+// R-tree with Guttman's quadratic split. `bulk_load` builds a packed
+// tree with the Sort-Tile-Recursive algorithm, which is both faster than
+// inserting one by one and gives better query performance.
+#[derive(Debug)]
+pub struct RTree<T> {
+    items: Vec<T>,
+    boxes: Vec<BoundingBox>,
+    nodes: Vec<Node>,
+    root: usize,
+}
+
+impl<T: Shape> Default for RTree<T> {
+    fn default() -> RTree<T> {
+        RTree::new()
+    }
+}
+
+impl<T: Shape> RTree<T> {
+    pub fn new() -> RTree<T> {
+        let origin = Point::new(0.0, 0.0);
+        RTree {
+            items: Vec::new(),
+            boxes: Vec::new(),
+            nodes: vec![Node {
+                bounds: BoundingBox::new(origin, origin),
+                leaf: true,
+                entries: Vec::new(),
+            }],
+            root: 0,
+        }
+    }
+
+    pub fn bulk_load(shapes: Vec<T>) -> RTree<T> {
+        let mut tree = RTree::new();
+        if shapes.is_empty() {
+            return tree;
+        }
+        tree.nodes.clear();
+        tree.boxes = shapes.iter().map(|s| s.bounding_box()).collect();
+        tree.items = shapes;
+
+        let mut level = tree.pack((0..tree.items.len()).collect(), true);
+        while level.len() > 1 {
+            level = tree.pack(level, false);
+        }
+        tree.root = level[0];
+        tree
+    }
+
+    pub fn iter(&self) -> ::std::slice::Iter<'_, T> {
+        self.items.iter()
+    }
+
+    // Groups `entries` into nodes of up to `MAX_ENTRIES`: sorted into
+    // vertical slices by x, then each slice into runs by y.
+    fn pack(&mut self, mut entries: Vec<usize>, leaf: bool) -> Vec<usize> {
+        let node_count = entries.len().div_ceil(MAX_ENTRIES);
+        let slice_count = (node_count as f64).sqrt().ceil() as usize;
+        let slice_len = slice_count * MAX_ENTRIES;
+
+        let centers: Vec<Point> = entries
+            .iter()
+            .map(|&e| self.entry_box(leaf, e).center())
+            .collect();
+        let mut order: Vec<usize> = (0..entries.len()).collect();
+        order.sort_by(|&a, &b| centers[a].x.total_cmp(&centers[b].x));
+
+        let mut packed = Vec::new();
+        for slice in order.chunks_mut(slice_len) {
+            slice.sort_by(|&a, &b| centers[a].y.total_cmp(&centers[b].y));
+            for run in slice.chunks(MAX_ENTRIES) {
+                let group: Vec<usize> = run.iter().map(|&i| entries[i]).collect();
+                packed.push(self.add_node(leaf, group));
+            }
+        }
+        entries.clear();
+        packed
+    }
+
+    fn entry_box(&self, leaf: bool, entry: usize) -> BoundingBox {
+        if leaf {
+            self.boxes[entry]
+        } else {
+            self.nodes[entry].bounds
+        }
+    }
+
+    fn bounds_of(&self, leaf: bool, entries: &[usize]) -> BoundingBox {
+        entries
+            .iter()
+            .map(|&e| self.entry_box(leaf, e))
+            .fold(None, |acc: Option<BoundingBox>, b| {
+                Some(acc.map_or(b, |a| a.union(&b)))
+            })
+            .expect("node without entries")
+    }
+
+    fn add_node(&mut self, leaf: bool, entries: Vec<usize>) -> usize {
+        let bounds = self.bounds_of(leaf, &entries);
+        self.nodes.push(Node {
+            bounds,
+            leaf,
+            entries,
+        });
+        self.nodes.len() - 1
+    }
+
+    // The child of `node` needing the least enlargement to take `b`.
+    fn choose_subtree(&self, node: usize, b: &BoundingBox) -> usize {
+        let cost = |child: usize| {
+            let bounds = self.nodes[child].bounds;
+            (bounds.union(b).area() - bounds.area(), bounds.area())
+        };
+        *self.nodes[node]
+            .entries
+            .iter()
+            .min_by(|&&x, &&y| {
+                let (ex, ax) = cost(x);
+                let (ey, ay) = cost(y);
+                ex.total_cmp(&ey).then(ax.total_cmp(&ay))
+            })
+            .expect("inner node without children")
+    }
+
+    // Quadratic split of an overflowing node. The node keeps one group
+    // and the other goes into a new sibling, whose index is returned.
+    fn split(&mut self, node: usize) -> usize {
+        let leaf = self.nodes[node].leaf;
+        let entries = ::std::mem::take(&mut self.nodes[node].entries);
+        let mut rest: Vec<(usize, BoundingBox)> = entries
+            .into_iter()
+            .map(|e| (e, self.entry_box(leaf, e)))
+            .collect();
+
+        // Seeds: the pair wasting the most area when put together.
+        let mut seeds = (0, 1);
+        let mut worst = f64::NEG_INFINITY;
+        for i in 0..rest.len() {
+            for j in i + 1..rest.len() {
+                let (a, b) = (rest[i].1, rest[j].1);
+                let waste = a.union(&b).area() - a.area() - b.area();
+                if waste > worst {
+                    worst = waste;
+                    seeds = (i, j);
+                }
+            }
+        }
+        let (second_seed, second_box) = rest.swap_remove(seeds.1);
+        let (first_seed, first_box) = rest.swap_remove(seeds.0);
+        let mut groups = [
+            (vec![first_seed], first_box),
+            (vec![second_seed], second_box),
+        ];
+
+        let growth = |group: &(Vec<usize>, BoundingBox), b: &BoundingBox| {
+            group.1.union(b).area() - group.1.area()
+        };
+        while !rest.is_empty() {
+            // A group that needs every remaining entry to reach the minimum gets them.
+            if let Some(g) = groups
+                .iter()
+                .position(|g| g.0.len() + rest.len() == MIN_ENTRIES)
+            {
+                for (e, b) in rest.drain(..) {
+                    groups[g].0.push(e);
+                    groups[g].1 = groups[g].1.union(&b);
+                }
+                break;
+            }
+            // The entry with the strongest preference for one group goes next.
+            let preference =
+                |b: &BoundingBox| (growth(&groups[0], b) - growth(&groups[1], b)).abs();
+            let pick = (0..rest.len())
+                .max_by(|&i, &j| preference(&rest[i].1).total_cmp(&preference(&rest[j].1)))
+                .unwrap();
+            let (e, b) = rest.swap_remove(pick);
+            // Least growth wins, then the smaller box, then the smaller group.
+            let key = |g: &(Vec<usize>, BoundingBox)| (growth(g, &b), g.1.area(), g.0.len());
+            let (k0, k1) = (key(&groups[0]), key(&groups[1]));
+            let first_wins =
+                k0.0 < k1.0 || (k0.0 == k1.0 && (k0.1 < k1.1 || (k0.1 == k1.1 && k0.2 <= k1.2)));
+            let g = if first_wins { 0 } else { 1 };
+            groups[g].0.push(e);
+            groups[g].1 = groups[g].1.union(&b);
+        }
+
+        let [(first, first_box), (second, _)] = groups;
+        self.nodes[node].entries = first;
+        self.nodes[node].bounds = first_box;
+        self.add_node(leaf, second)
+    }
+}
+
+impl<T: Shape> SpatialIndex<T> for RTree<T> {
+    fn insert(&mut self, shape: T) {
+        let b = shape.bounding_box();
+        let item = self.items.len();
+        self.items.push(shape);
+        self.boxes.push(b);
+
+        let mut path = Vec::new();
+        let mut node = self.root;
+        while !self.nodes[node].leaf {
+            path.push(node);
+            node = self.choose_subtree(node, &b);
+        }
+
+        let n = &mut self.nodes[node];
+        n.bounds = if n.entries.is_empty() {
+            b
+        } else {
+            n.bounds.union(&b)
+        };
+        n.entries.push(item);
+        let mut sibling = if n.entries.len() > MAX_ENTRIES {
+            Some(self.split(node))
+        } else {
+            None
+        };
+
+        while let Some(parent) = path.pop() {
+            let n = &mut self.nodes[parent];
+            n.bounds = n.bounds.union(&b);
+            sibling = match sibling {
+                Some(s) => {
+                    n.entries.push(s);
+                    if n.entries.len() > MAX_ENTRIES {
+                        Some(self.split(parent))
+                    } else {
+                        None
+                    }
+                }
+                None => None,
+            };
+        }
+
+        if let Some(s) = sibling {
+            let old = self.root;
+            self.root = self.add_node(false, vec![old, s]);
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    fn query_range(&self, area: &BoundingBox) -> Vec<&T> {
+        let mut found = Vec::new();
+        if self.items.is_empty() {
+            return found;
+        }
+        let mut stack = vec![self.root];
+        while let Some(node) = stack.pop() {
+            let n = &self.nodes[node];
+            if !n.bounds.intersects(area) {
+                continue;
+            }
+            if n.leaf {
+                found.extend(
+                    n.entries
+                        .iter()
+                        .filter(|&&e| self.boxes[e].intersects(area))
+                        .map(|&e| &self.items[e]),
+                );
+            } else {
+                stack.extend_from_slice(&n.entries);
+            }
+        }
+        found
+    }
+
+    fn nearest(&self, p: Point, k: usize) -> Vec<&T> {
+        let mut found = Vec::new();
+        if self.items.is_empty() {
+            return found;
+        }
+        let mut heap = BinaryHeap::new();
+        heap.push(Candidate {
+            distance: self.nodes[self.root].bounds.distance(p),
+            entry: Entry::Node(self.root),
+        });
+
+        while let Some(Candidate { entry, .. }) = heap.pop() {
+            if found.len() == k {
+                break;
+            }
+            match entry {
+                Entry::Item(item) => found.push(&self.items[item]),
+                Entry::Node(node) => {
+                    let n = &self.nodes[node];
+                    for &e in &n.entries {
+                        let (distance, entry) = if n.leaf {
+                            (collision::distance(&self.items[e], p), Entry::Item(e))
+                        } else {
+                            (self.nodes[e].bounds.distance(p), Entry::Node(e))
+                        };
+                        heap.push(Candidate { distance, entry });
+                    }
+                }
+            }
+        }
+        found
+    }
+}
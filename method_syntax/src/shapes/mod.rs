@@ -0,0 +1,203 @@
+// Every shape implements `Shape`, so mixed collections can be kept as
+// `Vec<Box<dyn Shape>>` and measured or sorted together.
+
+use std::fmt;
+
+pub mod collision;
+pub mod index;
+pub mod scene;
+pub mod svg;
+
+mod builder;
+mod circle;
+mod ellipse;
+mod polygon;
+mod rectangle;
+mod triangle;
+
+pub use self::builder::{
+    BuildError, CircleBuilder, EllipseBuilder, FieldError, HasRadius, NoRadius, PolygonBuilder,
+    RectangleBuilder, TriangleBuilder, TypedCircleBuilder,
+};
+pub use self::circle::Circle;
+pub use self::ellipse::Ellipse;
+pub use self::polygon::Polygon;
+pub use self::rectangle::Rectangle;
+pub use self::triangle::Triangle;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Point {
+    pub x: f64,
+    pub y: f64,
+}
+
+impl Point {
+    pub fn new(x: f64, y: f64) -> Point {
+        Point { x, y }
+    }
+
+    pub fn distance(&self, other: Point) -> f64 {
+        (self.x - other.x).hypot(self.y - other.y)
+    }
+}
+
+// Axis-aligned box given by its lower-left and upper-right corners.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoundingBox {
+    pub min: Point,
+    pub max: Point,
+}
+
+impl BoundingBox {
+    pub fn new(min: Point, max: Point) -> BoundingBox {
+        BoundingBox { min, max }
+    }
+
+    // Smallest box around all `points`, `None` when there are none.
+    pub fn around(points: &[Point]) -> Option<BoundingBox> {
+        let first = *points.first()?;
+        Some(points.iter().fold(BoundingBox::new(first, first), |b, p| {
+            b.union(&BoundingBox::new(*p, *p))
+        }))
+    }
+
+    pub fn width(&self) -> f64 {
+        self.max.x - self.min.x
+    }
+
+    pub fn height(&self) -> f64 {
+        self.max.y - self.min.y
+    }
+
+    pub fn area(&self) -> f64 {
+        self.width() * self.height()
+    }
+
+    pub fn center(&self) -> Point {
+        Point::new(
+            (self.min.x + self.max.x) / 2.0,
+            (self.min.y + self.max.y) / 2.0,
+        )
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        p.x >= self.min.x && p.x <= self.max.x && p.y >= self.min.y && p.y <= self.max.y
+    }
+
+    pub fn intersects(&self, other: &BoundingBox) -> bool {
+        self.min.x <= other.max.x
+            && other.min.x <= self.max.x
+            && self.min.y <= other.max.y
+            && other.min.y <= self.max.y
+    }
+
+    pub fn union(&self, other: &BoundingBox) -> BoundingBox {
+        BoundingBox {
+            min: Point::new(self.min.x.min(other.min.x), self.min.y.min(other.min.y)),
+            max: Point::new(self.max.x.max(other.max.x), self.max.y.max(other.max.y)),
+        }
+    }
+
+    // Whether `other` lies completely inside this box.
+    pub fn encloses(&self, other: &BoundingBox) -> bool {
+        self.contains(other.min) && self.contains(other.max)
+    }
+
+    // Distance from `p` to the nearest point of the box, 0.0 inside it.
+    pub fn distance(&self, p: Point) -> f64 {
+        let dx = (self.min.x - p.x).max(0.0).max(p.x - self.max.x);
+        let dy = (self.min.y - p.y).max(0.0).max(p.y - self.max.y);
+        dx.hypot(dy)
+    }
+}
+
+pub trait Shape: fmt::Debug {
+    fn area(&self) -> f64;
+
+    fn perimeter(&self) -> f64;
+
+    // Center of mass, assuming uniform density.
+    fn centroid(&self) -> Point;
+
+    fn bounding_box(&self) -> BoundingBox;
+
+    // Points on the boundary count as inside.
+    fn contains(&self, p: Point) -> bool;
+
+    // The concrete shape, for code such as collision tests that handles
+    // each kind of shape differently. Shapes defined elsewhere keep the
+    // default and are handled through `boundary`.
+    fn kind(&self) -> ShapeRef<'_> {
+        ShapeRef::Other
+    }
+
+    // The outline as a polygon, used for shapes of kind `Other`. Defaults
+    // to the corners of the bounding box, so a shape that is not a box
+    // should give its own.
+    fn boundary(&self) -> Vec<Point> {
+        let b = self.bounding_box();
+        vec![
+            b.min,
+            Point::new(b.max.x, b.min.y),
+            b.max,
+            Point::new(b.min.x, b.max.y),
+        ]
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum ShapeRef<'a> {
+    Circle(&'a Circle),
+    Ellipse(&'a Ellipse),
+    Polygon(&'a Polygon),
+    Rectangle(&'a Rectangle),
+    Triangle(&'a Triangle),
+    Other,
+}
+
+impl<S: Shape + ?Sized> Shape for Box<S> {
+    fn area(&self) -> f64 {
+        (**self).area()
+    }
+
+    fn perimeter(&self) -> f64 {
+        (**self).perimeter()
+    }
+
+    fn centroid(&self) -> Point {
+        (**self).centroid()
+    }
+
+    fn bounding_box(&self) -> BoundingBox {
+        (**self).bounding_box()
+    }
+
+    fn contains(&self, p: Point) -> bool {
+        (**self).contains(p)
+    }
+
+    fn kind(&self) -> ShapeRef<'_> {
+        (**self).kind()
+    }
+
+    fn boundary(&self) -> Vec<Point> {
+        (**self).boundary()
+    }
+}
+
+pub fn total_area(shapes: &[Box<dyn Shape>]) -> f64 {
+    shapes.iter().map(|s| s.area()).sum()
+}
+
+pub fn total_perimeter(shapes: &[Box<dyn Shape>]) -> f64 {
+    shapes.iter().map(|s| s.perimeter()).sum()
+}
+
+// Smallest first.
+pub fn sort_by_area(shapes: &mut [Box<dyn Shape>]) {
+    shapes.sort_by(|a, b| a.area().total_cmp(&b.area()));
+}
+
+pub fn sort_by_perimeter(shapes: &mut [Box<dyn Shape>]) {
+    shapes.sort_by(|a, b| a.perimeter().total_cmp(&b.perimeter()));
+}
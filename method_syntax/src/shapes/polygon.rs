@@ -0,0 +1,94 @@
+use super::{BoundingBox, Point, Shape, ShapeRef};
+
+// Simple (non self-intersecting) polygon. The last vertex connects back
+// to the first one.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polygon {
+    vertices: Vec<Point>,
+}
+
+impl Polygon {
+    pub fn new(vertices: Vec<Point>) -> Polygon {
+        Polygon { vertices }
+    }
+
+    pub fn vertices(&self) -> &[Point] {
+        &self.vertices
+    }
+
+    // Pairs of consecutive vertices, including the closing edge.
+    pub fn edges(&self) -> Vec<(Point, Point)> {
+        let n = self.vertices.len();
+        (0..n)
+            .map(|i| (self.vertices[i], self.vertices[(i + 1) % n]))
+            .collect()
+    }
+
+    // Shoelace formula; positive for counter-clockwise vertices.
+    fn signed_area(&self) -> f64 {
+        self.edges()
+            .iter()
+            .map(|&(p, q)| p.x * q.y - q.x * p.y)
+            .sum::<f64>()
+            / 2.0
+    }
+}
+
+impl Shape for Polygon {
+    fn area(&self) -> f64 {
+        self.signed_area().abs()
+    }
+
+    fn perimeter(&self) -> f64 {
+        if self.vertices.len() < 2 {
+            return 0.0;
+        }
+        self.edges().iter().map(|&(p, q)| p.distance(q)).sum()
+    }
+
+    fn centroid(&self) -> Point {
+        let area = self.signed_area();
+        if area == 0.0 {
+            // Degenerate polygon: fall back to the mean of the vertices.
+            let n = self.vertices.len().max(1) as f64;
+            let (x, y) = self
+                .vertices
+                .iter()
+                .fold((0.0, 0.0), |(x, y), p| (x + p.x, y + p.y));
+            return Point::new(x / n, y / n);
+        }
+        let (x, y) = self.edges().iter().fold((0.0, 0.0), |(x, y), &(p, q)| {
+            let cross = p.x * q.y - q.x * p.y;
+            (x + (p.x + q.x) * cross, y + (p.y + q.y) * cross)
+        });
+        Point::new(x / (6.0 * area), y / (6.0 * area))
+    }
+
+    fn bounding_box(&self) -> BoundingBox {
+        BoundingBox::around(&self.vertices)
+            .unwrap_or_else(|| BoundingBox::new(Point::new(0.0, 0.0), Point::new(0.0, 0.0)))
+    }
+
+    // Even-odd ray casting, with points on an edge counted as inside.
+    fn contains(&self, p: Point) -> bool {
+        let mut inside = false;
+        for (a, b) in self.edges() {
+            let cross = (b.x - a.x) * (p.y - a.y) - (b.y - a.y) * (p.x - a.x);
+            let within = p.x >= a.x.min(b.x)
+                && p.x <= a.x.max(b.x)
+                && p.y >= a.y.min(b.y)
+                && p.y <= a.y.max(b.y);
+            if cross == 0.0 && within {
+                return true;
+            }
+            if (a.y > p.y) != (b.y > p.y) && p.x < a.x + (p.y - a.y) * (b.x - a.x) / (b.y - a.y) {
+                inside = !inside;
+            }
+        }
+        inside
+    }
+
+    fn kind(&self) -> ShapeRef<'_> {
+        ShapeRef::Polygon(self)
+    }
+}
@@ -0,0 +1,63 @@
+use super::{BoundingBox, Point, Shape, ShapeRef};
+
+// Axis-aligned rectangle; `x` and `y` are its lower-left corner.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rectangle {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+impl Rectangle {
+    pub fn new(x: f64, y: f64, width: f64, height: f64) -> Rectangle {
+        Rectangle {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    // Corners counter-clockwise from the lower-left one.
+    pub fn corners(&self) -> [Point; 4] {
+        [
+            Point::new(self.x, self.y),
+            Point::new(self.x + self.width, self.y),
+            Point::new(self.x + self.width, self.y + self.height),
+            Point::new(self.x, self.y + self.height),
+        ]
+    }
+}
+
+impl Shape for Rectangle {
+    fn area(&self) -> f64 {
+        self.width * self.height
+    }
+
+    fn perimeter(&self) -> f64 {
+        2.0 * (self.width + self.height)
+    }
+
+    fn centroid(&self) -> Point {
+        Point::new(self.x + self.width / 2.0, self.y + self.height / 2.0)
+    }
+
+    // A negative width or height extends the rectangle left of `x` or
+    // below `y`.
+    fn bounding_box(&self) -> BoundingBox {
+        let (x2, y2) = (self.x + self.width, self.y + self.height);
+        BoundingBox::new(
+            Point::new(self.x.min(x2), self.y.min(y2)),
+            Point::new(self.x.max(x2), self.y.max(y2)),
+        )
+    }
+
+    fn contains(&self, p: Point) -> bool {
+        self.bounding_box().contains(p)
+    }
+
+    fn kind(&self) -> ShapeRef<'_> {
+        ShapeRef::Rectangle(self)
+    }
+}
@@ -0,0 +1,263 @@
+// A line-based text format for keeping scenes in files:
+//
+//     # comments start with a hash
+//     circle x=1 y=2 r=3
+//     rect x=0 y=0 w=4 h=3
+//     ellipse x=1 y=1 rx=3 ry=1
+//     triangle a=0,0 b=4,0 c=0,3
+//     polygon points=0,0;2,0;2,2;0,2
+//
+// Every line is one shape built through its builder, so the same
+// defaults and validation apply as when building shapes in code.
+
+use std::error::Error;
+use std::fmt;
+
+use super::{
+    BuildError, CircleBuilder, EllipseBuilder, PolygonBuilder, RectangleBuilder, Shape,
+    TriangleBuilder,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SceneErrorKind {
+    UnknownShape(String),
+    UnknownField(String),
+    DuplicateField(String),
+    // A token that is not `field=value`
+    Malformed(String),
+    InvalidValue { field: String, value: String },
+    Invalid(BuildError),
+}
+
+// `line` and `column` are 1-based and point at the token at fault, or at
+// the shape name when the shape as a whole is invalid. Columns count
+// bytes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SceneError {
+    pub line: usize,
+    pub column: usize,
+    pub kind: SceneErrorKind,
+}
+
+impl fmt::Display for SceneError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}, column {}: ", self.line, self.column)?;
+        match self.kind {
+            SceneErrorKind::UnknownShape(ref s) => write!(f, "unknown shape `{}`", s),
+            SceneErrorKind::UnknownField(ref s) => write!(f, "unknown field `{}`", s),
+            SceneErrorKind::DuplicateField(ref s) => write!(f, "field `{}` given twice", s),
+            SceneErrorKind::Malformed(ref s) => write!(f, "expected `field=value`, got `{}`", s),
+            SceneErrorKind::InvalidValue {
+                ref field,
+                ref value,
+            } => {
+                write!(f, "invalid value `{}` for `{}`", value, field)
+            }
+            SceneErrorKind::Invalid(ref e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl Error for SceneError {}
+
+// One line of the scene, for placing errors.
+#[derive(Clone, Copy)]
+struct Line<'a> {
+    number: usize,
+    text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    // Error at `part`, which must be a slice of the line.
+    fn error(&self, part: &str, kind: SceneErrorKind) -> SceneError {
+        SceneError {
+            line: self.number,
+            column: part.as_ptr() as usize - self.text.as_ptr() as usize + 1,
+            kind,
+        }
+    }
+}
+
+// The `field=value` pairs of one line.
+struct Fields<'a> {
+    line: Line<'a>,
+    pairs: Vec<(&'a str, &'a str)>,
+}
+
+impl<'a> Fields<'a> {
+    fn parse(
+        line: Line<'a>,
+        tokens: &[&'a str],
+        allowed: &[&str],
+    ) -> Result<Fields<'a>, SceneError> {
+        let mut pairs: Vec<(&str, &str)> = Vec::new();
+        for &token in tokens {
+            let (field, value) = match token.find('=') {
+                Some(i) if i > 0 => (&token[..i], &token[i + 1..]),
+                _ => return Err(line.error(token, SceneErrorKind::Malformed(token.to_string()))),
+            };
+            if !allowed.contains(&field) {
+                return Err(line.error(field, SceneErrorKind::UnknownField(field.to_string())));
+            }
+            if pairs.iter().any(|p| p.0 == field) {
+                return Err(line.error(field, SceneErrorKind::DuplicateField(field.to_string())));
+            }
+            pairs.push((field, value));
+        }
+        Ok(Fields { line, pairs })
+    }
+
+    fn invalid(&self, field: &str, value: &str) -> SceneError {
+        self.line.error(
+            value,
+            SceneErrorKind::InvalidValue {
+                field: field.to_string(),
+                value: value.to_string(),
+            },
+        )
+    }
+
+    fn number(&self, field: &str) -> Result<Option<f64>, SceneError> {
+        match self.pairs.iter().find(|p| p.0 == field) {
+            Some(&(_, value)) => value
+                .parse()
+                .map(Some)
+                .map_err(|_| self.invalid(field, value)),
+            None => Ok(None),
+        }
+    }
+
+    fn points(&self, field: &str) -> Result<Option<Vec<(f64, f64)>>, SceneError> {
+        let value = match self.pairs.iter().find(|p| p.0 == field) {
+            Some(&(_, value)) => value,
+            None => return Ok(None),
+        };
+        value
+            .split(';')
+            .map(|point| {
+                let mut xy = point.splitn(2, ',').map(|n| n.parse::<f64>());
+                match (xy.next(), xy.next()) {
+                    (Some(Ok(x)), Some(Ok(y))) => Ok((x, y)),
+                    _ => Err(self.invalid(field, value)),
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(Some)
+    }
+
+    fn point(&self, field: &str) -> Result<Option<(f64, f64)>, SceneError> {
+        match self.points(field)? {
+            Some(ref points) if points.len() == 1 => Ok(Some(points[0])),
+            Some(_) => {
+                Err(self.invalid(field, self.pairs.iter().find(|p| p.0 == field).unwrap().1))
+            }
+            None => Ok(None),
+        }
+    }
+}
+
+// Boxes a built shape, or blames the shape `kind` names for what is
+// wrong with it.
+fn build<S: Shape + 'static>(
+    line: Line,
+    kind: &str,
+    result: Result<S, BuildError>,
+) -> Result<Box<dyn Shape>, SceneError> {
+    match result {
+        Ok(shape) => Ok(Box::new(shape)),
+        Err(e) => Err(line.error(kind, SceneErrorKind::Invalid(e))),
+    }
+}
+
+fn parse_line(line: Line, kind: &str, tokens: &[&str]) -> Result<Box<dyn Shape>, SceneError> {
+    match kind {
+        "circle" => {
+            let f = Fields::parse(line, tokens, &["x", "y", "r"])?;
+            let mut b = CircleBuilder::new();
+            if let Some(x) = f.number("x")? {
+                b.x(x);
+            }
+            if let Some(y) = f.number("y")? {
+                b.y(y);
+            }
+            if let Some(r) = f.number("r")? {
+                b.radius(r);
+            }
+            build(line, kind, b.finalize())
+        }
+        "rect" => {
+            let f = Fields::parse(line, tokens, &["x", "y", "w", "h"])?;
+            let mut b = RectangleBuilder::new();
+            if let Some(x) = f.number("x")? {
+                b.x(x);
+            }
+            if let Some(y) = f.number("y")? {
+                b.y(y);
+            }
+            if let Some(w) = f.number("w")? {
+                b.width(w);
+            }
+            if let Some(h) = f.number("h")? {
+                b.height(h);
+            }
+            build(line, kind, b.finalize())
+        }
+        "ellipse" => {
+            let f = Fields::parse(line, tokens, &["x", "y", "rx", "ry"])?;
+            let mut b = EllipseBuilder::new();
+            if let Some(x) = f.number("x")? {
+                b.x(x);
+            }
+            if let Some(y) = f.number("y")? {
+                b.y(y);
+            }
+            if let Some(rx) = f.number("rx")? {
+                b.rx(rx);
+            }
+            if let Some(ry) = f.number("ry")? {
+                b.ry(ry);
+            }
+            build(line, kind, b.finalize())
+        }
+        "triangle" => {
+            let f = Fields::parse(line, tokens, &["a", "b", "c"])?;
+            let mut b = TriangleBuilder::new();
+            if let Some((x, y)) = f.point("a")? {
+                b.a(x, y);
+            }
+            if let Some((x, y)) = f.point("b")? {
+                b.b(x, y);
+            }
+            if let Some((x, y)) = f.point("c")? {
+                b.c(x, y);
+            }
+            build(line, kind, b.finalize())
+        }
+        "polygon" => {
+            let f = Fields::parse(line, tokens, &["points"])?;
+            let mut b = PolygonBuilder::new();
+            for (x, y) in f.points("points")?.unwrap_or_default() {
+                b.vertex(x, y);
+            }
+            build(line, kind, b.finalize())
+        }
+        _ => Err(line.error(kind, SceneErrorKind::UnknownShape(kind.to_string()))),
+    }
+}
+
+// Parses a whole scene, stopping at the first bad line.
+pub fn parse(src: &str) -> Result<Vec<Box<dyn Shape>>, SceneError> {
+    let mut shapes = Vec::new();
+    for (i, text) in src.lines().enumerate() {
+        let line = Line {
+            number: i + 1,
+            text,
+        };
+        let content = text.split('#').next().unwrap_or("");
+        let tokens: Vec<&str> = content.split_whitespace().collect();
+        if let Some((kind, fields)) = tokens.split_first() {
+            shapes.push(parse_line(line, kind, fields)?);
+        }
+    }
+    Ok(shapes)
+}
@@ -0,0 +1,334 @@
+// Writes shapes out as an SVG document.
+//
+// Shapes are grouped into layers, each becoming a `<g>` element, and
+// every shape carries its own style and transforms. Coordinates are used
+// as they are, so y grows downwards as usual in SVG.
+
+use std::fmt;
+use std::io;
+
+use super::{BoundingBox, Point, Shape, ShapeRef};
+
+// Red, green and blue, 0-255 each, written as `#rrggbb`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Color(pub u8, pub u8, pub u8);
+
+impl Color {
+    pub const BLACK: Color = Color(0, 0, 0);
+    pub const WHITE: Color = Color(255, 255, 255);
+    pub const RED: Color = Color(255, 0, 0);
+    pub const GREEN: Color = Color(0, 128, 0);
+    pub const BLUE: Color = Color(0, 0, 255);
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+}
+
+// `None` for stroke or fill leaves it out ("none" in SVG terms).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    pub stroke: Option<Color>,
+    pub stroke_width: f64,
+    pub fill: Option<Color>,
+    pub opacity: f64,
+}
+
+impl Default for Style {
+    // Thin black outline without fill.
+    fn default() -> Style {
+        Style {
+            stroke: Some(Color::BLACK),
+            stroke_width: 1.0,
+            fill: None,
+            opacity: 1.0,
+        }
+    }
+}
+
+impl Style {
+    pub fn stroke(mut self, color: Color, width: f64) -> Style {
+        self.stroke = Some(color);
+        self.stroke_width = width;
+        self
+    }
+
+    pub fn fill(mut self, color: Color) -> Style {
+        self.fill = Some(color);
+        self
+    }
+
+    pub fn opacity(mut self, opacity: f64) -> Style {
+        self.opacity = opacity;
+        self
+    }
+}
+
+impl fmt::Display for Style {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.stroke {
+            Some(c) => write!(
+                f,
+                " stroke=\"{}\" stroke-width=\"{}\"",
+                c, self.stroke_width
+            )?,
+            None => write!(f, " stroke=\"none\"")?,
+        }
+        match self.fill {
+            Some(c) => write!(f, " fill=\"{}\"", c)?,
+            None => write!(f, " fill=\"none\"")?,
+        }
+        if self.opacity != 1.0 {
+            write!(f, " opacity=\"{}\"", self.opacity)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Transform {
+    Translate(f64, f64),
+    // Degrees clockwise around the given point.
+    Rotate(f64, Point),
+    Scale(f64, f64),
+}
+
+impl Transform {
+    pub fn apply(&self, p: Point) -> Point {
+        match *self {
+            Transform::Translate(dx, dy) => Point::new(p.x + dx, p.y + dy),
+            Transform::Scale(sx, sy) => Point::new(p.x * sx, p.y * sy),
+            Transform::Rotate(degrees, c) => {
+                let (sin, cos) = degrees.to_radians().sin_cos();
+                let (dx, dy) = (p.x - c.x, p.y - c.y);
+                Point::new(c.x + dx * cos - dy * sin, c.y + dx * sin + dy * cos)
+            }
+        }
+    }
+}
+
+impl fmt::Display for Transform {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Transform::Translate(dx, dy) => write!(f, "translate({} {})", dx, dy),
+            Transform::Rotate(degrees, c) => write!(f, "rotate({} {} {})", degrees, c.x, c.y),
+            Transform::Scale(sx, sy) => write!(f, "scale({} {})", sx, sy),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Element {
+    pub shape: Box<dyn Shape>,
+    pub style: Style,
+    // Written in order, so as in SVG the last one is applied first.
+    pub transforms: Vec<Transform>,
+}
+
+impl Element {
+    // Bounding box after the transforms, used to fit the viewport.
+    fn bounding_box(&self) -> BoundingBox {
+        let b = self.shape.bounding_box();
+        let corners: Vec<Point> = [
+            b.min,
+            Point::new(b.max.x, b.min.y),
+            b.max,
+            Point::new(b.min.x, b.max.y),
+        ]
+        .iter()
+        .map(|&p| self.transforms.iter().rev().fold(p, |p, t| t.apply(p)))
+        .collect();
+        BoundingBox::around(&corners).unwrap()
+    }
+}
+
+impl fmt::Display for Element {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.shape.kind() {
+            ShapeRef::Circle(c) => write!(
+                f,
+                "<circle cx=\"{}\" cy=\"{}\" r=\"{}\"",
+                c.x, c.y, c.radius
+            )?,
+            ShapeRef::Ellipse(e) => write!(
+                f,
+                "<ellipse cx=\"{}\" cy=\"{}\" rx=\"{}\" ry=\"{}\"",
+                e.x, e.y, e.rx, e.ry
+            )?,
+            ShapeRef::Rectangle(r) => write!(
+                f,
+                "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\"",
+                r.x, r.y, r.width, r.height
+            )?,
+            ShapeRef::Triangle(t) => write_polygon(f, &[t.a, t.b, t.c])?,
+            ShapeRef::Polygon(p) => write_polygon(f, p.vertices())?,
+            ShapeRef::Other => write_polygon(f, &self.shape.boundary())?,
+        }
+        write!(f, "{}", self.style)?;
+        if !self.transforms.is_empty() {
+            let transforms: Vec<String> = self.transforms.iter().map(|t| t.to_string()).collect();
+            write!(f, " transform=\"{}\"", transforms.join(" "))?;
+        }
+        write!(f, "/>")
+    }
+}
+
+fn write_polygon(f: &mut fmt::Formatter, vertices: &[Point]) -> fmt::Result {
+    let points: Vec<String> = vertices
+        .iter()
+        .map(|p| format!("{},{}", p.x, p.y))
+        .collect();
+    write!(f, "<polygon points=\"{}\"", points.join(" "))
+}
+
+#[derive(Debug)]
+pub struct Layer {
+    pub name: String,
+    pub visible: bool,
+    pub elements: Vec<Element>,
+}
+
+impl Layer {
+    pub fn new(name: &str) -> Layer {
+        Layer {
+            name: name.to_string(),
+            visible: true,
+            elements: Vec::new(),
+        }
+    }
+
+    pub fn add<S: Shape + 'static>(&mut self, shape: S, style: Style) -> &mut Element {
+        self.add_transformed(shape, style, Vec::new())
+    }
+
+    pub fn add_transformed<S: Shape + 'static>(
+        &mut self,
+        shape: S,
+        style: Style,
+        transforms: Vec<Transform>,
+    ) -> &mut Element {
+        self.elements.push(Element {
+            shape: Box::new(shape),
+            style,
+            transforms,
+        });
+        self.elements.last_mut().unwrap()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Viewport {
+    Fixed(BoundingBox),
+    // The bounding box of everything drawn, grown by the given margin.
+    FitToContent(f64),
+}
+
+#[derive(Debug)]
+pub struct Document {
+    pub width: f64,
+    pub height: f64,
+    pub viewport: Viewport,
+    pub background: Option<Color>,
+    pub layers: Vec<Layer>,
+}
+
+impl Document {
+    pub fn new(width: f64, height: f64) -> Document {
+        Document {
+            width,
+            height,
+            viewport: Viewport::FitToContent(0.0),
+            background: None,
+            layers: Vec::new(),
+        }
+    }
+
+    // Adds an empty layer on top of the existing ones.
+    pub fn layer(&mut self, name: &str) -> &mut Layer {
+        self.layers.push(Layer::new(name));
+        self.layers.last_mut().unwrap()
+    }
+
+    // The area the `viewBox` shows. An empty document fitted to its
+    // content shows the document size from the origin.
+    pub fn view_box(&self) -> BoundingBox {
+        match self.viewport {
+            Viewport::Fixed(b) => b,
+            Viewport::FitToContent(margin) => {
+                let content = self
+                    .layers
+                    .iter()
+                    .filter(|l| l.visible)
+                    .flat_map(|l| l.elements.iter())
+                    .map(|e| {
+                        let b = e.bounding_box();
+                        let half = e.style.stroke.map_or(0.0, |_| e.style.stroke_width / 2.0);
+                        BoundingBox::new(
+                            Point::new(b.min.x - half, b.min.y - half),
+                            Point::new(b.max.x + half, b.max.y + half),
+                        )
+                    })
+                    .fold(None, |acc: Option<BoundingBox>, b| {
+                        Some(acc.map_or(b, |a| a.union(&b)))
+                    });
+                match content {
+                    Some(b) => BoundingBox::new(
+                        Point::new(b.min.x - margin, b.min.y - margin),
+                        Point::new(b.max.x + margin, b.max.y + margin),
+                    ),
+                    None => {
+                        BoundingBox::new(Point::new(0.0, 0.0), Point::new(self.width, self.height))
+                    }
+                }
+            }
+        }
+    }
+
+    pub fn write<W: io::Write>(&self, out: &mut W) -> io::Result<()> {
+        write!(out, "{}", self)
+    }
+}
+
+impl fmt::Display for Document {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let view = self.view_box();
+        writeln!(
+            f,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" viewBox=\"{} {} {} {}\">",
+            self.width,
+            self.height,
+            view.min.x,
+            view.min.y,
+            view.width(),
+            view.height()
+        )?;
+        if let Some(c) = self.background {
+            writeln!(
+                f,
+                "  <rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"{}\"/>",
+                view.min.x,
+                view.min.y,
+                view.width(),
+                view.height(),
+                c
+            )?;
+        }
+        for layer in self.layers.iter().filter(|l| l.visible) {
+            writeln!(f, "  <g id=\"{}\">", escape(&layer.name))?;
+            for element in &layer.elements {
+                writeln!(f, "    {}", element)?;
+            }
+            writeln!(f, "  </g>")?;
+        }
+        writeln!(f, "</svg>")
+    }
+}
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -0,0 +1,65 @@
+use super::{BoundingBox, Point, Shape, ShapeRef};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Triangle {
+    pub a: Point,
+    pub b: Point,
+    pub c: Point,
+}
+
+impl Triangle {
+    pub fn new(a: Point, b: Point, c: Point) -> Triangle {
+        Triangle { a, b, c }
+    }
+
+    // Positive when a, b, c run counter-clockwise.
+    fn signed_area(&self) -> f64 {
+        ((self.b.x - self.a.x) * (self.c.y - self.a.y)
+            - (self.c.x - self.a.x) * (self.b.y - self.a.y))
+            / 2.0
+    }
+}
+
+// Which side of the line through `from` and `to` the point `p` lies on.
+fn side(from: Point, to: Point, p: Point) -> f64 {
+    (to.x - from.x) * (p.y - from.y) - (to.y - from.y) * (p.x - from.x)
+}
+
+impl Shape for Triangle {
+    fn area(&self) -> f64 {
+        self.signed_area().abs()
+    }
+
+    fn perimeter(&self) -> f64 {
+        self.a.distance(self.b) + self.b.distance(self.c) + self.c.distance(self.a)
+    }
+
+    fn centroid(&self) -> Point {
+        Point::new(
+            (self.a.x + self.b.x + self.c.x) / 3.0,
+            (self.a.y + self.b.y + self.c.y) / 3.0,
+        )
+    }
+
+    fn bounding_box(&self) -> BoundingBox {
+        BoundingBox::around(&[self.a, self.b, self.c]).unwrap()
+    }
+
+    fn contains(&self, p: Point) -> bool {
+        // When the corners are collinear every point on their line is on
+        // no side of any edge, so only the segment between them counts
+        if !self.bounding_box().contains(p) {
+            return false;
+        }
+        let d1 = side(self.a, self.b, p);
+        let d2 = side(self.b, self.c, p);
+        let d3 = side(self.c, self.a, p);
+        let has_negative = d1 < 0.0 || d2 < 0.0 || d3 < 0.0;
+        let has_positive = d1 > 0.0 || d2 > 0.0 || d3 > 0.0;
+        !(has_negative && has_positive)
+    }
+
+    fn kind(&self) -> ShapeRef<'_> {
+        ShapeRef::Triangle(self)
+    }
+}
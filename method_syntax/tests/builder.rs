@@ -0,0 +1,174 @@
+extern crate method_syntax;
+
+use method_syntax::shapes::{
+    BuildError, Circle, CircleBuilder, EllipseBuilder, FieldError, Polygon, PolygonBuilder,
+    Rectangle, RectangleBuilder, Shape, TriangleBuilder, TypedCircleBuilder,
+};
+
+fn fields(error: BuildError) -> Vec<(&'static str, &'static str)> {
+    error.errors.iter().map(|e| (e.field, e.reason)).collect()
+}
+
+#[test]
+fn valid_inputs_build_the_shape() {
+    let circle = CircleBuilder::new().x(1.0).y(2.0).radius(3.0).finalize();
+    assert_eq!(circle, Ok(Circle::new(1.0, 2.0, 3.0)));
+    assert_eq!(
+        CircleBuilder::new().finalize(),
+        Ok(Circle::new(0.0, 0.0, 1.0))
+    );
+    let rectangle = RectangleBuilder::new().width(4.0).height(2.0).finalize();
+    assert_eq!(rectangle, Ok(Rectangle::new(0.0, 0.0, 4.0, 2.0)));
+    let typed = TypedCircleBuilder::new().radius(2.0).y(5.0).finalize();
+    assert_eq!(typed, Ok(Circle::new(0.0, 5.0, 2.0)));
+}
+
+#[test]
+fn every_invalid_field_is_reported() {
+    let error = CircleBuilder::new()
+        .x(f64::NAN)
+        .y(f64::INFINITY)
+        .radius(-1.0)
+        .finalize()
+        .unwrap_err();
+    assert_eq!(error.shape, "circle");
+    assert_eq!(
+        fields(error),
+        [
+            ("x", "must be finite"),
+            ("y", "must be finite"),
+            ("radius", "must be positive"),
+        ]
+    );
+
+    let error = RectangleBuilder::new().x(f64::NAN).height(0.0).finalize();
+    assert_eq!(
+        fields(error.unwrap_err()),
+        [
+            ("x", "must be finite"),
+            ("width", "is missing"),
+            ("height", "must be positive"),
+        ]
+    );
+
+    let error = EllipseBuilder::new().rx(f64::NAN).finalize();
+    assert_eq!(
+        fields(error.unwrap_err()),
+        [("rx", "must be finite"), ("ry", "is missing")]
+    );
+
+    let error = TriangleBuilder::new().a(0.0, f64::NAN).finalize();
+    assert_eq!(
+        fields(error.unwrap_err()),
+        [
+            ("a", "must be finite"),
+            ("b", "is missing"),
+            ("c", "is missing"),
+        ]
+    );
+
+    let error = PolygonBuilder::new()
+        .vertex(0.0, 0.0)
+        .vertex(f64::INFINITY, 1.0)
+        .finalize();
+    assert_eq!(
+        fields(error.unwrap_err()),
+        [
+            ("vertices", "must be at least three"),
+            ("vertices", "must be finite"),
+        ]
+    );
+}
+
+#[test]
+fn collinear_triangles_are_rejected_once_their_points_are_valid() {
+    let error = TriangleBuilder::new()
+        .a(0.0, 0.0)
+        .b(1.0, 1.0)
+        .c(3.0, 3.0)
+        .finalize()
+        .unwrap_err();
+    assert_eq!(
+        error.errors,
+        [FieldError {
+            field: "vertices",
+            reason: "are collinear",
+        }]
+    );
+    assert_eq!(
+        error.to_string(),
+        "invalid triangle: vertices are collinear"
+    );
+}
+
+fn polygon(vertices: &[(f64, f64)]) -> Result<Polygon, BuildError> {
+    let mut builder = PolygonBuilder::new();
+    for &(x, y) in vertices {
+        builder.vertex(x, y);
+    }
+    builder.finalize()
+}
+
+#[test]
+fn degenerate_and_crossing_polygons_are_rejected() {
+    let invalid = |vertices: &[(f64, f64)]| fields(polygon(vertices).unwrap_err());
+    let collinear = [("vertices", "are collinear")];
+    let crossing = [("edges", "cross each other")];
+
+    assert_eq!(
+        invalid(&[(0.0, 0.0), (1.0, 1.0), (3.0, 3.0), (2.0, 2.0)]),
+        collinear
+    );
+    assert_eq!(invalid(&[(1.0, 1.0), (1.0, 1.0), (1.0, 1.0)]), collinear);
+    // A bow tie, whose halves cancel out to no area
+    assert_eq!(
+        invalid(&[(0.0, 0.0), (2.0, 2.0), (2.0, 0.0), (0.0, 2.0)]),
+        crossing
+    );
+    // A spike that doubles back along its own edge
+    assert_eq!(
+        invalid(&[(0.0, 0.0), (2.0, 0.0), (1.0, 0.0), (1.0, 1.0)]),
+        crossing
+    );
+    // Touching itself at one vertex
+    assert_eq!(
+        invalid(&[
+            (0.0, 0.0),
+            (2.0, 0.0),
+            (1.0, 1.0),
+            (2.0, 2.0),
+            (0.0, 2.0),
+            (1.0, 1.0)
+        ]),
+        crossing
+    );
+
+    let concave = polygon(&[
+        (0.0, 0.0),
+        (2.0, 0.0),
+        (2.0, 1.0),
+        (1.0, 1.0),
+        (1.0, 2.0),
+        (0.0, 2.0),
+    ]);
+    assert_eq!(concave.unwrap().area(), 3.0);
+    assert_eq!(
+        polygon(&[(0.0, 0.0), (1.0, 0.0), (0.0, 1.0)])
+            .unwrap()
+            .area(),
+        0.5
+    );
+}
+
+#[test]
+fn typed_builder_still_validates_values() {
+    let error = TypedCircleBuilder::new()
+        .x(f64::NAN)
+        .radius(0.0)
+        .finalize()
+        .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "invalid circle: x must be finite, radius must be positive"
+    );
+}
@@ -0,0 +1,225 @@
+extern crate method_syntax;
+
+use method_syntax::shapes::collision::{self, convex_polygons_intersect};
+use method_syntax::shapes::{
+    BoundingBox, Circle, Ellipse, Point, Polygon, Rectangle, Shape, ShapeRef, Triangle,
+};
+
+fn p(x: f64, y: f64) -> Point {
+    Point::new(x, y)
+}
+
+fn square(x: f64, y: f64, side: f64) -> Vec<Point> {
+    Rectangle::new(x, y, side, side).corners().to_vec()
+}
+
+// A square turned 45 degrees, defined outside the library.
+#[derive(Debug)]
+struct Diamond {
+    center: Point,
+    // Center to corner
+    reach: f64,
+}
+
+impl Shape for Diamond {
+    fn area(&self) -> f64 {
+        2.0 * self.reach * self.reach
+    }
+
+    fn perimeter(&self) -> f64 {
+        4.0 * self.reach * 2f64.sqrt()
+    }
+
+    fn centroid(&self) -> Point {
+        self.center
+    }
+
+    fn bounding_box(&self) -> BoundingBox {
+        let r = self.reach;
+        BoundingBox::new(
+            p(self.center.x - r, self.center.y - r),
+            p(self.center.x + r, self.center.y + r),
+        )
+    }
+
+    fn contains(&self, q: Point) -> bool {
+        (q.x - self.center.x).abs() + (q.y - self.center.y).abs() <= self.reach
+    }
+
+    fn boundary(&self) -> Vec<Point> {
+        let (c, r) = (self.center, self.reach);
+        vec![
+            p(c.x + r, c.y),
+            p(c.x, c.y + r),
+            p(c.x - r, c.y),
+            p(c.x, c.y - r),
+        ]
+    }
+}
+
+#[test]
+fn separating_axes_count_touching_as_intersecting() {
+    let a = square(0.0, 0.0, 1.0);
+    assert!(convex_polygons_intersect(&a, &square(0.5, 0.5, 1.0)));
+    // Shared edge, shared corner
+    assert!(convex_polygons_intersect(&a, &square(1.0, 0.0, 1.0)));
+    assert!(convex_polygons_intersect(&a, &square(1.0, 1.0, 1.0)));
+    // Just apart along x, then along y
+    assert!(!convex_polygons_intersect(
+        &a,
+        &square(1.0 + 1e-9, 0.0, 1.0)
+    ));
+    assert!(!convex_polygons_intersect(
+        &a,
+        &square(0.0, -1.0 - 1e-9, 1.0)
+    ));
+    // Nested either way round
+    assert!(convex_polygons_intersect(&a, &square(0.25, 0.25, 0.5)));
+    assert!(convex_polygons_intersect(&square(0.25, 0.25, 0.5), &a));
+}
+
+#[test]
+fn separating_axes_find_diagonal_gaps() {
+    let a = [p(0.0, 0.0), p(2.0, 0.0), p(0.0, 2.0)];
+    // Boxes overlap, only the hypotenuse's normal separates them
+    let apart = [p(2.0, 2.0), p(1.0 + 1e-9, 1.0 + 1e-9), p(2.0, 1.5)];
+    assert!(!convex_polygons_intersect(&a, &apart));
+    let touching = [p(2.0, 2.0), p(1.0, 1.0), p(2.0, 1.5)];
+    assert!(convex_polygons_intersect(&a, &touching));
+
+    let triangle = Triangle::new(a[0], a[1], a[2]);
+    let diamond = |x: f64| Diamond {
+        center: p(x, x),
+        reach: 1.0,
+    };
+    assert!(collision::intersects(&triangle, &diamond(1.5)));
+    assert!(!collision::intersects(&triangle, &diamond(1.5 + 1e-9)));
+}
+
+#[test]
+fn circles_touch_at_one_point() {
+    let a = Circle::new(0.0, 0.0, 1.0);
+    assert!(collision::intersects(&a, &Circle::new(2.0, 0.0, 1.0)));
+    assert!(!collision::intersects(
+        &a,
+        &Circle::new(2.0 + 1e-9, 0.0, 1.0)
+    ));
+    assert_eq!(
+        collision::circle_circle_points(&a, &Circle::new(2.0, 0.0, 1.0)),
+        [p(1.0, 0.0)]
+    );
+    assert_eq!(
+        collision::circle_circle_points(&a, &Circle::new(0.0, 0.0, 0.5)),
+        []
+    );
+}
+
+#[test]
+fn circles_meet_rectangles_at_corners() {
+    let rectangle = Rectangle::new(0.0, 0.0, 2.0, 2.0);
+    let reach = 2f64.sqrt();
+    assert!(collision::intersects(
+        &Circle::new(3.0, 3.0, reach),
+        &rectangle
+    ));
+    assert!(!collision::intersects(
+        &rectangle,
+        &Circle::new(3.0, 3.0, reach - 1e-9)
+    ));
+    assert!(collision::intersects(
+        &Circle::new(1.0, 1.0, 0.1),
+        &rectangle
+    ));
+}
+
+#[test]
+fn rectangles_with_negative_sizes_extend_the_other_way() {
+    // Same square as `Rectangle::new(0.0, 0.0, 2.0, 2.0)`
+    let flipped = Rectangle::new(2.0, 2.0, -2.0, -2.0);
+    assert!(collision::intersects(&Circle::new(1.0, 1.0, 0.1), &flipped));
+    assert!(collision::intersects(
+        &Circle::new(-1.0, 1.0, 1.0),
+        &flipped
+    ));
+    assert!(!collision::intersects(
+        &Circle::new(3.0, 3.0, 1.0),
+        &flipped
+    ));
+    assert!(collision::circle_rectangle_intersect(
+        &Circle::new(1.0, -0.5, 0.5),
+        &flipped
+    ));
+    assert!(flipped.contains(p(0.5, 1.5)));
+}
+
+#[test]
+fn concave_polygons_leave_their_notch_empty() {
+    let l = Polygon::new(vec![
+        p(0.0, 0.0),
+        p(2.0, 0.0),
+        p(2.0, 1.0),
+        p(1.0, 1.0),
+        p(1.0, 2.0),
+        p(0.0, 2.0),
+    ]);
+    let in_notch = Rectangle::new(1.0 + 1e-9, 1.0 + 1e-9, 0.5, 0.5);
+    assert!(!collision::intersects(&l, &in_notch));
+    let on_corner = Rectangle::new(1.0, 1.0, 0.5, 0.5);
+    assert!(collision::intersects(&l, &on_corner));
+    assert!(collision::intersects(&l, &Circle::new(1.5, 1.5, 0.75)));
+    assert!(!collision::intersects(&l, &Circle::new(1.5, 1.5, 0.4)));
+}
+
+#[test]
+fn outside_shapes_use_their_boundary() {
+    let diamond = Diamond {
+        center: p(0.0, 0.0),
+        reach: 1.0,
+    };
+    match diamond.kind() {
+        ShapeRef::Other => {}
+        kind => panic!("expected Other, got {:?}", kind),
+    }
+    // Inside the diamond's box but outside the diamond
+    let corner = Circle::new(0.9, 0.9, 0.1);
+    assert!(!collision::intersects(&diamond, &corner));
+    assert!(collision::intersects(&diamond, &Circle::new(0.9, 0.0, 0.1)));
+    assert!((collision::distance(&diamond, p(2.0, 0.0)) - 1.0).abs() < 1e-12);
+    let boxed: Box<dyn Shape> = Box::new(diamond);
+    assert_eq!(boxed.boundary().len(), 4);
+}
+
+#[test]
+fn boundaries_cross_where_expected() {
+    let points = collision::intersection_points(
+        &Rectangle::new(0.0, 0.0, 2.0, 2.0),
+        &Rectangle::new(1.0, 1.0, 2.0, 2.0),
+    );
+    assert!(points.contains(&p(2.0, 1.0)) && points.contains(&p(1.0, 2.0)));
+    let points = collision::intersection_points(
+        &Circle::new(0.0, 0.0, 1.0),
+        &Triangle::new(p(0.0, -2.0), p(0.0, 2.0), p(-2.0, 0.0)),
+    );
+    assert_eq!(points.len(), 2);
+    assert_eq!(
+        collision::segment_intersection(p(0.0, 0.0), p(1.0, 0.0), p(0.0, 1.0), p(1.0, 1.0)),
+        None
+    );
+}
+
+#[test]
+fn pairs_are_found_among_many_shapes() {
+    let shapes: Vec<Box<dyn Shape>> = vec![
+        Box::new(Circle::new(0.0, 0.0, 1.0)),
+        Box::new(Rectangle::new(5.0, 5.0, 1.0, 1.0)),
+        Box::new(Ellipse::new(1.9, 0.0, 1.0, 0.5)),
+        // Box overlaps the circle's, the shapes do not
+        Box::new(Rectangle::new(0.8, 0.8, 0.5, 0.5)),
+        Box::new(Rectangle::new(6.0, 6.0, 1.0, 1.0)),
+    ];
+    assert_eq!(
+        collision::sweep_and_prune(&shapes),
+        [(0, 2), (0, 3), (1, 4)]
+    );
+    assert_eq!(collision::colliding_pairs(&shapes), [(0, 2), (1, 4)]);
+}
@@ -0,0 +1,143 @@
+extern crate method_syntax;
+
+use method_syntax::shapes::collision;
+use method_syntax::shapes::index::{QuadTree, RTree, SpatialIndex};
+use method_syntax::shapes::{BoundingBox, Circle, Point, Rectangle, Shape};
+
+// Same numbers on every run, without pulling in a crate for them.
+struct Lcg(u64);
+
+impl Lcg {
+    // Uniform in [0, 1)
+    fn next(&mut self) -> f64 {
+        self.0 = self
+            .0
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (self.0 >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+// Circles and rectangles scattered over 0..100, some poking out of it.
+fn scatter(n: usize) -> Vec<Box<dyn Shape>> {
+    let mut rng = Lcg(7);
+    (0..n)
+        .map(|i| -> Box<dyn Shape> {
+            let (x, y) = (rng.next() * 110.0 - 5.0, rng.next() * 110.0 - 5.0);
+            let size = rng.next() * 6.0 + 0.1;
+            if i % 2 == 0 {
+                Box::new(Circle::new(x, y, size))
+            } else {
+                Box::new(Rectangle::new(x, y, size, rng.next() * 3.0 + 0.1))
+            }
+        })
+        .collect()
+}
+
+fn key(shape: &dyn Shape) -> (u64, u64) {
+    let c = shape.centroid();
+    (c.x.to_bits(), c.y.to_bits())
+}
+
+fn keys<'a, I: IntoIterator<Item = &'a Box<dyn Shape>>>(shapes: I) -> Vec<(u64, u64)> {
+    let mut keys: Vec<_> = shapes.into_iter().map(|s| key(&**s)).collect();
+    keys.sort();
+    keys
+}
+
+type Index = Box<dyn SpatialIndex<Box<dyn Shape>>>;
+
+// Each kind of index, filled with `scatter(400)`.
+fn indexes() -> Vec<(&'static str, Index)> {
+    let bounds = BoundingBox::new(Point::new(0.0, 0.0), Point::new(100.0, 100.0));
+    let mut quadtree = QuadTree::with_capacity(bounds, 4, 8);
+    let mut rtree = RTree::new();
+    for shape in scatter(400) {
+        quadtree.insert(shape);
+    }
+    for shape in scatter(400) {
+        rtree.insert(shape);
+    }
+    vec![
+        ("quadtree", Box::new(quadtree)),
+        ("rtree", Box::new(rtree)),
+        (
+            "bulk-loaded rtree",
+            Box::new(RTree::bulk_load(scatter(400))),
+        ),
+    ]
+}
+
+#[test]
+fn range_queries_match_brute_force() {
+    let all = scatter(400);
+    let mut rng = Lcg(11);
+    for (name, index) in indexes() {
+        assert_eq!(index.len(), 400, "{}", name);
+        for _ in 0..50 {
+            let (x, y) = (rng.next() * 120.0 - 10.0, rng.next() * 120.0 - 10.0);
+            let (w, h) = (rng.next() * 30.0, rng.next() * 30.0);
+            let area = BoundingBox::new(Point::new(x, y), Point::new(x + w, y + h));
+            let expected = keys(all.iter().filter(|s| s.bounding_box().intersects(&area)));
+            let found = index.query_range(&area);
+            assert_eq!(keys(found), expected, "{} in {:?}", name, area);
+        }
+    }
+}
+
+#[test]
+fn point_queries_match_brute_force() {
+    let all = scatter(400);
+    let mut rng = Lcg(13);
+    for (name, index) in indexes() {
+        for _ in 0..100 {
+            let p = Point::new(rng.next() * 100.0, rng.next() * 100.0);
+            let expected = keys(all.iter().filter(|s| s.contains(p)));
+            assert_eq!(keys(index.query_point(p)), expected, "{} at {:?}", name, p);
+        }
+    }
+}
+
+#[test]
+fn nearest_queries_match_brute_force() {
+    let all = scatter(400);
+    let mut rng = Lcg(17);
+    for (name, index) in indexes() {
+        for &k in &[1, 5, 20] {
+            for _ in 0..20 {
+                // Also from outside the quadtree's bounds
+                let p = Point::new(rng.next() * 140.0 - 20.0, rng.next() * 140.0 - 20.0);
+                let mut expected: Vec<f64> =
+                    all.iter().map(|s| collision::distance(&**s, p)).collect();
+                expected.sort_by(|a, b| a.total_cmp(b));
+                expected.truncate(k);
+                // Compared by distance, as equally near shapes may come in
+                // any order
+                let found: Vec<f64> = index
+                    .nearest(p, k)
+                    .into_iter()
+                    .map(|s| collision::distance(&**s, p))
+                    .collect();
+                assert_eq!(found, expected, "{} nearest {} to {:?}", name, k, p);
+            }
+        }
+    }
+}
+
+#[test]
+fn small_and_empty_indexes() {
+    let empty: RTree<Circle> = RTree::new();
+    assert!(empty.is_empty());
+    assert!(empty.nearest(Point::new(0.0, 0.0), 3).is_empty());
+    assert!(RTree::<Circle>::bulk_load(Vec::new()).is_empty());
+
+    let bounds = BoundingBox::new(Point::new(0.0, 0.0), Point::new(10.0, 10.0));
+    let mut quadtree = QuadTree::new(bounds);
+    quadtree.insert(Circle::new(1.0, 1.0, 1.0));
+    quadtree.insert(Circle::new(50.0, 50.0, 1.0));
+    let nearest = quadtree.nearest(Point::new(60.0, 60.0), 5);
+    assert_eq!(
+        nearest,
+        [&Circle::new(50.0, 50.0, 1.0), &Circle::new(1.0, 1.0, 1.0)]
+    );
+}
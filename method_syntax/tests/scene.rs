@@ -0,0 +1,114 @@
+extern crate method_syntax;
+
+use method_syntax::shapes::scene::{self, SceneError, SceneErrorKind};
+use method_syntax::shapes::{BuildError, FieldError, Point, Shape};
+
+fn error(src: &str) -> SceneError {
+    match scene::parse(src) {
+        Ok(shapes) => panic!("{:?} should not parse, got {:?}", src, shapes),
+        Err(e) => e,
+    }
+}
+
+#[test]
+fn every_shape_can_be_read() {
+    let src = "\
+# a scene of everything
+circle x=1 y=2 r=3
+
+rect x=0 y=0 w=4 h=3   # trailing comment
+  ellipse rx=3 ry=1 x=1
+triangle a=0,0 b=4,0 c=0,3
+polygon points=0,0;2,0;2,2;0,2
+circle
+";
+    let shapes = scene::parse(src).unwrap();
+    let areas: Vec<f64> = shapes.iter().map(|s| s.area()).collect();
+    assert_eq!(areas.len(), 6);
+    assert_eq!(&areas[1..2], [12.0]);
+    assert_eq!(&areas[3..5], [6.0, 4.0]);
+    assert_eq!(shapes[0].centroid(), Point::new(1.0, 2.0));
+    assert_eq!(shapes[2].centroid(), Point::new(1.0, 0.0));
+    // Defaults come from the builders
+    assert_eq!(shapes[5].centroid(), Point::new(0.0, 0.0));
+    assert_eq!(shapes[5].perimeter(), 2.0 * std::f64::consts::PI);
+    assert!(scene::parse("").unwrap().is_empty());
+    assert!(scene::parse("# nothing\n\n   \n").unwrap().is_empty());
+}
+
+#[test]
+fn unknown_shapes_and_fields_are_placed() {
+    let e = error("circle r=1\n  hexagon side=2\n");
+    assert_eq!((e.line, e.column), (2, 3));
+    assert_eq!(e.kind, SceneErrorKind::UnknownShape("hexagon".to_string()));
+
+    let e = error("rect w=1 h=2 depth=3");
+    assert_eq!((e.line, e.column), (1, 14));
+    assert_eq!(e.kind, SceneErrorKind::UnknownField("depth".to_string()));
+    assert_eq!(e.to_string(), "line 1, column 14: unknown field `depth`");
+}
+
+#[test]
+fn malformed_and_repeated_fields_are_placed() {
+    let e = error("\n\ncircle x=1  r");
+    assert_eq!((e.line, e.column), (3, 13));
+    assert_eq!(e.kind, SceneErrorKind::Malformed("r".to_string()));
+
+    let e = error("circle =1");
+    assert_eq!((e.line, e.column), (1, 8));
+
+    let e = error("ellipse rx=1 ry=1 rx=2");
+    assert_eq!((e.line, e.column), (1, 19));
+    assert_eq!(e.kind, SceneErrorKind::DuplicateField("rx".to_string()));
+}
+
+#[test]
+fn bad_values_point_at_the_value() {
+    let e = error("circle x=1 r=big");
+    assert_eq!((e.line, e.column), (1, 14));
+    assert_eq!(
+        e.kind,
+        SceneErrorKind::InvalidValue {
+            field: "r".to_string(),
+            value: "big".to_string(),
+        }
+    );
+    assert_eq!(
+        e.to_string(),
+        "line 1, column 14: invalid value `big` for `r`"
+    );
+
+    let e = error("triangle a=0,0 b=1,0;2,0 c=0,1");
+    assert_eq!((e.line, e.column), (1, 18));
+    let e = error("polygon points=0,0;1;1,1");
+    assert_eq!((e.line, e.column), (1, 16));
+}
+
+#[test]
+fn invalid_shapes_point_at_their_name() {
+    let e = error("circle r=1\n\t rect w=-1\n");
+    assert_eq!((e.line, e.column), (2, 3));
+    assert_eq!(
+        e.kind,
+        SceneErrorKind::Invalid(BuildError {
+            shape: "rectangle",
+            errors: vec![
+                FieldError {
+                    field: "width",
+                    reason: "must be positive",
+                },
+                FieldError {
+                    field: "height",
+                    reason: "is missing",
+                },
+            ],
+        })
+    );
+    assert_eq!(
+        e.to_string(),
+        "line 2, column 3: invalid rectangle: width must be positive, height is missing"
+    );
+
+    let e = error("triangle a=0,0 b=1,1 c=2,2 # flat");
+    assert_eq!((e.line, e.column), (1, 1));
+}
@@ -0,0 +1,125 @@
+extern crate method_syntax;
+
+use std::f64::consts::PI;
+
+use method_syntax::shapes::{
+    self, BoundingBox, Circle, Ellipse, Point, Polygon, Rectangle, Shape, Triangle,
+};
+
+fn close(a: f64, b: f64) -> bool {
+    (a - b).abs() < 1e-9
+}
+
+fn p(x: f64, y: f64) -> Point {
+    Point::new(x, y)
+}
+
+#[test]
+fn circles_and_ellipses_measure_alike() {
+    let circle = Circle::new(1.0, 2.0, 3.0);
+    assert!(close(circle.area(), 9.0 * PI));
+    assert!(close(circle.perimeter(), 6.0 * PI));
+    assert_eq!(circle.centroid(), p(1.0, 2.0));
+    assert_eq!(
+        circle.bounding_box(),
+        BoundingBox::new(p(-2.0, -1.0), p(4.0, 5.0))
+    );
+    assert!(circle.contains(p(4.0, 2.0)));
+    assert!(!circle.contains(p(3.2, 4.2)));
+
+    let round = Ellipse::new(1.0, 2.0, 3.0, 3.0);
+    assert!(close(round.area(), circle.area()));
+    assert!(close(round.perimeter(), circle.perimeter()));
+
+    let ellipse = Ellipse::new(0.0, 0.0, 4.0, 1.0);
+    assert!(close(ellipse.area(), 4.0 * PI));
+    // 17.1568... to five figures
+    assert!((ellipse.perimeter() - 17.1568).abs() < 1e-3);
+    assert!(ellipse.contains(p(4.0, 0.0)));
+    assert!(!ellipse.contains(p(3.0, 0.9)));
+}
+
+#[test]
+fn rectangles_include_their_edges() {
+    let rectangle = Rectangle::new(1.0, 1.0, 4.0, 2.0);
+    assert_eq!(rectangle.area(), 8.0);
+    assert_eq!(rectangle.perimeter(), 12.0);
+    assert_eq!(rectangle.centroid(), p(3.0, 2.0));
+    assert_eq!(rectangle.corners()[2], p(5.0, 3.0));
+    assert!(rectangle.contains(p(1.0, 3.0)));
+    assert!(!rectangle.contains(p(5.1, 2.0)));
+}
+
+#[test]
+fn triangles_work_in_either_winding() {
+    let clockwise = Triangle::new(p(0.0, 0.0), p(0.0, 3.0), p(4.0, 0.0));
+    let counter = Triangle::new(p(0.0, 0.0), p(4.0, 0.0), p(0.0, 3.0));
+    for triangle in &[clockwise, counter] {
+        assert_eq!(triangle.area(), 6.0);
+        assert_eq!(triangle.perimeter(), 12.0);
+        assert!(close(triangle.centroid().x, 4.0 / 3.0));
+        assert!(triangle.contains(p(1.0, 1.0)));
+        assert!(triangle.contains(p(2.0, 1.5)));
+        assert!(!triangle.contains(p(2.0, 1.6)));
+        assert!(!triangle.contains(p(-0.1, 0.0)));
+    }
+}
+
+#[test]
+fn degenerate_triangles_contain_only_their_segment() {
+    let flat = Triangle::new(p(0.0, 0.0), p(1.0, 1.0), p(2.0, 2.0));
+    assert_eq!(flat.area(), 0.0);
+    assert!(flat.contains(p(0.5, 0.5)));
+    assert!(flat.contains(p(2.0, 2.0)));
+    assert!(!flat.contains(p(3.0, 3.0)));
+    assert!(!flat.contains(p(-1.0, -1.0)));
+    assert!(!flat.contains(p(1.0, 0.0)));
+
+    let dot = Triangle::new(p(1.0, 1.0), p(1.0, 1.0), p(1.0, 1.0));
+    assert!(dot.contains(p(1.0, 1.0)));
+    assert!(!dot.contains(p(1.0, 2.0)));
+}
+
+#[test]
+fn polygons_handle_concave_outlines() {
+    // An L, open towards the upper right
+    let l = Polygon::new(vec![
+        p(0.0, 0.0),
+        p(2.0, 0.0),
+        p(2.0, 1.0),
+        p(1.0, 1.0),
+        p(1.0, 2.0),
+        p(0.0, 2.0),
+    ]);
+    assert_eq!(l.area(), 3.0);
+    assert_eq!(l.perimeter(), 8.0);
+    let centroid = l.centroid();
+    assert!(close(centroid.x, 5.0 / 6.0) && close(centroid.y, 5.0 / 6.0));
+    assert!(l.contains(p(0.5, 1.5)));
+    assert!(l.contains(p(1.5, 1.0)));
+    assert!(!l.contains(p(1.5, 1.5)));
+    assert_eq!(l.bounding_box(), BoundingBox::new(p(0.0, 0.0), p(2.0, 2.0)));
+
+    let empty = Polygon::new(vec![]);
+    assert_eq!(empty.area(), 0.0);
+    assert_eq!(empty.perimeter(), 0.0);
+}
+
+#[test]
+fn mixed_shapes_are_summed_and_sorted() {
+    let mut mixed: Vec<Box<dyn Shape>> = vec![
+        Box::new(Rectangle::new(0.0, 0.0, 3.0, 3.0)),
+        Box::new(Circle::new(0.0, 0.0, 1.0)),
+        Box::new(Triangle::new(p(0.0, 0.0), p(4.0, 0.0), p(0.0, 3.0))),
+    ];
+    assert!(close(shapes::total_area(&mixed), 15.0 + PI));
+    assert!(close(shapes::total_perimeter(&mixed), 24.0 + 2.0 * PI));
+
+    shapes::sort_by_area(&mut mixed);
+    let areas: Vec<f64> = mixed.iter().map(|s| s.area()).collect();
+    assert_eq!(areas, [PI, 6.0, 9.0]);
+
+    shapes::sort_by_perimeter(&mut mixed);
+    assert_eq!(mixed[2].perimeter(), 12.0);
+    assert!(close(mixed[0].perimeter(), 2.0 * PI));
+}
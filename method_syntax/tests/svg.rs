@@ -0,0 +1,95 @@
+extern crate method_syntax;
+
+use method_syntax::shapes::svg::{Color, Document, Style, Transform, Viewport};
+use method_syntax::shapes::{BoundingBox, Circle, Point, Rectangle, Triangle};
+
+#[test]
+fn documents_are_written_layer_by_layer() {
+    let mut document = Document::new(200.0, 100.0);
+    document.viewport = Viewport::Fixed(BoundingBox::new(
+        Point::new(0.0, 0.0),
+        Point::new(20.0, 10.0),
+    ));
+    document.background = Some(Color::WHITE);
+    {
+        let base = document.layer("base & <frame>");
+        base.add(Rectangle::new(1.0, 2.0, 3.0, 4.0), Style::default());
+        base.add(
+            Circle::new(5.0, 5.0, 2.5),
+            Style::default().fill(Color(200, 220, 255)).opacity(0.5),
+        );
+    }
+    {
+        let marks = document.layer("marks");
+        marks.add_transformed(
+            Triangle::new(
+                Point::new(0.0, 0.0),
+                Point::new(1.0, 0.0),
+                Point::new(0.0, 1.0),
+            ),
+            Style::default().stroke(Color::RED, 0.5).fill(Color::BLUE),
+            vec![
+                Transform::Translate(10.0, 0.0),
+                Transform::Rotate(90.0, Point::new(0.0, 0.0)),
+            ],
+        );
+    }
+    document.layer("hidden").visible = false;
+
+    let expected = "\
+<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"200\" height=\"100\" viewBox=\"0 0 20 10\">
+  <rect x=\"0\" y=\"0\" width=\"20\" height=\"10\" fill=\"#ffffff\"/>
+  <g id=\"base &amp; &lt;frame&gt;\">
+    <rect x=\"1\" y=\"2\" width=\"3\" height=\"4\" stroke=\"#000000\" stroke-width=\"1\" fill=\"none\"/>
+    <circle cx=\"5\" cy=\"5\" r=\"2.5\" stroke=\"#000000\" stroke-width=\"1\" fill=\"#c8dcff\" opacity=\"0.5\"/>
+  </g>
+  <g id=\"marks\">
+    <polygon points=\"0,0 1,0 0,1\" stroke=\"#ff0000\" stroke-width=\"0.5\" fill=\"#0000ff\" \
+transform=\"translate(10 0) rotate(90 0 0)\"/>
+  </g>
+</svg>
+";
+    assert_eq!(document.to_string(), expected);
+
+    let mut bytes = Vec::new();
+    document.write(&mut bytes).unwrap();
+    assert_eq!(String::from_utf8(bytes).unwrap(), expected);
+}
+
+#[test]
+fn fitted_viewports_cover_strokes_transforms_and_margin() {
+    let mut document = Document::new(100.0, 100.0);
+    assert_eq!(
+        document.view_box(),
+        BoundingBox::new(Point::new(0.0, 0.0), Point::new(100.0, 100.0))
+    );
+
+    document.viewport = Viewport::FitToContent(1.0);
+    let layer = document.layer("shapes");
+    layer.add(
+        Rectangle::new(0.0, 0.0, 4.0, 2.0),
+        Style::default().stroke(Color::BLACK, 2.0),
+    );
+    layer.add_transformed(
+        Rectangle::new(0.0, 0.0, 1.0, 1.0),
+        Style {
+            stroke: None,
+            ..Style::default()
+        },
+        vec![Transform::Scale(2.0, 3.0), Transform::Translate(5.0, 5.0)],
+    );
+    let view = document.view_box();
+    assert_eq!(view.min, Point::new(-2.0, -2.0));
+    assert_eq!(view.max, Point::new(13.0, 19.0));
+}
+
+#[test]
+fn colors_are_written_as_hex() {
+    assert_eq!(Color(1, 171, 255).to_string(), "#01abff");
+    assert_eq!(Color::GREEN.to_string(), "#008000");
+    let style = Style {
+        stroke: None,
+        ..Style::default()
+    };
+    assert_eq!(style.to_string(), " stroke=\"none\" fill=\"none\"");
+}
@@ -0,0 +1,229 @@
+#!/usr/bin/env python3
+"""Generates src/tables.rs from the Unicode Character Database.
+
+    python3 scripts/unicode_tables.py path/to/ucd > src/tables.rs
+
+`path/to/ucd` is an unpacked UCD.zip from
+https://www.unicode.org/Public/<version>/ucd/, of the same version as
+the database bundled with Python. The segmentation properties of UAX #29
+come from its auxiliary/*BreakProperty.txt files, Extended_Pictographic
+from emoji/emoji-data.txt. `unicodedata` has none of those, but provides
+the rest: normalization and case folding tables come straight from
+`normalize` and `str.casefold`, display widths from `east_asian_width`.
+"""
+
+import io
+import os
+import sys
+import unicodedata
+
+MAX = 0x110000
+
+
+def cat(cp):
+    return unicodedata.category(chr(cp))
+
+
+def load_property(ucd, path, prop=None):
+    """Values of a UCD property file as {code point: value}.
+
+    Files such as emoji-data.txt hold several binary properties, one per
+    line; `prop` picks the one to keep.
+    """
+    out = {}
+    with open(os.path.join(ucd, path), encoding="utf-8") as f:
+        for line in f:
+            line = line.split("#")[0].strip()
+            if not line:
+                continue
+            span, value = [field.strip() for field in line.split(";")[:2]]
+            if prop is not None and value != prop:
+                continue
+            first, _, last = span.partition("..")
+            for cp in range(int(first, 16), int(last or first, 16) + 1):
+                out[cp] = value.replace("_", "")
+    return out
+
+
+def is_hangul_syllable(cp):
+    return 0xAC00 <= cp <= 0xD7A3
+
+
+def is_surrogate(cp):
+    return 0xD800 <= cp <= 0xDFFF
+
+
+def scalar_values():
+    return (cp for cp in range(MAX) if not is_surrogate(cp))
+
+
+def canonical_decompositions():
+    """Full canonical decompositions, Hangul syllables left to the algorithm."""
+    out = []
+    for cp in scalar_values():
+        nfd = unicodedata.normalize("NFD", chr(cp))
+        if nfd != chr(cp) and not is_hangul_syllable(cp):
+            out.append((cp, nfd))
+    return out
+
+
+def compatibility_decompositions():
+    """Full compatibility decompositions that differ from the canonical ones."""
+    out = []
+    for cp in scalar_values():
+        c = chr(cp)
+        nfkd = unicodedata.normalize("NFKD", c)
+        if nfkd != unicodedata.normalize("NFD", c):
+            out.append((cp, nfkd))
+    return out
+
+
+def compositions():
+    """(first, second, composite) for every primary composite.
+
+    Composition exclusions, singletons and non-starter decompositions all
+    fail to round-trip through NFC, so they drop out here.
+    """
+    out = []
+    for cp in scalar_values():
+        c = chr(cp)
+        pair = unicodedata.decomposition(c)
+        if not pair or pair.startswith("<") or is_hangul_syllable(cp):
+            continue
+        parts = [int(h, 16) for h in pair.split()]
+        if len(parts) == 2 and unicodedata.normalize("NFC", c) == c:
+            out.append((parts[0], parts[1], cp))
+    return sorted(out)
+
+
+def case_foldings():
+    out = []
+    for cp in scalar_values():
+        folded = chr(cp).casefold()
+        if folded != chr(cp):
+            out.append((cp, folded))
+    return out
+
+
+def char_width(cp):
+    """Columns a terminal gives `cp`: 0, 2, or None for the usual 1."""
+    if cp == 0x00AD:
+        return None
+    if cat(cp) in ("Mn", "Me", "Cf", "Cc", "Zl", "Zp") or 0x1160 <= cp <= 0x11FF or cp == 0x200B:
+        return 0
+    if unicodedata.east_asian_width(chr(cp)) in ("W", "F"):
+        return 2
+    return None
+
+
+def compress(prop):
+    """Sorted (first, last, value) ranges for every code point with a value."""
+    out = []
+    for cp in range(MAX):
+        value = prop(cp)
+        if value is None:
+            continue
+        if out and out[-1][1] == cp - 1 and out[-1][2] == value:
+            out[-1][1] = cp
+        else:
+            out.append([cp, cp, value])
+    return out
+
+
+def emit_enum(name, variants):
+    print("#[derive(Debug, Clone, Copy, PartialEq, Eq)]")
+    print("pub enum %s {" % name)
+    for v in variants:
+        print("    %s," % v)
+    print("}")
+    print()
+
+
+def emit_table(name, enum, rows):
+    print("pub const %s: &[(u32, u32, %s)] = &[" % (name, enum))
+    for first, last, value in rows:
+        print("    (0x%04X, 0x%04X, %s::%s)," % (first, last, enum, value))
+    print("];")
+    print()
+
+
+def emit_values(name, ty, rows):
+    print("pub const %s: &[(u32, u32, %s)] = &[" % (name, ty))
+    for first, last, value in rows:
+        print("    (0x%04X, 0x%04X, %s)," % (first, last, value))
+    print("];")
+    print()
+
+
+def rust_str(s):
+    return '"%s"' % "".join("\\u{%x}" % ord(c) for c in s)
+
+
+def emit_mapping(name, mapping):
+    print("pub const %s: &[(u32, &str)] = &[" % name)
+    for cp, s in mapping:
+        print("    (0x%04X, %s)," % (cp, rust_str(s)))
+    print("];")
+    print()
+
+
+def emit_set(name, rows):
+    print("pub const %s: &[(u32, u32)] = &[" % name)
+    for first, last, _ in rows:
+        print("    (0x%04X, 0x%04X)," % (first, last))
+    print("];")
+    print()
+
+
+def main(ucd):
+    print("// Generated by scripts/unicode_tables.py from Unicode %s, do not edit." % unicodedata.unidata_version)
+    print()
+    print("// Variants keep the property value names used by the UCD.")
+    print("#![allow(clippy::upper_case_acronyms)]")
+    print()
+    print("pub const UNICODE_VERSION: (u8, u8, u8) = (%s);" % ", ".join(unicodedata.unidata_version.split(".")))
+    print()
+
+    grapheme = compress(load_property(ucd, "auxiliary/GraphemeBreakProperty.txt").get)
+    emit_enum("GraphemeCat", ["Other"] + sorted({r[2] for r in grapheme}))
+    emit_table("GRAPHEME_CLUSTER_BREAK", "GraphemeCat", grapheme)
+    pictographic = load_property(ucd, "emoji/emoji-data.txt", "Extended_Pictographic")
+    emit_set("EXTENDED_PICTOGRAPHIC", compress(pictographic.get))
+
+    word = compress(load_property(ucd, "auxiliary/WordBreakProperty.txt").get)
+    emit_enum("WordCat", ["Other"] + sorted({r[2] for r in word}))
+    emit_table("WORD_BREAK", "WordCat", word)
+
+    sentence = compress(load_property(ucd, "auxiliary/SentenceBreakProperty.txt").get)
+    emit_enum("SentenceCat", ["Other"] + sorted({r[2] for r in sentence}))
+    emit_table("SENTENCE_BREAK", "SentenceCat", sentence)
+
+    emit_values("CANONICAL_COMBINING_CLASS", "u8",
+                compress(lambda cp: unicodedata.combining(chr(cp)) or None))
+    emit_mapping("CANONICAL_DECOMPOSITION", canonical_decompositions())
+    emit_mapping("COMPATIBILITY_DECOMPOSITION", compatibility_decompositions())
+
+    pairs = compositions()
+    print("pub const COMPOSITION: &[(u32, u32, u32)] = &[")
+    for first, second, composite in pairs:
+        print("    (0x%04X, 0x%04X, 0x%04X)," % (first, second, composite))
+    print("];")
+    print()
+    seconds = {second for _, second, _ in pairs}
+    emit_set("COMPOSES_WITH_PREVIOUS", compress(lambda cp: True if cp in seconds else None))
+
+    emit_mapping("CASE_FOLDING", case_foldings())
+
+    emit_values("CHAR_WIDTH", "u8", compress(char_width))
+    emit_set("AMBIGUOUS_WIDTH", compress(
+        lambda cp: True if unicodedata.east_asian_width(chr(cp)) == "A" and char_width(cp) is None else None))
+
+
+if __name__ == "__main__":
+    if len(sys.argv) != 2:
+        sys.exit("usage: %s path/to/ucd" % sys.argv[0])
+    out, sys.stdout = sys.stdout, io.StringIO()
+    main(sys.argv[1])
+    text, sys.stdout = sys.stdout.getvalue(), out
+    sys.stdout.reconfigure(newline="\n")
+    sys.stdout.write(text.rstrip("\n") + "\n")
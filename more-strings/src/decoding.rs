@@ -0,0 +1,392 @@
+// Turning bytes of a known or guessed encoding into a `String`, without
+// the panic of `str::from_utf8(bytes).unwrap()` on bad input.
+//
+//     use more_strings::decoding::{decode_lossy, Encoding};
+//
+//     let (text, errors) = decode_lossy(b"caf\xe9", Encoding::Utf8);
+//     assert_eq!(text, "caf\u{fffd}");
+//     assert_eq!(errors[0].position, 3);
+//
+// Every decoder works on chunks of input through `Decoder`, so a file can
+// be decoded a buffer at a time even when a character is split between
+// two buffers. The functions taking whole byte slices are built on it.
+
+use std::error::Error;
+use std::fmt;
+use std::mem;
+use std::str;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Encoding {
+    Utf8,
+    Utf16Le,
+    Utf16Be,
+    // ISO-8859-1: every byte is the code point of the same number.
+    Latin1,
+    // Latin-1 with printable characters such as "€" and curly quotes in
+    // place of most of the C1 controls at 0x80 to 0x9F.
+    Windows1252,
+}
+
+impl Encoding {
+    pub fn name(&self) -> &'static str {
+        match *self {
+            Encoding::Utf8 => "UTF-8",
+            Encoding::Utf16Le => "UTF-16LE",
+            Encoding::Utf16Be => "UTF-16BE",
+            Encoding::Latin1 => "ISO-8859-1",
+            Encoding::Windows1252 => "windows-1252",
+        }
+    }
+}
+
+impl fmt::Display for Encoding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodeErrorKind {
+    // Bytes that can never start or continue a character here, or an
+    // unpaired UTF-16 surrogate.
+    Invalid,
+    // The input ended in the middle of a character.
+    Truncated,
+}
+
+// Where decoding went wrong: `len` bytes starting at byte offset
+// `position` of the whole input, which a lossy decoder replaced with a
+// single U+FFFD REPLACEMENT CHARACTER.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodeError {
+    pub encoding: Encoding,
+    pub position: usize,
+    pub len: usize,
+    pub kind: DecodeErrorKind,
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let what = match self.kind {
+            DecodeErrorKind::Invalid => "invalid",
+            DecodeErrorKind::Truncated => "truncated",
+        };
+        write!(
+            f,
+            "{} {} sequence of {} byte{} at byte {}",
+            what,
+            self.encoding,
+            self.len,
+            if self.len == 1 { "" } else { "s" },
+            self.position
+        )
+    }
+}
+
+impl Error for DecodeError {}
+
+const REPLACEMENT: char = '\u{fffd}';
+const BOM: char = '\u{feff}';
+
+// Windows-1252 characters for bytes 0x80 to 0x9F. The five bytes the
+// code page leaves undefined map to the C1 control of the same number,
+// as in the WHATWG Encoding Standard that browsers follow.
+const WINDOWS_1252_HIGH: [char; 32] = [
+    '\u{20ac}', '\u{81}', '\u{201a}', '\u{192}', '\u{201e}', '\u{2026}', '\u{2020}', '\u{2021}',
+    '\u{2c6}', '\u{2030}', '\u{160}', '\u{2039}', '\u{152}', '\u{8d}', '\u{17d}', '\u{8f}',
+    '\u{90}', '\u{2018}', '\u{2019}', '\u{201c}', '\u{201d}', '\u{2022}', '\u{2013}', '\u{2014}',
+    '\u{2dc}', '\u{2122}', '\u{161}', '\u{203a}', '\u{153}', '\u{9d}', '\u{17e}', '\u{178}',
+];
+
+// The encoding announced by a byte order mark at the start of `bytes`,
+// and the length of the mark.
+pub fn detect_bom(bytes: &[u8]) -> Option<(Encoding, usize)> {
+    if bytes.starts_with(b"\xef\xbb\xbf") {
+        Some((Encoding::Utf8, 3))
+    } else if bytes.starts_with(b"\xff\xfe") {
+        Some((Encoding::Utf16Le, 2))
+    } else if bytes.starts_with(b"\xfe\xff") {
+        Some((Encoding::Utf16Be, 2))
+    } else {
+        None
+    }
+}
+
+// A best guess for bytes of unknown encoding: the byte order mark if
+// there is one, otherwise UTF-8 if they are valid UTF-8, otherwise
+// Windows-1252, which decodes any byte.
+pub fn detect(bytes: &[u8]) -> Encoding {
+    match detect_bom(bytes) {
+        Some((encoding, _)) => encoding,
+        None if str::from_utf8(bytes).is_ok() => Encoding::Utf8,
+        None => Encoding::Windows1252,
+    }
+}
+
+// Strict UTF-8 without copying. Unlike `str::from_utf8` the error tells
+// how many bytes were bad.
+pub fn decode_utf8(bytes: &[u8]) -> Result<&str, DecodeError> {
+    str::from_utf8(bytes).map_err(|e| DecodeError {
+        encoding: Encoding::Utf8,
+        position: e.valid_up_to(),
+        len: e.error_len().unwrap_or(bytes.len() - e.valid_up_to()),
+        kind: match e.error_len() {
+            Some(_) => DecodeErrorKind::Invalid,
+            None => DecodeErrorKind::Truncated,
+        },
+    })
+}
+
+// Decodes `bytes`, failing at the first error. A leading byte order mark
+// for `encoding` is dropped.
+pub fn decode(bytes: &[u8], encoding: Encoding) -> Result<String, DecodeError> {
+    let (text, mut errors) = decode_lossy(bytes, encoding);
+    if errors.is_empty() {
+        Ok(text)
+    } else {
+        Err(errors.swap_remove(0))
+    }
+}
+
+// Decodes `bytes` with every error replaced by U+FFFD, and the list of
+// those errors.
+pub fn decode_lossy(bytes: &[u8], encoding: Encoding) -> (String, Vec<DecodeError>) {
+    let mut decoder = Decoder::new(encoding);
+    let mut text = String::with_capacity(bytes.len());
+    decoder.push(bytes, &mut text);
+    decoder.finish(&mut text);
+    (text, decoder.errors)
+}
+
+// Decodes a byte stream a chunk at a time, lossily. Bytes of a character
+// split between chunks are held back until the rest arrives, and errors
+// carry offsets into the whole stream.
+//
+//     let mut decoder = Decoder::new(Encoding::Utf8);
+//     let mut text = String::new();
+//     decoder.push(b"\xe2\x82", &mut text);
+//     decoder.push(b"\xac", &mut text);
+//     decoder.finish(&mut text);
+//     assert_eq!(text, "\u{20ac}");
+#[derive(Debug, Clone)]
+pub struct Decoder {
+    // `None` until the byte order mark has been looked for
+    encoding: Option<Encoding>,
+    fallback: Encoding,
+    // Bytes of an incomplete character, or of a possible byte order mark
+    pending: Vec<u8>,
+    // A UTF-16 high surrogate waiting for its low half, and its offset
+    high_surrogate: Option<(u16, usize)>,
+    // Offset of the next byte to decode
+    position: usize,
+    at_start: bool,
+    errors: Vec<DecodeError>,
+}
+
+impl Decoder {
+    pub fn new(encoding: Encoding) -> Decoder {
+        Decoder {
+            encoding: Some(encoding),
+            ..Decoder::detecting(encoding)
+        }
+    }
+
+    // A decoder that picks the encoding from a byte order mark at the
+    // start of the stream, and uses `fallback` when there is none.
+    pub fn detecting(fallback: Encoding) -> Decoder {
+        Decoder {
+            encoding: None,
+            fallback,
+            pending: Vec::new(),
+            high_surrogate: None,
+            position: 0,
+            at_start: true,
+            errors: Vec::new(),
+        }
+    }
+
+    // The encoding in use, `None` while still waiting for enough bytes
+    // to tell whether there is a byte order mark.
+    pub fn encoding(&self) -> Option<Encoding> {
+        self.encoding
+    }
+
+    pub fn errors(&self) -> &[DecodeError] {
+        &self.errors
+    }
+
+    pub fn push(&mut self, bytes: &[u8], out: &mut String) {
+        let start = out.len();
+        match self.encoding {
+            Some(encoding) => self.decode(encoding, bytes, out),
+            None => {
+                self.pending.extend_from_slice(bytes);
+                if self.pending.len() >= 3 {
+                    self.pick_encoding(out);
+                }
+            }
+        }
+        self.drop_bom(out, start);
+    }
+
+    // Decodes what is still held back, reporting an unfinished character
+    // as truncated.
+    pub fn finish(&mut self, out: &mut String) {
+        let start = out.len();
+        if self.encoding.is_none() {
+            self.pick_encoding(out);
+        }
+        if let Some((_, position)) = self.high_surrogate.take() {
+            self.error(position, 2, DecodeErrorKind::Truncated, out);
+        }
+        if !self.pending.is_empty() {
+            let len = self.pending.len();
+            self.pending.clear();
+            self.error(self.position, len, DecodeErrorKind::Truncated, out);
+            self.position += len;
+        }
+        self.drop_bom(out, start);
+    }
+
+    fn pick_encoding(&mut self, out: &mut String) {
+        let buffered = mem::take(&mut self.pending);
+        let encoding = detect_bom(&buffered).map_or(self.fallback, |(e, _)| e);
+        self.encoding = Some(encoding);
+        self.decode(encoding, &buffered, out);
+    }
+
+    // A byte order mark is not part of the text.
+    fn drop_bom(&mut self, out: &mut String, start: usize) {
+        if self.at_start && out.len() > start {
+            self.at_start = false;
+            if out[start..].starts_with(BOM) {
+                out.replace_range(start..start + BOM.len_utf8(), "");
+            }
+        }
+    }
+
+    fn error(&mut self, position: usize, len: usize, kind: DecodeErrorKind, out: &mut String) {
+        self.errors.push(DecodeError {
+            encoding: self.encoding.unwrap_or(self.fallback),
+            position,
+            len,
+            kind,
+        });
+        out.push(REPLACEMENT);
+    }
+
+    fn decode(&mut self, encoding: Encoding, bytes: &[u8], out: &mut String) {
+        match encoding {
+            Encoding::Utf8 => self.decode_utf8(bytes, out),
+            Encoding::Utf16Le => self.decode_utf16(bytes, u16::from_le_bytes, out),
+            Encoding::Utf16Be => self.decode_utf16(bytes, u16::from_be_bytes, out),
+            Encoding::Latin1 => {
+                out.extend(bytes.iter().map(|&b| b as char));
+                self.position += bytes.len();
+            }
+            Encoding::Windows1252 => {
+                out.extend(bytes.iter().map(|&b| match b {
+                    0x80..=0x9f => WINDOWS_1252_HIGH[(b - 0x80) as usize],
+                    _ => b as char,
+                }));
+                self.position += bytes.len();
+            }
+        }
+    }
+
+    fn decode_utf8(&mut self, mut bytes: &[u8], out: &mut String) {
+        if !self.pending.is_empty() {
+            // Three more bytes either complete the held back character or
+            // show it to be invalid, as none is longer than four.
+            let take = bytes.len().min(3);
+            let mut joined = mem::take(&mut self.pending);
+            joined.extend_from_slice(&bytes[..take]);
+            self.decode_utf8_complete(&joined, out);
+            if bytes.len() == take {
+                return;
+            }
+            // Anything held back again came from `bytes`; go on from there.
+            let unused = self.pending.len();
+            self.pending.clear();
+            bytes = &bytes[take - unused..];
+        }
+        self.decode_utf8_complete(bytes, out);
+    }
+
+    // Decodes `bytes`, keeping an incomplete character at the end in
+    // `pending`.
+    fn decode_utf8_complete(&mut self, mut bytes: &[u8], out: &mut String) {
+        loop {
+            match str::from_utf8(bytes) {
+                Ok(s) => {
+                    out.push_str(s);
+                    self.position += bytes.len();
+                    return;
+                }
+                Err(e) => {
+                    let valid = e.valid_up_to();
+                    out.push_str(str::from_utf8(&bytes[..valid]).expect("checked above"));
+                    self.position += valid;
+                    match e.error_len() {
+                        Some(len) => {
+                            self.error(self.position, len, DecodeErrorKind::Invalid, out);
+                            self.position += len;
+                            bytes = &bytes[valid + len..];
+                        }
+                        None => {
+                            self.pending.extend_from_slice(&bytes[valid..]);
+                            return;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn decode_utf16(&mut self, bytes: &[u8], unit: fn([u8; 2]) -> u16, out: &mut String) {
+        let mut rest = bytes;
+        if let Some(&first) = self.pending.first() {
+            match rest.split_first() {
+                Some((&second, tail)) => {
+                    self.pending.clear();
+                    self.utf16_unit(unit([first, second]), out);
+                    rest = tail;
+                }
+                None => return,
+            }
+        }
+        let mut pairs = rest.chunks_exact(2);
+        for pair in &mut pairs {
+            self.utf16_unit(unit([pair[0], pair[1]]), out);
+        }
+        self.pending.extend_from_slice(pairs.remainder());
+    }
+
+    fn utf16_unit(&mut self, unit: u16, out: &mut String) {
+        let position = self.position;
+        self.position += 2;
+        match unit {
+            0xd800..=0xdbff => {
+                if let Some((_, unpaired)) = self.high_surrogate.take() {
+                    self.error(unpaired, 2, DecodeErrorKind::Invalid, out);
+                }
+                self.high_surrogate = Some((unit, position));
+            }
+            0xdc00..=0xdfff => match self.high_surrogate.take() {
+                Some((high, _)) => {
+                    let c =
+                        0x10000 + ((u32::from(high) - 0xd800) << 10) + (u32::from(unit) - 0xdc00);
+                    out.push(::std::char::from_u32(c).expect("surrogate pair"));
+                }
+                None => self.error(position, 2, DecodeErrorKind::Invalid, out),
+            },
+            _ => {
+                if let Some((_, unpaired)) = self.high_surrogate.take() {
+                    self.error(unpaired, 2, DecodeErrorKind::Invalid, out);
+                }
+                out.push(::std::char::from_u32(u32::from(unit)).expect("not a surrogate"));
+            }
+        }
+    }
+}
@@ -0,0 +1,249 @@
+// String interning: every distinct string is stored once, and callers
+// hold a 4-byte `Symbol` instead of their own `String`. Comparing and
+// hashing symbols is as cheap as comparing integers, and a parser that
+// sees the same identifier a million times keeps one copy of it.
+//
+//     use more_strings::interner::Interner;
+//
+//     let mut names = Interner::new();
+//     let a = names.intern("width");
+//     let b = names.intern("width");
+//     assert_eq!(a, b);
+//     assert_eq!(names.resolve(a), "width");
+//
+// The strings are copied into large chunks that are never reallocated,
+// so a resolved `&str` stays at the same address for as long as the
+// interner lives. `Arena` offers the same storage on its own.
+
+use std::cell::RefCell;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::hash::{Hash, Hasher};
+use std::mem;
+use std::ptr;
+use std::slice;
+use std::str;
+use std::sync::RwLock;
+
+// Chunks are at least this many bytes, so small strings share them.
+const CHUNK_SIZE: usize = 4096;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Symbol(u32);
+
+impl Symbol {
+    pub fn as_u32(self) -> u32 {
+        self.0
+    }
+}
+
+// Strings packed into chunks that are never grown or moved, so nothing
+// already copied in ever moves. The chunks are only reached through raw
+// pointers, since a `&mut` to a whole chunk while writing would clash
+// with the `&str` already handed out into it.
+#[derive(Debug, Default)]
+struct Chunks {
+    // Start and capacity of every chunk, the last one being filled
+    chunks: Vec<(*mut u8, usize)>,
+    used: usize,
+    bytes: usize,
+}
+
+// The chunks are plain bytes owned by `Chunks`, and are only written
+// through `&mut self`.
+unsafe impl Send for Chunks {}
+unsafe impl Sync for Chunks {}
+
+impl Chunks {
+    // Copies `s` in and returns where it now lives. The pointer stays
+    // valid until the `Chunks` is dropped.
+    fn push(&mut self, s: &str) -> *const str {
+        let fits = self
+            .chunks
+            .last()
+            .is_some_and(|&(_, capacity)| capacity - self.used >= s.len());
+        if !fits {
+            let mut chunk = Vec::<u8>::with_capacity(s.len().max(CHUNK_SIZE));
+            self.chunks.push((chunk.as_mut_ptr(), chunk.capacity()));
+            mem::forget(chunk);
+            self.used = 0;
+        }
+        let (start, _) = *self.chunks.last().unwrap();
+        unsafe {
+            let at = start.add(self.used);
+            ptr::copy_nonoverlapping(s.as_ptr(), at, s.len());
+            self.used += s.len();
+            self.bytes += s.len();
+            str::from_utf8_unchecked(slice::from_raw_parts(at, s.len())) as *const str
+        }
+    }
+}
+
+impl Drop for Chunks {
+    fn drop(&mut self) {
+        for &(start, capacity) in &self.chunks {
+            unsafe { drop(Vec::from_raw_parts(start, 0, capacity)) };
+        }
+    }
+}
+
+// Allocates strings that live as long as the arena, through a shared
+// reference, so that many of them can be borrowed at once.
+//
+//     let arena = Arena::new();
+//     let a = arena.alloc("left");
+//     let b = arena.alloc("right");
+//     assert_eq!((a, b), ("left", "right"));
+#[derive(Debug, Default)]
+pub struct Arena {
+    chunks: RefCell<Chunks>,
+}
+
+impl Arena {
+    pub fn new() -> Arena {
+        Arena::default()
+    }
+
+    pub fn alloc(&self, s: &str) -> &str {
+        let ptr = self.chunks.borrow_mut().push(s);
+        // Safe: the bytes never move or change while `self` is alive.
+        unsafe { &*ptr }
+    }
+
+    // Bytes of string data held, not counting unused chunk capacity.
+    pub fn allocated_bytes(&self) -> usize {
+        self.chunks.borrow().bytes
+    }
+}
+
+// Single-threaded interner. Symbols are handed out in order from 0, so
+// they can double as indexes into a `Vec`.
+#[derive(Debug, Default)]
+pub struct Interner {
+    // Both point into `chunks`, which outlives every use of them.
+    map: HashMap<&'static str, Symbol>,
+    strings: Vec<&'static str>,
+    chunks: Chunks,
+}
+
+impl Interner {
+    pub fn new() -> Interner {
+        Interner::default()
+    }
+
+    pub fn intern(&mut self, s: &str) -> Symbol {
+        if let Some(&symbol) = self.map.get(s) {
+            return symbol;
+        }
+        let symbol = Symbol(u32::try_from(self.strings.len()).expect("Interner is out of symbols"));
+        let stored: &'static str = unsafe { &*self.chunks.push(s) };
+        self.map.insert(stored, symbol);
+        self.strings.push(stored);
+        symbol
+    }
+
+    // The symbol for `s` if it was interned before.
+    pub fn get(&self, s: &str) -> Option<Symbol> {
+        self.map.get(s).copied()
+    }
+
+    // Panics for a symbol from another interner that this one has not
+    // handed out.
+    pub fn resolve(&self, symbol: Symbol) -> &str {
+        self.strings[symbol.0 as usize]
+    }
+
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.strings.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Symbol, &str)> {
+        self.strings
+            .iter()
+            .enumerate()
+            .map(|(i, &s)| (Symbol(i as u32), s))
+    }
+}
+
+// Shards split the interner so that threads interning different strings
+// rarely wait on the same lock.
+const SHARD_BITS: u32 = 4;
+const SHARDS: usize = 1 << SHARD_BITS;
+
+#[derive(Debug, Default)]
+struct Shard {
+    map: HashMap<&'static str, Symbol>,
+    strings: Vec<&'static str>,
+    chunks: Chunks,
+}
+
+// Interner that can be shared between threads, in an `Arc` or a
+// `static`. Looking up strings already interned only takes a read lock.
+// Symbols carry the shard they came from in their low bits, so unlike
+// `Interner` they are not consecutive.
+#[derive(Debug, Default)]
+pub struct ConcurrentInterner {
+    shards: [RwLock<Shard>; SHARDS],
+}
+
+impl ConcurrentInterner {
+    pub fn new() -> ConcurrentInterner {
+        ConcurrentInterner::default()
+    }
+
+    fn shard_of(s: &str) -> usize {
+        let mut hasher = DefaultHasher::new();
+        s.hash(&mut hasher);
+        hasher.finish() as usize % SHARDS
+    }
+
+    pub fn intern(&self, s: &str) -> Symbol {
+        if let Some(symbol) = self.get(s) {
+            return symbol;
+        }
+        let index = ConcurrentInterner::shard_of(s);
+        let mut shard = self.shards[index].write().unwrap();
+        // Another thread may have interned it since the read lock was let go
+        if let Some(&symbol) = shard.map.get(s) {
+            return symbol;
+        }
+        // The position in the shard has to fit above the shard bits
+        let position = u32::try_from(shard.strings.len())
+            .ok()
+            .filter(|&n| n <= u32::MAX >> SHARD_BITS)
+            .expect("ConcurrentInterner shard is out of symbols");
+        let symbol = Symbol((position << SHARD_BITS) | index as u32);
+        let stored: &'static str = unsafe { &*shard.chunks.push(s) };
+        shard.map.insert(stored, symbol);
+        shard.strings.push(stored);
+        symbol
+    }
+
+    pub fn get(&self, s: &str) -> Option<Symbol> {
+        let shard = self.shards[ConcurrentInterner::shard_of(s)].read().unwrap();
+        shard.map.get(s).copied()
+    }
+
+    // The returned `&str` outlives the lock: shards only ever add
+    // strings, and their bytes never move.
+    pub fn resolve(&self, symbol: Symbol) -> &str {
+        let shard = self.shards[symbol.0 as usize % SHARDS].read().unwrap();
+        shard.strings[(symbol.0 >> SHARD_BITS) as usize]
+    }
+
+    pub fn len(&self) -> usize {
+        self.shards
+            .iter()
+            .map(|shard| shard.read().unwrap().strings.len())
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
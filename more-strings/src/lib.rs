@@ -0,0 +1,43 @@
+// Unicode text handling beyond what `str` offers, following the levels
+// of unicode described in main.rs: bytes, code points and graphemes.
+
+mod tables;
+
+pub mod decoding;
+pub mod interner;
+pub mod normalization;
+pub mod segmentation;
+pub mod width;
+
+// The value `c` has in a table of sorted, non-overlapping code point
+// ranges, or `default` when no range holds it.
+fn lookup<T: Copy>(table: &[(u32, u32, T)], c: char, default: T) -> T {
+    let cp = c as u32;
+    match table.binary_search_by(|&(first, last, _)| {
+        if last < cp {
+            ::std::cmp::Ordering::Less
+        } else if first > cp {
+            ::std::cmp::Ordering::Greater
+        } else {
+            ::std::cmp::Ordering::Equal
+        }
+    }) {
+        Ok(i) => table[i].2,
+        Err(_) => default,
+    }
+}
+
+fn in_table(table: &[(u32, u32)], c: char) -> bool {
+    let cp = c as u32;
+    table
+        .binary_search_by(|&(first, last)| {
+            if last < cp {
+                ::std::cmp::Ordering::Less
+            } else if first > cp {
+                ::std::cmp::Ordering::Greater
+            } else {
+                ::std::cmp::Ordering::Equal
+            }
+        })
+        .is_ok()
+}
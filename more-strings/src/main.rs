@@ -1,5 +1,13 @@
+extern crate more_strings;
+
 use std::str;
 
+use more_strings::decoding::{self, Encoding};
+use more_strings::interner::Interner;
+use more_strings::normalization::{self, Form};
+use more_strings::segmentation::UnicodeSegmentation;
+use more_strings::width::{self, Align};
+
 fn main() {
     // 2 main 'string' types
 
@@ -32,6 +40,20 @@ fn main() {
     let x: &[u8] = &[b'a', b'a'];
     let stack_str: &str = str::from_utf8(x).unwrap();
 
+    // unwrap() panics when the bytes are not valid UTF-8. Bytes from
+    // files or the network should be decoded without trusting them:
+    let latin1_bytes: &[u8] = b"caf\xe9";
+    match decoding::decode_utf8(latin1_bytes) {
+        Ok(s) => println!("{}", s),
+        Err(e) => println!("{}", e),
+    }
+    // replacing what cannot be decoded with U+FFFD
+    let (lossy, errors) = decoding::decode_lossy(latin1_bytes, Encoding::Utf8);
+    println!("{} ({} error)", lossy, errors.len());
+    // or guessing the encoding
+    let guess = decoding::detect(latin1_bytes);
+    println!("{}: {}", guess, decoding::decode(latin1_bytes, guess).unwrap());
+
 
     // Best practise
     // one should prefer String when you need ownership and &str when you
@@ -47,6 +69,21 @@ fn main() {
     // onwership you don't need, as it can make your lifetimes
     // more complex.
 
+    // Taken further: when the same strings turn up over and over, keep
+    // one copy of each in an interner and pass around small Copy symbols
+    let mut names = Interner::new();
+    let source = "let x = y + x * y";
+    let symbols: Vec<_> = source
+        .split_whitespace()
+        .map(|token| names.intern(token))
+        .collect();
+    println!(
+        "{} tokens, {} distinct, first is {:?}",
+        symbols.len(),
+        names.len(),
+        names.resolve(symbols[0])
+    );
+
     // Generic functions
     fn some_string_length(x: &str) -> usize {
         x.len()
@@ -58,6 +95,18 @@ fn main() {
     let s2 = "awkjdjk2k2".to_string();
     println!{"{}", some_string_length(&s2)};
 
+    // len() is the length in bytes, which is not how wide the string is
+    // on screen. Columns only line up when padding by display width.
+    for name in &["Amélie", "日本語", "Zoë", "👍"] {
+        println!(
+            "|{}| {} bytes, |{}| {} columns",
+            name,
+            some_string_length(name),
+            width::pad(name, 8, Align::Left),
+            width::display_width(name)
+        );
+    }
+
     // Indexing strings
 
     // TEMPTED TO DO;
@@ -77,11 +126,45 @@ fn main() {
     // - .chars() will iterate over code points
     // - .graphemes() will iterate over graphemes
 
-    // let s = "u͔n͈̰̎i̙̮͚̦c͚̉o̼̩̰͗d͔̆̓ͥé";
-    //
-    // for l in s.graphemes(true) {
-    //     println!("{}", l);
-    // }
+    let s = "u͔n͈̰̎i̙̮͚̦c͚̉o̼̩̰͗d͔̆̓ͥé";
+
+    for l in s.graphemes(true) {
+        println!("{}", l);
+    }
+
+    // the same word is 7 graphemes but many more code points and bytes
+    println!(
+        "{} graphemes, {} chars, {} bytes",
+        s.graphemes(true).count(),
+        s.chars().count(),
+        s.len()
+    );
+
+    // word and sentence boundaries come from the same Unicode standard
+    let text = "The U.S. team can't stay. They leave at 3.30 today!";
+    for word in text.unicode_words() {
+        println!("{}", word);
+    }
+    for sentence in text.split_sentence_bounds() {
+        println!("{:?}", sentence);
+    }
+
+    // Normalization
+    // the same text can be written with different code points: "é" is
+    // one precomposed char or an "e" followed by a combining accent
+    let composed = "Am\u{e9}lie";
+    let decomposed = "Ame\u{301}lie";
+    println!("{} == {}: {}", composed, decomposed, composed == decomposed);
+    println!(
+        "after NFC: {}",
+        normalization::normalize(composed, Form::Nfc)
+            == normalization::normalize(decomposed, Form::Nfc)
+    );
+    // caseless_eq also ignores case, with full case folding (ß == SS)
+    println!(
+        "caseless: {}",
+        normalization::caseless_eq("AM\u{c9}LIE", decomposed)
+    );
 
     // Deref coercions
     // References to String will automatically coerce into &str
@@ -0,0 +1,361 @@
+// Unicode normalization from UAX #15 and case-insensitive comparison.
+//
+// The same text can be spelled with different code points: "é" is
+// either U+00E9 or "e" followed by U+0301 COMBINING ACUTE ACCENT. Both
+// look alike and compare unequal as `str`. Normalizing picks one
+// spelling:
+//
+// - NFD takes every character apart into base and combining marks
+// - NFC takes them apart and puts them back together, preferring the
+//   precomposed characters
+// - NFKD and NFKC also replace compatibility characters with their
+//   plain equivalents, such as "ﬁ" with "fi" or "²" with "2"
+//
+//     use more_strings::normalization::{caseless_eq, UnicodeNormalization};
+//
+//     let s: String = "e\u{301}".nfc().collect();
+//     assert_eq!(s, "\u{e9}");
+//     assert!(caseless_eq("Stra\u{df}e", "STRASSE"));
+
+use std::mem;
+use std::str::Chars;
+
+use tables::{
+    CANONICAL_COMBINING_CLASS, CANONICAL_DECOMPOSITION, CASE_FOLDING, COMPATIBILITY_DECOMPOSITION,
+    COMPOSES_WITH_PREVIOUS, COMPOSITION,
+};
+use {in_table, lookup};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Form {
+    Nfc,
+    Nfd,
+    Nfkc,
+    Nfkd,
+}
+
+impl Form {
+    fn compatibility(self) -> bool {
+        self == Form::Nfkc || self == Form::Nfkd
+    }
+
+    fn composed(self) -> bool {
+        self == Form::Nfc || self == Form::Nfkc
+    }
+}
+
+// Hangul syllables decompose and compose arithmetically instead of
+// through the tables.
+const S_BASE: u32 = 0xAC00;
+const L_BASE: u32 = 0x1100;
+const V_BASE: u32 = 0x1161;
+const T_BASE: u32 = 0x11A7;
+const L_COUNT: u32 = 19;
+const V_COUNT: u32 = 21;
+const T_COUNT: u32 = 28;
+const N_COUNT: u32 = V_COUNT * T_COUNT;
+const S_COUNT: u32 = L_COUNT * N_COUNT;
+
+fn combining_class(c: char) -> u8 {
+    lookup(CANONICAL_COMBINING_CLASS, c, 0)
+}
+
+fn mapping(table: &'static [(u32, &'static str)], c: char) -> Option<&'static str> {
+    table
+        .binary_search_by_key(&(c as u32), |&(cp, _)| cp)
+        .ok()
+        .map(|i| table[i].1)
+}
+
+// Appends the full decomposition of `c` to `out`.
+fn decompose(c: char, compatibility: bool, out: &mut Vec<char>) {
+    let cp = c as u32;
+    if (S_BASE..S_BASE + S_COUNT).contains(&cp) {
+        let index = cp - S_BASE;
+        let jamo = |cp| ::std::char::from_u32(cp).expect("Hangul jamo");
+        out.push(jamo(L_BASE + index / N_COUNT));
+        out.push(jamo(V_BASE + (index % N_COUNT) / T_COUNT));
+        if !index.is_multiple_of(T_COUNT) {
+            out.push(jamo(T_BASE + index % T_COUNT));
+        }
+        return;
+    }
+    let found = if compatibility {
+        mapping(COMPATIBILITY_DECOMPOSITION, c).or_else(|| mapping(CANONICAL_DECOMPOSITION, c))
+    } else {
+        mapping(CANONICAL_DECOMPOSITION, c)
+    };
+    match found {
+        Some(s) => out.extend(s.chars()),
+        None => out.push(c),
+    }
+}
+
+// The primary composite of `first` followed by `second`, if any.
+fn compose_pair(first: char, second: char) -> Option<char> {
+    let (a, b) = (first as u32, second as u32);
+    if (L_BASE..L_BASE + L_COUNT).contains(&a) && (V_BASE..V_BASE + V_COUNT).contains(&b) {
+        let index = (a - L_BASE) * N_COUNT + (b - V_BASE) * T_COUNT;
+        return ::std::char::from_u32(S_BASE + index);
+    }
+    if (S_BASE..S_BASE + S_COUNT).contains(&a)
+        && (a - S_BASE).is_multiple_of(T_COUNT)
+        && (T_BASE + 1..T_BASE + T_COUNT).contains(&b)
+    {
+        return ::std::char::from_u32(a + b - T_BASE);
+    }
+    COMPOSITION
+        .binary_search_by_key(&(a, b), |&(x, y, _)| (x, y))
+        .ok()
+        .and_then(|i| ::std::char::from_u32(COMPOSITION[i].2))
+}
+
+// Whether nothing before `c` can interact with anything from `c` on, so
+// that the text before it can be normalized on its own. `c` must be a
+// starter, and under composition also unable to combine with a
+// preceding character.
+fn is_boundary(c: char, composed: bool) -> bool {
+    if combining_class(c) != 0 {
+        return false;
+    }
+    if !composed {
+        return true;
+    }
+    let cp = c as u32;
+    let jamo_vowel_or_trailing =
+        (V_BASE..V_BASE + V_COUNT).contains(&cp) || (T_BASE + 1..T_BASE + T_COUNT).contains(&cp);
+    !jamo_vowel_or_trailing && !in_table(COMPOSES_WITH_PREVIOUS, c)
+}
+
+// Puts combining marks between two starters in canonical order. The
+// sort is stable, so marks of the same class keep their order.
+fn reorder(chars: &mut [char]) {
+    let mut start = 0;
+    while start < chars.len() {
+        if combining_class(chars[start]) == 0 {
+            start += 1;
+            continue;
+        }
+        let end = chars[start..]
+            .iter()
+            .position(|&c| combining_class(c) == 0)
+            .map_or(chars.len(), |n| start + n);
+        chars[start..end].sort_by_key(|&c| combining_class(c));
+        start = end;
+    }
+}
+
+// Canonical composition of decomposed, reordered `chars`.
+fn compose(chars: &mut Vec<char>) {
+    let mut out = Vec::with_capacity(chars.len());
+    let mut starter: Option<usize> = None;
+    // Class of the last character kept after the starter, if any
+    let mut last_class: Option<u8> = None;
+    for c in chars.drain(..) {
+        let class = combining_class(c);
+        if let Some(s) = starter {
+            let blocked = last_class.is_some_and(|last| last == 0 || last >= class);
+            if !blocked {
+                if let Some(composite) = compose_pair(out[s], c) {
+                    out[s] = composite;
+                    continue;
+                }
+            }
+        }
+        if class == 0 {
+            starter = Some(out.len());
+            last_class = None;
+        } else {
+            last_class = Some(class);
+        }
+        out.push(c);
+    }
+    *chars = out;
+}
+
+// Normalizes a run of decomposed characters in place.
+fn finish_segment(chars: &mut Vec<char>, form: Form) {
+    reorder(chars);
+    if form.composed() {
+        compose(chars);
+    }
+}
+
+// The characters between two boundaries, decomposed, waiting for the
+// next boundary before they can be reordered and composed.
+#[derive(Debug, Clone)]
+struct Segment {
+    form: Form,
+    pending: Vec<char>,
+    scratch: Vec<char>,
+}
+
+impl Segment {
+    fn new(form: Form) -> Segment {
+        Segment {
+            form,
+            pending: Vec::new(),
+            scratch: Vec::new(),
+        }
+    }
+
+    // Adds `c` and returns the finished segment before it, if `c`
+    // starts a new one.
+    fn push(&mut self, c: char) -> Option<Vec<char>> {
+        self.scratch.clear();
+        decompose(c, self.form.compatibility(), &mut self.scratch);
+        let boundary =
+            !self.pending.is_empty() && is_boundary(self.scratch[0], self.form.composed());
+        let done = if boundary { Some(self.take()) } else { None };
+        self.pending.extend_from_slice(&self.scratch);
+        done
+    }
+
+    fn take(&mut self) -> Vec<char> {
+        let mut chars = mem::take(&mut self.pending);
+        finish_segment(&mut chars, self.form);
+        chars
+    }
+}
+
+// Normalizes any iterator of characters lazily, holding on to no more
+// than one segment: a starter and the combining marks following it.
+#[derive(Debug, Clone)]
+pub struct Normalized<I> {
+    chars: I,
+    segment: Segment,
+    ready: Vec<char>,
+    position: usize,
+}
+
+impl<I: Iterator<Item = char>> Normalized<I> {
+    pub fn new(chars: I, form: Form) -> Normalized<I> {
+        Normalized {
+            chars,
+            segment: Segment::new(form),
+            ready: Vec::new(),
+            position: 0,
+        }
+    }
+}
+
+impl<I: Iterator<Item = char>> Iterator for Normalized<I> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        while self.position == self.ready.len() {
+            self.position = 0;
+            self.ready = match self.chars.next() {
+                Some(c) => self.segment.push(c).unwrap_or_default(),
+                None if self.segment.pending.is_empty() => {
+                    self.ready.clear();
+                    return None;
+                }
+                None => self.segment.take(),
+            };
+        }
+        self.position += 1;
+        Some(self.ready[self.position - 1])
+    }
+}
+
+// Normalizes text arriving in chunks, such as a large file read a buffer
+// at a time. Output lags behind input by at most one segment, which
+// `finish` flushes.
+//
+//     let mut normalizer = Normalizer::new(Form::Nfc);
+//     let mut out = String::new();
+//     normalizer.push_str("caf", &mut out);
+//     normalizer.push_str("e\u{301}", &mut out);
+//     normalizer.finish(&mut out);
+//     assert_eq!(out, "caf\u{e9}");
+#[derive(Debug, Clone)]
+pub struct Normalizer {
+    segment: Segment,
+}
+
+impl Normalizer {
+    pub fn new(form: Form) -> Normalizer {
+        Normalizer {
+            segment: Segment::new(form),
+        }
+    }
+
+    pub fn push_str(&mut self, chunk: &str, out: &mut String) {
+        for c in chunk.chars() {
+            if let Some(done) = self.segment.push(c) {
+                out.extend(done);
+            }
+        }
+    }
+
+    // Writes out whatever is still held back. The normalizer can be
+    // reused afterwards.
+    pub fn finish(&mut self, out: &mut String) {
+        out.extend(self.segment.take());
+    }
+}
+
+pub trait UnicodeNormalization {
+    fn nfd(&self) -> Normalized<Chars<'_>>;
+
+    fn nfc(&self) -> Normalized<Chars<'_>>;
+
+    fn nfkd(&self) -> Normalized<Chars<'_>>;
+
+    fn nfkc(&self) -> Normalized<Chars<'_>>;
+}
+
+impl UnicodeNormalization for str {
+    fn nfd(&self) -> Normalized<Chars<'_>> {
+        Normalized::new(self.chars(), Form::Nfd)
+    }
+
+    fn nfc(&self) -> Normalized<Chars<'_>> {
+        Normalized::new(self.chars(), Form::Nfc)
+    }
+
+    fn nfkd(&self) -> Normalized<Chars<'_>> {
+        Normalized::new(self.chars(), Form::Nfkd)
+    }
+
+    fn nfkc(&self) -> Normalized<Chars<'_>> {
+        Normalized::new(self.chars(), Form::Nfkc)
+    }
+}
+
+pub fn normalize(s: &str, form: Form) -> String {
+    Normalized::new(s.chars(), form).collect()
+}
+
+pub fn is_normalized(s: &str, form: Form) -> bool {
+    Normalized::new(s.chars(), form).eq(s.chars())
+}
+
+// Full case folding from CaseFolding.txt, which maps "ß" to "ss" and
+// both "Σ" and final "ς" to "σ". Unlike `to_lowercase` it is meant for
+// comparing strings rather than displaying them.
+pub fn case_fold(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match mapping(CASE_FOLDING, c) {
+            Some(folded) => out.push_str(folded),
+            None => out.push(c),
+        }
+    }
+    out
+}
+
+// A key that is equal for two strings exactly when `caseless_eq` holds,
+// for deduplicating with a `HashSet` or `HashMap`.
+pub fn caseless_key(s: &str) -> String {
+    normalize(&case_fold(&normalize(s, Form::Nfd)), Form::Nfd)
+}
+
+// Canonical caseless matching (definition D145 of the Unicode
+// standard): equal after normalizing, folding case and normalizing
+// again, so that composed and decomposed accents and differences in
+// case all compare equal.
+pub fn caseless_eq(a: &str, b: &str) -> bool {
+    caseless_key(a) == caseless_key(b)
+}
@@ -0,0 +1,429 @@
+// Text boundaries from UAX #29, Unicode Text Segmentation: extended
+// grapheme clusters (what a reader sees as one character), words and
+// sentences.
+//
+//     use more_strings::segmentation::UnicodeSegmentation;
+//
+//     let s = "e\u{301}!";
+//     assert_eq!(s.graphemes(true).collect::<Vec<_>>(), ["e\u{301}", "!"]);
+//
+// The property tables in tables.rs are generated by
+// scripts/unicode_tables.py.
+
+use std::vec;
+
+use tables::GraphemeCat as G;
+use tables::SentenceCat as S;
+use tables::WordCat as W;
+use tables::{EXTENDED_PICTOGRAPHIC, GRAPHEME_CLUSTER_BREAK, SENTENCE_BREAK, WORD_BREAK};
+use {in_table, lookup};
+
+pub use tables::UNICODE_VERSION;
+
+fn grapheme_cat(c: char) -> G {
+    lookup(GRAPHEME_CLUSTER_BREAK, c, G::Other)
+}
+
+fn word_cat(c: char) -> W {
+    lookup(WORD_BREAK, c, W::Other)
+}
+
+fn sentence_cat(c: char) -> S {
+    lookup(SENTENCE_BREAK, c, S::Other)
+}
+
+fn is_pictographic(c: char) -> bool {
+    in_table(EXTENDED_PICTOGRAPHIC, c)
+}
+
+// Byte offset of the grapheme cluster boundary following `start`.
+// Legacy clusters (`extended == false`) skip rules GB9a and GB9b.
+fn next_grapheme_boundary(s: &str, start: usize, extended: bool) -> usize {
+    let mut chars = s[start..].char_indices();
+    let first = match chars.next() {
+        Some((_, c)) => c,
+        None => return start,
+    };
+
+    let mut prev = grapheme_cat(first);
+    // Regional indicators seen in a row, for GB12 and GB13
+    let mut regional = if prev == G::RegionalIndicator { 1 } else { 0 };
+    // Inside `ExtPict Extend*`, and whether a ZWJ just followed that (GB11)
+    let mut pictographic = is_pictographic(first);
+    let mut emoji_zwj = false;
+
+    for (i, c) in chars {
+        let next = grapheme_cat(c);
+        let join = match (prev, next) {
+            (G::CR, G::LF) => true,
+            (G::Control, _) | (G::CR, _) | (G::LF, _) => false,
+            (_, G::Control) | (_, G::CR) | (_, G::LF) => false,
+            (G::L, G::L) | (G::L, G::V) | (G::L, G::LV) | (G::L, G::LVT) => true,
+            (G::LV, G::V) | (G::LV, G::T) | (G::V, G::V) | (G::V, G::T) => true,
+            (G::LVT, G::T) | (G::T, G::T) => true,
+            (_, G::Extend) | (_, G::ZWJ) => true,
+            (_, G::SpacingMark) if extended => true,
+            (G::Prepend, _) if extended => true,
+            (G::ZWJ, _) => emoji_zwj && is_pictographic(c),
+            (G::RegionalIndicator, G::RegionalIndicator) => regional % 2 == 1,
+            _ => false,
+        };
+        if !join {
+            return start + i;
+        }
+
+        regional = if next == G::RegionalIndicator {
+            regional + 1
+        } else {
+            0
+        };
+        emoji_zwj = pictographic && next == G::ZWJ;
+        pictographic = is_pictographic(c) || (pictographic && next == G::Extend);
+        prev = next;
+    }
+    s.len()
+}
+
+pub struct GraphemeIndices<'a> {
+    s: &'a str,
+    position: usize,
+    extended: bool,
+}
+
+impl<'a> Iterator for GraphemeIndices<'a> {
+    type Item = (usize, &'a str);
+
+    fn next(&mut self) -> Option<(usize, &'a str)> {
+        if self.position == self.s.len() {
+            return None;
+        }
+        let start = self.position;
+        self.position = next_grapheme_boundary(self.s, start, self.extended);
+        Some((start, &self.s[start..self.position]))
+    }
+}
+
+pub struct Graphemes<'a> {
+    inner: GraphemeIndices<'a>,
+}
+
+impl<'a> Iterator for Graphemes<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        self.inner.next().map(|(_, g)| g)
+    }
+}
+
+// Slices of a string between precomputed boundaries.
+pub struct Segments<'a> {
+    s: &'a str,
+    boundaries: vec::IntoIter<usize>,
+    start: usize,
+}
+
+impl<'a> Segments<'a> {
+    fn new(s: &'a str, boundaries: Vec<usize>) -> Segments<'a> {
+        let mut boundaries = boundaries.into_iter();
+        let start = boundaries.next().unwrap_or(0);
+        Segments {
+            s,
+            boundaries,
+            start,
+        }
+    }
+}
+
+impl<'a> Iterator for Segments<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        let end = self.boundaries.next()?;
+        let segment = &self.s[self.start..end];
+        self.start = end;
+        Some(segment)
+    }
+}
+
+// Word segments that contain a letter or digit, leaving out spaces and
+// punctuation.
+pub struct UnicodeWords<'a> {
+    inner: Segments<'a>,
+}
+
+impl<'a> Iterator for UnicodeWords<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        self.inner
+            .by_ref()
+            .find(|w| w.chars().any(char::is_alphanumeric))
+    }
+}
+
+// Code points of a string with their offsets and a property, shared by
+// the word and sentence rules, which need context on both sides.
+struct Classified<T> {
+    offsets: Vec<usize>,
+    chars: Vec<char>,
+    cats: Vec<T>,
+}
+
+impl<T: Copy + PartialEq> Classified<T> {
+    fn new(s: &str, classify: fn(char) -> T) -> Classified<T> {
+        let (offsets, chars): (Vec<usize>, Vec<char>) = s.char_indices().unzip();
+        let cats = chars.iter().map(|&c| classify(c)).collect();
+        Classified {
+            offsets,
+            chars,
+            cats,
+        }
+    }
+
+    // Index of the character standing for everything before boundary
+    // `i` once runs of `ignorable` characters are folded into the
+    // character they follow (WB4, SB5). A run following a `hard` stop or
+    // the start of text stands for itself.
+    fn before(&self, i: usize, ignorable: &[T], hard: &[T]) -> Option<usize> {
+        if i == 0 {
+            return None;
+        }
+        let mut j = i - 1;
+        while ignorable.contains(&self.cats[j]) {
+            if j == 0 || hard.contains(&self.cats[j - 1]) {
+                return Some(i - 1);
+            }
+            j -= 1;
+        }
+        Some(j)
+    }
+
+    // First character at or after `i` that is not `ignorable`.
+    fn after(&self, i: usize, ignorable: &[T]) -> Option<usize> {
+        (i..self.cats.len()).find(|&j| !ignorable.contains(&self.cats[j]))
+    }
+
+    fn cat(&self, i: Option<usize>) -> Option<T> {
+        i.map(|i| self.cats[i])
+    }
+}
+
+const WORD_IGNORABLE: &[W] = &[W::Extend, W::Format, W::ZWJ];
+const WORD_HARD: &[W] = &[W::CR, W::LF, W::Newline];
+
+fn is_ah_letter(c: Option<W>) -> bool {
+    c == Some(W::ALetter) || c == Some(W::HebrewLetter)
+}
+
+fn is_mid_letter(c: Option<W>) -> bool {
+    c == Some(W::MidLetter) || c == Some(W::MidNumLet) || c == Some(W::SingleQuote)
+}
+
+fn is_mid_num(c: Option<W>) -> bool {
+    c == Some(W::MidNum) || c == Some(W::MidNumLet) || c == Some(W::SingleQuote)
+}
+
+fn word_boundary(t: &Classified<W>, i: usize) -> bool {
+    let (a, b) = (t.cats[i - 1], t.cats[i]);
+    if a == W::CR && b == W::LF {
+        return false;
+    }
+    if WORD_HARD.contains(&a) || WORD_HARD.contains(&b) {
+        return true;
+    }
+    if a == W::ZWJ && is_pictographic(t.chars[i]) {
+        return false;
+    }
+    if a == W::WSegSpace && b == W::WSegSpace {
+        return false;
+    }
+    if WORD_IGNORABLE.contains(&b) {
+        return false;
+    }
+
+    let x = t.before(i, WORD_IGNORABLE, WORD_HARD);
+    let prev = t.cat(x);
+    let prev2 = t.cat(x.and_then(|x| t.before(x, WORD_IGNORABLE, WORD_HARD)));
+    let next = t.cat(t.after(i + 1, WORD_IGNORABLE));
+    let b = Some(b);
+
+    let numeric = |c: Option<W>| c == Some(W::Numeric);
+    let katakana = |c: Option<W>| c == Some(W::Katakana);
+    let extend_num_let = |c: Option<W>| c == Some(W::ExtendNumLet);
+    let hebrew = |c: Option<W>| c == Some(W::HebrewLetter);
+
+    let join = (is_ah_letter(prev) && is_ah_letter(b))
+        || (is_ah_letter(prev) && is_mid_letter(b) && is_ah_letter(next))
+        || (is_ah_letter(prev2) && is_mid_letter(prev) && is_ah_letter(b))
+        || (hebrew(prev) && b == Some(W::SingleQuote))
+        || (hebrew(prev) && b == Some(W::DoubleQuote) && hebrew(next))
+        || (hebrew(prev2) && prev == Some(W::DoubleQuote) && hebrew(b))
+        || ((numeric(prev) || is_ah_letter(prev)) && numeric(b))
+        || (numeric(prev) && is_ah_letter(b))
+        || (numeric(prev2) && is_mid_num(prev) && numeric(b))
+        || (numeric(prev) && is_mid_num(b) && numeric(next))
+        || (katakana(prev) && katakana(b))
+        || ((is_ah_letter(prev) || numeric(prev) || katakana(prev) || extend_num_let(prev))
+            && extend_num_let(b))
+        || (extend_num_let(prev) && (is_ah_letter(b) || numeric(b) || katakana(b)));
+    if join {
+        return false;
+    }
+
+    if prev == Some(W::RegionalIndicator) && b == Some(W::RegionalIndicator) {
+        let mut count = 0;
+        let mut k = x;
+        while t.cat(k) == Some(W::RegionalIndicator) {
+            count += 1;
+            k = k.and_then(|k| t.before(k, WORD_IGNORABLE, WORD_HARD));
+        }
+        return count % 2 == 0;
+    }
+    true
+}
+
+const SENTENCE_IGNORABLE: &[S] = &[S::Extend, S::Format];
+const PARAGRAPH_SEPARATOR: &[S] = &[S::Sep, S::CR, S::LF];
+
+fn sentence_boundary(t: &Classified<S>, i: usize) -> bool {
+    let (a, b) = (t.cats[i - 1], t.cats[i]);
+    if a == S::CR && b == S::LF {
+        return false;
+    }
+    if PARAGRAPH_SEPARATOR.contains(&a) {
+        return true;
+    }
+    if SENTENCE_IGNORABLE.contains(&b) {
+        return false;
+    }
+
+    let before = |k: usize| t.before(k, SENTENCE_IGNORABLE, PARAGRAPH_SEPARATOR);
+    let x = before(i);
+    let prev = t.cat(x);
+
+    if prev == Some(S::ATerm) {
+        let prev2 = t.cat(x.and_then(before));
+        if b == S::Numeric
+            || (b == S::Upper && (prev2 == Some(S::Upper) || prev2 == Some(S::Lower)))
+        {
+            return false;
+        }
+    }
+
+    // Match `SATerm Close* Sp*` backwards from the boundary.
+    let mut k = x;
+    let mut spaces = false;
+    while t.cat(k) == Some(S::Sp) {
+        spaces = true;
+        k = k.and_then(before);
+    }
+    while t.cat(k) == Some(S::Close) {
+        k = k.and_then(before);
+    }
+    let term = match t.cat(k) {
+        Some(S::ATerm) => S::ATerm,
+        Some(S::STerm) => S::STerm,
+        _ => return false,
+    };
+
+    if term == S::ATerm {
+        let stop = [
+            S::OLetter,
+            S::Upper,
+            S::Lower,
+            S::Sep,
+            S::CR,
+            S::LF,
+            S::STerm,
+            S::ATerm,
+        ];
+        let lower = (i..t.cats.len())
+            .find(|&j| stop.contains(&t.cats[j]))
+            .map(|j| t.cats[j]);
+        if lower == Some(S::Lower) {
+            return false;
+        }
+    }
+    if b == S::SContinue || b == S::STerm || b == S::ATerm {
+        return false;
+    }
+    if !spaces && b == S::Close {
+        return false;
+    }
+    if b == S::Sp || PARAGRAPH_SEPARATOR.contains(&b) {
+        return false;
+    }
+    true
+}
+
+fn boundaries<T: Copy + PartialEq>(
+    s: &str,
+    classify: fn(char) -> T,
+    is_boundary: fn(&Classified<T>, usize) -> bool,
+) -> Vec<usize> {
+    let t = Classified::new(s, classify);
+    let mut out = vec![0];
+    out.extend(
+        (1..t.chars.len())
+            .filter(|&i| is_boundary(&t, i))
+            .map(|i| t.offsets[i]),
+    );
+    if !s.is_empty() {
+        out.push(s.len());
+    }
+    out
+}
+
+pub fn word_boundaries(s: &str) -> Vec<usize> {
+    boundaries(s, word_cat, word_boundary)
+}
+
+pub fn sentence_boundaries(s: &str) -> Vec<usize> {
+    boundaries(s, sentence_cat, sentence_boundary)
+}
+
+pub trait UnicodeSegmentation {
+    // Legacy grapheme clusters when `is_extended` is false, which keep
+    // spacing marks and prepended characters separate.
+    fn graphemes(&self, is_extended: bool) -> Graphemes<'_>;
+
+    fn grapheme_indices(&self, is_extended: bool) -> GraphemeIndices<'_>;
+
+    // Every piece between two word boundaries, spaces and punctuation
+    // included, so the pieces join back to the original string.
+    fn split_word_bounds(&self) -> Segments<'_>;
+
+    fn unicode_words(&self) -> UnicodeWords<'_>;
+
+    fn split_sentence_bounds(&self) -> Segments<'_>;
+}
+
+impl UnicodeSegmentation for str {
+    fn graphemes(&self, is_extended: bool) -> Graphemes<'_> {
+        Graphemes {
+            inner: self.grapheme_indices(is_extended),
+        }
+    }
+
+    fn grapheme_indices(&self, is_extended: bool) -> GraphemeIndices<'_> {
+        GraphemeIndices {
+            s: self,
+            position: 0,
+            extended: is_extended,
+        }
+    }
+
+    fn split_word_bounds(&self) -> Segments<'_> {
+        Segments::new(self, word_boundaries(self))
+    }
+
+    fn unicode_words(&self) -> UnicodeWords<'_> {
+        UnicodeWords {
+            inner: self.split_word_bounds(),
+        }
+    }
+
+    fn split_sentence_bounds(&self) -> Segments<'_> {
+        Segments::new(self, sentence_boundaries(self))
+    }
+}
@@ -0,0 +1,14905 @@
+// Generated by scripts/unicode_tables.py from Unicode 14.0.0, do not edit.
+
+// Variants keep the property value names used by the UCD.
+#![allow(clippy::upper_case_acronyms)]
+
+pub const UNICODE_VERSION: (u8, u8, u8) = (14, 0, 0);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphemeCat {
+    Other,
+    CR,
+    Control,
+    Extend,
+    L,
+    LF,
+    LV,
+    LVT,
+    Prepend,
+    RegionalIndicator,
+    SpacingMark,
+    T,
+    V,
+    ZWJ,
+}
+
+pub const GRAPHEME_CLUSTER_BREAK: &[(u32, u32, GraphemeCat)] = &[
+    (0x0000, 0x0009, GraphemeCat::Control),
+    (0x000A, 0x000A, GraphemeCat::LF),
+    (0x000B, 0x000C, GraphemeCat::Control),
+    (0x000D, 0x000D, GraphemeCat::CR),
+    (0x000E, 0x001F, GraphemeCat::Control),
+    (0x007F, 0x009F, GraphemeCat::Control),
+    (0x00AD, 0x00AD, GraphemeCat::Control),
+    (0x0300, 0x036F, GraphemeCat::Extend),
+    (0x0483, 0x0489, GraphemeCat::Extend),
+    (0x0591, 0x05BD, GraphemeCat::Extend),
+    (0x05BF, 0x05BF, GraphemeCat::Extend),
+    (0x05C1, 0x05C2, GraphemeCat::Extend),
+    (0x05C4, 0x05C5, GraphemeCat::Extend),
+    (0x05C7, 0x05C7, GraphemeCat::Extend),
+    (0x0600, 0x0605, GraphemeCat::Prepend),
+    (0x0610, 0x061A, GraphemeCat::Extend),
+    (0x061C, 0x061C, GraphemeCat::Control),
+    (0x064B, 0x065F, GraphemeCat::Extend),
+    (0x0670, 0x0670, GraphemeCat::Extend),
+    (0x06D6, 0x06DC, GraphemeCat::Extend),
+    (0x06DD, 0x06DD, GraphemeCat::Prepend),
+    (0x06DF, 0x06E4, GraphemeCat::Extend),
+    (0x06E7, 0x06E8, GraphemeCat::Extend),
+    (0x06EA, 0x06ED, GraphemeCat::Extend),
+    (0x070F, 0x070F, GraphemeCat::Prepend),
+    (0x0711, 0x0711, GraphemeCat::Extend),
+    (0x0730, 0x074A, GraphemeCat::Extend),
+    (0x07A6, 0x07B0, GraphemeCat::Extend),
+    (0x07EB, 0x07F3, GraphemeCat::Extend),
+    (0x07FD, 0x07FD, GraphemeCat::Extend),
+    (0x0816, 0x0819, GraphemeCat::Extend),
+    (0x081B, 0x0823, GraphemeCat::Extend),
+    (0x0825, 0x0827, GraphemeCat::Extend),
+    (0x0829, 0x082D, GraphemeCat::Extend),
+    (0x0859, 0x085B, GraphemeCat::Extend),
+    (0x0890, 0x0891, GraphemeCat::Prepend),
+    (0x0898, 0x089F, GraphemeCat::Extend),
+    (0x08CA, 0x08E1, GraphemeCat::Extend),
+    (0x08E2, 0x08E2, GraphemeCat::Prepend),
+    (0x08E3, 0x0902, GraphemeCat::Extend),
+    (0x0903, 0x0903, GraphemeCat::SpacingMark),
+    (0x093A, 0x093A, GraphemeCat::Extend),
+    (0x093B, 0x093B, GraphemeCat::SpacingMark),
+    (0x093C, 0x093C, GraphemeCat::Extend),
+    (0x093E, 0x0940, GraphemeCat::SpacingMark),
+    (0x0941, 0x0948, GraphemeCat::Extend),
+    (0x0949, 0x094C, GraphemeCat::SpacingMark),
+    (0x094D, 0x094D, GraphemeCat::Extend),
+    (0x094E, 0x094F, GraphemeCat::SpacingMark),
+    (0x0951, 0x0957, GraphemeCat::Extend),
+    (0x0962, 0x0963, GraphemeCat::Extend),
+    (0x0981, 0x0981, GraphemeCat::Extend),
+    (0x0982, 0x0983, GraphemeCat::SpacingMark),
+    (0x09BC, 0x09BC, GraphemeCat::Extend),
+    (0x09BE, 0x09BE, GraphemeCat::Extend),
+    (0x09BF, 0x09C0, GraphemeCat::SpacingMark),
+    (0x09C1, 0x09C4, GraphemeCat::Extend),
+    (0x09C7, 0x09C8, GraphemeCat::SpacingMark),
+    (0x09CB, 0x09CC, GraphemeCat::SpacingMark),
+    (0x09CD, 0x09CD, GraphemeCat::Extend),
+    (0x09D7, 0x09D7, GraphemeCat::Extend),
+    (0x09E2, 0x09E3, GraphemeCat::Extend),
+    (0x09FE, 0x09FE, GraphemeCat::Extend),
+    (0x0A01, 0x0A02, GraphemeCat::Extend),
+    (0x0A03, 0x0A03, GraphemeCat::SpacingMark),
+    (0x0A3C, 0x0A3C, GraphemeCat::Extend),
+    (0x0A3E, 0x0A40, GraphemeCat::SpacingMark),
+    (0x0A41, 0x0A42, GraphemeCat::Extend),
+    (0x0A47, 0x0A48, GraphemeCat::Extend),
+    (0x0A4B, 0x0A4D, GraphemeCat::Extend),
+    (0x0A51, 0x0A51, GraphemeCat::Extend),
+    (0x0A70, 0x0A71, GraphemeCat::Extend),
+    (0x0A75, 0x0A75, GraphemeCat::Extend),
+    (0x0A81, 0x0A82, GraphemeCat::Extend),
+    (0x0A83, 0x0A83, GraphemeCat::SpacingMark),
+    (0x0ABC, 0x0ABC, GraphemeCat::Extend),
+    (0x0ABE, 0x0AC0, GraphemeCat::SpacingMark),
+    (0x0AC1, 0x0AC5, GraphemeCat::Extend),
+    (0x0AC7, 0x0AC8, GraphemeCat::Extend),
+    (0x0AC9, 0x0AC9, GraphemeCat::SpacingMark),
+    (0x0ACB, 0x0ACC, GraphemeCat::SpacingMark),
+    (0x0ACD, 0x0ACD, GraphemeCat::Extend),
+    (0x0AE2, 0x0AE3, GraphemeCat::Extend),
+    (0x0AFA, 0x0AFF, GraphemeCat::Extend),
+    (0x0B01, 0x0B01, GraphemeCat::Extend),
+    (0x0B02, 0x0B03, GraphemeCat::SpacingMark),
+    (0x0B3C, 0x0B3C, GraphemeCat::Extend),
+    (0x0B3E, 0x0B3F, GraphemeCat::Extend),
+    (0x0B40, 0x0B40, GraphemeCat::SpacingMark),
+    (0x0B41, 0x0B44, GraphemeCat::Extend),
+    (0x0B47, 0x0B48, GraphemeCat::SpacingMark),
+    (0x0B4B, 0x0B4C, GraphemeCat::SpacingMark),
+    (0x0B4D, 0x0B4D, GraphemeCat::Extend),
+    (0x0B55, 0x0B57, GraphemeCat::Extend),
+    (0x0B62, 0x0B63, GraphemeCat::Extend),
+    (0x0B82, 0x0B82, GraphemeCat::Extend),
+    (0x0BBE, 0x0BBE, GraphemeCat::Extend),
+    (0x0BBF, 0x0BBF, GraphemeCat::SpacingMark),
+    (0x0BC0, 0x0BC0, GraphemeCat::Extend),
+    (0x0BC1, 0x0BC2, GraphemeCat::SpacingMark),
+    (0x0BC6, 0x0BC8, GraphemeCat::SpacingMark),
+    (0x0BCA, 0x0BCC, GraphemeCat::SpacingMark),
+    (0x0BCD, 0x0BCD, GraphemeCat::Extend),
+    (0x0BD7, 0x0BD7, GraphemeCat::Extend),
+    (0x0C00, 0x0C00, GraphemeCat::Extend),
+    (0x0C01, 0x0C03, GraphemeCat::SpacingMark),
+    (0x0C04, 0x0C04, GraphemeCat::Extend),
+    (0x0C3C, 0x0C3C, GraphemeCat::Extend),
+    (0x0C3E, 0x0C40, GraphemeCat::Extend),
+    (0x0C41, 0x0C44, GraphemeCat::SpacingMark),
+    (0x0C46, 0x0C48, GraphemeCat::Extend),
+    (0x0C4A, 0x0C4D, GraphemeCat::Extend),
+    (0x0C55, 0x0C56, GraphemeCat::Extend),
+    (0x0C62, 0x0C63, GraphemeCat::Extend),
+    (0x0C81, 0x0C81, GraphemeCat::Extend),
+    (0x0C82, 0x0C83, GraphemeCat::SpacingMark),
+    (0x0CBC, 0x0CBC, GraphemeCat::Extend),
+    (0x0CBE, 0x0CBE, GraphemeCat::SpacingMark),
+    (0x0CBF, 0x0CBF, GraphemeCat::Extend),
+    (0x0CC0, 0x0CC1, GraphemeCat::SpacingMark),
+    (0x0CC2, 0x0CC2, GraphemeCat::Extend),
+    (0x0CC3, 0x0CC4, GraphemeCat::SpacingMark),
+    (0x0CC6, 0x0CC6, GraphemeCat::Extend),
+    (0x0CC7, 0x0CC8, GraphemeCat::SpacingMark),
+    (0x0CCA, 0x0CCB, GraphemeCat::SpacingMark),
+    (0x0CCC, 0x0CCD, GraphemeCat::Extend),
+    (0x0CD5, 0x0CD6, GraphemeCat::Extend),
+    (0x0CE2, 0x0CE3, GraphemeCat::Extend),
+    (0x0D00, 0x0D01, GraphemeCat::Extend),
+    (0x0D02, 0x0D03, GraphemeCat::SpacingMark),
+    (0x0D3B, 0x0D3C, GraphemeCat::Extend),
+    (0x0D3E, 0x0D3E, GraphemeCat::Extend),
+    (0x0D3F, 0x0D40, GraphemeCat::SpacingMark),
+    (0x0D41, 0x0D44, GraphemeCat::Extend),
+    (0x0D46, 0x0D48, GraphemeCat::SpacingMark),
+    (0x0D4A, 0x0D4C, GraphemeCat::SpacingMark),
+    (0x0D4D, 0x0D4D, GraphemeCat::Extend),
+    (0x0D4E, 0x0D4E, GraphemeCat::Prepend),
+    (0x0D57, 0x0D57, GraphemeCat::Extend),
+    (0x0D62, 0x0D63, GraphemeCat::Extend),
+    (0x0D81, 0x0D81, GraphemeCat::Extend),
+    (0x0D82, 0x0D83, GraphemeCat::SpacingMark),
+    (0x0DCA, 0x0DCA, GraphemeCat::Extend),
+    (0x0DCF, 0x0DCF, GraphemeCat::Extend),
+    (0x0DD0, 0x0DD1, GraphemeCat::SpacingMark),
+    (0x0DD2, 0x0DD4, GraphemeCat::Extend),
+    (0x0DD6, 0x0DD6, GraphemeCat::Extend),
+    (0x0DD8, 0x0DDE, GraphemeCat::SpacingMark),
+    (0x0DDF, 0x0DDF, GraphemeCat::Extend),
+    (0x0DF2, 0x0DF3, GraphemeCat::SpacingMark),
+    (0x0E31, 0x0E31, GraphemeCat::Extend),
+    (0x0E33, 0x0E33, GraphemeCat::SpacingMark),
+    (0x0E34, 0x0E3A, GraphemeCat::Extend),
+    (0x0E47, 0x0E4E, GraphemeCat::Extend),
+    (0x0EB1, 0x0EB1, GraphemeCat::Extend),
+    (0x0EB3, 0x0EB3, GraphemeCat::SpacingMark),
+    (0x0EB4, 0x0EBC, GraphemeCat::Extend),
+    (0x0EC8, 0x0ECD, GraphemeCat::Extend),
+    (0x0F18, 0x0F19, GraphemeCat::Extend),
+    (0x0F35, 0x0F35, GraphemeCat::Extend),
+    (0x0F37, 0x0F37, GraphemeCat::Extend),
+    (0x0F39, 0x0F39, GraphemeCat::Extend),
+    (0x0F3E, 0x0F3F, GraphemeCat::SpacingMark),
+    (0x0F71, 0x0F7E, GraphemeCat::Extend),
+    (0x0F7F, 0x0F7F, GraphemeCat::SpacingMark),
+    (0x0F80, 0x0F84, GraphemeCat::Extend),
+    (0x0F86, 0x0F87, GraphemeCat::Extend),
+    (0x0F8D, 0x0F97, GraphemeCat::Extend),
+    (0x0F99, 0x0FBC, GraphemeCat::Extend),
+    (0x0FC6, 0x0FC6, GraphemeCat::Extend),
+    (0x102D, 0x1030, GraphemeCat::Extend),
+    (0x1031, 0x1031, GraphemeCat::SpacingMark),
+    (0x1032, 0x1037, GraphemeCat::Extend),
+    (0x1039, 0x103A, GraphemeCat::Extend),
+    (0x103B, 0x103C, GraphemeCat::SpacingMark),
+    (0x103D, 0x103E, GraphemeCat::Extend),
+    (0x1056, 0x1057, GraphemeCat::SpacingMark),
+    (0x1058, 0x1059, GraphemeCat::Extend),
+    (0x105E, 0x1060, GraphemeCat::Extend),
+    (0x1071, 0x1074, GraphemeCat::Extend),
+    (0x1082, 0x1082, GraphemeCat::Extend),
+    (0x1084, 0x1084, GraphemeCat::SpacingMark),
+    (0x1085, 0x1086, GraphemeCat::Extend),
+    (0x108D, 0x108D, GraphemeCat::Extend),
+    (0x109D, 0x109D, GraphemeCat::Extend),
+    (0x1100, 0x115F, GraphemeCat::L),
+    (0x1160, 0x11A7, GraphemeCat::V),
+    (0x11A8, 0x11FF, GraphemeCat::T),
+    (0x135D, 0x135F, GraphemeCat::Extend),
+    (0x1712, 0x1714, GraphemeCat::Extend),
+    (0x1715, 0x1715, GraphemeCat::SpacingMark),
+    (0x1732, 0x1733, GraphemeCat::Extend),
+    (0x1734, 0x1734, GraphemeCat::SpacingMark),
+    (0x1752, 0x1753, GraphemeCat::Extend),
+    (0x1772, 0x1773, GraphemeCat::Extend),
+    (0x17B4, 0x17B5, GraphemeCat::Extend),
+    (0x17B6, 0x17B6, GraphemeCat::SpacingMark),
+    (0x17B7, 0x17BD, GraphemeCat::Extend),
+    (0x17BE, 0x17C5, GraphemeCat::SpacingMark),
+    (0x17C6, 0x17C6, GraphemeCat::Extend),
+    (0x17C7, 0x17C8, GraphemeCat::SpacingMark),
+    (0x17C9, 0x17D3, GraphemeCat::Extend),
+    (0x17DD, 0x17DD, GraphemeCat::Extend),
+    (0x180B, 0x180D, GraphemeCat::Extend),
+    (0x180E, 0x180E, GraphemeCat::Control),
+    (0x180F, 0x180F, GraphemeCat::Extend),
+    (0x1885, 0x1886, GraphemeCat::Extend),
+    (0x18A9, 0x18A9, GraphemeCat::Extend),
+    (0x1920, 0x1922, GraphemeCat::Extend),
+    (0x1923, 0x1926, GraphemeCat::SpacingMark),
+    (0x1927, 0x1928, GraphemeCat::Extend),
+    (0x1929, 0x192B, GraphemeCat::SpacingMark),
+    (0x1930, 0x1931, GraphemeCat::SpacingMark),
+    (0x1932, 0x1932, GraphemeCat::Extend),
+    (0x1933, 0x1938, GraphemeCat::SpacingMark),
+    (0x1939, 0x193B, GraphemeCat::Extend),
+    (0x1A17, 0x1A18, GraphemeCat::Extend),
+    (0x1A19, 0x1A1A, GraphemeCat::SpacingMark),
+    (0x1A1B, 0x1A1B, GraphemeCat::Extend),
+    (0x1A55, 0x1A55, GraphemeCat::SpacingMark),
+    (0x1A56, 0x1A56, GraphemeCat::Extend),
+    (0x1A57, 0x1A57, GraphemeCat::SpacingMark),
+    (0x1A58, 0x1A5E, GraphemeCat::Extend),
+    (0x1A60, 0x1A60, GraphemeCat::Extend),
+    (0x1A62, 0x1A62, GraphemeCat::Extend),
+    (0x1A65, 0x1A6C, GraphemeCat::Extend),
+    (0x1A6D, 0x1A72, GraphemeCat::SpacingMark),
+    (0x1A73, 0x1A7C, GraphemeCat::Extend),
+    (0x1A7F, 0x1A7F, GraphemeCat::Extend),
+    (0x1AB0, 0x1ACE, GraphemeCat::Extend),
+    (0x1B00, 0x1B03, GraphemeCat::Extend),
+    (0x1B04, 0x1B04, GraphemeCat::SpacingMark),
+    (0x1B34, 0x1B3A, GraphemeCat::Extend),
+    (0x1B3B, 0x1B3B, GraphemeCat::SpacingMark),
+    (0x1B3C, 0x1B3C, GraphemeCat::Extend),
+    (0x1B3D, 0x1B41, GraphemeCat::SpacingMark),
+    (0x1B42, 0x1B42, GraphemeCat::Extend),
+    (0x1B43, 0x1B44, GraphemeCat::SpacingMark),
+    (0x1B6B, 0x1B73, GraphemeCat::Extend),
+    (0x1B80, 0x1B81, GraphemeCat::Extend),
+    (0x1B82, 0x1B82, GraphemeCat::SpacingMark),
+    (0x1BA1, 0x1BA1, GraphemeCat::SpacingMark),
+    (0x1BA2, 0x1BA5, GraphemeCat::Extend),
+    (0x1BA6, 0x1BA7, GraphemeCat::SpacingMark),
+    (0x1BA8, 0x1BA9, GraphemeCat::Extend),
+    (0x1BAA, 0x1BAA, GraphemeCat::SpacingMark),
+    (0x1BAB, 0x1BAD, GraphemeCat::Extend),
+    (0x1BE6, 0x1BE6, GraphemeCat::Extend),
+    (0x1BE7, 0x1BE7, GraphemeCat::SpacingMark),
+    (0x1BE8, 0x1BE9, GraphemeCat::Extend),
+    (0x1BEA, 0x1BEC, GraphemeCat::SpacingMark),
+    (0x1BED, 0x1BED, GraphemeCat::Extend),
+    (0x1BEE, 0x1BEE, GraphemeCat::SpacingMark),
+    (0x1BEF, 0x1BF1, GraphemeCat::Extend),
+    (0x1BF2, 0x1BF3, GraphemeCat::SpacingMark),
+    (0x1C24, 0x1C2B, GraphemeCat::SpacingMark),
+    (0x1C2C, 0x1C33, GraphemeCat::Extend),
+    (0x1C34, 0x1C35, GraphemeCat::SpacingMark),
+    (0x1C36, 0x1C37, GraphemeCat::Extend),
+    (0x1CD0, 0x1CD2, GraphemeCat::Extend),
+    (0x1CD4, 0x1CE0, GraphemeCat::Extend),
+    (0x1CE1, 0x1CE1, GraphemeCat::SpacingMark),
+    (0x1CE2, 0x1CE8, GraphemeCat::Extend),
+    (0x1CED, 0x1CED, GraphemeCat::Extend),
+    (0x1CF4, 0x1CF4, GraphemeCat::Extend),
+    (0x1CF7, 0x1CF7, GraphemeCat::SpacingMark),
+    (0x1CF8, 0x1CF9, GraphemeCat::Extend),
+    (0x1DC0, 0x1DFF, GraphemeCat::Extend),
+    (0x200B, 0x200B, GraphemeCat::Control),
+    (0x200C, 0x200C, GraphemeCat::Extend),
+    (0x200D, 0x200D, GraphemeCat::ZWJ),
+    (0x200E, 0x200F, GraphemeCat::Control),
+    (0x2028, 0x202E, GraphemeCat::Control),
+    (0x2060, 0x206F, GraphemeCat::Control),
+    (0x20D0, 0x20F0, GraphemeCat::Extend),
+    (0x2CEF, 0x2CF1, GraphemeCat::Extend),
+    (0x2D7F, 0x2D7F, GraphemeCat::Extend),
+    (0x2DE0, 0x2DFF, GraphemeCat::Extend),
+    (0x302A, 0x302F, GraphemeCat::Extend),
+    (0x3099, 0x309A, GraphemeCat::Extend),
+    (0xA66F, 0xA672, GraphemeCat::Extend),
+    (0xA674, 0xA67D, GraphemeCat::Extend),
+    (0xA69E, 0xA69F, GraphemeCat::Extend),
+    (0xA6F0, 0xA6F1, GraphemeCat::Extend),
+    (0xA802, 0xA802, GraphemeCat::Extend),
+    (0xA806, 0xA806, GraphemeCat::Extend),
+    (0xA80B, 0xA80B, GraphemeCat::Extend),
+    (0xA823, 0xA824, GraphemeCat::SpacingMark),
+    (0xA825, 0xA826, GraphemeCat::Extend),
+    (0xA827, 0xA827, GraphemeCat::SpacingMark),
+    (0xA82C, 0xA82C, GraphemeCat::Extend),
+    (0xA880, 0xA881, GraphemeCat::SpacingMark),
+    (0xA8B4, 0xA8C3, GraphemeCat::SpacingMark),
+    (0xA8C4, 0xA8C5, GraphemeCat::Extend),
+    (0xA8E0, 0xA8F1, GraphemeCat::Extend),
+    (0xA8FF, 0xA8FF, GraphemeCat::Extend),
+    (0xA926, 0xA92D, GraphemeCat::Extend),
+    (0xA947, 0xA951, GraphemeCat::Extend),
+    (0xA952, 0xA953, GraphemeCat::SpacingMark),
+    (0xA960, 0xA97C, GraphemeCat::L),
+    (0xA980, 0xA982, GraphemeCat::Extend),
+    (0xA983, 0xA983, GraphemeCat::SpacingMark),
+    (0xA9B3, 0xA9B3, GraphemeCat::Extend),
+    (0xA9B4, 0xA9B5, GraphemeCat::SpacingMark),
+    (0xA9B6, 0xA9B9, GraphemeCat::Extend),
+    (0xA9BA, 0xA9BB, GraphemeCat::SpacingMark),
+    (0xA9BC, 0xA9BD, GraphemeCat::Extend),
+    (0xA9BE, 0xA9C0, GraphemeCat::SpacingMark),
+    (0xA9E5, 0xA9E5, GraphemeCat::Extend),
+    (0xAA29, 0xAA2E, GraphemeCat::Extend),
+    (0xAA2F, 0xAA30, GraphemeCat::SpacingMark),
+    (0xAA31, 0xAA32, GraphemeCat::Extend),
+    (0xAA33, 0xAA34, GraphemeCat::SpacingMark),
+    (0xAA35, 0xAA36, GraphemeCat::Extend),
+    (0xAA43, 0xAA43, GraphemeCat::Extend),
+    (0xAA4C, 0xAA4C, GraphemeCat::Extend),
+    (0xAA4D, 0xAA4D, GraphemeCat::SpacingMark),
+    (0xAA7C, 0xAA7C, GraphemeCat::Extend),
+    (0xAAB0, 0xAAB0, GraphemeCat::Extend),
+    (0xAAB2, 0xAAB4, GraphemeCat::Extend),
+    (0xAAB7, 0xAAB8, GraphemeCat::Extend),
+    (0xAABE, 0xAABF, GraphemeCat::Extend),
+    (0xAAC1, 0xAAC1, GraphemeCat::Extend),
+    (0xAAEB, 0xAAEB, GraphemeCat::SpacingMark),
+    (0xAAEC, 0xAAED, GraphemeCat::Extend),
+    (0xAAEE, 0xAAEF, GraphemeCat::SpacingMark),
+    (0xAAF5, 0xAAF5, GraphemeCat::SpacingMark),
+    (0xAAF6, 0xAAF6, GraphemeCat::Extend),
+    (0xABE3, 0xABE4, GraphemeCat::SpacingMark),
+    (0xABE5, 0xABE5, GraphemeCat::Extend),
+    (0xABE6, 0xABE7, GraphemeCat::SpacingMark),
+    (0xABE8, 0xABE8, GraphemeCat::Extend),
+    (0xABE9, 0xABEA, GraphemeCat::SpacingMark),
+    (0xABEC, 0xABEC, GraphemeCat::SpacingMark),
+    (0xABED, 0xABED, GraphemeCat::Extend),
+    (0xAC00, 0xAC00, GraphemeCat::LV),
+    (0xAC01, 0xAC1B, GraphemeCat::LVT),
+    (0xAC1C, 0xAC1C, GraphemeCat::LV),
+    (0xAC1D, 0xAC37, GraphemeCat::LVT),
+    (0xAC38, 0xAC38, GraphemeCat::LV),
+    (0xAC39, 0xAC53, GraphemeCat::LVT),
+    (0xAC54, 0xAC54, GraphemeCat::LV),
+    (0xAC55, 0xAC6F, GraphemeCat::LVT),
+    (0xAC70, 0xAC70, GraphemeCat::LV),
+    (0xAC71, 0xAC8B, GraphemeCat::LVT),
+    (0xAC8C, 0xAC8C, GraphemeCat::LV),
+    (0xAC8D, 0xACA7, GraphemeCat::LVT),
+    (0xACA8, 0xACA8, GraphemeCat::LV),
+    (0xACA9, 0xACC3, GraphemeCat::LVT),
+    (0xACC4, 0xACC4, GraphemeCat::LV),
+    (0xACC5, 0xACDF, GraphemeCat::LVT),
+    (0xACE0, 0xACE0, GraphemeCat::LV),
+    (0xACE1, 0xACFB, GraphemeCat::LVT),
+    (0xACFC, 0xACFC, GraphemeCat::LV),
+    (0xACFD, 0xAD17, GraphemeCat::LVT),
+    (0xAD18, 0xAD18, GraphemeCat::LV),
+    (0xAD19, 0xAD33, GraphemeCat::LVT),
+    (0xAD34, 0xAD34, GraphemeCat::LV),
+    (0xAD35, 0xAD4F, GraphemeCat::LVT),
+    (0xAD50, 0xAD50, GraphemeCat::LV),
+    (0xAD51, 0xAD6B, GraphemeCat::LVT),
+    (0xAD6C, 0xAD6C, GraphemeCat::LV),
+    (0xAD6D, 0xAD87, GraphemeCat::LVT),
+    (0xAD88, 0xAD88, GraphemeCat::LV),
+    (0xAD89, 0xADA3, GraphemeCat::LVT),
+    (0xADA4, 0xADA4, GraphemeCat::LV),
+    (0xADA5, 0xADBF, GraphemeCat::LVT),
+    (0xADC0, 0xADC0, GraphemeCat::LV),
+    (0xADC1, 0xADDB, GraphemeCat::LVT),
+    (0xADDC, 0xADDC, GraphemeCat::LV),
+    (0xADDD, 0xADF7, GraphemeCat::LVT),
+    (0xADF8, 0xADF8, GraphemeCat::LV),
+    (0xADF9, 0xAE13, GraphemeCat::LVT),
+    (0xAE14, 0xAE14, GraphemeCat::LV),
+    (0xAE15, 0xAE2F, GraphemeCat::LVT),
+    (0xAE30, 0xAE30, GraphemeCat::LV),
+    (0xAE31, 0xAE4B, GraphemeCat::LVT),
+    (0xAE4C, 0xAE4C, GraphemeCat::LV),
+    (0xAE4D, 0xAE67, GraphemeCat::LVT),
+    (0xAE68, 0xAE68, GraphemeCat::LV),
+    (0xAE69, 0xAE83, GraphemeCat::LVT),
+    (0xAE84, 0xAE84, GraphemeCat::LV),
+    (0xAE85, 0xAE9F, GraphemeCat::LVT),
+    (0xAEA0, 0xAEA0, GraphemeCat::LV),
+    (0xAEA1, 0xAEBB, GraphemeCat::LVT),
+    (0xAEBC, 0xAEBC, GraphemeCat::LV),
+    (0xAEBD, 0xAED7, GraphemeCat::LVT),
+    (0xAED8, 0xAED8, GraphemeCat::LV),
+    (0xAED9, 0xAEF3, GraphemeCat::LVT),
+    (0xAEF4, 0xAEF4, GraphemeCat::LV),
+    (0xAEF5, 0xAF0F, GraphemeCat::LVT),
+    (0xAF10, 0xAF10, GraphemeCat::LV),
+    (0xAF11, 0xAF2B, GraphemeCat::LVT),
+    (0xAF2C, 0xAF2C, GraphemeCat::LV),
+    (0xAF2D, 0xAF47, GraphemeCat::LVT),
+    (0xAF48, 0xAF48, GraphemeCat::LV),
+    (0xAF49, 0xAF63, GraphemeCat::LVT),
+    (0xAF64, 0xAF64, GraphemeCat::LV),
+    (0xAF65, 0xAF7F, GraphemeCat::LVT),
+    (0xAF80, 0xAF80, GraphemeCat::LV),
+    (0xAF81, 0xAF9B, GraphemeCat::LVT),
+    (0xAF9C, 0xAF9C, GraphemeCat::LV),
+    (0xAF9D, 0xAFB7, GraphemeCat::LVT),
+    (0xAFB8, 0xAFB8, GraphemeCat::LV),
+    (0xAFB9, 0xAFD3, GraphemeCat::LVT),
+    (0xAFD4, 0xAFD4, GraphemeCat::LV),
+    (0xAFD5, 0xAFEF, GraphemeCat::LVT),
+    (0xAFF0, 0xAFF0, GraphemeCat::LV),
+    (0xAFF1, 0xB00B, GraphemeCat::LVT),
+    (0xB00C, 0xB00C, GraphemeCat::LV),
+    (0xB00D, 0xB027, GraphemeCat::LVT),
+    (0xB028, 0xB028, GraphemeCat::LV),
+    (0xB029, 0xB043, GraphemeCat::LVT),
+    (0xB044, 0xB044, GraphemeCat::LV),
+    (0xB045, 0xB05F, GraphemeCat::LVT),
+    (0xB060, 0xB060, GraphemeCat::LV),
+    (0xB061, 0xB07B, GraphemeCat::LVT),
+    (0xB07C, 0xB07C, GraphemeCat::LV),
+    (0xB07D, 0xB097, GraphemeCat::LVT),
+    (0xB098, 0xB098, GraphemeCat::LV),
+    (0xB099, 0xB0B3, GraphemeCat::LVT),
+    (0xB0B4, 0xB0B4, GraphemeCat::LV),
+    (0xB0B5, 0xB0CF, GraphemeCat::LVT),
+    (0xB0D0, 0xB0D0, GraphemeCat::LV),
+    (0xB0D1, 0xB0EB, GraphemeCat::LVT),
+    (0xB0EC, 0xB0EC, GraphemeCat::LV),
+    (0xB0ED, 0xB107, GraphemeCat::LVT),
+    (0xB108, 0xB108, GraphemeCat::LV),
+    (0xB109, 0xB123, GraphemeCat::LVT),
+    (0xB124, 0xB124, GraphemeCat::LV),
+    (0xB125, 0xB13F, GraphemeCat::LVT),
+    (0xB140, 0xB140, GraphemeCat::LV),
+    (0xB141, 0xB15B, GraphemeCat::LVT),
+    (0xB15C, 0xB15C, GraphemeCat::LV),
+    (0xB15D, 0xB177, GraphemeCat::LVT),
+    (0xB178, 0xB178, GraphemeCat::LV),
+    (0xB179, 0xB193, GraphemeCat::LVT),
+    (0xB194, 0xB194, GraphemeCat::LV),
+    (0xB195, 0xB1AF, GraphemeCat::LVT),
+    (0xB1B0, 0xB1B0, GraphemeCat::LV),
+    (0xB1B1, 0xB1CB, GraphemeCat::LVT),
+    (0xB1CC, 0xB1CC, GraphemeCat::LV),
+    (0xB1CD, 0xB1E7, GraphemeCat::LVT),
+    (0xB1E8, 0xB1E8, GraphemeCat::LV),
+    (0xB1E9, 0xB203, GraphemeCat::LVT),
+    (0xB204, 0xB204, GraphemeCat::LV),
+    (0xB205, 0xB21F, GraphemeCat::LVT),
+    (0xB220, 0xB220, GraphemeCat::LV),
+    (0xB221, 0xB23B, GraphemeCat::LVT),
+    (0xB23C, 0xB23C, GraphemeCat::LV),
+    (0xB23D, 0xB257, GraphemeCat::LVT),
+    (0xB258, 0xB258, GraphemeCat::LV),
+    (0xB259, 0xB273, GraphemeCat::LVT),
+    (0xB274, 0xB274, GraphemeCat::LV),
+    (0xB275, 0xB28F, GraphemeCat::LVT),
+    (0xB290, 0xB290, GraphemeCat::LV),
+    (0xB291, 0xB2AB, GraphemeCat::LVT),
+    (0xB2AC, 0xB2AC, GraphemeCat::LV),
+    (0xB2AD, 0xB2C7, GraphemeCat::LVT),
+    (0xB2C8, 0xB2C8, GraphemeCat::LV),
+    (0xB2C9, 0xB2E3, GraphemeCat::LVT),
+    (0xB2E4, 0xB2E4, GraphemeCat::LV),
+    (0xB2E5, 0xB2FF, GraphemeCat::LVT),
+    (0xB300, 0xB300, GraphemeCat::LV),
+    (0xB301, 0xB31B, GraphemeCat::LVT),
+    (0xB31C, 0xB31C, GraphemeCat::LV),
+    (0xB31D, 0xB337, GraphemeCat::LVT),
+    (0xB338, 0xB338, GraphemeCat::LV),
+    (0xB339, 0xB353, GraphemeCat::LVT),
+    (0xB354, 0xB354, GraphemeCat::LV),
+    (0xB355, 0xB36F, GraphemeCat::LVT),
+    (0xB370, 0xB370, GraphemeCat::LV),
+    (0xB371, 0xB38B, GraphemeCat::LVT),
+    (0xB38C, 0xB38C, GraphemeCat::LV),
+    (0xB38D, 0xB3A7, GraphemeCat::LVT),
+    (0xB3A8, 0xB3A8, GraphemeCat::LV),
+    (0xB3A9, 0xB3C3, GraphemeCat::LVT),
+    (0xB3C4, 0xB3C4, GraphemeCat::LV),
+    (0xB3C5, 0xB3DF, GraphemeCat::LVT),
+    (0xB3E0, 0xB3E0, GraphemeCat::LV),
+    (0xB3E1, 0xB3FB, GraphemeCat::LVT),
+    (0xB3FC, 0xB3FC, GraphemeCat::LV),
+    (0xB3FD, 0xB417, GraphemeCat::LVT),
+    (0xB418, 0xB418, GraphemeCat::LV),
+    (0xB419, 0xB433, GraphemeCat::LVT),
+    (0xB434, 0xB434, GraphemeCat::LV),
+    (0xB435, 0xB44F, GraphemeCat::LVT),
+    (0xB450, 0xB450, GraphemeCat::LV),
+    (0xB451, 0xB46B, GraphemeCat::LVT),
+    (0xB46C, 0xB46C, GraphemeCat::LV),
+    (0xB46D, 0xB487, GraphemeCat::LVT),
+    (0xB488, 0xB488, GraphemeCat::LV),
+    (0xB489, 0xB4A3, GraphemeCat::LVT),
+    (0xB4A4, 0xB4A4, GraphemeCat::LV),
+    (0xB4A5, 0xB4BF, GraphemeCat::LVT),
+    (0xB4C0, 0xB4C0, GraphemeCat::LV),
+    (0xB4C1, 0xB4DB, GraphemeCat::LVT),
+    (0xB4DC, 0xB4DC, GraphemeCat::LV),
+    (0xB4DD, 0xB4F7, GraphemeCat::LVT),
+    (0xB4F8, 0xB4F8, GraphemeCat::LV),
+    (0xB4F9, 0xB513, GraphemeCat::LVT),
+    (0xB514, 0xB514, GraphemeCat::LV),
+    (0xB515, 0xB52F, GraphemeCat::LVT),
+    (0xB530, 0xB530, GraphemeCat::LV),
+    (0xB531, 0xB54B, GraphemeCat::LVT),
+    (0xB54C, 0xB54C, GraphemeCat::LV),
+    (0xB54D, 0xB567, GraphemeCat::LVT),
+    (0xB568, 0xB568, GraphemeCat::LV),
+    (0xB569, 0xB583, GraphemeCat::LVT),
+    (0xB584, 0xB584, GraphemeCat::LV),
+    (0xB585, 0xB59F, GraphemeCat::LVT),
+    (0xB5A0, 0xB5A0, GraphemeCat::LV),
+    (0xB5A1, 0xB5BB, GraphemeCat::LVT),
+    (0xB5BC, 0xB5BC, GraphemeCat::LV),
+    (0xB5BD, 0xB5D7, GraphemeCat::LVT),
+    (0xB5D8, 0xB5D8, GraphemeCat::LV),
+    (0xB5D9, 0xB5F3, GraphemeCat::LVT),
+    (0xB5F4, 0xB5F4, GraphemeCat::LV),
+    (0xB5F5, 0xB60F, GraphemeCat::LVT),
+    (0xB610, 0xB610, GraphemeCat::LV),
+    (0xB611, 0xB62B, GraphemeCat::LVT),
+    (0xB62C, 0xB62C, GraphemeCat::LV),
+    (0xB62D, 0xB647, GraphemeCat::LVT),
+    (0xB648, 0xB648, GraphemeCat::LV),
+    (0xB649, 0xB663, GraphemeCat::LVT),
+    (0xB664, 0xB664, GraphemeCat::LV),
+    (0xB665, 0xB67F, GraphemeCat::LVT),
+    (0xB680, 0xB680, GraphemeCat::LV),
+    (0xB681, 0xB69B, GraphemeCat::LVT),
+    (0xB69C, 0xB69C, GraphemeCat::LV),
+    (0xB69D, 0xB6B7, GraphemeCat::LVT),
+    (0xB6B8, 0xB6B8, GraphemeCat::LV),
+    (0xB6B9, 0xB6D3, GraphemeCat::LVT),
+    (0xB6D4, 0xB6D4, GraphemeCat::LV),
+    (0xB6D5, 0xB6EF, GraphemeCat::LVT),
+    (0xB6F0, 0xB6F0, GraphemeCat::LV),
+    (0xB6F1, 0xB70B, GraphemeCat::LVT),
+    (0xB70C, 0xB70C, GraphemeCat::LV),
+    (0xB70D, 0xB727, GraphemeCat::LVT),
+    (0xB728, 0xB728, GraphemeCat::LV),
+    (0xB729, 0xB743, GraphemeCat::LVT),
+    (0xB744, 0xB744, GraphemeCat::LV),
+    (0xB745, 0xB75F, GraphemeCat::LVT),
+    (0xB760, 0xB760, GraphemeCat::LV),
+    (0xB761, 0xB77B, GraphemeCat::LVT),
+    (0xB77C, 0xB77C, GraphemeCat::LV),
+    (0xB77D, 0xB797, GraphemeCat::LVT),
+    (0xB798, 0xB798, GraphemeCat::LV),
+    (0xB799, 0xB7B3, GraphemeCat::LVT),
+    (0xB7B4, 0xB7B4, GraphemeCat::LV),
+    (0xB7B5, 0xB7CF, GraphemeCat::LVT),
+    (0xB7D0, 0xB7D0, GraphemeCat::LV),
+    (0xB7D1, 0xB7EB, GraphemeCat::LVT),
+    (0xB7EC, 0xB7EC, GraphemeCat::LV),
+    (0xB7ED, 0xB807, GraphemeCat::LVT),
+    (0xB808, 0xB808, GraphemeCat::LV),
+    (0xB809, 0xB823, GraphemeCat::LVT),
+    (0xB824, 0xB824, GraphemeCat::LV),
+    (0xB825, 0xB83F, GraphemeCat::LVT),
+    (0xB840, 0xB840, GraphemeCat::LV),
+    (0xB841, 0xB85B, GraphemeCat::LVT),
+    (0xB85C, 0xB85C, GraphemeCat::LV),
+    (0xB85D, 0xB877, GraphemeCat::LVT),
+    (0xB878, 0xB878, GraphemeCat::LV),
+    (0xB879, 0xB893, GraphemeCat::LVT),
+    (0xB894, 0xB894, GraphemeCat::LV),
+    (0xB895, 0xB8AF, GraphemeCat::LVT),
+    (0xB8B0, 0xB8B0, GraphemeCat::LV),
+    (0xB8B1, 0xB8CB, GraphemeCat::LVT),
+    (0xB8CC, 0xB8CC, GraphemeCat::LV),
+    (0xB8CD, 0xB8E7, GraphemeCat::LVT),
+    (0xB8E8, 0xB8E8, GraphemeCat::LV),
+    (0xB8E9, 0xB903, GraphemeCat::LVT),
+    (0xB904, 0xB904, GraphemeCat::LV),
+    (0xB905, 0xB91F, GraphemeCat::LVT),
+    (0xB920, 0xB920, GraphemeCat::LV),
+    (0xB921, 0xB93B, GraphemeCat::LVT),
+    (0xB93C, 0xB93C, GraphemeCat::LV),
+    (0xB93D, 0xB957, GraphemeCat::LVT),
+    (0xB958, 0xB958, GraphemeCat::LV),
+    (0xB959, 0xB973, GraphemeCat::LVT),
+    (0xB974, 0xB974, GraphemeCat::LV),
+    (0xB975, 0xB98F, GraphemeCat::LVT),
+    (0xB990, 0xB990, GraphemeCat::LV),
+    (0xB991, 0xB9AB, GraphemeCat::LVT),
+    (0xB9AC, 0xB9AC, GraphemeCat::LV),
+    (0xB9AD, 0xB9C7, GraphemeCat::LVT),
+    (0xB9C8, 0xB9C8, GraphemeCat::LV),
+    (0xB9C9, 0xB9E3, GraphemeCat::LVT),
+    (0xB9E4, 0xB9E4, GraphemeCat::LV),
+    (0xB9E5, 0xB9FF, GraphemeCat::LVT),
+    (0xBA00, 0xBA00, GraphemeCat::LV),
+    (0xBA01, 0xBA1B, GraphemeCat::LVT),
+    (0xBA1C, 0xBA1C, GraphemeCat::LV),
+    (0xBA1D, 0xBA37, GraphemeCat::LVT),
+    (0xBA38, 0xBA38, GraphemeCat::LV),
+    (0xBA39, 0xBA53, GraphemeCat::LVT),
+    (0xBA54, 0xBA54, GraphemeCat::LV),
+    (0xBA55, 0xBA6F, GraphemeCat::LVT),
+    (0xBA70, 0xBA70, GraphemeCat::LV),
+    (0xBA71, 0xBA8B, GraphemeCat::LVT),
+    (0xBA8C, 0xBA8C, GraphemeCat::LV),
+    (0xBA8D, 0xBAA7, GraphemeCat::LVT),
+    (0xBAA8, 0xBAA8, GraphemeCat::LV),
+    (0xBAA9, 0xBAC3, GraphemeCat::LVT),
+    (0xBAC4, 0xBAC4, GraphemeCat::LV),
+    (0xBAC5, 0xBADF, GraphemeCat::LVT),
+    (0xBAE0, 0xBAE0, GraphemeCat::LV),
+    (0xBAE1, 0xBAFB, GraphemeCat::LVT),
+    (0xBAFC, 0xBAFC, GraphemeCat::LV),
+    (0xBAFD, 0xBB17, GraphemeCat::LVT),
+    (0xBB18, 0xBB18, GraphemeCat::LV),
+    (0xBB19, 0xBB33, GraphemeCat::LVT),
+    (0xBB34, 0xBB34, GraphemeCat::LV),
+    (0xBB35, 0xBB4F, GraphemeCat::LVT),
+    (0xBB50, 0xBB50, GraphemeCat::LV),
+    (0xBB51, 0xBB6B, GraphemeCat::LVT),
+    (0xBB6C, 0xBB6C, GraphemeCat::LV),
+    (0xBB6D, 0xBB87, GraphemeCat::LVT),
+    (0xBB88, 0xBB88, GraphemeCat::LV),
+    (0xBB89, 0xBBA3, GraphemeCat::LVT),
+    (0xBBA4, 0xBBA4, GraphemeCat::LV),
+    (0xBBA5, 0xBBBF, GraphemeCat::LVT),
+    (0xBBC0, 0xBBC0, GraphemeCat::LV),
+    (0xBBC1, 0xBBDB, GraphemeCat::LVT),
+    (0xBBDC, 0xBBDC, GraphemeCat::LV),
+    (0xBBDD, 0xBBF7, GraphemeCat::LVT),
+    (0xBBF8, 0xBBF8, GraphemeCat::LV),
+    (0xBBF9, 0xBC13, GraphemeCat::LVT),
+    (0xBC14, 0xBC14, GraphemeCat::LV),
+    (0xBC15, 0xBC2F, GraphemeCat::LVT),
+    (0xBC30, 0xBC30, GraphemeCat::LV),
+    (0xBC31, 0xBC4B, GraphemeCat::LVT),
+    (0xBC4C, 0xBC4C, GraphemeCat::LV),
+    (0xBC4D, 0xBC67, GraphemeCat::LVT),
+    (0xBC68, 0xBC68, GraphemeCat::LV),
+    (0xBC69, 0xBC83, GraphemeCat::LVT),
+    (0xBC84, 0xBC84, GraphemeCat::LV),
+    (0xBC85, 0xBC9F, GraphemeCat::LVT),
+    (0xBCA0, 0xBCA0, GraphemeCat::LV),
+    (0xBCA1, 0xBCBB, GraphemeCat::LVT),
+    (0xBCBC, 0xBCBC, GraphemeCat::LV),
+    (0xBCBD, 0xBCD7, GraphemeCat::LVT),
+    (0xBCD8, 0xBCD8, GraphemeCat::LV),
+    (0xBCD9, 0xBCF3, GraphemeCat::LVT),
+    (0xBCF4, 0xBCF4, GraphemeCat::LV),
+    (0xBCF5, 0xBD0F, GraphemeCat::LVT),
+    (0xBD10, 0xBD10, GraphemeCat::LV),
+    (0xBD11, 0xBD2B, GraphemeCat::LVT),
+    (0xBD2C, 0xBD2C, GraphemeCat::LV),
+    (0xBD2D, 0xBD47, GraphemeCat::LVT),
+    (0xBD48, 0xBD48, GraphemeCat::LV),
+    (0xBD49, 0xBD63, GraphemeCat::LVT),
+    (0xBD64, 0xBD64, GraphemeCat::LV),
+    (0xBD65, 0xBD7F, GraphemeCat::LVT),
+    (0xBD80, 0xBD80, GraphemeCat::LV),
+    (0xBD81, 0xBD9B, GraphemeCat::LVT),
+    (0xBD9C, 0xBD9C, GraphemeCat::LV),
+    (0xBD9D, 0xBDB7, GraphemeCat::LVT),
+    (0xBDB8, 0xBDB8, GraphemeCat::LV),
+    (0xBDB9, 0xBDD3, GraphemeCat::LVT),
+    (0xBDD4, 0xBDD4, GraphemeCat::LV),
+    (0xBDD5, 0xBDEF, GraphemeCat::LVT),
+    (0xBDF0, 0xBDF0, GraphemeCat::LV),
+    (0xBDF1, 0xBE0B, GraphemeCat::LVT),
+    (0xBE0C, 0xBE0C, GraphemeCat::LV),
+    (0xBE0D, 0xBE27, GraphemeCat::LVT),
+    (0xBE28, 0xBE28, GraphemeCat::LV),
+    (0xBE29, 0xBE43, GraphemeCat::LVT),
+    (0xBE44, 0xBE44, GraphemeCat::LV),
+    (0xBE45, 0xBE5F, GraphemeCat::LVT),
+    (0xBE60, 0xBE60, GraphemeCat::LV),
+    (0xBE61, 0xBE7B, GraphemeCat::LVT),
+    (0xBE7C, 0xBE7C, GraphemeCat::LV),
+    (0xBE7D, 0xBE97, GraphemeCat::LVT),
+    (0xBE98, 0xBE98, GraphemeCat::LV),
+    (0xBE99, 0xBEB3, GraphemeCat::LVT),
+    (0xBEB4, 0xBEB4, GraphemeCat::LV),
+    (0xBEB5, 0xBECF, GraphemeCat::LVT),
+    (0xBED0, 0xBED0, GraphemeCat::LV),
+    (0xBED1, 0xBEEB, GraphemeCat::LVT),
+    (0xBEEC, 0xBEEC, GraphemeCat::LV),
+    (0xBEED, 0xBF07, GraphemeCat::LVT),
+    (0xBF08, 0xBF08, GraphemeCat::LV),
+    (0xBF09, 0xBF23, GraphemeCat::LVT),
+    (0xBF24, 0xBF24, GraphemeCat::LV),
+    (0xBF25, 0xBF3F, GraphemeCat::LVT),
+    (0xBF40, 0xBF40, GraphemeCat::LV),
+    (0xBF41, 0xBF5B, GraphemeCat::LVT),
+    (0xBF5C, 0xBF5C, GraphemeCat::LV),
+    (0xBF5D, 0xBF77, GraphemeCat::LVT),
+    (0xBF78, 0xBF78, GraphemeCat::LV),
+    (0xBF79, 0xBF93, GraphemeCat::LVT),
+    (0xBF94, 0xBF94, GraphemeCat::LV),
+    (0xBF95, 0xBFAF, GraphemeCat::LVT),
+    (0xBFB0, 0xBFB0, GraphemeCat::LV),
+    (0xBFB1, 0xBFCB, GraphemeCat::LVT),
+    (0xBFCC, 0xBFCC, GraphemeCat::LV),
+    (0xBFCD, 0xBFE7, GraphemeCat::LVT),
+    (0xBFE8, 0xBFE8, GraphemeCat::LV),
+    (0xBFE9, 0xC003, GraphemeCat::LVT),
+    (0xC004, 0xC004, GraphemeCat::LV),
+    (0xC005, 0xC01F, GraphemeCat::LVT),
+    (0xC020, 0xC020, GraphemeCat::LV),
+    (0xC021, 0xC03B, GraphemeCat::LVT),
+    (0xC03C, 0xC03C, GraphemeCat::LV),
+    (0xC03D, 0xC057, GraphemeCat::LVT),
+    (0xC058, 0xC058, GraphemeCat::LV),
+    (0xC059, 0xC073, GraphemeCat::LVT),
+    (0xC074, 0xC074, GraphemeCat::LV),
+    (0xC075, 0xC08F, GraphemeCat::LVT),
+    (0xC090, 0xC090, GraphemeCat::LV),
+    (0xC091, 0xC0AB, GraphemeCat::LVT),
+    (0xC0AC, 0xC0AC, GraphemeCat::LV),
+    (0xC0AD, 0xC0C7, GraphemeCat::LVT),
+    (0xC0C8, 0xC0C8, GraphemeCat::LV),
+    (0xC0C9, 0xC0E3, GraphemeCat::LVT),
+    (0xC0E4, 0xC0E4, GraphemeCat::LV),
+    (0xC0E5, 0xC0FF, GraphemeCat::LVT),
+    (0xC100, 0xC100, GraphemeCat::LV),
+    (0xC101, 0xC11B, GraphemeCat::LVT),
+    (0xC11C, 0xC11C, GraphemeCat::LV),
+    (0xC11D, 0xC137, GraphemeCat::LVT),
+    (0xC138, 0xC138, GraphemeCat::LV),
+    (0xC139, 0xC153, GraphemeCat::LVT),
+    (0xC154, 0xC154, GraphemeCat::LV),
+    (0xC155, 0xC16F, GraphemeCat::LVT),
+    (0xC170, 0xC170, GraphemeCat::LV),
+    (0xC171, 0xC18B, GraphemeCat::LVT),
+    (0xC18C, 0xC18C, GraphemeCat::LV),
+    (0xC18D, 0xC1A7, GraphemeCat::LVT),
+    (0xC1A8, 0xC1A8, GraphemeCat::LV),
+    (0xC1A9, 0xC1C3, GraphemeCat::LVT),
+    (0xC1C4, 0xC1C4, GraphemeCat::LV),
+    (0xC1C5, 0xC1DF, GraphemeCat::LVT),
+    (0xC1E0, 0xC1E0, GraphemeCat::LV),
+    (0xC1E1, 0xC1FB, GraphemeCat::LVT),
+    (0xC1FC, 0xC1FC, GraphemeCat::LV),
+    (0xC1FD, 0xC217, GraphemeCat::LVT),
+    (0xC218, 0xC218, GraphemeCat::LV),
+    (0xC219, 0xC233, GraphemeCat::LVT),
+    (0xC234, 0xC234, GraphemeCat::LV),
+    (0xC235, 0xC24F, GraphemeCat::LVT),
+    (0xC250, 0xC250, GraphemeCat::LV),
+    (0xC251, 0xC26B, GraphemeCat::LVT),
+    (0xC26C, 0xC26C, GraphemeCat::LV),
+    (0xC26D, 0xC287, GraphemeCat::LVT),
+    (0xC288, 0xC288, GraphemeCat::LV),
+    (0xC289, 0xC2A3, GraphemeCat::LVT),
+    (0xC2A4, 0xC2A4, GraphemeCat::LV),
+    (0xC2A5, 0xC2BF, GraphemeCat::LVT),
+    (0xC2C0, 0xC2C0, GraphemeCat::LV),
+    (0xC2C1, 0xC2DB, GraphemeCat::LVT),
+    (0xC2DC, 0xC2DC, GraphemeCat::LV),
+    (0xC2DD, 0xC2F7, GraphemeCat::LVT),
+    (0xC2F8, 0xC2F8, GraphemeCat::LV),
+    (0xC2F9, 0xC313, GraphemeCat::LVT),
+    (0xC314, 0xC314, GraphemeCat::LV),
+    (0xC315, 0xC32F, GraphemeCat::LVT),
+    (0xC330, 0xC330, GraphemeCat::LV),
+    (0xC331, 0xC34B, GraphemeCat::LVT),
+    (0xC34C, 0xC34C, GraphemeCat::LV),
+    (0xC34D, 0xC367, GraphemeCat::LVT),
+    (0xC368, 0xC368, GraphemeCat::LV),
+    (0xC369, 0xC383, GraphemeCat::LVT),
+    (0xC384, 0xC384, GraphemeCat::LV),
+    (0xC385, 0xC39F, GraphemeCat::LVT),
+    (0xC3A0, 0xC3A0, GraphemeCat::LV),
+    (0xC3A1, 0xC3BB, GraphemeCat::LVT),
+    (0xC3BC, 0xC3BC, GraphemeCat::LV),
+    (0xC3BD, 0xC3D7, GraphemeCat::LVT),
+    (0xC3D8, 0xC3D8, GraphemeCat::LV),
+    (0xC3D9, 0xC3F3, GraphemeCat::LVT),
+    (0xC3F4, 0xC3F4, GraphemeCat::LV),
+    (0xC3F5, 0xC40F, GraphemeCat::LVT),
+    (0xC410, 0xC410, GraphemeCat::LV),
+    (0xC411, 0xC42B, GraphemeCat::LVT),
+    (0xC42C, 0xC42C, GraphemeCat::LV),
+    (0xC42D, 0xC447, GraphemeCat::LVT),
+    (0xC448, 0xC448, GraphemeCat::LV),
+    (0xC449, 0xC463, GraphemeCat::LVT),
+    (0xC464, 0xC464, GraphemeCat::LV),
+    (0xC465, 0xC47F, GraphemeCat::LVT),
+    (0xC480, 0xC480, GraphemeCat::LV),
+    (0xC481, 0xC49B, GraphemeCat::LVT),
+    (0xC49C, 0xC49C, GraphemeCat::LV),
+    (0xC49D, 0xC4B7, GraphemeCat::LVT),
+    (0xC4B8, 0xC4B8, GraphemeCat::LV),
+    (0xC4B9, 0xC4D3, GraphemeCat::LVT),
+    (0xC4D4, 0xC4D4, GraphemeCat::LV),
+    (0xC4D5, 0xC4EF, GraphemeCat::LVT),
+    (0xC4F0, 0xC4F0, GraphemeCat::LV),
+    (0xC4F1, 0xC50B, GraphemeCat::LVT),
+    (0xC50C, 0xC50C, GraphemeCat::LV),
+    (0xC50D, 0xC527, GraphemeCat::LVT),
+    (0xC528, 0xC528, GraphemeCat::LV),
+    (0xC529, 0xC543, GraphemeCat::LVT),
+    (0xC544, 0xC544, GraphemeCat::LV),
+    (0xC545, 0xC55F, GraphemeCat::LVT),
+    (0xC560, 0xC560, GraphemeCat::LV),
+    (0xC561, 0xC57B, GraphemeCat::LVT),
+    (0xC57C, 0xC57C, GraphemeCat::LV),
+    (0xC57D, 0xC597, GraphemeCat::LVT),
+    (0xC598, 0xC598, GraphemeCat::LV),
+    (0xC599, 0xC5B3, GraphemeCat::LVT),
+    (0xC5B4, 0xC5B4, GraphemeCat::LV),
+    (0xC5B5, 0xC5CF, GraphemeCat::LVT),
+    (0xC5D0, 0xC5D0, GraphemeCat::LV),
+    (0xC5D1, 0xC5EB, GraphemeCat::LVT),
+    (0xC5EC, 0xC5EC, GraphemeCat::LV),
+    (0xC5ED, 0xC607, GraphemeCat::LVT),
+    (0xC608, 0xC608, GraphemeCat::LV),
+    (0xC609, 0xC623, GraphemeCat::LVT),
+    (0xC624, 0xC624, GraphemeCat::LV),
+    (0xC625, 0xC63F, GraphemeCat::LVT),
+    (0xC640, 0xC640, GraphemeCat::LV),
+    (0xC641, 0xC65B, GraphemeCat::LVT),
+    (0xC65C, 0xC65C, GraphemeCat::LV),
+    (0xC65D, 0xC677, GraphemeCat::LVT),
+    (0xC678, 0xC678, GraphemeCat::LV),
+    (0xC679, 0xC693, GraphemeCat::LVT),
+    (0xC694, 0xC694, GraphemeCat::LV),
+    (0xC695, 0xC6AF, GraphemeCat::LVT),
+    (0xC6B0, 0xC6B0, GraphemeCat::LV),
+    (0xC6B1, 0xC6CB, GraphemeCat::LVT),
+    (0xC6CC, 0xC6CC, GraphemeCat::LV),
+    (0xC6CD, 0xC6E7, GraphemeCat::LVT),
+    (0xC6E8, 0xC6E8, GraphemeCat::LV),
+    (0xC6E9, 0xC703, GraphemeCat::LVT),
+    (0xC704, 0xC704, GraphemeCat::LV),
+    (0xC705, 0xC71F, GraphemeCat::LVT),
+    (0xC720, 0xC720, GraphemeCat::LV),
+    (0xC721, 0xC73B, GraphemeCat::LVT),
+    (0xC73C, 0xC73C, GraphemeCat::LV),
+    (0xC73D, 0xC757, GraphemeCat::LVT),
+    (0xC758, 0xC758, GraphemeCat::LV),
+    (0xC759, 0xC773, GraphemeCat::LVT),
+    (0xC774, 0xC774, GraphemeCat::LV),
+    (0xC775, 0xC78F, GraphemeCat::LVT),
+    (0xC790, 0xC790, GraphemeCat::LV),
+    (0xC791, 0xC7AB, GraphemeCat::LVT),
+    (0xC7AC, 0xC7AC, GraphemeCat::LV),
+    (0xC7AD, 0xC7C7, GraphemeCat::LVT),
+    (0xC7C8, 0xC7C8, GraphemeCat::LV),
+    (0xC7C9, 0xC7E3, GraphemeCat::LVT),
+    (0xC7E4, 0xC7E4, GraphemeCat::LV),
+    (0xC7E5, 0xC7FF, GraphemeCat::LVT),
+    (0xC800, 0xC800, GraphemeCat::LV),
+    (0xC801, 0xC81B, GraphemeCat::LVT),
+    (0xC81C, 0xC81C, GraphemeCat::LV),
+    (0xC81D, 0xC837, GraphemeCat::LVT),
+    (0xC838, 0xC838, GraphemeCat::LV),
+    (0xC839, 0xC853, GraphemeCat::LVT),
+    (0xC854, 0xC854, GraphemeCat::LV),
+    (0xC855, 0xC86F, GraphemeCat::LVT),
+    (0xC870, 0xC870, GraphemeCat::LV),
+    (0xC871, 0xC88B, GraphemeCat::LVT),
+    (0xC88C, 0xC88C, GraphemeCat::LV),
+    (0xC88D, 0xC8A7, GraphemeCat::LVT),
+    (0xC8A8, 0xC8A8, GraphemeCat::LV),
+    (0xC8A9, 0xC8C3, GraphemeCat::LVT),
+    (0xC8C4, 0xC8C4, GraphemeCat::LV),
+    (0xC8C5, 0xC8DF, GraphemeCat::LVT),
+    (0xC8E0, 0xC8E0, GraphemeCat::LV),
+    (0xC8E1, 0xC8FB, GraphemeCat::LVT),
+    (0xC8FC, 0xC8FC, GraphemeCat::LV),
+    (0xC8FD, 0xC917, GraphemeCat::LVT),
+    (0xC918, 0xC918, GraphemeCat::LV),
+    (0xC919, 0xC933, GraphemeCat::LVT),
+    (0xC934, 0xC934, GraphemeCat::LV),
+    (0xC935, 0xC94F, GraphemeCat::LVT),
+    (0xC950, 0xC950, GraphemeCat::LV),
+    (0xC951, 0xC96B, GraphemeCat::LVT),
+    (0xC96C, 0xC96C, GraphemeCat::LV),
+    (0xC96D, 0xC987, GraphemeCat::LVT),
+    (0xC988, 0xC988, GraphemeCat::LV),
+    (0xC989, 0xC9A3, GraphemeCat::LVT),
+    (0xC9A4, 0xC9A4, GraphemeCat::LV),
+    (0xC9A5, 0xC9BF, GraphemeCat::LVT),
+    (0xC9C0, 0xC9C0, GraphemeCat::LV),
+    (0xC9C1, 0xC9DB, GraphemeCat::LVT),
+    (0xC9DC, 0xC9DC, GraphemeCat::LV),
+    (0xC9DD, 0xC9F7, GraphemeCat::LVT),
+    (0xC9F8, 0xC9F8, GraphemeCat::LV),
+    (0xC9F9, 0xCA13, GraphemeCat::LVT),
+    (0xCA14, 0xCA14, GraphemeCat::LV),
+    (0xCA15, 0xCA2F, GraphemeCat::LVT),
+    (0xCA30, 0xCA30, GraphemeCat::LV),
+    (0xCA31, 0xCA4B, GraphemeCat::LVT),
+    (0xCA4C, 0xCA4C, GraphemeCat::LV),
+    (0xCA4D, 0xCA67, GraphemeCat::LVT),
+    (0xCA68, 0xCA68, GraphemeCat::LV),
+    (0xCA69, 0xCA83, GraphemeCat::LVT),
+    (0xCA84, 0xCA84, GraphemeCat::LV),
+    (0xCA85, 0xCA9F, GraphemeCat::LVT),
+    (0xCAA0, 0xCAA0, GraphemeCat::LV),
+    (0xCAA1, 0xCABB, GraphemeCat::LVT),
+    (0xCABC, 0xCABC, GraphemeCat::LV),
+    (0xCABD, 0xCAD7, GraphemeCat::LVT),
+    (0xCAD8, 0xCAD8, GraphemeCat::LV),
+    (0xCAD9, 0xCAF3, GraphemeCat::LVT),
+    (0xCAF4, 0xCAF4, GraphemeCat::LV),
+    (0xCAF5, 0xCB0F, GraphemeCat::LVT),
+    (0xCB10, 0xCB10, GraphemeCat::LV),
+    (0xCB11, 0xCB2B, GraphemeCat::LVT),
+    (0xCB2C, 0xCB2C, GraphemeCat::LV),
+    (0xCB2D, 0xCB47, GraphemeCat::LVT),
+    (0xCB48, 0xCB48, GraphemeCat::LV),
+    (0xCB49, 0xCB63, GraphemeCat::LVT),
+    (0xCB64, 0xCB64, GraphemeCat::LV),
+    (0xCB65, 0xCB7F, GraphemeCat::LVT),
+    (0xCB80, 0xCB80, GraphemeCat::LV),
+    (0xCB81, 0xCB9B, GraphemeCat::LVT),
+    (0xCB9C, 0xCB9C, GraphemeCat::LV),
+    (0xCB9D, 0xCBB7, GraphemeCat::LVT),
+    (0xCBB8, 0xCBB8, GraphemeCat::LV),
+    (0xCBB9, 0xCBD3, GraphemeCat::LVT),
+    (0xCBD4, 0xCBD4, GraphemeCat::LV),
+    (0xCBD5, 0xCBEF, GraphemeCat::LVT),
+    (0xCBF0, 0xCBF0, GraphemeCat::LV),
+    (0xCBF1, 0xCC0B, GraphemeCat::LVT),
+    (0xCC0C, 0xCC0C, GraphemeCat::LV),
+    (0xCC0D, 0xCC27, GraphemeCat::LVT),
+    (0xCC28, 0xCC28, GraphemeCat::LV),
+    (0xCC29, 0xCC43, GraphemeCat::LVT),
+    (0xCC44, 0xCC44, GraphemeCat::LV),
+    (0xCC45, 0xCC5F, GraphemeCat::LVT),
+    (0xCC60, 0xCC60, GraphemeCat::LV),
+    (0xCC61, 0xCC7B, GraphemeCat::LVT),
+    (0xCC7C, 0xCC7C, GraphemeCat::LV),
+    (0xCC7D, 0xCC97, GraphemeCat::LVT),
+    (0xCC98, 0xCC98, GraphemeCat::LV),
+    (0xCC99, 0xCCB3, GraphemeCat::LVT),
+    (0xCCB4, 0xCCB4, GraphemeCat::LV),
+    (0xCCB5, 0xCCCF, GraphemeCat::LVT),
+    (0xCCD0, 0xCCD0, GraphemeCat::LV),
+    (0xCCD1, 0xCCEB, GraphemeCat::LVT),
+    (0xCCEC, 0xCCEC, GraphemeCat::LV),
+    (0xCCED, 0xCD07, GraphemeCat::LVT),
+    (0xCD08, 0xCD08, GraphemeCat::LV),
+    (0xCD09, 0xCD23, GraphemeCat::LVT),
+    (0xCD24, 0xCD24, GraphemeCat::LV),
+    (0xCD25, 0xCD3F, GraphemeCat::LVT),
+    (0xCD40, 0xCD40, GraphemeCat::LV),
+    (0xCD41, 0xCD5B, GraphemeCat::LVT),
+    (0xCD5C, 0xCD5C, GraphemeCat::LV),
+    (0xCD5D, 0xCD77, GraphemeCat::LVT),
+    (0xCD78, 0xCD78, GraphemeCat::LV),
+    (0xCD79, 0xCD93, GraphemeCat::LVT),
+    (0xCD94, 0xCD94, GraphemeCat::LV),
+    (0xCD95, 0xCDAF, GraphemeCat::LVT),
+    (0xCDB0, 0xCDB0, GraphemeCat::LV),
+    (0xCDB1, 0xCDCB, GraphemeCat::LVT),
+    (0xCDCC, 0xCDCC, GraphemeCat::LV),
+    (0xCDCD, 0xCDE7, GraphemeCat::LVT),
+    (0xCDE8, 0xCDE8, GraphemeCat::LV),
+    (0xCDE9, 0xCE03, GraphemeCat::LVT),
+    (0xCE04, 0xCE04, GraphemeCat::LV),
+    (0xCE05, 0xCE1F, GraphemeCat::LVT),
+    (0xCE20, 0xCE20, GraphemeCat::LV),
+    (0xCE21, 0xCE3B, GraphemeCat::LVT),
+    (0xCE3C, 0xCE3C, GraphemeCat::LV),
+    (0xCE3D, 0xCE57, GraphemeCat::LVT),
+    (0xCE58, 0xCE58, GraphemeCat::LV),
+    (0xCE59, 0xCE73, GraphemeCat::LVT),
+    (0xCE74, 0xCE74, GraphemeCat::LV),
+    (0xCE75, 0xCE8F, GraphemeCat::LVT),
+    (0xCE90, 0xCE90, GraphemeCat::LV),
+    (0xCE91, 0xCEAB, GraphemeCat::LVT),
+    (0xCEAC, 0xCEAC, GraphemeCat::LV),
+    (0xCEAD, 0xCEC7, GraphemeCat::LVT),
+    (0xCEC8, 0xCEC8, GraphemeCat::LV),
+    (0xCEC9, 0xCEE3, GraphemeCat::LVT),
+    (0xCEE4, 0xCEE4, GraphemeCat::LV),
+    (0xCEE5, 0xCEFF, GraphemeCat::LVT),
+    (0xCF00, 0xCF00, GraphemeCat::LV),
+    (0xCF01, 0xCF1B, GraphemeCat::LVT),
+    (0xCF1C, 0xCF1C, GraphemeCat::LV),
+    (0xCF1D, 0xCF37, GraphemeCat::LVT),
+    (0xCF38, 0xCF38, GraphemeCat::LV),
+    (0xCF39, 0xCF53, GraphemeCat::LVT),
+    (0xCF54, 0xCF54, GraphemeCat::LV),
+    (0xCF55, 0xCF6F, GraphemeCat::LVT),
+    (0xCF70, 0xCF70, GraphemeCat::LV),
+    (0xCF71, 0xCF8B, GraphemeCat::LVT),
+    (0xCF8C, 0xCF8C, GraphemeCat::LV),
+    (0xCF8D, 0xCFA7, GraphemeCat::LVT),
+    (0xCFA8, 0xCFA8, GraphemeCat::LV),
+    (0xCFA9, 0xCFC3, GraphemeCat::LVT),
+    (0xCFC4, 0xCFC4, GraphemeCat::LV),
+    (0xCFC5, 0xCFDF, GraphemeCat::LVT),
+    (0xCFE0, 0xCFE0, GraphemeCat::LV),
+    (0xCFE1, 0xCFFB, GraphemeCat::LVT),
+    (0xCFFC, 0xCFFC, GraphemeCat::LV),
+    (0xCFFD, 0xD017, GraphemeCat::LVT),
+    (0xD018, 0xD018, GraphemeCat::LV),
+    (0xD019, 0xD033, GraphemeCat::LVT),
+    (0xD034, 0xD034, GraphemeCat::LV),
+    (0xD035, 0xD04F, GraphemeCat::LVT),
+    (0xD050, 0xD050, GraphemeCat::LV),
+    (0xD051, 0xD06B, GraphemeCat::LVT),
+    (0xD06C, 0xD06C, GraphemeCat::LV),
+    (0xD06D, 0xD087, GraphemeCat::LVT),
+    (0xD088, 0xD088, GraphemeCat::LV),
+    (0xD089, 0xD0A3, GraphemeCat::LVT),
+    (0xD0A4, 0xD0A4, GraphemeCat::LV),
+    (0xD0A5, 0xD0BF, GraphemeCat::LVT),
+    (0xD0C0, 0xD0C0, GraphemeCat::LV),
+    (0xD0C1, 0xD0DB, GraphemeCat::LVT),
+    (0xD0DC, 0xD0DC, GraphemeCat::LV),
+    (0xD0DD, 0xD0F7, GraphemeCat::LVT),
+    (0xD0F8, 0xD0F8, GraphemeCat::LV),
+    (0xD0F9, 0xD113, GraphemeCat::LVT),
+    (0xD114, 0xD114, GraphemeCat::LV),
+    (0xD115, 0xD12F, GraphemeCat::LVT),
+    (0xD130, 0xD130, GraphemeCat::LV),
+    (0xD131, 0xD14B, GraphemeCat::LVT),
+    (0xD14C, 0xD14C, GraphemeCat::LV),
+    (0xD14D, 0xD167, GraphemeCat::LVT),
+    (0xD168, 0xD168, GraphemeCat::LV),
+    (0xD169, 0xD183, GraphemeCat::LVT),
+    (0xD184, 0xD184, GraphemeCat::LV),
+    (0xD185, 0xD19F, GraphemeCat::LVT),
+    (0xD1A0, 0xD1A0, GraphemeCat::LV),
+    (0xD1A1, 0xD1BB, GraphemeCat::LVT),
+    (0xD1BC, 0xD1BC, GraphemeCat::LV),
+    (0xD1BD, 0xD1D7, GraphemeCat::LVT),
+    (0xD1D8, 0xD1D8, GraphemeCat::LV),
+    (0xD1D9, 0xD1F3, GraphemeCat::LVT),
+    (0xD1F4, 0xD1F4, GraphemeCat::LV),
+    (0xD1F5, 0xD20F, GraphemeCat::LVT),
+    (0xD210, 0xD210, GraphemeCat::LV),
+    (0xD211, 0xD22B, GraphemeCat::LVT),
+    (0xD22C, 0xD22C, GraphemeCat::LV),
+    (0xD22D, 0xD247, GraphemeCat::LVT),
+    (0xD248, 0xD248, GraphemeCat::LV),
+    (0xD249, 0xD263, GraphemeCat::LVT),
+    (0xD264, 0xD264, GraphemeCat::LV),
+    (0xD265, 0xD27F, GraphemeCat::LVT),
+    (0xD280, 0xD280, GraphemeCat::LV),
+    (0xD281, 0xD29B, GraphemeCat::LVT),
+    (0xD29C, 0xD29C, GraphemeCat::LV),
+    (0xD29D, 0xD2B7, GraphemeCat::LVT),
+    (0xD2B8, 0xD2B8, GraphemeCat::LV),
+    (0xD2B9, 0xD2D3, GraphemeCat::LVT),
+    (0xD2D4, 0xD2D4, GraphemeCat::LV),
+    (0xD2D5, 0xD2EF, GraphemeCat::LVT),
+    (0xD2F0, 0xD2F0, GraphemeCat::LV),
+    (0xD2F1, 0xD30B, GraphemeCat::LVT),
+    (0xD30C, 0xD30C, GraphemeCat::LV),
+    (0xD30D, 0xD327, GraphemeCat::LVT),
+    (0xD328, 0xD328, GraphemeCat::LV),
+    (0xD329, 0xD343, GraphemeCat::LVT),
+    (0xD344, 0xD344, GraphemeCat::LV),
+    (0xD345, 0xD35F, GraphemeCat::LVT),
+    (0xD360, 0xD360, GraphemeCat::LV),
+    (0xD361, 0xD37B, GraphemeCat::LVT),
+    (0xD37C, 0xD37C, GraphemeCat::LV),
+    (0xD37D, 0xD397, GraphemeCat::LVT),
+    (0xD398, 0xD398, GraphemeCat::LV),
+    (0xD399, 0xD3B3, GraphemeCat::LVT),
+    (0xD3B4, 0xD3B4, GraphemeCat::LV),
+    (0xD3B5, 0xD3CF, GraphemeCat::LVT),
+    (0xD3D0, 0xD3D0, GraphemeCat::LV),
+    (0xD3D1, 0xD3EB, GraphemeCat::LVT),
+    (0xD3EC, 0xD3EC, GraphemeCat::LV),
+    (0xD3ED, 0xD407, GraphemeCat::LVT),
+    (0xD408, 0xD408, GraphemeCat::LV),
+    (0xD409, 0xD423, GraphemeCat::LVT),
+    (0xD424, 0xD424, GraphemeCat::LV),
+    (0xD425, 0xD43F, GraphemeCat::LVT),
+    (0xD440, 0xD440, GraphemeCat::LV),
+    (0xD441, 0xD45B, GraphemeCat::LVT),
+    (0xD45C, 0xD45C, GraphemeCat::LV),
+    (0xD45D, 0xD477, GraphemeCat::LVT),
+    (0xD478, 0xD478, GraphemeCat::LV),
+    (0xD479, 0xD493, GraphemeCat::LVT),
+    (0xD494, 0xD494, GraphemeCat::LV),
+    (0xD495, 0xD4AF, GraphemeCat::LVT),
+    (0xD4B0, 0xD4B0, GraphemeCat::LV),
+    (0xD4B1, 0xD4CB, GraphemeCat::LVT),
+    (0xD4CC, 0xD4CC, GraphemeCat::LV),
+    (0xD4CD, 0xD4E7, GraphemeCat::LVT),
+    (0xD4E8, 0xD4E8, GraphemeCat::LV),
+    (0xD4E9, 0xD503, GraphemeCat::LVT),
+    (0xD504, 0xD504, GraphemeCat::LV),
+    (0xD505, 0xD51F, GraphemeCat::LVT),
+    (0xD520, 0xD520, GraphemeCat::LV),
+    (0xD521, 0xD53B, GraphemeCat::LVT),
+    (0xD53C, 0xD53C, GraphemeCat::LV),
+    (0xD53D, 0xD557, GraphemeCat::LVT),
+    (0xD558, 0xD558, GraphemeCat::LV),
+    (0xD559, 0xD573, GraphemeCat::LVT),
+    (0xD574, 0xD574, GraphemeCat::LV),
+    (0xD575, 0xD58F, GraphemeCat::LVT),
+    (0xD590, 0xD590, GraphemeCat::LV),
+    (0xD591, 0xD5AB, GraphemeCat::LVT),
+    (0xD5AC, 0xD5AC, GraphemeCat::LV),
+    (0xD5AD, 0xD5C7, GraphemeCat::LVT),
+    (0xD5C8, 0xD5C8, GraphemeCat::LV),
+    (0xD5C9, 0xD5E3, GraphemeCat::LVT),
+    (0xD5E4, 0xD5E4, GraphemeCat::LV),
+    (0xD5E5, 0xD5FF, GraphemeCat::LVT),
+    (0xD600, 0xD600, GraphemeCat::LV),
+    (0xD601, 0xD61B, GraphemeCat::LVT),
+    (0xD61C, 0xD61C, GraphemeCat::LV),
+    (0xD61D, 0xD637, GraphemeCat::LVT),
+    (0xD638, 0xD638, GraphemeCat::LV),
+    (0xD639, 0xD653, GraphemeCat::LVT),
+    (0xD654, 0xD654, GraphemeCat::LV),
+    (0xD655, 0xD66F, GraphemeCat::LVT),
+    (0xD670, 0xD670, GraphemeCat::LV),
+    (0xD671, 0xD68B, GraphemeCat::LVT),
+    (0xD68C, 0xD68C, GraphemeCat::LV),
+    (0xD68D, 0xD6A7, GraphemeCat::LVT),
+    (0xD6A8, 0xD6A8, GraphemeCat::LV),
+    (0xD6A9, 0xD6C3, GraphemeCat::LVT),
+    (0xD6C4, 0xD6C4, GraphemeCat::LV),
+    (0xD6C5, 0xD6DF, GraphemeCat::LVT),
+    (0xD6E0, 0xD6E0, GraphemeCat::LV),
+    (0xD6E1, 0xD6FB, GraphemeCat::LVT),
+    (0xD6FC, 0xD6FC, GraphemeCat::LV),
+    (0xD6FD, 0xD717, GraphemeCat::LVT),
+    (0xD718, 0xD718, GraphemeCat::LV),
+    (0xD719, 0xD733, GraphemeCat::LVT),
+    (0xD734, 0xD734, GraphemeCat::LV),
+    (0xD735, 0xD74F, GraphemeCat::LVT),
+    (0xD750, 0xD750, GraphemeCat::LV),
+    (0xD751, 0xD76B, GraphemeCat::LVT),
+    (0xD76C, 0xD76C, GraphemeCat::LV),
+    (0xD76D, 0xD787, GraphemeCat::LVT),
+    (0xD788, 0xD788, GraphemeCat::LV),
+    (0xD789, 0xD7A3, GraphemeCat::LVT),
+    (0xD7B0, 0xD7C6, GraphemeCat::V),
+    (0xD7CB, 0xD7FB, GraphemeCat::T),
+    (0xFB1E, 0xFB1E, GraphemeCat::Extend),
+    (0xFE00, 0xFE0F, GraphemeCat::Extend),
+    (0xFE20, 0xFE2F, GraphemeCat::Extend),
+    (0xFEFF, 0xFEFF, GraphemeCat::Control),
+    (0xFF9E, 0xFF9F, GraphemeCat::Extend),
+    (0xFFF0, 0xFFFB, GraphemeCat::Control),
+    (0x101FD, 0x101FD, GraphemeCat::Extend),
+    (0x102E0, 0x102E0, GraphemeCat::Extend),
+    (0x10376, 0x1037A, GraphemeCat::Extend),
+    (0x10A01, 0x10A03, GraphemeCat::Extend),
+    (0x10A05, 0x10A06, GraphemeCat::Extend),
+    (0x10A0C, 0x10A0F, GraphemeCat::Extend),
+    (0x10A38, 0x10A3A, GraphemeCat::Extend),
+    (0x10A3F, 0x10A3F, GraphemeCat::Extend),
+    (0x10AE5, 0x10AE6, GraphemeCat::Extend),
+    (0x10D24, 0x10D27, GraphemeCat::Extend),
+    (0x10EAB, 0x10EAC, GraphemeCat::Extend),
+    (0x10F46, 0x10F50, GraphemeCat::Extend),
+    (0x10F82, 0x10F85, GraphemeCat::Extend),
+    (0x11000, 0x11000, GraphemeCat::SpacingMark),
+    (0x11001, 0x11001, GraphemeCat::Extend),
+    (0x11002, 0x11002, GraphemeCat::SpacingMark),
+    (0x11038, 0x11046, GraphemeCat::Extend),
+    (0x11070, 0x11070, GraphemeCat::Extend),
+    (0x11073, 0x11074, GraphemeCat::Extend),
+    (0x1107F, 0x11081, GraphemeCat::Extend),
+    (0x11082, 0x11082, GraphemeCat::SpacingMark),
+    (0x110B0, 0x110B2, GraphemeCat::SpacingMark),
+    (0x110B3, 0x110B6, GraphemeCat::Extend),
+    (0x110B7, 0x110B8, GraphemeCat::SpacingMark),
+    (0x110B9, 0x110BA, GraphemeCat::Extend),
+    (0x110BD, 0x110BD, GraphemeCat::Prepend),
+    (0x110C2, 0x110C2, GraphemeCat::Extend),
+    (0x110CD, 0x110CD, GraphemeCat::Prepend),
+    (0x11100, 0x11102, GraphemeCat::Extend),
+    (0x11127, 0x1112B, GraphemeCat::Extend),
+    (0x1112C, 0x1112C, GraphemeCat::SpacingMark),
+    (0x1112D, 0x11134, GraphemeCat::Extend),
+    (0x11145, 0x11146, GraphemeCat::SpacingMark),
+    (0x11173, 0x11173, GraphemeCat::Extend),
+    (0x11180, 0x11181, GraphemeCat::Extend),
+    (0x11182, 0x11182, GraphemeCat::SpacingMark),
+    (0x111B3, 0x111B5, GraphemeCat::SpacingMark),
+    (0x111B6, 0x111BE, GraphemeCat::Extend),
+    (0x111BF, 0x111C0, GraphemeCat::SpacingMark),
+    (0x111C2, 0x111C3, GraphemeCat::Prepend),
+    (0x111C9, 0x111CC, GraphemeCat::Extend),
+    (0x111CE, 0x111CE, GraphemeCat::SpacingMark),
+    (0x111CF, 0x111CF, GraphemeCat::Extend),
+    (0x1122C, 0x1122E, GraphemeCat::SpacingMark),
+    (0x1122F, 0x11231, GraphemeCat::Extend),
+    (0x11232, 0x11233, GraphemeCat::SpacingMark),
+    (0x11234, 0x11234, GraphemeCat::Extend),
+    (0x11235, 0x11235, GraphemeCat::SpacingMark),
+    (0x11236, 0x11237, GraphemeCat::Extend),
+    (0x1123E, 0x1123E, GraphemeCat::Extend),
+    (0x112DF, 0x112DF, GraphemeCat::Extend),
+    (0x112E0, 0x112E2, GraphemeCat::SpacingMark),
+    (0x112E3, 0x112EA, GraphemeCat::Extend),
+    (0x11300, 0x11301, GraphemeCat::Extend),
+    (0x11302, 0x11303, GraphemeCat::SpacingMark),
+    (0x1133B, 0x1133C, GraphemeCat::Extend),
+    (0x1133E, 0x1133E, GraphemeCat::Extend),
+    (0x1133F, 0x1133F, GraphemeCat::SpacingMark),
+    (0x11340, 0x11340, GraphemeCat::Extend),
+    (0x11341, 0x11344, GraphemeCat::SpacingMark),
+    (0x11347, 0x11348, GraphemeCat::SpacingMark),
+    (0x1134B, 0x1134D, GraphemeCat::SpacingMark),
+    (0x11357, 0x11357, GraphemeCat::Extend),
+    (0x11362, 0x11363, GraphemeCat::SpacingMark),
+    (0x11366, 0x1136C, GraphemeCat::Extend),
+    (0x11370, 0x11374, GraphemeCat::Extend),
+    (0x11435, 0x11437, GraphemeCat::SpacingMark),
+    (0x11438, 0x1143F, GraphemeCat::Extend),
+    (0x11440, 0x11441, GraphemeCat::SpacingMark),
+    (0x11442, 0x11444, GraphemeCat::Extend),
+    (0x11445, 0x11445, GraphemeCat::SpacingMark),
+    (0x11446, 0x11446, GraphemeCat::Extend),
+    (0x1145E, 0x1145E, GraphemeCat::Extend),
+    (0x114B0, 0x114B0, GraphemeCat::Extend),
+    (0x114B1, 0x114B2, GraphemeCat::SpacingMark),
+    (0x114B3, 0x114B8, GraphemeCat::Extend),
+    (0x114B9, 0x114B9, GraphemeCat::SpacingMark),
+    (0x114BA, 0x114BA, GraphemeCat::Extend),
+    (0x114BB, 0x114BC, GraphemeCat::SpacingMark),
+    (0x114BD, 0x114BD, GraphemeCat::Extend),
+    (0x114BE, 0x114BE, GraphemeCat::SpacingMark),
+    (0x114BF, 0x114C0, GraphemeCat::Extend),
+    (0x114C1, 0x114C1, GraphemeCat::SpacingMark),
+    (0x114C2, 0x114C3, GraphemeCat::Extend),
+    (0x115AF, 0x115AF, GraphemeCat::Extend),
+    (0x115B0, 0x115B1, GraphemeCat::SpacingMark),
+    (0x115B2, 0x115B5, GraphemeCat::Extend),
+    (0x115B8, 0x115BB, GraphemeCat::SpacingMark),
+    (0x115BC, 0x115BD, GraphemeCat::Extend),
+    (0x115BE, 0x115BE, GraphemeCat::SpacingMark),
+    (0x115BF, 0x115C0, GraphemeCat::Extend),
+    (0x115DC, 0x115DD, GraphemeCat::Extend),
+    (0x11630, 0x11632, GraphemeCat::SpacingMark),
+    (0x11633, 0x1163A, GraphemeCat::Extend),
+    (0x1163B, 0x1163C, GraphemeCat::SpacingMark),
+    (0x1163D, 0x1163D, GraphemeCat::Extend),
+    (0x1163E, 0x1163E, GraphemeCat::SpacingMark),
+    (0x1163F, 0x11640, GraphemeCat::Extend),
+    (0x116AB, 0x116AB, GraphemeCat::Extend),
+    (0x116AC, 0x116AC, GraphemeCat::SpacingMark),
+    (0x116AD, 0x116AD, GraphemeCat::Extend),
+    (0x116AE, 0x116AF, GraphemeCat::SpacingMark),
+    (0x116B0, 0x116B5, GraphemeCat::Extend),
+    (0x116B6, 0x116B6, GraphemeCat::SpacingMark),
+    (0x116B7, 0x116B7, GraphemeCat::Extend),
+    (0x1171D, 0x1171F, GraphemeCat::Extend),
+    (0x11722, 0x11725, GraphemeCat::Extend),
+    (0x11726, 0x11726, GraphemeCat::SpacingMark),
+    (0x11727, 0x1172B, GraphemeCat::Extend),
+    (0x1182C, 0x1182E, GraphemeCat::SpacingMark),
+    (0x1182F, 0x11837, GraphemeCat::Extend),
+    (0x11838, 0x11838, GraphemeCat::SpacingMark),
+    (0x11839, 0x1183A, GraphemeCat::Extend),
+    (0x11930, 0x11930, GraphemeCat::Extend),
+    (0x11931, 0x11935, GraphemeCat::SpacingMark),
+    (0x11937, 0x11938, GraphemeCat::SpacingMark),
+    (0x1193B, 0x1193C, GraphemeCat::Extend),
+    (0x1193D, 0x1193D, GraphemeCat::SpacingMark),
+    (0x1193E, 0x1193E, GraphemeCat::Extend),
+    (0x1193F, 0x1193F, GraphemeCat::Prepend),
+    (0x11940, 0x11940, GraphemeCat::SpacingMark),
+    (0x11941, 0x11941, GraphemeCat::Prepend),
+    (0x11942, 0x11942, GraphemeCat::SpacingMark),
+    (0x11943, 0x11943, GraphemeCat::Extend),
+    (0x119D1, 0x119D3, GraphemeCat::SpacingMark),
+    (0x119D4, 0x119D7, GraphemeCat::Extend),
+    (0x119DA, 0x119DB, GraphemeCat::Extend),
+    (0x119DC, 0x119DF, GraphemeCat::SpacingMark),
+    (0x119E0, 0x119E0, GraphemeCat::Extend),
+    (0x119E4, 0x119E4, GraphemeCat::SpacingMark),
+    (0x11A01, 0x11A0A, GraphemeCat::Extend),
+    (0x11A33, 0x11A38, GraphemeCat::Extend),
+    (0x11A39, 0x11A39, GraphemeCat::SpacingMark),
+    (0x11A3A, 0x11A3A, GraphemeCat::Prepend),
+    (0x11A3B, 0x11A3E, GraphemeCat::Extend),
+    (0x11A47, 0x11A47, GraphemeCat::Extend),
+    (0x11A51, 0x11A56, GraphemeCat::Extend),
+    (0x11A57, 0x11A58, GraphemeCat::SpacingMark),
+    (0x11A59, 0x11A5B, GraphemeCat::Extend),
+    (0x11A84, 0x11A89, GraphemeCat::Prepend),
+    (0x11A8A, 0x11A96, GraphemeCat::Extend),
+    (0x11A97, 0x11A97, GraphemeCat::SpacingMark),
+    (0x11A98, 0x11A99, GraphemeCat::Extend),
+    (0x11C2F, 0x11C2F, GraphemeCat::SpacingMark),
+    (0x11C30, 0x11C36, GraphemeCat::Extend),
+    (0x11C38, 0x11C3D, GraphemeCat::Extend),
+    (0x11C3E, 0x11C3E, GraphemeCat::SpacingMark),
+    (0x11C3F, 0x11C3F, GraphemeCat::Extend),
+    (0x11C92, 0x11CA7, GraphemeCat::Extend),
+    (0x11CA9, 0x11CA9, GraphemeCat::SpacingMark),
+    (0x11CAA, 0x11CB0, GraphemeCat::Extend),
+    (0x11CB1, 0x11CB1, GraphemeCat::SpacingMark),
+    (0x11CB2, 0x11CB3, GraphemeCat::Extend),
+    (0x11CB4, 0x11CB4, GraphemeCat::SpacingMark),
+    (0x11CB5, 0x11CB6, GraphemeCat::Extend),
+    (0x11D31, 0x11D36, GraphemeCat::Extend),
+    (0x11D3A, 0x11D3A, GraphemeCat::Extend),
+    (0x11D3C, 0x11D3D, GraphemeCat::Extend),
+    (0x11D3F, 0x11D45, GraphemeCat::Extend),
+    (0x11D46, 0x11D46, GraphemeCat::Prepend),
+    (0x11D47, 0x11D47, GraphemeCat::Extend),
+    (0x11D8A, 0x11D8E, GraphemeCat::SpacingMark),
+    (0x11D90, 0x11D91, GraphemeCat::Extend),
+    (0x11D93, 0x11D94, GraphemeCat::SpacingMark),
+    (0x11D95, 0x11D95, GraphemeCat::Extend),
+    (0x11D96, 0x11D96, GraphemeCat::SpacingMark),
+    (0x11D97, 0x11D97, GraphemeCat::Extend),
+    (0x11EF3, 0x11EF4, GraphemeCat::Extend),
+    (0x11EF5, 0x11EF6, GraphemeCat::SpacingMark),
+    (0x13430, 0x13438, GraphemeCat::Control),
+    (0x16AF0, 0x16AF4, GraphemeCat::Extend),
+    (0x16B30, 0x16B36, GraphemeCat::Extend),
+    (0x16F4F, 0x16F4F, GraphemeCat::Extend),
+    (0x16F51, 0x16F87, GraphemeCat::SpacingMark),
+    (0x16F8F, 0x16F92, GraphemeCat::Extend),
+    (0x16FE4, 0x16FE4, GraphemeCat::Extend),
+    (0x16FF0, 0x16FF1, GraphemeCat::SpacingMark),
+    (0x1BC9D, 0x1BC9E, GraphemeCat::Extend),
+    (0x1BCA0, 0x1BCA3, GraphemeCat::Control),
+    (0x1CF00, 0x1CF2D, GraphemeCat::Extend),
+    (0x1CF30, 0x1CF46, GraphemeCat::Extend),
+    (0x1D165, 0x1D165, GraphemeCat::Extend),
+    (0x1D166, 0x1D166, GraphemeCat::SpacingMark),
+    (0x1D167, 0x1D169, GraphemeCat::Extend),
+    (0x1D16D, 0x1D16D, GraphemeCat::SpacingMark),
+    (0x1D16E, 0x1D172, GraphemeCat::Extend),
+    (0x1D173, 0x1D17A, GraphemeCat::Control),
+    (0x1D17B, 0x1D182, GraphemeCat::Extend),
+    (0x1D185, 0x1D18B, GraphemeCat::Extend),
+    (0x1D1AA, 0x1D1AD, GraphemeCat::Extend),
+    (0x1D242, 0x1D244, GraphemeCat::Extend),
+    (0x1DA00, 0x1DA36, GraphemeCat::Extend),
+    (0x1DA3B, 0x1DA6C, GraphemeCat::Extend),
+    (0x1DA75, 0x1DA75, GraphemeCat::Extend),
+    (0x1DA84, 0x1DA84, GraphemeCat::Extend),
+    (0x1DA9B, 0x1DA9F, GraphemeCat::Extend),
+    (0x1DAA1, 0x1DAAF, GraphemeCat::Extend),
+    (0x1E000, 0x1E006, GraphemeCat::Extend),
+    (0x1E008, 0x1E018, GraphemeCat::Extend),
+    (0x1E01B, 0x1E021, GraphemeCat::Extend),
+    (0x1E023, 0x1E024, GraphemeCat::Extend),
+    (0x1E026, 0x1E02A, GraphemeCat::Extend),
+    (0x1E130, 0x1E136, GraphemeCat::Extend),
+    (0x1E2AE, 0x1E2AE, GraphemeCat::Extend),
+    (0x1E2EC, 0x1E2EF, GraphemeCat::Extend),
+    (0x1E8D0, 0x1E8D6, GraphemeCat::Extend),
+    (0x1E944, 0x1E94A, GraphemeCat::Extend),
+    (0x1F1E6, 0x1F1FF, GraphemeCat::RegionalIndicator),
+    (0x1F3FB, 0x1F3FF, GraphemeCat::Extend),
+    (0xE0000, 0xE001F, GraphemeCat::Control),
+    (0xE0020, 0xE007F, GraphemeCat::Extend),
+    (0xE0080, 0xE00FF, GraphemeCat::Control),
+    (0xE0100, 0xE01EF, GraphemeCat::Extend),
+    (0xE01F0, 0xE0FFF, GraphemeCat::Control),
+];
+
+pub const EXTENDED_PICTOGRAPHIC: &[(u32, u32)] = &[
+    (0x00A9, 0x00A9),
+    (0x00AE, 0x00AE),
+    (0x203C, 0x203C),
+    (0x2049, 0x2049),
+    (0x2122, 0x2122),
+    (0x2139, 0x2139),
+    (0x2194, 0x2199),
+    (0x21A9, 0x21AA),
+    (0x231A, 0x231B),
+    (0x2328, 0x2328),
+    (0x2388, 0x2388),
+    (0x23CF, 0x23CF),
+    (0x23E9, 0x23F3),
+    (0x23F8, 0x23FA),
+    (0x24C2, 0x24C2),
+    (0x25AA, 0x25AB),
+    (0x25B6, 0x25B6),
+    (0x25C0, 0x25C0),
+    (0x25FB, 0x25FE),
+    (0x2600, 0x2605),
+    (0x2607, 0x2612),
+    (0x2614, 0x2685),
+    (0x2690, 0x2705),
+    (0x2708, 0x2712),
+    (0x2714, 0x2714),
+    (0x2716, 0x2716),
+    (0x271D, 0x271D),
+    (0x2721, 0x2721),
+    (0x2728, 0x2728),
+    (0x2733, 0x2734),
+    (0x2744, 0x2744),
+    (0x2747, 0x2747),
+    (0x274C, 0x274C),
+    (0x274E, 0x274E),
+    (0x2753, 0x2755),
+    (0x2757, 0x2757),
+    (0x2763, 0x2767),
+    (0x2795, 0x2797),
+    (0x27A1, 0x27A1),
+    (0x27B0, 0x27B0),
+    (0x27BF, 0x27BF),
+    (0x2934, 0x2935),
+    (0x2B05, 0x2B07),
+    (0x2B1B, 0x2B1C),
+    (0x2B50, 0x2B50),
+    (0x2B55, 0x2B55),
+    (0x3030, 0x3030),
+    (0x303D, 0x303D),
+    (0x3297, 0x3297),
+    (0x3299, 0x3299),
+    (0x1F000, 0x1F0FF),
+    (0x1F10D, 0x1F10F),
+    (0x1F12F, 0x1F12F),
+    (0x1F16C, 0x1F171),
+    (0x1F17E, 0x1F17F),
+    (0x1F18E, 0x1F18E),
+    (0x1F191, 0x1F19A),
+    (0x1F1AD, 0x1F1E5),
+    (0x1F201, 0x1F20F),
+    (0x1F21A, 0x1F21A),
+    (0x1F22F, 0x1F22F),
+    (0x1F232, 0x1F23A),
+    (0x1F23C, 0x1F23F),
+    (0x1F249, 0x1F3FA),
+    (0x1F400, 0x1F53D),
+    (0x1F546, 0x1F64F),
+    (0x1F680, 0x1F6FF),
+    (0x1F774, 0x1F77F),
+    (0x1F7D5, 0x1F7FF),
+    (0x1F80C, 0x1F80F),
+    (0x1F848, 0x1F84F),
+    (0x1F85A, 0x1F85F),
+    (0x1F888, 0x1F88F),
+    (0x1F8AE, 0x1F8FF),
+    (0x1F90C, 0x1F93A),
+    (0x1F93C, 0x1F945),
+    (0x1F947, 0x1FAFF),
+    (0x1FC00, 0x1FFFD),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WordCat {
+    Other,
+    ALetter,
+    CR,
+    DoubleQuote,
+    Extend,
+    ExtendNumLet,
+    Format,
+    HebrewLetter,
+    Katakana,
+    LF,
+    MidLetter,
+    MidNum,
+    MidNumLet,
+    Newline,
+    Numeric,
+    RegionalIndicator,
+    SingleQuote,
+    WSegSpace,
+    ZWJ,
+}
+
+pub const WORD_BREAK: &[(u32, u32, WordCat)] = &[
+    (0x000A, 0x000A, WordCat::LF),
+    (0x000B, 0x000C, WordCat::Newline),
+    (0x000D, 0x000D, WordCat::CR),
+    (0x0020, 0x0020, WordCat::WSegSpace),
+    (0x0022, 0x0022, WordCat::DoubleQuote),
+    (0x0027, 0x0027, WordCat::SingleQuote),
+    (0x002C, 0x002C, WordCat::MidNum),
+    (0x002E, 0x002E, WordCat::MidNumLet),
+    (0x0030, 0x0039, WordCat::Numeric),
+    (0x003A, 0x003A, WordCat::MidLetter),
+    (0x003B, 0x003B, WordCat::MidNum),
+    (0x0041, 0x005A, WordCat::ALetter),
+    (0x005F, 0x005F, WordCat::ExtendNumLet),
+    (0x0061, 0x007A, WordCat::ALetter),
+    (0x0085, 0x0085, WordCat::Newline),
+    (0x00AA, 0x00AA, WordCat::ALetter),
+    (0x00AD, 0x00AD, WordCat::Format),
+    (0x00B5, 0x00B5, WordCat::ALetter),
+    (0x00B7, 0x00B7, WordCat::MidLetter),
+    (0x00BA, 0x00BA, WordCat::ALetter),
+    (0x00C0, 0x00D6, WordCat::ALetter),
+    (0x00D8, 0x00F6, WordCat::ALetter),
+    (0x00F8, 0x02D7, WordCat::ALetter),
+    (0x02DE, 0x02FF, WordCat::ALetter),
+    (0x0300, 0x036F, WordCat::Extend),
+    (0x0370, 0x0374, WordCat::ALetter),
+    (0x0376, 0x0377, WordCat::ALetter),
+    (0x037A, 0x037D, WordCat::ALetter),
+    (0x037E, 0x037E, WordCat::MidNum),
+    (0x037F, 0x037F, WordCat::ALetter),
+    (0x0386, 0x0386, WordCat::ALetter),
+    (0x0387, 0x0387, WordCat::MidLetter),
+    (0x0388, 0x038A, WordCat::ALetter),
+    (0x038C, 0x038C, WordCat::ALetter),
+    (0x038E, 0x03A1, WordCat::ALetter),
+    (0x03A3, 0x03F5, WordCat::ALetter),
+    (0x03F7, 0x0481, WordCat::ALetter),
+    (0x0483, 0x0489, WordCat::Extend),
+    (0x048A, 0x052F, WordCat::ALetter),
+    (0x0531, 0x0556, WordCat::ALetter),
+    (0x0559, 0x055C, WordCat::ALetter),
+    (0x055E, 0x055E, WordCat::ALetter),
+    (0x055F, 0x055F, WordCat::MidLetter),
+    (0x0560, 0x0588, WordCat::ALetter),
+    (0x0589, 0x0589, WordCat::MidNum),
+    (0x058A, 0x058A, WordCat::ALetter),
+    (0x0591, 0x05BD, WordCat::Extend),
+    (0x05BF, 0x05BF, WordCat::Extend),
+    (0x05C1, 0x05C2, WordCat::Extend),
+    (0x05C4, 0x05C5, WordCat::Extend),
+    (0x05C7, 0x05C7, WordCat::Extend),
+    (0x05D0, 0x05EA, WordCat::HebrewLetter),
+    (0x05EF, 0x05F2, WordCat::HebrewLetter),
+    (0x05F3, 0x05F3, WordCat::ALetter),
+    (0x05F4, 0x05F4, WordCat::MidLetter),
+    (0x0600, 0x0605, WordCat::Format),
+    (0x060C, 0x060D, WordCat::MidNum),
+    (0x0610, 0x061A, WordCat::Extend),
+    (0x061C, 0x061C, WordCat::Format),
+    (0x0620, 0x064A, WordCat::ALetter),
+    (0x064B, 0x065F, WordCat::Extend),
+    (0x0660, 0x0669, WordCat::Numeric),
+    (0x066B, 0x066B, WordCat::Numeric),
+    (0x066C, 0x066C, WordCat::MidNum),
+    (0x066E, 0x066F, WordCat::ALetter),
+    (0x0670, 0x0670, WordCat::Extend),
+    (0x0671, 0x06D3, WordCat::ALetter),
+    (0x06D5, 0x06D5, WordCat::ALetter),
+    (0x06D6, 0x06DC, WordCat::Extend),
+    (0x06DD, 0x06DD, WordCat::Format),
+    (0x06DF, 0x06E4, WordCat::Extend),
+    (0x06E5, 0x06E6, WordCat::ALetter),
+    (0x06E7, 0x06E8, WordCat::Extend),
+    (0x06EA, 0x06ED, WordCat::Extend),
+    (0x06EE, 0x06EF, WordCat::ALetter),
+    (0x06F0, 0x06F9, WordCat::Numeric),
+    (0x06FA, 0x06FC, WordCat::ALetter),
+    (0x06FF, 0x06FF, WordCat::ALetter),
+    (0x070F, 0x070F, WordCat::Format),
+    (0x0710, 0x0710, WordCat::ALetter),
+    (0x0711, 0x0711, WordCat::Extend),
+    (0x0712, 0x072F, WordCat::ALetter),
+    (0x0730, 0x074A, WordCat::Extend),
+    (0x074D, 0x07A5, WordCat::ALetter),
+    (0x07A6, 0x07B0, WordCat::Extend),
+    (0x07B1, 0x07B1, WordCat::ALetter),
+    (0x07C0, 0x07C9, WordCat::Numeric),
+    (0x07CA, 0x07EA, WordCat::ALetter),
+    (0x07EB, 0x07F3, WordCat::Extend),
+    (0x07F4, 0x07F5, WordCat::ALetter),
+    (0x07F8, 0x07F8, WordCat::MidNum),
+    (0x07FA, 0x07FA, WordCat::ALetter),
+    (0x07FD, 0x07FD, WordCat::Extend),
+    (0x0800, 0x0815, WordCat::ALetter),
+    (0x0816, 0x0819, WordCat::Extend),
+    (0x081A, 0x081A, WordCat::ALetter),
+    (0x081B, 0x0823, WordCat::Extend),
+    (0x0824, 0x0824, WordCat::ALetter),
+    (0x0825, 0x0827, WordCat::Extend),
+    (0x0828, 0x0828, WordCat::ALetter),
+    (0x0829, 0x082D, WordCat::Extend),
+    (0x0840, 0x0858, WordCat::ALetter),
+    (0x0859, 0x085B, WordCat::Extend),
+    (0x0860, 0x086A, WordCat::ALetter),
+    (0x0870, 0x0887, WordCat::ALetter),
+    (0x0889, 0x088E, WordCat::ALetter),
+    (0x0890, 0x0891, WordCat::Format),
+    (0x0898, 0x089F, WordCat::Extend),
+    (0x08A0, 0x08C9, WordCat::ALetter),
+    (0x08CA, 0x08E1, WordCat::Extend),
+    (0x08E2, 0x08E2, WordCat::Format),
+    (0x08E3, 0x0903, WordCat::Extend),
+    (0x0904, 0x0939, WordCat::ALetter),
+    (0x093A, 0x093C, WordCat::Extend),
+    (0x093D, 0x093D, WordCat::ALetter),
+    (0x093E, 0x094F, WordCat::Extend),
+    (0x0950, 0x0950, WordCat::ALetter),
+    (0x0951, 0x0957, WordCat::Extend),
+    (0x0958, 0x0961, WordCat::ALetter),
+    (0x0962, 0x0963, WordCat::Extend),
+    (0x0966, 0x096F, WordCat::Numeric),
+    (0x0971, 0x0980, WordCat::ALetter),
+    (0x0981, 0x0983, WordCat::Extend),
+    (0x0985, 0x098C, WordCat::ALetter),
+    (0x098F, 0x0990, WordCat::ALetter),
+    (0x0993, 0x09A8, WordCat::ALetter),
+    (0x09AA, 0x09B0, WordCat::ALetter),
+    (0x09B2, 0x09B2, WordCat::ALetter),
+    (0x09B6, 0x09B9, WordCat::ALetter),
+    (0x09BC, 0x09BC, WordCat::Extend),
+    (0x09BD, 0x09BD, WordCat::ALetter),
+    (0x09BE, 0x09C4, WordCat::Extend),
+    (0x09C7, 0x09C8, WordCat::Extend),
+    (0x09CB, 0x09CD, WordCat::Extend),
+    (0x09CE, 0x09CE, WordCat::ALetter),
+    (0x09D7, 0x09D7, WordCat::Extend),
+    (0x09DC, 0x09DD, WordCat::ALetter),
+    (0x09DF, 0x09E1, WordCat::ALetter),
+    (0x09E2, 0x09E3, WordCat::Extend),
+    (0x09E6, 0x09EF, WordCat::Numeric),
+    (0x09F0, 0x09F1, WordCat::ALetter),
+    (0x09FC, 0x09FC, WordCat::ALetter),
+    (0x09FE, 0x09FE, WordCat::Extend),
+    (0x0A01, 0x0A03, WordCat::Extend),
+    (0x0A05, 0x0A0A, WordCat::ALetter),
+    (0x0A0F, 0x0A10, WordCat::ALetter),
+    (0x0A13, 0x0A28, WordCat::ALetter),
+    (0x0A2A, 0x0A30, WordCat::ALetter),
+    (0x0A32, 0x0A33, WordCat::ALetter),
+    (0x0A35, 0x0A36, WordCat::ALetter),
+    (0x0A38, 0x0A39, WordCat::ALetter),
+    (0x0A3C, 0x0A3C, WordCat::Extend),
+    (0x0A3E, 0x0A42, WordCat::Extend),
+    (0x0A47, 0x0A48, WordCat::Extend),
+    (0x0A4B, 0x0A4D, WordCat::Extend),
+    (0x0A51, 0x0A51, WordCat::Extend),
+    (0x0A59, 0x0A5C, WordCat::ALetter),
+    (0x0A5E, 0x0A5E, WordCat::ALetter),
+    (0x0A66, 0x0A6F, WordCat::Numeric),
+    (0x0A70, 0x0A71, WordCat::Extend),
+    (0x0A72, 0x0A74, WordCat::ALetter),
+    (0x0A75, 0x0A75, WordCat::Extend),
+    (0x0A81, 0x0A83, WordCat::Extend),
+    (0x0A85, 0x0A8D, WordCat::ALetter),
+    (0x0A8F, 0x0A91, WordCat::ALetter),
+    (0x0A93, 0x0AA8, WordCat::ALetter),
+    (0x0AAA, 0x0AB0, WordCat::ALetter),
+    (0x0AB2, 0x0AB3, WordCat::ALetter),
+    (0x0AB5, 0x0AB9, WordCat::ALetter),
+    (0x0ABC, 0x0ABC, WordCat::Extend),
+    (0x0ABD, 0x0ABD, WordCat::ALetter),
+    (0x0ABE, 0x0AC5, WordCat::Extend),
+    (0x0AC7, 0x0AC9, WordCat::Extend),
+    (0x0ACB, 0x0ACD, WordCat::Extend),
+    (0x0AD0, 0x0AD0, WordCat::ALetter),
+    (0x0AE0, 0x0AE1, WordCat::ALetter),
+    (0x0AE2, 0x0AE3, WordCat::Extend),
+    (0x0AE6, 0x0AEF, WordCat::Numeric),
+    (0x0AF9, 0x0AF9, WordCat::ALetter),
+    (0x0AFA, 0x0AFF, WordCat::Extend),
+    (0x0B01, 0x0B03, WordCat::Extend),
+    (0x0B05, 0x0B0C, WordCat::ALetter),
+    (0x0B0F, 0x0B10, WordCat::ALetter),
+    (0x0B13, 0x0B28, WordCat::ALetter),
+    (0x0B2A, 0x0B30, WordCat::ALetter),
+    (0x0B32, 0x0B33, WordCat::ALetter),
+    (0x0B35, 0x0B39, WordCat::ALetter),
+    (0x0B3C, 0x0B3C, WordCat::Extend),
+    (0x0B3D, 0x0B3D, WordCat::ALetter),
+    (0x0B3E, 0x0B44, WordCat::Extend),
+    (0x0B47, 0x0B48, WordCat::Extend),
+    (0x0B4B, 0x0B4D, WordCat::Extend),
+    (0x0B55, 0x0B57, WordCat::Extend),
+    (0x0B5C, 0x0B5D, WordCat::ALetter),
+    (0x0B5F, 0x0B61, WordCat::ALetter),
+    (0x0B62, 0x0B63, WordCat::Extend),
+    (0x0B66, 0x0B6F, WordCat::Numeric),
+    (0x0B71, 0x0B71, WordCat::ALetter),
+    (0x0B82, 0x0B82, WordCat::Extend),
+    (0x0B83, 0x0B83, WordCat::ALetter),
+    (0x0B85, 0x0B8A, WordCat::ALetter),
+    (0x0B8E, 0x0B90, WordCat::ALetter),
+    (0x0B92, 0x0B95, WordCat::ALetter),
+    (0x0B99, 0x0B9A, WordCat::ALetter),
+    (0x0B9C, 0x0B9C, WordCat::ALetter),
+    (0x0B9E, 0x0B9F, WordCat::ALetter),
+    (0x0BA3, 0x0BA4, WordCat::ALetter),
+    (0x0BA8, 0x0BAA, WordCat::ALetter),
+    (0x0BAE, 0x0BB9, WordCat::ALetter),
+    (0x0BBE, 0x0BC2, WordCat::Extend),
+    (0x0BC6, 0x0BC8, WordCat::Extend),
+    (0x0BCA, 0x0BCD, WordCat::Extend),
+    (0x0BD0, 0x0BD0, WordCat::ALetter),
+    (0x0BD7, 0x0BD7, WordCat::Extend),
+    (0x0BE6, 0x0BEF, WordCat::Numeric),
+    (0x0C00, 0x0C04, WordCat::Extend),
+    (0x0C05, 0x0C0C, WordCat::ALetter),
+    (0x0C0E, 0x0C10, WordCat::ALetter),
+    (0x0C12, 0x0C28, WordCat::ALetter),
+    (0x0C2A, 0x0C39, WordCat::ALetter),
+    (0x0C3C, 0x0C3C, WordCat::Extend),
+    (0x0C3D, 0x0C3D, WordCat::ALetter),
+    (0x0C3E, 0x0C44, WordCat::Extend),
+    (0x0C46, 0x0C48, WordCat::Extend),
+    (0x0C4A, 0x0C4D, WordCat::Extend),
+    (0x0C55, 0x0C56, WordCat::Extend),
+    (0x0C58, 0x0C5A, WordCat::ALetter),
+    (0x0C5D, 0x0C5D, WordCat::ALetter),
+    (0x0C60, 0x0C61, WordCat::ALetter),
+    (0x0C62, 0x0C63, WordCat::Extend),
+    (0x0C66, 0x0C6F, WordCat::Numeric),
+    (0x0C80, 0x0C80, WordCat::ALetter),
+    (0x0C81, 0x0C83, WordCat::Extend),
+    (0x0C85, 0x0C8C, WordCat::ALetter),
+    (0x0C8E, 0x0C90, WordCat::ALetter),
+    (0x0C92, 0x0CA8, WordCat::ALetter),
+    (0x0CAA, 0x0CB3, WordCat::ALetter),
+    (0x0CB5, 0x0CB9, WordCat::ALetter),
+    (0x0CBC, 0x0CBC, WordCat::Extend),
+    (0x0CBD, 0x0CBD, WordCat::ALetter),
+    (0x0CBE, 0x0CC4, WordCat::Extend),
+    (0x0CC6, 0x0CC8, WordCat::Extend),
+    (0x0CCA, 0x0CCD, WordCat::Extend),
+    (0x0CD5, 0x0CD6, WordCat::Extend),
+    (0x0CDD, 0x0CDE, WordCat::ALetter),
+    (0x0CE0, 0x0CE1, WordCat::ALetter),
+    (0x0CE2, 0x0CE3, WordCat::Extend),
+    (0x0CE6, 0x0CEF, WordCat::Numeric),
+    (0x0CF1, 0x0CF2, WordCat::ALetter),
+    (0x0D00, 0x0D03, WordCat::Extend),
+    (0x0D04, 0x0D0C, WordCat::ALetter),
+    (0x0D0E, 0x0D10, WordCat::ALetter),
+    (0x0D12, 0x0D3A, WordCat::ALetter),
+    (0x0D3B, 0x0D3C, WordCat::Extend),
+    (0x0D3D, 0x0D3D, WordCat::ALetter),
+    (0x0D3E, 0x0D44, WordCat::Extend),
+    (0x0D46, 0x0D48, WordCat::Extend),
+    (0x0D4A, 0x0D4D, WordCat::Extend),
+    (0x0D4E, 0x0D4E, WordCat::ALetter),
+    (0x0D54, 0x0D56, WordCat::ALetter),
+    (0x0D57, 0x0D57, WordCat::Extend),
+    (0x0D5F, 0x0D61, WordCat::ALetter),
+    (0x0D62, 0x0D63, WordCat::Extend),
+    (0x0D66, 0x0D6F, WordCat::Numeric),
+    (0x0D7A, 0x0D7F, WordCat::ALetter),
+    (0x0D81, 0x0D83, WordCat::Extend),
+    (0x0D85, 0x0D96, WordCat::ALetter),
+    (0x0D9A, 0x0DB1, WordCat::ALetter),
+    (0x0DB3, 0x0DBB, WordCat::ALetter),
+    (0x0DBD, 0x0DBD, WordCat::ALetter),
+    (0x0DC0, 0x0DC6, WordCat::ALetter),
+    (0x0DCA, 0x0DCA, WordCat::Extend),
+    (0x0DCF, 0x0DD4, WordCat::Extend),
+    (0x0DD6, 0x0DD6, WordCat::Extend),
+    (0x0DD8, 0x0DDF, WordCat::Extend),
+    (0x0DE6, 0x0DEF, WordCat::Numeric),
+    (0x0DF2, 0x0DF3, WordCat::Extend),
+    (0x0E31, 0x0E31, WordCat::Extend),
+    (0x0E34, 0x0E3A, WordCat::Extend),
+    (0x0E47, 0x0E4E, WordCat::Extend),
+    (0x0E50, 0x0E59, WordCat::Numeric),
+    (0x0EB1, 0x0EB1, WordCat::Extend),
+    (0x0EB4, 0x0EBC, WordCat::Extend),
+    (0x0EC8, 0x0ECD, WordCat::Extend),
+    (0x0ED0, 0x0ED9, WordCat::Numeric),
+    (0x0F00, 0x0F00, WordCat::ALetter),
+    (0x0F18, 0x0F19, WordCat::Extend),
+    (0x0F20, 0x0F29, WordCat::Numeric),
+    (0x0F35, 0x0F35, WordCat::Extend),
+    (0x0F37, 0x0F37, WordCat::Extend),
+    (0x0F39, 0x0F39, WordCat::Extend),
+    (0x0F3E, 0x0F3F, WordCat::Extend),
+    (0x0F40, 0x0F47, WordCat::ALetter),
+    (0x0F49, 0x0F6C, WordCat::ALetter),
+    (0x0F71, 0x0F84, WordCat::Extend),
+    (0x0F86, 0x0F87, WordCat::Extend),
+    (0x0F88, 0x0F8C, WordCat::ALetter),
+    (0x0F8D, 0x0F97, WordCat::Extend),
+    (0x0F99, 0x0FBC, WordCat::Extend),
+    (0x0FC6, 0x0FC6, WordCat::Extend),
+    (0x102B, 0x103E, WordCat::Extend),
+    (0x1040, 0x1049, WordCat::Numeric),
+    (0x1056, 0x1059, WordCat::Extend),
+    (0x105E, 0x1060, WordCat::Extend),
+    (0x1062, 0x1064, WordCat::Extend),
+    (0x1067, 0x106D, WordCat::Extend),
+    (0x1071, 0x1074, WordCat::Extend),
+    (0x1082, 0x108D, WordCat::Extend),
+    (0x108F, 0x108F, WordCat::Extend),
+    (0x1090, 0x1099, WordCat::Numeric),
+    (0x109A, 0x109D, WordCat::Extend),
+    (0x10A0, 0x10C5, WordCat::ALetter),
+    (0x10C7, 0x10C7, WordCat::ALetter),
+    (0x10CD, 0x10CD, WordCat::ALetter),
+    (0x10D0, 0x10FA, WordCat::ALetter),
+    (0x10FC, 0x1248, WordCat::ALetter),
+    (0x124A, 0x124D, WordCat::ALetter),
+    (0x1250, 0x1256, WordCat::ALetter),
+    (0x1258, 0x1258, WordCat::ALetter),
+    (0x125A, 0x125D, WordCat::ALetter),
+    (0x1260, 0x1288, WordCat::ALetter),
+    (0x128A, 0x128D, WordCat::ALetter),
+    (0x1290, 0x12B0, WordCat::ALetter),
+    (0x12B2, 0x12B5, WordCat::ALetter),
+    (0x12B8, 0x12BE, WordCat::ALetter),
+    (0x12C0, 0x12C0, WordCat::ALetter),
+    (0x12C2, 0x12C5, WordCat::ALetter),
+    (0x12C8, 0x12D6, WordCat::ALetter),
+    (0x12D8, 0x1310, WordCat::ALetter),
+    (0x1312, 0x1315, WordCat::ALetter),
+    (0x1318, 0x135A, WordCat::ALetter),
+    (0x135D, 0x135F, WordCat::Extend),
+    (0x1380, 0x138F, WordCat::ALetter),
+    (0x13A0, 0x13F5, WordCat::ALetter),
+    (0x13F8, 0x13FD, WordCat::ALetter),
+    (0x1401, 0x166C, WordCat::ALetter),
+    (0x166F, 0x167F, WordCat::ALetter),
+    (0x1680, 0x1680, WordCat::WSegSpace),
+    (0x1681, 0x169A, WordCat::ALetter),
+    (0x16A0, 0x16EA, WordCat::ALetter),
+    (0x16EE, 0x16F8, WordCat::ALetter),
+    (0x1700, 0x1711, WordCat::ALetter),
+    (0x1712, 0x1715, WordCat::Extend),
+    (0x171F, 0x1731, WordCat::ALetter),
+    (0x1732, 0x1734, WordCat::Extend),
+    (0x1740, 0x1751, WordCat::ALetter),
+    (0x1752, 0x1753, WordCat::Extend),
+    (0x1760, 0x176C, WordCat::ALetter),
+    (0x176E, 0x1770, WordCat::ALetter),
+    (0x1772, 0x1773, WordCat::Extend),
+    (0x17B4, 0x17D3, WordCat::Extend),
+    (0x17DD, 0x17DD, WordCat::Extend),
+    (0x17E0, 0x17E9, WordCat::Numeric),
+    (0x180B, 0x180D, WordCat::Extend),
+    (0x180E, 0x180E, WordCat::Format),
+    (0x180F, 0x180F, WordCat::Extend),
+    (0x1810, 0x1819, WordCat::Numeric),
+    (0x1820, 0x1878, WordCat::ALetter),
+    (0x1880, 0x1884, WordCat::ALetter),
+    (0x1885, 0x1886, WordCat::Extend),
+    (0x1887, 0x18A8, WordCat::ALetter),
+    (0x18A9, 0x18A9, WordCat::Extend),
+    (0x18AA, 0x18AA, WordCat::ALetter),
+    (0x18B0, 0x18F5, WordCat::ALetter),
+    (0x1900, 0x191E, WordCat::ALetter),
+    (0x1920, 0x192B, WordCat::Extend),
+    (0x1930, 0x193B, WordCat::Extend),
+    (0x1946, 0x194F, WordCat::Numeric),
+    (0x19D0, 0x19D9, WordCat::Numeric),
+    (0x1A00, 0x1A16, WordCat::ALetter),
+    (0x1A17, 0x1A1B, WordCat::Extend),
+    (0x1A55, 0x1A5E, WordCat::Extend),
+    (0x1A60, 0x1A7C, WordCat::Extend),
+    (0x1A7F, 0x1A7F, WordCat::Extend),
+    (0x1A80, 0x1A89, WordCat::Numeric),
+    (0x1A90, 0x1A99, WordCat::Numeric),
+    (0x1AB0, 0x1ACE, WordCat::Extend),
+    (0x1B00, 0x1B04, WordCat::Extend),
+    (0x1B05, 0x1B33, WordCat::ALetter),
+    (0x1B34, 0x1B44, WordCat::Extend),
+    (0x1B45, 0x1B4C, WordCat::ALetter),
+    (0x1B50, 0x1B59, WordCat::Numeric),
+    (0x1B6B, 0x1B73, WordCat::Extend),
+    (0x1B80, 0x1B82, WordCat::Extend),
+    (0x1B83, 0x1BA0, WordCat::ALetter),
+    (0x1BA1, 0x1BAD, WordCat::Extend),
+    (0x1BAE, 0x1BAF, WordCat::ALetter),
+    (0x1BB0, 0x1BB9, WordCat::Numeric),
+    (0x1BBA, 0x1BE5, WordCat::ALetter),
+    (0x1BE6, 0x1BF3, WordCat::Extend),
+    (0x1C00, 0x1C23, WordCat::ALetter),
+    (0x1C24, 0x1C37, WordCat::Extend),
+    (0x1C40, 0x1C49, WordCat::Numeric),
+    (0x1C4D, 0x1C4F, WordCat::ALetter),
+    (0x1C50, 0x1C59, WordCat::Numeric),
+    (0x1C5A, 0x1C7D, WordCat::ALetter),
+    (0x1C80, 0x1C88, WordCat::ALetter),
+    (0x1C90, 0x1CBA, WordCat::ALetter),
+    (0x1CBD, 0x1CBF, WordCat::ALetter),
+    (0x1CD0, 0x1CD2, WordCat::Extend),
+    (0x1CD4, 0x1CE8, WordCat::Extend),
+    (0x1CE9, 0x1CEC, WordCat::ALetter),
+    (0x1CED, 0x1CED, WordCat::Extend),
+    (0x1CEE, 0x1CF3, WordCat::ALetter),
+    (0x1CF4, 0x1CF4, WordCat::Extend),
+    (0x1CF5, 0x1CF6, WordCat::ALetter),
+    (0x1CF7, 0x1CF9, WordCat::Extend),
+    (0x1CFA, 0x1CFA, WordCat::ALetter),
+    (0x1D00, 0x1DBF, WordCat::ALetter),
+    (0x1DC0, 0x1DFF, WordCat::Extend),
+    (0x1E00, 0x1F15, WordCat::ALetter),
+    (0x1F18, 0x1F1D, WordCat::ALetter),
+    (0x1F20, 0x1F45, WordCat::ALetter),
+    (0x1F48, 0x1F4D, WordCat::ALetter),
+    (0x1F50, 0x1F57, WordCat::ALetter),
+    (0x1F59, 0x1F59, WordCat::ALetter),
+    (0x1F5B, 0x1F5B, WordCat::ALetter),
+    (0x1F5D, 0x1F5D, WordCat::ALetter),
+    (0x1F5F, 0x1F7D, WordCat::ALetter),
+    (0x1F80, 0x1FB4, WordCat::ALetter),
+    (0x1FB6, 0x1FBC, WordCat::ALetter),
+    (0x1FBE, 0x1FBE, WordCat::ALetter),
+    (0x1FC2, 0x1FC4, WordCat::ALetter),
+    (0x1FC6, 0x1FCC, WordCat::ALetter),
+    (0x1FD0, 0x1FD3, WordCat::ALetter),
+    (0x1FD6, 0x1FDB, WordCat::ALetter),
+    (0x1FE0, 0x1FEC, WordCat::ALetter),
+    (0x1FF2, 0x1FF4, WordCat::ALetter),
+    (0x1FF6, 0x1FFC, WordCat::ALetter),
+    (0x2000, 0x2006, WordCat::WSegSpace),
+    (0x2008, 0x200A, WordCat::WSegSpace),
+    (0x200C, 0x200C, WordCat::Extend),
+    (0x200D, 0x200D, WordCat::ZWJ),
+    (0x200E, 0x200F, WordCat::Format),
+    (0x2018, 0x2019, WordCat::MidNumLet),
+    (0x2024, 0x2024, WordCat::MidNumLet),
+    (0x2027, 0x2027, WordCat::MidLetter),
+    (0x2028, 0x2029, WordCat::Newline),
+    (0x202A, 0x202E, WordCat::Format),
+    (0x202F, 0x202F, WordCat::ExtendNumLet),
+    (0x203F, 0x2040, WordCat::ExtendNumLet),
+    (0x2044, 0x2044, WordCat::MidNum),
+    (0x2054, 0x2054, WordCat::ExtendNumLet),
+    (0x205F, 0x205F, WordCat::WSegSpace),
+    (0x2060, 0x2064, WordCat::Format),
+    (0x2066, 0x206F, WordCat::Format),
+    (0x2071, 0x2071, WordCat::ALetter),
+    (0x207F, 0x207F, WordCat::ALetter),
+    (0x2090, 0x209C, WordCat::ALetter),
+    (0x20D0, 0x20F0, WordCat::Extend),
+    (0x2102, 0x2102, WordCat::ALetter),
+    (0x2107, 0x2107, WordCat::ALetter),
+    (0x210A, 0x2113, WordCat::ALetter),
+    (0x2115, 0x2115, WordCat::ALetter),
+    (0x2119, 0x211D, WordCat::ALetter),
+    (0x2124, 0x2124, WordCat::ALetter),
+    (0x2126, 0x2126, WordCat::ALetter),
+    (0x2128, 0x2128, WordCat::ALetter),
+    (0x212A, 0x212D, WordCat::ALetter),
+    (0x212F, 0x2139, WordCat::ALetter),
+    (0x213C, 0x213F, WordCat::ALetter),
+    (0x2145, 0x2149, WordCat::ALetter),
+    (0x214E, 0x214E, WordCat::ALetter),
+    (0x2160, 0x2188, WordCat::ALetter),
+    (0x24B6, 0x24E9, WordCat::ALetter),
+    (0x2C00, 0x2CE4, WordCat::ALetter),
+    (0x2CEB, 0x2CEE, WordCat::ALetter),
+    (0x2CEF, 0x2CF1, WordCat::Extend),
+    (0x2CF2, 0x2CF3, WordCat::ALetter),
+    (0x2D00, 0x2D25, WordCat::ALetter),
+    (0x2D27, 0x2D27, WordCat::ALetter),
+    (0x2D2D, 0x2D2D, WordCat::ALetter),
+    (0x2D30, 0x2D67, WordCat::ALetter),
+    (0x2D6F, 0x2D6F, WordCat::ALetter),
+    (0x2D7F, 0x2D7F, WordCat::Extend),
+    (0x2D80, 0x2D96, WordCat::ALetter),
+    (0x2DA0, 0x2DA6, WordCat::ALetter),
+    (0x2DA8, 0x2DAE, WordCat::ALetter),
+    (0x2DB0, 0x2DB6, WordCat::ALetter),
+    (0x2DB8, 0x2DBE, WordCat::ALetter),
+    (0x2DC0, 0x2DC6, WordCat::ALetter),
+    (0x2DC8, 0x2DCE, WordCat::ALetter),
+    (0x2DD0, 0x2DD6, WordCat::ALetter),
+    (0x2DD8, 0x2DDE, WordCat::ALetter),
+    (0x2DE0, 0x2DFF, WordCat::Extend),
+    (0x2E2F, 0x2E2F, WordCat::ALetter),
+    (0x3000, 0x3000, WordCat::WSegSpace),
+    (0x3005, 0x3005, WordCat::ALetter),
+    (0x302A, 0x302F, WordCat::Extend),
+    (0x3031, 0x3035, WordCat::Katakana),
+    (0x303B, 0x303C, WordCat::ALetter),
+    (0x3099, 0x309A, WordCat::Extend),
+    (0x309B, 0x309C, WordCat::Katakana),
+    (0x30A0, 0x30FA, WordCat::Katakana),
+    (0x30FC, 0x30FF, WordCat::Katakana),
+    (0x3105, 0x312F, WordCat::ALetter),
+    (0x3131, 0x318E, WordCat::ALetter),
+    (0x31A0, 0x31BF, WordCat::ALetter),
+    (0x31F0, 0x31FF, WordCat::Katakana),
+    (0x32D0, 0x32FE, WordCat::Katakana),
+    (0x3300, 0x3357, WordCat::Katakana),
+    (0xA000, 0xA48C, WordCat::ALetter),
+    (0xA4D0, 0xA4FD, WordCat::ALetter),
+    (0xA500, 0xA60C, WordCat::ALetter),
+    (0xA610, 0xA61F, WordCat::ALetter),
+    (0xA620, 0xA629, WordCat::Numeric),
+    (0xA62A, 0xA62B, WordCat::ALetter),
+    (0xA640, 0xA66E, WordCat::ALetter),
+    (0xA66F, 0xA672, WordCat::Extend),
+    (0xA674, 0xA67D, WordCat::Extend),
+    (0xA67F, 0xA69D, WordCat::ALetter),
+    (0xA69E, 0xA69F, WordCat::Extend),
+    (0xA6A0, 0xA6EF, WordCat::ALetter),
+    (0xA6F0, 0xA6F1, WordCat::Extend),
+    (0xA708, 0xA7CA, WordCat::ALetter),
+    (0xA7D0, 0xA7D1, WordCat::ALetter),
+    (0xA7D3, 0xA7D3, WordCat::ALetter),
+    (0xA7D5, 0xA7D9, WordCat::ALetter),
+    (0xA7F2, 0xA801, WordCat::ALetter),
+    (0xA802, 0xA802, WordCat::Extend),
+    (0xA803, 0xA805, WordCat::ALetter),
+    (0xA806, 0xA806, WordCat::Extend),
+    (0xA807, 0xA80A, WordCat::ALetter),
+    (0xA80B, 0xA80B, WordCat::Extend),
+    (0xA80C, 0xA822, WordCat::ALetter),
+    (0xA823, 0xA827, WordCat::Extend),
+    (0xA82C, 0xA82C, WordCat::Extend),
+    (0xA840, 0xA873, WordCat::ALetter),
+    (0xA880, 0xA881, WordCat::Extend),
+    (0xA882, 0xA8B3, WordCat::ALetter),
+    (0xA8B4, 0xA8C5, WordCat::Extend),
+    (0xA8D0, 0xA8D9, WordCat::Numeric),
+    (0xA8E0, 0xA8F1, WordCat::Extend),
+    (0xA8F2, 0xA8F7, WordCat::ALetter),
+    (0xA8FB, 0xA8FB, WordCat::ALetter),
+    (0xA8FD, 0xA8FE, WordCat::ALetter),
+    (0xA8FF, 0xA8FF, WordCat::Extend),
+    (0xA900, 0xA909, WordCat::Numeric),
+    (0xA90A, 0xA925, WordCat::ALetter),
+    (0xA926, 0xA92D, WordCat::Extend),
+    (0xA930, 0xA946, WordCat::ALetter),
+    (0xA947, 0xA953, WordCat::Extend),
+    (0xA960, 0xA97C, WordCat::ALetter),
+    (0xA980, 0xA983, WordCat::Extend),
+    (0xA984, 0xA9B2, WordCat::ALetter),
+    (0xA9B3, 0xA9C0, WordCat::Extend),
+    (0xA9CF, 0xA9CF, WordCat::ALetter),
+    (0xA9D0, 0xA9D9, WordCat::Numeric),
+    (0xA9E5, 0xA9E5, WordCat::Extend),
+    (0xA9F0, 0xA9F9, WordCat::Numeric),
+    (0xAA00, 0xAA28, WordCat::ALetter),
+    (0xAA29, 0xAA36, WordCat::Extend),
+    (0xAA40, 0xAA42, WordCat::ALetter),
+    (0xAA43, 0xAA43, WordCat::Extend),
+    (0xAA44, 0xAA4B, WordCat::ALetter),
+    (0xAA4C, 0xAA4D, WordCat::Extend),
+    (0xAA50, 0xAA59, WordCat::Numeric),
+    (0xAA7B, 0xAA7D, WordCat::Extend),
+    (0xAAB0, 0xAAB0, WordCat::Extend),
+    (0xAAB2, 0xAAB4, WordCat::Extend),
+    (0xAAB7, 0xAAB8, WordCat::Extend),
+    (0xAABE, 0xAABF, WordCat::Extend),
+    (0xAAC1, 0xAAC1, WordCat::Extend),
+    (0xAAE0, 0xAAEA, WordCat::ALetter),
+    (0xAAEB, 0xAAEF, WordCat::Extend),
+    (0xAAF2, 0xAAF4, WordCat::ALetter),
+    (0xAAF5, 0xAAF6, WordCat::Extend),
+    (0xAB01, 0xAB06, WordCat::ALetter),
+    (0xAB09, 0xAB0E, WordCat::ALetter),
+    (0xAB11, 0xAB16, WordCat::ALetter),
+    (0xAB20, 0xAB26, WordCat::ALetter),
+    (0xAB28, 0xAB2E, WordCat::ALetter),
+    (0xAB30, 0xAB69, WordCat::ALetter),
+    (0xAB70, 0xABE2, WordCat::ALetter),
+    (0xABE3, 0xABEA, WordCat::Extend),
+    (0xABEC, 0xABED, WordCat::Extend),
+    (0xABF0, 0xABF9, WordCat::Numeric),
+    (0xAC00, 0xD7A3, WordCat::ALetter),
+    (0xD7B0, 0xD7C6, WordCat::ALetter),
+    (0xD7CB, 0xD7FB, WordCat::ALetter),
+    (0xFB00, 0xFB06, WordCat::ALetter),
+    (0xFB13, 0xFB17, WordCat::ALetter),
+    (0xFB1D, 0xFB1D, WordCat::HebrewLetter),
+    (0xFB1E, 0xFB1E, WordCat::Extend),
+    (0xFB1F, 0xFB28, WordCat::HebrewLetter),
+    (0xFB2A, 0xFB36, WordCat::HebrewLetter),
+    (0xFB38, 0xFB3C, WordCat::HebrewLetter),
+    (0xFB3E, 0xFB3E, WordCat::HebrewLetter),
+    (0xFB40, 0xFB41, WordCat::HebrewLetter),
+    (0xFB43, 0xFB44, WordCat::HebrewLetter),
+    (0xFB46, 0xFB4F, WordCat::HebrewLetter),
+    (0xFB50, 0xFBB1, WordCat::ALetter),
+    (0xFBD3, 0xFD3D, WordCat::ALetter),
+    (0xFD50, 0xFD8F, WordCat::ALetter),
+    (0xFD92, 0xFDC7, WordCat::ALetter),
+    (0xFDF0, 0xFDFB, WordCat::ALetter),
+    (0xFE00, 0xFE0F, WordCat::Extend),
+    (0xFE10, 0xFE10, WordCat::MidNum),
+    (0xFE13, 0xFE13, WordCat::MidLetter),
+    (0xFE14, 0xFE14, WordCat::MidNum),
+    (0xFE20, 0xFE2F, WordCat::Extend),
+    (0xFE33, 0xFE34, WordCat::ExtendNumLet),
+    (0xFE4D, 0xFE4F, WordCat::ExtendNumLet),
+    (0xFE50, 0xFE50, WordCat::MidNum),
+    (0xFE52, 0xFE52, WordCat::MidNumLet),
+    (0xFE54, 0xFE54, WordCat::MidNum),
+    (0xFE55, 0xFE55, WordCat::MidLetter),
+    (0xFE70, 0xFE74, WordCat::ALetter),
+    (0xFE76, 0xFEFC, WordCat::ALetter),
+    (0xFEFF, 0xFEFF, WordCat::Format),
+    (0xFF07, 0xFF07, WordCat::MidNumLet),
+    (0xFF0C, 0xFF0C, WordCat::MidNum),
+    (0xFF0E, 0xFF0E, WordCat::MidNumLet),
+    (0xFF10, 0xFF19, WordCat::Numeric),
+    (0xFF1A, 0xFF1A, WordCat::MidLetter),
+    (0xFF1B, 0xFF1B, WordCat::MidNum),
+    (0xFF21, 0xFF3A, WordCat::ALetter),
+    (0xFF3F, 0xFF3F, WordCat::ExtendNumLet),
+    (0xFF41, 0xFF5A, WordCat::ALetter),
+    (0xFF66, 0xFF9D, WordCat::Katakana),
+    (0xFF9E, 0xFF9F, WordCat::Extend),
+    (0xFFA0, 0xFFBE, WordCat::ALetter),
+    (0xFFC2, 0xFFC7, WordCat::ALetter),
+    (0xFFCA, 0xFFCF, WordCat::ALetter),
+    (0xFFD2, 0xFFD7, WordCat::ALetter),
+    (0xFFDA, 0xFFDC, WordCat::ALetter),
+    (0xFFF9, 0xFFFB, WordCat::Format),
+    (0x10000, 0x1000B, WordCat::ALetter),
+    (0x1000D, 0x10026, WordCat::ALetter),
+    (0x10028, 0x1003A, WordCat::ALetter),
+    (0x1003C, 0x1003D, WordCat::ALetter),
+    (0x1003F, 0x1004D, WordCat::ALetter),
+    (0x10050, 0x1005D, WordCat::ALetter),
+    (0x10080, 0x100FA, WordCat::ALetter),
+    (0x10140, 0x10174, WordCat::ALetter),
+    (0x101FD, 0x101FD, WordCat::Extend),
+    (0x10280, 0x1029C, WordCat::ALetter),
+    (0x102A0, 0x102D0, WordCat::ALetter),
+    (0x102E0, 0x102E0, WordCat::Extend),
+    (0x10300, 0x1031F, WordCat::ALetter),
+    (0x1032D, 0x1034A, WordCat::ALetter),
+    (0x10350, 0x10375, WordCat::ALetter),
+    (0x10376, 0x1037A, WordCat::Extend),
+    (0x10380, 0x1039D, WordCat::ALetter),
+    (0x103A0, 0x103C3, WordCat::ALetter),
+    (0x103C8, 0x103CF, WordCat::ALetter),
+    (0x103D1, 0x103D5, WordCat::ALetter),
+    (0x10400, 0x1049D, WordCat::ALetter),
+    (0x104A0, 0x104A9, WordCat::Numeric),
+    (0x104B0, 0x104D3, WordCat::ALetter),
+    (0x104D8, 0x104FB, WordCat::ALetter),
+    (0x10500, 0x10527, WordCat::ALetter),
+    (0x10530, 0x10563, WordCat::ALetter),
+    (0x10570, 0x1057A, WordCat::ALetter),
+    (0x1057C, 0x1058A, WordCat::ALetter),
+    (0x1058C, 0x10592, WordCat::ALetter),
+    (0x10594, 0x10595, WordCat::ALetter),
+    (0x10597, 0x105A1, WordCat::ALetter),
+    (0x105A3, 0x105B1, WordCat::ALetter),
+    (0x105B3, 0x105B9, WordCat::ALetter),
+    (0x105BB, 0x105BC, WordCat::ALetter),
+    (0x10600, 0x10736, WordCat::ALetter),
+    (0x10740, 0x10755, WordCat::ALetter),
+    (0x10760, 0x10767, WordCat::ALetter),
+    (0x10780, 0x10785, WordCat::ALetter),
+    (0x10787, 0x107B0, WordCat::ALetter),
+    (0x107B2, 0x107BA, WordCat::ALetter),
+    (0x10800, 0x10805, WordCat::ALetter),
+    (0x10808, 0x10808, WordCat::ALetter),
+    (0x1080A, 0x10835, WordCat::ALetter),
+    (0x10837, 0x10838, WordCat::ALetter),
+    (0x1083C, 0x1083C, WordCat::ALetter),
+    (0x1083F, 0x10855, WordCat::ALetter),
+    (0x10860, 0x10876, WordCat::ALetter),
+    (0x10880, 0x1089E, WordCat::ALetter),
+    (0x108E0, 0x108F2, WordCat::ALetter),
+    (0x108F4, 0x108F5, WordCat::ALetter),
+    (0x10900, 0x10915, WordCat::ALetter),
+    (0x10920, 0x10939, WordCat::ALetter),
+    (0x10980, 0x109B7, WordCat::ALetter),
+    (0x109BE, 0x109BF, WordCat::ALetter),
+    (0x10A00, 0x10A00, WordCat::ALetter),
+    (0x10A01, 0x10A03, WordCat::Extend),
+    (0x10A05, 0x10A06, WordCat::Extend),
+    (0x10A0C, 0x10A0F, WordCat::Extend),
+    (0x10A10, 0x10A13, WordCat::ALetter),
+    (0x10A15, 0x10A17, WordCat::ALetter),
+    (0x10A19, 0x10A35, WordCat::ALetter),
+    (0x10A38, 0x10A3A, WordCat::Extend),
+    (0x10A3F, 0x10A3F, WordCat::Extend),
+    (0x10A60, 0x10A7C, WordCat::ALetter),
+    (0x10A80, 0x10A9C, WordCat::ALetter),
+    (0x10AC0, 0x10AC7, WordCat::ALetter),
+    (0x10AC9, 0x10AE4, WordCat::ALetter),
+    (0x10AE5, 0x10AE6, WordCat::Extend),
+    (0x10B00, 0x10B35, WordCat::ALetter),
+    (0x10B40, 0x10B55, WordCat::ALetter),
+    (0x10B60, 0x10B72, WordCat::ALetter),
+    (0x10B80, 0x10B91, WordCat::ALetter),
+    (0x10C00, 0x10C48, WordCat::ALetter),
+    (0x10C80, 0x10CB2, WordCat::ALetter),
+    (0x10CC0, 0x10CF2, WordCat::ALetter),
+    (0x10D00, 0x10D23, WordCat::ALetter),
+    (0x10D24, 0x10D27, WordCat::Extend),
+    (0x10D30, 0x10D39, WordCat::Numeric),
+    (0x10E80, 0x10EA9, WordCat::ALetter),
+    (0x10EAB, 0x10EAC, WordCat::Extend),
+    (0x10EB0, 0x10EB1, WordCat::ALetter),
+    (0x10F00, 0x10F1C, WordCat::ALetter),
+    (0x10F27, 0x10F27, WordCat::ALetter),
+    (0x10F30, 0x10F45, WordCat::ALetter),
+    (0x10F46, 0x10F50, WordCat::Extend),
+    (0x10F70, 0x10F81, WordCat::ALetter),
+    (0x10F82, 0x10F85, WordCat::Extend),
+    (0x10FB0, 0x10FC4, WordCat::ALetter),
+    (0x10FE0, 0x10FF6, WordCat::ALetter),
+    (0x11000, 0x11002, WordCat::Extend),
+    (0x11003, 0x11037, WordCat::ALetter),
+    (0x11038, 0x11046, WordCat::Extend),
+    (0x11066, 0x1106F, WordCat::Numeric),
+    (0x11070, 0x11070, WordCat::Extend),
+    (0x11071, 0x11072, WordCat::ALetter),
+    (0x11073, 0x11074, WordCat::Extend),
+    (0x11075, 0x11075, WordCat::ALetter),
+    (0x1107F, 0x11082, WordCat::Extend),
+    (0x11083, 0x110AF, WordCat::ALetter),
+    (0x110B0, 0x110BA, WordCat::Extend),
+    (0x110BD, 0x110BD, WordCat::Format),
+    (0x110C2, 0x110C2, WordCat::Extend),
+    (0x110CD, 0x110CD, WordCat::Format),
+    (0x110D0, 0x110E8, WordCat::ALetter),
+    (0x110F0, 0x110F9, WordCat::Numeric),
+    (0x11100, 0x11102, WordCat::Extend),
+    (0x11103, 0x11126, WordCat::ALetter),
+    (0x11127, 0x11134, WordCat::Extend),
+    (0x11136, 0x1113F, WordCat::Numeric),
+    (0x11144, 0x11144, WordCat::ALetter),
+    (0x11145, 0x11146, WordCat::Extend),
+    (0x11147, 0x11147, WordCat::ALetter),
+    (0x11150, 0x11172, WordCat::ALetter),
+    (0x11173, 0x11173, WordCat::Extend),
+    (0x11176, 0x11176, WordCat::ALetter),
+    (0x11180, 0x11182, WordCat::Extend),
+    (0x11183, 0x111B2, WordCat::ALetter),
+    (0x111B3, 0x111C0, WordCat::Extend),
+    (0x111C1, 0x111C4, WordCat::ALetter),
+    (0x111C9, 0x111CC, WordCat::Extend),
+    (0x111CE, 0x111CF, WordCat::Extend),
+    (0x111D0, 0x111D9, WordCat::Numeric),
+    (0x111DA, 0x111DA, WordCat::ALetter),
+    (0x111DC, 0x111DC, WordCat::ALetter),
+    (0x11200, 0x11211, WordCat::ALetter),
+    (0x11213, 0x1122B, WordCat::ALetter),
+    (0x1122C, 0x11237, WordCat::Extend),
+    (0x1123E, 0x1123E, WordCat::Extend),
+    (0x11280, 0x11286, WordCat::ALetter),
+    (0x11288, 0x11288, WordCat::ALetter),
+    (0x1128A, 0x1128D, WordCat::ALetter),
+    (0x1128F, 0x1129D, WordCat::ALetter),
+    (0x1129F, 0x112A8, WordCat::ALetter),
+    (0x112B0, 0x112DE, WordCat::ALetter),
+    (0x112DF, 0x112EA, WordCat::Extend),
+    (0x112F0, 0x112F9, WordCat::Numeric),
+    (0x11300, 0x11303, WordCat::Extend),
+    (0x11305, 0x1130C, WordCat::ALetter),
+    (0x1130F, 0x11310, WordCat::ALetter),
+    (0x11313, 0x11328, WordCat::ALetter),
+    (0x1132A, 0x11330, WordCat::ALetter),
+    (0x11332, 0x11333, WordCat::ALetter),
+    (0x11335, 0x11339, WordCat::ALetter),
+    (0x1133B, 0x1133C, WordCat::Extend),
+    (0x1133D, 0x1133D, WordCat::ALetter),
+    (0x1133E, 0x11344, WordCat::Extend),
+    (0x11347, 0x11348, WordCat::Extend),
+    (0x1134B, 0x1134D, WordCat::Extend),
+    (0x11350, 0x11350, WordCat::ALetter),
+    (0x11357, 0x11357, WordCat::Extend),
+    (0x1135D, 0x11361, WordCat::ALetter),
+    (0x11362, 0x11363, WordCat::Extend),
+    (0x11366, 0x1136C, WordCat::Extend),
+    (0x11370, 0x11374, WordCat::Extend),
+    (0x11400, 0x11434, WordCat::ALetter),
+    (0x11435, 0x11446, WordCat::Extend),
+    (0x11447, 0x1144A, WordCat::ALetter),
+    (0x11450, 0x11459, WordCat::Numeric),
+    (0x1145E, 0x1145E, WordCat::Extend),
+    (0x1145F, 0x11461, WordCat::ALetter),
+    (0x11480, 0x114AF, WordCat::ALetter),
+    (0x114B0, 0x114C3, WordCat::Extend),
+    (0x114C4, 0x114C5, WordCat::ALetter),
+    (0x114C7, 0x114C7, WordCat::ALetter),
+    (0x114D0, 0x114D9, WordCat::Numeric),
+    (0x11580, 0x115AE, WordCat::ALetter),
+    (0x115AF, 0x115B5, WordCat::Extend),
+    (0x115B8, 0x115C0, WordCat::Extend),
+    (0x115D8, 0x115DB, WordCat::ALetter),
+    (0x115DC, 0x115DD, WordCat::Extend),
+    (0x11600, 0x1162F, WordCat::ALetter),
+    (0x11630, 0x11640, WordCat::Extend),
+    (0x11644, 0x11644, WordCat::ALetter),
+    (0x11650, 0x11659, WordCat::Numeric),
+    (0x11680, 0x116AA, WordCat::ALetter),
+    (0x116AB, 0x116B7, WordCat::Extend),
+    (0x116B8, 0x116B8, WordCat::ALetter),
+    (0x116C0, 0x116C9, WordCat::Numeric),
+    (0x1171D, 0x1172B, WordCat::Extend),
+    (0x11730, 0x11739, WordCat::Numeric),
+    (0x11800, 0x1182B, WordCat::ALetter),
+    (0x1182C, 0x1183A, WordCat::Extend),
+    (0x118A0, 0x118DF, WordCat::ALetter),
+    (0x118E0, 0x118E9, WordCat::Numeric),
+    (0x118FF, 0x11906, WordCat::ALetter),
+    (0x11909, 0x11909, WordCat::ALetter),
+    (0x1190C, 0x11913, WordCat::ALetter),
+    (0x11915, 0x11916, WordCat::ALetter),
+    (0x11918, 0x1192F, WordCat::ALetter),
+    (0x11930, 0x11935, WordCat::Extend),
+    (0x11937, 0x11938, WordCat::Extend),
+    (0x1193B, 0x1193E, WordCat::Extend),
+    (0x1193F, 0x1193F, WordCat::ALetter),
+    (0x11940, 0x11940, WordCat::Extend),
+    (0x11941, 0x11941, WordCat::ALetter),
+    (0x11942, 0x11943, WordCat::Extend),
+    (0x11950, 0x11959, WordCat::Numeric),
+    (0x119A0, 0x119A7, WordCat::ALetter),
+    (0x119AA, 0x119D0, WordCat::ALetter),
+    (0x119D1, 0x119D7, WordCat::Extend),
+    (0x119DA, 0x119E0, WordCat::Extend),
+    (0x119E1, 0x119E1, WordCat::ALetter),
+    (0x119E3, 0x119E3, WordCat::ALetter),
+    (0x119E4, 0x119E4, WordCat::Extend),
+    (0x11A00, 0x11A00, WordCat::ALetter),
+    (0x11A01, 0x11A0A, WordCat::Extend),
+    (0x11A0B, 0x11A32, WordCat::ALetter),
+    (0x11A33, 0x11A39, WordCat::Extend),
+    (0x11A3A, 0x11A3A, WordCat::ALetter),
+    (0x11A3B, 0x11A3E, WordCat::Extend),
+    (0x11A47, 0x11A47, WordCat::Extend),
+    (0x11A50, 0x11A50, WordCat::ALetter),
+    (0x11A51, 0x11A5B, WordCat::Extend),
+    (0x11A5C, 0x11A89, WordCat::ALetter),
+    (0x11A8A, 0x11A99, WordCat::Extend),
+    (0x11A9D, 0x11A9D, WordCat::ALetter),
+    (0x11AB0, 0x11AF8, WordCat::ALetter),
+    (0x11C00, 0x11C08, WordCat::ALetter),
+    (0x11C0A, 0x11C2E, WordCat::ALetter),
+    (0x11C2F, 0x11C36, WordCat::Extend),
+    (0x11C38, 0x11C3F, WordCat::Extend),
+    (0x11C40, 0x11C40, WordCat::ALetter),
+    (0x11C50, 0x11C59, WordCat::Numeric),
+    (0x11C72, 0x11C8F, WordCat::ALetter),
+    (0x11C92, 0x11CA7, WordCat::Extend),
+    (0x11CA9, 0x11CB6, WordCat::Extend),
+    (0x11D00, 0x11D06, WordCat::ALetter),
+    (0x11D08, 0x11D09, WordCat::ALetter),
+    (0x11D0B, 0x11D30, WordCat::ALetter),
+    (0x11D31, 0x11D36, WordCat::Extend),
+    (0x11D3A, 0x11D3A, WordCat::Extend),
+    (0x11D3C, 0x11D3D, WordCat::Extend),
+    (0x11D3F, 0x11D45, WordCat::Extend),
+    (0x11D46, 0x11D46, WordCat::ALetter),
+    (0x11D47, 0x11D47, WordCat::Extend),
+    (0x11D50, 0x11D59, WordCat::Numeric),
+    (0x11D60, 0x11D65, WordCat::ALetter),
+    (0x11D67, 0x11D68, WordCat::ALetter),
+    (0x11D6A, 0x11D89, WordCat::ALetter),
+    (0x11D8A, 0x11D8E, WordCat::Extend),
+    (0x11D90, 0x11D91, WordCat::Extend),
+    (0x11D93, 0x11D97, WordCat::Extend),
+    (0x11D98, 0x11D98, WordCat::ALetter),
+    (0x11DA0, 0x11DA9, WordCat::Numeric),
+    (0x11EE0, 0x11EF2, WordCat::ALetter),
+    (0x11EF3, 0x11EF6, WordCat::Extend),
+    (0x11FB0, 0x11FB0, WordCat::ALetter),
+    (0x12000, 0x12399, WordCat::ALetter),
+    (0x12400, 0x1246E, WordCat::ALetter),
+    (0x12480, 0x12543, WordCat::ALetter),
+    (0x12F90, 0x12FF0, WordCat::ALetter),
+    (0x13000, 0x1342E, WordCat::ALetter),
+    (0x13430, 0x13438, WordCat::Format),
+    (0x14400, 0x14646, WordCat::ALetter),
+    (0x16800, 0x16A38, WordCat::ALetter),
+    (0x16A40, 0x16A5E, WordCat::ALetter),
+    (0x16A60, 0x16A69, WordCat::Numeric),
+    (0x16A70, 0x16ABE, WordCat::ALetter),
+    (0x16AC0, 0x16AC9, WordCat::Numeric),
+    (0x16AD0, 0x16AED, WordCat::ALetter),
+    (0x16AF0, 0x16AF4, WordCat::Extend),
+    (0x16B00, 0x16B2F, WordCat::ALetter),
+    (0x16B30, 0x16B36, WordCat::Extend),
+    (0x16B40, 0x16B43, WordCat::ALetter),
+    (0x16B50, 0x16B59, WordCat::Numeric),
+    (0x16B63, 0x16B77, WordCat::ALetter),
+    (0x16B7D, 0x16B8F, WordCat::ALetter),
+    (0x16E40, 0x16E7F, WordCat::ALetter),
+    (0x16F00, 0x16F4A, WordCat::ALetter),
+    (0x16F4F, 0x16F4F, WordCat::Extend),
+    (0x16F50, 0x16F50, WordCat::ALetter),
+    (0x16F51, 0x16F87, WordCat::Extend),
+    (0x16F8F, 0x16F92, WordCat::Extend),
+    (0x16F93, 0x16F9F, WordCat::ALetter),
+    (0x16FE0, 0x16FE1, WordCat::ALetter),
+    (0x16FE3, 0x16FE3, WordCat::ALetter),
+    (0x16FE4, 0x16FE4, WordCat::Extend),
+    (0x16FF0, 0x16FF1, WordCat::Extend),
+    (0x1AFF0, 0x1AFF3, WordCat::Katakana),
+    (0x1AFF5, 0x1AFFB, WordCat::Katakana),
+    (0x1AFFD, 0x1AFFE, WordCat::Katakana),
+    (0x1B000, 0x1B000, WordCat::Katakana),
+    (0x1B120, 0x1B122, WordCat::Katakana),
+    (0x1B164, 0x1B167, WordCat::Katakana),
+    (0x1BC00, 0x1BC6A, WordCat::ALetter),
+    (0x1BC70, 0x1BC7C, WordCat::ALetter),
+    (0x1BC80, 0x1BC88, WordCat::ALetter),
+    (0x1BC90, 0x1BC99, WordCat::ALetter),
+    (0x1BC9D, 0x1BC9E, WordCat::Extend),
+    (0x1BCA0, 0x1BCA3, WordCat::Format),
+    (0x1CF00, 0x1CF2D, WordCat::Extend),
+    (0x1CF30, 0x1CF46, WordCat::Extend),
+    (0x1D165, 0x1D169, WordCat::Extend),
+    (0x1D16D, 0x1D172, WordCat::Extend),
+    (0x1D173, 0x1D17A, WordCat::Format),
+    (0x1D17B, 0x1D182, WordCat::Extend),
+    (0x1D185, 0x1D18B, WordCat::Extend),
+    (0x1D1AA, 0x1D1AD, WordCat::Extend),
+    (0x1D242, 0x1D244, WordCat::Extend),
+    (0x1D400, 0x1D454, WordCat::ALetter),
+    (0x1D456, 0x1D49C, WordCat::ALetter),
+    (0x1D49E, 0x1D49F, WordCat::ALetter),
+    (0x1D4A2, 0x1D4A2, WordCat::ALetter),
+    (0x1D4A5, 0x1D4A6, WordCat::ALetter),
+    (0x1D4A9, 0x1D4AC, WordCat::ALetter),
+    (0x1D4AE, 0x1D4B9, WordCat::ALetter),
+    (0x1D4BB, 0x1D4BB, WordCat::ALetter),
+    (0x1D4BD, 0x1D4C3, WordCat::ALetter),
+    (0x1D4C5, 0x1D505, WordCat::ALetter),
+    (0x1D507, 0x1D50A, WordCat::ALetter),
+    (0x1D50D, 0x1D514, WordCat::ALetter),
+    (0x1D516, 0x1D51C, WordCat::ALetter),
+    (0x1D51E, 0x1D539, WordCat::ALetter),
+    (0x1D53B, 0x1D53E, WordCat::ALetter),
+    (0x1D540, 0x1D544, WordCat::ALetter),
+    (0x1D546, 0x1D546, WordCat::ALetter),
+    (0x1D54A, 0x1D550, WordCat::ALetter),
+    (0x1D552, 0x1D6A5, WordCat::ALetter),
+    (0x1D6A8, 0x1D6C0, WordCat::ALetter),
+    (0x1D6C2, 0x1D6DA, WordCat::ALetter),
+    (0x1D6DC, 0x1D6FA, WordCat::ALetter),
+    (0x1D6FC, 0x1D714, WordCat::ALetter),
+    (0x1D716, 0x1D734, WordCat::ALetter),
+    (0x1D736, 0x1D74E, WordCat::ALetter),
+    (0x1D750, 0x1D76E, WordCat::ALetter),
+    (0x1D770, 0x1D788, WordCat::ALetter),
+    (0x1D78A, 0x1D7A8, WordCat::ALetter),
+    (0x1D7AA, 0x1D7C2, WordCat::ALetter),
+    (0x1D7C4, 0x1D7CB, WordCat::ALetter),
+    (0x1D7CE, 0x1D7FF, WordCat::Numeric),
+    (0x1DA00, 0x1DA36, WordCat::Extend),
+    (0x1DA3B, 0x1DA6C, WordCat::Extend),
+    (0x1DA75, 0x1DA75, WordCat::Extend),
+    (0x1DA84, 0x1DA84, WordCat::Extend),
+    (0x1DA9B, 0x1DA9F, WordCat::Extend),
+    (0x1DAA1, 0x1DAAF, WordCat::Extend),
+    (0x1DF00, 0x1DF1E, WordCat::ALetter),
+    (0x1E000, 0x1E006, WordCat::Extend),
+    (0x1E008, 0x1E018, WordCat::Extend),
+    (0x1E01B, 0x1E021, WordCat::Extend),
+    (0x1E023, 0x1E024, WordCat::Extend),
+    (0x1E026, 0x1E02A, WordCat::Extend),
+    (0x1E100, 0x1E12C, WordCat::ALetter),
+    (0x1E130, 0x1E136, WordCat::Extend),
+    (0x1E137, 0x1E13D, WordCat::ALetter),
+    (0x1E140, 0x1E149, WordCat::Numeric),
+    (0x1E14E, 0x1E14E, WordCat::ALetter),
+    (0x1E290, 0x1E2AD, WordCat::ALetter),
+    (0x1E2AE, 0x1E2AE, WordCat::Extend),
+    (0x1E2C0, 0x1E2EB, WordCat::ALetter),
+    (0x1E2EC, 0x1E2EF, WordCat::Extend),
+    (0x1E2F0, 0x1E2F9, WordCat::Numeric),
+    (0x1E7E0, 0x1E7E6, WordCat::ALetter),
+    (0x1E7E8, 0x1E7EB, WordCat::ALetter),
+    (0x1E7ED, 0x1E7EE, WordCat::ALetter),
+    (0x1E7F0, 0x1E7FE, WordCat::ALetter),
+    (0x1E800, 0x1E8C4, WordCat::ALetter),
+    (0x1E8D0, 0x1E8D6, WordCat::Extend),
+    (0x1E900, 0x1E943, WordCat::ALetter),
+    (0x1E944, 0x1E94A, WordCat::Extend),
+    (0x1E94B, 0x1E94B, WordCat::ALetter),
+    (0x1E950, 0x1E959, WordCat::Numeric),
+    (0x1EE00, 0x1EE03, WordCat::ALetter),
+    (0x1EE05, 0x1EE1F, WordCat::ALetter),
+    (0x1EE21, 0x1EE22, WordCat::ALetter),
+    (0x1EE24, 0x1EE24, WordCat::ALetter),
+    (0x1EE27, 0x1EE27, WordCat::ALetter),
+    (0x1EE29, 0x1EE32, WordCat::ALetter),
+    (0x1EE34, 0x1EE37, WordCat::ALetter),
+    (0x1EE39, 0x1EE39, WordCat::ALetter),
+    (0x1EE3B, 0x1EE3B, WordCat::ALetter),
+    (0x1EE42, 0x1EE42, WordCat::ALetter),
+    (0x1EE47, 0x1EE47, WordCat::ALetter),
+    (0x1EE49, 0x1EE49, WordCat::ALetter),
+    (0x1EE4B, 0x1EE4B, WordCat::ALetter),
+    (0x1EE4D, 0x1EE4F, WordCat::ALetter),
+    (0x1EE51, 0x1EE52, WordCat::ALetter),
+    (0x1EE54, 0x1EE54, WordCat::ALetter),
+    (0x1EE57, 0x1EE57, WordCat::ALetter),
+    (0x1EE59, 0x1EE59, WordCat::ALetter),
+    (0x1EE5B, 0x1EE5B, WordCat::ALetter),
+    (0x1EE5D, 0x1EE5D, WordCat::ALetter),
+    (0x1EE5F, 0x1EE5F, WordCat::ALetter),
+    (0x1EE61, 0x1EE62, WordCat::ALetter),
+    (0x1EE64, 0x1EE64, WordCat::ALetter),
+    (0x1EE67, 0x1EE6A, WordCat::ALetter),
+    (0x1EE6C, 0x1EE72, WordCat::ALetter),
+    (0x1EE74, 0x1EE77, WordCat::ALetter),
+    (0x1EE79, 0x1EE7C, WordCat::ALetter),
+    (0x1EE7E, 0x1EE7E, WordCat::ALetter),
+    (0x1EE80, 0x1EE89, WordCat::ALetter),
+    (0x1EE8B, 0x1EE9B, WordCat::ALetter),
+    (0x1EEA1, 0x1EEA3, WordCat::ALetter),
+    (0x1EEA5, 0x1EEA9, WordCat::ALetter),
+    (0x1EEAB, 0x1EEBB, WordCat::ALetter),
+    (0x1F130, 0x1F149, WordCat::ALetter),
+    (0x1F150, 0x1F169, WordCat::ALetter),
+    (0x1F170, 0x1F189, WordCat::ALetter),
+    (0x1F1E6, 0x1F1FF, WordCat::RegionalIndicator),
+    (0x1F3FB, 0x1F3FF, WordCat::Extend),
+    (0x1FBF0, 0x1FBF9, WordCat::Numeric),
+    (0xE0001, 0xE0001, WordCat::Format),
+    (0xE0020, 0xE007F, WordCat::Extend),
+    (0xE0100, 0xE01EF, WordCat::Extend),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SentenceCat {
+    Other,
+    ATerm,
+    CR,
+    Close,
+    Extend,
+    Format,
+    LF,
+    Lower,
+    Numeric,
+    OLetter,
+    SContinue,
+    STerm,
+    Sep,
+    Sp,
+    Upper,
+}
+
+pub const SENTENCE_BREAK: &[(u32, u32, SentenceCat)] = &[
+    (0x0009, 0x0009, SentenceCat::Sp),
+    (0x000A, 0x000A, SentenceCat::LF),
+    (0x000B, 0x000C, SentenceCat::Sp),
+    (0x000D, 0x000D, SentenceCat::CR),
+    (0x0020, 0x0020, SentenceCat::Sp),
+    (0x0021, 0x0021, SentenceCat::STerm),
+    (0x0022, 0x0022, SentenceCat::Close),
+    (0x0027, 0x0029, SentenceCat::Close),
+    (0x002C, 0x002D, SentenceCat::SContinue),
+    (0x002E, 0x002E, SentenceCat::ATerm),
+    (0x0030, 0x0039, SentenceCat::Numeric),
+    (0x003A, 0x003A, SentenceCat::SContinue),
+    (0x003F, 0x003F, SentenceCat::STerm),
+    (0x0041, 0x005A, SentenceCat::Upper),
+    (0x005B, 0x005B, SentenceCat::Close),
+    (0x005D, 0x005D, SentenceCat::Close),
+    (0x0061, 0x007A, SentenceCat::Lower),
+    (0x007B, 0x007B, SentenceCat::Close),
+    (0x007D, 0x007D, SentenceCat::Close),
+    (0x0085, 0x0085, SentenceCat::Sep),
+    (0x00A0, 0x00A0, SentenceCat::Sp),
+    (0x00AA, 0x00AA, SentenceCat::Lower),
+    (0x00AB, 0x00AB, SentenceCat::Close),
+    (0x00AD, 0x00AD, SentenceCat::Format),
+    (0x00B5, 0x00B5, SentenceCat::Lower),
+    (0x00BA, 0x00BA, SentenceCat::Lower),
+    (0x00BB, 0x00BB, SentenceCat::Close),
+    (0x00C0, 0x00D6, SentenceCat::Upper),
+    (0x00D8, 0x00DE, SentenceCat::Upper),
+    (0x00DF, 0x00F6, SentenceCat::Lower),
+    (0x00F8, 0x00FF, SentenceCat::Lower),
+    (0x0100, 0x0100, SentenceCat::Upper),
+    (0x0101, 0x0101, SentenceCat::Lower),
+    (0x0102, 0x0102, SentenceCat::Upper),
+    (0x0103, 0x0103, SentenceCat::Lower),
+    (0x0104, 0x0104, SentenceCat::Upper),
+    (0x0105, 0x0105, SentenceCat::Lower),
+    (0x0106, 0x0106, SentenceCat::Upper),
+    (0x0107, 0x0107, SentenceCat::Lower),
+    (0x0108, 0x0108, SentenceCat::Upper),
+    (0x0109, 0x0109, SentenceCat::Lower),
+    (0x010A, 0x010A, SentenceCat::Upper),
+    (0x010B, 0x010B, SentenceCat::Lower),
+    (0x010C, 0x010C, SentenceCat::Upper),
+    (0x010D, 0x010D, SentenceCat::Lower),
+    (0x010E, 0x010E, SentenceCat::Upper),
+    (0x010F, 0x010F, SentenceCat::Lower),
+    (0x0110, 0x0110, SentenceCat::Upper),
+    (0x0111, 0x0111, SentenceCat::Lower),
+    (0x0112, 0x0112, SentenceCat::Upper),
+    (0x0113, 0x0113, SentenceCat::Lower),
+    (0x0114, 0x0114, SentenceCat::Upper),
+    (0x0115, 0x0115, SentenceCat::Lower),
+    (0x0116, 0x0116, SentenceCat::Upper),
+    (0x0117, 0x0117, SentenceCat::Lower),
+    (0x0118, 0x0118, SentenceCat::Upper),
+    (0x0119, 0x0119, SentenceCat::Lower),
+    (0x011A, 0x011A, SentenceCat::Upper),
+    (0x011B, 0x011B, SentenceCat::Lower),
+    (0x011C, 0x011C, SentenceCat::Upper),
+    (0x011D, 0x011D, SentenceCat::Lower),
+    (0x011E, 0x011E, SentenceCat::Upper),
+    (0x011F, 0x011F, SentenceCat::Lower),
+    (0x0120, 0x0120, SentenceCat::Upper),
+    (0x0121, 0x0121, SentenceCat::Lower),
+    (0x0122, 0x0122, SentenceCat::Upper),
+    (0x0123, 0x0123, SentenceCat::Lower),
+    (0x0124, 0x0124, SentenceCat::Upper),
+    (0x0125, 0x0125, SentenceCat::Lower),
+    (0x0126, 0x0126, SentenceCat::Upper),
+    (0x0127, 0x0127, SentenceCat::Lower),
+    (0x0128, 0x0128, SentenceCat::Upper),
+    (0x0129, 0x0129, SentenceCat::Lower),
+    (0x012A, 0x012A, SentenceCat::Upper),
+    (0x012B, 0x012B, SentenceCat::Lower),
+    (0x012C, 0x012C, SentenceCat::Upper),
+    (0x012D, 0x012D, SentenceCat::Lower),
+    (0x012E, 0x012E, SentenceCat::Upper),
+    (0x012F, 0x012F, SentenceCat::Lower),
+    (0x0130, 0x0130, SentenceCat::Upper),
+    (0x0131, 0x0131, SentenceCat::Lower),
+    (0x0132, 0x0132, SentenceCat::Upper),
+    (0x0133, 0x0133, SentenceCat::Lower),
+    (0x0134, 0x0134, SentenceCat::Upper),
+    (0x0135, 0x0135, SentenceCat::Lower),
+    (0x0136, 0x0136, SentenceCat::Upper),
+    (0x0137, 0x0138, SentenceCat::Lower),
+    (0x0139, 0x0139, SentenceCat::Upper),
+    (0x013A, 0x013A, SentenceCat::Lower),
+    (0x013B, 0x013B, SentenceCat::Upper),
+    (0x013C, 0x013C, SentenceCat::Lower),
+    (0x013D, 0x013D, SentenceCat::Upper),
+    (0x013E, 0x013E, SentenceCat::Lower),
+    (0x013F, 0x013F, SentenceCat::Upper),
+    (0x0140, 0x0140, SentenceCat::Lower),
+    (0x0141, 0x0141, SentenceCat::Upper),
+    (0x0142, 0x0142, SentenceCat::Lower),
+    (0x0143, 0x0143, SentenceCat::Upper),
+    (0x0144, 0x0144, SentenceCat::Lower),
+    (0x0145, 0x0145, SentenceCat::Upper),
+    (0x0146, 0x0146, SentenceCat::Lower),
+    (0x0147, 0x0147, SentenceCat::Upper),
+    (0x0148, 0x0149, SentenceCat::Lower),
+    (0x014A, 0x014A, SentenceCat::Upper),
+    (0x014B, 0x014B, SentenceCat::Lower),
+    (0x014C, 0x014C, SentenceCat::Upper),
+    (0x014D, 0x014D, SentenceCat::Lower),
+    (0x014E, 0x014E, SentenceCat::Upper),
+    (0x014F, 0x014F, SentenceCat::Lower),
+    (0x0150, 0x0150, SentenceCat::Upper),
+    (0x0151, 0x0151, SentenceCat::Lower),
+    (0x0152, 0x0152, SentenceCat::Upper),
+    (0x0153, 0x0153, SentenceCat::Lower),
+    (0x0154, 0x0154, SentenceCat::Upper),
+    (0x0155, 0x0155, SentenceCat::Lower),
+    (0x0156, 0x0156, SentenceCat::Upper),
+    (0x0157, 0x0157, SentenceCat::Lower),
+    (0x0158, 0x0158, SentenceCat::Upper),
+    (0x0159, 0x0159, SentenceCat::Lower),
+    (0x015A, 0x015A, SentenceCat::Upper),
+    (0x015B, 0x015B, SentenceCat::Lower),
+    (0x015C, 0x015C, SentenceCat::Upper),
+    (0x015D, 0x015D, SentenceCat::Lower),
+    (0x015E, 0x015E, SentenceCat::Upper),
+    (0x015F, 0x015F, SentenceCat::Lower),
+    (0x0160, 0x0160, SentenceCat::Upper),
+    (0x0161, 0x0161, SentenceCat::Lower),
+    (0x0162, 0x0162, SentenceCat::Upper),
+    (0x0163, 0x0163, SentenceCat::Lower),
+    (0x0164, 0x0164, SentenceCat::Upper),
+    (0x0165, 0x0165, SentenceCat::Lower),
+    (0x0166, 0x0166, SentenceCat::Upper),
+    (0x0167, 0x0167, SentenceCat::Lower),
+    (0x0168, 0x0168, SentenceCat::Upper),
+    (0x0169, 0x0169, SentenceCat::Lower),
+    (0x016A, 0x016A, SentenceCat::Upper),
+    (0x016B, 0x016B, SentenceCat::Lower),
+    (0x016C, 0x016C, SentenceCat::Upper),
+    (0x016D, 0x016D, SentenceCat::Lower),
+    (0x016E, 0x016E, SentenceCat::Upper),
+    (0x016F, 0x016F, SentenceCat::Lower),
+    (0x0170, 0x0170, SentenceCat::Upper),
+    (0x0171, 0x0171, SentenceCat::Lower),
+    (0x0172, 0x0172, SentenceCat::Upper),
+    (0x0173, 0x0173, SentenceCat::Lower),
+    (0x0174, 0x0174, SentenceCat::Upper),
+    (0x0175, 0x0175, SentenceCat::Lower),
+    (0x0176, 0x0176, SentenceCat::Upper),
+    (0x0177, 0x0177, SentenceCat::Lower),
+    (0x0178, 0x0179, SentenceCat::Upper),
+    (0x017A, 0x017A, SentenceCat::Lower),
+    (0x017B, 0x017B, SentenceCat::Upper),
+    (0x017C, 0x017C, SentenceCat::Lower),
+    (0x017D, 0x017D, SentenceCat::Upper),
+    (0x017E, 0x0180, SentenceCat::Lower),
+    (0x0181, 0x0182, SentenceCat::Upper),
+    (0x0183, 0x0183, SentenceCat::Lower),
+    (0x0184, 0x0184, SentenceCat::Upper),
+    (0x0185, 0x0185, SentenceCat::Lower),
+    (0x0186, 0x0187, SentenceCat::Upper),
+    (0x0188, 0x0188, SentenceCat::Lower),
+    (0x0189, 0x018B, SentenceCat::Upper),
+    (0x018C, 0x018D, SentenceCat::Lower),
+    (0x018E, 0x0191, SentenceCat::Upper),
+    (0x0192, 0x0192, SentenceCat::Lower),
+    (0x0193, 0x0194, SentenceCat::Upper),
+    (0x0195, 0x0195, SentenceCat::Lower),
+    (0x0196, 0x0198, SentenceCat::Upper),
+    (0x0199, 0x019B, SentenceCat::Lower),
+    (0x019C, 0x019D, SentenceCat::Upper),
+    (0x019E, 0x019E, SentenceCat::Lower),
+    (0x019F, 0x01A0, SentenceCat::Upper),
+    (0x01A1, 0x01A1, SentenceCat::Lower),
+    (0x01A2, 0x01A2, SentenceCat::Upper),
+    (0x01A3, 0x01A3, SentenceCat::Lower),
+    (0x01A4, 0x01A4, SentenceCat::Upper),
+    (0x01A5, 0x01A5, SentenceCat::Lower),
+    (0x01A6, 0x01A7, SentenceCat::Upper),
+    (0x01A8, 0x01A8, SentenceCat::Lower),
+    (0x01A9, 0x01A9, SentenceCat::Upper),
+    (0x01AA, 0x01AB, SentenceCat::Lower),
+    (0x01AC, 0x01AC, SentenceCat::Upper),
+    (0x01AD, 0x01AD, SentenceCat::Lower),
+    (0x01AE, 0x01AF, SentenceCat::Upper),
+    (0x01B0, 0x01B0, SentenceCat::Lower),
+    (0x01B1, 0x01B3, SentenceCat::Upper),
+    (0x01B4, 0x01B4, SentenceCat::Lower),
+    (0x01B5, 0x01B5, SentenceCat::Upper),
+    (0x01B6, 0x01B6, SentenceCat::Lower),
+    (0x01B7, 0x01B8, SentenceCat::Upper),
+    (0x01B9, 0x01BA, SentenceCat::Lower),
+    (0x01BB, 0x01BB, SentenceCat::OLetter),
+    (0x01BC, 0x01BC, SentenceCat::Upper),
+    (0x01BD, 0x01BF, SentenceCat::Lower),
+    (0x01C0, 0x01C3, SentenceCat::OLetter),
+    (0x01C4, 0x01C5, SentenceCat::Upper),
+    (0x01C6, 0x01C6, SentenceCat::Lower),
+    (0x01C7, 0x01C8, SentenceCat::Upper),
+    (0x01C9, 0x01C9, SentenceCat::Lower),
+    (0x01CA, 0x01CB, SentenceCat::Upper),
+    (0x01CC, 0x01CC, SentenceCat::Lower),
+    (0x01CD, 0x01CD, SentenceCat::Upper),
+    (0x01CE, 0x01CE, SentenceCat::Lower),
+    (0x01CF, 0x01CF, SentenceCat::Upper),
+    (0x01D0, 0x01D0, SentenceCat::Lower),
+    (0x01D1, 0x01D1, SentenceCat::Upper),
+    (0x01D2, 0x01D2, SentenceCat::Lower),
+    (0x01D3, 0x01D3, SentenceCat::Upper),
+    (0x01D4, 0x01D4, SentenceCat::Lower),
+    (0x01D5, 0x01D5, SentenceCat::Upper),
+    (0x01D6, 0x01D6, SentenceCat::Lower),
+    (0x01D7, 0x01D7, SentenceCat::Upper),
+    (0x01D8, 0x01D8, SentenceCat::Lower),
+    (0x01D9, 0x01D9, SentenceCat::Upper),
+    (0x01DA, 0x01DA, SentenceCat::Lower),
+    (0x01DB, 0x01DB, SentenceCat::Upper),
+    (0x01DC, 0x01DD, SentenceCat::Lower),
+    (0x01DE, 0x01DE, SentenceCat::Upper),
+    (0x01DF, 0x01DF, SentenceCat::Lower),
+    (0x01E0, 0x01E0, SentenceCat::Upper),
+    (0x01E1, 0x01E1, SentenceCat::Lower),
+    (0x01E2, 0x01E2, SentenceCat::Upper),
+    (0x01E3, 0x01E3, SentenceCat::Lower),
+    (0x01E4, 0x01E4, SentenceCat::Upper),
+    (0x01E5, 0x01E5, SentenceCat::Lower),
+    (0x01E6, 0x01E6, SentenceCat::Upper),
+    (0x01E7, 0x01E7, SentenceCat::Lower),
+    (0x01E8, 0x01E8, SentenceCat::Upper),
+    (0x01E9, 0x01E9, SentenceCat::Lower),
+    (0x01EA, 0x01EA, SentenceCat::Upper),
+    (0x01EB, 0x01EB, SentenceCat::Lower),
+    (0x01EC, 0x01EC, SentenceCat::Upper),
+    (0x01ED, 0x01ED, SentenceCat::Lower),
+    (0x01EE, 0x01EE, SentenceCat::Upper),
+    (0x01EF, 0x01F0, SentenceCat::Lower),
+    (0x01F1, 0x01F2, SentenceCat::Upper),
+    (0x01F3, 0x01F3, SentenceCat::Lower),
+    (0x01F4, 0x01F4, SentenceCat::Upper),
+    (0x01F5, 0x01F5, SentenceCat::Lower),
+    (0x01F6, 0x01F8, SentenceCat::Upper),
+    (0x01F9, 0x01F9, SentenceCat::Lower),
+    (0x01FA, 0x01FA, SentenceCat::Upper),
+    (0x01FB, 0x01FB, SentenceCat::Lower),
+    (0x01FC, 0x01FC, SentenceCat::Upper),
+    (0x01FD, 0x01FD, SentenceCat::Lower),
+    (0x01FE, 0x01FE, SentenceCat::Upper),
+    (0x01FF, 0x01FF, SentenceCat::Lower),
+    (0x0200, 0x0200, SentenceCat::Upper),
+    (0x0201, 0x0201, SentenceCat::Lower),
+    (0x0202, 0x0202, SentenceCat::Upper),
+    (0x0203, 0x0203, SentenceCat::Lower),
+    (0x0204, 0x0204, SentenceCat::Upper),
+    (0x0205, 0x0205, SentenceCat::Lower),
+    (0x0206, 0x0206, SentenceCat::Upper),
+    (0x0207, 0x0207, SentenceCat::Lower),
+    (0x0208, 0x0208, SentenceCat::Upper),
+    (0x0209, 0x0209, SentenceCat::Lower),
+    (0x020A, 0x020A, SentenceCat::Upper),
+    (0x020B, 0x020B, SentenceCat::Lower),
+    (0x020C, 0x020C, SentenceCat::Upper),
+    (0x020D, 0x020D, SentenceCat::Lower),
+    (0x020E, 0x020E, SentenceCat::Upper),
+    (0x020F, 0x020F, SentenceCat::Lower),
+    (0x0210, 0x0210, SentenceCat::Upper),
+    (0x0211, 0x0211, SentenceCat::Lower),
+    (0x0212, 0x0212, SentenceCat::Upper),
+    (0x0213, 0x0213, SentenceCat::Lower),
+    (0x0214, 0x0214, SentenceCat::Upper),
+    (0x0215, 0x0215, SentenceCat::Lower),
+    (0x0216, 0x0216, SentenceCat::Upper),
+    (0x0217, 0x0217, SentenceCat::Lower),
+    (0x0218, 0x0218, SentenceCat::Upper),
+    (0x0219, 0x0219, SentenceCat::Lower),
+    (0x021A, 0x021A, SentenceCat::Upper),
+    (0x021B, 0x021B, SentenceCat::Lower),
+    (0x021C, 0x021C, SentenceCat::Upper),
+    (0x021D, 0x021D, SentenceCat::Lower),
+    (0x021E, 0x021E, SentenceCat::Upper),
+    (0x021F, 0x021F, SentenceCat::Lower),
+    (0x0220, 0x0220, SentenceCat::Upper),
+    (0x0221, 0x0221, SentenceCat::Lower),
+    (0x0222, 0x0222, SentenceCat::Upper),
+    (0x0223, 0x0223, SentenceCat::Lower),
+    (0x0224, 0x0224, SentenceCat::Upper),
+    (0x0225, 0x0225, SentenceCat::Lower),
+    (0x0226, 0x0226, SentenceCat::Upper),
+    (0x0227, 0x0227, SentenceCat::Lower),
+    (0x0228, 0x0228, SentenceCat::Upper),
+    (0x0229, 0x0229, SentenceCat::Lower),
+    (0x022A, 0x022A, SentenceCat::Upper),
+    (0x022B, 0x022B, SentenceCat::Lower),
+    (0x022C, 0x022C, SentenceCat::Upper),
+    (0x022D, 0x022D, SentenceCat::Lower),
+    (0x022E, 0x022E, SentenceCat::Upper),
+    (0x022F, 0x022F, SentenceCat::Lower),
+    (0x0230, 0x0230, SentenceCat::Upper),
+    (0x0231, 0x0231, SentenceCat::Lower),
+    (0x0232, 0x0232, SentenceCat::Upper),
+    (0x0233, 0x0239, SentenceCat::Lower),
+    (0x023A, 0x023B, SentenceCat::Upper),
+    (0x023C, 0x023C, SentenceCat::Lower),
+    (0x023D, 0x023E, SentenceCat::Upper),
+    (0x023F, 0x0240, SentenceCat::Lower),
+    (0x0241, 0x0241, SentenceCat::Upper),
+    (0x0242, 0x0242, SentenceCat::Lower),
+    (0x0243, 0x0246, SentenceCat::Upper),
+    (0x0247, 0x0247, SentenceCat::Lower),
+    (0x0248, 0x0248, SentenceCat::Upper),
+    (0x0249, 0x0249, SentenceCat::Lower),
+    (0x024A, 0x024A, SentenceCat::Upper),
+    (0x024B, 0x024B, SentenceCat::Lower),
+    (0x024C, 0x024C, SentenceCat::Upper),
+    (0x024D, 0x024D, SentenceCat::Lower),
+    (0x024E, 0x024E, SentenceCat::Upper),
+    (0x024F, 0x0293, SentenceCat::Lower),
+    (0x0294, 0x0294, SentenceCat::OLetter),
+    (0x0295, 0x02B8, SentenceCat::Lower),
+    (0x02B9, 0x02BF, SentenceCat::OLetter),
+    (0x02C0, 0x02C1, SentenceCat::Lower),
+    (0x02C6, 0x02D1, SentenceCat::OLetter),
+    (0x02E0, 0x02E4, SentenceCat::Lower),
+    (0x02EC, 0x02EC, SentenceCat::OLetter),
+    (0x02EE, 0x02EE, SentenceCat::OLetter),
+    (0x0300, 0x036F, SentenceCat::Extend),
+    (0x0370, 0x0370, SentenceCat::Upper),
+    (0x0371, 0x0371, SentenceCat::Lower),
+    (0x0372, 0x0372, SentenceCat::Upper),
+    (0x0373, 0x0373, SentenceCat::Lower),
+    (0x0374, 0x0374, SentenceCat::OLetter),
+    (0x0376, 0x0376, SentenceCat::Upper),
+    (0x0377, 0x0377, SentenceCat::Lower),
+    (0x037A, 0x037D, SentenceCat::Lower),
+    (0x037F, 0x037F, SentenceCat::Upper),
+    (0x0386, 0x0386, SentenceCat::Upper),
+    (0x0388, 0x038A, SentenceCat::Upper),
+    (0x038C, 0x038C, SentenceCat::Upper),
+    (0x038E, 0x038F, SentenceCat::Upper),
+    (0x0390, 0x0390, SentenceCat::Lower),
+    (0x0391, 0x03A1, SentenceCat::Upper),
+    (0x03A3, 0x03AB, SentenceCat::Upper),
+    (0x03AC, 0x03CE, SentenceCat::Lower),
+    (0x03CF, 0x03CF, SentenceCat::Upper),
+    (0x03D0, 0x03D1, SentenceCat::Lower),
+    (0x03D2, 0x03D4, SentenceCat::Upper),
+    (0x03D5, 0x03D7, SentenceCat::Lower),
+    (0x03D8, 0x03D8, SentenceCat::Upper),
+    (0x03D9, 0x03D9, SentenceCat::Lower),
+    (0x03DA, 0x03DA, SentenceCat::Upper),
+    (0x03DB, 0x03DB, SentenceCat::Lower),
+    (0x03DC, 0x03DC, SentenceCat::Upper),
+    (0x03DD, 0x03DD, SentenceCat::Lower),
+    (0x03DE, 0x03DE, SentenceCat::Upper),
+    (0x03DF, 0x03DF, SentenceCat::Lower),
+    (0x03E0, 0x03E0, SentenceCat::Upper),
+    (0x03E1, 0x03E1, SentenceCat::Lower),
+    (0x03E2, 0x03E2, SentenceCat::Upper),
+    (0x03E3, 0x03E3, SentenceCat::Lower),
+    (0x03E4, 0x03E4, SentenceCat::Upper),
+    (0x03E5, 0x03E5, SentenceCat::Lower),
+    (0x03E6, 0x03E6, SentenceCat::Upper),
+    (0x03E7, 0x03E7, SentenceCat::Lower),
+    (0x03E8, 0x03E8, SentenceCat::Upper),
+    (0x03E9, 0x03E9, SentenceCat::Lower),
+    (0x03EA, 0x03EA, SentenceCat::Upper),
+    (0x03EB, 0x03EB, SentenceCat::Lower),
+    (0x03EC, 0x03EC, SentenceCat::Upper),
+    (0x03ED, 0x03ED, SentenceCat::Lower),
+    (0x03EE, 0x03EE, SentenceCat::Upper),
+    (0x03EF, 0x03F3, SentenceCat::Lower),
+    (0x03F4, 0x03F4, SentenceCat::Upper),
+    (0x03F5, 0x03F5, SentenceCat::Lower),
+    (0x03F7, 0x03F7, SentenceCat::Upper),
+    (0x03F8, 0x03F8, SentenceCat::Lower),
+    (0x03F9, 0x03FA, SentenceCat::Upper),
+    (0x03FB, 0x03FC, SentenceCat::Lower),
+    (0x03FD, 0x042F, SentenceCat::Upper),
+    (0x0430, 0x045F, SentenceCat::Lower),
+    (0x0460, 0x0460, SentenceCat::Upper),
+    (0x0461, 0x0461, SentenceCat::Lower),
+    (0x0462, 0x0462, SentenceCat::Upper),
+    (0x0463, 0x0463, SentenceCat::Lower),
+    (0x0464, 0x0464, SentenceCat::Upper),
+    (0x0465, 0x0465, SentenceCat::Lower),
+    (0x0466, 0x0466, SentenceCat::Upper),
+    (0x0467, 0x0467, SentenceCat::Lower),
+    (0x0468, 0x0468, SentenceCat::Upper),
+    (0x0469, 0x0469, SentenceCat::Lower),
+    (0x046A, 0x046A, SentenceCat::Upper),
+    (0x046B, 0x046B, SentenceCat::Lower),
+    (0x046C, 0x046C, SentenceCat::Upper),
+    (0x046D, 0x046D, SentenceCat::Lower),
+    (0x046E, 0x046E, SentenceCat::Upper),
+    (0x046F, 0x046F, SentenceCat::Lower),
+    (0x0470, 0x0470, SentenceCat::Upper),
+    (0x0471, 0x0471, SentenceCat::Lower),
+    (0x0472, 0x0472, SentenceCat::Upper),
+    (0x0473, 0x0473, SentenceCat::Lower),
+    (0x0474, 0x0474, SentenceCat::Upper),
+    (0x0475, 0x0475, SentenceCat::Lower),
+    (0x0476, 0x0476, SentenceCat::Upper),
+    (0x0477, 0x0477, SentenceCat::Lower),
+    (0x0478, 0x0478, SentenceCat::Upper),
+    (0x0479, 0x0479, SentenceCat::Lower),
+    (0x047A, 0x047A, SentenceCat::Upper),
+    (0x047B, 0x047B, SentenceCat::Lower),
+    (0x047C, 0x047C, SentenceCat::Upper),
+    (0x047D, 0x047D, SentenceCat::Lower),
+    (0x047E, 0x047E, SentenceCat::Upper),
+    (0x047F, 0x047F, SentenceCat::Lower),
+    (0x0480, 0x0480, SentenceCat::Upper),
+    (0x0481, 0x0481, SentenceCat::Lower),
+    (0x0483, 0x0489, SentenceCat::Extend),
+    (0x048A, 0x048A, SentenceCat::Upper),
+    (0x048B, 0x048B, SentenceCat::Lower),
+    (0x048C, 0x048C, SentenceCat::Upper),
+    (0x048D, 0x048D, SentenceCat::Lower),
+    (0x048E, 0x048E, SentenceCat::Upper),
+    (0x048F, 0x048F, SentenceCat::Lower),
+    (0x0490, 0x0490, SentenceCat::Upper),
+    (0x0491, 0x0491, SentenceCat::Lower),
+    (0x0492, 0x0492, SentenceCat::Upper),
+    (0x0493, 0x0493, SentenceCat::Lower),
+    (0x0494, 0x0494, SentenceCat::Upper),
+    (0x0495, 0x0495, SentenceCat::Lower),
+    (0x0496, 0x0496, SentenceCat::Upper),
+    (0x0497, 0x0497, SentenceCat::Lower),
+    (0x0498, 0x0498, SentenceCat::Upper),
+    (0x0499, 0x0499, SentenceCat::Lower),
+    (0x049A, 0x049A, SentenceCat::Upper),
+    (0x049B, 0x049B, SentenceCat::Lower),
+    (0x049C, 0x049C, SentenceCat::Upper),
+    (0x049D, 0x049D, SentenceCat::Lower),
+    (0x049E, 0x049E, SentenceCat::Upper),
+    (0x049F, 0x049F, SentenceCat::Lower),
+    (0x04A0, 0x04A0, SentenceCat::Upper),
+    (0x04A1, 0x04A1, SentenceCat::Lower),
+    (0x04A2, 0x04A2, SentenceCat::Upper),
+    (0x04A3, 0x04A3, SentenceCat::Lower),
+    (0x04A4, 0x04A4, SentenceCat::Upper),
+    (0x04A5, 0x04A5, SentenceCat::Lower),
+    (0x04A6, 0x04A6, SentenceCat::Upper),
+    (0x04A7, 0x04A7, SentenceCat::Lower),
+    (0x04A8, 0x04A8, SentenceCat::Upper),
+    (0x04A9, 0x04A9, SentenceCat::Lower),
+    (0x04AA, 0x04AA, SentenceCat::Upper),
+    (0x04AB, 0x04AB, SentenceCat::Lower),
+    (0x04AC, 0x04AC, SentenceCat::Upper),
+    (0x04AD, 0x04AD, SentenceCat::Lower),
+    (0x04AE, 0x04AE, SentenceCat::Upper),
+    (0x04AF, 0x04AF, SentenceCat::Lower),
+    (0x04B0, 0x04B0, SentenceCat::Upper),
+    (0x04B1, 0x04B1, SentenceCat::Lower),
+    (0x04B2, 0x04B2, SentenceCat::Upper),
+    (0x04B3, 0x04B3, SentenceCat::Lower),
+    (0x04B4, 0x04B4, SentenceCat::Upper),
+    (0x04B5, 0x04B5, SentenceCat::Lower),
+    (0x04B6, 0x04B6, SentenceCat::Upper),
+    (0x04B7, 0x04B7, SentenceCat::Lower),
+    (0x04B8, 0x04B8, SentenceCat::Upper),
+    (0x04B9, 0x04B9, SentenceCat::Lower),
+    (0x04BA, 0x04BA, SentenceCat::Upper),
+    (0x04BB, 0x04BB, SentenceCat::Lower),
+    (0x04BC, 0x04BC, SentenceCat::Upper),
+    (0x04BD, 0x04BD, SentenceCat::Lower),
+    (0x04BE, 0x04BE, SentenceCat::Upper),
+    (0x04BF, 0x04BF, SentenceCat::Lower),
+    (0x04C0, 0x04C1, SentenceCat::Upper),
+    (0x04C2, 0x04C2, SentenceCat::Lower),
+    (0x04C3, 0x04C3, SentenceCat::Upper),
+    (0x04C4, 0x04C4, SentenceCat::Lower),
+    (0x04C5, 0x04C5, SentenceCat::Upper),
+    (0x04C6, 0x04C6, SentenceCat::Lower),
+    (0x04C7, 0x04C7, SentenceCat::Upper),
+    (0x04C8, 0x04C8, SentenceCat::Lower),
+    (0x04C9, 0x04C9, SentenceCat::Upper),
+    (0x04CA, 0x04CA, SentenceCat::Lower),
+    (0x04CB, 0x04CB, SentenceCat::Upper),
+    (0x04CC, 0x04CC, SentenceCat::Lower),
+    (0x04CD, 0x04CD, SentenceCat::Upper),
+    (0x04CE, 0x04CF, SentenceCat::Lower),
+    (0x04D0, 0x04D0, SentenceCat::Upper),
+    (0x04D1, 0x04D1, SentenceCat::Lower),
+    (0x04D2, 0x04D2, SentenceCat::Upper),
+    (0x04D3, 0x04D3, SentenceCat::Lower),
+    (0x04D4, 0x04D4, SentenceCat::Upper),
+    (0x04D5, 0x04D5, SentenceCat::Lower),
+    (0x04D6, 0x04D6, SentenceCat::Upper),
+    (0x04D7, 0x04D7, SentenceCat::Lower),
+    (0x04D8, 0x04D8, SentenceCat::Upper),
+    (0x04D9, 0x04D9, SentenceCat::Lower),
+    (0x04DA, 0x04DA, SentenceCat::Upper),
+    (0x04DB, 0x04DB, SentenceCat::Lower),
+    (0x04DC, 0x04DC, SentenceCat::Upper),
+    (0x04DD, 0x04DD, SentenceCat::Lower),
+    (0x04DE, 0x04DE, SentenceCat::Upper),
+    (0x04DF, 0x04DF, SentenceCat::Lower),
+    (0x04E0, 0x04E0, SentenceCat::Upper),
+    (0x04E1, 0x04E1, SentenceCat::Lower),
+    (0x04E2, 0x04E2, SentenceCat::Upper),
+    (0x04E3, 0x04E3, SentenceCat::Lower),
+    (0x04E4, 0x04E4, SentenceCat::Upper),
+    (0x04E5, 0x04E5, SentenceCat::Lower),
+    (0x04E6, 0x04E6, SentenceCat::Upper),
+    (0x04E7, 0x04E7, SentenceCat::Lower),
+    (0x04E8, 0x04E8, SentenceCat::Upper),
+    (0x04E9, 0x04E9, SentenceCat::Lower),
+    (0x04EA, 0x04EA, SentenceCat::Upper),
+    (0x04EB, 0x04EB, SentenceCat::Lower),
+    (0x04EC, 0x04EC, SentenceCat::Upper),
+    (0x04ED, 0x04ED, SentenceCat::Lower),
+    (0x04EE, 0x04EE, SentenceCat::Upper),
+    (0x04EF, 0x04EF, SentenceCat::Lower),
+    (0x04F0, 0x04F0, SentenceCat::Upper),
+    (0x04F1, 0x04F1, SentenceCat::Lower),
+    (0x04F2, 0x04F2, SentenceCat::Upper),
+    (0x04F3, 0x04F3, SentenceCat::Lower),
+    (0x04F4, 0x04F4, SentenceCat::Upper),
+    (0x04F5, 0x04F5, SentenceCat::Lower),
+    (0x04F6, 0x04F6, SentenceCat::Upper),
+    (0x04F7, 0x04F7, SentenceCat::Lower),
+    (0x04F8, 0x04F8, SentenceCat::Upper),
+    (0x04F9, 0x04F9, SentenceCat::Lower),
+    (0x04FA, 0x04FA, SentenceCat::Upper),
+    (0x04FB, 0x04FB, SentenceCat::Lower),
+    (0x04FC, 0x04FC, SentenceCat::Upper),
+    (0x04FD, 0x04FD, SentenceCat::Lower),
+    (0x04FE, 0x04FE, SentenceCat::Upper),
+    (0x04FF, 0x04FF, SentenceCat::Lower),
+    (0x0500, 0x0500, SentenceCat::Upper),
+    (0x0501, 0x0501, SentenceCat::Lower),
+    (0x0502, 0x0502, SentenceCat::Upper),
+    (0x0503, 0x0503, SentenceCat::Lower),
+    (0x0504, 0x0504, SentenceCat::Upper),
+    (0x0505, 0x0505, SentenceCat::Lower),
+    (0x0506, 0x0506, SentenceCat::Upper),
+    (0x0507, 0x0507, SentenceCat::Lower),
+    (0x0508, 0x0508, SentenceCat::Upper),
+    (0x0509, 0x0509, SentenceCat::Lower),
+    (0x050A, 0x050A, SentenceCat::Upper),
+    (0x050B, 0x050B, SentenceCat::Lower),
+    (0x050C, 0x050C, SentenceCat::Upper),
+    (0x050D, 0x050D, SentenceCat::Lower),
+    (0x050E, 0x050E, SentenceCat::Upper),
+    (0x050F, 0x050F, SentenceCat::Lower),
+    (0x0510, 0x0510, SentenceCat::Upper),
+    (0x0511, 0x0511, SentenceCat::Lower),
+    (0x0512, 0x0512, SentenceCat::Upper),
+    (0x0513, 0x0513, SentenceCat::Lower),
+    (0x0514, 0x0514, SentenceCat::Upper),
+    (0x0515, 0x0515, SentenceCat::Lower),
+    (0x0516, 0x0516, SentenceCat::Upper),
+    (0x0517, 0x0517, SentenceCat::Lower),
+    (0x0518, 0x0518, SentenceCat::Upper),
+    (0x0519, 0x0519, SentenceCat::Lower),
+    (0x051A, 0x051A, SentenceCat::Upper),
+    (0x051B, 0x051B, SentenceCat::Lower),
+    (0x051C, 0x051C, SentenceCat::Upper),
+    (0x051D, 0x051D, SentenceCat::Lower),
+    (0x051E, 0x051E, SentenceCat::Upper),
+    (0x051F, 0x051F, SentenceCat::Lower),
+    (0x0520, 0x0520, SentenceCat::Upper),
+    (0x0521, 0x0521, SentenceCat::Lower),
+    (0x0522, 0x0522, SentenceCat::Upper),
+    (0x0523, 0x0523, SentenceCat::Lower),
+    (0x0524, 0x0524, SentenceCat::Upper),
+    (0x0525, 0x0525, SentenceCat::Lower),
+    (0x0526, 0x0526, SentenceCat::Upper),
+    (0x0527, 0x0527, SentenceCat::Lower),
+    (0x0528, 0x0528, SentenceCat::Upper),
+    (0x0529, 0x0529, SentenceCat::Lower),
+    (0x052A, 0x052A, SentenceCat::Upper),
+    (0x052B, 0x052B, SentenceCat::Lower),
+    (0x052C, 0x052C, SentenceCat::Upper),
+    (0x052D, 0x052D, SentenceCat::Lower),
+    (0x052E, 0x052E, SentenceCat::Upper),
+    (0x052F, 0x052F, SentenceCat::Lower),
+    (0x0531, 0x0556, SentenceCat::Upper),
+    (0x0559, 0x0559, SentenceCat::OLetter),
+    (0x055D, 0x055D, SentenceCat::SContinue),
+    (0x0560, 0x0588, SentenceCat::Lower),
+    (0x0589, 0x0589, SentenceCat::STerm),
+    (0x0591, 0x05BD, SentenceCat::Extend),
+    (0x05BF, 0x05BF, SentenceCat::Extend),
+    (0x05C1, 0x05C2, SentenceCat::Extend),
+    (0x05C4, 0x05C5, SentenceCat::Extend),
+    (0x05C7, 0x05C7, SentenceCat::Extend),
+    (0x05D0, 0x05EA, SentenceCat::OLetter),
+    (0x05EF, 0x05F3, SentenceCat::OLetter),
+    (0x0600, 0x0605, SentenceCat::Format),
+    (0x060C, 0x060D, SentenceCat::SContinue),
+    (0x0610, 0x061A, SentenceCat::Extend),
+    (0x061C, 0x061C, SentenceCat::Format),
+    (0x061D, 0x061F, SentenceCat::STerm),
+    (0x0620, 0x064A, SentenceCat::OLetter),
+    (0x064B, 0x065F, SentenceCat::Extend),
+    (0x0660, 0x0669, SentenceCat::Numeric),
+    (0x066B, 0x066C, SentenceCat::Numeric),
+    (0x066E, 0x066F, SentenceCat::OLetter),
+    (0x0670, 0x0670, SentenceCat::Extend),
+    (0x0671, 0x06D3, SentenceCat::OLetter),
+    (0x06D4, 0x06D4, SentenceCat::STerm),
+    (0x06D5, 0x06D5, SentenceCat::OLetter),
+    (0x06D6, 0x06DC, SentenceCat::Extend),
+    (0x06DD, 0x06DD, SentenceCat::Format),
+    (0x06DF, 0x06E4, SentenceCat::Extend),
+    (0x06E5, 0x06E6, SentenceCat::OLetter),
+    (0x06E7, 0x06E8, SentenceCat::Extend),
+    (0x06EA, 0x06ED, SentenceCat::Extend),
+    (0x06EE, 0x06EF, SentenceCat::OLetter),
+    (0x06F0, 0x06F9, SentenceCat::Numeric),
+    (0x06FA, 0x06FC, SentenceCat::OLetter),
+    (0x06FF, 0x06FF, SentenceCat::OLetter),
+    (0x0700, 0x0702, SentenceCat::STerm),
+    (0x070F, 0x070F, SentenceCat::Format),
+    (0x0710, 0x0710, SentenceCat::OLetter),
+    (0x0711, 0x0711, SentenceCat::Extend),
+    (0x0712, 0x072F, SentenceCat::OLetter),
+    (0x0730, 0x074A, SentenceCat::Extend),
+    (0x074D, 0x07A5, SentenceCat::OLetter),
+    (0x07A6, 0x07B0, SentenceCat::Extend),
+    (0x07B1, 0x07B1, SentenceCat::OLetter),
+    (0x07C0, 0x07C9, SentenceCat::Numeric),
+    (0x07CA, 0x07EA, SentenceCat::OLetter),
+    (0x07EB, 0x07F3, SentenceCat::Extend),
+    (0x07F4, 0x07F5, SentenceCat::OLetter),
+    (0x07F8, 0x07F8, SentenceCat::SContinue),
+    (0x07F9, 0x07F9, SentenceCat::STerm),
+    (0x07FA, 0x07FA, SentenceCat::OLetter),
+    (0x07FD, 0x07FD, SentenceCat::Extend),
+    (0x0800, 0x0815, SentenceCat::OLetter),
+    (0x0816, 0x0819, SentenceCat::Extend),
+    (0x081A, 0x081A, SentenceCat::OLetter),
+    (0x081B, 0x0823, SentenceCat::Extend),
+    (0x0824, 0x0824, SentenceCat::OLetter),
+    (0x0825, 0x0827, SentenceCat::Extend),
+    (0x0828, 0x0828, SentenceCat::OLetter),
+    (0x0829, 0x082D, SentenceCat::Extend),
+    (0x0837, 0x0837, SentenceCat::STerm),
+    (0x0839, 0x0839, SentenceCat::STerm),
+    (0x083D, 0x083E, SentenceCat::STerm),
+    (0x0840, 0x0858, SentenceCat::OLetter),
+    (0x0859, 0x085B, SentenceCat::Extend),
+    (0x0860, 0x086A, SentenceCat::OLetter),
+    (0x0870, 0x0887, SentenceCat::OLetter),
+    (0x0889, 0x088E, SentenceCat::OLetter),
+    (0x0890, 0x0891, SentenceCat::Format),
+    (0x0898, 0x089F, SentenceCat::Extend),
+    (0x08A0, 0x08C9, SentenceCat::OLetter),
+    (0x08CA, 0x08E1, SentenceCat::Extend),
+    (0x08E2, 0x08E2, SentenceCat::Format),
+    (0x08E3, 0x0903, SentenceCat::Extend),
+    (0x0904, 0x0939, SentenceCat::OLetter),
+    (0x093A, 0x093C, SentenceCat::Extend),
+    (0x093D, 0x093D, SentenceCat::OLetter),
+    (0x093E, 0x094F, SentenceCat::Extend),
+    (0x0950, 0x0950, SentenceCat::OLetter),
+    (0x0951, 0x0957, SentenceCat::Extend),
+    (0x0958, 0x0961, SentenceCat::OLetter),
+    (0x0962, 0x0963, SentenceCat::Extend),
+    (0x0964, 0x0965, SentenceCat::STerm),
+    (0x0966, 0x096F, SentenceCat::Numeric),
+    (0x0971, 0x0980, SentenceCat::OLetter),
+    (0x0981, 0x0983, SentenceCat::Extend),
+    (0x0985, 0x098C, SentenceCat::OLetter),
+    (0x098F, 0x0990, SentenceCat::OLetter),
+    (0x0993, 0x09A8, SentenceCat::OLetter),
+    (0x09AA, 0x09B0, SentenceCat::OLetter),
+    (0x09B2, 0x09B2, SentenceCat::OLetter),
+    (0x09B6, 0x09B9, SentenceCat::OLetter),
+    (0x09BC, 0x09BC, SentenceCat::Extend),
+    (0x09BD, 0x09BD, SentenceCat::OLetter),
+    (0x09BE, 0x09C4, SentenceCat::Extend),
+    (0x09C7, 0x09C8, SentenceCat::Extend),
+    (0x09CB, 0x09CD, SentenceCat::Extend),
+    (0x09CE, 0x09CE, SentenceCat::OLetter),
+    (0x09D7, 0x09D7, SentenceCat::Extend),
+    (0x09DC, 0x09DD, SentenceCat::OLetter),
+    (0x09DF, 0x09E1, SentenceCat::OLetter),
+    (0x09E2, 0x09E3, SentenceCat::Extend),
+    (0x09E6, 0x09EF, SentenceCat::Numeric),
+    (0x09F0, 0x09F1, SentenceCat::OLetter),
+    (0x09FC, 0x09FC, SentenceCat::OLetter),
+    (0x09FE, 0x09FE, SentenceCat::Extend),
+    (0x0A01, 0x0A03, SentenceCat::Extend),
+    (0x0A05, 0x0A0A, SentenceCat::OLetter),
+    (0x0A0F, 0x0A10, SentenceCat::OLetter),
+    (0x0A13, 0x0A28, SentenceCat::OLetter),
+    (0x0A2A, 0x0A30, SentenceCat::OLetter),
+    (0x0A32, 0x0A33, SentenceCat::OLetter),
+    (0x0A35, 0x0A36, SentenceCat::OLetter),
+    (0x0A38, 0x0A39, SentenceCat::OLetter),
+    (0x0A3C, 0x0A3C, SentenceCat::Extend),
+    (0x0A3E, 0x0A42, SentenceCat::Extend),
+    (0x0A47, 0x0A48, SentenceCat::Extend),
+    (0x0A4B, 0x0A4D, SentenceCat::Extend),
+    (0x0A51, 0x0A51, SentenceCat::Extend),
+    (0x0A59, 0x0A5C, SentenceCat::OLetter),
+    (0x0A5E, 0x0A5E, SentenceCat::OLetter),
+    (0x0A66, 0x0A6F, SentenceCat::Numeric),
+    (0x0A70, 0x0A71, SentenceCat::Extend),
+    (0x0A72, 0x0A74, SentenceCat::OLetter),
+    (0x0A75, 0x0A75, SentenceCat::Extend),
+    (0x0A81, 0x0A83, SentenceCat::Extend),
+    (0x0A85, 0x0A8D, SentenceCat::OLetter),
+    (0x0A8F, 0x0A91, SentenceCat::OLetter),
+    (0x0A93, 0x0AA8, SentenceCat::OLetter),
+    (0x0AAA, 0x0AB0, SentenceCat::OLetter),
+    (0x0AB2, 0x0AB3, SentenceCat::OLetter),
+    (0x0AB5, 0x0AB9, SentenceCat::OLetter),
+    (0x0ABC, 0x0ABC, SentenceCat::Extend),
+    (0x0ABD, 0x0ABD, SentenceCat::OLetter),
+    (0x0ABE, 0x0AC5, SentenceCat::Extend),
+    (0x0AC7, 0x0AC9, SentenceCat::Extend),
+    (0x0ACB, 0x0ACD, SentenceCat::Extend),
+    (0x0AD0, 0x0AD0, SentenceCat::OLetter),
+    (0x0AE0, 0x0AE1, SentenceCat::OLetter),
+    (0x0AE2, 0x0AE3, SentenceCat::Extend),
+    (0x0AE6, 0x0AEF, SentenceCat::Numeric),
+    (0x0AF9, 0x0AF9, SentenceCat::OLetter),
+    (0x0AFA, 0x0AFF, SentenceCat::Extend),
+    (0x0B01, 0x0B03, SentenceCat::Extend),
+    (0x0B05, 0x0B0C, SentenceCat::OLetter),
+    (0x0B0F, 0x0B10, SentenceCat::OLetter),
+    (0x0B13, 0x0B28, SentenceCat::OLetter),
+    (0x0B2A, 0x0B30, SentenceCat::OLetter),
+    (0x0B32, 0x0B33, SentenceCat::OLetter),
+    (0x0B35, 0x0B39, SentenceCat::OLetter),
+    (0x0B3C, 0x0B3C, SentenceCat::Extend),
+    (0x0B3D, 0x0B3D, SentenceCat::OLetter),
+    (0x0B3E, 0x0B44, SentenceCat::Extend),
+    (0x0B47, 0x0B48, SentenceCat::Extend),
+    (0x0B4B, 0x0B4D, SentenceCat::Extend),
+    (0x0B55, 0x0B57, SentenceCat::Extend),
+    (0x0B5C, 0x0B5D, SentenceCat::OLetter),
+    (0x0B5F, 0x0B61, SentenceCat::OLetter),
+    (0x0B62, 0x0B63, SentenceCat::Extend),
+    (0x0B66, 0x0B6F, SentenceCat::Numeric),
+    (0x0B71, 0x0B71, SentenceCat::OLetter),
+    (0x0B82, 0x0B82, SentenceCat::Extend),
+    (0x0B83, 0x0B83, SentenceCat::OLetter),
+    (0x0B85, 0x0B8A, SentenceCat::OLetter),
+    (0x0B8E, 0x0B90, SentenceCat::OLetter),
+    (0x0B92, 0x0B95, SentenceCat::OLetter),
+    (0x0B99, 0x0B9A, SentenceCat::OLetter),
+    (0x0B9C, 0x0B9C, SentenceCat::OLetter),
+    (0x0B9E, 0x0B9F, SentenceCat::OLetter),
+    (0x0BA3, 0x0BA4, SentenceCat::OLetter),
+    (0x0BA8, 0x0BAA, SentenceCat::OLetter),
+    (0x0BAE, 0x0BB9, SentenceCat::OLetter),
+    (0x0BBE, 0x0BC2, SentenceCat::Extend),
+    (0x0BC6, 0x0BC8, SentenceCat::Extend),
+    (0x0BCA, 0x0BCD, SentenceCat::Extend),
+    (0x0BD0, 0x0BD0, SentenceCat::OLetter),
+    (0x0BD7, 0x0BD7, SentenceCat::Extend),
+    (0x0BE6, 0x0BEF, SentenceCat::Numeric),
+    (0x0C00, 0x0C04, SentenceCat::Extend),
+    (0x0C05, 0x0C0C, SentenceCat::OLetter),
+    (0x0C0E, 0x0C10, SentenceCat::OLetter),
+    (0x0C12, 0x0C28, SentenceCat::OLetter),
+    (0x0C2A, 0x0C39, SentenceCat::OLetter),
+    (0x0C3C, 0x0C3C, SentenceCat::Extend),
+    (0x0C3D, 0x0C3D, SentenceCat::OLetter),
+    (0x0C3E, 0x0C44, SentenceCat::Extend),
+    (0x0C46, 0x0C48, SentenceCat::Extend),
+    (0x0C4A, 0x0C4D, SentenceCat::Extend),
+    (0x0C55, 0x0C56, SentenceCat::Extend),
+    (0x0C58, 0x0C5A, SentenceCat::OLetter),
+    (0x0C5D, 0x0C5D, SentenceCat::OLetter),
+    (0x0C60, 0x0C61, SentenceCat::OLetter),
+    (0x0C62, 0x0C63, SentenceCat::Extend),
+    (0x0C66, 0x0C6F, SentenceCat::Numeric),
+    (0x0C80, 0x0C80, SentenceCat::OLetter),
+    (0x0C81, 0x0C83, SentenceCat::Extend),
+    (0x0C85, 0x0C8C, SentenceCat::OLetter),
+    (0x0C8E, 0x0C90, SentenceCat::OLetter),
+    (0x0C92, 0x0CA8, SentenceCat::OLetter),
+    (0x0CAA, 0x0CB3, SentenceCat::OLetter),
+    (0x0CB5, 0x0CB9, SentenceCat::OLetter),
+    (0x0CBC, 0x0CBC, SentenceCat::Extend),
+    (0x0CBD, 0x0CBD, SentenceCat::OLetter),
+    (0x0CBE, 0x0CC4, SentenceCat::Extend),
+    (0x0CC6, 0x0CC8, SentenceCat::Extend),
+    (0x0CCA, 0x0CCD, SentenceCat::Extend),
+    (0x0CD5, 0x0CD6, SentenceCat::Extend),
+    (0x0CDD, 0x0CDE, SentenceCat::OLetter),
+    (0x0CE0, 0x0CE1, SentenceCat::OLetter),
+    (0x0CE2, 0x0CE3, SentenceCat::Extend),
+    (0x0CE6, 0x0CEF, SentenceCat::Numeric),
+    (0x0CF1, 0x0CF2, SentenceCat::OLetter),
+    (0x0D00, 0x0D03, SentenceCat::Extend),
+    (0x0D04, 0x0D0C, SentenceCat::OLetter),
+    (0x0D0E, 0x0D10, SentenceCat::OLetter),
+    (0x0D12, 0x0D3A, SentenceCat::OLetter),
+    (0x0D3B, 0x0D3C, SentenceCat::Extend),
+    (0x0D3D, 0x0D3D, SentenceCat::OLetter),
+    (0x0D3E, 0x0D44, SentenceCat::Extend),
+    (0x0D46, 0x0D48, SentenceCat::Extend),
+    (0x0D4A, 0x0D4D, SentenceCat::Extend),
+    (0x0D4E, 0x0D4E, SentenceCat::OLetter),
+    (0x0D54, 0x0D56, SentenceCat::OLetter),
+    (0x0D57, 0x0D57, SentenceCat::Extend),
+    (0x0D5F, 0x0D61, SentenceCat::OLetter),
+    (0x0D62, 0x0D63, SentenceCat::Extend),
+    (0x0D66, 0x0D6F, SentenceCat::Numeric),
+    (0x0D7A, 0x0D7F, SentenceCat::OLetter),
+    (0x0D81, 0x0D83, SentenceCat::Extend),
+    (0x0D85, 0x0D96, SentenceCat::OLetter),
+    (0x0D9A, 0x0DB1, SentenceCat::OLetter),
+    (0x0DB3, 0x0DBB, SentenceCat::OLetter),
+    (0x0DBD, 0x0DBD, SentenceCat::OLetter),
+    (0x0DC0, 0x0DC6, SentenceCat::OLetter),
+    (0x0DCA, 0x0DCA, SentenceCat::Extend),
+    (0x0DCF, 0x0DD4, SentenceCat::Extend),
+    (0x0DD6, 0x0DD6, SentenceCat::Extend),
+    (0x0DD8, 0x0DDF, SentenceCat::Extend),
+    (0x0DE6, 0x0DEF, SentenceCat::Numeric),
+    (0x0DF2, 0x0DF3, SentenceCat::Extend),
+    (0x0E01, 0x0E30, SentenceCat::OLetter),
+    (0x0E31, 0x0E31, SentenceCat::Extend),
+    (0x0E32, 0x0E33, SentenceCat::OLetter),
+    (0x0E34, 0x0E3A, SentenceCat::Extend),
+    (0x0E40, 0x0E46, SentenceCat::OLetter),
+    (0x0E47, 0x0E4E, SentenceCat::Extend),
+    (0x0E50, 0x0E59, SentenceCat::Numeric),
+    (0x0E81, 0x0E82, SentenceCat::OLetter),
+    (0x0E84, 0x0E84, SentenceCat::OLetter),
+    (0x0E86, 0x0E8A, SentenceCat::OLetter),
+    (0x0E8C, 0x0EA3, SentenceCat::OLetter),
+    (0x0EA5, 0x0EA5, SentenceCat::OLetter),
+    (0x0EA7, 0x0EB0, SentenceCat::OLetter),
+    (0x0EB1, 0x0EB1, SentenceCat::Extend),
+    (0x0EB2, 0x0EB3, SentenceCat::OLetter),
+    (0x0EB4, 0x0EBC, SentenceCat::Extend),
+    (0x0EBD, 0x0EBD, SentenceCat::OLetter),
+    (0x0EC0, 0x0EC4, SentenceCat::OLetter),
+    (0x0EC6, 0x0EC6, SentenceCat::OLetter),
+    (0x0EC8, 0x0ECD, SentenceCat::Extend),
+    (0x0ED0, 0x0ED9, SentenceCat::Numeric),
+    (0x0EDC, 0x0EDF, SentenceCat::OLetter),
+    (0x0F00, 0x0F00, SentenceCat::OLetter),
+    (0x0F18, 0x0F19, SentenceCat::Extend),
+    (0x0F20, 0x0F29, SentenceCat::Numeric),
+    (0x0F35, 0x0F35, SentenceCat::Extend),
+    (0x0F37, 0x0F37, SentenceCat::Extend),
+    (0x0F39, 0x0F39, SentenceCat::Extend),
+    (0x0F3A, 0x0F3D, SentenceCat::Close),
+    (0x0F3E, 0x0F3F, SentenceCat::Extend),
+    (0x0F40, 0x0F47, SentenceCat::OLetter),
+    (0x0F49, 0x0F6C, SentenceCat::OLetter),
+    (0x0F71, 0x0F84, SentenceCat::Extend),
+    (0x0F86, 0x0F87, SentenceCat::Extend),
+    (0x0F88, 0x0F8C, SentenceCat::OLetter),
+    (0x0F8D, 0x0F97, SentenceCat::Extend),
+    (0x0F99, 0x0FBC, SentenceCat::Extend),
+    (0x0FC6, 0x0FC6, SentenceCat::Extend),
+    (0x1000, 0x102A, SentenceCat::OLetter),
+    (0x102B, 0x103E, SentenceCat::Extend),
+    (0x103F, 0x103F, SentenceCat::OLetter),
+    (0x1040, 0x1049, SentenceCat::Numeric),
+    (0x104A, 0x104B, SentenceCat::STerm),
+    (0x1050, 0x1055, SentenceCat::OLetter),
+    (0x1056, 0x1059, SentenceCat::Extend),
+    (0x105A, 0x105D, SentenceCat::OLetter),
+    (0x105E, 0x1060, SentenceCat::Extend),
+    (0x1061, 0x1061, SentenceCat::OLetter),
+    (0x1062, 0x1064, SentenceCat::Extend),
+    (0x1065, 0x1066, SentenceCat::OLetter),
+    (0x1067, 0x106D, SentenceCat::Extend),
+    (0x106E, 0x1070, SentenceCat::OLetter),
+    (0x1071, 0x1074, SentenceCat::Extend),
+    (0x1075, 0x1081, SentenceCat::OLetter),
+    (0x1082, 0x108D, SentenceCat::Extend),
+    (0x108E, 0x108E, SentenceCat::OLetter),
+    (0x108F, 0x108F, SentenceCat::Extend),
+    (0x1090, 0x1099, SentenceCat::Numeric),
+    (0x109A, 0x109D, SentenceCat::Extend),
+    (0x10A0, 0x10C5, SentenceCat::Upper),
+    (0x10C7, 0x10C7, SentenceCat::Upper),
+    (0x10CD, 0x10CD, SentenceCat::Upper),
+    (0x10D0, 0x10FA, SentenceCat::OLetter),
+    (0x10FC, 0x1248, SentenceCat::OLetter),
+    (0x124A, 0x124D, SentenceCat::OLetter),
+    (0x1250, 0x1256, SentenceCat::OLetter),
+    (0x1258, 0x1258, SentenceCat::OLetter),
+    (0x125A, 0x125D, SentenceCat::OLetter),
+    (0x1260, 0x1288, SentenceCat::OLetter),
+    (0x128A, 0x128D, SentenceCat::OLetter),
+    (0x1290, 0x12B0, SentenceCat::OLetter),
+    (0x12B2, 0x12B5, SentenceCat::OLetter),
+    (0x12B8, 0x12BE, SentenceCat::OLetter),
+    (0x12C0, 0x12C0, SentenceCat::OLetter),
+    (0x12C2, 0x12C5, SentenceCat::OLetter),
+    (0x12C8, 0x12D6, SentenceCat::OLetter),
+    (0x12D8, 0x1310, SentenceCat::OLetter),
+    (0x1312, 0x1315, SentenceCat::OLetter),
+    (0x1318, 0x135A, SentenceCat::OLetter),
+    (0x135D, 0x135F, SentenceCat::Extend),
+    (0x1362, 0x1362, SentenceCat::STerm),
+    (0x1367, 0x1368, SentenceCat::STerm),
+    (0x1380, 0x138F, SentenceCat::OLetter),
+    (0x13A0, 0x13F5, SentenceCat::Upper),
+    (0x13F8, 0x13FD, SentenceCat::Lower),
+    (0x1401, 0x166C, SentenceCat::OLetter),
+    (0x166E, 0x166E, SentenceCat::STerm),
+    (0x166F, 0x167F, SentenceCat::OLetter),
+    (0x1680, 0x1680, SentenceCat::Sp),
+    (0x1681, 0x169A, SentenceCat::OLetter),
+    (0x169B, 0x169C, SentenceCat::Close),
+    (0x16A0, 0x16EA, SentenceCat::OLetter),
+    (0x16EE, 0x16F8, SentenceCat::OLetter),
+    (0x1700, 0x1711, SentenceCat::OLetter),
+    (0x1712, 0x1715, SentenceCat::Extend),
+    (0x171F, 0x1731, SentenceCat::OLetter),
+    (0x1732, 0x1734, SentenceCat::Extend),
+    (0x1735, 0x1736, SentenceCat::STerm),
+    (0x1740, 0x1751, SentenceCat::OLetter),
+    (0x1752, 0x1753, SentenceCat::Extend),
+    (0x1760, 0x176C, SentenceCat::OLetter),
+    (0x176E, 0x1770, SentenceCat::OLetter),
+    (0x1772, 0x1773, SentenceCat::Extend),
+    (0x1780, 0x17B3, SentenceCat::OLetter),
+    (0x17B4, 0x17D3, SentenceCat::Extend),
+    (0x17D7, 0x17D7, SentenceCat::OLetter),
+    (0x17DC, 0x17DC, SentenceCat::OLetter),
+    (0x17DD, 0x17DD, SentenceCat::Extend),
+    (0x17E0, 0x17E9, SentenceCat::Numeric),
+    (0x1802, 0x1802, SentenceCat::SContinue),
+    (0x1803, 0x1803, SentenceCat::STerm),
+    (0x1808, 0x1808, SentenceCat::SContinue),
+    (0x1809, 0x1809, SentenceCat::STerm),
+    (0x180B, 0x180D, SentenceCat::Extend),
+    (0x180E, 0x180E, SentenceCat::Format),
+    (0x180F, 0x180F, SentenceCat::Extend),
+    (0x1810, 0x1819, SentenceCat::Numeric),
+    (0x1820, 0x1878, SentenceCat::OLetter),
+    (0x1880, 0x1884, SentenceCat::OLetter),
+    (0x1885, 0x1886, SentenceCat::Extend),
+    (0x1887, 0x18A8, SentenceCat::OLetter),
+    (0x18A9, 0x18A9, SentenceCat::Extend),
+    (0x18AA, 0x18AA, SentenceCat::OLetter),
+    (0x18B0, 0x18F5, SentenceCat::OLetter),
+    (0x1900, 0x191E, SentenceCat::OLetter),
+    (0x1920, 0x192B, SentenceCat::Extend),
+    (0x1930, 0x193B, SentenceCat::Extend),
+    (0x1944, 0x1945, SentenceCat::STerm),
+    (0x1946, 0x194F, SentenceCat::Numeric),
+    (0x1950, 0x196D, SentenceCat::OLetter),
+    (0x1970, 0x1974, SentenceCat::OLetter),
+    (0x1980, 0x19AB, SentenceCat::OLetter),
+    (0x19B0, 0x19C9, SentenceCat::OLetter),
+    (0x19D0, 0x19D9, SentenceCat::Numeric),
+    (0x1A00, 0x1A16, SentenceCat::OLetter),
+    (0x1A17, 0x1A1B, SentenceCat::Extend),
+    (0x1A20, 0x1A54, SentenceCat::OLetter),
+    (0x1A55, 0x1A5E, SentenceCat::Extend),
+    (0x1A60, 0x1A7C, SentenceCat::Extend),
+    (0x1A7F, 0x1A7F, SentenceCat::Extend),
+    (0x1A80, 0x1A89, SentenceCat::Numeric),
+    (0x1A90, 0x1A99, SentenceCat::Numeric),
+    (0x1AA7, 0x1AA7, SentenceCat::OLetter),
+    (0x1AA8, 0x1AAB, SentenceCat::STerm),
+    (0x1AB0, 0x1ACE, SentenceCat::Extend),
+    (0x1B00, 0x1B04, SentenceCat::Extend),
+    (0x1B05, 0x1B33, SentenceCat::OLetter),
+    (0x1B34, 0x1B44, SentenceCat::Extend),
+    (0x1B45, 0x1B4C, SentenceCat::OLetter),
+    (0x1B50, 0x1B59, SentenceCat::Numeric),
+    (0x1B5A, 0x1B5B, SentenceCat::STerm),
+    (0x1B5E, 0x1B5F, SentenceCat::STerm),
+    (0x1B6B, 0x1B73, SentenceCat::Extend),
+    (0x1B7D, 0x1B7E, SentenceCat::STerm),
+    (0x1B80, 0x1B82, SentenceCat::Extend),
+    (0x1B83, 0x1BA0, SentenceCat::OLetter),
+    (0x1BA1, 0x1BAD, SentenceCat::Extend),
+    (0x1BAE, 0x1BAF, SentenceCat::OLetter),
+    (0x1BB0, 0x1BB9, SentenceCat::Numeric),
+    (0x1BBA, 0x1BE5, SentenceCat::OLetter),
+    (0x1BE6, 0x1BF3, SentenceCat::Extend),
+    (0x1C00, 0x1C23, SentenceCat::OLetter),
+    (0x1C24, 0x1C37, SentenceCat::Extend),
+    (0x1C3B, 0x1C3C, SentenceCat::STerm),
+    (0x1C40, 0x1C49, SentenceCat::Numeric),
+    (0x1C4D, 0x1C4F, SentenceCat::OLetter),
+    (0x1C50, 0x1C59, SentenceCat::Numeric),
+    (0x1C5A, 0x1C7D, SentenceCat::OLetter),
+    (0x1C7E, 0x1C7F, SentenceCat::STerm),
+    (0x1C80, 0x1C88, SentenceCat::Lower),
+    (0x1C90, 0x1CBA, SentenceCat::OLetter),
+    (0x1CBD, 0x1CBF, SentenceCat::OLetter),
+    (0x1CD0, 0x1CD2, SentenceCat::Extend),
+    (0x1CD4, 0x1CE8, SentenceCat::Extend),
+    (0x1CE9, 0x1CEC, SentenceCat::OLetter),
+    (0x1CED, 0x1CED, SentenceCat::Extend),
+    (0x1CEE, 0x1CF3, SentenceCat::OLetter),
+    (0x1CF4, 0x1CF4, SentenceCat::Extend),
+    (0x1CF5, 0x1CF6, SentenceCat::OLetter),
+    (0x1CF7, 0x1CF9, SentenceCat::Extend),
+    (0x1CFA, 0x1CFA, SentenceCat::OLetter),
+    (0x1D00, 0x1DBF, SentenceCat::Lower),
+    (0x1DC0, 0x1DFF, SentenceCat::Extend),
+    (0x1E00, 0x1E00, SentenceCat::Upper),
+    (0x1E01, 0x1E01, SentenceCat::Lower),
+    (0x1E02, 0x1E02, SentenceCat::Upper),
+    (0x1E03, 0x1E03, SentenceCat::Lower),
+    (0x1E04, 0x1E04, SentenceCat::Upper),
+    (0x1E05, 0x1E05, SentenceCat::Lower),
+    (0x1E06, 0x1E06, SentenceCat::Upper),
+    (0x1E07, 0x1E07, SentenceCat::Lower),
+    (0x1E08, 0x1E08, SentenceCat::Upper),
+    (0x1E09, 0x1E09, SentenceCat::Lower),
+    (0x1E0A, 0x1E0A, SentenceCat::Upper),
+    (0x1E0B, 0x1E0B, SentenceCat::Lower),
+    (0x1E0C, 0x1E0C, SentenceCat::Upper),
+    (0x1E0D, 0x1E0D, SentenceCat::Lower),
+    (0x1E0E, 0x1E0E, SentenceCat::Upper),
+    (0x1E0F, 0x1E0F, SentenceCat::Lower),
+    (0x1E10, 0x1E10, SentenceCat::Upper),
+    (0x1E11, 0x1E11, SentenceCat::Lower),
+    (0x1E12, 0x1E12, SentenceCat::Upper),
+    (0x1E13, 0x1E13, SentenceCat::Lower),
+    (0x1E14, 0x1E14, SentenceCat::Upper),
+    (0x1E15, 0x1E15, SentenceCat::Lower),
+    (0x1E16, 0x1E16, SentenceCat::Upper),
+    (0x1E17, 0x1E17, SentenceCat::Lower),
+    (0x1E18, 0x1E18, SentenceCat::Upper),
+    (0x1E19, 0x1E19, SentenceCat::Lower),
+    (0x1E1A, 0x1E1A, SentenceCat::Upper),
+    (0x1E1B, 0x1E1B, SentenceCat::Lower),
+    (0x1E1C, 0x1E1C, SentenceCat::Upper),
+    (0x1E1D, 0x1E1D, SentenceCat::Lower),
+    (0x1E1E, 0x1E1E, SentenceCat::Upper),
+    (0x1E1F, 0x1E1F, SentenceCat::Lower),
+    (0x1E20, 0x1E20, SentenceCat::Upper),
+    (0x1E21, 0x1E21, SentenceCat::Lower),
+    (0x1E22, 0x1E22, SentenceCat::Upper),
+    (0x1E23, 0x1E23, SentenceCat::Lower),
+    (0x1E24, 0x1E24, SentenceCat::Upper),
+    (0x1E25, 0x1E25, SentenceCat::Lower),
+    (0x1E26, 0x1E26, SentenceCat::Upper),
+    (0x1E27, 0x1E27, SentenceCat::Lower),
+    (0x1E28, 0x1E28, SentenceCat::Upper),
+    (0x1E29, 0x1E29, SentenceCat::Lower),
+    (0x1E2A, 0x1E2A, SentenceCat::Upper),
+    (0x1E2B, 0x1E2B, SentenceCat::Lower),
+    (0x1E2C, 0x1E2C, SentenceCat::Upper),
+    (0x1E2D, 0x1E2D, SentenceCat::Lower),
+    (0x1E2E, 0x1E2E, SentenceCat::Upper),
+    (0x1E2F, 0x1E2F, SentenceCat::Lower),
+    (0x1E30, 0x1E30, SentenceCat::Upper),
+    (0x1E31, 0x1E31, SentenceCat::Lower),
+    (0x1E32, 0x1E32, SentenceCat::Upper),
+    (0x1E33, 0x1E33, SentenceCat::Lower),
+    (0x1E34, 0x1E34, SentenceCat::Upper),
+    (0x1E35, 0x1E35, SentenceCat::Lower),
+    (0x1E36, 0x1E36, SentenceCat::Upper),
+    (0x1E37, 0x1E37, SentenceCat::Lower),
+    (0x1E38, 0x1E38, SentenceCat::Upper),
+    (0x1E39, 0x1E39, SentenceCat::Lower),
+    (0x1E3A, 0x1E3A, SentenceCat::Upper),
+    (0x1E3B, 0x1E3B, SentenceCat::Lower),
+    (0x1E3C, 0x1E3C, SentenceCat::Upper),
+    (0x1E3D, 0x1E3D, SentenceCat::Lower),
+    (0x1E3E, 0x1E3E, SentenceCat::Upper),
+    (0x1E3F, 0x1E3F, SentenceCat::Lower),
+    (0x1E40, 0x1E40, SentenceCat::Upper),
+    (0x1E41, 0x1E41, SentenceCat::Lower),
+    (0x1E42, 0x1E42, SentenceCat::Upper),
+    (0x1E43, 0x1E43, SentenceCat::Lower),
+    (0x1E44, 0x1E44, SentenceCat::Upper),
+    (0x1E45, 0x1E45, SentenceCat::Lower),
+    (0x1E46, 0x1E46, SentenceCat::Upper),
+    (0x1E47, 0x1E47, SentenceCat::Lower),
+    (0x1E48, 0x1E48, SentenceCat::Upper),
+    (0x1E49, 0x1E49, SentenceCat::Lower),
+    (0x1E4A, 0x1E4A, SentenceCat::Upper),
+    (0x1E4B, 0x1E4B, SentenceCat::Lower),
+    (0x1E4C, 0x1E4C, SentenceCat::Upper),
+    (0x1E4D, 0x1E4D, SentenceCat::Lower),
+    (0x1E4E, 0x1E4E, SentenceCat::Upper),
+    (0x1E4F, 0x1E4F, SentenceCat::Lower),
+    (0x1E50, 0x1E50, SentenceCat::Upper),
+    (0x1E51, 0x1E51, SentenceCat::Lower),
+    (0x1E52, 0x1E52, SentenceCat::Upper),
+    (0x1E53, 0x1E53, SentenceCat::Lower),
+    (0x1E54, 0x1E54, SentenceCat::Upper),
+    (0x1E55, 0x1E55, SentenceCat::Lower),
+    (0x1E56, 0x1E56, SentenceCat::Upper),
+    (0x1E57, 0x1E57, SentenceCat::Lower),
+    (0x1E58, 0x1E58, SentenceCat::Upper),
+    (0x1E59, 0x1E59, SentenceCat::Lower),
+    (0x1E5A, 0x1E5A, SentenceCat::Upper),
+    (0x1E5B, 0x1E5B, SentenceCat::Lower),
+    (0x1E5C, 0x1E5C, SentenceCat::Upper),
+    (0x1E5D, 0x1E5D, SentenceCat::Lower),
+    (0x1E5E, 0x1E5E, SentenceCat::Upper),
+    (0x1E5F, 0x1E5F, SentenceCat::Lower),
+    (0x1E60, 0x1E60, SentenceCat::Upper),
+    (0x1E61, 0x1E61, SentenceCat::Lower),
+    (0x1E62, 0x1E62, SentenceCat::Upper),
+    (0x1E63, 0x1E63, SentenceCat::Lower),
+    (0x1E64, 0x1E64, SentenceCat::Upper),
+    (0x1E65, 0x1E65, SentenceCat::Lower),
+    (0x1E66, 0x1E66, SentenceCat::Upper),
+    (0x1E67, 0x1E67, SentenceCat::Lower),
+    (0x1E68, 0x1E68, SentenceCat::Upper),
+    (0x1E69, 0x1E69, SentenceCat::Lower),
+    (0x1E6A, 0x1E6A, SentenceCat::Upper),
+    (0x1E6B, 0x1E6B, SentenceCat::Lower),
+    (0x1E6C, 0x1E6C, SentenceCat::Upper),
+    (0x1E6D, 0x1E6D, SentenceCat::Lower),
+    (0x1E6E, 0x1E6E, SentenceCat::Upper),
+    (0x1E6F, 0x1E6F, SentenceCat::Lower),
+    (0x1E70, 0x1E70, SentenceCat::Upper),
+    (0x1E71, 0x1E71, SentenceCat::Lower),
+    (0x1E72, 0x1E72, SentenceCat::Upper),
+    (0x1E73, 0x1E73, SentenceCat::Lower),
+    (0x1E74, 0x1E74, SentenceCat::Upper),
+    (0x1E75, 0x1E75, SentenceCat::Lower),
+    (0x1E76, 0x1E76, SentenceCat::Upper),
+    (0x1E77, 0x1E77, SentenceCat::Lower),
+    (0x1E78, 0x1E78, SentenceCat::Upper),
+    (0x1E79, 0x1E79, SentenceCat::Lower),
+    (0x1E7A, 0x1E7A, SentenceCat::Upper),
+    (0x1E7B, 0x1E7B, SentenceCat::Lower),
+    (0x1E7C, 0x1E7C, SentenceCat::Upper),
+    (0x1E7D, 0x1E7D, SentenceCat::Lower),
+    (0x1E7E, 0x1E7E, SentenceCat::Upper),
+    (0x1E7F, 0x1E7F, SentenceCat::Lower),
+    (0x1E80, 0x1E80, SentenceCat::Upper),
+    (0x1E81, 0x1E81, SentenceCat::Lower),
+    (0x1E82, 0x1E82, SentenceCat::Upper),
+    (0x1E83, 0x1E83, SentenceCat::Lower),
+    (0x1E84, 0x1E84, SentenceCat::Upper),
+    (0x1E85, 0x1E85, SentenceCat::Lower),
+    (0x1E86, 0x1E86, SentenceCat::Upper),
+    (0x1E87, 0x1E87, SentenceCat::Lower),
+    (0x1E88, 0x1E88, SentenceCat::Upper),
+    (0x1E89, 0x1E89, SentenceCat::Lower),
+    (0x1E8A, 0x1E8A, SentenceCat::Upper),
+    (0x1E8B, 0x1E8B, SentenceCat::Lower),
+    (0x1E8C, 0x1E8C, SentenceCat::Upper),
+    (0x1E8D, 0x1E8D, SentenceCat::Lower),
+    (0x1E8E, 0x1E8E, SentenceCat::Upper),
+    (0x1E8F, 0x1E8F, SentenceCat::Lower),
+    (0x1E90, 0x1E90, SentenceCat::Upper),
+    (0x1E91, 0x1E91, SentenceCat::Lower),
+    (0x1E92, 0x1E92, SentenceCat::Upper),
+    (0x1E93, 0x1E93, SentenceCat::Lower),
+    (0x1E94, 0x1E94, SentenceCat::Upper),
+    (0x1E95, 0x1E9D, SentenceCat::Lower),
+    (0x1E9E, 0x1E9E, SentenceCat::Upper),
+    (0x1E9F, 0x1E9F, SentenceCat::Lower),
+    (0x1EA0, 0x1EA0, SentenceCat::Upper),
+    (0x1EA1, 0x1EA1, SentenceCat::Lower),
+    (0x1EA2, 0x1EA2, SentenceCat::Upper),
+    (0x1EA3, 0x1EA3, SentenceCat::Lower),
+    (0x1EA4, 0x1EA4, SentenceCat::Upper),
+    (0x1EA5, 0x1EA5, SentenceCat::Lower),
+    (0x1EA6, 0x1EA6, SentenceCat::Upper),
+    (0x1EA7, 0x1EA7, SentenceCat::Lower),
+    (0x1EA8, 0x1EA8, SentenceCat::Upper),
+    (0x1EA9, 0x1EA9, SentenceCat::Lower),
+    (0x1EAA, 0x1EAA, SentenceCat::Upper),
+    (0x1EAB, 0x1EAB, SentenceCat::Lower),
+    (0x1EAC, 0x1EAC, SentenceCat::Upper),
+    (0x1EAD, 0x1EAD, SentenceCat::Lower),
+    (0x1EAE, 0x1EAE, SentenceCat::Upper),
+    (0x1EAF, 0x1EAF, SentenceCat::Lower),
+    (0x1EB0, 0x1EB0, SentenceCat::Upper),
+    (0x1EB1, 0x1EB1, SentenceCat::Lower),
+    (0x1EB2, 0x1EB2, SentenceCat::Upper),
+    (0x1EB3, 0x1EB3, SentenceCat::Lower),
+    (0x1EB4, 0x1EB4, SentenceCat::Upper),
+    (0x1EB5, 0x1EB5, SentenceCat::Lower),
+    (0x1EB6, 0x1EB6, SentenceCat::Upper),
+    (0x1EB7, 0x1EB7, SentenceCat::Lower),
+    (0x1EB8, 0x1EB8, SentenceCat::Upper),
+    (0x1EB9, 0x1EB9, SentenceCat::Lower),
+    (0x1EBA, 0x1EBA, SentenceCat::Upper),
+    (0x1EBB, 0x1EBB, SentenceCat::Lower),
+    (0x1EBC, 0x1EBC, SentenceCat::Upper),
+    (0x1EBD, 0x1EBD, SentenceCat::Lower),
+    (0x1EBE, 0x1EBE, SentenceCat::Upper),
+    (0x1EBF, 0x1EBF, SentenceCat::Lower),
+    (0x1EC0, 0x1EC0, SentenceCat::Upper),
+    (0x1EC1, 0x1EC1, SentenceCat::Lower),
+    (0x1EC2, 0x1EC2, SentenceCat::Upper),
+    (0x1EC3, 0x1EC3, SentenceCat::Lower),
+    (0x1EC4, 0x1EC4, SentenceCat::Upper),
+    (0x1EC5, 0x1EC5, SentenceCat::Lower),
+    (0x1EC6, 0x1EC6, SentenceCat::Upper),
+    (0x1EC7, 0x1EC7, SentenceCat::Lower),
+    (0x1EC8, 0x1EC8, SentenceCat::Upper),
+    (0x1EC9, 0x1EC9, SentenceCat::Lower),
+    (0x1ECA, 0x1ECA, SentenceCat::Upper),
+    (0x1ECB, 0x1ECB, SentenceCat::Lower),
+    (0x1ECC, 0x1ECC, SentenceCat::Upper),
+    (0x1ECD, 0x1ECD, SentenceCat::Lower),
+    (0x1ECE, 0x1ECE, SentenceCat::Upper),
+    (0x1ECF, 0x1ECF, SentenceCat::Lower),
+    (0x1ED0, 0x1ED0, SentenceCat::Upper),
+    (0x1ED1, 0x1ED1, SentenceCat::Lower),
+    (0x1ED2, 0x1ED2, SentenceCat::Upper),
+    (0x1ED3, 0x1ED3, SentenceCat::Lower),
+    (0x1ED4, 0x1ED4, SentenceCat::Upper),
+    (0x1ED5, 0x1ED5, SentenceCat::Lower),
+    (0x1ED6, 0x1ED6, SentenceCat::Upper),
+    (0x1ED7, 0x1ED7, SentenceCat::Lower),
+    (0x1ED8, 0x1ED8, SentenceCat::Upper),
+    (0x1ED9, 0x1ED9, SentenceCat::Lower),
+    (0x1EDA, 0x1EDA, SentenceCat::Upper),
+    (0x1EDB, 0x1EDB, SentenceCat::Lower),
+    (0x1EDC, 0x1EDC, SentenceCat::Upper),
+    (0x1EDD, 0x1EDD, SentenceCat::Lower),
+    (0x1EDE, 0x1EDE, SentenceCat::Upper),
+    (0x1EDF, 0x1EDF, SentenceCat::Lower),
+    (0x1EE0, 0x1EE0, SentenceCat::Upper),
+    (0x1EE1, 0x1EE1, SentenceCat::Lower),
+    (0x1EE2, 0x1EE2, SentenceCat::Upper),
+    (0x1EE3, 0x1EE3, SentenceCat::Lower),
+    (0x1EE4, 0x1EE4, SentenceCat::Upper),
+    (0x1EE5, 0x1EE5, SentenceCat::Lower),
+    (0x1EE6, 0x1EE6, SentenceCat::Upper),
+    (0x1EE7, 0x1EE7, SentenceCat::Lower),
+    (0x1EE8, 0x1EE8, SentenceCat::Upper),
+    (0x1EE9, 0x1EE9, SentenceCat::Lower),
+    (0x1EEA, 0x1EEA, SentenceCat::Upper),
+    (0x1EEB, 0x1EEB, SentenceCat::Lower),
+    (0x1EEC, 0x1EEC, SentenceCat::Upper),
+    (0x1EED, 0x1EED, SentenceCat::Lower),
+    (0x1EEE, 0x1EEE, SentenceCat::Upper),
+    (0x1EEF, 0x1EEF, SentenceCat::Lower),
+    (0x1EF0, 0x1EF0, SentenceCat::Upper),
+    (0x1EF1, 0x1EF1, SentenceCat::Lower),
+    (0x1EF2, 0x1EF2, SentenceCat::Upper),
+    (0x1EF3, 0x1EF3, SentenceCat::Lower),
+    (0x1EF4, 0x1EF4, SentenceCat::Upper),
+    (0x1EF5, 0x1EF5, SentenceCat::Lower),
+    (0x1EF6, 0x1EF6, SentenceCat::Upper),
+    (0x1EF7, 0x1EF7, SentenceCat::Lower),
+    (0x1EF8, 0x1EF8, SentenceCat::Upper),
+    (0x1EF9, 0x1EF9, SentenceCat::Lower),
+    (0x1EFA, 0x1EFA, SentenceCat::Upper),
+    (0x1EFB, 0x1EFB, SentenceCat::Lower),
+    (0x1EFC, 0x1EFC, SentenceCat::Upper),
+    (0x1EFD, 0x1EFD, SentenceCat::Lower),
+    (0x1EFE, 0x1EFE, SentenceCat::Upper),
+    (0x1EFF, 0x1F07, SentenceCat::Lower),
+    (0x1F08, 0x1F0F, SentenceCat::Upper),
+    (0x1F10, 0x1F15, SentenceCat::Lower),
+    (0x1F18, 0x1F1D, SentenceCat::Upper),
+    (0x1F20, 0x1F27, SentenceCat::Lower),
+    (0x1F28, 0x1F2F, SentenceCat::Upper),
+    (0x1F30, 0x1F37, SentenceCat::Lower),
+    (0x1F38, 0x1F3F, SentenceCat::Upper),
+    (0x1F40, 0x1F45, SentenceCat::Lower),
+    (0x1F48, 0x1F4D, SentenceCat::Upper),
+    (0x1F50, 0x1F57, SentenceCat::Lower),
+    (0x1F59, 0x1F59, SentenceCat::Upper),
+    (0x1F5B, 0x1F5B, SentenceCat::Upper),
+    (0x1F5D, 0x1F5D, SentenceCat::Upper),
+    (0x1F5F, 0x1F5F, SentenceCat::Upper),
+    (0x1F60, 0x1F67, SentenceCat::Lower),
+    (0x1F68, 0x1F6F, SentenceCat::Upper),
+    (0x1F70, 0x1F7D, SentenceCat::Lower),
+    (0x1F80, 0x1F87, SentenceCat::Lower),
+    (0x1F88, 0x1F8F, SentenceCat::Upper),
+    (0x1F90, 0x1F97, SentenceCat::Lower),
+    (0x1F98, 0x1F9F, SentenceCat::Upper),
+    (0x1FA0, 0x1FA7, SentenceCat::Lower),
+    (0x1FA8, 0x1FAF, SentenceCat::Upper),
+    (0x1FB0, 0x1FB4, SentenceCat::Lower),
+    (0x1FB6, 0x1FB7, SentenceCat::Lower),
+    (0x1FB8, 0x1FBC, SentenceCat::Upper),
+    (0x1FBE, 0x1FBE, SentenceCat::Lower),
+    (0x1FC2, 0x1FC4, SentenceCat::Lower),
+    (0x1FC6, 0x1FC7, SentenceCat::Lower),
+    (0x1FC8, 0x1FCC, SentenceCat::Upper),
+    (0x1FD0, 0x1FD3, SentenceCat::Lower),
+    (0x1FD6, 0x1FD7, SentenceCat::Lower),
+    (0x1FD8, 0x1FDB, SentenceCat::Upper),
+    (0x1FE0, 0x1FE7, SentenceCat::Lower),
+    (0x1FE8, 0x1FEC, SentenceCat::Upper),
+    (0x1FF2, 0x1FF4, SentenceCat::Lower),
+    (0x1FF6, 0x1FF7, SentenceCat::Lower),
+    (0x1FF8, 0x1FFC, SentenceCat::Upper),
+    (0x2000, 0x200A, SentenceCat::Sp),
+    (0x200B, 0x200B, SentenceCat::Format),
+    (0x200C, 0x200D, SentenceCat::Extend),
+    (0x200E, 0x200F, SentenceCat::Format),
+    (0x2013, 0x2014, SentenceCat::SContinue),
+    (0x2018, 0x201F, SentenceCat::Close),
+    (0x2024, 0x2024, SentenceCat::ATerm),
+    (0x2028, 0x2029, SentenceCat::Sep),
+    (0x202A, 0x202E, SentenceCat::Format),
+    (0x202F, 0x202F, SentenceCat::Sp),
+    (0x2039, 0x203A, SentenceCat::Close),
+    (0x203C, 0x203D, SentenceCat::STerm),
+    (0x2045, 0x2046, SentenceCat::Close),
+    (0x2047, 0x2049, SentenceCat::STerm),
+    (0x205F, 0x205F, SentenceCat::Sp),
+    (0x2060, 0x2064, SentenceCat::Format),
+    (0x2066, 0x206F, SentenceCat::Format),
+    (0x2071, 0x2071, SentenceCat::Lower),
+    (0x207D, 0x207E, SentenceCat::Close),
+    (0x207F, 0x207F, SentenceCat::Lower),
+    (0x208D, 0x208E, SentenceCat::Close),
+    (0x2090, 0x209C, SentenceCat::Lower),
+    (0x20D0, 0x20F0, SentenceCat::Extend),
+    (0x2102, 0x2102, SentenceCat::Upper),
+    (0x2107, 0x2107, SentenceCat::Upper),
+    (0x210A, 0x210A, SentenceCat::Lower),
+    (0x210B, 0x210D, SentenceCat::Upper),
+    (0x210E, 0x210F, SentenceCat::Lower),
+    (0x2110, 0x2112, SentenceCat::Upper),
+    (0x2113, 0x2113, SentenceCat::Lower),
+    (0x2115, 0x2115, SentenceCat::Upper),
+    (0x2119, 0x211D, SentenceCat::Upper),
+    (0x2124, 0x2124, SentenceCat::Upper),
+    (0x2126, 0x2126, SentenceCat::Upper),
+    (0x2128, 0x2128, SentenceCat::Upper),
+    (0x212A, 0x212D, SentenceCat::Upper),
+    (0x212F, 0x212F, SentenceCat::Lower),
+    (0x2130, 0x2133, SentenceCat::Upper),
+    (0x2134, 0x2134, SentenceCat::Lower),
+    (0x2135, 0x2138, SentenceCat::OLetter),
+    (0x2139, 0x2139, SentenceCat::Lower),
+    (0x213C, 0x213D, SentenceCat::Lower),
+    (0x213E, 0x213F, SentenceCat::Upper),
+    (0x2145, 0x2145, SentenceCat::Upper),
+    (0x2146, 0x2149, SentenceCat::Lower),
+    (0x214E, 0x214E, SentenceCat::Lower),
+    (0x2160, 0x216F, SentenceCat::Upper),
+    (0x2170, 0x217F, SentenceCat::Lower),
+    (0x2180, 0x2182, SentenceCat::OLetter),
+    (0x2183, 0x2183, SentenceCat::Upper),
+    (0x2184, 0x2184, SentenceCat::Lower),
+    (0x2185, 0x2188, SentenceCat::OLetter),
+    (0x2308, 0x230B, SentenceCat::Close),
+    (0x2329, 0x232A, SentenceCat::Close),
+    (0x24B6, 0x24CF, SentenceCat::Upper),
+    (0x24D0, 0x24E9, SentenceCat::Lower),
+    (0x275B, 0x2760, SentenceCat::Close),
+    (0x2768, 0x2775, SentenceCat::Close),
+    (0x27C5, 0x27C6, SentenceCat::Close),
+    (0x27E6, 0x27EF, SentenceCat::Close),
+    (0x2983, 0x2998, SentenceCat::Close),
+    (0x29D8, 0x29DB, SentenceCat::Close),
+    (0x29FC, 0x29FD, SentenceCat::Close),
+    (0x2C00, 0x2C2F, SentenceCat::Upper),
+    (0x2C30, 0x2C5F, SentenceCat::Lower),
+    (0x2C60, 0x2C60, SentenceCat::Upper),
+    (0x2C61, 0x2C61, SentenceCat::Lower),
+    (0x2C62, 0x2C64, SentenceCat::Upper),
+    (0x2C65, 0x2C66, SentenceCat::Lower),
+    (0x2C67, 0x2C67, SentenceCat::Upper),
+    (0x2C68, 0x2C68, SentenceCat::Lower),
+    (0x2C69, 0x2C69, SentenceCat::Upper),
+    (0x2C6A, 0x2C6A, SentenceCat::Lower),
+    (0x2C6B, 0x2C6B, SentenceCat::Upper),
+    (0x2C6C, 0x2C6C, SentenceCat::Lower),
+    (0x2C6D, 0x2C70, SentenceCat::Upper),
+    (0x2C71, 0x2C71, SentenceCat::Lower),
+    (0x2C72, 0x2C72, SentenceCat::Upper),
+    (0x2C73, 0x2C74, SentenceCat::Lower),
+    (0x2C75, 0x2C75, SentenceCat::Upper),
+    (0x2C76, 0x2C7D, SentenceCat::Lower),
+    (0x2C7E, 0x2C80, SentenceCat::Upper),
+    (0x2C81, 0x2C81, SentenceCat::Lower),
+    (0x2C82, 0x2C82, SentenceCat::Upper),
+    (0x2C83, 0x2C83, SentenceCat::Lower),
+    (0x2C84, 0x2C84, SentenceCat::Upper),
+    (0x2C85, 0x2C85, SentenceCat::Lower),
+    (0x2C86, 0x2C86, SentenceCat::Upper),
+    (0x2C87, 0x2C87, SentenceCat::Lower),
+    (0x2C88, 0x2C88, SentenceCat::Upper),
+    (0x2C89, 0x2C89, SentenceCat::Lower),
+    (0x2C8A, 0x2C8A, SentenceCat::Upper),
+    (0x2C8B, 0x2C8B, SentenceCat::Lower),
+    (0x2C8C, 0x2C8C, SentenceCat::Upper),
+    (0x2C8D, 0x2C8D, SentenceCat::Lower),
+    (0x2C8E, 0x2C8E, SentenceCat::Upper),
+    (0x2C8F, 0x2C8F, SentenceCat::Lower),
+    (0x2C90, 0x2C90, SentenceCat::Upper),
+    (0x2C91, 0x2C91, SentenceCat::Lower),
+    (0x2C92, 0x2C92, SentenceCat::Upper),
+    (0x2C93, 0x2C93, SentenceCat::Lower),
+    (0x2C94, 0x2C94, SentenceCat::Upper),
+    (0x2C95, 0x2C95, SentenceCat::Lower),
+    (0x2C96, 0x2C96, SentenceCat::Upper),
+    (0x2C97, 0x2C97, SentenceCat::Lower),
+    (0x2C98, 0x2C98, SentenceCat::Upper),
+    (0x2C99, 0x2C99, SentenceCat::Lower),
+    (0x2C9A, 0x2C9A, SentenceCat::Upper),
+    (0x2C9B, 0x2C9B, SentenceCat::Lower),
+    (0x2C9C, 0x2C9C, SentenceCat::Upper),
+    (0x2C9D, 0x2C9D, SentenceCat::Lower),
+    (0x2C9E, 0x2C9E, SentenceCat::Upper),
+    (0x2C9F, 0x2C9F, SentenceCat::Lower),
+    (0x2CA0, 0x2CA0, SentenceCat::Upper),
+    (0x2CA1, 0x2CA1, SentenceCat::Lower),
+    (0x2CA2, 0x2CA2, SentenceCat::Upper),
+    (0x2CA3, 0x2CA3, SentenceCat::Lower),
+    (0x2CA4, 0x2CA4, SentenceCat::Upper),
+    (0x2CA5, 0x2CA5, SentenceCat::Lower),
+    (0x2CA6, 0x2CA6, SentenceCat::Upper),
+    (0x2CA7, 0x2CA7, SentenceCat::Lower),
+    (0x2CA8, 0x2CA8, SentenceCat::Upper),
+    (0x2CA9, 0x2CA9, SentenceCat::Lower),
+    (0x2CAA, 0x2CAA, SentenceCat::Upper),
+    (0x2CAB, 0x2CAB, SentenceCat::Lower),
+    (0x2CAC, 0x2CAC, SentenceCat::Upper),
+    (0x2CAD, 0x2CAD, SentenceCat::Lower),
+    (0x2CAE, 0x2CAE, SentenceCat::Upper),
+    (0x2CAF, 0x2CAF, SentenceCat::Lower),
+    (0x2CB0, 0x2CB0, SentenceCat::Upper),
+    (0x2CB1, 0x2CB1, SentenceCat::Lower),
+    (0x2CB2, 0x2CB2, SentenceCat::Upper),
+    (0x2CB3, 0x2CB3, SentenceCat::Lower),
+    (0x2CB4, 0x2CB4, SentenceCat::Upper),
+    (0x2CB5, 0x2CB5, SentenceCat::Lower),
+    (0x2CB6, 0x2CB6, SentenceCat::Upper),
+    (0x2CB7, 0x2CB7, SentenceCat::Lower),
+    (0x2CB8, 0x2CB8, SentenceCat::Upper),
+    (0x2CB9, 0x2CB9, SentenceCat::Lower),
+    (0x2CBA, 0x2CBA, SentenceCat::Upper),
+    (0x2CBB, 0x2CBB, SentenceCat::Lower),
+    (0x2CBC, 0x2CBC, SentenceCat::Upper),
+    (0x2CBD, 0x2CBD, SentenceCat::Lower),
+    (0x2CBE, 0x2CBE, SentenceCat::Upper),
+    (0x2CBF, 0x2CBF, SentenceCat::Lower),
+    (0x2CC0, 0x2CC0, SentenceCat::Upper),
+    (0x2CC1, 0x2CC1, SentenceCat::Lower),
+    (0x2CC2, 0x2CC2, SentenceCat::Upper),
+    (0x2CC3, 0x2CC3, SentenceCat::Lower),
+    (0x2CC4, 0x2CC4, SentenceCat::Upper),
+    (0x2CC5, 0x2CC5, SentenceCat::Lower),
+    (0x2CC6, 0x2CC6, SentenceCat::Upper),
+    (0x2CC7, 0x2CC7, SentenceCat::Lower),
+    (0x2CC8, 0x2CC8, SentenceCat::Upper),
+    (0x2CC9, 0x2CC9, SentenceCat::Lower),
+    (0x2CCA, 0x2CCA, SentenceCat::Upper),
+    (0x2CCB, 0x2CCB, SentenceCat::Lower),
+    (0x2CCC, 0x2CCC, SentenceCat::Upper),
+    (0x2CCD, 0x2CCD, SentenceCat::Lower),
+    (0x2CCE, 0x2CCE, SentenceCat::Upper),
+    (0x2CCF, 0x2CCF, SentenceCat::Lower),
+    (0x2CD0, 0x2CD0, SentenceCat::Upper),
+    (0x2CD1, 0x2CD1, SentenceCat::Lower),
+    (0x2CD2, 0x2CD2, SentenceCat::Upper),
+    (0x2CD3, 0x2CD3, SentenceCat::Lower),
+    (0x2CD4, 0x2CD4, SentenceCat::Upper),
+    (0x2CD5, 0x2CD5, SentenceCat::Lower),
+    (0x2CD6, 0x2CD6, SentenceCat::Upper),
+    (0x2CD7, 0x2CD7, SentenceCat::Lower),
+    (0x2CD8, 0x2CD8, SentenceCat::Upper),
+    (0x2CD9, 0x2CD9, SentenceCat::Lower),
+    (0x2CDA, 0x2CDA, SentenceCat::Upper),
+    (0x2CDB, 0x2CDB, SentenceCat::Lower),
+    (0x2CDC, 0x2CDC, SentenceCat::Upper),
+    (0x2CDD, 0x2CDD, SentenceCat::Lower),
+    (0x2CDE, 0x2CDE, SentenceCat::Upper),
+    (0x2CDF, 0x2CDF, SentenceCat::Lower),
+    (0x2CE0, 0x2CE0, SentenceCat::Upper),
+    (0x2CE1, 0x2CE1, SentenceCat::Lower),
+    (0x2CE2, 0x2CE2, SentenceCat::Upper),
+    (0x2CE3, 0x2CE4, SentenceCat::Lower),
+    (0x2CEB, 0x2CEB, SentenceCat::Upper),
+    (0x2CEC, 0x2CEC, SentenceCat::Lower),
+    (0x2CED, 0x2CED, SentenceCat::Upper),
+    (0x2CEE, 0x2CEE, SentenceCat::Lower),
+    (0x2CEF, 0x2CF1, SentenceCat::Extend),
+    (0x2CF2, 0x2CF2, SentenceCat::Upper),
+    (0x2CF3, 0x2CF3, SentenceCat::Lower),
+    (0x2D00, 0x2D25, SentenceCat::Lower),
+    (0x2D27, 0x2D27, SentenceCat::Lower),
+    (0x2D2D, 0x2D2D, SentenceCat::Lower),
+    (0x2D30, 0x2D67, SentenceCat::OLetter),
+    (0x2D6F, 0x2D6F, SentenceCat::OLetter),
+    (0x2D7F, 0x2D7F, SentenceCat::Extend),
+    (0x2D80, 0x2D96, SentenceCat::OLetter),
+    (0x2DA0, 0x2DA6, SentenceCat::OLetter),
+    (0x2DA8, 0x2DAE, SentenceCat::OLetter),
+    (0x2DB0, 0x2DB6, SentenceCat::OLetter),
+    (0x2DB8, 0x2DBE, SentenceCat::OLetter),
+    (0x2DC0, 0x2DC6, SentenceCat::OLetter),
+    (0x2DC8, 0x2DCE, SentenceCat::OLetter),
+    (0x2DD0, 0x2DD6, SentenceCat::OLetter),
+    (0x2DD8, 0x2DDE, SentenceCat::OLetter),
+    (0x2DE0, 0x2DFF, SentenceCat::Extend),
+    (0x2E00, 0x2E0D, SentenceCat::Close),
+    (0x2E1C, 0x2E1D, SentenceCat::Close),
+    (0x2E20, 0x2E29, SentenceCat::Close),
+    (0x2E2E, 0x2E2E, SentenceCat::STerm),
+    (0x2E2F, 0x2E2F, SentenceCat::OLetter),
+    (0x2E3C, 0x2E3C, SentenceCat::STerm),
+    (0x2E42, 0x2E42, SentenceCat::Close),
+    (0x2E53, 0x2E54, SentenceCat::STerm),
+    (0x2E55, 0x2E5C, SentenceCat::Close),
+    (0x3000, 0x3000, SentenceCat::Sp),
+    (0x3001, 0x3001, SentenceCat::SContinue),
+    (0x3002, 0x3002, SentenceCat::STerm),
+    (0x3005, 0x3007, SentenceCat::OLetter),
+    (0x3008, 0x3011, SentenceCat::Close),
+    (0x3014, 0x301B, SentenceCat::Close),
+    (0x301D, 0x301F, SentenceCat::Close),
+    (0x3021, 0x3029, SentenceCat::OLetter),
+    (0x302A, 0x302F, SentenceCat::Extend),
+    (0x3031, 0x3035, SentenceCat::OLetter),
+    (0x3038, 0x303C, SentenceCat::OLetter),
+    (0x3041, 0x3096, SentenceCat::OLetter),
+    (0x3099, 0x309A, SentenceCat::Extend),
+    (0x309D, 0x309F, SentenceCat::OLetter),
+    (0x30A1, 0x30FA, SentenceCat::OLetter),
+    (0x30FC, 0x30FF, SentenceCat::OLetter),
+    (0x3105, 0x312F, SentenceCat::OLetter),
+    (0x3131, 0x318E, SentenceCat::OLetter),
+    (0x31A0, 0x31BF, SentenceCat::OLetter),
+    (0x31F0, 0x31FF, SentenceCat::OLetter),
+    (0x3400, 0x4DBF, SentenceCat::OLetter),
+    (0x4E00, 0xA48C, SentenceCat::OLetter),
+    (0xA4D0, 0xA4FD, SentenceCat::OLetter),
+    (0xA4FF, 0xA4FF, SentenceCat::STerm),
+    (0xA500, 0xA60C, SentenceCat::OLetter),
+    (0xA60E, 0xA60F, SentenceCat::STerm),
+    (0xA610, 0xA61F, SentenceCat::OLetter),
+    (0xA620, 0xA629, SentenceCat::Numeric),
+    (0xA62A, 0xA62B, SentenceCat::OLetter),
+    (0xA640, 0xA640, SentenceCat::Upper),
+    (0xA641, 0xA641, SentenceCat::Lower),
+    (0xA642, 0xA642, SentenceCat::Upper),
+    (0xA643, 0xA643, SentenceCat::Lower),
+    (0xA644, 0xA644, SentenceCat::Upper),
+    (0xA645, 0xA645, SentenceCat::Lower),
+    (0xA646, 0xA646, SentenceCat::Upper),
+    (0xA647, 0xA647, SentenceCat::Lower),
+    (0xA648, 0xA648, SentenceCat::Upper),
+    (0xA649, 0xA649, SentenceCat::Lower),
+    (0xA64A, 0xA64A, SentenceCat::Upper),
+    (0xA64B, 0xA64B, SentenceCat::Lower),
+    (0xA64C, 0xA64C, SentenceCat::Upper),
+    (0xA64D, 0xA64D, SentenceCat::Lower),
+    (0xA64E, 0xA64E, SentenceCat::Upper),
+    (0xA64F, 0xA64F, SentenceCat::Lower),
+    (0xA650, 0xA650, SentenceCat::Upper),
+    (0xA651, 0xA651, SentenceCat::Lower),
+    (0xA652, 0xA652, SentenceCat::Upper),
+    (0xA653, 0xA653, SentenceCat::Lower),
+    (0xA654, 0xA654, SentenceCat::Upper),
+    (0xA655, 0xA655, SentenceCat::Lower),
+    (0xA656, 0xA656, SentenceCat::Upper),
+    (0xA657, 0xA657, SentenceCat::Lower),
+    (0xA658, 0xA658, SentenceCat::Upper),
+    (0xA659, 0xA659, SentenceCat::Lower),
+    (0xA65A, 0xA65A, SentenceCat::Upper),
+    (0xA65B, 0xA65B, SentenceCat::Lower),
+    (0xA65C, 0xA65C, SentenceCat::Upper),
+    (0xA65D, 0xA65D, SentenceCat::Lower),
+    (0xA65E, 0xA65E, SentenceCat::Upper),
+    (0xA65F, 0xA65F, SentenceCat::Lower),
+    (0xA660, 0xA660, SentenceCat::Upper),
+    (0xA661, 0xA661, SentenceCat::Lower),
+    (0xA662, 0xA662, SentenceCat::Upper),
+    (0xA663, 0xA663, SentenceCat::Lower),
+    (0xA664, 0xA664, SentenceCat::Upper),
+    (0xA665, 0xA665, SentenceCat::Lower),
+    (0xA666, 0xA666, SentenceCat::Upper),
+    (0xA667, 0xA667, SentenceCat::Lower),
+    (0xA668, 0xA668, SentenceCat::Upper),
+    (0xA669, 0xA669, SentenceCat::Lower),
+    (0xA66A, 0xA66A, SentenceCat::Upper),
+    (0xA66B, 0xA66B, SentenceCat::Lower),
+    (0xA66C, 0xA66C, SentenceCat::Upper),
+    (0xA66D, 0xA66D, SentenceCat::Lower),
+    (0xA66E, 0xA66E, SentenceCat::OLetter),
+    (0xA66F, 0xA672, SentenceCat::Extend),
+    (0xA674, 0xA67D, SentenceCat::Extend),
+    (0xA67F, 0xA67F, SentenceCat::OLetter),
+    (0xA680, 0xA680, SentenceCat::Upper),
+    (0xA681, 0xA681, SentenceCat::Lower),
+    (0xA682, 0xA682, SentenceCat::Upper),
+    (0xA683, 0xA683, SentenceCat::Lower),
+    (0xA684, 0xA684, SentenceCat::Upper),
+    (0xA685, 0xA685, SentenceCat::Lower),
+    (0xA686, 0xA686, SentenceCat::Upper),
+    (0xA687, 0xA687, SentenceCat::Lower),
+    (0xA688, 0xA688, SentenceCat::Upper),
+    (0xA689, 0xA689, SentenceCat::Lower),
+    (0xA68A, 0xA68A, SentenceCat::Upper),
+    (0xA68B, 0xA68B, SentenceCat::Lower),
+    (0xA68C, 0xA68C, SentenceCat::Upper),
+    (0xA68D, 0xA68D, SentenceCat::Lower),
+    (0xA68E, 0xA68E, SentenceCat::Upper),
+    (0xA68F, 0xA68F, SentenceCat::Lower),
+    (0xA690, 0xA690, SentenceCat::Upper),
+    (0xA691, 0xA691, SentenceCat::Lower),
+    (0xA692, 0xA692, SentenceCat::Upper),
+    (0xA693, 0xA693, SentenceCat::Lower),
+    (0xA694, 0xA694, SentenceCat::Upper),
+    (0xA695, 0xA695, SentenceCat::Lower),
+    (0xA696, 0xA696, SentenceCat::Upper),
+    (0xA697, 0xA697, SentenceCat::Lower),
+    (0xA698, 0xA698, SentenceCat::Upper),
+    (0xA699, 0xA699, SentenceCat::Lower),
+    (0xA69A, 0xA69A, SentenceCat::Upper),
+    (0xA69B, 0xA69D, SentenceCat::Lower),
+    (0xA69E, 0xA69F, SentenceCat::Extend),
+    (0xA6A0, 0xA6EF, SentenceCat::OLetter),
+    (0xA6F0, 0xA6F1, SentenceCat::Extend),
+    (0xA6F3, 0xA6F3, SentenceCat::STerm),
+    (0xA6F7, 0xA6F7, SentenceCat::STerm),
+    (0xA717, 0xA71F, SentenceCat::OLetter),
+    (0xA722, 0xA722, SentenceCat::Upper),
+    (0xA723, 0xA723, SentenceCat::Lower),
+    (0xA724, 0xA724, SentenceCat::Upper),
+    (0xA725, 0xA725, SentenceCat::Lower),
+    (0xA726, 0xA726, SentenceCat::Upper),
+    (0xA727, 0xA727, SentenceCat::Lower),
+    (0xA728, 0xA728, SentenceCat::Upper),
+    (0xA729, 0xA729, SentenceCat::Lower),
+    (0xA72A, 0xA72A, SentenceCat::Upper),
+    (0xA72B, 0xA72B, SentenceCat::Lower),
+    (0xA72C, 0xA72C, SentenceCat::Upper),
+    (0xA72D, 0xA72D, SentenceCat::Lower),
+    (0xA72E, 0xA72E, SentenceCat::Upper),
+    (0xA72F, 0xA731, SentenceCat::Lower),
+    (0xA732, 0xA732, SentenceCat::Upper),
+    (0xA733, 0xA733, SentenceCat::Lower),
+    (0xA734, 0xA734, SentenceCat::Upper),
+    (0xA735, 0xA735, SentenceCat::Lower),
+    (0xA736, 0xA736, SentenceCat::Upper),
+    (0xA737, 0xA737, SentenceCat::Lower),
+    (0xA738, 0xA738, SentenceCat::Upper),
+    (0xA739, 0xA739, SentenceCat::Lower),
+    (0xA73A, 0xA73A, SentenceCat::Upper),
+    (0xA73B, 0xA73B, SentenceCat::Lower),
+    (0xA73C, 0xA73C, SentenceCat::Upper),
+    (0xA73D, 0xA73D, SentenceCat::Lower),
+    (0xA73E, 0xA73E, SentenceCat::Upper),
+    (0xA73F, 0xA73F, SentenceCat::Lower),
+    (0xA740, 0xA740, SentenceCat::Upper),
+    (0xA741, 0xA741, SentenceCat::Lower),
+    (0xA742, 0xA742, SentenceCat::Upper),
+    (0xA743, 0xA743, SentenceCat::Lower),
+    (0xA744, 0xA744, SentenceCat::Upper),
+    (0xA745, 0xA745, SentenceCat::Lower),
+    (0xA746, 0xA746, SentenceCat::Upper),
+    (0xA747, 0xA747, SentenceCat::Lower),
+    (0xA748, 0xA748, SentenceCat::Upper),
+    (0xA749, 0xA749, SentenceCat::Lower),
+    (0xA74A, 0xA74A, SentenceCat::Upper),
+    (0xA74B, 0xA74B, SentenceCat::Lower),
+    (0xA74C, 0xA74C, SentenceCat::Upper),
+    (0xA74D, 0xA74D, SentenceCat::Lower),
+    (0xA74E, 0xA74E, SentenceCat::Upper),
+    (0xA74F, 0xA74F, SentenceCat::Lower),
+    (0xA750, 0xA750, SentenceCat::Upper),
+    (0xA751, 0xA751, SentenceCat::Lower),
+    (0xA752, 0xA752, SentenceCat::Upper),
+    (0xA753, 0xA753, SentenceCat::Lower),
+    (0xA754, 0xA754, SentenceCat::Upper),
+    (0xA755, 0xA755, SentenceCat::Lower),
+    (0xA756, 0xA756, SentenceCat::Upper),
+    (0xA757, 0xA757, SentenceCat::Lower),
+    (0xA758, 0xA758, SentenceCat::Upper),
+    (0xA759, 0xA759, SentenceCat::Lower),
+    (0xA75A, 0xA75A, SentenceCat::Upper),
+    (0xA75B, 0xA75B, SentenceCat::Lower),
+    (0xA75C, 0xA75C, SentenceCat::Upper),
+    (0xA75D, 0xA75D, SentenceCat::Lower),
+    (0xA75E, 0xA75E, SentenceCat::Upper),
+    (0xA75F, 0xA75F, SentenceCat::Lower),
+    (0xA760, 0xA760, SentenceCat::Upper),
+    (0xA761, 0xA761, SentenceCat::Lower),
+    (0xA762, 0xA762, SentenceCat::Upper),
+    (0xA763, 0xA763, SentenceCat::Lower),
+    (0xA764, 0xA764, SentenceCat::Upper),
+    (0xA765, 0xA765, SentenceCat::Lower),
+    (0xA766, 0xA766, SentenceCat::Upper),
+    (0xA767, 0xA767, SentenceCat::Lower),
+    (0xA768, 0xA768, SentenceCat::Upper),
+    (0xA769, 0xA769, SentenceCat::Lower),
+    (0xA76A, 0xA76A, SentenceCat::Upper),
+    (0xA76B, 0xA76B, SentenceCat::Lower),
+    (0xA76C, 0xA76C, SentenceCat::Upper),
+    (0xA76D, 0xA76D, SentenceCat::Lower),
+    (0xA76E, 0xA76E, SentenceCat::Upper),
+    (0xA76F, 0xA778, SentenceCat::Lower),
+    (0xA779, 0xA779, SentenceCat::Upper),
+    (0xA77A, 0xA77A, SentenceCat::Lower),
+    (0xA77B, 0xA77B, SentenceCat::Upper),
+    (0xA77C, 0xA77C, SentenceCat::Lower),
+    (0xA77D, 0xA77E, SentenceCat::Upper),
+    (0xA77F, 0xA77F, SentenceCat::Lower),
+    (0xA780, 0xA780, SentenceCat::Upper),
+    (0xA781, 0xA781, SentenceCat::Lower),
+    (0xA782, 0xA782, SentenceCat::Upper),
+    (0xA783, 0xA783, SentenceCat::Lower),
+    (0xA784, 0xA784, SentenceCat::Upper),
+    (0xA785, 0xA785, SentenceCat::Lower),
+    (0xA786, 0xA786, SentenceCat::Upper),
+    (0xA787, 0xA787, SentenceCat::Lower),
+    (0xA788, 0xA788, SentenceCat::OLetter),
+    (0xA78B, 0xA78B, SentenceCat::Upper),
+    (0xA78C, 0xA78C, SentenceCat::Lower),
+    (0xA78D, 0xA78D, SentenceCat::Upper),
+    (0xA78E, 0xA78E, SentenceCat::Lower),
+    (0xA78F, 0xA78F, SentenceCat::OLetter),
+    (0xA790, 0xA790, SentenceCat::Upper),
+    (0xA791, 0xA791, SentenceCat::Lower),
+    (0xA792, 0xA792, SentenceCat::Upper),
+    (0xA793, 0xA795, SentenceCat::Lower),
+    (0xA796, 0xA796, SentenceCat::Upper),
+    (0xA797, 0xA797, SentenceCat::Lower),
+    (0xA798, 0xA798, SentenceCat::Upper),
+    (0xA799, 0xA799, SentenceCat::Lower),
+    (0xA79A, 0xA79A, SentenceCat::Upper),
+    (0xA79B, 0xA79B, SentenceCat::Lower),
+    (0xA79C, 0xA79C, SentenceCat::Upper),
+    (0xA79D, 0xA79D, SentenceCat::Lower),
+    (0xA79E, 0xA79E, SentenceCat::Upper),
+    (0xA79F, 0xA79F, SentenceCat::Lower),
+    (0xA7A0, 0xA7A0, SentenceCat::Upper),
+    (0xA7A1, 0xA7A1, SentenceCat::Lower),
+    (0xA7A2, 0xA7A2, SentenceCat::Upper),
+    (0xA7A3, 0xA7A3, SentenceCat::Lower),
+    (0xA7A4, 0xA7A4, SentenceCat::Upper),
+    (0xA7A5, 0xA7A5, SentenceCat::Lower),
+    (0xA7A6, 0xA7A6, SentenceCat::Upper),
+    (0xA7A7, 0xA7A7, SentenceCat::Lower),
+    (0xA7A8, 0xA7A8, SentenceCat::Upper),
+    (0xA7A9, 0xA7A9, SentenceCat::Lower),
+    (0xA7AA, 0xA7AE, SentenceCat::Upper),
+    (0xA7AF, 0xA7AF, SentenceCat::Lower),
+    (0xA7B0, 0xA7B4, SentenceCat::Upper),
+    (0xA7B5, 0xA7B5, SentenceCat::Lower),
+    (0xA7B6, 0xA7B6, SentenceCat::Upper),
+    (0xA7B7, 0xA7B7, SentenceCat::Lower),
+    (0xA7B8, 0xA7B8, SentenceCat::Upper),
+    (0xA7B9, 0xA7B9, SentenceCat::Lower),
+    (0xA7BA, 0xA7BA, SentenceCat::Upper),
+    (0xA7BB, 0xA7BB, SentenceCat::Lower),
+    (0xA7BC, 0xA7BC, SentenceCat::Upper),
+    (0xA7BD, 0xA7BD, SentenceCat::Lower),
+    (0xA7BE, 0xA7BE, SentenceCat::Upper),
+    (0xA7BF, 0xA7BF, SentenceCat::Lower),
+    (0xA7C0, 0xA7C0, SentenceCat::Upper),
+    (0xA7C1, 0xA7C1, SentenceCat::Lower),
+    (0xA7C2, 0xA7C2, SentenceCat::Upper),
+    (0xA7C3, 0xA7C3, SentenceCat::Lower),
+    (0xA7C4, 0xA7C7, SentenceCat::Upper),
+    (0xA7C8, 0xA7C8, SentenceCat::Lower),
+    (0xA7C9, 0xA7C9, SentenceCat::Upper),
+    (0xA7CA, 0xA7CA, SentenceCat::Lower),
+    (0xA7D0, 0xA7D0, SentenceCat::Upper),
+    (0xA7D1, 0xA7D1, SentenceCat::Lower),
+    (0xA7D3, 0xA7D3, SentenceCat::Lower),
+    (0xA7D5, 0xA7D5, SentenceCat::Lower),
+    (0xA7D6, 0xA7D6, SentenceCat::Upper),
+    (0xA7D7, 0xA7D7, SentenceCat::Lower),
+    (0xA7D8, 0xA7D8, SentenceCat::Upper),
+    (0xA7D9, 0xA7D9, SentenceCat::Lower),
+    (0xA7F2, 0xA7F4, SentenceCat::OLetter),
+    (0xA7F5, 0xA7F5, SentenceCat::Upper),
+    (0xA7F6, 0xA7F6, SentenceCat::Lower),
+    (0xA7F7, 0xA7F7, SentenceCat::OLetter),
+    (0xA7F8, 0xA7FA, SentenceCat::Lower),
+    (0xA7FB, 0xA801, SentenceCat::OLetter),
+    (0xA802, 0xA802, SentenceCat::Extend),
+    (0xA803, 0xA805, SentenceCat::OLetter),
+    (0xA806, 0xA806, SentenceCat::Extend),
+    (0xA807, 0xA80A, SentenceCat::OLetter),
+    (0xA80B, 0xA80B, SentenceCat::Extend),
+    (0xA80C, 0xA822, SentenceCat::OLetter),
+    (0xA823, 0xA827, SentenceCat::Extend),
+    (0xA82C, 0xA82C, SentenceCat::Extend),
+    (0xA840, 0xA873, SentenceCat::OLetter),
+    (0xA876, 0xA877, SentenceCat::STerm),
+    (0xA880, 0xA881, SentenceCat::Extend),
+    (0xA882, 0xA8B3, SentenceCat::OLetter),
+    (0xA8B4, 0xA8C5, SentenceCat::Extend),
+    (0xA8CE, 0xA8CF, SentenceCat::STerm),
+    (0xA8D0, 0xA8D9, SentenceCat::Numeric),
+    (0xA8E0, 0xA8F1, SentenceCat::Extend),
+    (0xA8F2, 0xA8F7, SentenceCat::OLetter),
+    (0xA8FB, 0xA8FB, SentenceCat::OLetter),
+    (0xA8FD, 0xA8FE, SentenceCat::OLetter),
+    (0xA8FF, 0xA8FF, SentenceCat::Extend),
+    (0xA900, 0xA909, SentenceCat::Numeric),
+    (0xA90A, 0xA925, SentenceCat::OLetter),
+    (0xA926, 0xA92D, SentenceCat::Extend),
+    (0xA92F, 0xA92F, SentenceCat::STerm),
+    (0xA930, 0xA946, SentenceCat::OLetter),
+    (0xA947, 0xA953, SentenceCat::Extend),
+    (0xA960, 0xA97C, SentenceCat::OLetter),
+    (0xA980, 0xA983, SentenceCat::Extend),
+    (0xA984, 0xA9B2, SentenceCat::OLetter),
+    (0xA9B3, 0xA9C0, SentenceCat::Extend),
+    (0xA9C8, 0xA9C9, SentenceCat::STerm),
+    (0xA9CF, 0xA9CF, SentenceCat::OLetter),
+    (0xA9D0, 0xA9D9, SentenceCat::Numeric),
+    (0xA9E0, 0xA9E4, SentenceCat::OLetter),
+    (0xA9E5, 0xA9E5, SentenceCat::Extend),
+    (0xA9E6, 0xA9EF, SentenceCat::OLetter),
+    (0xA9F0, 0xA9F9, SentenceCat::Numeric),
+    (0xA9FA, 0xA9FE, SentenceCat::OLetter),
+    (0xAA00, 0xAA28, SentenceCat::OLetter),
+    (0xAA29, 0xAA36, SentenceCat::Extend),
+    (0xAA40, 0xAA42, SentenceCat::OLetter),
+    (0xAA43, 0xAA43, SentenceCat::Extend),
+    (0xAA44, 0xAA4B, SentenceCat::OLetter),
+    (0xAA4C, 0xAA4D, SentenceCat::Extend),
+    (0xAA50, 0xAA59, SentenceCat::Numeric),
+    (0xAA5D, 0xAA5F, SentenceCat::STerm),
+    (0xAA60, 0xAA76, SentenceCat::OLetter),
+    (0xAA7A, 0xAA7A, SentenceCat::OLetter),
+    (0xAA7B, 0xAA7D, SentenceCat::Extend),
+    (0xAA7E, 0xAAAF, SentenceCat::OLetter),
+    (0xAAB0, 0xAAB0, SentenceCat::Extend),
+    (0xAAB1, 0xAAB1, SentenceCat::OLetter),
+    (0xAAB2, 0xAAB4, SentenceCat::Extend),
+    (0xAAB5, 0xAAB6, SentenceCat::OLetter),
+    (0xAAB7, 0xAAB8, SentenceCat::Extend),
+    (0xAAB9, 0xAABD, SentenceCat::OLetter),
+    (0xAABE, 0xAABF, SentenceCat::Extend),
+    (0xAAC0, 0xAAC0, SentenceCat::OLetter),
+    (0xAAC1, 0xAAC1, SentenceCat::Extend),
+    (0xAAC2, 0xAAC2, SentenceCat::OLetter),
+    (0xAADB, 0xAADD, SentenceCat::OLetter),
+    (0xAAE0, 0xAAEA, SentenceCat::OLetter),
+    (0xAAEB, 0xAAEF, SentenceCat::Extend),
+    (0xAAF0, 0xAAF1, SentenceCat::STerm),
+    (0xAAF2, 0xAAF4, SentenceCat::OLetter),
+    (0xAAF5, 0xAAF6, SentenceCat::Extend),
+    (0xAB01, 0xAB06, SentenceCat::OLetter),
+    (0xAB09, 0xAB0E, SentenceCat::OLetter),
+    (0xAB11, 0xAB16, SentenceCat::OLetter),
+    (0xAB20, 0xAB26, SentenceCat::OLetter),
+    (0xAB28, 0xAB2E, SentenceCat::OLetter),
+    (0xAB30, 0xAB5A, SentenceCat::Lower),
+    (0xAB5C, 0xAB68, SentenceCat::Lower),
+    (0xAB69, 0xAB69, SentenceCat::OLetter),
+    (0xAB70, 0xABBF, SentenceCat::Lower),
+    (0xABC0, 0xABE2, SentenceCat::OLetter),
+    (0xABE3, 0xABEA, SentenceCat::Extend),
+    (0xABEB, 0xABEB, SentenceCat::STerm),
+    (0xABEC, 0xABED, SentenceCat::Extend),
+    (0xABF0, 0xABF9, SentenceCat::Numeric),
+    (0xAC00, 0xD7A3, SentenceCat::OLetter),
+    (0xD7B0, 0xD7C6, SentenceCat::OLetter),
+    (0xD7CB, 0xD7FB, SentenceCat::OLetter),
+    (0xF900, 0xFA6D, SentenceCat::OLetter),
+    (0xFA70, 0xFAD9, SentenceCat::OLetter),
+    (0xFB00, 0xFB06, SentenceCat::Lower),
+    (0xFB13, 0xFB17, SentenceCat::Lower),
+    (0xFB1D, 0xFB1D, SentenceCat::OLetter),
+    (0xFB1E, 0xFB1E, SentenceCat::Extend),
+    (0xFB1F, 0xFB28, SentenceCat::OLetter),
+    (0xFB2A, 0xFB36, SentenceCat::OLetter),
+    (0xFB38, 0xFB3C, SentenceCat::OLetter),
+    (0xFB3E, 0xFB3E, SentenceCat::OLetter),
+    (0xFB40, 0xFB41, SentenceCat::OLetter),
+    (0xFB43, 0xFB44, SentenceCat::OLetter),
+    (0xFB46, 0xFBB1, SentenceCat::OLetter),
+    (0xFBD3, 0xFD3D, SentenceCat::OLetter),
+    (0xFD3E, 0xFD3F, SentenceCat::Close),
+    (0xFD50, 0xFD8F, SentenceCat::OLetter),
+    (0xFD92, 0xFDC7, SentenceCat::OLetter),
+    (0xFDF0, 0xFDFB, SentenceCat::OLetter),
+    (0xFE00, 0xFE0F, SentenceCat::Extend),
+    (0xFE10, 0xFE11, SentenceCat::SContinue),
+    (0xFE13, 0xFE13, SentenceCat::SContinue),
+    (0xFE17, 0xFE18, SentenceCat::Close),
+    (0xFE20, 0xFE2F, SentenceCat::Extend),
+    (0xFE31, 0xFE32, SentenceCat::SContinue),
+    (0xFE35, 0xFE44, SentenceCat::Close),
+    (0xFE47, 0xFE48, SentenceCat::Close),
+    (0xFE50, 0xFE51, SentenceCat::SContinue),
+    (0xFE52, 0xFE52, SentenceCat::ATerm),
+    (0xFE55, 0xFE55, SentenceCat::SContinue),
+    (0xFE56, 0xFE57, SentenceCat::STerm),
+    (0xFE58, 0xFE58, SentenceCat::SContinue),
+    (0xFE59, 0xFE5E, SentenceCat::Close),
+    (0xFE63, 0xFE63, SentenceCat::SContinue),
+    (0xFE70, 0xFE74, SentenceCat::OLetter),
+    (0xFE76, 0xFEFC, SentenceCat::OLetter),
+    (0xFEFF, 0xFEFF, SentenceCat::Format),
+    (0xFF01, 0xFF01, SentenceCat::STerm),
+    (0xFF08, 0xFF09, SentenceCat::Close),
+    (0xFF0C, 0xFF0D, SentenceCat::SContinue),
+    (0xFF0E, 0xFF0E, SentenceCat::ATerm),
+    (0xFF10, 0xFF19, SentenceCat::Numeric),
+    (0xFF1A, 0xFF1A, SentenceCat::SContinue),
+    (0xFF1F, 0xFF1F, SentenceCat::STerm),
+    (0xFF21, 0xFF3A, SentenceCat::Upper),
+    (0xFF3B, 0xFF3B, SentenceCat::Close),
+    (0xFF3D, 0xFF3D, SentenceCat::Close),
+    (0xFF41, 0xFF5A, SentenceCat::Lower),
+    (0xFF5B, 0xFF5B, SentenceCat::Close),
+    (0xFF5D, 0xFF5D, SentenceCat::Close),
+    (0xFF5F, 0xFF60, SentenceCat::Close),
+    (0xFF61, 0xFF61, SentenceCat::STerm),
+    (0xFF62, 0xFF63, SentenceCat::Close),
+    (0xFF64, 0xFF64, SentenceCat::SContinue),
+    (0xFF66, 0xFF9D, SentenceCat::OLetter),
+    (0xFF9E, 0xFF9F, SentenceCat::Extend),
+    (0xFFA0, 0xFFBE, SentenceCat::OLetter),
+    (0xFFC2, 0xFFC7, SentenceCat::OLetter),
+    (0xFFCA, 0xFFCF, SentenceCat::OLetter),
+    (0xFFD2, 0xFFD7, SentenceCat::OLetter),
+    (0xFFDA, 0xFFDC, SentenceCat::OLetter),
+    (0xFFF9, 0xFFFB, SentenceCat::Format),
+    (0x10000, 0x1000B, SentenceCat::OLetter),
+    (0x1000D, 0x10026, SentenceCat::OLetter),
+    (0x10028, 0x1003A, SentenceCat::OLetter),
+    (0x1003C, 0x1003D, SentenceCat::OLetter),
+    (0x1003F, 0x1004D, SentenceCat::OLetter),
+    (0x10050, 0x1005D, SentenceCat::OLetter),
+    (0x10080, 0x100FA, SentenceCat::OLetter),
+    (0x10140, 0x10174, SentenceCat::OLetter),
+    (0x101FD, 0x101FD, SentenceCat::Extend),
+    (0x10280, 0x1029C, SentenceCat::OLetter),
+    (0x102A0, 0x102D0, SentenceCat::OLetter),
+    (0x102E0, 0x102E0, SentenceCat::Extend),
+    (0x10300, 0x1031F, SentenceCat::OLetter),
+    (0x1032D, 0x1034A, SentenceCat::OLetter),
+    (0x10350, 0x10375, SentenceCat::OLetter),
+    (0x10376, 0x1037A, SentenceCat::Extend),
+    (0x10380, 0x1039D, SentenceCat::OLetter),
+    (0x103A0, 0x103C3, SentenceCat::OLetter),
+    (0x103C8, 0x103CF, SentenceCat::OLetter),
+    (0x103D1, 0x103D5, SentenceCat::OLetter),
+    (0x10400, 0x10427, SentenceCat::Upper),
+    (0x10428, 0x1044F, SentenceCat::Lower),
+    (0x10450, 0x1049D, SentenceCat::OLetter),
+    (0x104A0, 0x104A9, SentenceCat::Numeric),
+    (0x104B0, 0x104D3, SentenceCat::Upper),
+    (0x104D8, 0x104FB, SentenceCat::Lower),
+    (0x10500, 0x10527, SentenceCat::OLetter),
+    (0x10530, 0x10563, SentenceCat::OLetter),
+    (0x10570, 0x1057A, SentenceCat::Upper),
+    (0x1057C, 0x1058A, SentenceCat::Upper),
+    (0x1058C, 0x10592, SentenceCat::Upper),
+    (0x10594, 0x10595, SentenceCat::Upper),
+    (0x10597, 0x105A1, SentenceCat::Lower),
+    (0x105A3, 0x105B1, SentenceCat::Lower),
+    (0x105B3, 0x105B9, SentenceCat::Lower),
+    (0x105BB, 0x105BC, SentenceCat::Lower),
+    (0x10600, 0x10736, SentenceCat::OLetter),
+    (0x10740, 0x10755, SentenceCat::OLetter),
+    (0x10760, 0x10767, SentenceCat::OLetter),
+    (0x10780, 0x10780, SentenceCat::Lower),
+    (0x10781, 0x10782, SentenceCat::OLetter),
+    (0x10783, 0x10785, SentenceCat::Lower),
+    (0x10787, 0x107B0, SentenceCat::Lower),
+    (0x107B2, 0x107BA, SentenceCat::Lower),
+    (0x10800, 0x10805, SentenceCat::OLetter),
+    (0x10808, 0x10808, SentenceCat::OLetter),
+    (0x1080A, 0x10835, SentenceCat::OLetter),
+    (0x10837, 0x10838, SentenceCat::OLetter),
+    (0x1083C, 0x1083C, SentenceCat::OLetter),
+    (0x1083F, 0x10855, SentenceCat::OLetter),
+    (0x10860, 0x10876, SentenceCat::OLetter),
+    (0x10880, 0x1089E, SentenceCat::OLetter),
+    (0x108E0, 0x108F2, SentenceCat::OLetter),
+    (0x108F4, 0x108F5, SentenceCat::OLetter),
+    (0x10900, 0x10915, SentenceCat::OLetter),
+    (0x10920, 0x10939, SentenceCat::OLetter),
+    (0x10980, 0x109B7, SentenceCat::OLetter),
+    (0x109BE, 0x109BF, SentenceCat::OLetter),
+    (0x10A00, 0x10A00, SentenceCat::OLetter),
+    (0x10A01, 0x10A03, SentenceCat::Extend),
+    (0x10A05, 0x10A06, SentenceCat::Extend),
+    (0x10A0C, 0x10A0F, SentenceCat::Extend),
+    (0x10A10, 0x10A13, SentenceCat::OLetter),
+    (0x10A15, 0x10A17, SentenceCat::OLetter),
+    (0x10A19, 0x10A35, SentenceCat::OLetter),
+    (0x10A38, 0x10A3A, SentenceCat::Extend),
+    (0x10A3F, 0x10A3F, SentenceCat::Extend),
+    (0x10A56, 0x10A57, SentenceCat::STerm),
+    (0x10A60, 0x10A7C, SentenceCat::OLetter),
+    (0x10A80, 0x10A9C, SentenceCat::OLetter),
+    (0x10AC0, 0x10AC7, SentenceCat::OLetter),
+    (0x10AC9, 0x10AE4, SentenceCat::OLetter),
+    (0x10AE5, 0x10AE6, SentenceCat::Extend),
+    (0x10B00, 0x10B35, SentenceCat::OLetter),
+    (0x10B40, 0x10B55, SentenceCat::OLetter),
+    (0x10B60, 0x10B72, SentenceCat::OLetter),
+    (0x10B80, 0x10B91, SentenceCat::OLetter),
+    (0x10C00, 0x10C48, SentenceCat::OLetter),
+    (0x10C80, 0x10CB2, SentenceCat::Upper),
+    (0x10CC0, 0x10CF2, SentenceCat::Lower),
+    (0x10D00, 0x10D23, SentenceCat::OLetter),
+    (0x10D24, 0x10D27, SentenceCat::Extend),
+    (0x10D30, 0x10D39, SentenceCat::Numeric),
+    (0x10E80, 0x10EA9, SentenceCat::OLetter),
+    (0x10EAB, 0x10EAC, SentenceCat::Extend),
+    (0x10EB0, 0x10EB1, SentenceCat::OLetter),
+    (0x10F00, 0x10F1C, SentenceCat::OLetter),
+    (0x10F27, 0x10F27, SentenceCat::OLetter),
+    (0x10F30, 0x10F45, SentenceCat::OLetter),
+    (0x10F46, 0x10F50, SentenceCat::Extend),
+    (0x10F55, 0x10F59, SentenceCat::STerm),
+    (0x10F70, 0x10F81, SentenceCat::OLetter),
+    (0x10F82, 0x10F85, SentenceCat::Extend),
+    (0x10F86, 0x10F89, SentenceCat::STerm),
+    (0x10FB0, 0x10FC4, SentenceCat::OLetter),
+    (0x10FE0, 0x10FF6, SentenceCat::OLetter),
+    (0x11000, 0x11002, SentenceCat::Extend),
+    (0x11003, 0x11037, SentenceCat::OLetter),
+    (0x11038, 0x11046, SentenceCat::Extend),
+    (0x11047, 0x11048, SentenceCat::STerm),
+    (0x11066, 0x1106F, SentenceCat::Numeric),
+    (0x11070, 0x11070, SentenceCat::Extend),
+    (0x11071, 0x11072, SentenceCat::OLetter),
+    (0x11073, 0x11074, SentenceCat::Extend),
+    (0x11075, 0x11075, SentenceCat::OLetter),
+    (0x1107F, 0x11082, SentenceCat::Extend),
+    (0x11083, 0x110AF, SentenceCat::OLetter),
+    (0x110B0, 0x110BA, SentenceCat::Extend),
+    (0x110BD, 0x110BD, SentenceCat::Format),
+    (0x110BE, 0x110C1, SentenceCat::STerm),
+    (0x110C2, 0x110C2, SentenceCat::Extend),
+    (0x110CD, 0x110CD, SentenceCat::Format),
+    (0x110D0, 0x110E8, SentenceCat::OLetter),
+    (0x110F0, 0x110F9, SentenceCat::Numeric),
+    (0x11100, 0x11102, SentenceCat::Extend),
+    (0x11103, 0x11126, SentenceCat::OLetter),
+    (0x11127, 0x11134, SentenceCat::Extend),
+    (0x11136, 0x1113F, SentenceCat::Numeric),
+    (0x11141, 0x11143, SentenceCat::STerm),
+    (0x11144, 0x11144, SentenceCat::OLetter),
+    (0x11145, 0x11146, SentenceCat::Extend),
+    (0x11147, 0x11147, SentenceCat::OLetter),
+    (0x11150, 0x11172, SentenceCat::OLetter),
+    (0x11173, 0x11173, SentenceCat::Extend),
+    (0x11176, 0x11176, SentenceCat::OLetter),
+    (0x11180, 0x11182, SentenceCat::Extend),
+    (0x11183, 0x111B2, SentenceCat::OLetter),
+    (0x111B3, 0x111C0, SentenceCat::Extend),
+    (0x111C1, 0x111C4, SentenceCat::OLetter),
+    (0x111C5, 0x111C6, SentenceCat::STerm),
+    (0x111C9, 0x111CC, SentenceCat::Extend),
+    (0x111CD, 0x111CD, SentenceCat::STerm),
+    (0x111CE, 0x111CF, SentenceCat::Extend),
+    (0x111D0, 0x111D9, SentenceCat::Numeric),
+    (0x111DA, 0x111DA, SentenceCat::OLetter),
+    (0x111DC, 0x111DC, SentenceCat::OLetter),
+    (0x111DE, 0x111DF, SentenceCat::STerm),
+    (0x11200, 0x11211, SentenceCat::OLetter),
+    (0x11213, 0x1122B, SentenceCat::OLetter),
+    (0x1122C, 0x11237, SentenceCat::Extend),
+    (0x11238, 0x11239, SentenceCat::STerm),
+    (0x1123B, 0x1123C, SentenceCat::STerm),
+    (0x1123E, 0x1123E, SentenceCat::Extend),
+    (0x11280, 0x11286, SentenceCat::OLetter),
+    (0x11288, 0x11288, SentenceCat::OLetter),
+    (0x1128A, 0x1128D, SentenceCat::OLetter),
+    (0x1128F, 0x1129D, SentenceCat::OLetter),
+    (0x1129F, 0x112A8, SentenceCat::OLetter),
+    (0x112A9, 0x112A9, SentenceCat::STerm),
+    (0x112B0, 0x112DE, SentenceCat::OLetter),
+    (0x112DF, 0x112EA, SentenceCat::Extend),
+    (0x112F0, 0x112F9, SentenceCat::Numeric),
+    (0x11300, 0x11303, SentenceCat::Extend),
+    (0x11305, 0x1130C, SentenceCat::OLetter),
+    (0x1130F, 0x11310, SentenceCat::OLetter),
+    (0x11313, 0x11328, SentenceCat::OLetter),
+    (0x1132A, 0x11330, SentenceCat::OLetter),
+    (0x11332, 0x11333, SentenceCat::OLetter),
+    (0x11335, 0x11339, SentenceCat::OLetter),
+    (0x1133B, 0x1133C, SentenceCat::Extend),
+    (0x1133D, 0x1133D, SentenceCat::OLetter),
+    (0x1133E, 0x11344, SentenceCat::Extend),
+    (0x11347, 0x11348, SentenceCat::Extend),
+    (0x1134B, 0x1134D, SentenceCat::Extend),
+    (0x11350, 0x11350, SentenceCat::OLetter),
+    (0x11357, 0x11357, SentenceCat::Extend),
+    (0x1135D, 0x11361, SentenceCat::OLetter),
+    (0x11362, 0x11363, SentenceCat::Extend),
+    (0x11366, 0x1136C, SentenceCat::Extend),
+    (0x11370, 0x11374, SentenceCat::Extend),
+    (0x11400, 0x11434, SentenceCat::OLetter),
+    (0x11435, 0x11446, SentenceCat::Extend),
+    (0x11447, 0x1144A, SentenceCat::OLetter),
+    (0x1144B, 0x1144C, SentenceCat::STerm),
+    (0x11450, 0x11459, SentenceCat::Numeric),
+    (0x1145E, 0x1145E, SentenceCat::Extend),
+    (0x1145F, 0x11461, SentenceCat::OLetter),
+    (0x11480, 0x114AF, SentenceCat::OLetter),
+    (0x114B0, 0x114C3, SentenceCat::Extend),
+    (0x114C4, 0x114C5, SentenceCat::OLetter),
+    (0x114C7, 0x114C7, SentenceCat::OLetter),
+    (0x114D0, 0x114D9, SentenceCat::Numeric),
+    (0x11580, 0x115AE, SentenceCat::OLetter),
+    (0x115AF, 0x115B5, SentenceCat::Extend),
+    (0x115B8, 0x115C0, SentenceCat::Extend),
+    (0x115C2, 0x115C3, SentenceCat::STerm),
+    (0x115C9, 0x115D7, SentenceCat::STerm),
+    (0x115D8, 0x115DB, SentenceCat::OLetter),
+    (0x115DC, 0x115DD, SentenceCat::Extend),
+    (0x11600, 0x1162F, SentenceCat::OLetter),
+    (0x11630, 0x11640, SentenceCat::Extend),
+    (0x11641, 0x11642, SentenceCat::STerm),
+    (0x11644, 0x11644, SentenceCat::OLetter),
+    (0x11650, 0x11659, SentenceCat::Numeric),
+    (0x11680, 0x116AA, SentenceCat::OLetter),
+    (0x116AB, 0x116B7, SentenceCat::Extend),
+    (0x116B8, 0x116B8, SentenceCat::OLetter),
+    (0x116C0, 0x116C9, SentenceCat::Numeric),
+    (0x11700, 0x1171A, SentenceCat::OLetter),
+    (0x1171D, 0x1172B, SentenceCat::Extend),
+    (0x11730, 0x11739, SentenceCat::Numeric),
+    (0x1173C, 0x1173E, SentenceCat::STerm),
+    (0x11740, 0x11746, SentenceCat::OLetter),
+    (0x11800, 0x1182B, SentenceCat::OLetter),
+    (0x1182C, 0x1183A, SentenceCat::Extend),
+    (0x118A0, 0x118BF, SentenceCat::Upper),
+    (0x118C0, 0x118DF, SentenceCat::Lower),
+    (0x118E0, 0x118E9, SentenceCat::Numeric),
+    (0x118FF, 0x11906, SentenceCat::OLetter),
+    (0x11909, 0x11909, SentenceCat::OLetter),
+    (0x1190C, 0x11913, SentenceCat::OLetter),
+    (0x11915, 0x11916, SentenceCat::OLetter),
+    (0x11918, 0x1192F, SentenceCat::OLetter),
+    (0x11930, 0x11935, SentenceCat::Extend),
+    (0x11937, 0x11938, SentenceCat::Extend),
+    (0x1193B, 0x1193E, SentenceCat::Extend),
+    (0x1193F, 0x1193F, SentenceCat::OLetter),
+    (0x11940, 0x11940, SentenceCat::Extend),
+    (0x11941, 0x11941, SentenceCat::OLetter),
+    (0x11942, 0x11943, SentenceCat::Extend),
+    (0x11944, 0x11944, SentenceCat::STerm),
+    (0x11946, 0x11946, SentenceCat::STerm),
+    (0x11950, 0x11959, SentenceCat::Numeric),
+    (0x119A0, 0x119A7, SentenceCat::OLetter),
+    (0x119AA, 0x119D0, SentenceCat::OLetter),
+    (0x119D1, 0x119D7, SentenceCat::Extend),
+    (0x119DA, 0x119E0, SentenceCat::Extend),
+    (0x119E1, 0x119E1, SentenceCat::OLetter),
+    (0x119E3, 0x119E3, SentenceCat::OLetter),
+    (0x119E4, 0x119E4, SentenceCat::Extend),
+    (0x11A00, 0x11A00, SentenceCat::OLetter),
+    (0x11A01, 0x11A0A, SentenceCat::Extend),
+    (0x11A0B, 0x11A32, SentenceCat::OLetter),
+    (0x11A33, 0x11A39, SentenceCat::Extend),
+    (0x11A3A, 0x11A3A, SentenceCat::OLetter),
+    (0x11A3B, 0x11A3E, SentenceCat::Extend),
+    (0x11A42, 0x11A43, SentenceCat::STerm),
+    (0x11A47, 0x11A47, SentenceCat::Extend),
+    (0x11A50, 0x11A50, SentenceCat::OLetter),
+    (0x11A51, 0x11A5B, SentenceCat::Extend),
+    (0x11A5C, 0x11A89, SentenceCat::OLetter),
+    (0x11A8A, 0x11A99, SentenceCat::Extend),
+    (0x11A9B, 0x11A9C, SentenceCat::STerm),
+    (0x11A9D, 0x11A9D, SentenceCat::OLetter),
+    (0x11AB0, 0x11AF8, SentenceCat::OLetter),
+    (0x11C00, 0x11C08, SentenceCat::OLetter),
+    (0x11C0A, 0x11C2E, SentenceCat::OLetter),
+    (0x11C2F, 0x11C36, SentenceCat::Extend),
+    (0x11C38, 0x11C3F, SentenceCat::Extend),
+    (0x11C40, 0x11C40, SentenceCat::OLetter),
+    (0x11C41, 0x11C42, SentenceCat::STerm),
+    (0x11C50, 0x11C59, SentenceCat::Numeric),
+    (0x11C72, 0x11C8F, SentenceCat::OLetter),
+    (0x11C92, 0x11CA7, SentenceCat::Extend),
+    (0x11CA9, 0x11CB6, SentenceCat::Extend),
+    (0x11D00, 0x11D06, SentenceCat::OLetter),
+    (0x11D08, 0x11D09, SentenceCat::OLetter),
+    (0x11D0B, 0x11D30, SentenceCat::OLetter),
+    (0x11D31, 0x11D36, SentenceCat::Extend),
+    (0x11D3A, 0x11D3A, SentenceCat::Extend),
+    (0x11D3C, 0x11D3D, SentenceCat::Extend),
+    (0x11D3F, 0x11D45, SentenceCat::Extend),
+    (0x11D46, 0x11D46, SentenceCat::OLetter),
+    (0x11D47, 0x11D47, SentenceCat::Extend),
+    (0x11D50, 0x11D59, SentenceCat::Numeric),
+    (0x11D60, 0x11D65, SentenceCat::OLetter),
+    (0x11D67, 0x11D68, SentenceCat::OLetter),
+    (0x11D6A, 0x11D89, SentenceCat::OLetter),
+    (0x11D8A, 0x11D8E, SentenceCat::Extend),
+    (0x11D90, 0x11D91, SentenceCat::Extend),
+    (0x11D93, 0x11D97, SentenceCat::Extend),
+    (0x11D98, 0x11D98, SentenceCat::OLetter),
+    (0x11DA0, 0x11DA9, SentenceCat::Numeric),
+    (0x11EE0, 0x11EF2, SentenceCat::OLetter),
+    (0x11EF3, 0x11EF6, SentenceCat::Extend),
+    (0x11EF7, 0x11EF8, SentenceCat::STerm),
+    (0x11FB0, 0x11FB0, SentenceCat::OLetter),
+    (0x12000, 0x12399, SentenceCat::OLetter),
+    (0x12400, 0x1246E, SentenceCat::OLetter),
+    (0x12480, 0x12543, SentenceCat::OLetter),
+    (0x12F90, 0x12FF0, SentenceCat::OLetter),
+    (0x13000, 0x1342E, SentenceCat::OLetter),
+    (0x13430, 0x13438, SentenceCat::Format),
+    (0x14400, 0x14646, SentenceCat::OLetter),
+    (0x16800, 0x16A38, SentenceCat::OLetter),
+    (0x16A40, 0x16A5E, SentenceCat::OLetter),
+    (0x16A60, 0x16A69, SentenceCat::Numeric),
+    (0x16A6E, 0x16A6F, SentenceCat::STerm),
+    (0x16A70, 0x16ABE, SentenceCat::OLetter),
+    (0x16AC0, 0x16AC9, SentenceCat::Numeric),
+    (0x16AD0, 0x16AED, SentenceCat::OLetter),
+    (0x16AF0, 0x16AF4, SentenceCat::Extend),
+    (0x16AF5, 0x16AF5, SentenceCat::STerm),
+    (0x16B00, 0x16B2F, SentenceCat::OLetter),
+    (0x16B30, 0x16B36, SentenceCat::Extend),
+    (0x16B37, 0x16B38, SentenceCat::STerm),
+    (0x16B40, 0x16B43, SentenceCat::OLetter),
+    (0x16B44, 0x16B44, SentenceCat::STerm),
+    (0x16B50, 0x16B59, SentenceCat::Numeric),
+    (0x16B63, 0x16B77, SentenceCat::OLetter),
+    (0x16B7D, 0x16B8F, SentenceCat::OLetter),
+    (0x16E40, 0x16E5F, SentenceCat::Upper),
+    (0x16E60, 0x16E7F, SentenceCat::Lower),
+    (0x16E98, 0x16E98, SentenceCat::STerm),
+    (0x16F00, 0x16F4A, SentenceCat::OLetter),
+    (0x16F4F, 0x16F4F, SentenceCat::Extend),
+    (0x16F50, 0x16F50, SentenceCat::OLetter),
+    (0x16F51, 0x16F87, SentenceCat::Extend),
+    (0x16F8F, 0x16F92, SentenceCat::Extend),
+    (0x16F93, 0x16F9F, SentenceCat::OLetter),
+    (0x16FE0, 0x16FE1, SentenceCat::OLetter),
+    (0x16FE3, 0x16FE3, SentenceCat::OLetter),
+    (0x16FE4, 0x16FE4, SentenceCat::Extend),
+    (0x16FF0, 0x16FF1, SentenceCat::Extend),
+    (0x17000, 0x187F7, SentenceCat::OLetter),
+    (0x18800, 0x18CD5, SentenceCat::OLetter),
+    (0x18D00, 0x18D08, SentenceCat::OLetter),
+    (0x1AFF0, 0x1AFF3, SentenceCat::OLetter),
+    (0x1AFF5, 0x1AFFB, SentenceCat::OLetter),
+    (0x1AFFD, 0x1AFFE, SentenceCat::OLetter),
+    (0x1B000, 0x1B122, SentenceCat::OLetter),
+    (0x1B150, 0x1B152, SentenceCat::OLetter),
+    (0x1B164, 0x1B167, SentenceCat::OLetter),
+    (0x1B170, 0x1B2FB, SentenceCat::OLetter),
+    (0x1BC00, 0x1BC6A, SentenceCat::OLetter),
+    (0x1BC70, 0x1BC7C, SentenceCat::OLetter),
+    (0x1BC80, 0x1BC88, SentenceCat::OLetter),
+    (0x1BC90, 0x1BC99, SentenceCat::OLetter),
+    (0x1BC9D, 0x1BC9E, SentenceCat::Extend),
+    (0x1BC9F, 0x1BC9F, SentenceCat::STerm),
+    (0x1BCA0, 0x1BCA3, SentenceCat::Format),
+    (0x1CF00, 0x1CF2D, SentenceCat::Extend),
+    (0x1CF30, 0x1CF46, SentenceCat::Extend),
+    (0x1D165, 0x1D169, SentenceCat::Extend),
+    (0x1D16D, 0x1D172, SentenceCat::Extend),
+    (0x1D173, 0x1D17A, SentenceCat::Format),
+    (0x1D17B, 0x1D182, SentenceCat::Extend),
+    (0x1D185, 0x1D18B, SentenceCat::Extend),
+    (0x1D1AA, 0x1D1AD, SentenceCat::Extend),
+    (0x1D242, 0x1D244, SentenceCat::Extend),
+    (0x1D400, 0x1D419, SentenceCat::Upper),
+    (0x1D41A, 0x1D433, SentenceCat::Lower),
+    (0x1D434, 0x1D44D, SentenceCat::Upper),
+    (0x1D44E, 0x1D454, SentenceCat::Lower),
+    (0x1D456, 0x1D467, SentenceCat::Lower),
+    (0x1D468, 0x1D481, SentenceCat::Upper),
+    (0x1D482, 0x1D49B, SentenceCat::Lower),
+    (0x1D49C, 0x1D49C, SentenceCat::Upper),
+    (0x1D49E, 0x1D49F, SentenceCat::Upper),
+    (0x1D4A2, 0x1D4A2, SentenceCat::Upper),
+    (0x1D4A5, 0x1D4A6, SentenceCat::Upper),
+    (0x1D4A9, 0x1D4AC, SentenceCat::Upper),
+    (0x1D4AE, 0x1D4B5, SentenceCat::Upper),
+    (0x1D4B6, 0x1D4B9, SentenceCat::Lower),
+    (0x1D4BB, 0x1D4BB, SentenceCat::Lower),
+    (0x1D4BD, 0x1D4C3, SentenceCat::Lower),
+    (0x1D4C5, 0x1D4CF, SentenceCat::Lower),
+    (0x1D4D0, 0x1D4E9, SentenceCat::Upper),
+    (0x1D4EA, 0x1D503, SentenceCat::Lower),
+    (0x1D504, 0x1D505, SentenceCat::Upper),
+    (0x1D507, 0x1D50A, SentenceCat::Upper),
+    (0x1D50D, 0x1D514, SentenceCat::Upper),
+    (0x1D516, 0x1D51C, SentenceCat::Upper),
+    (0x1D51E, 0x1D537, SentenceCat::Lower),
+    (0x1D538, 0x1D539, SentenceCat::Upper),
+    (0x1D53B, 0x1D53E, SentenceCat::Upper),
+    (0x1D540, 0x1D544, SentenceCat::Upper),
+    (0x1D546, 0x1D546, SentenceCat::Upper),
+    (0x1D54A, 0x1D550, SentenceCat::Upper),
+    (0x1D552, 0x1D56B, SentenceCat::Lower),
+    (0x1D56C, 0x1D585, SentenceCat::Upper),
+    (0x1D586, 0x1D59F, SentenceCat::Lower),
+    (0x1D5A0, 0x1D5B9, SentenceCat::Upper),
+    (0x1D5BA, 0x1D5D3, SentenceCat::Lower),
+    (0x1D5D4, 0x1D5ED, SentenceCat::Upper),
+    (0x1D5EE, 0x1D607, SentenceCat::Lower),
+    (0x1D608, 0x1D621, SentenceCat::Upper),
+    (0x1D622, 0x1D63B, SentenceCat::Lower),
+    (0x1D63C, 0x1D655, SentenceCat::Upper),
+    (0x1D656, 0x1D66F, SentenceCat::Lower),
+    (0x1D670, 0x1D689, SentenceCat::Upper),
+    (0x1D68A, 0x1D6A5, SentenceCat::Lower),
+    (0x1D6A8, 0x1D6C0, SentenceCat::Upper),
+    (0x1D6C2, 0x1D6DA, SentenceCat::Lower),
+    (0x1D6DC, 0x1D6E1, SentenceCat::Lower),
+    (0x1D6E2, 0x1D6FA, SentenceCat::Upper),
+    (0x1D6FC, 0x1D714, SentenceCat::Lower),
+    (0x1D716, 0x1D71B, SentenceCat::Lower),
+    (0x1D71C, 0x1D734, SentenceCat::Upper),
+    (0x1D736, 0x1D74E, SentenceCat::Lower),
+    (0x1D750, 0x1D755, SentenceCat::Lower),
+    (0x1D756, 0x1D76E, SentenceCat::Upper),
+    (0x1D770, 0x1D788, SentenceCat::Lower),
+    (0x1D78A, 0x1D78F, SentenceCat::Lower),
+    (0x1D790, 0x1D7A8, SentenceCat::Upper),
+    (0x1D7AA, 0x1D7C2, SentenceCat::Lower),
+    (0x1D7C4, 0x1D7C9, SentenceCat::Lower),
+    (0x1D7CA, 0x1D7CA, SentenceCat::Upper),
+    (0x1D7CB, 0x1D7CB, SentenceCat::Lower),
+    (0x1D7CE, 0x1D7FF, SentenceCat::Numeric),
+    (0x1DA00, 0x1DA36, SentenceCat::Extend),
+    (0x1DA3B, 0x1DA6C, SentenceCat::Extend),
+    (0x1DA75, 0x1DA75, SentenceCat::Extend),
+    (0x1DA84, 0x1DA84, SentenceCat::Extend),
+    (0x1DA88, 0x1DA88, SentenceCat::STerm),
+    (0x1DA9B, 0x1DA9F, SentenceCat::Extend),
+    (0x1DAA1, 0x1DAAF, SentenceCat::Extend),
+    (0x1DF00, 0x1DF09, SentenceCat::Lower),
+    (0x1DF0A, 0x1DF0A, SentenceCat::OLetter),
+    (0x1DF0B, 0x1DF1E, SentenceCat::Lower),
+    (0x1E000, 0x1E006, SentenceCat::Extend),
+    (0x1E008, 0x1E018, SentenceCat::Extend),
+    (0x1E01B, 0x1E021, SentenceCat::Extend),
+    (0x1E023, 0x1E024, SentenceCat::Extend),
+    (0x1E026, 0x1E02A, SentenceCat::Extend),
+    (0x1E100, 0x1E12C, SentenceCat::OLetter),
+    (0x1E130, 0x1E136, SentenceCat::Extend),
+    (0x1E137, 0x1E13D, SentenceCat::OLetter),
+    (0x1E140, 0x1E149, SentenceCat::Numeric),
+    (0x1E14E, 0x1E14E, SentenceCat::OLetter),
+    (0x1E290, 0x1E2AD, SentenceCat::OLetter),
+    (0x1E2AE, 0x1E2AE, SentenceCat::Extend),
+    (0x1E2C0, 0x1E2EB, SentenceCat::OLetter),
+    (0x1E2EC, 0x1E2EF, SentenceCat::Extend),
+    (0x1E2F0, 0x1E2F9, SentenceCat::Numeric),
+    (0x1E7E0, 0x1E7E6, SentenceCat::OLetter),
+    (0x1E7E8, 0x1E7EB, SentenceCat::OLetter),
+    (0x1E7ED, 0x1E7EE, SentenceCat::OLetter),
+    (0x1E7F0, 0x1E7FE, SentenceCat::OLetter),
+    (0x1E800, 0x1E8C4, SentenceCat::OLetter),
+    (0x1E8D0, 0x1E8D6, SentenceCat::Extend),
+    (0x1E900, 0x1E921, SentenceCat::Upper),
+    (0x1E922, 0x1E943, SentenceCat::Lower),
+    (0x1E944, 0x1E94A, SentenceCat::Extend),
+    (0x1E94B, 0x1E94B, SentenceCat::OLetter),
+    (0x1E950, 0x1E959, SentenceCat::Numeric),
+    (0x1EE00, 0x1EE03, SentenceCat::OLetter),
+    (0x1EE05, 0x1EE1F, SentenceCat::OLetter),
+    (0x1EE21, 0x1EE22, SentenceCat::OLetter),
+    (0x1EE24, 0x1EE24, SentenceCat::OLetter),
+    (0x1EE27, 0x1EE27, SentenceCat::OLetter),
+    (0x1EE29, 0x1EE32, SentenceCat::OLetter),
+    (0x1EE34, 0x1EE37, SentenceCat::OLetter),
+    (0x1EE39, 0x1EE39, SentenceCat::OLetter),
+    (0x1EE3B, 0x1EE3B, SentenceCat::OLetter),
+    (0x1EE42, 0x1EE42, SentenceCat::OLetter),
+    (0x1EE47, 0x1EE47, SentenceCat::OLetter),
+    (0x1EE49, 0x1EE49, SentenceCat::OLetter),
+    (0x1EE4B, 0x1EE4B, SentenceCat::OLetter),
+    (0x1EE4D, 0x1EE4F, SentenceCat::OLetter),
+    (0x1EE51, 0x1EE52, SentenceCat::OLetter),
+    (0x1EE54, 0x1EE54, SentenceCat::OLetter),
+    (0x1EE57, 0x1EE57, SentenceCat::OLetter),
+    (0x1EE59, 0x1EE59, SentenceCat::OLetter),
+    (0x1EE5B, 0x1EE5B, SentenceCat::OLetter),
+    (0x1EE5D, 0x1EE5D, SentenceCat::OLetter),
+    (0x1EE5F, 0x1EE5F, SentenceCat::OLetter),
+    (0x1EE61, 0x1EE62, SentenceCat::OLetter),
+    (0x1EE64, 0x1EE64, SentenceCat::OLetter),
+    (0x1EE67, 0x1EE6A, SentenceCat::OLetter),
+    (0x1EE6C, 0x1EE72, SentenceCat::OLetter),
+    (0x1EE74, 0x1EE77, SentenceCat::OLetter),
+    (0x1EE79, 0x1EE7C, SentenceCat::OLetter),
+    (0x1EE7E, 0x1EE7E, SentenceCat::OLetter),
+    (0x1EE80, 0x1EE89, SentenceCat::OLetter),
+    (0x1EE8B, 0x1EE9B, SentenceCat::OLetter),
+    (0x1EEA1, 0x1EEA3, SentenceCat::OLetter),
+    (0x1EEA5, 0x1EEA9, SentenceCat::OLetter),
+    (0x1EEAB, 0x1EEBB, SentenceCat::OLetter),
+    (0x1F130, 0x1F149, SentenceCat::Upper),
+    (0x1F150, 0x1F169, SentenceCat::Upper),
+    (0x1F170, 0x1F189, SentenceCat::Upper),
+    (0x1F676, 0x1F678, SentenceCat::Close),
+    (0x1FBF0, 0x1FBF9, SentenceCat::Numeric),
+    (0x20000, 0x2A6DF, SentenceCat::OLetter),
+    (0x2A700, 0x2B738, SentenceCat::OLetter),
+    (0x2B740, 0x2B81D, SentenceCat::OLetter),
+    (0x2B820, 0x2CEA1, SentenceCat::OLetter),
+    (0x2CEB0, 0x2EBE0, SentenceCat::OLetter),
+    (0x2F800, 0x2FA1D, SentenceCat::OLetter),
+    (0x30000, 0x3134A, SentenceCat::OLetter),
+    (0xE0001, 0xE0001, SentenceCat::Format),
+    (0xE0020, 0xE007F, SentenceCat::Extend),
+    (0xE0100, 0xE01EF, SentenceCat::Extend),
+];
+
+pub const CANONICAL_COMBINING_CLASS: &[(u32, u32, u8)] = &[
+    (0x0300, 0x0314, 230),
+    (0x0315, 0x0315, 232),
+    (0x0316, 0x0319, 220),
+    (0x031A, 0x031A, 232),
+    (0x031B, 0x031B, 216),
+    (0x031C, 0x0320, 220),
+    (0x0321, 0x0322, 202),
+    (0x0323, 0x0326, 220),
+    (0x0327, 0x0328, 202),
+    (0x0329, 0x0333, 220),
+    (0x0334, 0x0338, 1),
+    (0x0339, 0x033C, 220),
+    (0x033D, 0x0344, 230),
+    (0x0345, 0x0345, 240),
+    (0x0346, 0x0346, 230),
+    (0x0347, 0x0349, 220),
+    (0x034A, 0x034C, 230),
+    (0x034D, 0x034E, 220),
+    (0x0350, 0x0352, 230),
+    (0x0353, 0x0356, 220),
+    (0x0357, 0x0357, 230),
+    (0x0358, 0x0358, 232),
+    (0x0359, 0x035A, 220),
+    (0x035B, 0x035B, 230),
+    (0x035C, 0x035C, 233),
+    (0x035D, 0x035E, 234),
+    (0x035F, 0x035F, 233),
+    (0x0360, 0x0361, 234),
+    (0x0362, 0x0362, 233),
+    (0x0363, 0x036F, 230),
+    (0x0483, 0x0487, 230),
+    (0x0591, 0x0591, 220),
+    (0x0592, 0x0595, 230),
+    (0x0596, 0x0596, 220),
+    (0x0597, 0x0599, 230),
+    (0x059A, 0x059A, 222),
+    (0x059B, 0x059B, 220),
+    (0x059C, 0x05A1, 230),
+    (0x05A2, 0x05A7, 220),
+    (0x05A8, 0x05A9, 230),
+    (0x05AA, 0x05AA, 220),
+    (0x05AB, 0x05AC, 230),
+    (0x05AD, 0x05AD, 222),
+    (0x05AE, 0x05AE, 228),
+    (0x05AF, 0x05AF, 230),
+    (0x05B0, 0x05B0, 10),
+    (0x05B1, 0x05B1, 11),
+    (0x05B2, 0x05B2, 12),
+    (0x05B3, 0x05B3, 13),
+    (0x05B4, 0x05B4, 14),
+    (0x05B5, 0x05B5, 15),
+    (0x05B6, 0x05B6, 16),
+    (0x05B7, 0x05B7, 17),
+    (0x05B8, 0x05B8, 18),
+    (0x05B9, 0x05BA, 19),
+    (0x05BB, 0x05BB, 20),
+    (0x05BC, 0x05BC, 21),
+    (0x05BD, 0x05BD, 22),
+    (0x05BF, 0x05BF, 23),
+    (0x05C1, 0x05C1, 24),
+    (0x05C2, 0x05C2, 25),
+    (0x05C4, 0x05C4, 230),
+    (0x05C5, 0x05C5, 220),
+    (0x05C7, 0x05C7, 18),
+    (0x0610, 0x0617, 230),
+    (0x0618, 0x0618, 30),
+    (0x0619, 0x0619, 31),
+    (0x061A, 0x061A, 32),
+    (0x064B, 0x064B, 27),
+    (0x064C, 0x064C, 28),
+    (0x064D, 0x064D, 29),
+    (0x064E, 0x064E, 30),
+    (0x064F, 0x064F, 31),
+    (0x0650, 0x0650, 32),
+    (0x0651, 0x0651, 33),
+    (0x0652, 0x0652, 34),
+    (0x0653, 0x0654, 230),
+    (0x0655, 0x0656, 220),
+    (0x0657, 0x065B, 230),
+    (0x065C, 0x065C, 220),
+    (0x065D, 0x065E, 230),
+    (0x065F, 0x065F, 220),
+    (0x0670, 0x0670, 35),
+    (0x06D6, 0x06DC, 230),
+    (0x06DF, 0x06E2, 230),
+    (0x06E3, 0x06E3, 220),
+    (0x06E4, 0x06E4, 230),
+    (0x06E7, 0x06E8, 230),
+    (0x06EA, 0x06EA, 220),
+    (0x06EB, 0x06EC, 230),
+    (0x06ED, 0x06ED, 220),
+    (0x0711, 0x0711, 36),
+    (0x0730, 0x0730, 230),
+    (0x0731, 0x0731, 220),
+    (0x0732, 0x0733, 230),
+    (0x0734, 0x0734, 220),
+    (0x0735, 0x0736, 230),
+    (0x0737, 0x0739, 220),
+    (0x073A, 0x073A, 230),
+    (0x073B, 0x073C, 220),
+    (0x073D, 0x073D, 230),
+    (0x073E, 0x073E, 220),
+    (0x073F, 0x0741, 230),
+    (0x0742, 0x0742, 220),
+    (0x0743, 0x0743, 230),
+    (0x0744, 0x0744, 220),
+    (0x0745, 0x0745, 230),
+    (0x0746, 0x0746, 220),
+    (0x0747, 0x0747, 230),
+    (0x0748, 0x0748, 220),
+    (0x0749, 0x074A, 230),
+    (0x07EB, 0x07F1, 230),
+    (0x07F2, 0x07F2, 220),
+    (0x07F3, 0x07F3, 230),
+    (0x07FD, 0x07FD, 220),
+    (0x0816, 0x0819, 230),
+    (0x081B, 0x0823, 230),
+    (0x0825, 0x0827, 230),
+    (0x0829, 0x082D, 230),
+    (0x0859, 0x085B, 220),
+    (0x0898, 0x0898, 230),
+    (0x0899, 0x089B, 220),
+    (0x089C, 0x089F, 230),
+    (0x08CA, 0x08CE, 230),
+    (0x08CF, 0x08D3, 220),
+    (0x08D4, 0x08E1, 230),
+    (0x08E3, 0x08E3, 220),
+    (0x08E4, 0x08E5, 230),
+    (0x08E6, 0x08E6, 220),
+    (0x08E7, 0x08E8, 230),
+    (0x08E9, 0x08E9, 220),
+    (0x08EA, 0x08EC, 230),
+    (0x08ED, 0x08EF, 220),
+    (0x08F0, 0x08F0, 27),
+    (0x08F1, 0x08F1, 28),
+    (0x08F2, 0x08F2, 29),
+    (0x08F3, 0x08F5, 230),
+    (0x08F6, 0x08F6, 220),
+    (0x08F7, 0x08F8, 230),
+    (0x08F9, 0x08FA, 220),
+    (0x08FB, 0x08FF, 230),
+    (0x093C, 0x093C, 7),
+    (0x094D, 0x094D, 9),
+    (0x0951, 0x0951, 230),
+    (0x0952, 0x0952, 220),
+    (0x0953, 0x0954, 230),
+    (0x09BC, 0x09BC, 7),
+    (0x09CD, 0x09CD, 9),
+    (0x09FE, 0x09FE, 230),
+    (0x0A3C, 0x0A3C, 7),
+    (0x0A4D, 0x0A4D, 9),
+    (0x0ABC, 0x0ABC, 7),
+    (0x0ACD, 0x0ACD, 9),
+    (0x0B3C, 0x0B3C, 7),
+    (0x0B4D, 0x0B4D, 9),
+    (0x0BCD, 0x0BCD, 9),
+    (0x0C3C, 0x0C3C, 7),
+    (0x0C4D, 0x0C4D, 9),
+    (0x0C55, 0x0C55, 84),
+    (0x0C56, 0x0C56, 91),
+    (0x0CBC, 0x0CBC, 7),
+    (0x0CCD, 0x0CCD, 9),
+    (0x0D3B, 0x0D3C, 9),
+    (0x0D4D, 0x0D4D, 9),
+    (0x0DCA, 0x0DCA, 9),
+    (0x0E38, 0x0E39, 103),
+    (0x0E3A, 0x0E3A, 9),
+    (0x0E48, 0x0E4B, 107),
+    (0x0EB8, 0x0EB9, 118),
+    (0x0EBA, 0x0EBA, 9),
+    (0x0EC8, 0x0ECB, 122),
+    (0x0F18, 0x0F19, 220),
+    (0x0F35, 0x0F35, 220),
+    (0x0F37, 0x0F37, 220),
+    (0x0F39, 0x0F39, 216),
+    (0x0F71, 0x0F71, 129),
+    (0x0F72, 0x0F72, 130),
+    (0x0F74, 0x0F74, 132),
+    (0x0F7A, 0x0F7D, 130),
+    (0x0F80, 0x0F80, 130),
+    (0x0F82, 0x0F83, 230),
+    (0x0F84, 0x0F84, 9),
+    (0x0F86, 0x0F87, 230),
+    (0x0FC6, 0x0FC6, 220),
+    (0x1037, 0x1037, 7),
+    (0x1039, 0x103A, 9),
+    (0x108D, 0x108D, 220),
+    (0x135D, 0x135F, 230),
+    (0x1714, 0x1715, 9),
+    (0x1734, 0x1734, 9),
+    (0x17D2, 0x17D2, 9),
+    (0x17DD, 0x17DD, 230),
+    (0x18A9, 0x18A9, 228),
+    (0x1939, 0x1939, 222),
+    (0x193A, 0x193A, 230),
+    (0x193B, 0x193B, 220),
+    (0x1A17, 0x1A17, 230),
+    (0x1A18, 0x1A18, 220),
+    (0x1A60, 0x1A60, 9),
+    (0x1A75, 0x1A7C, 230),
+    (0x1A7F, 0x1A7F, 220),
+    (0x1AB0, 0x1AB4, 230),
+    (0x1AB5, 0x1ABA, 220),
+    (0x1ABB, 0x1ABC, 230),
+    (0x1ABD, 0x1ABD, 220),
+    (0x1ABF, 0x1AC0, 220),
+    (0x1AC1, 0x1AC2, 230),
+    (0x1AC3, 0x1AC4, 220),
+    (0x1AC5, 0x1AC9, 230),
+    (0x1ACA, 0x1ACA, 220),
+    (0x1ACB, 0x1ACE, 230),
+    (0x1B34, 0x1B34, 7),
+    (0x1B44, 0x1B44, 9),
+    (0x1B6B, 0x1B6B, 230),
+    (0x1B6C, 0x1B6C, 220),
+    (0x1B6D, 0x1B73, 230),
+    (0x1BAA, 0x1BAB, 9),
+    (0x1BE6, 0x1BE6, 7),
+    (0x1BF2, 0x1BF3, 9),
+    (0x1C37, 0x1C37, 7),
+    (0x1CD0, 0x1CD2, 230),
+    (0x1CD4, 0x1CD4, 1),
+    (0x1CD5, 0x1CD9, 220),
+    (0x1CDA, 0x1CDB, 230),
+    (0x1CDC, 0x1CDF, 220),
+    (0x1CE0, 0x1CE0, 230),
+    (0x1CE2, 0x1CE8, 1),
+    (0x1CED, 0x1CED, 220),
+    (0x1CF4, 0x1CF4, 230),
+    (0x1CF8, 0x1CF9, 230),
+    (0x1DC0, 0x1DC1, 230),
+    (0x1DC2, 0x1DC2, 220),
+    (0x1DC3, 0x1DC9, 230),
+    (0x1DCA, 0x1DCA, 220),
+    (0x1DCB, 0x1DCC, 230),
+    (0x1DCD, 0x1DCD, 234),
+    (0x1DCE, 0x1DCE, 214),
+    (0x1DCF, 0x1DCF, 220),
+    (0x1DD0, 0x1DD0, 202),
+    (0x1DD1, 0x1DF5, 230),
+    (0x1DF6, 0x1DF6, 232),
+    (0x1DF7, 0x1DF8, 228),
+    (0x1DF9, 0x1DF9, 220),
+    (0x1DFA, 0x1DFA, 218),
+    (0x1DFB, 0x1DFB, 230),
+    (0x1DFC, 0x1DFC, 233),
+    (0x1DFD, 0x1DFD, 220),
+    (0x1DFE, 0x1DFE, 230),
+    (0x1DFF, 0x1DFF, 220),
+    (0x20D0, 0x20D1, 230),
+    (0x20D2, 0x20D3, 1),
+    (0x20D4, 0x20D7, 230),
+    (0x20D8, 0x20DA, 1),
+    (0x20DB, 0x20DC, 230),
+    (0x20E1, 0x20E1, 230),
+    (0x20E5, 0x20E6, 1),
+    (0x20E7, 0x20E7, 230),
+    (0x20E8, 0x20E8, 220),
+    (0x20E9, 0x20E9, 230),
+    (0x20EA, 0x20EB, 1),
+    (0x20EC, 0x20EF, 220),
+    (0x20F0, 0x20F0, 230),
+    (0x2CEF, 0x2CF1, 230),
+    (0x2D7F, 0x2D7F, 9),
+    (0x2DE0, 0x2DFF, 230),
+    (0x302A, 0x302A, 218),
+    (0x302B, 0x302B, 228),
+    (0x302C, 0x302C, 232),
+    (0x302D, 0x302D, 222),
+    (0x302E, 0x302F, 224),
+    (0x3099, 0x309A, 8),
+    (0xA66F, 0xA66F, 230),
+    (0xA674, 0xA67D, 230),
+    (0xA69E, 0xA69F, 230),
+    (0xA6F0, 0xA6F1, 230),
+    (0xA806, 0xA806, 9),
+    (0xA82C, 0xA82C, 9),
+    (0xA8C4, 0xA8C4, 9),
+    (0xA8E0, 0xA8F1, 230),
+    (0xA92B, 0xA92D, 220),
+    (0xA953, 0xA953, 9),
+    (0xA9B3, 0xA9B3, 7),
+    (0xA9C0, 0xA9C0, 9),
+    (0xAAB0, 0xAAB0, 230),
+    (0xAAB2, 0xAAB3, 230),
+    (0xAAB4, 0xAAB4, 220),
+    (0xAAB7, 0xAAB8, 230),
+    (0xAABE, 0xAABF, 230),
+    (0xAAC1, 0xAAC1, 230),
+    (0xAAF6, 0xAAF6, 9),
+    (0xABED, 0xABED, 9),
+    (0xFB1E, 0xFB1E, 26),
+    (0xFE20, 0xFE26, 230),
+    (0xFE27, 0xFE2D, 220),
+    (0xFE2E, 0xFE2F, 230),
+    (0x101FD, 0x101FD, 220),
+    (0x102E0, 0x102E0, 220),
+    (0x10376, 0x1037A, 230),
+    (0x10A0D, 0x10A0D, 220),
+    (0x10A0F, 0x10A0F, 230),
+    (0x10A38, 0x10A38, 230),
+    (0x10A39, 0x10A39, 1),
+    (0x10A3A, 0x10A3A, 220),
+    (0x10A3F, 0x10A3F, 9),
+    (0x10AE5, 0x10AE5, 230),
+    (0x10AE6, 0x10AE6, 220),
+    (0x10D24, 0x10D27, 230),
+    (0x10EAB, 0x10EAC, 230),
+    (0x10F46, 0x10F47, 220),
+    (0x10F48, 0x10F4A, 230),
+    (0x10F4B, 0x10F4B, 220),
+    (0x10F4C, 0x10F4C, 230),
+    (0x10F4D, 0x10F50, 220),
+    (0x10F82, 0x10F82, 230),
+    (0x10F83, 0x10F83, 220),
+    (0x10F84, 0x10F84, 230),
+    (0x10F85, 0x10F85, 220),
+    (0x11046, 0x11046, 9),
+    (0x11070, 0x11070, 9),
+    (0x1107F, 0x1107F, 9),
+    (0x110B9, 0x110B9, 9),
+    (0x110BA, 0x110BA, 7),
+    (0x11100, 0x11102, 230),
+    (0x11133, 0x11134, 9),
+    (0x11173, 0x11173, 7),
+    (0x111C0, 0x111C0, 9),
+    (0x111CA, 0x111CA, 7),
+    (0x11235, 0x11235, 9),
+    (0x11236, 0x11236, 7),
+    (0x112E9, 0x112E9, 7),
+    (0x112EA, 0x112EA, 9),
+    (0x1133B, 0x1133C, 7),
+    (0x1134D, 0x1134D, 9),
+    (0x11366, 0x1136C, 230),
+    (0x11370, 0x11374, 230),
+    (0x11442, 0x11442, 9),
+    (0x11446, 0x11446, 7),
+    (0x1145E, 0x1145E, 230),
+    (0x114C2, 0x114C2, 9),
+    (0x114C3, 0x114C3, 7),
+    (0x115BF, 0x115BF, 9),
+    (0x115C0, 0x115C0, 7),
+    (0x1163F, 0x1163F, 9),
+    (0x116B6, 0x116B6, 9),
+    (0x116B7, 0x116B7, 7),
+    (0x1172B, 0x1172B, 9),
+    (0x11839, 0x11839, 9),
+    (0x1183A, 0x1183A, 7),
+    (0x1193D, 0x1193E, 9),
+    (0x11943, 0x11943, 7),
+    (0x119E0, 0x119E0, 9),
+    (0x11A34, 0x11A34, 9),
+    (0x11A47, 0x11A47, 9),
+    (0x11A99, 0x11A99, 9),
+    (0x11C3F, 0x11C3F, 9),
+    (0x11D42, 0x11D42, 7),
+    (0x11D44, 0x11D45, 9),
+    (0x11D97, 0x11D97, 9),
+    (0x16AF0, 0x16AF4, 1),
+    (0x16B30, 0x16B36, 230),
+    (0x16FF0, 0x16FF1, 6),
+    (0x1BC9E, 0x1BC9E, 1),
+    (0x1D165, 0x1D166, 216),
+    (0x1D167, 0x1D169, 1),
+    (0x1D16D, 0x1D16D, 226),
+    (0x1D16E, 0x1D172, 216),
+    (0x1D17B, 0x1D182, 220),
+    (0x1D185, 0x1D189, 230),
+    (0x1D18A, 0x1D18B, 220),
+    (0x1D1AA, 0x1D1AD, 230),
+    (0x1D242, 0x1D244, 230),
+    (0x1E000, 0x1E006, 230),
+    (0x1E008, 0x1E018, 230),
+    (0x1E01B, 0x1E021, 230),
+    (0x1E023, 0x1E024, 230),
+    (0x1E026, 0x1E02A, 230),
+    (0x1E130, 0x1E136, 230),
+    (0x1E2AE, 0x1E2AE, 230),
+    (0x1E2EC, 0x1E2EF, 230),
+    (0x1E8D0, 0x1E8D6, 220),
+    (0x1E944, 0x1E949, 230),
+    (0x1E94A, 0x1E94A, 7),
+];
+
+pub const CANONICAL_DECOMPOSITION: &[(u32, &str)] = &[
+    (0x00C0, "\u{41}\u{300}"),
+    (0x00C1, "\u{41}\u{301}"),
+    (0x00C2, "\u{41}\u{302}"),
+    (0x00C3, "\u{41}\u{303}"),
+    (0x00C4, "\u{41}\u{308}"),
+    (0x00C5, "\u{41}\u{30a}"),
+    (0x00C7, "\u{43}\u{327}"),
+    (0x00C8, "\u{45}\u{300}"),
+    (0x00C9, "\u{45}\u{301}"),
+    (0x00CA, "\u{45}\u{302}"),
+    (0x00CB, "\u{45}\u{308}"),
+    (0x00CC, "\u{49}\u{300}"),
+    (0x00CD, "\u{49}\u{301}"),
+    (0x00CE, "\u{49}\u{302}"),
+    (0x00CF, "\u{49}\u{308}"),
+    (0x00D1, "\u{4e}\u{303}"),
+    (0x00D2, "\u{4f}\u{300}"),
+    (0x00D3, "\u{4f}\u{301}"),
+    (0x00D4, "\u{4f}\u{302}"),
+    (0x00D5, "\u{4f}\u{303}"),
+    (0x00D6, "\u{4f}\u{308}"),
+    (0x00D9, "\u{55}\u{300}"),
+    (0x00DA, "\u{55}\u{301}"),
+    (0x00DB, "\u{55}\u{302}"),
+    (0x00DC, "\u{55}\u{308}"),
+    (0x00DD, "\u{59}\u{301}"),
+    (0x00E0, "\u{61}\u{300}"),
+    (0x00E1, "\u{61}\u{301}"),
+    (0x00E2, "\u{61}\u{302}"),
+    (0x00E3, "\u{61}\u{303}"),
+    (0x00E4, "\u{61}\u{308}"),
+    (0x00E5, "\u{61}\u{30a}"),
+    (0x00E7, "\u{63}\u{327}"),
+    (0x00E8, "\u{65}\u{300}"),
+    (0x00E9, "\u{65}\u{301}"),
+    (0x00EA, "\u{65}\u{302}"),
+    (0x00EB, "\u{65}\u{308}"),
+    (0x00EC, "\u{69}\u{300}"),
+    (0x00ED, "\u{69}\u{301}"),
+    (0x00EE, "\u{69}\u{302}"),
+    (0x00EF, "\u{69}\u{308}"),
+    (0x00F1, "\u{6e}\u{303}"),
+    (0x00F2, "\u{6f}\u{300}"),
+    (0x00F3, "\u{6f}\u{301}"),
+    (0x00F4, "\u{6f}\u{302}"),
+    (0x00F5, "\u{6f}\u{303}"),
+    (0x00F6, "\u{6f}\u{308}"),
+    (0x00F9, "\u{75}\u{300}"),
+    (0x00FA, "\u{75}\u{301}"),
+    (0x00FB, "\u{75}\u{302}"),
+    (0x00FC, "\u{75}\u{308}"),
+    (0x00FD, "\u{79}\u{301}"),
+    (0x00FF, "\u{79}\u{308}"),
+    (0x0100, "\u{41}\u{304}"),
+    (0x0101, "\u{61}\u{304}"),
+    (0x0102, "\u{41}\u{306}"),
+    (0x0103, "\u{61}\u{306}"),
+    (0x0104, "\u{41}\u{328}"),
+    (0x0105, "\u{61}\u{328}"),
+    (0x0106, "\u{43}\u{301}"),
+    (0x0107, "\u{63}\u{301}"),
+    (0x0108, "\u{43}\u{302}"),
+    (0x0109, "\u{63}\u{302}"),
+    (0x010A, "\u{43}\u{307}"),
+    (0x010B, "\u{63}\u{307}"),
+    (0x010C, "\u{43}\u{30c}"),
+    (0x010D, "\u{63}\u{30c}"),
+    (0x010E, "\u{44}\u{30c}"),
+    (0x010F, "\u{64}\u{30c}"),
+    (0x0112, "\u{45}\u{304}"),
+    (0x0113, "\u{65}\u{304}"),
+    (0x0114, "\u{45}\u{306}"),
+    (0x0115, "\u{65}\u{306}"),
+    (0x0116, "\u{45}\u{307}"),
+    (0x0117, "\u{65}\u{307}"),
+    (0x0118, "\u{45}\u{328}"),
+    (0x0119, "\u{65}\u{328}"),
+    (0x011A, "\u{45}\u{30c}"),
+    (0x011B, "\u{65}\u{30c}"),
+    (0x011C, "\u{47}\u{302}"),
+    (0x011D, "\u{67}\u{302}"),
+    (0x011E, "\u{47}\u{306}"),
+    (0x011F, "\u{67}\u{306}"),
+    (0x0120, "\u{47}\u{307}"),
+    (0x0121, "\u{67}\u{307}"),
+    (0x0122, "\u{47}\u{327}"),
+    (0x0123, "\u{67}\u{327}"),
+    (0x0124, "\u{48}\u{302}"),
+    (0x0125, "\u{68}\u{302}"),
+    (0x0128, "\u{49}\u{303}"),
+    (0x0129, "\u{69}\u{303}"),
+    (0x012A, "\u{49}\u{304}"),
+    (0x012B, "\u{69}\u{304}"),
+    (0x012C, "\u{49}\u{306}"),
+    (0x012D, "\u{69}\u{306}"),
+    (0x012E, "\u{49}\u{328}"),
+    (0x012F, "\u{69}\u{328}"),
+    (0x0130, "\u{49}\u{307}"),
+    (0x0134, "\u{4a}\u{302}"),
+    (0x0135, "\u{6a}\u{302}"),
+    (0x0136, "\u{4b}\u{327}"),
+    (0x0137, "\u{6b}\u{327}"),
+    (0x0139, "\u{4c}\u{301}"),
+    (0x013A, "\u{6c}\u{301}"),
+    (0x013B, "\u{4c}\u{327}"),
+    (0x013C, "\u{6c}\u{327}"),
+    (0x013D, "\u{4c}\u{30c}"),
+    (0x013E, "\u{6c}\u{30c}"),
+    (0x0143, "\u{4e}\u{301}"),
+    (0x0144, "\u{6e}\u{301}"),
+    (0x0145, "\u{4e}\u{327}"),
+    (0x0146, "\u{6e}\u{327}"),
+    (0x0147, "\u{4e}\u{30c}"),
+    (0x0148, "\u{6e}\u{30c}"),
+    (0x014C, "\u{4f}\u{304}"),
+    (0x014D, "\u{6f}\u{304}"),
+    (0x014E, "\u{4f}\u{306}"),
+    (0x014F, "\u{6f}\u{306}"),
+    (0x0150, "\u{4f}\u{30b}"),
+    (0x0151, "\u{6f}\u{30b}"),
+    (0x0154, "\u{52}\u{301}"),
+    (0x0155, "\u{72}\u{301}"),
+    (0x0156, "\u{52}\u{327}"),
+    (0x0157, "\u{72}\u{327}"),
+    (0x0158, "\u{52}\u{30c}"),
+    (0x0159, "\u{72}\u{30c}"),
+    (0x015A, "\u{53}\u{301}"),
+    (0x015B, "\u{73}\u{301}"),
+    (0x015C, "\u{53}\u{302}"),
+    (0x015D, "\u{73}\u{302}"),
+    (0x015E, "\u{53}\u{327}"),
+    (0x015F, "\u{73}\u{327}"),
+    (0x0160, "\u{53}\u{30c}"),
+    (0x0161, "\u{73}\u{30c}"),
+    (0x0162, "\u{54}\u{327}"),
+    (0x0163, "\u{74}\u{327}"),
+    (0x0164, "\u{54}\u{30c}"),
+    (0x0165, "\u{74}\u{30c}"),
+    (0x0168, "\u{55}\u{303}"),
+    (0x0169, "\u{75}\u{303}"),
+    (0x016A, "\u{55}\u{304}"),
+    (0x016B, "\u{75}\u{304}"),
+    (0x016C, "\u{55}\u{306}"),
+    (0x016D, "\u{75}\u{306}"),
+    (0x016E, "\u{55}\u{30a}"),
+    (0x016F, "\u{75}\u{30a}"),
+    (0x0170, "\u{55}\u{30b}"),
+    (0x0171, "\u{75}\u{30b}"),
+    (0x0172, "\u{55}\u{328}"),
+    (0x0173, "\u{75}\u{328}"),
+    (0x0174, "\u{57}\u{302}"),
+    (0x0175, "\u{77}\u{302}"),
+    (0x0176, "\u{59}\u{302}"),
+    (0x0177, "\u{79}\u{302}"),
+    (0x0178, "\u{59}\u{308}"),
+    (0x0179, "\u{5a}\u{301}"),
+    (0x017A, "\u{7a}\u{301}"),
+    (0x017B, "\u{5a}\u{307}"),
+    (0x017C, "\u{7a}\u{307}"),
+    (0x017D, "\u{5a}\u{30c}"),
+    (0x017E, "\u{7a}\u{30c}"),
+    (0x01A0, "\u{4f}\u{31b}"),
+    (0x01A1, "\u{6f}\u{31b}"),
+    (0x01AF, "\u{55}\u{31b}"),
+    (0x01B0, "\u{75}\u{31b}"),
+    (0x01CD, "\u{41}\u{30c}"),
+    (0x01CE, "\u{61}\u{30c}"),
+    (0x01CF, "\u{49}\u{30c}"),
+    (0x01D0, "\u{69}\u{30c}"),
+    (0x01D1, "\u{4f}\u{30c}"),
+    (0x01D2, "\u{6f}\u{30c}"),
+    (0x01D3, "\u{55}\u{30c}"),
+    (0x01D4, "\u{75}\u{30c}"),
+    (0x01D5, "\u{55}\u{308}\u{304}"),
+    (0x01D6, "\u{75}\u{308}\u{304}"),
+    (0x01D7, "\u{55}\u{308}\u{301}"),
+    (0x01D8, "\u{75}\u{308}\u{301}"),
+    (0x01D9, "\u{55}\u{308}\u{30c}"),
+    (0x01DA, "\u{75}\u{308}\u{30c}"),
+    (0x01DB, "\u{55}\u{308}\u{300}"),
+    (0x01DC, "\u{75}\u{308}\u{300}"),
+    (0x01DE, "\u{41}\u{308}\u{304}"),
+    (0x01DF, "\u{61}\u{308}\u{304}"),
+    (0x01E0, "\u{41}\u{307}\u{304}"),
+    (0x01E1, "\u{61}\u{307}\u{304}"),
+    (0x01E2, "\u{c6}\u{304}"),
+    (0x01E3, "\u{e6}\u{304}"),
+    (0x01E6, "\u{47}\u{30c}"),
+    (0x01E7, "\u{67}\u{30c}"),
+    (0x01E8, "\u{4b}\u{30c}"),
+    (0x01E9, "\u{6b}\u{30c}"),
+    (0x01EA, "\u{4f}\u{328}"),
+    (0x01EB, "\u{6f}\u{328}"),
+    (0x01EC, "\u{4f}\u{328}\u{304}"),
+    (0x01ED, "\u{6f}\u{328}\u{304}"),
+    (0x01EE, "\u{1b7}\u{30c}"),
+    (0x01EF, "\u{292}\u{30c}"),
+    (0x01F0, "\u{6a}\u{30c}"),
+    (0x01F4, "\u{47}\u{301}"),
+    (0x01F5, "\u{67}\u{301}"),
+    (0x01F8, "\u{4e}\u{300}"),
+    (0x01F9, "\u{6e}\u{300}"),
+    (0x01FA, "\u{41}\u{30a}\u{301}"),
+    (0x01FB, "\u{61}\u{30a}\u{301}"),
+    (0x01FC, "\u{c6}\u{301}"),
+    (0x01FD, "\u{e6}\u{301}"),
+    (0x01FE, "\u{d8}\u{301}"),
+    (0x01FF, "\u{f8}\u{301}"),
+    (0x0200, "\u{41}\u{30f}"),
+    (0x0201, "\u{61}\u{30f}"),
+    (0x0202, "\u{41}\u{311}"),
+    (0x0203, "\u{61}\u{311}"),
+    (0x0204, "\u{45}\u{30f}"),
+    (0x0205, "\u{65}\u{30f}"),
+    (0x0206, "\u{45}\u{311}"),
+    (0x0207, "\u{65}\u{311}"),
+    (0x0208, "\u{49}\u{30f}"),
+    (0x0209, "\u{69}\u{30f}"),
+    (0x020A, "\u{49}\u{311}"),
+    (0x020B, "\u{69}\u{311}"),
+    (0x020C, "\u{4f}\u{30f}"),
+    (0x020D, "\u{6f}\u{30f}"),
+    (0x020E, "\u{4f}\u{311}"),
+    (0x020F, "\u{6f}\u{311}"),
+    (0x0210, "\u{52}\u{30f}"),
+    (0x0211, "\u{72}\u{30f}"),
+    (0x0212, "\u{52}\u{311}"),
+    (0x0213, "\u{72}\u{311}"),
+    (0x0214, "\u{55}\u{30f}"),
+    (0x0215, "\u{75}\u{30f}"),
+    (0x0216, "\u{55}\u{311}"),
+    (0x0217, "\u{75}\u{311}"),
+    (0x0218, "\u{53}\u{326}"),
+    (0x0219, "\u{73}\u{326}"),
+    (0x021A, "\u{54}\u{326}"),
+    (0x021B, "\u{74}\u{326}"),
+    (0x021E, "\u{48}\u{30c}"),
+    (0x021F, "\u{68}\u{30c}"),
+    (0x0226, "\u{41}\u{307}"),
+    (0x0227, "\u{61}\u{307}"),
+    (0x0228, "\u{45}\u{327}"),
+    (0x0229, "\u{65}\u{327}"),
+    (0x022A, "\u{4f}\u{308}\u{304}"),
+    (0x022B, "\u{6f}\u{308}\u{304}"),
+    (0x022C, "\u{4f}\u{303}\u{304}"),
+    (0x022D, "\u{6f}\u{303}\u{304}"),
+    (0x022E, "\u{4f}\u{307}"),
+    (0x022F, "\u{6f}\u{307}"),
+    (0x0230, "\u{4f}\u{307}\u{304}"),
+    (0x0231, "\u{6f}\u{307}\u{304}"),
+    (0x0232, "\u{59}\u{304}"),
+    (0x0233, "\u{79}\u{304}"),
+    (0x0340, "\u{300}"),
+    (0x0341, "\u{301}"),
+    (0x0343, "\u{313}"),
+    (0x0344, "\u{308}\u{301}"),
+    (0x0374, "\u{2b9}"),
+    (0x037E, "\u{3b}"),
+    (0x0385, "\u{a8}\u{301}"),
+    (0x0386, "\u{391}\u{301}"),
+    (0x0387, "\u{b7}"),
+    (0x0388, "\u{395}\u{301}"),
+    (0x0389, "\u{397}\u{301}"),
+    (0x038A, "\u{399}\u{301}"),
+    (0x038C, "\u{39f}\u{301}"),
+    (0x038E, "\u{3a5}\u{301}"),
+    (0x038F, "\u{3a9}\u{301}"),
+    (0x0390, "\u{3b9}\u{308}\u{301}"),
+    (0x03AA, "\u{399}\u{308}"),
+    (0x03AB, "\u{3a5}\u{308}"),
+    (0x03AC, "\u{3b1}\u{301}"),
+    (0x03AD, "\u{3b5}\u{301}"),
+    (0x03AE, "\u{3b7}\u{301}"),
+    (0x03AF, "\u{3b9}\u{301}"),
+    (0x03B0, "\u{3c5}\u{308}\u{301}"),
+    (0x03CA, "\u{3b9}\u{308}"),
+    (0x03CB, "\u{3c5}\u{308}"),
+    (0x03CC, "\u{3bf}\u{301}"),
+    (0x03CD, "\u{3c5}\u{301}"),
+    (0x03CE, "\u{3c9}\u{301}"),
+    (0x03D3, "\u{3d2}\u{301}"),
+    (0x03D4, "\u{3d2}\u{308}"),
+    (0x0400, "\u{415}\u{300}"),
+    (0x0401, "\u{415}\u{308}"),
+    (0x0403, "\u{413}\u{301}"),
+    (0x0407, "\u{406}\u{308}"),
+    (0x040C, "\u{41a}\u{301}"),
+    (0x040D, "\u{418}\u{300}"),
+    (0x040E, "\u{423}\u{306}"),
+    (0x0419, "\u{418}\u{306}"),
+    (0x0439, "\u{438}\u{306}"),
+    (0x0450, "\u{435}\u{300}"),
+    (0x0451, "\u{435}\u{308}"),
+    (0x0453, "\u{433}\u{301}"),
+    (0x0457, "\u{456}\u{308}"),
+    (0x045C, "\u{43a}\u{301}"),
+    (0x045D, "\u{438}\u{300}"),
+    (0x045E, "\u{443}\u{306}"),
+    (0x0476, "\u{474}\u{30f}"),
+    (0x0477, "\u{475}\u{30f}"),
+    (0x04C1, "\u{416}\u{306}"),
+    (0x04C2, "\u{436}\u{306}"),
+    (0x04D0, "\u{410}\u{306}"),
+    (0x04D1, "\u{430}\u{306}"),
+    (0x04D2, "\u{410}\u{308}"),
+    (0x04D3, "\u{430}\u{308}"),
+    (0x04D6, "\u{415}\u{306}"),
+    (0x04D7, "\u{435}\u{306}"),
+    (0x04DA, "\u{4d8}\u{308}"),
+    (0x04DB, "\u{4d9}\u{308}"),
+    (0x04DC, "\u{416}\u{308}"),
+    (0x04DD, "\u{436}\u{308}"),
+    (0x04DE, "\u{417}\u{308}"),
+    (0x04DF, "\u{437}\u{308}"),
+    (0x04E2, "\u{418}\u{304}"),
+    (0x04E3, "\u{438}\u{304}"),
+    (0x04E4, "\u{418}\u{308}"),
+    (0x04E5, "\u{438}\u{308}"),
+    (0x04E6, "\u{41e}\u{308}"),
+    (0x04E7, "\u{43e}\u{308}"),
+    (0x04EA, "\u{4e8}\u{308}"),
+    (0x04EB, "\u{4e9}\u{308}"),
+    (0x04EC, "\u{42d}\u{308}"),
+    (0x04ED, "\u{44d}\u{308}"),
+    (0x04EE, "\u{423}\u{304}"),
+    (0x04EF, "\u{443}\u{304}"),
+    (0x04F0, "\u{423}\u{308}"),
+    (0x04F1, "\u{443}\u{308}"),
+    (0x04F2, "\u{423}\u{30b}"),
+    (0x04F3, "\u{443}\u{30b}"),
+    (0x04F4, "\u{427}\u{308}"),
+    (0x04F5, "\u{447}\u{308}"),
+    (0x04F8, "\u{42b}\u{308}"),
+    (0x04F9, "\u{44b}\u{308}"),
+    (0x0622, "\u{627}\u{653}"),
+    (0x0623, "\u{627}\u{654}"),
+    (0x0624, "\u{648}\u{654}"),
+    (0x0625, "\u{627}\u{655}"),
+    (0x0626, "\u{64a}\u{654}"),
+    (0x06C0, "\u{6d5}\u{654}"),
+    (0x06C2, "\u{6c1}\u{654}"),
+    (0x06D3, "\u{6d2}\u{654}"),
+    (0x0929, "\u{928}\u{93c}"),
+    (0x0931, "\u{930}\u{93c}"),
+    (0x0934, "\u{933}\u{93c}"),
+    (0x0958, "\u{915}\u{93c}"),
+    (0x0959, "\u{916}\u{93c}"),
+    (0x095A, "\u{917}\u{93c}"),
+    (0x095B, "\u{91c}\u{93c}"),
+    (0x095C, "\u{921}\u{93c}"),
+    (0x095D, "\u{922}\u{93c}"),
+    (0x095E, "\u{92b}\u{93c}"),
+    (0x095F, "\u{92f}\u{93c}"),
+    (0x09CB, "\u{9c7}\u{9be}"),
+    (0x09CC, "\u{9c7}\u{9d7}"),
+    (0x09DC, "\u{9a1}\u{9bc}"),
+    (0x09DD, "\u{9a2}\u{9bc}"),
+    (0x09DF, "\u{9af}\u{9bc}"),
+    (0x0A33, "\u{a32}\u{a3c}"),
+    (0x0A36, "\u{a38}\u{a3c}"),
+    (0x0A59, "\u{a16}\u{a3c}"),
+    (0x0A5A, "\u{a17}\u{a3c}"),
+    (0x0A5B, "\u{a1c}\u{a3c}"),
+    (0x0A5E, "\u{a2b}\u{a3c}"),
+    (0x0B48, "\u{b47}\u{b56}"),
+    (0x0B4B, "\u{b47}\u{b3e}"),
+    (0x0B4C, "\u{b47}\u{b57}"),
+    (0x0B5C, "\u{b21}\u{b3c}"),
+    (0x0B5D, "\u{b22}\u{b3c}"),
+    (0x0B94, "\u{b92}\u{bd7}"),
+    (0x0BCA, "\u{bc6}\u{bbe}"),
+    (0x0BCB, "\u{bc7}\u{bbe}"),
+    (0x0BCC, "\u{bc6}\u{bd7}"),
+    (0x0C48, "\u{c46}\u{c56}"),
+    (0x0CC0, "\u{cbf}\u{cd5}"),
+    (0x0CC7, "\u{cc6}\u{cd5}"),
+    (0x0CC8, "\u{cc6}\u{cd6}"),
+    (0x0CCA, "\u{cc6}\u{cc2}"),
+    (0x0CCB, "\u{cc6}\u{cc2}\u{cd5}"),
+    (0x0D4A, "\u{d46}\u{d3e}"),
+    (0x0D4B, "\u{d47}\u{d3e}"),
+    (0x0D4C, "\u{d46}\u{d57}"),
+    (0x0DDA, "\u{dd9}\u{dca}"),
+    (0x0DDC, "\u{dd9}\u{dcf}"),
+    (0x0DDD, "\u{dd9}\u{dcf}\u{dca}"),
+    (0x0DDE, "\u{dd9}\u{ddf}"),
+    (0x0F43, "\u{f42}\u{fb7}"),
+    (0x0F4D, "\u{f4c}\u{fb7}"),
+    (0x0F52, "\u{f51}\u{fb7}"),
+    (0x0F57, "\u{f56}\u{fb7}"),
+    (0x0F5C, "\u{f5b}\u{fb7}"),
+    (0x0F69, "\u{f40}\u{fb5}"),
+    (0x0F73, "\u{f71}\u{f72}"),
+    (0x0F75, "\u{f71}\u{f74}"),
+    (0x0F76, "\u{fb2}\u{f80}"),
+    (0x0F78, "\u{fb3}\u{f80}"),
+    (0x0F81, "\u{f71}\u{f80}"),
+    (0x0F93, "\u{f92}\u{fb7}"),
+    (0x0F9D, "\u{f9c}\u{fb7}"),
+    (0x0FA2, "\u{fa1}\u{fb7}"),
+    (0x0FA7, "\u{fa6}\u{fb7}"),
+    (0x0FAC, "\u{fab}\u{fb7}"),
+    (0x0FB9, "\u{f90}\u{fb5}"),
+    (0x1026, "\u{1025}\u{102e}"),
+    (0x1B06, "\u{1b05}\u{1b35}"),
+    (0x1B08, "\u{1b07}\u{1b35}"),
+    (0x1B0A, "\u{1b09}\u{1b35}"),
+    (0x1B0C, "\u{1b0b}\u{1b35}"),
+    (0x1B0E, "\u{1b0d}\u{1b35}"),
+    (0x1B12, "\u{1b11}\u{1b35}"),
+    (0x1B3B, "\u{1b3a}\u{1b35}"),
+    (0x1B3D, "\u{1b3c}\u{1b35}"),
+    (0x1B40, "\u{1b3e}\u{1b35}"),
+    (0x1B41, "\u{1b3f}\u{1b35}"),
+    (0x1B43, "\u{1b42}\u{1b35}"),
+    (0x1E00, "\u{41}\u{325}"),
+    (0x1E01, "\u{61}\u{325}"),
+    (0x1E02, "\u{42}\u{307}"),
+    (0x1E03, "\u{62}\u{307}"),
+    (0x1E04, "\u{42}\u{323}"),
+    (0x1E05, "\u{62}\u{323}"),
+    (0x1E06, "\u{42}\u{331}"),
+    (0x1E07, "\u{62}\u{331}"),
+    (0x1E08, "\u{43}\u{327}\u{301}"),
+    (0x1E09, "\u{63}\u{327}\u{301}"),
+    (0x1E0A, "\u{44}\u{307}"),
+    (0x1E0B, "\u{64}\u{307}"),
+    (0x1E0C, "\u{44}\u{323}"),
+    (0x1E0D, "\u{64}\u{323}"),
+    (0x1E0E, "\u{44}\u{331}"),
+    (0x1E0F, "\u{64}\u{331}"),
+    (0x1E10, "\u{44}\u{327}"),
+    (0x1E11, "\u{64}\u{327}"),
+    (0x1E12, "\u{44}\u{32d}"),
+    (0x1E13, "\u{64}\u{32d}"),
+    (0x1E14, "\u{45}\u{304}\u{300}"),
+    (0x1E15, "\u{65}\u{304}\u{300}"),
+    (0x1E16, "\u{45}\u{304}\u{301}"),
+    (0x1E17, "\u{65}\u{304}\u{301}"),
+    (0x1E18, "\u{45}\u{32d}"),
+    (0x1E19, "\u{65}\u{32d}"),
+    (0x1E1A, "\u{45}\u{330}"),
+    (0x1E1B, "\u{65}\u{330}"),
+    (0x1E1C, "\u{45}\u{327}\u{306}"),
+    (0x1E1D, "\u{65}\u{327}\u{306}"),
+    (0x1E1E, "\u{46}\u{307}"),
+    (0x1E1F, "\u{66}\u{307}"),
+    (0x1E20, "\u{47}\u{304}"),
+    (0x1E21, "\u{67}\u{304}"),
+    (0x1E22, "\u{48}\u{307}"),
+    (0x1E23, "\u{68}\u{307}"),
+    (0x1E24, "\u{48}\u{323}"),
+    (0x1E25, "\u{68}\u{323}"),
+    (0x1E26, "\u{48}\u{308}"),
+    (0x1E27, "\u{68}\u{308}"),
+    (0x1E28, "\u{48}\u{327}"),
+    (0x1E29, "\u{68}\u{327}"),
+    (0x1E2A, "\u{48}\u{32e}"),
+    (0x1E2B, "\u{68}\u{32e}"),
+    (0x1E2C, "\u{49}\u{330}"),
+    (0x1E2D, "\u{69}\u{330}"),
+    (0x1E2E, "\u{49}\u{308}\u{301}"),
+    (0x1E2F, "\u{69}\u{308}\u{301}"),
+    (0x1E30, "\u{4b}\u{301}"),
+    (0x1E31, "\u{6b}\u{301}"),
+    (0x1E32, "\u{4b}\u{323}"),
+    (0x1E33, "\u{6b}\u{323}"),
+    (0x1E34, "\u{4b}\u{331}"),
+    (0x1E35, "\u{6b}\u{331}"),
+    (0x1E36, "\u{4c}\u{323}"),
+    (0x1E37, "\u{6c}\u{323}"),
+    (0x1E38, "\u{4c}\u{323}\u{304}"),
+    (0x1E39, "\u{6c}\u{323}\u{304}"),
+    (0x1E3A, "\u{4c}\u{331}"),
+    (0x1E3B, "\u{6c}\u{331}"),
+    (0x1E3C, "\u{4c}\u{32d}"),
+    (0x1E3D, "\u{6c}\u{32d}"),
+    (0x1E3E, "\u{4d}\u{301}"),
+    (0x1E3F, "\u{6d}\u{301}"),
+    (0x1E40, "\u{4d}\u{307}"),
+    (0x1E41, "\u{6d}\u{307}"),
+    (0x1E42, "\u{4d}\u{323}"),
+    (0x1E43, "\u{6d}\u{323}"),
+    (0x1E44, "\u{4e}\u{307}"),
+    (0x1E45, "\u{6e}\u{307}"),
+    (0x1E46, "\u{4e}\u{323}"),
+    (0x1E47, "\u{6e}\u{323}"),
+    (0x1E48, "\u{4e}\u{331}"),
+    (0x1E49, "\u{6e}\u{331}"),
+    (0x1E4A, "\u{4e}\u{32d}"),
+    (0x1E4B, "\u{6e}\u{32d}"),
+    (0x1E4C, "\u{4f}\u{303}\u{301}"),
+    (0x1E4D, "\u{6f}\u{303}\u{301}"),
+    (0x1E4E, "\u{4f}\u{303}\u{308}"),
+    (0x1E4F, "\u{6f}\u{303}\u{308}"),
+    (0x1E50, "\u{4f}\u{304}\u{300}"),
+    (0x1E51, "\u{6f}\u{304}\u{300}"),
+    (0x1E52, "\u{4f}\u{304}\u{301}"),
+    (0x1E53, "\u{6f}\u{304}\u{301}"),
+    (0x1E54, "\u{50}\u{301}"),
+    (0x1E55, "\u{70}\u{301}"),
+    (0x1E56, "\u{50}\u{307}"),
+    (0x1E57, "\u{70}\u{307}"),
+    (0x1E58, "\u{52}\u{307}"),
+    (0x1E59, "\u{72}\u{307}"),
+    (0x1E5A, "\u{52}\u{323}"),
+    (0x1E5B, "\u{72}\u{323}"),
+    (0x1E5C, "\u{52}\u{323}\u{304}"),
+    (0x1E5D, "\u{72}\u{323}\u{304}"),
+    (0x1E5E, "\u{52}\u{331}"),
+    (0x1E5F, "\u{72}\u{331}"),
+    (0x1E60, "\u{53}\u{307}"),
+    (0x1E61, "\u{73}\u{307}"),
+    (0x1E62, "\u{53}\u{323}"),
+    (0x1E63, "\u{73}\u{323}"),
+    (0x1E64, "\u{53}\u{301}\u{307}"),
+    (0x1E65, "\u{73}\u{301}\u{307}"),
+    (0x1E66, "\u{53}\u{30c}\u{307}"),
+    (0x1E67, "\u{73}\u{30c}\u{307}"),
+    (0x1E68, "\u{53}\u{323}\u{307}"),
+    (0x1E69, "\u{73}\u{323}\u{307}"),
+    (0x1E6A, "\u{54}\u{307}"),
+    (0x1E6B, "\u{74}\u{307}"),
+    (0x1E6C, "\u{54}\u{323}"),
+    (0x1E6D, "\u{74}\u{323}"),
+    (0x1E6E, "\u{54}\u{331}"),
+    (0x1E6F, "\u{74}\u{331}"),
+    (0x1E70, "\u{54}\u{32d}"),
+    (0x1E71, "\u{74}\u{32d}"),
+    (0x1E72, "\u{55}\u{324}"),
+    (0x1E73, "\u{75}\u{324}"),
+    (0x1E74, "\u{55}\u{330}"),
+    (0x1E75, "\u{75}\u{330}"),
+    (0x1E76, "\u{55}\u{32d}"),
+    (0x1E77, "\u{75}\u{32d}"),
+    (0x1E78, "\u{55}\u{303}\u{301}"),
+    (0x1E79, "\u{75}\u{303}\u{301}"),
+    (0x1E7A, "\u{55}\u{304}\u{308}"),
+    (0x1E7B, "\u{75}\u{304}\u{308}"),
+    (0x1E7C, "\u{56}\u{303}"),
+    (0x1E7D, "\u{76}\u{303}"),
+    (0x1E7E, "\u{56}\u{323}"),
+    (0x1E7F, "\u{76}\u{323}"),
+    (0x1E80, "\u{57}\u{300}"),
+    (0x1E81, "\u{77}\u{300}"),
+    (0x1E82, "\u{57}\u{301}"),
+    (0x1E83, "\u{77}\u{301}"),
+    (0x1E84, "\u{57}\u{308}"),
+    (0x1E85, "\u{77}\u{308}"),
+    (0x1E86, "\u{57}\u{307}"),
+    (0x1E87, "\u{77}\u{307}"),
+    (0x1E88, "\u{57}\u{323}"),
+    (0x1E89, "\u{77}\u{323}"),
+    (0x1E8A, "\u{58}\u{307}"),
+    (0x1E8B, "\u{78}\u{307}"),
+    (0x1E8C, "\u{58}\u{308}"),
+    (0x1E8D, "\u{78}\u{308}"),
+    (0x1E8E, "\u{59}\u{307}"),
+    (0x1E8F, "\u{79}\u{307}"),
+    (0x1E90, "\u{5a}\u{302}"),
+    (0x1E91, "\u{7a}\u{302}"),
+    (0x1E92, "\u{5a}\u{323}"),
+    (0x1E93, "\u{7a}\u{323}"),
+    (0x1E94, "\u{5a}\u{331}"),
+    (0x1E95, "\u{7a}\u{331}"),
+    (0x1E96, "\u{68}\u{331}"),
+    (0x1E97, "\u{74}\u{308}"),
+    (0x1E98, "\u{77}\u{30a}"),
+    (0x1E99, "\u{79}\u{30a}"),
+    (0x1E9B, "\u{17f}\u{307}"),
+    (0x1EA0, "\u{41}\u{323}"),
+    (0x1EA1, "\u{61}\u{323}"),
+    (0x1EA2, "\u{41}\u{309}"),
+    (0x1EA3, "\u{61}\u{309}"),
+    (0x1EA4, "\u{41}\u{302}\u{301}"),
+    (0x1EA5, "\u{61}\u{302}\u{301}"),
+    (0x1EA6, "\u{41}\u{302}\u{300}"),
+    (0x1EA7, "\u{61}\u{302}\u{300}"),
+    (0x1EA8, "\u{41}\u{302}\u{309}"),
+    (0x1EA9, "\u{61}\u{302}\u{309}"),
+    (0x1EAA, "\u{41}\u{302}\u{303}"),
+    (0x1EAB, "\u{61}\u{302}\u{303}"),
+    (0x1EAC, "\u{41}\u{323}\u{302}"),
+    (0x1EAD, "\u{61}\u{323}\u{302}"),
+    (0x1EAE, "\u{41}\u{306}\u{301}"),
+    (0x1EAF, "\u{61}\u{306}\u{301}"),
+    (0x1EB0, "\u{41}\u{306}\u{300}"),
+    (0x1EB1, "\u{61}\u{306}\u{300}"),
+    (0x1EB2, "\u{41}\u{306}\u{309}"),
+    (0x1EB3, "\u{61}\u{306}\u{309}"),
+    (0x1EB4, "\u{41}\u{306}\u{303}"),
+    (0x1EB5, "\u{61}\u{306}\u{303}"),
+    (0x1EB6, "\u{41}\u{323}\u{306}"),
+    (0x1EB7, "\u{61}\u{323}\u{306}"),
+    (0x1EB8, "\u{45}\u{323}"),
+    (0x1EB9, "\u{65}\u{323}"),
+    (0x1EBA, "\u{45}\u{309}"),
+    (0x1EBB, "\u{65}\u{309}"),
+    (0x1EBC, "\u{45}\u{303}"),
+    (0x1EBD, "\u{65}\u{303}"),
+    (0x1EBE, "\u{45}\u{302}\u{301}"),
+    (0x1EBF, "\u{65}\u{302}\u{301}"),
+    (0x1EC0, "\u{45}\u{302}\u{300}"),
+    (0x1EC1, "\u{65}\u{302}\u{300}"),
+    (0x1EC2, "\u{45}\u{302}\u{309}"),
+    (0x1EC3, "\u{65}\u{302}\u{309}"),
+    (0x1EC4, "\u{45}\u{302}\u{303}"),
+    (0x1EC5, "\u{65}\u{302}\u{303}"),
+    (0x1EC6, "\u{45}\u{323}\u{302}"),
+    (0x1EC7, "\u{65}\u{323}\u{302}"),
+    (0x1EC8, "\u{49}\u{309}"),
+    (0x1EC9, "\u{69}\u{309}"),
+    (0x1ECA, "\u{49}\u{323}"),
+    (0x1ECB, "\u{69}\u{323}"),
+    (0x1ECC, "\u{4f}\u{323}"),
+    (0x1ECD, "\u{6f}\u{323}"),
+    (0x1ECE, "\u{4f}\u{309}"),
+    (0x1ECF, "\u{6f}\u{309}"),
+    (0x1ED0, "\u{4f}\u{302}\u{301}"),
+    (0x1ED1, "\u{6f}\u{302}\u{301}"),
+    (0x1ED2, "\u{4f}\u{302}\u{300}"),
+    (0x1ED3, "\u{6f}\u{302}\u{300}"),
+    (0x1ED4, "\u{4f}\u{302}\u{309}"),
+    (0x1ED5, "\u{6f}\u{302}\u{309}"),
+    (0x1ED6, "\u{4f}\u{302}\u{303}"),
+    (0x1ED7, "\u{6f}\u{302}\u{303}"),
+    (0x1ED8, "\u{4f}\u{323}\u{302}"),
+    (0x1ED9, "\u{6f}\u{323}\u{302}"),
+    (0x1EDA, "\u{4f}\u{31b}\u{301}"),
+    (0x1EDB, "\u{6f}\u{31b}\u{301}"),
+    (0x1EDC, "\u{4f}\u{31b}\u{300}"),
+    (0x1EDD, "\u{6f}\u{31b}\u{300}"),
+    (0x1EDE, "\u{4f}\u{31b}\u{309}"),
+    (0x1EDF, "\u{6f}\u{31b}\u{309}"),
+    (0x1EE0, "\u{4f}\u{31b}\u{303}"),
+    (0x1EE1, "\u{6f}\u{31b}\u{303}"),
+    (0x1EE2, "\u{4f}\u{31b}\u{323}"),
+    (0x1EE3, "\u{6f}\u{31b}\u{323}"),
+    (0x1EE4, "\u{55}\u{323}"),
+    (0x1EE5, "\u{75}\u{323}"),
+    (0x1EE6, "\u{55}\u{309}"),
+    (0x1EE7, "\u{75}\u{309}"),
+    (0x1EE8, "\u{55}\u{31b}\u{301}"),
+    (0x1EE9, "\u{75}\u{31b}\u{301}"),
+    (0x1EEA, "\u{55}\u{31b}\u{300}"),
+    (0x1EEB, "\u{75}\u{31b}\u{300}"),
+    (0x1EEC, "\u{55}\u{31b}\u{309}"),
+    (0x1EED, "\u{75}\u{31b}\u{309}"),
+    (0x1EEE, "\u{55}\u{31b}\u{303}"),
+    (0x1EEF, "\u{75}\u{31b}\u{303}"),
+    (0x1EF0, "\u{55}\u{31b}\u{323}"),
+    (0x1EF1, "\u{75}\u{31b}\u{323}"),
+    (0x1EF2, "\u{59}\u{300}"),
+    (0x1EF3, "\u{79}\u{300}"),
+    (0x1EF4, "\u{59}\u{323}"),
+    (0x1EF5, "\u{79}\u{323}"),
+    (0x1EF6, "\u{59}\u{309}"),
+    (0x1EF7, "\u{79}\u{309}"),
+    (0x1EF8, "\u{59}\u{303}"),
+    (0x1EF9, "\u{79}\u{303}"),
+    (0x1F00, "\u{3b1}\u{313}"),
+    (0x1F01, "\u{3b1}\u{314}"),
+    (0x1F02, "\u{3b1}\u{313}\u{300}"),
+    (0x1F03, "\u{3b1}\u{314}\u{300}"),
+    (0x1F04, "\u{3b1}\u{313}\u{301}"),
+    (0x1F05, "\u{3b1}\u{314}\u{301}"),
+    (0x1F06, "\u{3b1}\u{313}\u{342}"),
+    (0x1F07, "\u{3b1}\u{314}\u{342}"),
+    (0x1F08, "\u{391}\u{313}"),
+    (0x1F09, "\u{391}\u{314}"),
+    (0x1F0A, "\u{391}\u{313}\u{300}"),
+    (0x1F0B, "\u{391}\u{314}\u{300}"),
+    (0x1F0C, "\u{391}\u{313}\u{301}"),
+    (0x1F0D, "\u{391}\u{314}\u{301}"),
+    (0x1F0E, "\u{391}\u{313}\u{342}"),
+    (0x1F0F, "\u{391}\u{314}\u{342}"),
+    (0x1F10, "\u{3b5}\u{313}"),
+    (0x1F11, "\u{3b5}\u{314}"),
+    (0x1F12, "\u{3b5}\u{313}\u{300}"),
+    (0x1F13, "\u{3b5}\u{314}\u{300}"),
+    (0x1F14, "\u{3b5}\u{313}\u{301}"),
+    (0x1F15, "\u{3b5}\u{314}\u{301}"),
+    (0x1F18, "\u{395}\u{313}"),
+    (0x1F19, "\u{395}\u{314}"),
+    (0x1F1A, "\u{395}\u{313}\u{300}"),
+    (0x1F1B, "\u{395}\u{314}\u{300}"),
+    (0x1F1C, "\u{395}\u{313}\u{301}"),
+    (0x1F1D, "\u{395}\u{314}\u{301}"),
+    (0x1F20, "\u{3b7}\u{313}"),
+    (0x1F21, "\u{3b7}\u{314}"),
+    (0x1F22, "\u{3b7}\u{313}\u{300}"),
+    (0x1F23, "\u{3b7}\u{314}\u{300}"),
+    (0x1F24, "\u{3b7}\u{313}\u{301}"),
+    (0x1F25, "\u{3b7}\u{314}\u{301}"),
+    (0x1F26, "\u{3b7}\u{313}\u{342}"),
+    (0x1F27, "\u{3b7}\u{314}\u{342}"),
+    (0x1F28, "\u{397}\u{313}"),
+    (0x1F29, "\u{397}\u{314}"),
+    (0x1F2A, "\u{397}\u{313}\u{300}"),
+    (0x1F2B, "\u{397}\u{314}\u{300}"),
+    (0x1F2C, "\u{397}\u{313}\u{301}"),
+    (0x1F2D, "\u{397}\u{314}\u{301}"),
+    (0x1F2E, "\u{397}\u{313}\u{342}"),
+    (0x1F2F, "\u{397}\u{314}\u{342}"),
+    (0x1F30, "\u{3b9}\u{313}"),
+    (0x1F31, "\u{3b9}\u{314}"),
+    (0x1F32, "\u{3b9}\u{313}\u{300}"),
+    (0x1F33, "\u{3b9}\u{314}\u{300}"),
+    (0x1F34, "\u{3b9}\u{313}\u{301}"),
+    (0x1F35, "\u{3b9}\u{314}\u{301}"),
+    (0x1F36, "\u{3b9}\u{313}\u{342}"),
+    (0x1F37, "\u{3b9}\u{314}\u{342}"),
+    (0x1F38, "\u{399}\u{313}"),
+    (0x1F39, "\u{399}\u{314}"),
+    (0x1F3A, "\u{399}\u{313}\u{300}"),
+    (0x1F3B, "\u{399}\u{314}\u{300}"),
+    (0x1F3C, "\u{399}\u{313}\u{301}"),
+    (0x1F3D, "\u{399}\u{314}\u{301}"),
+    (0x1F3E, "\u{399}\u{313}\u{342}"),
+    (0x1F3F, "\u{399}\u{314}\u{342}"),
+    (0x1F40, "\u{3bf}\u{313}"),
+    (0x1F41, "\u{3bf}\u{314}"),
+    (0x1F42, "\u{3bf}\u{313}\u{300}"),
+    (0x1F43, "\u{3bf}\u{314}\u{300}"),
+    (0x1F44, "\u{3bf}\u{313}\u{301}"),
+    (0x1F45, "\u{3bf}\u{314}\u{301}"),
+    (0x1F48, "\u{39f}\u{313}"),
+    (0x1F49, "\u{39f}\u{314}"),
+    (0x1F4A, "\u{39f}\u{313}\u{300}"),
+    (0x1F4B, "\u{39f}\u{314}\u{300}"),
+    (0x1F4C, "\u{39f}\u{313}\u{301}"),
+    (0x1F4D, "\u{39f}\u{314}\u{301}"),
+    (0x1F50, "\u{3c5}\u{313}"),
+    (0x1F51, "\u{3c5}\u{314}"),
+    (0x1F52, "\u{3c5}\u{313}\u{300}"),
+    (0x1F53, "\u{3c5}\u{314}\u{300}"),
+    (0x1F54, "\u{3c5}\u{313}\u{301}"),
+    (0x1F55, "\u{3c5}\u{314}\u{301}"),
+    (0x1F56, "\u{3c5}\u{313}\u{342}"),
+    (0x1F57, "\u{3c5}\u{314}\u{342}"),
+    (0x1F59, "\u{3a5}\u{314}"),
+    (0x1F5B, "\u{3a5}\u{314}\u{300}"),
+    (0x1F5D, "\u{3a5}\u{314}\u{301}"),
+    (0x1F5F, "\u{3a5}\u{314}\u{342}"),
+    (0x1F60, "\u{3c9}\u{313}"),
+    (0x1F61, "\u{3c9}\u{314}"),
+    (0x1F62, "\u{3c9}\u{313}\u{300}"),
+    (0x1F63, "\u{3c9}\u{314}\u{300}"),
+    (0x1F64, "\u{3c9}\u{313}\u{301}"),
+    (0x1F65, "\u{3c9}\u{314}\u{301}"),
+    (0x1F66, "\u{3c9}\u{313}\u{342}"),
+    (0x1F67, "\u{3c9}\u{314}\u{342}"),
+    (0x1F68, "\u{3a9}\u{313}"),
+    (0x1F69, "\u{3a9}\u{314}"),
+    (0x1F6A, "\u{3a9}\u{313}\u{300}"),
+    (0x1F6B, "\u{3a9}\u{314}\u{300}"),
+    (0x1F6C, "\u{3a9}\u{313}\u{301}"),
+    (0x1F6D, "\u{3a9}\u{314}\u{301}"),
+    (0x1F6E, "\u{3a9}\u{313}\u{342}"),
+    (0x1F6F, "\u{3a9}\u{314}\u{342}"),
+    (0x1F70, "\u{3b1}\u{300}"),
+    (0x1F71, "\u{3b1}\u{301}"),
+    (0x1F72, "\u{3b5}\u{300}"),
+    (0x1F73, "\u{3b5}\u{301}"),
+    (0x1F74, "\u{3b7}\u{300}"),
+    (0x1F75, "\u{3b7}\u{301}"),
+    (0x1F76, "\u{3b9}\u{300}"),
+    (0x1F77, "\u{3b9}\u{301}"),
+    (0x1F78, "\u{3bf}\u{300}"),
+    (0x1F79, "\u{3bf}\u{301}"),
+    (0x1F7A, "\u{3c5}\u{300}"),
+    (0x1F7B, "\u{3c5}\u{301}"),
+    (0x1F7C, "\u{3c9}\u{300}"),
+    (0x1F7D, "\u{3c9}\u{301}"),
+    (0x1F80, "\u{3b1}\u{313}\u{345}"),
+    (0x1F81, "\u{3b1}\u{314}\u{345}"),
+    (0x1F82, "\u{3b1}\u{313}\u{300}\u{345}"),
+    (0x1F83, "\u{3b1}\u{314}\u{300}\u{345}"),
+    (0x1F84, "\u{3b1}\u{313}\u{301}\u{345}"),
+    (0x1F85, "\u{3b1}\u{314}\u{301}\u{345}"),
+    (0x1F86, "\u{3b1}\u{313}\u{342}\u{345}"),
+    (0x1F87, "\u{3b1}\u{314}\u{342}\u{345}"),
+    (0x1F88, "\u{391}\u{313}\u{345}"),
+    (0x1F89, "\u{391}\u{314}\u{345}"),
+    (0x1F8A, "\u{391}\u{313}\u{300}\u{345}"),
+    (0x1F8B, "\u{391}\u{314}\u{300}\u{345}"),
+    (0x1F8C, "\u{391}\u{313}\u{301}\u{345}"),
+    (0x1F8D, "\u{391}\u{314}\u{301}\u{345}"),
+    (0x1F8E, "\u{391}\u{313}\u{342}\u{345}"),
+    (0x1F8F, "\u{391}\u{314}\u{342}\u{345}"),
+    (0x1F90, "\u{3b7}\u{313}\u{345}"),
+    (0x1F91, "\u{3b7}\u{314}\u{345}"),
+    (0x1F92, "\u{3b7}\u{313}\u{300}\u{345}"),
+    (0x1F93, "\u{3b7}\u{314}\u{300}\u{345}"),
+    (0x1F94, "\u{3b7}\u{313}\u{301}\u{345}"),
+    (0x1F95, "\u{3b7}\u{314}\u{301}\u{345}"),
+    (0x1F96, "\u{3b7}\u{313}\u{342}\u{345}"),
+    (0x1F97, "\u{3b7}\u{314}\u{342}\u{345}"),
+    (0x1F98, "\u{397}\u{313}\u{345}"),
+    (0x1F99, "\u{397}\u{314}\u{345}"),
+    (0x1F9A, "\u{397}\u{313}\u{300}\u{345}"),
+    (0x1F9B, "\u{397}\u{314}\u{300}\u{345}"),
+    (0x1F9C, "\u{397}\u{313}\u{301}\u{345}"),
+    (0x1F9D, "\u{397}\u{314}\u{301}\u{345}"),
+    (0x1F9E, "\u{397}\u{313}\u{342}\u{345}"),
+    (0x1F9F, "\u{397}\u{314}\u{342}\u{345}"),
+    (0x1FA0, "\u{3c9}\u{313}\u{345}"),
+    (0x1FA1, "\u{3c9}\u{314}\u{345}"),
+    (0x1FA2, "\u{3c9}\u{313}\u{300}\u{345}"),
+    (0x1FA3, "\u{3c9}\u{314}\u{300}\u{345}"),
+    (0x1FA4, "\u{3c9}\u{313}\u{301}\u{345}"),
+    (0x1FA5, "\u{3c9}\u{314}\u{301}\u{345}"),
+    (0x1FA6, "\u{3c9}\u{313}\u{342}\u{345}"),
+    (0x1FA7, "\u{3c9}\u{314}\u{342}\u{345}"),
+    (0x1FA8, "\u{3a9}\u{313}\u{345}"),
+    (0x1FA9, "\u{3a9}\u{314}\u{345}"),
+    (0x1FAA, "\u{3a9}\u{313}\u{300}\u{345}"),
+    (0x1FAB, "\u{3a9}\u{314}\u{300}\u{345}"),
+    (0x1FAC, "\u{3a9}\u{313}\u{301}\u{345}"),
+    (0x1FAD, "\u{3a9}\u{314}\u{301}\u{345}"),
+    (0x1FAE, "\u{3a9}\u{313}\u{342}\u{345}"),
+    (0x1FAF, "\u{3a9}\u{314}\u{342}\u{345}"),
+    (0x1FB0, "\u{3b1}\u{306}"),
+    (0x1FB1, "\u{3b1}\u{304}"),
+    (0x1FB2, "\u{3b1}\u{300}\u{345}"),
+    (0x1FB3, "\u{3b1}\u{345}"),
+    (0x1FB4, "\u{3b1}\u{301}\u{345}"),
+    (0x1FB6, "\u{3b1}\u{342}"),
+    (0x1FB7, "\u{3b1}\u{342}\u{345}"),
+    (0x1FB8, "\u{391}\u{306}"),
+    (0x1FB9, "\u{391}\u{304}"),
+    (0x1FBA, "\u{391}\u{300}"),
+    (0x1FBB, "\u{391}\u{301}"),
+    (0x1FBC, "\u{391}\u{345}"),
+    (0x1FBE, "\u{3b9}"),
+    (0x1FC1, "\u{a8}\u{342}"),
+    (0x1FC2, "\u{3b7}\u{300}\u{345}"),
+    (0x1FC3, "\u{3b7}\u{345}"),
+    (0x1FC4, "\u{3b7}\u{301}\u{345}"),
+    (0x1FC6, "\u{3b7}\u{342}"),
+    (0x1FC7, "\u{3b7}\u{342}\u{345}"),
+    (0x1FC8, "\u{395}\u{300}"),
+    (0x1FC9, "\u{395}\u{301}"),
+    (0x1FCA, "\u{397}\u{300}"),
+    (0x1FCB, "\u{397}\u{301}"),
+    (0x1FCC, "\u{397}\u{345}"),
+    (0x1FCD, "\u{1fbf}\u{300}"),
+    (0x1FCE, "\u{1fbf}\u{301}"),
+    (0x1FCF, "\u{1fbf}\u{342}"),
+    (0x1FD0, "\u{3b9}\u{306}"),
+    (0x1FD1, "\u{3b9}\u{304}"),
+    (0x1FD2, "\u{3b9}\u{308}\u{300}"),
+    (0x1FD3, "\u{3b9}\u{308}\u{301}"),
+    (0x1FD6, "\u{3b9}\u{342}"),
+    (0x1FD7, "\u{3b9}\u{308}\u{342}"),
+    (0x1FD8, "\u{399}\u{306}"),
+    (0x1FD9, "\u{399}\u{304}"),
+    (0x1FDA, "\u{399}\u{300}"),
+    (0x1FDB, "\u{399}\u{301}"),
+    (0x1FDD, "\u{1ffe}\u{300}"),
+    (0x1FDE, "\u{1ffe}\u{301}"),
+    (0x1FDF, "\u{1ffe}\u{342}"),
+    (0x1FE0, "\u{3c5}\u{306}"),
+    (0x1FE1, "\u{3c5}\u{304}"),
+    (0x1FE2, "\u{3c5}\u{308}\u{300}"),
+    (0x1FE3, "\u{3c5}\u{308}\u{301}"),
+    (0x1FE4, "\u{3c1}\u{313}"),
+    (0x1FE5, "\u{3c1}\u{314}"),
+    (0x1FE6, "\u{3c5}\u{342}"),
+    (0x1FE7, "\u{3c5}\u{308}\u{342}"),
+    (0x1FE8, "\u{3a5}\u{306}"),
+    (0x1FE9, "\u{3a5}\u{304}"),
+    (0x1FEA, "\u{3a5}\u{300}"),
+    (0x1FEB, "\u{3a5}\u{301}"),
+    (0x1FEC, "\u{3a1}\u{314}"),
+    (0x1FED, "\u{a8}\u{300}"),
+    (0x1FEE, "\u{a8}\u{301}"),
+    (0x1FEF, "\u{60}"),
+    (0x1FF2, "\u{3c9}\u{300}\u{345}"),
+    (0x1FF3, "\u{3c9}\u{345}"),
+    (0x1FF4, "\u{3c9}\u{301}\u{345}"),
+    (0x1FF6, "\u{3c9}\u{342}"),
+    (0x1FF7, "\u{3c9}\u{342}\u{345}"),
+    (0x1FF8, "\u{39f}\u{300}"),
+    (0x1FF9, "\u{39f}\u{301}"),
+    (0x1FFA, "\u{3a9}\u{300}"),
+    (0x1FFB, "\u{3a9}\u{301}"),
+    (0x1FFC, "\u{3a9}\u{345}"),
+    (0x1FFD, "\u{b4}"),
+    (0x2000, "\u{2002}"),
+    (0x2001, "\u{2003}"),
+    (0x2126, "\u{3a9}"),
+    (0x212A, "\u{4b}"),
+    (0x212B, "\u{41}\u{30a}"),
+    (0x219A, "\u{2190}\u{338}"),
+    (0x219B, "\u{2192}\u{338}"),
+    (0x21AE, "\u{2194}\u{338}"),
+    (0x21CD, "\u{21d0}\u{338}"),
+    (0x21CE, "\u{21d4}\u{338}"),
+    (0x21CF, "\u{21d2}\u{338}"),
+    (0x2204, "\u{2203}\u{338}"),
+    (0x2209, "\u{2208}\u{338}"),
+    (0x220C, "\u{220b}\u{338}"),
+    (0x2224, "\u{2223}\u{338}"),
+    (0x2226, "\u{2225}\u{338}"),
+    (0x2241, "\u{223c}\u{338}"),
+    (0x2244, "\u{2243}\u{338}"),
+    (0x2247, "\u{2245}\u{338}"),
+    (0x2249, "\u{2248}\u{338}"),
+    (0x2260, "\u{3d}\u{338}"),
+    (0x2262, "\u{2261}\u{338}"),
+    (0x226D, "\u{224d}\u{338}"),
+    (0x226E, "\u{3c}\u{338}"),
+    (0x226F, "\u{3e}\u{338}"),
+    (0x2270, "\u{2264}\u{338}"),
+    (0x2271, "\u{2265}\u{338}"),
+    (0x2274, "\u{2272}\u{338}"),
+    (0x2275, "\u{2273}\u{338}"),
+    (0x2278, "\u{2276}\u{338}"),
+    (0x2279, "\u{2277}\u{338}"),
+    (0x2280, "\u{227a}\u{338}"),
+    (0x2281, "\u{227b}\u{338}"),
+    (0x2284, "\u{2282}\u{338}"),
+    (0x2285, "\u{2283}\u{338}"),
+    (0x2288, "\u{2286}\u{338}"),
+    (0x2289, "\u{2287}\u{338}"),
+    (0x22AC, "\u{22a2}\u{338}"),
+    (0x22AD, "\u{22a8}\u{338}"),
+    (0x22AE, "\u{22a9}\u{338}"),
+    (0x22AF, "\u{22ab}\u{338}"),
+    (0x22E0, "\u{227c}\u{338}"),
+    (0x22E1, "\u{227d}\u{338}"),
+    (0x22E2, "\u{2291}\u{338}"),
+    (0x22E3, "\u{2292}\u{338}"),
+    (0x22EA, "\u{22b2}\u{338}"),
+    (0x22EB, "\u{22b3}\u{338}"),
+    (0x22EC, "\u{22b4}\u{338}"),
+    (0x22ED, "\u{22b5}\u{338}"),
+    (0x2329, "\u{3008}"),
+    (0x232A, "\u{3009}"),
+    (0x2ADC, "\u{2add}\u{338}"),
+    (0x304C, "\u{304b}\u{3099}"),
+    (0x304E, "\u{304d}\u{3099}"),
+    (0x3050, "\u{304f}\u{3099}"),
+    (0x3052, "\u{3051}\u{3099}"),
+    (0x3054, "\u{3053}\u{3099}"),
+    (0x3056, "\u{3055}\u{3099}"),
+    (0x3058, "\u{3057}\u{3099}"),
+    (0x305A, "\u{3059}\u{3099}"),
+    (0x305C, "\u{305b}\u{3099}"),
+    (0x305E, "\u{305d}\u{3099}"),
+    (0x3060, "\u{305f}\u{3099}"),
+    (0x3062, "\u{3061}\u{3099}"),
+    (0x3065, "\u{3064}\u{3099}"),
+    (0x3067, "\u{3066}\u{3099}"),
+    (0x3069, "\u{3068}\u{3099}"),
+    (0x3070, "\u{306f}\u{3099}"),
+    (0x3071, "\u{306f}\u{309a}"),
+    (0x3073, "\u{3072}\u{3099}"),
+    (0x3074, "\u{3072}\u{309a}"),
+    (0x3076, "\u{3075}\u{3099}"),
+    (0x3077, "\u{3075}\u{309a}"),
+    (0x3079, "\u{3078}\u{3099}"),
+    (0x307A, "\u{3078}\u{309a}"),
+    (0x307C, "\u{307b}\u{3099}"),
+    (0x307D, "\u{307b}\u{309a}"),
+    (0x3094, "\u{3046}\u{3099}"),
+    (0x309E, "\u{309d}\u{3099}"),
+    (0x30AC, "\u{30ab}\u{3099}"),
+    (0x30AE, "\u{30ad}\u{3099}"),
+    (0x30B0, "\u{30af}\u{3099}"),
+    (0x30B2, "\u{30b1}\u{3099}"),
+    (0x30B4, "\u{30b3}\u{3099}"),
+    (0x30B6, "\u{30b5}\u{3099}"),
+    (0x30B8, "\u{30b7}\u{3099}"),
+    (0x30BA, "\u{30b9}\u{3099}"),
+    (0x30BC, "\u{30bb}\u{3099}"),
+    (0x30BE, "\u{30bd}\u{3099}"),
+    (0x30C0, "\u{30bf}\u{3099}"),
+    (0x30C2, "\u{30c1}\u{3099}"),
+    (0x30C5, "\u{30c4}\u{3099}"),
+    (0x30C7, "\u{30c6}\u{3099}"),
+    (0x30C9, "\u{30c8}\u{3099}"),
+    (0x30D0, "\u{30cf}\u{3099}"),
+    (0x30D1, "\u{30cf}\u{309a}"),
+    (0x30D3, "\u{30d2}\u{3099}"),
+    (0x30D4, "\u{30d2}\u{309a}"),
+    (0x30D6, "\u{30d5}\u{3099}"),
+    (0x30D7, "\u{30d5}\u{309a}"),
+    (0x30D9, "\u{30d8}\u{3099}"),
+    (0x30DA, "\u{30d8}\u{309a}"),
+    (0x30DC, "\u{30db}\u{3099}"),
+    (0x30DD, "\u{30db}\u{309a}"),
+    (0x30F4, "\u{30a6}\u{3099}"),
+    (0x30F7, "\u{30ef}\u{3099}"),
+    (0x30F8, "\u{30f0}\u{3099}"),
+    (0x30F9, "\u{30f1}\u{3099}"),
+    (0x30FA, "\u{30f2}\u{3099}"),
+    (0x30FE, "\u{30fd}\u{3099}"),
+    (0xF900, "\u{8c48}"),
+    (0xF901, "\u{66f4}"),
+    (0xF902, "\u{8eca}"),
+    (0xF903, "\u{8cc8}"),
+    (0xF904, "\u{6ed1}"),
+    (0xF905, "\u{4e32}"),
+    (0xF906, "\u{53e5}"),
+    (0xF907, "\u{9f9c}"),
+    (0xF908, "\u{9f9c}"),
+    (0xF909, "\u{5951}"),
+    (0xF90A, "\u{91d1}"),
+    (0xF90B, "\u{5587}"),
+    (0xF90C, "\u{5948}"),
+    (0xF90D, "\u{61f6}"),
+    (0xF90E, "\u{7669}"),
+    (0xF90F, "\u{7f85}"),
+    (0xF910, "\u{863f}"),
+    (0xF911, "\u{87ba}"),
+    (0xF912, "\u{88f8}"),
+    (0xF913, "\u{908f}"),
+    (0xF914, "\u{6a02}"),
+    (0xF915, "\u{6d1b}"),
+    (0xF916, "\u{70d9}"),
+    (0xF917, "\u{73de}"),
+    (0xF918, "\u{843d}"),
+    (0xF919, "\u{916a}"),
+    (0xF91A, "\u{99f1}"),
+    (0xF91B, "\u{4e82}"),
+    (0xF91C, "\u{5375}"),
+    (0xF91D, "\u{6b04}"),
+    (0xF91E, "\u{721b}"),
+    (0xF91F, "\u{862d}"),
+    (0xF920, "\u{9e1e}"),
+    (0xF921, "\u{5d50}"),
+    (0xF922, "\u{6feb}"),
+    (0xF923, "\u{85cd}"),
+    (0xF924, "\u{8964}"),
+    (0xF925, "\u{62c9}"),
+    (0xF926, "\u{81d8}"),
+    (0xF927, "\u{881f}"),
+    (0xF928, "\u{5eca}"),
+    (0xF929, "\u{6717}"),
+    (0xF92A, "\u{6d6a}"),
+    (0xF92B, "\u{72fc}"),
+    (0xF92C, "\u{90ce}"),
+    (0xF92D, "\u{4f86}"),
+    (0xF92E, "\u{51b7}"),
+    (0xF92F, "\u{52de}"),
+    (0xF930, "\u{64c4}"),
+    (0xF931, "\u{6ad3}"),
+    (0xF932, "\u{7210}"),
+    (0xF933, "\u{76e7}"),
+    (0xF934, "\u{8001}"),
+    (0xF935, "\u{8606}"),
+    (0xF936, "\u{865c}"),
+    (0xF937, "\u{8def}"),
+    (0xF938, "\u{9732}"),
+    (0xF939, "\u{9b6f}"),
+    (0xF93A, "\u{9dfa}"),
+    (0xF93B, "\u{788c}"),
+    (0xF93C, "\u{797f}"),
+    (0xF93D, "\u{7da0}"),
+    (0xF93E, "\u{83c9}"),
+    (0xF93F, "\u{9304}"),
+    (0xF940, "\u{9e7f}"),
+    (0xF941, "\u{8ad6}"),
+    (0xF942, "\u{58df}"),
+    (0xF943, "\u{5f04}"),
+    (0xF944, "\u{7c60}"),
+    (0xF945, "\u{807e}"),
+    (0xF946, "\u{7262}"),
+    (0xF947, "\u{78ca}"),
+    (0xF948, "\u{8cc2}"),
+    (0xF949, "\u{96f7}"),
+    (0xF94A, "\u{58d8}"),
+    (0xF94B, "\u{5c62}"),
+    (0xF94C, "\u{6a13}"),
+    (0xF94D, "\u{6dda}"),
+    (0xF94E, "\u{6f0f}"),
+    (0xF94F, "\u{7d2f}"),
+    (0xF950, "\u{7e37}"),
+    (0xF951, "\u{964b}"),
+    (0xF952, "\u{52d2}"),
+    (0xF953, "\u{808b}"),
+    (0xF954, "\u{51dc}"),
+    (0xF955, "\u{51cc}"),
+    (0xF956, "\u{7a1c}"),
+    (0xF957, "\u{7dbe}"),
+    (0xF958, "\u{83f1}"),
+    (0xF959, "\u{9675}"),
+    (0xF95A, "\u{8b80}"),
+    (0xF95B, "\u{62cf}"),
+    (0xF95C, "\u{6a02}"),
+    (0xF95D, "\u{8afe}"),
+    (0xF95E, "\u{4e39}"),
+    (0xF95F, "\u{5be7}"),
+    (0xF960, "\u{6012}"),
+    (0xF961, "\u{7387}"),
+    (0xF962, "\u{7570}"),
+    (0xF963, "\u{5317}"),
+    (0xF964, "\u{78fb}"),
+    (0xF965, "\u{4fbf}"),
+    (0xF966, "\u{5fa9}"),
+    (0xF967, "\u{4e0d}"),
+    (0xF968, "\u{6ccc}"),
+    (0xF969, "\u{6578}"),
+    (0xF96A, "\u{7d22}"),
+    (0xF96B, "\u{53c3}"),
+    (0xF96C, "\u{585e}"),
+    (0xF96D, "\u{7701}"),
+    (0xF96E, "\u{8449}"),
+    (0xF96F, "\u{8aaa}"),
+    (0xF970, "\u{6bba}"),
+    (0xF971, "\u{8fb0}"),
+    (0xF972, "\u{6c88}"),
+    (0xF973, "\u{62fe}"),
+    (0xF974, "\u{82e5}"),
+    (0xF975, "\u{63a0}"),
+    (0xF976, "\u{7565}"),
+    (0xF977, "\u{4eae}"),
+    (0xF978, "\u{5169}"),
+    (0xF979, "\u{51c9}"),
+    (0xF97A, "\u{6881}"),
+    (0xF97B, "\u{7ce7}"),
+    (0xF97C, "\u{826f}"),
+    (0xF97D, "\u{8ad2}"),
+    (0xF97E, "\u{91cf}"),
+    (0xF97F, "\u{52f5}"),
+    (0xF980, "\u{5442}"),
+    (0xF981, "\u{5973}"),
+    (0xF982, "\u{5eec}"),
+    (0xF983, "\u{65c5}"),
+    (0xF984, "\u{6ffe}"),
+    (0xF985, "\u{792a}"),
+    (0xF986, "\u{95ad}"),
+    (0xF987, "\u{9a6a}"),
+    (0xF988, "\u{9e97}"),
+    (0xF989, "\u{9ece}"),
+    (0xF98A, "\u{529b}"),
+    (0xF98B, "\u{66c6}"),
+    (0xF98C, "\u{6b77}"),
+    (0xF98D, "\u{8f62}"),
+    (0xF98E, "\u{5e74}"),
+    (0xF98F, "\u{6190}"),
+    (0xF990, "\u{6200}"),
+    (0xF991, "\u{649a}"),
+    (0xF992, "\u{6f23}"),
+    (0xF993, "\u{7149}"),
+    (0xF994, "\u{7489}"),
+    (0xF995, "\u{79ca}"),
+    (0xF996, "\u{7df4}"),
+    (0xF997, "\u{806f}"),
+    (0xF998, "\u{8f26}"),
+    (0xF999, "\u{84ee}"),
+    (0xF99A, "\u{9023}"),
+    (0xF99B, "\u{934a}"),
+    (0xF99C, "\u{5217}"),
+    (0xF99D, "\u{52a3}"),
+    (0xF99E, "\u{54bd}"),
+    (0xF99F, "\u{70c8}"),
+    (0xF9A0, "\u{88c2}"),
+    (0xF9A1, "\u{8aaa}"),
+    (0xF9A2, "\u{5ec9}"),
+    (0xF9A3, "\u{5ff5}"),
+    (0xF9A4, "\u{637b}"),
+    (0xF9A5, "\u{6bae}"),
+    (0xF9A6, "\u{7c3e}"),
+    (0xF9A7, "\u{7375}"),
+    (0xF9A8, "\u{4ee4}"),
+    (0xF9A9, "\u{56f9}"),
+    (0xF9AA, "\u{5be7}"),
+    (0xF9AB, "\u{5dba}"),
+    (0xF9AC, "\u{601c}"),
+    (0xF9AD, "\u{73b2}"),
+    (0xF9AE, "\u{7469}"),
+    (0xF9AF, "\u{7f9a}"),
+    (0xF9B0, "\u{8046}"),
+    (0xF9B1, "\u{9234}"),
+    (0xF9B2, "\u{96f6}"),
+    (0xF9B3, "\u{9748}"),
+    (0xF9B4, "\u{9818}"),
+    (0xF9B5, "\u{4f8b}"),
+    (0xF9B6, "\u{79ae}"),
+    (0xF9B7, "\u{91b4}"),
+    (0xF9B8, "\u{96b8}"),
+    (0xF9B9, "\u{60e1}"),
+    (0xF9BA, "\u{4e86}"),
+    (0xF9BB, "\u{50da}"),
+    (0xF9BC, "\u{5bee}"),
+    (0xF9BD, "\u{5c3f}"),
+    (0xF9BE, "\u{6599}"),
+    (0xF9BF, "\u{6a02}"),
+    (0xF9C0, "\u{71ce}"),
+    (0xF9C1, "\u{7642}"),
+    (0xF9C2, "\u{84fc}"),
+    (0xF9C3, "\u{907c}"),
+    (0xF9C4, "\u{9f8d}"),
+    (0xF9C5, "\u{6688}"),
+    (0xF9C6, "\u{962e}"),
+    (0xF9C7, "\u{5289}"),
+    (0xF9C8, "\u{677b}"),
+    (0xF9C9, "\u{67f3}"),
+    (0xF9CA, "\u{6d41}"),
+    (0xF9CB, "\u{6e9c}"),
+    (0xF9CC, "\u{7409}"),
+    (0xF9CD, "\u{7559}"),
+    (0xF9CE, "\u{786b}"),
+    (0xF9CF, "\u{7d10}"),
+    (0xF9D0, "\u{985e}"),
+    (0xF9D1, "\u{516d}"),
+    (0xF9D2, "\u{622e}"),
+    (0xF9D3, "\u{9678}"),
+    (0xF9D4, "\u{502b}"),
+    (0xF9D5, "\u{5d19}"),
+    (0xF9D6, "\u{6dea}"),
+    (0xF9D7, "\u{8f2a}"),
+    (0xF9D8, "\u{5f8b}"),
+    (0xF9D9, "\u{6144}"),
+    (0xF9DA, "\u{6817}"),
+    (0xF9DB, "\u{7387}"),
+    (0xF9DC, "\u{9686}"),
+    (0xF9DD, "\u{5229}"),
+    (0xF9DE, "\u{540f}"),
+    (0xF9DF, "\u{5c65}"),
+    (0xF9E0, "\u{6613}"),
+    (0xF9E1, "\u{674e}"),
+    (0xF9E2, "\u{68a8}"),
+    (0xF9E3, "\u{6ce5}"),
+    (0xF9E4, "\u{7406}"),
+    (0xF9E5, "\u{75e2}"),
+    (0xF9E6, "\u{7f79}"),
+    (0xF9E7, "\u{88cf}"),
+    (0xF9E8, "\u{88e1}"),
+    (0xF9E9, "\u{91cc}"),
+    (0xF9EA, "\u{96e2}"),
+    (0xF9EB, "\u{533f}"),
+    (0xF9EC, "\u{6eba}"),
+    (0xF9ED, "\u{541d}"),
+    (0xF9EE, "\u{71d0}"),
+    (0xF9EF, "\u{7498}"),
+    (0xF9F0, "\u{85fa}"),
+    (0xF9F1, "\u{96a3}"),
+    (0xF9F2, "\u{9c57}"),
+    (0xF9F3, "\u{9e9f}"),
+    (0xF9F4, "\u{6797}"),
+    (0xF9F5, "\u{6dcb}"),
+    (0xF9F6, "\u{81e8}"),
+    (0xF9F7, "\u{7acb}"),
+    (0xF9F8, "\u{7b20}"),
+    (0xF9F9, "\u{7c92}"),
+    (0xF9FA, "\u{72c0}"),
+    (0xF9FB, "\u{7099}"),
+    (0xF9FC, "\u{8b58}"),
+    (0xF9FD, "\u{4ec0}"),
+    (0xF9FE, "\u{8336}"),
+    (0xF9FF, "\u{523a}"),
+    (0xFA00, "\u{5207}"),
+    (0xFA01, "\u{5ea6}"),
+    (0xFA02, "\u{62d3}"),
+    (0xFA03, "\u{7cd6}"),
+    (0xFA04, "\u{5b85}"),
+    (0xFA05, "\u{6d1e}"),
+    (0xFA06, "\u{66b4}"),
+    (0xFA07, "\u{8f3b}"),
+    (0xFA08, "\u{884c}"),
+    (0xFA09, "\u{964d}"),
+    (0xFA0A, "\u{898b}"),
+    (0xFA0B, "\u{5ed3}"),
+    (0xFA0C, "\u{5140}"),
+    (0xFA0D, "\u{55c0}"),
+    (0xFA10, "\u{585a}"),
+    (0xFA12, "\u{6674}"),
+    (0xFA15, "\u{51de}"),
+    (0xFA16, "\u{732a}"),
+    (0xFA17, "\u{76ca}"),
+    (0xFA18, "\u{793c}"),
+    (0xFA19, "\u{795e}"),
+    (0xFA1A, "\u{7965}"),
+    (0xFA1B, "\u{798f}"),
+    (0xFA1C, "\u{9756}"),
+    (0xFA1D, "\u{7cbe}"),
+    (0xFA1E, "\u{7fbd}"),
+    (0xFA20, "\u{8612}"),
+    (0xFA22, "\u{8af8}"),
+    (0xFA25, "\u{9038}"),
+    (0xFA26, "\u{90fd}"),
+    (0xFA2A, "\u{98ef}"),
+    (0xFA2B, "\u{98fc}"),
+    (0xFA2C, "\u{9928}"),
+    (0xFA2D, "\u{9db4}"),
+    (0xFA2E, "\u{90de}"),
+    (0xFA2F, "\u{96b7}"),
+    (0xFA30, "\u{4fae}"),
+    (0xFA31, "\u{50e7}"),
+    (0xFA32, "\u{514d}"),
+    (0xFA33, "\u{52c9}"),
+    (0xFA34, "\u{52e4}"),
+    (0xFA35, "\u{5351}"),
+    (0xFA36, "\u{559d}"),
+    (0xFA37, "\u{5606}"),
+    (0xFA38, "\u{5668}"),
+    (0xFA39, "\u{5840}"),
+    (0xFA3A, "\u{58a8}"),
+    (0xFA3B, "\u{5c64}"),
+    (0xFA3C, "\u{5c6e}"),
+    (0xFA3D, "\u{6094}"),
+    (0xFA3E, "\u{6168}"),
+    (0xFA3F, "\u{618e}"),
+    (0xFA40, "\u{61f2}"),
+    (0xFA41, "\u{654f}"),
+    (0xFA42, "\u{65e2}"),
+    (0xFA43, "\u{6691}"),
+    (0xFA44, "\u{6885}"),
+    (0xFA45, "\u{6d77}"),
+    (0xFA46, "\u{6e1a}"),
+    (0xFA47, "\u{6f22}"),
+    (0xFA48, "\u{716e}"),
+    (0xFA49, "\u{722b}"),
+    (0xFA4A, "\u{7422}"),
+    (0xFA4B, "\u{7891}"),
+    (0xFA4C, "\u{793e}"),
+    (0xFA4D, "\u{7949}"),
+    (0xFA4E, "\u{7948}"),
+    (0xFA4F, "\u{7950}"),
+    (0xFA50, "\u{7956}"),
+    (0xFA51, "\u{795d}"),
+    (0xFA52, "\u{798d}"),
+    (0xFA53, "\u{798e}"),
+    (0xFA54, "\u{7a40}"),
+    (0xFA55, "\u{7a81}"),
+    (0xFA56, "\u{7bc0}"),
+    (0xFA57, "\u{7df4}"),
+    (0xFA58, "\u{7e09}"),
+    (0xFA59, "\u{7e41}"),
+    (0xFA5A, "\u{7f72}"),
+    (0xFA5B, "\u{8005}"),
+    (0xFA5C, "\u{81ed}"),
+    (0xFA5D, "\u{8279}"),
+    (0xFA5E, "\u{8279}"),
+    (0xFA5F, "\u{8457}"),
+    (0xFA60, "\u{8910}"),
+    (0xFA61, "\u{8996}"),
+    (0xFA62, "\u{8b01}"),
+    (0xFA63, "\u{8b39}"),
+    (0xFA64, "\u{8cd3}"),
+    (0xFA65, "\u{8d08}"),
+    (0xFA66, "\u{8fb6}"),
+    (0xFA67, "\u{9038}"),
+    (0xFA68, "\u{96e3}"),
+    (0xFA69, "\u{97ff}"),
+    (0xFA6A, "\u{983b}"),
+    (0xFA6B, "\u{6075}"),
+    (0xFA6C, "\u{242ee}"),
+    (0xFA6D, "\u{8218}"),
+    (0xFA70, "\u{4e26}"),
+    (0xFA71, "\u{51b5}"),
+    (0xFA72, "\u{5168}"),
+    (0xFA73, "\u{4f80}"),
+    (0xFA74, "\u{5145}"),
+    (0xFA75, "\u{5180}"),
+    (0xFA76, "\u{52c7}"),
+    (0xFA77, "\u{52fa}"),
+    (0xFA78, "\u{559d}"),
+    (0xFA79, "\u{5555}"),
+    (0xFA7A, "\u{5599}"),
+    (0xFA7B, "\u{55e2}"),
+    (0xFA7C, "\u{585a}"),
+    (0xFA7D, "\u{58b3}"),
+    (0xFA7E, "\u{5944}"),
+    (0xFA7F, "\u{5954}"),
+    (0xFA80, "\u{5a62}"),
+    (0xFA81, "\u{5b28}"),
+    (0xFA82, "\u{5ed2}"),
+    (0xFA83, "\u{5ed9}"),
+    (0xFA84, "\u{5f69}"),
+    (0xFA85, "\u{5fad}"),
+    (0xFA86, "\u{60d8}"),
+    (0xFA87, "\u{614e}"),
+    (0xFA88, "\u{6108}"),
+    (0xFA89, "\u{618e}"),
+    (0xFA8A, "\u{6160}"),
+    (0xFA8B, "\u{61f2}"),
+    (0xFA8C, "\u{6234}"),
+    (0xFA8D, "\u{63c4}"),
+    (0xFA8E, "\u{641c}"),
+    (0xFA8F, "\u{6452}"),
+    (0xFA90, "\u{6556}"),
+    (0xFA91, "\u{6674}"),
+    (0xFA92, "\u{6717}"),
+    (0xFA93, "\u{671b}"),
+    (0xFA94, "\u{6756}"),
+    (0xFA95, "\u{6b79}"),
+    (0xFA96, "\u{6bba}"),
+    (0xFA97, "\u{6d41}"),
+    (0xFA98, "\u{6edb}"),
+    (0xFA99, "\u{6ecb}"),
+    (0xFA9A, "\u{6f22}"),
+    (0xFA9B, "\u{701e}"),
+    (0xFA9C, "\u{716e}"),
+    (0xFA9D, "\u{77a7}"),
+    (0xFA9E, "\u{7235}"),
+    (0xFA9F, "\u{72af}"),
+    (0xFAA0, "\u{732a}"),
+    (0xFAA1, "\u{7471}"),
+    (0xFAA2, "\u{7506}"),
+    (0xFAA3, "\u{753b}"),
+    (0xFAA4, "\u{761d}"),
+    (0xFAA5, "\u{761f}"),
+    (0xFAA6, "\u{76ca}"),
+    (0xFAA7, "\u{76db}"),
+    (0xFAA8, "\u{76f4}"),
+    (0xFAA9, "\u{774a}"),
+    (0xFAAA, "\u{7740}"),
+    (0xFAAB, "\u{78cc}"),
+    (0xFAAC, "\u{7ab1}"),
+    (0xFAAD, "\u{7bc0}"),
+    (0xFAAE, "\u{7c7b}"),
+    (0xFAAF, "\u{7d5b}"),
+    (0xFAB0, "\u{7df4}"),
+    (0xFAB1, "\u{7f3e}"),
+    (0xFAB2, "\u{8005}"),
+    (0xFAB3, "\u{8352}"),
+    (0xFAB4, "\u{83ef}"),
+    (0xFAB5, "\u{8779}"),
+    (0xFAB6, "\u{8941}"),
+    (0xFAB7, "\u{8986}"),
+    (0xFAB8, "\u{8996}"),
+    (0xFAB9, "\u{8abf}"),
+    (0xFABA, "\u{8af8}"),
+    (0xFABB, "\u{8acb}"),
+    (0xFABC, "\u{8b01}"),
+    (0xFABD, "\u{8afe}"),
+    (0xFABE, "\u{8aed}"),
+    (0xFABF, "\u{8b39}"),
+    (0xFAC0, "\u{8b8a}"),
+    (0xFAC1, "\u{8d08}"),
+    (0xFAC2, "\u{8f38}"),
+    (0xFAC3, "\u{9072}"),
+    (0xFAC4, "\u{9199}"),
+    (0xFAC5, "\u{9276}"),
+    (0xFAC6, "\u{967c}"),
+    (0xFAC7, "\u{96e3}"),
+    (0xFAC8, "\u{9756}"),
+    (0xFAC9, "\u{97db}"),
+    (0xFACA, "\u{97ff}"),
+    (0xFACB, "\u{980b}"),
+    (0xFACC, "\u{983b}"),
+    (0xFACD, "\u{9b12}"),
+    (0xFACE, "\u{9f9c}"),
+    (0xFACF, "\u{2284a}"),
+    (0xFAD0, "\u{22844}"),
+    (0xFAD1, "\u{233d5}"),
+    (0xFAD2, "\u{3b9d}"),
+    (0xFAD3, "\u{4018}"),
+    (0xFAD4, "\u{4039}"),
+    (0xFAD5, "\u{25249}"),
+    (0xFAD6, "\u{25cd0}"),
+    (0xFAD7, "\u{27ed3}"),
+    (0xFAD8, "\u{9f43}"),
+    (0xFAD9, "\u{9f8e}"),
+    (0xFB1D, "\u{5d9}\u{5b4}"),
+    (0xFB1F, "\u{5f2}\u{5b7}"),
+    (0xFB2A, "\u{5e9}\u{5c1}"),
+    (0xFB2B, "\u{5e9}\u{5c2}"),
+    (0xFB2C, "\u{5e9}\u{5bc}\u{5c1}"),
+    (0xFB2D, "\u{5e9}\u{5bc}\u{5c2}"),
+    (0xFB2E, "\u{5d0}\u{5b7}"),
+    (0xFB2F, "\u{5d0}\u{5b8}"),
+    (0xFB30, "\u{5d0}\u{5bc}"),
+    (0xFB31, "\u{5d1}\u{5bc}"),
+    (0xFB32, "\u{5d2}\u{5bc}"),
+    (0xFB33, "\u{5d3}\u{5bc}"),
+    (0xFB34, "\u{5d4}\u{5bc}"),
+    (0xFB35, "\u{5d5}\u{5bc}"),
+    (0xFB36, "\u{5d6}\u{5bc}"),
+    (0xFB38, "\u{5d8}\u{5bc}"),
+    (0xFB39, "\u{5d9}\u{5bc}"),
+    (0xFB3A, "\u{5da}\u{5bc}"),
+    (0xFB3B, "\u{5db}\u{5bc}"),
+    (0xFB3C, "\u{5dc}\u{5bc}"),
+    (0xFB3E, "\u{5de}\u{5bc}"),
+    (0xFB40, "\u{5e0}\u{5bc}"),
+    (0xFB41, "\u{5e1}\u{5bc}"),
+    (0xFB43, "\u{5e3}\u{5bc}"),
+    (0xFB44, "\u{5e4}\u{5bc}"),
+    (0xFB46, "\u{5e6}\u{5bc}"),
+    (0xFB47, "\u{5e7}\u{5bc}"),
+    (0xFB48, "\u{5e8}\u{5bc}"),
+    (0xFB49, "\u{5e9}\u{5bc}"),
+    (0xFB4A, "\u{5ea}\u{5bc}"),
+    (0xFB4B, "\u{5d5}\u{5b9}"),
+    (0xFB4C, "\u{5d1}\u{5bf}"),
+    (0xFB4D, "\u{5db}\u{5bf}"),
+    (0xFB4E, "\u{5e4}\u{5bf}"),
+    (0x1109A, "\u{11099}\u{110ba}"),
+    (0x1109C, "\u{1109b}\u{110ba}"),
+    (0x110AB, "\u{110a5}\u{110ba}"),
+    (0x1112E, "\u{11131}\u{11127}"),
+    (0x1112F, "\u{11132}\u{11127}"),
+    (0x1134B, "\u{11347}\u{1133e}"),
+    (0x1134C, "\u{11347}\u{11357}"),
+    (0x114BB, "\u{114b9}\u{114ba}"),
+    (0x114BC, "\u{114b9}\u{114b0}"),
+    (0x114BE, "\u{114b9}\u{114bd}"),
+    (0x115BA, "\u{115b8}\u{115af}"),
+    (0x115BB, "\u{115b9}\u{115af}"),
+    (0x11938, "\u{11935}\u{11930}"),
+    (0x1D15E, "\u{1d157}\u{1d165}"),
+    (0x1D15F, "\u{1d158}\u{1d165}"),
+    (0x1D160, "\u{1d158}\u{1d165}\u{1d16e}"),
+    (0x1D161, "\u{1d158}\u{1d165}\u{1d16f}"),
+    (0x1D162, "\u{1d158}\u{1d165}\u{1d170}"),
+    (0x1D163, "\u{1d158}\u{1d165}\u{1d171}"),
+    (0x1D164, "\u{1d158}\u{1d165}\u{1d172}"),
+    (0x1D1BB, "\u{1d1b9}\u{1d165}"),
+    (0x1D1BC, "\u{1d1ba}\u{1d165}"),
+    (0x1D1BD, "\u{1d1b9}\u{1d165}\u{1d16e}"),
+    (0x1D1BE, "\u{1d1ba}\u{1d165}\u{1d16e}"),
+    (0x1D1BF, "\u{1d1b9}\u{1d165}\u{1d16f}"),
+    (0x1D1C0, "\u{1d1ba}\u{1d165}\u{1d16f}"),
+    (0x2F800, "\u{4e3d}"),
+    (0x2F801, "\u{4e38}"),
+    (0x2F802, "\u{4e41}"),
+    (0x2F803, "\u{20122}"),
+    (0x2F804, "\u{4f60}"),
+    (0x2F805, "\u{4fae}"),
+    (0x2F806, "\u{4fbb}"),
+    (0x2F807, "\u{5002}"),
+    (0x2F808, "\u{507a}"),
+    (0x2F809, "\u{5099}"),
+    (0x2F80A, "\u{50e7}"),
+    (0x2F80B, "\u{50cf}"),
+    (0x2F80C, "\u{349e}"),
+    (0x2F80D, "\u{2063a}"),
+    (0x2F80E, "\u{514d}"),
+    (0x2F80F, "\u{5154}"),
+    (0x2F810, "\u{5164}"),
+    (0x2F811, "\u{5177}"),
+    (0x2F812, "\u{2051c}"),
+    (0x2F813, "\u{34b9}"),
+    (0x2F814, "\u{5167}"),
+    (0x2F815, "\u{518d}"),
+    (0x2F816, "\u{2054b}"),
+    (0x2F817, "\u{5197}"),
+    (0x2F818, "\u{51a4}"),
+    (0x2F819, "\u{4ecc}"),
+    (0x2F81A, "\u{51ac}"),
+    (0x2F81B, "\u{51b5}"),
+    (0x2F81C, "\u{291df}"),
+    (0x2F81D, "\u{51f5}"),
+    (0x2F81E, "\u{5203}"),
+    (0x2F81F, "\u{34df}"),
+    (0x2F820, "\u{523b}"),
+    (0x2F821, "\u{5246}"),
+    (0x2F822, "\u{5272}"),
+    (0x2F823, "\u{5277}"),
+    (0x2F824, "\u{3515}"),
+    (0x2F825, "\u{52c7}"),
+    (0x2F826, "\u{52c9}"),
+    (0x2F827, "\u{52e4}"),
+    (0x2F828, "\u{52fa}"),
+    (0x2F829, "\u{5305}"),
+    (0x2F82A, "\u{5306}"),
+    (0x2F82B, "\u{5317}"),
+    (0x2F82C, "\u{5349}"),
+    (0x2F82D, "\u{5351}"),
+    (0x2F82E, "\u{535a}"),
+    (0x2F82F, "\u{5373}"),
+    (0x2F830, "\u{537d}"),
+    (0x2F831, "\u{537f}"),
+    (0x2F832, "\u{537f}"),
+    (0x2F833, "\u{537f}"),
+    (0x2F834, "\u{20a2c}"),
+    (0x2F835, "\u{7070}"),
+    (0x2F836, "\u{53ca}"),
+    (0x2F837, "\u{53df}"),
+    (0x2F838, "\u{20b63}"),
+    (0x2F839, "\u{53eb}"),
+    (0x2F83A, "\u{53f1}"),
+    (0x2F83B, "\u{5406}"),
+    (0x2F83C, "\u{549e}"),
+    (0x2F83D, "\u{5438}"),
+    (0x2F83E, "\u{5448}"),
+    (0x2F83F, "\u{5468}"),
+    (0x2F840, "\u{54a2}"),
+    (0x2F841, "\u{54f6}"),
+    (0x2F842, "\u{5510}"),
+    (0x2F843, "\u{5553}"),
+    (0x2F844, "\u{5563}"),
+    (0x2F845, "\u{5584}"),
+    (0x2F846, "\u{5584}"),
+    (0x2F847, "\u{5599}"),
+    (0x2F848, "\u{55ab}"),
+    (0x2F849, "\u{55b3}"),
+    (0x2F84A, "\u{55c2}"),
+    (0x2F84B, "\u{5716}"),
+    (0x2F84C, "\u{5606}"),
+    (0x2F84D, "\u{5717}"),
+    (0x2F84E, "\u{5651}"),
+    (0x2F84F, "\u{5674}"),
+    (0x2F850, "\u{5207}"),
+    (0x2F851, "\u{58ee}"),
+    (0x2F852, "\u{57ce}"),
+    (0x2F853, "\u{57f4}"),
+    (0x2F854, "\u{580d}"),
+    (0x2F855, "\u{578b}"),
+    (0x2F856, "\u{5832}"),
+    (0x2F857, "\u{5831}"),
+    (0x2F858, "\u{58ac}"),
+    (0x2F859, "\u{214e4}"),
+    (0x2F85A, "\u{58f2}"),
+    (0x2F85B, "\u{58f7}"),
+    (0x2F85C, "\u{5906}"),
+    (0x2F85D, "\u{591a}"),
+    (0x2F85E, "\u{5922}"),
+    (0x2F85F, "\u{5962}"),
+    (0x2F860, "\u{216a8}"),
+    (0x2F861, "\u{216ea}"),
+    (0x2F862, "\u{59ec}"),
+    (0x2F863, "\u{5a1b}"),
+    (0x2F864, "\u{5a27}"),
+    (0x2F865, "\u{59d8}"),
+    (0x2F866, "\u{5a66}"),
+    (0x2F867, "\u{36ee}"),
+    (0x2F868, "\u{36fc}"),
+    (0x2F869, "\u{5b08}"),
+    (0x2F86A, "\u{5b3e}"),
+    (0x2F86B, "\u{5b3e}"),
+    (0x2F86C, "\u{219c8}"),
+    (0x2F86D, "\u{5bc3}"),
+    (0x2F86E, "\u{5bd8}"),
+    (0x2F86F, "\u{5be7}"),
+    (0x2F870, "\u{5bf3}"),
+    (0x2F871, "\u{21b18}"),
+    (0x2F872, "\u{5bff}"),
+    (0x2F873, "\u{5c06}"),
+    (0x2F874, "\u{5f53}"),
+    (0x2F875, "\u{5c22}"),
+    (0x2F876, "\u{3781}"),
+    (0x2F877, "\u{5c60}"),
+    (0x2F878, "\u{5c6e}"),
+    (0x2F879, "\u{5cc0}"),
+    (0x2F87A, "\u{5c8d}"),
+    (0x2F87B, "\u{21de4}"),
+    (0x2F87C, "\u{5d43}"),
+    (0x2F87D, "\u{21de6}"),
+    (0x2F87E, "\u{5d6e}"),
+    (0x2F87F, "\u{5d6b}"),
+    (0x2F880, "\u{5d7c}"),
+    (0x2F881, "\u{5de1}"),
+    (0x2F882, "\u{5de2}"),
+    (0x2F883, "\u{382f}"),
+    (0x2F884, "\u{5dfd}"),
+    (0x2F885, "\u{5e28}"),
+    (0x2F886, "\u{5e3d}"),
+    (0x2F887, "\u{5e69}"),
+    (0x2F888, "\u{3862}"),
+    (0x2F889, "\u{22183}"),
+    (0x2F88A, "\u{387c}"),
+    (0x2F88B, "\u{5eb0}"),
+    (0x2F88C, "\u{5eb3}"),
+    (0x2F88D, "\u{5eb6}"),
+    (0x2F88E, "\u{5eca}"),
+    (0x2F88F, "\u{2a392}"),
+    (0x2F890, "\u{5efe}"),
+    (0x2F891, "\u{22331}"),
+    (0x2F892, "\u{22331}"),
+    (0x2F893, "\u{8201}"),
+    (0x2F894, "\u{5f22}"),
+    (0x2F895, "\u{5f22}"),
+    (0x2F896, "\u{38c7}"),
+    (0x2F897, "\u{232b8}"),
+    (0x2F898, "\u{261da}"),
+    (0x2F899, "\u{5f62}"),
+    (0x2F89A, "\u{5f6b}"),
+    (0x2F89B, "\u{38e3}"),
+    (0x2F89C, "\u{5f9a}"),
+    (0x2F89D, "\u{5fcd}"),
+    (0x2F89E, "\u{5fd7}"),
+    (0x2F89F, "\u{5ff9}"),
+    (0x2F8A0, "\u{6081}"),
+    (0x2F8A1, "\u{393a}"),
+    (0x2F8A2, "\u{391c}"),
+    (0x2F8A3, "\u{6094}"),
+    (0x2F8A4, "\u{226d4}"),
+    (0x2F8A5, "\u{60c7}"),
+    (0x2F8A6, "\u{6148}"),
+    (0x2F8A7, "\u{614c}"),
+    (0x2F8A8, "\u{614e}"),
+    (0x2F8A9, "\u{614c}"),
+    (0x2F8AA, "\u{617a}"),
+    (0x2F8AB, "\u{618e}"),
+    (0x2F8AC, "\u{61b2}"),
+    (0x2F8AD, "\u{61a4}"),
+    (0x2F8AE, "\u{61af}"),
+    (0x2F8AF, "\u{61de}"),
+    (0x2F8B0, "\u{61f2}"),
+    (0x2F8B1, "\u{61f6}"),
+    (0x2F8B2, "\u{6210}"),
+    (0x2F8B3, "\u{621b}"),
+    (0x2F8B4, "\u{625d}"),
+    (0x2F8B5, "\u{62b1}"),
+    (0x2F8B6, "\u{62d4}"),
+    (0x2F8B7, "\u{6350}"),
+    (0x2F8B8, "\u{22b0c}"),
+    (0x2F8B9, "\u{633d}"),
+    (0x2F8BA, "\u{62fc}"),
+    (0x2F8BB, "\u{6368}"),
+    (0x2F8BC, "\u{6383}"),
+    (0x2F8BD, "\u{63e4}"),
+    (0x2F8BE, "\u{22bf1}"),
+    (0x2F8BF, "\u{6422}"),
+    (0x2F8C0, "\u{63c5}"),
+    (0x2F8C1, "\u{63a9}"),
+    (0x2F8C2, "\u{3a2e}"),
+    (0x2F8C3, "\u{6469}"),
+    (0x2F8C4, "\u{647e}"),
+    (0x2F8C5, "\u{649d}"),
+    (0x2F8C6, "\u{6477}"),
+    (0x2F8C7, "\u{3a6c}"),
+    (0x2F8C8, "\u{654f}"),
+    (0x2F8C9, "\u{656c}"),
+    (0x2F8CA, "\u{2300a}"),
+    (0x2F8CB, "\u{65e3}"),
+    (0x2F8CC, "\u{66f8}"),
+    (0x2F8CD, "\u{6649}"),
+    (0x2F8CE, "\u{3b19}"),
+    (0x2F8CF, "\u{6691}"),
+    (0x2F8D0, "\u{3b08}"),
+    (0x2F8D1, "\u{3ae4}"),
+    (0x2F8D2, "\u{5192}"),
+    (0x2F8D3, "\u{5195}"),
+    (0x2F8D4, "\u{6700}"),
+    (0x2F8D5, "\u{669c}"),
+    (0x2F8D6, "\u{80ad}"),
+    (0x2F8D7, "\u{43d9}"),
+    (0x2F8D8, "\u{6717}"),
+    (0x2F8D9, "\u{671b}"),
+    (0x2F8DA, "\u{6721}"),
+    (0x2F8DB, "\u{675e}"),
+    (0x2F8DC, "\u{6753}"),
+    (0x2F8DD, "\u{233c3}"),
+    (0x2F8DE, "\u{3b49}"),
+    (0x2F8DF, "\u{67fa}"),
+    (0x2F8E0, "\u{6785}"),
+    (0x2F8E1, "\u{6852}"),
+    (0x2F8E2, "\u{6885}"),
+    (0x2F8E3, "\u{2346d}"),
+    (0x2F8E4, "\u{688e}"),
+    (0x2F8E5, "\u{681f}"),
+    (0x2F8E6, "\u{6914}"),
+    (0x2F8E7, "\u{3b9d}"),
+    (0x2F8E8, "\u{6942}"),
+    (0x2F8E9, "\u{69a3}"),
+    (0x2F8EA, "\u{69ea}"),
+    (0x2F8EB, "\u{6aa8}"),
+    (0x2F8EC, "\u{236a3}"),
+    (0x2F8ED, "\u{6adb}"),
+    (0x2F8EE, "\u{3c18}"),
+    (0x2F8EF, "\u{6b21}"),
+    (0x2F8F0, "\u{238a7}"),
+    (0x2F8F1, "\u{6b54}"),
+    (0x2F8F2, "\u{3c4e}"),
+    (0x2F8F3, "\u{6b72}"),
+    (0x2F8F4, "\u{6b9f}"),
+    (0x2F8F5, "\u{6bba}"),
+    (0x2F8F6, "\u{6bbb}"),
+    (0x2F8F7, "\u{23a8d}"),
+    (0x2F8F8, "\u{21d0b}"),
+    (0x2F8F9, "\u{23afa}"),
+    (0x2F8FA, "\u{6c4e}"),
+    (0x2F8FB, "\u{23cbc}"),
+    (0x2F8FC, "\u{6cbf}"),
+    (0x2F8FD, "\u{6ccd}"),
+    (0x2F8FE, "\u{6c67}"),
+    (0x2F8FF, "\u{6d16}"),
+    (0x2F900, "\u{6d3e}"),
+    (0x2F901, "\u{6d77}"),
+    (0x2F902, "\u{6d41}"),
+    (0x2F903, "\u{6d69}"),
+    (0x2F904, "\u{6d78}"),
+    (0x2F905, "\u{6d85}"),
+    (0x2F906, "\u{23d1e}"),
+    (0x2F907, "\u{6d34}"),
+    (0x2F908, "\u{6e2f}"),
+    (0x2F909, "\u{6e6e}"),
+    (0x2F90A, "\u{3d33}"),
+    (0x2F90B, "\u{6ecb}"),
+    (0x2F90C, "\u{6ec7}"),
+    (0x2F90D, "\u{23ed1}"),
+    (0x2F90E, "\u{6df9}"),
+    (0x2F90F, "\u{6f6e}"),
+    (0x2F910, "\u{23f5e}"),
+    (0x2F911, "\u{23f8e}"),
+    (0x2F912, "\u{6fc6}"),
+    (0x2F913, "\u{7039}"),
+    (0x2F914, "\u{701e}"),
+    (0x2F915, "\u{701b}"),
+    (0x2F916, "\u{3d96}"),
+    (0x2F917, "\u{704a}"),
+    (0x2F918, "\u{707d}"),
+    (0x2F919, "\u{7077}"),
+    (0x2F91A, "\u{70ad}"),
+    (0x2F91B, "\u{20525}"),
+    (0x2F91C, "\u{7145}"),
+    (0x2F91D, "\u{24263}"),
+    (0x2F91E, "\u{719c}"),
+    (0x2F91F, "\u{243ab}"),
+    (0x2F920, "\u{7228}"),
+    (0x2F921, "\u{7235}"),
+    (0x2F922, "\u{7250}"),
+    (0x2F923, "\u{24608}"),
+    (0x2F924, "\u{7280}"),
+    (0x2F925, "\u{7295}"),
+    (0x2F926, "\u{24735}"),
+    (0x2F927, "\u{24814}"),
+    (0x2F928, "\u{737a}"),
+    (0x2F929, "\u{738b}"),
+    (0x2F92A, "\u{3eac}"),
+    (0x2F92B, "\u{73a5}"),
+    (0x2F92C, "\u{3eb8}"),
+    (0x2F92D, "\u{3eb8}"),
+    (0x2F92E, "\u{7447}"),
+    (0x2F92F, "\u{745c}"),
+    (0x2F930, "\u{7471}"),
+    (0x2F931, "\u{7485}"),
+    (0x2F932, "\u{74ca}"),
+    (0x2F933, "\u{3f1b}"),
+    (0x2F934, "\u{7524}"),
+    (0x2F935, "\u{24c36}"),
+    (0x2F936, "\u{753e}"),
+    (0x2F937, "\u{24c92}"),
+    (0x2F938, "\u{7570}"),
+    (0x2F939, "\u{2219f}"),
+    (0x2F93A, "\u{7610}"),
+    (0x2F93B, "\u{24fa1}"),
+    (0x2F93C, "\u{24fb8}"),
+    (0x2F93D, "\u{25044}"),
+    (0x2F93E, "\u{3ffc}"),
+    (0x2F93F, "\u{4008}"),
+    (0x2F940, "\u{76f4}"),
+    (0x2F941, "\u{250f3}"),
+    (0x2F942, "\u{250f2}"),
+    (0x2F943, "\u{25119}"),
+    (0x2F944, "\u{25133}"),
+    (0x2F945, "\u{771e}"),
+    (0x2F946, "\u{771f}"),
+    (0x2F947, "\u{771f}"),
+    (0x2F948, "\u{774a}"),
+    (0x2F949, "\u{4039}"),
+    (0x2F94A, "\u{778b}"),
+    (0x2F94B, "\u{4046}"),
+    (0x2F94C, "\u{4096}"),
+    (0x2F94D, "\u{2541d}"),
+    (0x2F94E, "\u{784e}"),
+    (0x2F94F, "\u{788c}"),
+    (0x2F950, "\u{78cc}"),
+    (0x2F951, "\u{40e3}"),
+    (0x2F952, "\u{25626}"),
+    (0x2F953, "\u{7956}"),
+    (0x2F954, "\u{2569a}"),
+    (0x2F955, "\u{256c5}"),
+    (0x2F956, "\u{798f}"),
+    (0x2F957, "\u{79eb}"),
+    (0x2F958, "\u{412f}"),
+    (0x2F959, "\u{7a40}"),
+    (0x2F95A, "\u{7a4a}"),
+    (0x2F95B, "\u{7a4f}"),
+    (0x2F95C, "\u{2597c}"),
+    (0x2F95D, "\u{25aa7}"),
+    (0x2F95E, "\u{25aa7}"),
+    (0x2F95F, "\u{7aee}"),
+    (0x2F960, "\u{4202}"),
+    (0x2F961, "\u{25bab}"),
+    (0x2F962, "\u{7bc6}"),
+    (0x2F963, "\u{7bc9}"),
+    (0x2F964, "\u{4227}"),
+    (0x2F965, "\u{25c80}"),
+    (0x2F966, "\u{7cd2}"),
+    (0x2F967, "\u{42a0}"),
+    (0x2F968, "\u{7ce8}"),
+    (0x2F969, "\u{7ce3}"),
+    (0x2F96A, "\u{7d00}"),
+    (0x2F96B, "\u{25f86}"),
+    (0x2F96C, "\u{7d63}"),
+    (0x2F96D, "\u{4301}"),
+    (0x2F96E, "\u{7dc7}"),
+    (0x2F96F, "\u{7e02}"),
+    (0x2F970, "\u{7e45}"),
+    (0x2F971, "\u{4334}"),
+    (0x2F972, "\u{26228}"),
+    (0x2F973, "\u{26247}"),
+    (0x2F974, "\u{4359}"),
+    (0x2F975, "\u{262d9}"),
+    (0x2F976, "\u{7f7a}"),
+    (0x2F977, "\u{2633e}"),
+    (0x2F978, "\u{7f95}"),
+    (0x2F979, "\u{7ffa}"),
+    (0x2F97A, "\u{8005}"),
+    (0x2F97B, "\u{264da}"),
+    (0x2F97C, "\u{26523}"),
+    (0x2F97D, "\u{8060}"),
+    (0x2F97E, "\u{265a8}"),
+    (0x2F97F, "\u{8070}"),
+    (0x2F980, "\u{2335f}"),
+    (0x2F981, "\u{43d5}"),
+    (0x2F982, "\u{80b2}"),
+    (0x2F983, "\u{8103}"),
+    (0x2F984, "\u{440b}"),
+    (0x2F985, "\u{813e}"),
+    (0x2F986, "\u{5ab5}"),
+    (0x2F987, "\u{267a7}"),
+    (0x2F988, "\u{267b5}"),
+    (0x2F989, "\u{23393}"),
+    (0x2F98A, "\u{2339c}"),
+    (0x2F98B, "\u{8201}"),
+    (0x2F98C, "\u{8204}"),
+    (0x2F98D, "\u{8f9e}"),
+    (0x2F98E, "\u{446b}"),
+    (0x2F98F, "\u{8291}"),
+    (0x2F990, "\u{828b}"),
+    (0x2F991, "\u{829d}"),
+    (0x2F992, "\u{52b3}"),
+    (0x2F993, "\u{82b1}"),
+    (0x2F994, "\u{82b3}"),
+    (0x2F995, "\u{82bd}"),
+    (0x2F996, "\u{82e6}"),
+    (0x2F997, "\u{26b3c}"),
+    (0x2F998, "\u{82e5}"),
+    (0x2F999, "\u{831d}"),
+    (0x2F99A, "\u{8363}"),
+    (0x2F99B, "\u{83ad}"),
+    (0x2F99C, "\u{8323}"),
+    (0x2F99D, "\u{83bd}"),
+    (0x2F99E, "\u{83e7}"),
+    (0x2F99F, "\u{8457}"),
+    (0x2F9A0, "\u{8353}"),
+    (0x2F9A1, "\u{83ca}"),
+    (0x2F9A2, "\u{83cc}"),
+    (0x2F9A3, "\u{83dc}"),
+    (0x2F9A4, "\u{26c36}"),
+    (0x2F9A5, "\u{26d6b}"),
+    (0x2F9A6, "\u{26cd5}"),
+    (0x2F9A7, "\u{452b}"),
+    (0x2F9A8, "\u{84f1}"),
+    (0x2F9A9, "\u{84f3}"),
+    (0x2F9AA, "\u{8516}"),
+    (0x2F9AB, "\u{273ca}"),
+    (0x2F9AC, "\u{8564}"),
+    (0x2F9AD, "\u{26f2c}"),
+    (0x2F9AE, "\u{455d}"),
+    (0x2F9AF, "\u{4561}"),
+    (0x2F9B0, "\u{26fb1}"),
+    (0x2F9B1, "\u{270d2}"),
+    (0x2F9B2, "\u{456b}"),
+    (0x2F9B3, "\u{8650}"),
+    (0x2F9B4, "\u{865c}"),
+    (0x2F9B5, "\u{8667}"),
+    (0x2F9B6, "\u{8669}"),
+    (0x2F9B7, "\u{86a9}"),
+    (0x2F9B8, "\u{8688}"),
+    (0x2F9B9, "\u{870e}"),
+    (0x2F9BA, "\u{86e2}"),
+    (0x2F9BB, "\u{8779}"),
+    (0x2F9BC, "\u{8728}"),
+    (0x2F9BD, "\u{876b}"),
+    (0x2F9BE, "\u{8786}"),
+    (0x2F9BF, "\u{45d7}"),
+    (0x2F9C0, "\u{87e1}"),
+    (0x2F9C1, "\u{8801}"),
+    (0x2F9C2, "\u{45f9}"),
+    (0x2F9C3, "\u{8860}"),
+    (0x2F9C4, "\u{8863}"),
+    (0x2F9C5, "\u{27667}"),
+    (0x2F9C6, "\u{88d7}"),
+    (0x2F9C7, "\u{88de}"),
+    (0x2F9C8, "\u{4635}"),
+    (0x2F9C9, "\u{88fa}"),
+    (0x2F9CA, "\u{34bb}"),
+    (0x2F9CB, "\u{278ae}"),
+    (0x2F9CC, "\u{27966}"),
+    (0x2F9CD, "\u{46be}"),
+    (0x2F9CE, "\u{46c7}"),
+    (0x2F9CF, "\u{8aa0}"),
+    (0x2F9D0, "\u{8aed}"),
+    (0x2F9D1, "\u{8b8a}"),
+    (0x2F9D2, "\u{8c55}"),
+    (0x2F9D3, "\u{27ca8}"),
+    (0x2F9D4, "\u{8cab}"),
+    (0x2F9D5, "\u{8cc1}"),
+    (0x2F9D6, "\u{8d1b}"),
+    (0x2F9D7, "\u{8d77}"),
+    (0x2F9D8, "\u{27f2f}"),
+    (0x2F9D9, "\u{20804}"),
+    (0x2F9DA, "\u{8dcb}"),
+    (0x2F9DB, "\u{8dbc}"),
+    (0x2F9DC, "\u{8df0}"),
+    (0x2F9DD, "\u{208de}"),
+    (0x2F9DE, "\u{8ed4}"),
+    (0x2F9DF, "\u{8f38}"),
+    (0x2F9E0, "\u{285d2}"),
+    (0x2F9E1, "\u{285ed}"),
+    (0x2F9E2, "\u{9094}"),
+    (0x2F9E3, "\u{90f1}"),
+    (0x2F9E4, "\u{9111}"),
+    (0x2F9E5, "\u{2872e}"),
+    (0x2F9E6, "\u{911b}"),
+    (0x2F9E7, "\u{9238}"),
+    (0x2F9E8, "\u{92d7}"),
+    (0x2F9E9, "\u{92d8}"),
+    (0x2F9EA, "\u{927c}"),
+    (0x2F9EB, "\u{93f9}"),
+    (0x2F9EC, "\u{9415}"),
+    (0x2F9ED, "\u{28bfa}"),
+    (0x2F9EE, "\u{958b}"),
+    (0x2F9EF, "\u{4995}"),
+    (0x2F9F0, "\u{95b7}"),
+    (0x2F9F1, "\u{28d77}"),
+    (0x2F9F2, "\u{49e6}"),
+    (0x2F9F3, "\u{96c3}"),
+    (0x2F9F4, "\u{5db2}"),
+    (0x2F9F5, "\u{9723}"),
+    (0x2F9F6, "\u{29145}"),
+    (0x2F9F7, "\u{2921a}"),
+    (0x2F9F8, "\u{4a6e}"),
+    (0x2F9F9, "\u{4a76}"),
+    (0x2F9FA, "\u{97e0}"),
+    (0x2F9FB, "\u{2940a}"),
+    (0x2F9FC, "\u{4ab2}"),
+    (0x2F9FD, "\u{29496}"),
+    (0x2F9FE, "\u{980b}"),
+    (0x2F9FF, "\u{980b}"),
+    (0x2FA00, "\u{9829}"),
+    (0x2FA01, "\u{295b6}"),
+    (0x2FA02, "\u{98e2}"),
+    (0x2FA03, "\u{4b33}"),
+    (0x2FA04, "\u{9929}"),
+    (0x2FA05, "\u{99a7}"),
+    (0x2FA06, "\u{99c2}"),
+    (0x2FA07, "\u{99fe}"),
+    (0x2FA08, "\u{4bce}"),
+    (0x2FA09, "\u{29b30}"),
+    (0x2FA0A, "\u{9b12}"),
+    (0x2FA0B, "\u{9c40}"),
+    (0x2FA0C, "\u{9cfd}"),
+    (0x2FA0D, "\u{4cce}"),
+    (0x2FA0E, "\u{4ced}"),
+    (0x2FA0F, "\u{9d67}"),
+    (0x2FA10, "\u{2a0ce}"),
+    (0x2FA11, "\u{4cf8}"),
+    (0x2FA12, "\u{2a105}"),
+    (0x2FA13, "\u{2a20e}"),
+    (0x2FA14, "\u{2a291}"),
+    (0x2FA15, "\u{9ebb}"),
+    (0x2FA16, "\u{4d56}"),
+    (0x2FA17, "\u{9ef9}"),
+    (0x2FA18, "\u{9efe}"),
+    (0x2FA19, "\u{9f05}"),
+    (0x2FA1A, "\u{9f0f}"),
+    (0x2FA1B, "\u{9f16}"),
+    (0x2FA1C, "\u{9f3b}"),
+    (0x2FA1D, "\u{2a600}"),
+];
+
+pub const COMPATIBILITY_DECOMPOSITION: &[(u32, &str)] = &[
+    (0x00A0, "\u{20}"),
+    (0x00A8, "\u{20}\u{308}"),
+    (0x00AA, "\u{61}"),
+    (0x00AF, "\u{20}\u{304}"),
+    (0x00B2, "\u{32}"),
+    (0x00B3, "\u{33}"),
+    (0x00B4, "\u{20}\u{301}"),
+    (0x00B5, "\u{3bc}"),
+    (0x00B8, "\u{20}\u{327}"),
+    (0x00B9, "\u{31}"),
+    (0x00BA, "\u{6f}"),
+    (0x00BC, "\u{31}\u{2044}\u{34}"),
+    (0x00BD, "\u{31}\u{2044}\u{32}"),
+    (0x00BE, "\u{33}\u{2044}\u{34}"),
+    (0x0132, "\u{49}\u{4a}"),
+    (0x0133, "\u{69}\u{6a}"),
+    (0x013F, "\u{4c}\u{b7}"),
+    (0x0140, "\u{6c}\u{b7}"),
+    (0x0149, "\u{2bc}\u{6e}"),
+    (0x017F, "\u{73}"),
+    (0x01C4, "\u{44}\u{5a}\u{30c}"),
+    (0x01C5, "\u{44}\u{7a}\u{30c}"),
+    (0x01C6, "\u{64}\u{7a}\u{30c}"),
+    (0x01C7, "\u{4c}\u{4a}"),
+    (0x01C8, "\u{4c}\u{6a}"),
+    (0x01C9, "\u{6c}\u{6a}"),
+    (0x01CA, "\u{4e}\u{4a}"),
+    (0x01CB, "\u{4e}\u{6a}"),
+    (0x01CC, "\u{6e}\u{6a}"),
+    (0x01F1, "\u{44}\u{5a}"),
+    (0x01F2, "\u{44}\u{7a}"),
+    (0x01F3, "\u{64}\u{7a}"),
+    (0x02B0, "\u{68}"),
+    (0x02B1, "\u{266}"),
+    (0x02B2, "\u{6a}"),
+    (0x02B3, "\u{72}"),
+    (0x02B4, "\u{279}"),
+    (0x02B5, "\u{27b}"),
+    (0x02B6, "\u{281}"),
+    (0x02B7, "\u{77}"),
+    (0x02B8, "\u{79}"),
+    (0x02D8, "\u{20}\u{306}"),
+    (0x02D9, "\u{20}\u{307}"),
+    (0x02DA, "\u{20}\u{30a}"),
+    (0x02DB, "\u{20}\u{328}"),
+    (0x02DC, "\u{20}\u{303}"),
+    (0x02DD, "\u{20}\u{30b}"),
+    (0x02E0, "\u{263}"),
+    (0x02E1, "\u{6c}"),
+    (0x02E2, "\u{73}"),
+    (0x02E3, "\u{78}"),
+    (0x02E4, "\u{295}"),
+    (0x037A, "\u{20}\u{345}"),
+    (0x0384, "\u{20}\u{301}"),
+    (0x0385, "\u{20}\u{308}\u{301}"),
+    (0x03D0, "\u{3b2}"),
+    (0x03D1, "\u{3b8}"),
+    (0x03D2, "\u{3a5}"),
+    (0x03D3, "\u{3a5}\u{301}"),
+    (0x03D4, "\u{3a5}\u{308}"),
+    (0x03D5, "\u{3c6}"),
+    (0x03D6, "\u{3c0}"),
+    (0x03F0, "\u{3ba}"),
+    (0x03F1, "\u{3c1}"),
+    (0x03F2, "\u{3c2}"),
+    (0x03F4, "\u{398}"),
+    (0x03F5, "\u{3b5}"),
+    (0x03F9, "\u{3a3}"),
+    (0x0587, "\u{565}\u{582}"),
+    (0x0675, "\u{627}\u{674}"),
+    (0x0676, "\u{648}\u{674}"),
+    (0x0677, "\u{6c7}\u{674}"),
+    (0x0678, "\u{64a}\u{674}"),
+    (0x0E33, "\u{e4d}\u{e32}"),
+    (0x0EB3, "\u{ecd}\u{eb2}"),
+    (0x0EDC, "\u{eab}\u{e99}"),
+    (0x0EDD, "\u{eab}\u{ea1}"),
+    (0x0F0C, "\u{f0b}"),
+    (0x0F77, "\u{fb2}\u{f71}\u{f80}"),
+    (0x0F79, "\u{fb3}\u{f71}\u{f80}"),
+    (0x10FC, "\u{10dc}"),
+    (0x1D2C, "\u{41}"),
+    (0x1D2D, "\u{c6}"),
+    (0x1D2E, "\u{42}"),
+    (0x1D30, "\u{44}"),
+    (0x1D31, "\u{45}"),
+    (0x1D32, "\u{18e}"),
+    (0x1D33, "\u{47}"),
+    (0x1D34, "\u{48}"),
+    (0x1D35, "\u{49}"),
+    (0x1D36, "\u{4a}"),
+    (0x1D37, "\u{4b}"),
+    (0x1D38, "\u{4c}"),
+    (0x1D39, "\u{4d}"),
+    (0x1D3A, "\u{4e}"),
+    (0x1D3C, "\u{4f}"),
+    (0x1D3D, "\u{222}"),
+    (0x1D3E, "\u{50}"),
+    (0x1D3F, "\u{52}"),
+    (0x1D40, "\u{54}"),
+    (0x1D41, "\u{55}"),
+    (0x1D42, "\u{57}"),
+    (0x1D43, "\u{61}"),
+    (0x1D44, "\u{250}"),
+    (0x1D45, "\u{251}"),
+    (0x1D46, "\u{1d02}"),
+    (0x1D47, "\u{62}"),
+    (0x1D48, "\u{64}"),
+    (0x1D49, "\u{65}"),
+    (0x1D4A, "\u{259}"),
+    (0x1D4B, "\u{25b}"),
+    (0x1D4C, "\u{25c}"),
+    (0x1D4D, "\u{67}"),
+    (0x1D4F, "\u{6b}"),
+    (0x1D50, "\u{6d}"),
+    (0x1D51, "\u{14b}"),
+    (0x1D52, "\u{6f}"),
+    (0x1D53, "\u{254}"),
+    (0x1D54, "\u{1d16}"),
+    (0x1D55, "\u{1d17}"),
+    (0x1D56, "\u{70}"),
+    (0x1D57, "\u{74}"),
+    (0x1D58, "\u{75}"),
+    (0x1D59, "\u{1d1d}"),
+    (0x1D5A, "\u{26f}"),
+    (0x1D5B, "\u{76}"),
+    (0x1D5C, "\u{1d25}"),
+    (0x1D5D, "\u{3b2}"),
+    (0x1D5E, "\u{3b3}"),
+    (0x1D5F, "\u{3b4}"),
+    (0x1D60, "\u{3c6}"),
+    (0x1D61, "\u{3c7}"),
+    (0x1D62, "\u{69}"),
+    (0x1D63, "\u{72}"),
+    (0x1D64, "\u{75}"),
+    (0x1D65, "\u{76}"),
+    (0x1D66, "\u{3b2}"),
+    (0x1D67, "\u{3b3}"),
+    (0x1D68, "\u{3c1}"),
+    (0x1D69, "\u{3c6}"),
+    (0x1D6A, "\u{3c7}"),
+    (0x1D78, "\u{43d}"),
+    (0x1D9B, "\u{252}"),
+    (0x1D9C, "\u{63}"),
+    (0x1D9D, "\u{255}"),
+    (0x1D9E, "\u{f0}"),
+    (0x1D9F, "\u{25c}"),
+    (0x1DA0, "\u{66}"),
+    (0x1DA1, "\u{25f}"),
+    (0x1DA2, "\u{261}"),
+    (0x1DA3, "\u{265}"),
+    (0x1DA4, "\u{268}"),
+    (0x1DA5, "\u{269}"),
+    (0x1DA6, "\u{26a}"),
+    (0x1DA7, "\u{1d7b}"),
+    (0x1DA8, "\u{29d}"),
+    (0x1DA9, "\u{26d}"),
+    (0x1DAA, "\u{1d85}"),
+    (0x1DAB, "\u{29f}"),
+    (0x1DAC, "\u{271}"),
+    (0x1DAD, "\u{270}"),
+    (0x1DAE, "\u{272}"),
+    (0x1DAF, "\u{273}"),
+    (0x1DB0, "\u{274}"),
+    (0x1DB1, "\u{275}"),
+    (0x1DB2, "\u{278}"),
+    (0x1DB3, "\u{282}"),
+    (0x1DB4, "\u{283}"),
+    (0x1DB5, "\u{1ab}"),
+    (0x1DB6, "\u{289}"),
+    (0x1DB7, "\u{28a}"),
+    (0x1DB8, "\u{1d1c}"),
+    (0x1DB9, "\u{28b}"),
+    (0x1DBA, "\u{28c}"),
+    (0x1DBB, "\u{7a}"),
+    (0x1DBC, "\u{290}"),
+    (0x1DBD, "\u{291}"),
+    (0x1DBE, "\u{292}"),
+    (0x1DBF, "\u{3b8}"),
+    (0x1E9A, "\u{61}\u{2be}"),
+    (0x1E9B, "\u{73}\u{307}"),
+    (0x1FBD, "\u{20}\u{313}"),
+    (0x1FBF, "\u{20}\u{313}"),
+    (0x1FC0, "\u{20}\u{342}"),
+    (0x1FC1, "\u{20}\u{308}\u{342}"),
+    (0x1FCD, "\u{20}\u{313}\u{300}"),
+    (0x1FCE, "\u{20}\u{313}\u{301}"),
+    (0x1FCF, "\u{20}\u{313}\u{342}"),
+    (0x1FDD, "\u{20}\u{314}\u{300}"),
+    (0x1FDE, "\u{20}\u{314}\u{301}"),
+    (0x1FDF, "\u{20}\u{314}\u{342}"),
+    (0x1FED, "\u{20}\u{308}\u{300}"),
+    (0x1FEE, "\u{20}\u{308}\u{301}"),
+    (0x1FFD, "\u{20}\u{301}"),
+    (0x1FFE, "\u{20}\u{314}"),
+    (0x2000, "\u{20}"),
+    (0x2001, "\u{20}"),
+    (0x2002, "\u{20}"),
+    (0x2003, "\u{20}"),
+    (0x2004, "\u{20}"),
+    (0x2005, "\u{20}"),
+    (0x2006, "\u{20}"),
+    (0x2007, "\u{20}"),
+    (0x2008, "\u{20}"),
+    (0x2009, "\u{20}"),
+    (0x200A, "\u{20}"),
+    (0x2011, "\u{2010}"),
+    (0x2017, "\u{20}\u{333}"),
+    (0x2024, "\u{2e}"),
+    (0x2025, "\u{2e}\u{2e}"),
+    (0x2026, "\u{2e}\u{2e}\u{2e}"),
+    (0x202F, "\u{20}"),
+    (0x2033, "\u{2032}\u{2032}"),
+    (0x2034, "\u{2032}\u{2032}\u{2032}"),
+    (0x2036, "\u{2035}\u{2035}"),
+    (0x2037, "\u{2035}\u{2035}\u{2035}"),
+    (0x203C, "\u{21}\u{21}"),
+    (0x203E, "\u{20}\u{305}"),
+    (0x2047, "\u{3f}\u{3f}"),
+    (0x2048, "\u{3f}\u{21}"),
+    (0x2049, "\u{21}\u{3f}"),
+    (0x2057, "\u{2032}\u{2032}\u{2032}\u{2032}"),
+    (0x205F, "\u{20}"),
+    (0x2070, "\u{30}"),
+    (0x2071, "\u{69}"),
+    (0x2074, "\u{34}"),
+    (0x2075, "\u{35}"),
+    (0x2076, "\u{36}"),
+    (0x2077, "\u{37}"),
+    (0x2078, "\u{38}"),
+    (0x2079, "\u{39}"),
+    (0x207A, "\u{2b}"),
+    (0x207B, "\u{2212}"),
+    (0x207C, "\u{3d}"),
+    (0x207D, "\u{28}"),
+    (0x207E, "\u{29}"),
+    (0x207F, "\u{6e}"),
+    (0x2080, "\u{30}"),
+    (0x2081, "\u{31}"),
+    (0x2082, "\u{32}"),
+    (0x2083, "\u{33}"),
+    (0x2084, "\u{34}"),
+    (0x2085, "\u{35}"),
+    (0x2086, "\u{36}"),
+    (0x2087, "\u{37}"),
+    (0x2088, "\u{38}"),
+    (0x2089, "\u{39}"),
+    (0x208A, "\u{2b}"),
+    (0x208B, "\u{2212}"),
+    (0x208C, "\u{3d}"),
+    (0x208D, "\u{28}"),
+    (0x208E, "\u{29}"),
+    (0x2090, "\u{61}"),
+    (0x2091, "\u{65}"),
+    (0x2092, "\u{6f}"),
+    (0x2093, "\u{78}"),
+    (0x2094, "\u{259}"),
+    (0x2095, "\u{68}"),
+    (0x2096, "\u{6b}"),
+    (0x2097, "\u{6c}"),
+    (0x2098, "\u{6d}"),
+    (0x2099, "\u{6e}"),
+    (0x209A, "\u{70}"),
+    (0x209B, "\u{73}"),
+    (0x209C, "\u{74}"),
+    (0x20A8, "\u{52}\u{73}"),
+    (0x2100, "\u{61}\u{2f}\u{63}"),
+    (0x2101, "\u{61}\u{2f}\u{73}"),
+    (0x2102, "\u{43}"),
+    (0x2103, "\u{b0}\u{43}"),
+    (0x2105, "\u{63}\u{2f}\u{6f}"),
+    (0x2106, "\u{63}\u{2f}\u{75}"),
+    (0x2107, "\u{190}"),
+    (0x2109, "\u{b0}\u{46}"),
+    (0x210A, "\u{67}"),
+    (0x210B, "\u{48}"),
+    (0x210C, "\u{48}"),
+    (0x210D, "\u{48}"),
+    (0x210E, "\u{68}"),
+    (0x210F, "\u{127}"),
+    (0x2110, "\u{49}"),
+    (0x2111, "\u{49}"),
+    (0x2112, "\u{4c}"),
+    (0x2113, "\u{6c}"),
+    (0x2115, "\u{4e}"),
+    (0x2116, "\u{4e}\u{6f}"),
+    (0x2119, "\u{50}"),
+    (0x211A, "\u{51}"),
+    (0x211B, "\u{52}"),
+    (0x211C, "\u{52}"),
+    (0x211D, "\u{52}"),
+    (0x2120, "\u{53}\u{4d}"),
+    (0x2121, "\u{54}\u{45}\u{4c}"),
+    (0x2122, "\u{54}\u{4d}"),
+    (0x2124, "\u{5a}"),
+    (0x2128, "\u{5a}"),
+    (0x212C, "\u{42}"),
+    (0x212D, "\u{43}"),
+    (0x212F, "\u{65}"),
+    (0x2130, "\u{45}"),
+    (0x2131, "\u{46}"),
+    (0x2133, "\u{4d}"),
+    (0x2134, "\u{6f}"),
+    (0x2135, "\u{5d0}"),
+    (0x2136, "\u{5d1}"),
+    (0x2137, "\u{5d2}"),
+    (0x2138, "\u{5d3}"),
+    (0x2139, "\u{69}"),
+    (0x213B, "\u{46}\u{41}\u{58}"),
+    (0x213C, "\u{3c0}"),
+    (0x213D, "\u{3b3}"),
+    (0x213E, "\u{393}"),
+    (0x213F, "\u{3a0}"),
+    (0x2140, "\u{2211}"),
+    (0x2145, "\u{44}"),
+    (0x2146, "\u{64}"),
+    (0x2147, "\u{65}"),
+    (0x2148, "\u{69}"),
+    (0x2149, "\u{6a}"),
+    (0x2150, "\u{31}\u{2044}\u{37}"),
+    (0x2151, "\u{31}\u{2044}\u{39}"),
+    (0x2152, "\u{31}\u{2044}\u{31}\u{30}"),
+    (0x2153, "\u{31}\u{2044}\u{33}"),
+    (0x2154, "\u{32}\u{2044}\u{33}"),
+    (0x2155, "\u{31}\u{2044}\u{35}"),
+    (0x2156, "\u{32}\u{2044}\u{35}"),
+    (0x2157, "\u{33}\u{2044}\u{35}"),
+    (0x2158, "\u{34}\u{2044}\u{35}"),
+    (0x2159, "\u{31}\u{2044}\u{36}"),
+    (0x215A, "\u{35}\u{2044}\u{36}"),
+    (0x215B, "\u{31}\u{2044}\u{38}"),
+    (0x215C, "\u{33}\u{2044}\u{38}"),
+    (0x215D, "\u{35}\u{2044}\u{38}"),
+    (0x215E, "\u{37}\u{2044}\u{38}"),
+    (0x215F, "\u{31}\u{2044}"),
+    (0x2160, "\u{49}"),
+    (0x2161, "\u{49}\u{49}"),
+    (0x2162, "\u{49}\u{49}\u{49}"),
+    (0x2163, "\u{49}\u{56}"),
+    (0x2164, "\u{56}"),
+    (0x2165, "\u{56}\u{49}"),
+    (0x2166, "\u{56}\u{49}\u{49}"),
+    (0x2167, "\u{56}\u{49}\u{49}\u{49}"),
+    (0x2168, "\u{49}\u{58}"),
+    (0x2169, "\u{58}"),
+    (0x216A, "\u{58}\u{49}"),
+    (0x216B, "\u{58}\u{49}\u{49}"),
+    (0x216C, "\u{4c}"),
+    (0x216D, "\u{43}"),
+    (0x216E, "\u{44}"),
+    (0x216F, "\u{4d}"),
+    (0x2170, "\u{69}"),
+    (0x2171, "\u{69}\u{69}"),
+    (0x2172, "\u{69}\u{69}\u{69}"),
+    (0x2173, "\u{69}\u{76}"),
+    (0x2174, "\u{76}"),
+    (0x2175, "\u{76}\u{69}"),
+    (0x2176, "\u{76}\u{69}\u{69}"),
+    (0x2177, "\u{76}\u{69}\u{69}\u{69}"),
+    (0x2178, "\u{69}\u{78}"),
+    (0x2179, "\u{78}"),
+    (0x217A, "\u{78}\u{69}"),
+    (0x217B, "\u{78}\u{69}\u{69}"),
+    (0x217C, "\u{6c}"),
+    (0x217D, "\u{63}"),
+    (0x217E, "\u{64}"),
+    (0x217F, "\u{6d}"),
+    (0x2189, "\u{30}\u{2044}\u{33}"),
+    (0x222C, "\u{222b}\u{222b}"),
+    (0x222D, "\u{222b}\u{222b}\u{222b}"),
+    (0x222F, "\u{222e}\u{222e}"),
+    (0x2230, "\u{222e}\u{222e}\u{222e}"),
+    (0x2460, "\u{31}"),
+    (0x2461, "\u{32}"),
+    (0x2462, "\u{33}"),
+    (0x2463, "\u{34}"),
+    (0x2464, "\u{35}"),
+    (0x2465, "\u{36}"),
+    (0x2466, "\u{37}"),
+    (0x2467, "\u{38}"),
+    (0x2468, "\u{39}"),
+    (0x2469, "\u{31}\u{30}"),
+    (0x246A, "\u{31}\u{31}"),
+    (0x246B, "\u{31}\u{32}"),
+    (0x246C, "\u{31}\u{33}"),
+    (0x246D, "\u{31}\u{34}"),
+    (0x246E, "\u{31}\u{35}"),
+    (0x246F, "\u{31}\u{36}"),
+    (0x2470, "\u{31}\u{37}"),
+    (0x2471, "\u{31}\u{38}"),
+    (0x2472, "\u{31}\u{39}"),
+    (0x2473, "\u{32}\u{30}"),
+    (0x2474, "\u{28}\u{31}\u{29}"),
+    (0x2475, "\u{28}\u{32}\u{29}"),
+    (0x2476, "\u{28}\u{33}\u{29}"),
+    (0x2477, "\u{28}\u{34}\u{29}"),
+    (0x2478, "\u{28}\u{35}\u{29}"),
+    (0x2479, "\u{28}\u{36}\u{29}"),
+    (0x247A, "\u{28}\u{37}\u{29}"),
+    (0x247B, "\u{28}\u{38}\u{29}"),
+    (0x247C, "\u{28}\u{39}\u{29}"),
+    (0x247D, "\u{28}\u{31}\u{30}\u{29}"),
+    (0x247E, "\u{28}\u{31}\u{31}\u{29}"),
+    (0x247F, "\u{28}\u{31}\u{32}\u{29}"),
+    (0x2480, "\u{28}\u{31}\u{33}\u{29}"),
+    (0x2481, "\u{28}\u{31}\u{34}\u{29}"),
+    (0x2482, "\u{28}\u{31}\u{35}\u{29}"),
+    (0x2483, "\u{28}\u{31}\u{36}\u{29}"),
+    (0x2484, "\u{28}\u{31}\u{37}\u{29}"),
+    (0x2485, "\u{28}\u{31}\u{38}\u{29}"),
+    (0x2486, "\u{28}\u{31}\u{39}\u{29}"),
+    (0x2487, "\u{28}\u{32}\u{30}\u{29}"),
+    (0x2488, "\u{31}\u{2e}"),
+    (0x2489, "\u{32}\u{2e}"),
+    (0x248A, "\u{33}\u{2e}"),
+    (0x248B, "\u{34}\u{2e}"),
+    (0x248C, "\u{35}\u{2e}"),
+    (0x248D, "\u{36}\u{2e}"),
+    (0x248E, "\u{37}\u{2e}"),
+    (0x248F, "\u{38}\u{2e}"),
+    (0x2490, "\u{39}\u{2e}"),
+    (0x2491, "\u{31}\u{30}\u{2e}"),
+    (0x2492, "\u{31}\u{31}\u{2e}"),
+    (0x2493, "\u{31}\u{32}\u{2e}"),
+    (0x2494, "\u{31}\u{33}\u{2e}"),
+    (0x2495, "\u{31}\u{34}\u{2e}"),
+    (0x2496, "\u{31}\u{35}\u{2e}"),
+    (0x2497, "\u{31}\u{36}\u{2e}"),
+    (0x2498, "\u{31}\u{37}\u{2e}"),
+    (0x2499, "\u{31}\u{38}\u{2e}"),
+    (0x249A, "\u{31}\u{39}\u{2e}"),
+    (0x249B, "\u{32}\u{30}\u{2e}"),
+    (0x249C, "\u{28}\u{61}\u{29}"),
+    (0x249D, "\u{28}\u{62}\u{29}"),
+    (0x249E, "\u{28}\u{63}\u{29}"),
+    (0x249F, "\u{28}\u{64}\u{29}"),
+    (0x24A0, "\u{28}\u{65}\u{29}"),
+    (0x24A1, "\u{28}\u{66}\u{29}"),
+    (0x24A2, "\u{28}\u{67}\u{29}"),
+    (0x24A3, "\u{28}\u{68}\u{29}"),
+    (0x24A4, "\u{28}\u{69}\u{29}"),
+    (0x24A5, "\u{28}\u{6a}\u{29}"),
+    (0x24A6, "\u{28}\u{6b}\u{29}"),
+    (0x24A7, "\u{28}\u{6c}\u{29}"),
+    (0x24A8, "\u{28}\u{6d}\u{29}"),
+    (0x24A9, "\u{28}\u{6e}\u{29}"),
+    (0x24AA, "\u{28}\u{6f}\u{29}"),
+    (0x24AB, "\u{28}\u{70}\u{29}"),
+    (0x24AC, "\u{28}\u{71}\u{29}"),
+    (0x24AD, "\u{28}\u{72}\u{29}"),
+    (0x24AE, "\u{28}\u{73}\u{29}"),
+    (0x24AF, "\u{28}\u{74}\u{29}"),
+    (0x24B0, "\u{28}\u{75}\u{29}"),
+    (0x24B1, "\u{28}\u{76}\u{29}"),
+    (0x24B2, "\u{28}\u{77}\u{29}"),
+    (0x24B3, "\u{28}\u{78}\u{29}"),
+    (0x24B4, "\u{28}\u{79}\u{29}"),
+    (0x24B5, "\u{28}\u{7a}\u{29}"),
+    (0x24B6, "\u{41}"),
+    (0x24B7, "\u{42}"),
+    (0x24B8, "\u{43}"),
+    (0x24B9, "\u{44}"),
+    (0x24BA, "\u{45}"),
+    (0x24BB, "\u{46}"),
+    (0x24BC, "\u{47}"),
+    (0x24BD, "\u{48}"),
+    (0x24BE, "\u{49}"),
+    (0x24BF, "\u{4a}"),
+    (0x24C0, "\u{4b}"),
+    (0x24C1, "\u{4c}"),
+    (0x24C2, "\u{4d}"),
+    (0x24C3, "\u{4e}"),
+    (0x24C4, "\u{4f}"),
+    (0x24C5, "\u{50}"),
+    (0x24C6, "\u{51}"),
+    (0x24C7, "\u{52}"),
+    (0x24C8, "\u{53}"),
+    (0x24C9, "\u{54}"),
+    (0x24CA, "\u{55}"),
+    (0x24CB, "\u{56}"),
+    (0x24CC, "\u{57}"),
+    (0x24CD, "\u{58}"),
+    (0x24CE, "\u{59}"),
+    (0x24CF, "\u{5a}"),
+    (0x24D0, "\u{61}"),
+    (0x24D1, "\u{62}"),
+    (0x24D2, "\u{63}"),
+    (0x24D3, "\u{64}"),
+    (0x24D4, "\u{65}"),
+    (0x24D5, "\u{66}"),
+    (0x24D6, "\u{67}"),
+    (0x24D7, "\u{68}"),
+    (0x24D8, "\u{69}"),
+    (0x24D9, "\u{6a}"),
+    (0x24DA, "\u{6b}"),
+    (0x24DB, "\u{6c}"),
+    (0x24DC, "\u{6d}"),
+    (0x24DD, "\u{6e}"),
+    (0x24DE, "\u{6f}"),
+    (0x24DF, "\u{70}"),
+    (0x24E0, "\u{71}"),
+    (0x24E1, "\u{72}"),
+    (0x24E2, "\u{73}"),
+    (0x24E3, "\u{74}"),
+    (0x24E4, "\u{75}"),
+    (0x24E5, "\u{76}"),
+    (0x24E6, "\u{77}"),
+    (0x24E7, "\u{78}"),
+    (0x24E8, "\u{79}"),
+    (0x24E9, "\u{7a}"),
+    (0x24EA, "\u{30}"),
+    (0x2A0C, "\u{222b}\u{222b}\u{222b}\u{222b}"),
+    (0x2A74, "\u{3a}\u{3a}\u{3d}"),
+    (0x2A75, "\u{3d}\u{3d}"),
+    (0x2A76, "\u{3d}\u{3d}\u{3d}"),
+    (0x2C7C, "\u{6a}"),
+    (0x2C7D, "\u{56}"),
+    (0x2D6F, "\u{2d61}"),
+    (0x2E9F, "\u{6bcd}"),
+    (0x2EF3, "\u{9f9f}"),
+    (0x2F00, "\u{4e00}"),
+    (0x2F01, "\u{4e28}"),
+    (0x2F02, "\u{4e36}"),
+    (0x2F03, "\u{4e3f}"),
+    (0x2F04, "\u{4e59}"),
+    (0x2F05, "\u{4e85}"),
+    (0x2F06, "\u{4e8c}"),
+    (0x2F07, "\u{4ea0}"),
+    (0x2F08, "\u{4eba}"),
+    (0x2F09, "\u{513f}"),
+    (0x2F0A, "\u{5165}"),
+    (0x2F0B, "\u{516b}"),
+    (0x2F0C, "\u{5182}"),
+    (0x2F0D, "\u{5196}"),
+    (0x2F0E, "\u{51ab}"),
+    (0x2F0F, "\u{51e0}"),
+    (0x2F10, "\u{51f5}"),
+    (0x2F11, "\u{5200}"),
+    (0x2F12, "\u{529b}"),
+    (0x2F13, "\u{52f9}"),
+    (0x2F14, "\u{5315}"),
+    (0x2F15, "\u{531a}"),
+    (0x2F16, "\u{5338}"),
+    (0x2F17, "\u{5341}"),
+    (0x2F18, "\u{535c}"),
+    (0x2F19, "\u{5369}"),
+    (0x2F1A, "\u{5382}"),
+    (0x2F1B, "\u{53b6}"),
+    (0x2F1C, "\u{53c8}"),
+    (0x2F1D, "\u{53e3}"),
+    (0x2F1E, "\u{56d7}"),
+    (0x2F1F, "\u{571f}"),
+    (0x2F20, "\u{58eb}"),
+    (0x2F21, "\u{5902}"),
+    (0x2F22, "\u{590a}"),
+    (0x2F23, "\u{5915}"),
+    (0x2F24, "\u{5927}"),
+    (0x2F25, "\u{5973}"),
+    (0x2F26, "\u{5b50}"),
+    (0x2F27, "\u{5b80}"),
+    (0x2F28, "\u{5bf8}"),
+    (0x2F29, "\u{5c0f}"),
+    (0x2F2A, "\u{5c22}"),
+    (0x2F2B, "\u{5c38}"),
+    (0x2F2C, "\u{5c6e}"),
+    (0x2F2D, "\u{5c71}"),
+    (0x2F2E, "\u{5ddb}"),
+    (0x2F2F, "\u{5de5}"),
+    (0x2F30, "\u{5df1}"),
+    (0x2F31, "\u{5dfe}"),
+    (0x2F32, "\u{5e72}"),
+    (0x2F33, "\u{5e7a}"),
+    (0x2F34, "\u{5e7f}"),
+    (0x2F35, "\u{5ef4}"),
+    (0x2F36, "\u{5efe}"),
+    (0x2F37, "\u{5f0b}"),
+    (0x2F38, "\u{5f13}"),
+    (0x2F39, "\u{5f50}"),
+    (0x2F3A, "\u{5f61}"),
+    (0x2F3B, "\u{5f73}"),
+    (0x2F3C, "\u{5fc3}"),
+    (0x2F3D, "\u{6208}"),
+    (0x2F3E, "\u{6236}"),
+    (0x2F3F, "\u{624b}"),
+    (0x2F40, "\u{652f}"),
+    (0x2F41, "\u{6534}"),
+    (0x2F42, "\u{6587}"),
+    (0x2F43, "\u{6597}"),
+    (0x2F44, "\u{65a4}"),
+    (0x2F45, "\u{65b9}"),
+    (0x2F46, "\u{65e0}"),
+    (0x2F47, "\u{65e5}"),
+    (0x2F48, "\u{66f0}"),
+    (0x2F49, "\u{6708}"),
+    (0x2F4A, "\u{6728}"),
+    (0x2F4B, "\u{6b20}"),
+    (0x2F4C, "\u{6b62}"),
+    (0x2F4D, "\u{6b79}"),
+    (0x2F4E, "\u{6bb3}"),
+    (0x2F4F, "\u{6bcb}"),
+    (0x2F50, "\u{6bd4}"),
+    (0x2F51, "\u{6bdb}"),
+    (0x2F52, "\u{6c0f}"),
+    (0x2F53, "\u{6c14}"),
+    (0x2F54, "\u{6c34}"),
+    (0x2F55, "\u{706b}"),
+    (0x2F56, "\u{722a}"),
+    (0x2F57, "\u{7236}"),
+    (0x2F58, "\u{723b}"),
+    (0x2F59, "\u{723f}"),
+    (0x2F5A, "\u{7247}"),
+    (0x2F5B, "\u{7259}"),
+    (0x2F5C, "\u{725b}"),
+    (0x2F5D, "\u{72ac}"),
+    (0x2F5E, "\u{7384}"),
+    (0x2F5F, "\u{7389}"),
+    (0x2F60, "\u{74dc}"),
+    (0x2F61, "\u{74e6}"),
+    (0x2F62, "\u{7518}"),
+    (0x2F63, "\u{751f}"),
+    (0x2F64, "\u{7528}"),
+    (0x2F65, "\u{7530}"),
+    (0x2F66, "\u{758b}"),
+    (0x2F67, "\u{7592}"),
+    (0x2F68, "\u{7676}"),
+    (0x2F69, "\u{767d}"),
+    (0x2F6A, "\u{76ae}"),
+    (0x2F6B, "\u{76bf}"),
+    (0x2F6C, "\u{76ee}"),
+    (0x2F6D, "\u{77db}"),
+    (0x2F6E, "\u{77e2}"),
+    (0x2F6F, "\u{77f3}"),
+    (0x2F70, "\u{793a}"),
+    (0x2F71, "\u{79b8}"),
+    (0x2F72, "\u{79be}"),
+    (0x2F73, "\u{7a74}"),
+    (0x2F74, "\u{7acb}"),
+    (0x2F75, "\u{7af9}"),
+    (0x2F76, "\u{7c73}"),
+    (0x2F77, "\u{7cf8}"),
+    (0x2F78, "\u{7f36}"),
+    (0x2F79, "\u{7f51}"),
+    (0x2F7A, "\u{7f8a}"),
+    (0x2F7B, "\u{7fbd}"),
+    (0x2F7C, "\u{8001}"),
+    (0x2F7D, "\u{800c}"),
+    (0x2F7E, "\u{8012}"),
+    (0x2F7F, "\u{8033}"),
+    (0x2F80, "\u{807f}"),
+    (0x2F81, "\u{8089}"),
+    (0x2F82, "\u{81e3}"),
+    (0x2F83, "\u{81ea}"),
+    (0x2F84, "\u{81f3}"),
+    (0x2F85, "\u{81fc}"),
+    (0x2F86, "\u{820c}"),
+    (0x2F87, "\u{821b}"),
+    (0x2F88, "\u{821f}"),
+    (0x2F89, "\u{826e}"),
+    (0x2F8A, "\u{8272}"),
+    (0x2F8B, "\u{8278}"),
+    (0x2F8C, "\u{864d}"),
+    (0x2F8D, "\u{866b}"),
+    (0x2F8E, "\u{8840}"),
+    (0x2F8F, "\u{884c}"),
+    (0x2F90, "\u{8863}"),
+    (0x2F91, "\u{897e}"),
+    (0x2F92, "\u{898b}"),
+    (0x2F93, "\u{89d2}"),
+    (0x2F94, "\u{8a00}"),
+    (0x2F95, "\u{8c37}"),
+    (0x2F96, "\u{8c46}"),
+    (0x2F97, "\u{8c55}"),
+    (0x2F98, "\u{8c78}"),
+    (0x2F99, "\u{8c9d}"),
+    (0x2F9A, "\u{8d64}"),
+    (0x2F9B, "\u{8d70}"),
+    (0x2F9C, "\u{8db3}"),
+    (0x2F9D, "\u{8eab}"),
+    (0x2F9E, "\u{8eca}"),
+    (0x2F9F, "\u{8f9b}"),
+    (0x2FA0, "\u{8fb0}"),
+    (0x2FA1, "\u{8fb5}"),
+    (0x2FA2, "\u{9091}"),
+    (0x2FA3, "\u{9149}"),
+    (0x2FA4, "\u{91c6}"),
+    (0x2FA5, "\u{91cc}"),
+    (0x2FA6, "\u{91d1}"),
+    (0x2FA7, "\u{9577}"),
+    (0x2FA8, "\u{9580}"),
+    (0x2FA9, "\u{961c}"),
+    (0x2FAA, "\u{96b6}"),
+    (0x2FAB, "\u{96b9}"),
+    (0x2FAC, "\u{96e8}"),
+    (0x2FAD, "\u{9751}"),
+    (0x2FAE, "\u{975e}"),
+    (0x2FAF, "\u{9762}"),
+    (0x2FB0, "\u{9769}"),
+    (0x2FB1, "\u{97cb}"),
+    (0x2FB2, "\u{97ed}"),
+    (0x2FB3, "\u{97f3}"),
+    (0x2FB4, "\u{9801}"),
+    (0x2FB5, "\u{98a8}"),
+    (0x2FB6, "\u{98db}"),
+    (0x2FB7, "\u{98df}"),
+    (0x2FB8, "\u{9996}"),
+    (0x2FB9, "\u{9999}"),
+    (0x2FBA, "\u{99ac}"),
+    (0x2FBB, "\u{9aa8}"),
+    (0x2FBC, "\u{9ad8}"),
+    (0x2FBD, "\u{9adf}"),
+    (0x2FBE, "\u{9b25}"),
+    (0x2FBF, "\u{9b2f}"),
+    (0x2FC0, "\u{9b32}"),
+    (0x2FC1, "\u{9b3c}"),
+    (0x2FC2, "\u{9b5a}"),
+    (0x2FC3, "\u{9ce5}"),
+    (0x2FC4, "\u{9e75}"),
+    (0x2FC5, "\u{9e7f}"),
+    (0x2FC6, "\u{9ea5}"),
+    (0x2FC7, "\u{9ebb}"),
+    (0x2FC8, "\u{9ec3}"),
+    (0x2FC9, "\u{9ecd}"),
+    (0x2FCA, "\u{9ed1}"),
+    (0x2FCB, "\u{9ef9}"),
+    (0x2FCC, "\u{9efd}"),
+    (0x2FCD, "\u{9f0e}"),
+    (0x2FCE, "\u{9f13}"),
+    (0x2FCF, "\u{9f20}"),
+    (0x2FD0, "\u{9f3b}"),
+    (0x2FD1, "\u{9f4a}"),
+    (0x2FD2, "\u{9f52}"),
+    (0x2FD3, "\u{9f8d}"),
+    (0x2FD4, "\u{9f9c}"),
+    (0x2FD5, "\u{9fa0}"),
+    (0x3000, "\u{20}"),
+    (0x3036, "\u{3012}"),
+    (0x3038, "\u{5341}"),
+    (0x3039, "\u{5344}"),
+    (0x303A, "\u{5345}"),
+    (0x309B, "\u{20}\u{3099}"),
+    (0x309C, "\u{20}\u{309a}"),
+    (0x309F, "\u{3088}\u{308a}"),
+    (0x30FF, "\u{30b3}\u{30c8}"),
+    (0x3131, "\u{1100}"),
+    (0x3132, "\u{1101}"),
+    (0x3133, "\u{11aa}"),
+    (0x3134, "\u{1102}"),
+    (0x3135, "\u{11ac}"),
+    (0x3136, "\u{11ad}"),
+    (0x3137, "\u{1103}"),
+    (0x3138, "\u{1104}"),
+    (0x3139, "\u{1105}"),
+    (0x313A, "\u{11b0}"),
+    (0x313B, "\u{11b1}"),
+    (0x313C, "\u{11b2}"),
+    (0x313D, "\u{11b3}"),
+    (0x313E, "\u{11b4}"),
+    (0x313F, "\u{11b5}"),
+    (0x3140, "\u{111a}"),
+    (0x3141, "\u{1106}"),
+    (0x3142, "\u{1107}"),
+    (0x3143, "\u{1108}"),
+    (0x3144, "\u{1121}"),
+    (0x3145, "\u{1109}"),
+    (0x3146, "\u{110a}"),
+    (0x3147, "\u{110b}"),
+    (0x3148, "\u{110c}"),
+    (0x3149, "\u{110d}"),
+    (0x314A, "\u{110e}"),
+    (0x314B, "\u{110f}"),
+    (0x314C, "\u{1110}"),
+    (0x314D, "\u{1111}"),
+    (0x314E, "\u{1112}"),
+    (0x314F, "\u{1161}"),
+    (0x3150, "\u{1162}"),
+    (0x3151, "\u{1163}"),
+    (0x3152, "\u{1164}"),
+    (0x3153, "\u{1165}"),
+    (0x3154, "\u{1166}"),
+    (0x3155, "\u{1167}"),
+    (0x3156, "\u{1168}"),
+    (0x3157, "\u{1169}"),
+    (0x3158, "\u{116a}"),
+    (0x3159, "\u{116b}"),
+    (0x315A, "\u{116c}"),
+    (0x315B, "\u{116d}"),
+    (0x315C, "\u{116e}"),
+    (0x315D, "\u{116f}"),
+    (0x315E, "\u{1170}"),
+    (0x315F, "\u{1171}"),
+    (0x3160, "\u{1172}"),
+    (0x3161, "\u{1173}"),
+    (0x3162, "\u{1174}"),
+    (0x3163, "\u{1175}"),
+    (0x3164, "\u{1160}"),
+    (0x3165, "\u{1114}"),
+    (0x3166, "\u{1115}"),
+    (0x3167, "\u{11c7}"),
+    (0x3168, "\u{11c8}"),
+    (0x3169, "\u{11cc}"),
+    (0x316A, "\u{11ce}"),
+    (0x316B, "\u{11d3}"),
+    (0x316C, "\u{11d7}"),
+    (0x316D, "\u{11d9}"),
+    (0x316E, "\u{111c}"),
+    (0x316F, "\u{11dd}"),
+    (0x3170, "\u{11df}"),
+    (0x3171, "\u{111d}"),
+    (0x3172, "\u{111e}"),
+    (0x3173, "\u{1120}"),
+    (0x3174, "\u{1122}"),
+    (0x3175, "\u{1123}"),
+    (0x3176, "\u{1127}"),
+    (0x3177, "\u{1129}"),
+    (0x3178, "\u{112b}"),
+    (0x3179, "\u{112c}"),
+    (0x317A, "\u{112d}"),
+    (0x317B, "\u{112e}"),
+    (0x317C, "\u{112f}"),
+    (0x317D, "\u{1132}"),
+    (0x317E, "\u{1136}"),
+    (0x317F, "\u{1140}"),
+    (0x3180, "\u{1147}"),
+    (0x3181, "\u{114c}"),
+    (0x3182, "\u{11f1}"),
+    (0x3183, "\u{11f2}"),
+    (0x3184, "\u{1157}"),
+    (0x3185, "\u{1158}"),
+    (0x3186, "\u{1159}"),
+    (0x3187, "\u{1184}"),
+    (0x3188, "\u{1185}"),
+    (0x3189, "\u{1188}"),
+    (0x318A, "\u{1191}"),
+    (0x318B, "\u{1192}"),
+    (0x318C, "\u{1194}"),
+    (0x318D, "\u{119e}"),
+    (0x318E, "\u{11a1}"),
+    (0x3192, "\u{4e00}"),
+    (0x3193, "\u{4e8c}"),
+    (0x3194, "\u{4e09}"),
+    (0x3195, "\u{56db}"),
+    (0x3196, "\u{4e0a}"),
+    (0x3197, "\u{4e2d}"),
+    (0x3198, "\u{4e0b}"),
+    (0x3199, "\u{7532}"),
+    (0x319A, "\u{4e59}"),
+    (0x319B, "\u{4e19}"),
+    (0x319C, "\u{4e01}"),
+    (0x319D, "\u{5929}"),
+    (0x319E, "\u{5730}"),
+    (0x319F, "\u{4eba}"),
+    (0x3200, "\u{28}\u{1100}\u{29}"),
+    (0x3201, "\u{28}\u{1102}\u{29}"),
+    (0x3202, "\u{28}\u{1103}\u{29}"),
+    (0x3203, "\u{28}\u{1105}\u{29}"),
+    (0x3204, "\u{28}\u{1106}\u{29}"),
+    (0x3205, "\u{28}\u{1107}\u{29}"),
+    (0x3206, "\u{28}\u{1109}\u{29}"),
+    (0x3207, "\u{28}\u{110b}\u{29}"),
+    (0x3208, "\u{28}\u{110c}\u{29}"),
+    (0x3209, "\u{28}\u{110e}\u{29}"),
+    (0x320A, "\u{28}\u{110f}\u{29}"),
+    (0x320B, "\u{28}\u{1110}\u{29}"),
+    (0x320C, "\u{28}\u{1111}\u{29}"),
+    (0x320D, "\u{28}\u{1112}\u{29}"),
+    (0x320E, "\u{28}\u{1100}\u{1161}\u{29}"),
+    (0x320F, "\u{28}\u{1102}\u{1161}\u{29}"),
+    (0x3210, "\u{28}\u{1103}\u{1161}\u{29}"),
+    (0x3211, "\u{28}\u{1105}\u{1161}\u{29}"),
+    (0x3212, "\u{28}\u{1106}\u{1161}\u{29}"),
+    (0x3213, "\u{28}\u{1107}\u{1161}\u{29}"),
+    (0x3214, "\u{28}\u{1109}\u{1161}\u{29}"),
+    (0x3215, "\u{28}\u{110b}\u{1161}\u{29}"),
+    (0x3216, "\u{28}\u{110c}\u{1161}\u{29}"),
+    (0x3217, "\u{28}\u{110e}\u{1161}\u{29}"),
+    (0x3218, "\u{28}\u{110f}\u{1161}\u{29}"),
+    (0x3219, "\u{28}\u{1110}\u{1161}\u{29}"),
+    (0x321A, "\u{28}\u{1111}\u{1161}\u{29}"),
+    (0x321B, "\u{28}\u{1112}\u{1161}\u{29}"),
+    (0x321C, "\u{28}\u{110c}\u{116e}\u{29}"),
+    (0x321D, "\u{28}\u{110b}\u{1169}\u{110c}\u{1165}\u{11ab}\u{29}"),
+    (0x321E, "\u{28}\u{110b}\u{1169}\u{1112}\u{116e}\u{29}"),
+    (0x3220, "\u{28}\u{4e00}\u{29}"),
+    (0x3221, "\u{28}\u{4e8c}\u{29}"),
+    (0x3222, "\u{28}\u{4e09}\u{29}"),
+    (0x3223, "\u{28}\u{56db}\u{29}"),
+    (0x3224, "\u{28}\u{4e94}\u{29}"),
+    (0x3225, "\u{28}\u{516d}\u{29}"),
+    (0x3226, "\u{28}\u{4e03}\u{29}"),
+    (0x3227, "\u{28}\u{516b}\u{29}"),
+    (0x3228, "\u{28}\u{4e5d}\u{29}"),
+    (0x3229, "\u{28}\u{5341}\u{29}"),
+    (0x322A, "\u{28}\u{6708}\u{29}"),
+    (0x322B, "\u{28}\u{706b}\u{29}"),
+    (0x322C, "\u{28}\u{6c34}\u{29}"),
+    (0x322D, "\u{28}\u{6728}\u{29}"),
+    (0x322E, "\u{28}\u{91d1}\u{29}"),
+    (0x322F, "\u{28}\u{571f}\u{29}"),
+    (0x3230, "\u{28}\u{65e5}\u{29}"),
+    (0x3231, "\u{28}\u{682a}\u{29}"),
+    (0x3232, "\u{28}\u{6709}\u{29}"),
+    (0x3233, "\u{28}\u{793e}\u{29}"),
+    (0x3234, "\u{28}\u{540d}\u{29}"),
+    (0x3235, "\u{28}\u{7279}\u{29}"),
+    (0x3236, "\u{28}\u{8ca1}\u{29}"),
+    (0x3237, "\u{28}\u{795d}\u{29}"),
+    (0x3238, "\u{28}\u{52b4}\u{29}"),
+    (0x3239, "\u{28}\u{4ee3}\u{29}"),
+    (0x323A, "\u{28}\u{547c}\u{29}"),
+    (0x323B, "\u{28}\u{5b66}\u{29}"),
+    (0x323C, "\u{28}\u{76e3}\u{29}"),
+    (0x323D, "\u{28}\u{4f01}\u{29}"),
+    (0x323E, "\u{28}\u{8cc7}\u{29}"),
+    (0x323F, "\u{28}\u{5354}\u{29}"),
+    (0x3240, "\u{28}\u{796d}\u{29}"),
+    (0x3241, "\u{28}\u{4f11}\u{29}"),
+    (0x3242, "\u{28}\u{81ea}\u{29}"),
+    (0x3243, "\u{28}\u{81f3}\u{29}"),
+    (0x3244, "\u{554f}"),
+    (0x3245, "\u{5e7c}"),
+    (0x3246, "\u{6587}"),
+    (0x3247, "\u{7b8f}"),
+    (0x3250, "\u{50}\u{54}\u{45}"),
+    (0x3251, "\u{32}\u{31}"),
+    (0x3252, "\u{32}\u{32}"),
+    (0x3253, "\u{32}\u{33}"),
+    (0x3254, "\u{32}\u{34}"),
+    (0x3255, "\u{32}\u{35}"),
+    (0x3256, "\u{32}\u{36}"),
+    (0x3257, "\u{32}\u{37}"),
+    (0x3258, "\u{32}\u{38}"),
+    (0x3259, "\u{32}\u{39}"),
+    (0x325A, "\u{33}\u{30}"),
+    (0x325B, "\u{33}\u{31}"),
+    (0x325C, "\u{33}\u{32}"),
+    (0x325D, "\u{33}\u{33}"),
+    (0x325E, "\u{33}\u{34}"),
+    (0x325F, "\u{33}\u{35}"),
+    (0x3260, "\u{1100}"),
+    (0x3261, "\u{1102}"),
+    (0x3262, "\u{1103}"),
+    (0x3263, "\u{1105}"),
+    (0x3264, "\u{1106}"),
+    (0x3265, "\u{1107}"),
+    (0x3266, "\u{1109}"),
+    (0x3267, "\u{110b}"),
+    (0x3268, "\u{110c}"),
+    (0x3269, "\u{110e}"),
+    (0x326A, "\u{110f}"),
+    (0x326B, "\u{1110}"),
+    (0x326C, "\u{1111}"),
+    (0x326D, "\u{1112}"),
+    (0x326E, "\u{1100}\u{1161}"),
+    (0x326F, "\u{1102}\u{1161}"),
+    (0x3270, "\u{1103}\u{1161}"),
+    (0x3271, "\u{1105}\u{1161}"),
+    (0x3272, "\u{1106}\u{1161}"),
+    (0x3273, "\u{1107}\u{1161}"),
+    (0x3274, "\u{1109}\u{1161}"),
+    (0x3275, "\u{110b}\u{1161}"),
+    (0x3276, "\u{110c}\u{1161}"),
+    (0x3277, "\u{110e}\u{1161}"),
+    (0x3278, "\u{110f}\u{1161}"),
+    (0x3279, "\u{1110}\u{1161}"),
+    (0x327A, "\u{1111}\u{1161}"),
+    (0x327B, "\u{1112}\u{1161}"),
+    (0x327C, "\u{110e}\u{1161}\u{11b7}\u{1100}\u{1169}"),
+    (0x327D, "\u{110c}\u{116e}\u{110b}\u{1174}"),
+    (0x327E, "\u{110b}\u{116e}"),
+    (0x3280, "\u{4e00}"),
+    (0x3281, "\u{4e8c}"),
+    (0x3282, "\u{4e09}"),
+    (0x3283, "\u{56db}"),
+    (0x3284, "\u{4e94}"),
+    (0x3285, "\u{516d}"),
+    (0x3286, "\u{4e03}"),
+    (0x3287, "\u{516b}"),
+    (0x3288, "\u{4e5d}"),
+    (0x3289, "\u{5341}"),
+    (0x328A, "\u{6708}"),
+    (0x328B, "\u{706b}"),
+    (0x328C, "\u{6c34}"),
+    (0x328D, "\u{6728}"),
+    (0x328E, "\u{91d1}"),
+    (0x328F, "\u{571f}"),
+    (0x3290, "\u{65e5}"),
+    (0x3291, "\u{682a}"),
+    (0x3292, "\u{6709}"),
+    (0x3293, "\u{793e}"),
+    (0x3294, "\u{540d}"),
+    (0x3295, "\u{7279}"),
+    (0x3296, "\u{8ca1}"),
+    (0x3297, "\u{795d}"),
+    (0x3298, "\u{52b4}"),
+    (0x3299, "\u{79d8}"),
+    (0x329A, "\u{7537}"),
+    (0x329B, "\u{5973}"),
+    (0x329C, "\u{9069}"),
+    (0x329D, "\u{512a}"),
+    (0x329E, "\u{5370}"),
+    (0x329F, "\u{6ce8}"),
+    (0x32A0, "\u{9805}"),
+    (0x32A1, "\u{4f11}"),
+    (0x32A2, "\u{5199}"),
+    (0x32A3, "\u{6b63}"),
+    (0x32A4, "\u{4e0a}"),
+    (0x32A5, "\u{4e2d}"),
+    (0x32A6, "\u{4e0b}"),
+    (0x32A7, "\u{5de6}"),
+    (0x32A8, "\u{53f3}"),
+    (0x32A9, "\u{533b}"),
+    (0x32AA, "\u{5b97}"),
+    (0x32AB, "\u{5b66}"),
+    (0x32AC, "\u{76e3}"),
+    (0x32AD, "\u{4f01}"),
+    (0x32AE, "\u{8cc7}"),
+    (0x32AF, "\u{5354}"),
+    (0x32B0, "\u{591c}"),
+    (0x32B1, "\u{33}\u{36}"),
+    (0x32B2, "\u{33}\u{37}"),
+    (0x32B3, "\u{33}\u{38}"),
+    (0x32B4, "\u{33}\u{39}"),
+    (0x32B5, "\u{34}\u{30}"),
+    (0x32B6, "\u{34}\u{31}"),
+    (0x32B7, "\u{34}\u{32}"),
+    (0x32B8, "\u{34}\u{33}"),
+    (0x32B9, "\u{34}\u{34}"),
+    (0x32BA, "\u{34}\u{35}"),
+    (0x32BB, "\u{34}\u{36}"),
+    (0x32BC, "\u{34}\u{37}"),
+    (0x32BD, "\u{34}\u{38}"),
+    (0x32BE, "\u{34}\u{39}"),
+    (0x32BF, "\u{35}\u{30}"),
+    (0x32C0, "\u{31}\u{6708}"),
+    (0x32C1, "\u{32}\u{6708}"),
+    (0x32C2, "\u{33}\u{6708}"),
+    (0x32C3, "\u{34}\u{6708}"),
+    (0x32C4, "\u{35}\u{6708}"),
+    (0x32C5, "\u{36}\u{6708}"),
+    (0x32C6, "\u{37}\u{6708}"),
+    (0x32C7, "\u{38}\u{6708}"),
+    (0x32C8, "\u{39}\u{6708}"),
+    (0x32C9, "\u{31}\u{30}\u{6708}"),
+    (0x32CA, "\u{31}\u{31}\u{6708}"),
+    (0x32CB, "\u{31}\u{32}\u{6708}"),
+    (0x32CC, "\u{48}\u{67}"),
+    (0x32CD, "\u{65}\u{72}\u{67}"),
+    (0x32CE, "\u{65}\u{56}"),
+    (0x32CF, "\u{4c}\u{54}\u{44}"),
+    (0x32D0, "\u{30a2}"),
+    (0x32D1, "\u{30a4}"),
+    (0x32D2, "\u{30a6}"),
+    (0x32D3, "\u{30a8}"),
+    (0x32D4, "\u{30aa}"),
+    (0x32D5, "\u{30ab}"),
+    (0x32D6, "\u{30ad}"),
+    (0x32D7, "\u{30af}"),
+    (0x32D8, "\u{30b1}"),
+    (0x32D9, "\u{30b3}"),
+    (0x32DA, "\u{30b5}"),
+    (0x32DB, "\u{30b7}"),
+    (0x32DC, "\u{30b9}"),
+    (0x32DD, "\u{30bb}"),
+    (0x32DE, "\u{30bd}"),
+    (0x32DF, "\u{30bf}"),
+    (0x32E0, "\u{30c1}"),
+    (0x32E1, "\u{30c4}"),
+    (0x32E2, "\u{30c6}"),
+    (0x32E3, "\u{30c8}"),
+    (0x32E4, "\u{30ca}"),
+    (0x32E5, "\u{30cb}"),
+    (0x32E6, "\u{30cc}"),
+    (0x32E7, "\u{30cd}"),
+    (0x32E8, "\u{30ce}"),
+    (0x32E9, "\u{30cf}"),
+    (0x32EA, "\u{30d2}"),
+    (0x32EB, "\u{30d5}"),
+    (0x32EC, "\u{30d8}"),
+    (0x32ED, "\u{30db}"),
+    (0x32EE, "\u{30de}"),
+    (0x32EF, "\u{30df}"),
+    (0x32F0, "\u{30e0}"),
+    (0x32F1, "\u{30e1}"),
+    (0x32F2, "\u{30e2}"),
+    (0x32F3, "\u{30e4}"),
+    (0x32F4, "\u{30e6}"),
+    (0x32F5, "\u{30e8}"),
+    (0x32F6, "\u{30e9}"),
+    (0x32F7, "\u{30ea}"),
+    (0x32F8, "\u{30eb}"),
+    (0x32F9, "\u{30ec}"),
+    (0x32FA, "\u{30ed}"),
+    (0x32FB, "\u{30ef}"),
+    (0x32FC, "\u{30f0}"),
+    (0x32FD, "\u{30f1}"),
+    (0x32FE, "\u{30f2}"),
+    (0x32FF, "\u{4ee4}\u{548c}"),
+    (0x3300, "\u{30a2}\u{30cf}\u{309a}\u{30fc}\u{30c8}"),
+    (0x3301, "\u{30a2}\u{30eb}\u{30d5}\u{30a1}"),
+    (0x3302, "\u{30a2}\u{30f3}\u{30d8}\u{309a}\u{30a2}"),
+    (0x3303, "\u{30a2}\u{30fc}\u{30eb}"),
+    (0x3304, "\u{30a4}\u{30cb}\u{30f3}\u{30af}\u{3099}"),
+    (0x3305, "\u{30a4}\u{30f3}\u{30c1}"),
+    (0x3306, "\u{30a6}\u{30a9}\u{30f3}"),
+    (0x3307, "\u{30a8}\u{30b9}\u{30af}\u{30fc}\u{30c8}\u{3099}"),
+    (0x3308, "\u{30a8}\u{30fc}\u{30ab}\u{30fc}"),
+    (0x3309, "\u{30aa}\u{30f3}\u{30b9}"),
+    (0x330A, "\u{30aa}\u{30fc}\u{30e0}"),
+    (0x330B, "\u{30ab}\u{30a4}\u{30ea}"),
+    (0x330C, "\u{30ab}\u{30e9}\u{30c3}\u{30c8}"),
+    (0x330D, "\u{30ab}\u{30ed}\u{30ea}\u{30fc}"),
+    (0x330E, "\u{30ab}\u{3099}\u{30ed}\u{30f3}"),
+    (0x330F, "\u{30ab}\u{3099}\u{30f3}\u{30de}"),
+    (0x3310, "\u{30ad}\u{3099}\u{30ab}\u{3099}"),
+    (0x3311, "\u{30ad}\u{3099}\u{30cb}\u{30fc}"),
+    (0x3312, "\u{30ad}\u{30e5}\u{30ea}\u{30fc}"),
+    (0x3313, "\u{30ad}\u{3099}\u{30eb}\u{30bf}\u{3099}\u{30fc}"),
+    (0x3314, "\u{30ad}\u{30ed}"),
+    (0x3315, "\u{30ad}\u{30ed}\u{30af}\u{3099}\u{30e9}\u{30e0}"),
+    (0x3316, "\u{30ad}\u{30ed}\u{30e1}\u{30fc}\u{30c8}\u{30eb}"),
+    (0x3317, "\u{30ad}\u{30ed}\u{30ef}\u{30c3}\u{30c8}"),
+    (0x3318, "\u{30af}\u{3099}\u{30e9}\u{30e0}"),
+    (0x3319, "\u{30af}\u{3099}\u{30e9}\u{30e0}\u{30c8}\u{30f3}"),
+    (0x331A, "\u{30af}\u{30eb}\u{30bb}\u{3099}\u{30a4}\u{30ed}"),
+    (0x331B, "\u{30af}\u{30ed}\u{30fc}\u{30cd}"),
+    (0x331C, "\u{30b1}\u{30fc}\u{30b9}"),
+    (0x331D, "\u{30b3}\u{30eb}\u{30ca}"),
+    (0x331E, "\u{30b3}\u{30fc}\u{30db}\u{309a}"),
+    (0x331F, "\u{30b5}\u{30a4}\u{30af}\u{30eb}"),
+    (0x3320, "\u{30b5}\u{30f3}\u{30c1}\u{30fc}\u{30e0}"),
+    (0x3321, "\u{30b7}\u{30ea}\u{30f3}\u{30af}\u{3099}"),
+    (0x3322, "\u{30bb}\u{30f3}\u{30c1}"),
+    (0x3323, "\u{30bb}\u{30f3}\u{30c8}"),
+    (0x3324, "\u{30bf}\u{3099}\u{30fc}\u{30b9}"),
+    (0x3325, "\u{30c6}\u{3099}\u{30b7}"),
+    (0x3326, "\u{30c8}\u{3099}\u{30eb}"),
+    (0x3327, "\u{30c8}\u{30f3}"),
+    (0x3328, "\u{30ca}\u{30ce}"),
+    (0x3329, "\u{30ce}\u{30c3}\u{30c8}"),
+    (0x332A, "\u{30cf}\u{30a4}\u{30c4}"),
+    (0x332B, "\u{30cf}\u{309a}\u{30fc}\u{30bb}\u{30f3}\u{30c8}"),
+    (0x332C, "\u{30cf}\u{309a}\u{30fc}\u{30c4}"),
+    (0x332D, "\u{30cf}\u{3099}\u{30fc}\u{30ec}\u{30eb}"),
+    (0x332E, "\u{30d2}\u{309a}\u{30a2}\u{30b9}\u{30c8}\u{30eb}"),
+    (0x332F, "\u{30d2}\u{309a}\u{30af}\u{30eb}"),
+    (0x3330, "\u{30d2}\u{309a}\u{30b3}"),
+    (0x3331, "\u{30d2}\u{3099}\u{30eb}"),
+    (0x3332, "\u{30d5}\u{30a1}\u{30e9}\u{30c3}\u{30c8}\u{3099}"),
+    (0x3333, "\u{30d5}\u{30a3}\u{30fc}\u{30c8}"),
+    (0x3334, "\u{30d5}\u{3099}\u{30c3}\u{30b7}\u{30a7}\u{30eb}"),
+    (0x3335, "\u{30d5}\u{30e9}\u{30f3}"),
+    (0x3336, "\u{30d8}\u{30af}\u{30bf}\u{30fc}\u{30eb}"),
+    (0x3337, "\u{30d8}\u{309a}\u{30bd}"),
+    (0x3338, "\u{30d8}\u{309a}\u{30cb}\u{30d2}"),
+    (0x3339, "\u{30d8}\u{30eb}\u{30c4}"),
+    (0x333A, "\u{30d8}\u{309a}\u{30f3}\u{30b9}"),
+    (0x333B, "\u{30d8}\u{309a}\u{30fc}\u{30b7}\u{3099}"),
+    (0x333C, "\u{30d8}\u{3099}\u{30fc}\u{30bf}"),
+    (0x333D, "\u{30db}\u{309a}\u{30a4}\u{30f3}\u{30c8}"),
+    (0x333E, "\u{30db}\u{3099}\u{30eb}\u{30c8}"),
+    (0x333F, "\u{30db}\u{30f3}"),
+    (0x3340, "\u{30db}\u{309a}\u{30f3}\u{30c8}\u{3099}"),
+    (0x3341, "\u{30db}\u{30fc}\u{30eb}"),
+    (0x3342, "\u{30db}\u{30fc}\u{30f3}"),
+    (0x3343, "\u{30de}\u{30a4}\u{30af}\u{30ed}"),
+    (0x3344, "\u{30de}\u{30a4}\u{30eb}"),
+    (0x3345, "\u{30de}\u{30c3}\u{30cf}"),
+    (0x3346, "\u{30de}\u{30eb}\u{30af}"),
+    (0x3347, "\u{30de}\u{30f3}\u{30b7}\u{30e7}\u{30f3}"),
+    (0x3348, "\u{30df}\u{30af}\u{30ed}\u{30f3}"),
+    (0x3349, "\u{30df}\u{30ea}"),
+    (0x334A, "\u{30df}\u{30ea}\u{30cf}\u{3099}\u{30fc}\u{30eb}"),
+    (0x334B, "\u{30e1}\u{30ab}\u{3099}"),
+    (0x334C, "\u{30e1}\u{30ab}\u{3099}\u{30c8}\u{30f3}"),
+    (0x334D, "\u{30e1}\u{30fc}\u{30c8}\u{30eb}"),
+    (0x334E, "\u{30e4}\u{30fc}\u{30c8}\u{3099}"),
+    (0x334F, "\u{30e4}\u{30fc}\u{30eb}"),
+    (0x3350, "\u{30e6}\u{30a2}\u{30f3}"),
+    (0x3351, "\u{30ea}\u{30c3}\u{30c8}\u{30eb}"),
+    (0x3352, "\u{30ea}\u{30e9}"),
+    (0x3353, "\u{30eb}\u{30d2}\u{309a}\u{30fc}"),
+    (0x3354, "\u{30eb}\u{30fc}\u{30d5}\u{3099}\u{30eb}"),
+    (0x3355, "\u{30ec}\u{30e0}"),
+    (0x3356, "\u{30ec}\u{30f3}\u{30c8}\u{30b1}\u{3099}\u{30f3}"),
+    (0x3357, "\u{30ef}\u{30c3}\u{30c8}"),
+    (0x3358, "\u{30}\u{70b9}"),
+    (0x3359, "\u{31}\u{70b9}"),
+    (0x335A, "\u{32}\u{70b9}"),
+    (0x335B, "\u{33}\u{70b9}"),
+    (0x335C, "\u{34}\u{70b9}"),
+    (0x335D, "\u{35}\u{70b9}"),
+    (0x335E, "\u{36}\u{70b9}"),
+    (0x335F, "\u{37}\u{70b9}"),
+    (0x3360, "\u{38}\u{70b9}"),
+    (0x3361, "\u{39}\u{70b9}"),
+    (0x3362, "\u{31}\u{30}\u{70b9}"),
+    (0x3363, "\u{31}\u{31}\u{70b9}"),
+    (0x3364, "\u{31}\u{32}\u{70b9}"),
+    (0x3365, "\u{31}\u{33}\u{70b9}"),
+    (0x3366, "\u{31}\u{34}\u{70b9}"),
+    (0x3367, "\u{31}\u{35}\u{70b9}"),
+    (0x3368, "\u{31}\u{36}\u{70b9}"),
+    (0x3369, "\u{31}\u{37}\u{70b9}"),
+    (0x336A, "\u{31}\u{38}\u{70b9}"),
+    (0x336B, "\u{31}\u{39}\u{70b9}"),
+    (0x336C, "\u{32}\u{30}\u{70b9}"),
+    (0x336D, "\u{32}\u{31}\u{70b9}"),
+    (0x336E, "\u{32}\u{32}\u{70b9}"),
+    (0x336F, "\u{32}\u{33}\u{70b9}"),
+    (0x3370, "\u{32}\u{34}\u{70b9}"),
+    (0x3371, "\u{68}\u{50}\u{61}"),
+    (0x3372, "\u{64}\u{61}"),
+    (0x3373, "\u{41}\u{55}"),
+    (0x3374, "\u{62}\u{61}\u{72}"),
+    (0x3375, "\u{6f}\u{56}"),
+    (0x3376, "\u{70}\u{63}"),
+    (0x3377, "\u{64}\u{6d}"),
+    (0x3378, "\u{64}\u{6d}\u{32}"),
+    (0x3379, "\u{64}\u{6d}\u{33}"),
+    (0x337A, "\u{49}\u{55}"),
+    (0x337B, "\u{5e73}\u{6210}"),
+    (0x337C, "\u{662d}\u{548c}"),
+    (0x337D, "\u{5927}\u{6b63}"),
+    (0x337E, "\u{660e}\u{6cbb}"),
+    (0x337F, "\u{682a}\u{5f0f}\u{4f1a}\u{793e}"),
+    (0x3380, "\u{70}\u{41}"),
+    (0x3381, "\u{6e}\u{41}"),
+    (0x3382, "\u{3bc}\u{41}"),
+    (0x3383, "\u{6d}\u{41}"),
+    (0x3384, "\u{6b}\u{41}"),
+    (0x3385, "\u{4b}\u{42}"),
+    (0x3386, "\u{4d}\u{42}"),
+    (0x3387, "\u{47}\u{42}"),
+    (0x3388, "\u{63}\u{61}\u{6c}"),
+    (0x3389, "\u{6b}\u{63}\u{61}\u{6c}"),
+    (0x338A, "\u{70}\u{46}"),
+    (0x338B, "\u{6e}\u{46}"),
+    (0x338C, "\u{3bc}\u{46}"),
+    (0x338D, "\u{3bc}\u{67}"),
+    (0x338E, "\u{6d}\u{67}"),
+    (0x338F, "\u{6b}\u{67}"),
+    (0x3390, "\u{48}\u{7a}"),
+    (0x3391, "\u{6b}\u{48}\u{7a}"),
+    (0x3392, "\u{4d}\u{48}\u{7a}"),
+    (0x3393, "\u{47}\u{48}\u{7a}"),
+    (0x3394, "\u{54}\u{48}\u{7a}"),
+    (0x3395, "\u{3bc}\u{6c}"),
+    (0x3396, "\u{6d}\u{6c}"),
+    (0x3397, "\u{64}\u{6c}"),
+    (0x3398, "\u{6b}\u{6c}"),
+    (0x3399, "\u{66}\u{6d}"),
+    (0x339A, "\u{6e}\u{6d}"),
+    (0x339B, "\u{3bc}\u{6d}"),
+    (0x339C, "\u{6d}\u{6d}"),
+    (0x339D, "\u{63}\u{6d}"),
+    (0x339E, "\u{6b}\u{6d}"),
+    (0x339F, "\u{6d}\u{6d}\u{32}"),
+    (0x33A0, "\u{63}\u{6d}\u{32}"),
+    (0x33A1, "\u{6d}\u{32}"),
+    (0x33A2, "\u{6b}\u{6d}\u{32}"),
+    (0x33A3, "\u{6d}\u{6d}\u{33}"),
+    (0x33A4, "\u{63}\u{6d}\u{33}"),
+    (0x33A5, "\u{6d}\u{33}"),
+    (0x33A6, "\u{6b}\u{6d}\u{33}"),
+    (0x33A7, "\u{6d}\u{2215}\u{73}"),
+    (0x33A8, "\u{6d}\u{2215}\u{73}\u{32}"),
+    (0x33A9, "\u{50}\u{61}"),
+    (0x33AA, "\u{6b}\u{50}\u{61}"),
+    (0x33AB, "\u{4d}\u{50}\u{61}"),
+    (0x33AC, "\u{47}\u{50}\u{61}"),
+    (0x33AD, "\u{72}\u{61}\u{64}"),
+    (0x33AE, "\u{72}\u{61}\u{64}\u{2215}\u{73}"),
+    (0x33AF, "\u{72}\u{61}\u{64}\u{2215}\u{73}\u{32}"),
+    (0x33B0, "\u{70}\u{73}"),
+    (0x33B1, "\u{6e}\u{73}"),
+    (0x33B2, "\u{3bc}\u{73}"),
+    (0x33B3, "\u{6d}\u{73}"),
+    (0x33B4, "\u{70}\u{56}"),
+    (0x33B5, "\u{6e}\u{56}"),
+    (0x33B6, "\u{3bc}\u{56}"),
+    (0x33B7, "\u{6d}\u{56}"),
+    (0x33B8, "\u{6b}\u{56}"),
+    (0x33B9, "\u{4d}\u{56}"),
+    (0x33BA, "\u{70}\u{57}"),
+    (0x33BB, "\u{6e}\u{57}"),
+    (0x33BC, "\u{3bc}\u{57}"),
+    (0x33BD, "\u{6d}\u{57}"),
+    (0x33BE, "\u{6b}\u{57}"),
+    (0x33BF, "\u{4d}\u{57}"),
+    (0x33C0, "\u{6b}\u{3a9}"),
+    (0x33C1, "\u{4d}\u{3a9}"),
+    (0x33C2, "\u{61}\u{2e}\u{6d}\u{2e}"),
+    (0x33C3, "\u{42}\u{71}"),
+    (0x33C4, "\u{63}\u{63}"),
+    (0x33C5, "\u{63}\u{64}"),
+    (0x33C6, "\u{43}\u{2215}\u{6b}\u{67}"),
+    (0x33C7, "\u{43}\u{6f}\u{2e}"),
+    (0x33C8, "\u{64}\u{42}"),
+    (0x33C9, "\u{47}\u{79}"),
+    (0x33CA, "\u{68}\u{61}"),
+    (0x33CB, "\u{48}\u{50}"),
+    (0x33CC, "\u{69}\u{6e}"),
+    (0x33CD, "\u{4b}\u{4b}"),
+    (0x33CE, "\u{4b}\u{4d}"),
+    (0x33CF, "\u{6b}\u{74}"),
+    (0x33D0, "\u{6c}\u{6d}"),
+    (0x33D1, "\u{6c}\u{6e}"),
+    (0x33D2, "\u{6c}\u{6f}\u{67}"),
+    (0x33D3, "\u{6c}\u{78}"),
+    (0x33D4, "\u{6d}\u{62}"),
+    (0x33D5, "\u{6d}\u{69}\u{6c}"),
+    (0x33D6, "\u{6d}\u{6f}\u{6c}"),
+    (0x33D7, "\u{50}\u{48}"),
+    (0x33D8, "\u{70}\u{2e}\u{6d}\u{2e}"),
+    (0x33D9, "\u{50}\u{50}\u{4d}"),
+    (0x33DA, "\u{50}\u{52}"),
+    (0x33DB, "\u{73}\u{72}"),
+    (0x33DC, "\u{53}\u{76}"),
+    (0x33DD, "\u{57}\u{62}"),
+    (0x33DE, "\u{56}\u{2215}\u{6d}"),
+    (0x33DF, "\u{41}\u{2215}\u{6d}"),
+    (0x33E0, "\u{31}\u{65e5}"),
+    (0x33E1, "\u{32}\u{65e5}"),
+    (0x33E2, "\u{33}\u{65e5}"),
+    (0x33E3, "\u{34}\u{65e5}"),
+    (0x33E4, "\u{35}\u{65e5}"),
+    (0x33E5, "\u{36}\u{65e5}"),
+    (0x33E6, "\u{37}\u{65e5}"),
+    (0x33E7, "\u{38}\u{65e5}"),
+    (0x33E8, "\u{39}\u{65e5}"),
+    (0x33E9, "\u{31}\u{30}\u{65e5}"),
+    (0x33EA, "\u{31}\u{31}\u{65e5}"),
+    (0x33EB, "\u{31}\u{32}\u{65e5}"),
+    (0x33EC, "\u{31}\u{33}\u{65e5}"),
+    (0x33ED, "\u{31}\u{34}\u{65e5}"),
+    (0x33EE, "\u{31}\u{35}\u{65e5}"),
+    (0x33EF, "\u{31}\u{36}\u{65e5}"),
+    (0x33F0, "\u{31}\u{37}\u{65e5}"),
+    (0x33F1, "\u{31}\u{38}\u{65e5}"),
+    (0x33F2, "\u{31}\u{39}\u{65e5}"),
+    (0x33F3, "\u{32}\u{30}\u{65e5}"),
+    (0x33F4, "\u{32}\u{31}\u{65e5}"),
+    (0x33F5, "\u{32}\u{32}\u{65e5}"),
+    (0x33F6, "\u{32}\u{33}\u{65e5}"),
+    (0x33F7, "\u{32}\u{34}\u{65e5}"),
+    (0x33F8, "\u{32}\u{35}\u{65e5}"),
+    (0x33F9, "\u{32}\u{36}\u{65e5}"),
+    (0x33FA, "\u{32}\u{37}\u{65e5}"),
+    (0x33FB, "\u{32}\u{38}\u{65e5}"),
+    (0x33FC, "\u{32}\u{39}\u{65e5}"),
+    (0x33FD, "\u{33}\u{30}\u{65e5}"),
+    (0x33FE, "\u{33}\u{31}\u{65e5}"),
+    (0x33FF, "\u{67}\u{61}\u{6c}"),
+    (0xA69C, "\u{44a}"),
+    (0xA69D, "\u{44c}"),
+    (0xA770, "\u{a76f}"),
+    (0xA7F2, "\u{43}"),
+    (0xA7F3, "\u{46}"),
+    (0xA7F4, "\u{51}"),
+    (0xA7F8, "\u{126}"),
+    (0xA7F9, "\u{153}"),
+    (0xAB5C, "\u{a727}"),
+    (0xAB5D, "\u{ab37}"),
+    (0xAB5E, "\u{26b}"),
+    (0xAB5F, "\u{ab52}"),
+    (0xAB69, "\u{28d}"),
+    (0xFB00, "\u{66}\u{66}"),
+    (0xFB01, "\u{66}\u{69}"),
+    (0xFB02, "\u{66}\u{6c}"),
+    (0xFB03, "\u{66}\u{66}\u{69}"),
+    (0xFB04, "\u{66}\u{66}\u{6c}"),
+    (0xFB05, "\u{73}\u{74}"),
+    (0xFB06, "\u{73}\u{74}"),
+    (0xFB13, "\u{574}\u{576}"),
+    (0xFB14, "\u{574}\u{565}"),
+    (0xFB15, "\u{574}\u{56b}"),
+    (0xFB16, "\u{57e}\u{576}"),
+    (0xFB17, "\u{574}\u{56d}"),
+    (0xFB20, "\u{5e2}"),
+    (0xFB21, "\u{5d0}"),
+    (0xFB22, "\u{5d3}"),
+    (0xFB23, "\u{5d4}"),
+    (0xFB24, "\u{5db}"),
+    (0xFB25, "\u{5dc}"),
+    (0xFB26, "\u{5dd}"),
+    (0xFB27, "\u{5e8}"),
+    (0xFB28, "\u{5ea}"),
+    (0xFB29, "\u{2b}"),
+    (0xFB4F, "\u{5d0}\u{5dc}"),
+    (0xFB50, "\u{671}"),
+    (0xFB51, "\u{671}"),
+    (0xFB52, "\u{67b}"),
+    (0xFB53, "\u{67b}"),
+    (0xFB54, "\u{67b}"),
+    (0xFB55, "\u{67b}"),
+    (0xFB56, "\u{67e}"),
+    (0xFB57, "\u{67e}"),
+    (0xFB58, "\u{67e}"),
+    (0xFB59, "\u{67e}"),
+    (0xFB5A, "\u{680}"),
+    (0xFB5B, "\u{680}"),
+    (0xFB5C, "\u{680}"),
+    (0xFB5D, "\u{680}"),
+    (0xFB5E, "\u{67a}"),
+    (0xFB5F, "\u{67a}"),
+    (0xFB60, "\u{67a}"),
+    (0xFB61, "\u{67a}"),
+    (0xFB62, "\u{67f}"),
+    (0xFB63, "\u{67f}"),
+    (0xFB64, "\u{67f}"),
+    (0xFB65, "\u{67f}"),
+    (0xFB66, "\u{679}"),
+    (0xFB67, "\u{679}"),
+    (0xFB68, "\u{679}"),
+    (0xFB69, "\u{679}"),
+    (0xFB6A, "\u{6a4}"),
+    (0xFB6B, "\u{6a4}"),
+    (0xFB6C, "\u{6a4}"),
+    (0xFB6D, "\u{6a4}"),
+    (0xFB6E, "\u{6a6}"),
+    (0xFB6F, "\u{6a6}"),
+    (0xFB70, "\u{6a6}"),
+    (0xFB71, "\u{6a6}"),
+    (0xFB72, "\u{684}"),
+    (0xFB73, "\u{684}"),
+    (0xFB74, "\u{684}"),
+    (0xFB75, "\u{684}"),
+    (0xFB76, "\u{683}"),
+    (0xFB77, "\u{683}"),
+    (0xFB78, "\u{683}"),
+    (0xFB79, "\u{683}"),
+    (0xFB7A, "\u{686}"),
+    (0xFB7B, "\u{686}"),
+    (0xFB7C, "\u{686}"),
+    (0xFB7D, "\u{686}"),
+    (0xFB7E, "\u{687}"),
+    (0xFB7F, "\u{687}"),
+    (0xFB80, "\u{687}"),
+    (0xFB81, "\u{687}"),
+    (0xFB82, "\u{68d}"),
+    (0xFB83, "\u{68d}"),
+    (0xFB84, "\u{68c}"),
+    (0xFB85, "\u{68c}"),
+    (0xFB86, "\u{68e}"),
+    (0xFB87, "\u{68e}"),
+    (0xFB88, "\u{688}"),
+    (0xFB89, "\u{688}"),
+    (0xFB8A, "\u{698}"),
+    (0xFB8B, "\u{698}"),
+    (0xFB8C, "\u{691}"),
+    (0xFB8D, "\u{691}"),
+    (0xFB8E, "\u{6a9}"),
+    (0xFB8F, "\u{6a9}"),
+    (0xFB90, "\u{6a9}"),
+    (0xFB91, "\u{6a9}"),
+    (0xFB92, "\u{6af}"),
+    (0xFB93, "\u{6af}"),
+    (0xFB94, "\u{6af}"),
+    (0xFB95, "\u{6af}"),
+    (0xFB96, "\u{6b3}"),
+    (0xFB97, "\u{6b3}"),
+    (0xFB98, "\u{6b3}"),
+    (0xFB99, "\u{6b3}"),
+    (0xFB9A, "\u{6b1}"),
+    (0xFB9B, "\u{6b1}"),
+    (0xFB9C, "\u{6b1}"),
+    (0xFB9D, "\u{6b1}"),
+    (0xFB9E, "\u{6ba}"),
+    (0xFB9F, "\u{6ba}"),
+    (0xFBA0, "\u{6bb}"),
+    (0xFBA1, "\u{6bb}"),
+    (0xFBA2, "\u{6bb}"),
+    (0xFBA3, "\u{6bb}"),
+    (0xFBA4, "\u{6d5}\u{654}"),
+    (0xFBA5, "\u{6d5}\u{654}"),
+    (0xFBA6, "\u{6c1}"),
+    (0xFBA7, "\u{6c1}"),
+    (0xFBA8, "\u{6c1}"),
+    (0xFBA9, "\u{6c1}"),
+    (0xFBAA, "\u{6be}"),
+    (0xFBAB, "\u{6be}"),
+    (0xFBAC, "\u{6be}"),
+    (0xFBAD, "\u{6be}"),
+    (0xFBAE, "\u{6d2}"),
+    (0xFBAF, "\u{6d2}"),
+    (0xFBB0, "\u{6d2}\u{654}"),
+    (0xFBB1, "\u{6d2}\u{654}"),
+    (0xFBD3, "\u{6ad}"),
+    (0xFBD4, "\u{6ad}"),
+    (0xFBD5, "\u{6ad}"),
+    (0xFBD6, "\u{6ad}"),
+    (0xFBD7, "\u{6c7}"),
+    (0xFBD8, "\u{6c7}"),
+    (0xFBD9, "\u{6c6}"),
+    (0xFBDA, "\u{6c6}"),
+    (0xFBDB, "\u{6c8}"),
+    (0xFBDC, "\u{6c8}"),
+    (0xFBDD, "\u{6c7}\u{674}"),
+    (0xFBDE, "\u{6cb}"),
+    (0xFBDF, "\u{6cb}"),
+    (0xFBE0, "\u{6c5}"),
+    (0xFBE1, "\u{6c5}"),
+    (0xFBE2, "\u{6c9}"),
+    (0xFBE3, "\u{6c9}"),
+    (0xFBE4, "\u{6d0}"),
+    (0xFBE5, "\u{6d0}"),
+    (0xFBE6, "\u{6d0}"),
+    (0xFBE7, "\u{6d0}"),
+    (0xFBE8, "\u{649}"),
+    (0xFBE9, "\u{649}"),
+    (0xFBEA, "\u{64a}\u{654}\u{627}"),
+    (0xFBEB, "\u{64a}\u{654}\u{627}"),
+    (0xFBEC, "\u{64a}\u{654}\u{6d5}"),
+    (0xFBED, "\u{64a}\u{654}\u{6d5}"),
+    (0xFBEE, "\u{64a}\u{654}\u{648}"),
+    (0xFBEF, "\u{64a}\u{654}\u{648}"),
+    (0xFBF0, "\u{64a}\u{654}\u{6c7}"),
+    (0xFBF1, "\u{64a}\u{654}\u{6c7}"),
+    (0xFBF2, "\u{64a}\u{654}\u{6c6}"),
+    (0xFBF3, "\u{64a}\u{654}\u{6c6}"),
+    (0xFBF4, "\u{64a}\u{654}\u{6c8}"),
+    (0xFBF5, "\u{64a}\u{654}\u{6c8}"),
+    (0xFBF6, "\u{64a}\u{654}\u{6d0}"),
+    (0xFBF7, "\u{64a}\u{654}\u{6d0}"),
+    (0xFBF8, "\u{64a}\u{654}\u{6d0}"),
+    (0xFBF9, "\u{64a}\u{654}\u{649}"),
+    (0xFBFA, "\u{64a}\u{654}\u{649}"),
+    (0xFBFB, "\u{64a}\u{654}\u{649}"),
+    (0xFBFC, "\u{6cc}"),
+    (0xFBFD, "\u{6cc}"),
+    (0xFBFE, "\u{6cc}"),
+    (0xFBFF, "\u{6cc}"),
+    (0xFC00, "\u{64a}\u{654}\u{62c}"),
+    (0xFC01, "\u{64a}\u{654}\u{62d}"),
+    (0xFC02, "\u{64a}\u{654}\u{645}"),
+    (0xFC03, "\u{64a}\u{654}\u{649}"),
+    (0xFC04, "\u{64a}\u{654}\u{64a}"),
+    (0xFC05, "\u{628}\u{62c}"),
+    (0xFC06, "\u{628}\u{62d}"),
+    (0xFC07, "\u{628}\u{62e}"),
+    (0xFC08, "\u{628}\u{645}"),
+    (0xFC09, "\u{628}\u{649}"),
+    (0xFC0A, "\u{628}\u{64a}"),
+    (0xFC0B, "\u{62a}\u{62c}"),
+    (0xFC0C, "\u{62a}\u{62d}"),
+    (0xFC0D, "\u{62a}\u{62e}"),
+    (0xFC0E, "\u{62a}\u{645}"),
+    (0xFC0F, "\u{62a}\u{649}"),
+    (0xFC10, "\u{62a}\u{64a}"),
+    (0xFC11, "\u{62b}\u{62c}"),
+    (0xFC12, "\u{62b}\u{645}"),
+    (0xFC13, "\u{62b}\u{649}"),
+    (0xFC14, "\u{62b}\u{64a}"),
+    (0xFC15, "\u{62c}\u{62d}"),
+    (0xFC16, "\u{62c}\u{645}"),
+    (0xFC17, "\u{62d}\u{62c}"),
+    (0xFC18, "\u{62d}\u{645}"),
+    (0xFC19, "\u{62e}\u{62c}"),
+    (0xFC1A, "\u{62e}\u{62d}"),
+    (0xFC1B, "\u{62e}\u{645}"),
+    (0xFC1C, "\u{633}\u{62c}"),
+    (0xFC1D, "\u{633}\u{62d}"),
+    (0xFC1E, "\u{633}\u{62e}"),
+    (0xFC1F, "\u{633}\u{645}"),
+    (0xFC20, "\u{635}\u{62d}"),
+    (0xFC21, "\u{635}\u{645}"),
+    (0xFC22, "\u{636}\u{62c}"),
+    (0xFC23, "\u{636}\u{62d}"),
+    (0xFC24, "\u{636}\u{62e}"),
+    (0xFC25, "\u{636}\u{645}"),
+    (0xFC26, "\u{637}\u{62d}"),
+    (0xFC27, "\u{637}\u{645}"),
+    (0xFC28, "\u{638}\u{645}"),
+    (0xFC29, "\u{639}\u{62c}"),
+    (0xFC2A, "\u{639}\u{645}"),
+    (0xFC2B, "\u{63a}\u{62c}"),
+    (0xFC2C, "\u{63a}\u{645}"),
+    (0xFC2D, "\u{641}\u{62c}"),
+    (0xFC2E, "\u{641}\u{62d}"),
+    (0xFC2F, "\u{641}\u{62e}"),
+    (0xFC30, "\u{641}\u{645}"),
+    (0xFC31, "\u{641}\u{649}"),
+    (0xFC32, "\u{641}\u{64a}"),
+    (0xFC33, "\u{642}\u{62d}"),
+    (0xFC34, "\u{642}\u{645}"),
+    (0xFC35, "\u{642}\u{649}"),
+    (0xFC36, "\u{642}\u{64a}"),
+    (0xFC37, "\u{643}\u{627}"),
+    (0xFC38, "\u{643}\u{62c}"),
+    (0xFC39, "\u{643}\u{62d}"),
+    (0xFC3A, "\u{643}\u{62e}"),
+    (0xFC3B, "\u{643}\u{644}"),
+    (0xFC3C, "\u{643}\u{645}"),
+    (0xFC3D, "\u{643}\u{649}"),
+    (0xFC3E, "\u{643}\u{64a}"),
+    (0xFC3F, "\u{644}\u{62c}"),
+    (0xFC40, "\u{644}\u{62d}"),
+    (0xFC41, "\u{644}\u{62e}"),
+    (0xFC42, "\u{644}\u{645}"),
+    (0xFC43, "\u{644}\u{649}"),
+    (0xFC44, "\u{644}\u{64a}"),
+    (0xFC45, "\u{645}\u{62c}"),
+    (0xFC46, "\u{645}\u{62d}"),
+    (0xFC47, "\u{645}\u{62e}"),
+    (0xFC48, "\u{645}\u{645}"),
+    (0xFC49, "\u{645}\u{649}"),
+    (0xFC4A, "\u{645}\u{64a}"),
+    (0xFC4B, "\u{646}\u{62c}"),
+    (0xFC4C, "\u{646}\u{62d}"),
+    (0xFC4D, "\u{646}\u{62e}"),
+    (0xFC4E, "\u{646}\u{645}"),
+    (0xFC4F, "\u{646}\u{649}"),
+    (0xFC50, "\u{646}\u{64a}"),
+    (0xFC51, "\u{647}\u{62c}"),
+    (0xFC52, "\u{647}\u{645}"),
+    (0xFC53, "\u{647}\u{649}"),
+    (0xFC54, "\u{647}\u{64a}"),
+    (0xFC55, "\u{64a}\u{62c}"),
+    (0xFC56, "\u{64a}\u{62d}"),
+    (0xFC57, "\u{64a}\u{62e}"),
+    (0xFC58, "\u{64a}\u{645}"),
+    (0xFC59, "\u{64a}\u{649}"),
+    (0xFC5A, "\u{64a}\u{64a}"),
+    (0xFC5B, "\u{630}\u{670}"),
+    (0xFC5C, "\u{631}\u{670}"),
+    (0xFC5D, "\u{649}\u{670}"),
+    (0xFC5E, "\u{20}\u{64c}\u{651}"),
+    (0xFC5F, "\u{20}\u{64d}\u{651}"),
+    (0xFC60, "\u{20}\u{64e}\u{651}"),
+    (0xFC61, "\u{20}\u{64f}\u{651}"),
+    (0xFC62, "\u{20}\u{650}\u{651}"),
+    (0xFC63, "\u{20}\u{651}\u{670}"),
+    (0xFC64, "\u{64a}\u{654}\u{631}"),
+    (0xFC65, "\u{64a}\u{654}\u{632}"),
+    (0xFC66, "\u{64a}\u{654}\u{645}"),
+    (0xFC67, "\u{64a}\u{654}\u{646}"),
+    (0xFC68, "\u{64a}\u{654}\u{649}"),
+    (0xFC69, "\u{64a}\u{654}\u{64a}"),
+    (0xFC6A, "\u{628}\u{631}"),
+    (0xFC6B, "\u{628}\u{632}"),
+    (0xFC6C, "\u{628}\u{645}"),
+    (0xFC6D, "\u{628}\u{646}"),
+    (0xFC6E, "\u{628}\u{649}"),
+    (0xFC6F, "\u{628}\u{64a}"),
+    (0xFC70, "\u{62a}\u{631}"),
+    (0xFC71, "\u{62a}\u{632}"),
+    (0xFC72, "\u{62a}\u{645}"),
+    (0xFC73, "\u{62a}\u{646}"),
+    (0xFC74, "\u{62a}\u{649}"),
+    (0xFC75, "\u{62a}\u{64a}"),
+    (0xFC76, "\u{62b}\u{631}"),
+    (0xFC77, "\u{62b}\u{632}"),
+    (0xFC78, "\u{62b}\u{645}"),
+    (0xFC79, "\u{62b}\u{646}"),
+    (0xFC7A, "\u{62b}\u{649}"),
+    (0xFC7B, "\u{62b}\u{64a}"),
+    (0xFC7C, "\u{641}\u{649}"),
+    (0xFC7D, "\u{641}\u{64a}"),
+    (0xFC7E, "\u{642}\u{649}"),
+    (0xFC7F, "\u{642}\u{64a}"),
+    (0xFC80, "\u{643}\u{627}"),
+    (0xFC81, "\u{643}\u{644}"),
+    (0xFC82, "\u{643}\u{645}"),
+    (0xFC83, "\u{643}\u{649}"),
+    (0xFC84, "\u{643}\u{64a}"),
+    (0xFC85, "\u{644}\u{645}"),
+    (0xFC86, "\u{644}\u{649}"),
+    (0xFC87, "\u{644}\u{64a}"),
+    (0xFC88, "\u{645}\u{627}"),
+    (0xFC89, "\u{645}\u{645}"),
+    (0xFC8A, "\u{646}\u{631}"),
+    (0xFC8B, "\u{646}\u{632}"),
+    (0xFC8C, "\u{646}\u{645}"),
+    (0xFC8D, "\u{646}\u{646}"),
+    (0xFC8E, "\u{646}\u{649}"),
+    (0xFC8F, "\u{646}\u{64a}"),
+    (0xFC90, "\u{649}\u{670}"),
+    (0xFC91, "\u{64a}\u{631}"),
+    (0xFC92, "\u{64a}\u{632}"),
+    (0xFC93, "\u{64a}\u{645}"),
+    (0xFC94, "\u{64a}\u{646}"),
+    (0xFC95, "\u{64a}\u{649}"),
+    (0xFC96, "\u{64a}\u{64a}"),
+    (0xFC97, "\u{64a}\u{654}\u{62c}"),
+    (0xFC98, "\u{64a}\u{654}\u{62d}"),
+    (0xFC99, "\u{64a}\u{654}\u{62e}"),
+    (0xFC9A, "\u{64a}\u{654}\u{645}"),
+    (0xFC9B, "\u{64a}\u{654}\u{647}"),
+    (0xFC9C, "\u{628}\u{62c}"),
+    (0xFC9D, "\u{628}\u{62d}"),
+    (0xFC9E, "\u{628}\u{62e}"),
+    (0xFC9F, "\u{628}\u{645}"),
+    (0xFCA0, "\u{628}\u{647}"),
+    (0xFCA1, "\u{62a}\u{62c}"),
+    (0xFCA2, "\u{62a}\u{62d}"),
+    (0xFCA3, "\u{62a}\u{62e}"),
+    (0xFCA4, "\u{62a}\u{645}"),
+    (0xFCA5, "\u{62a}\u{647}"),
+    (0xFCA6, "\u{62b}\u{645}"),
+    (0xFCA7, "\u{62c}\u{62d}"),
+    (0xFCA8, "\u{62c}\u{645}"),
+    (0xFCA9, "\u{62d}\u{62c}"),
+    (0xFCAA, "\u{62d}\u{645}"),
+    (0xFCAB, "\u{62e}\u{62c}"),
+    (0xFCAC, "\u{62e}\u{645}"),
+    (0xFCAD, "\u{633}\u{62c}"),
+    (0xFCAE, "\u{633}\u{62d}"),
+    (0xFCAF, "\u{633}\u{62e}"),
+    (0xFCB0, "\u{633}\u{645}"),
+    (0xFCB1, "\u{635}\u{62d}"),
+    (0xFCB2, "\u{635}\u{62e}"),
+    (0xFCB3, "\u{635}\u{645}"),
+    (0xFCB4, "\u{636}\u{62c}"),
+    (0xFCB5, "\u{636}\u{62d}"),
+    (0xFCB6, "\u{636}\u{62e}"),
+    (0xFCB7, "\u{636}\u{645}"),
+    (0xFCB8, "\u{637}\u{62d}"),
+    (0xFCB9, "\u{638}\u{645}"),
+    (0xFCBA, "\u{639}\u{62c}"),
+    (0xFCBB, "\u{639}\u{645}"),
+    (0xFCBC, "\u{63a}\u{62c}"),
+    (0xFCBD, "\u{63a}\u{645}"),
+    (0xFCBE, "\u{641}\u{62c}"),
+    (0xFCBF, "\u{641}\u{62d}"),
+    (0xFCC0, "\u{641}\u{62e}"),
+    (0xFCC1, "\u{641}\u{645}"),
+    (0xFCC2, "\u{642}\u{62d}"),
+    (0xFCC3, "\u{642}\u{645}"),
+    (0xFCC4, "\u{643}\u{62c}"),
+    (0xFCC5, "\u{643}\u{62d}"),
+    (0xFCC6, "\u{643}\u{62e}"),
+    (0xFCC7, "\u{643}\u{644}"),
+    (0xFCC8, "\u{643}\u{645}"),
+    (0xFCC9, "\u{644}\u{62c}"),
+    (0xFCCA, "\u{644}\u{62d}"),
+    (0xFCCB, "\u{644}\u{62e}"),
+    (0xFCCC, "\u{644}\u{645}"),
+    (0xFCCD, "\u{644}\u{647}"),
+    (0xFCCE, "\u{645}\u{62c}"),
+    (0xFCCF, "\u{645}\u{62d}"),
+    (0xFCD0, "\u{645}\u{62e}"),
+    (0xFCD1, "\u{645}\u{645}"),
+    (0xFCD2, "\u{646}\u{62c}"),
+    (0xFCD3, "\u{646}\u{62d}"),
+    (0xFCD4, "\u{646}\u{62e}"),
+    (0xFCD5, "\u{646}\u{645}"),
+    (0xFCD6, "\u{646}\u{647}"),
+    (0xFCD7, "\u{647}\u{62c}"),
+    (0xFCD8, "\u{647}\u{645}"),
+    (0xFCD9, "\u{647}\u{670}"),
+    (0xFCDA, "\u{64a}\u{62c}"),
+    (0xFCDB, "\u{64a}\u{62d}"),
+    (0xFCDC, "\u{64a}\u{62e}"),
+    (0xFCDD, "\u{64a}\u{645}"),
+    (0xFCDE, "\u{64a}\u{647}"),
+    (0xFCDF, "\u{64a}\u{654}\u{645}"),
+    (0xFCE0, "\u{64a}\u{654}\u{647}"),
+    (0xFCE1, "\u{628}\u{645}"),
+    (0xFCE2, "\u{628}\u{647}"),
+    (0xFCE3, "\u{62a}\u{645}"),
+    (0xFCE4, "\u{62a}\u{647}"),
+    (0xFCE5, "\u{62b}\u{645}"),
+    (0xFCE6, "\u{62b}\u{647}"),
+    (0xFCE7, "\u{633}\u{645}"),
+    (0xFCE8, "\u{633}\u{647}"),
+    (0xFCE9, "\u{634}\u{645}"),
+    (0xFCEA, "\u{634}\u{647}"),
+    (0xFCEB, "\u{643}\u{644}"),
+    (0xFCEC, "\u{643}\u{645}"),
+    (0xFCED, "\u{644}\u{645}"),
+    (0xFCEE, "\u{646}\u{645}"),
+    (0xFCEF, "\u{646}\u{647}"),
+    (0xFCF0, "\u{64a}\u{645}"),
+    (0xFCF1, "\u{64a}\u{647}"),
+    (0xFCF2, "\u{640}\u{64e}\u{651}"),
+    (0xFCF3, "\u{640}\u{64f}\u{651}"),
+    (0xFCF4, "\u{640}\u{650}\u{651}"),
+    (0xFCF5, "\u{637}\u{649}"),
+    (0xFCF6, "\u{637}\u{64a}"),
+    (0xFCF7, "\u{639}\u{649}"),
+    (0xFCF8, "\u{639}\u{64a}"),
+    (0xFCF9, "\u{63a}\u{649}"),
+    (0xFCFA, "\u{63a}\u{64a}"),
+    (0xFCFB, "\u{633}\u{649}"),
+    (0xFCFC, "\u{633}\u{64a}"),
+    (0xFCFD, "\u{634}\u{649}"),
+    (0xFCFE, "\u{634}\u{64a}"),
+    (0xFCFF, "\u{62d}\u{649}"),
+    (0xFD00, "\u{62d}\u{64a}"),
+    (0xFD01, "\u{62c}\u{649}"),
+    (0xFD02, "\u{62c}\u{64a}"),
+    (0xFD03, "\u{62e}\u{649}"),
+    (0xFD04, "\u{62e}\u{64a}"),
+    (0xFD05, "\u{635}\u{649}"),
+    (0xFD06, "\u{635}\u{64a}"),
+    (0xFD07, "\u{636}\u{649}"),
+    (0xFD08, "\u{636}\u{64a}"),
+    (0xFD09, "\u{634}\u{62c}"),
+    (0xFD0A, "\u{634}\u{62d}"),
+    (0xFD0B, "\u{634}\u{62e}"),
+    (0xFD0C, "\u{634}\u{645}"),
+    (0xFD0D, "\u{634}\u{631}"),
+    (0xFD0E, "\u{633}\u{631}"),
+    (0xFD0F, "\u{635}\u{631}"),
+    (0xFD10, "\u{636}\u{631}"),
+    (0xFD11, "\u{637}\u{649}"),
+    (0xFD12, "\u{637}\u{64a}"),
+    (0xFD13, "\u{639}\u{649}"),
+    (0xFD14, "\u{639}\u{64a}"),
+    (0xFD15, "\u{63a}\u{649}"),
+    (0xFD16, "\u{63a}\u{64a}"),
+    (0xFD17, "\u{633}\u{649}"),
+    (0xFD18, "\u{633}\u{64a}"),
+    (0xFD19, "\u{634}\u{649}"),
+    (0xFD1A, "\u{634}\u{64a}"),
+    (0xFD1B, "\u{62d}\u{649}"),
+    (0xFD1C, "\u{62d}\u{64a}"),
+    (0xFD1D, "\u{62c}\u{649}"),
+    (0xFD1E, "\u{62c}\u{64a}"),
+    (0xFD1F, "\u{62e}\u{649}"),
+    (0xFD20, "\u{62e}\u{64a}"),
+    (0xFD21, "\u{635}\u{649}"),
+    (0xFD22, "\u{635}\u{64a}"),
+    (0xFD23, "\u{636}\u{649}"),
+    (0xFD24, "\u{636}\u{64a}"),
+    (0xFD25, "\u{634}\u{62c}"),
+    (0xFD26, "\u{634}\u{62d}"),
+    (0xFD27, "\u{634}\u{62e}"),
+    (0xFD28, "\u{634}\u{645}"),
+    (0xFD29, "\u{634}\u{631}"),
+    (0xFD2A, "\u{633}\u{631}"),
+    (0xFD2B, "\u{635}\u{631}"),
+    (0xFD2C, "\u{636}\u{631}"),
+    (0xFD2D, "\u{634}\u{62c}"),
+    (0xFD2E, "\u{634}\u{62d}"),
+    (0xFD2F, "\u{634}\u{62e}"),
+    (0xFD30, "\u{634}\u{645}"),
+    (0xFD31, "\u{633}\u{647}"),
+    (0xFD32, "\u{634}\u{647}"),
+    (0xFD33, "\u{637}\u{645}"),
+    (0xFD34, "\u{633}\u{62c}"),
+    (0xFD35, "\u{633}\u{62d}"),
+    (0xFD36, "\u{633}\u{62e}"),
+    (0xFD37, "\u{634}\u{62c}"),
+    (0xFD38, "\u{634}\u{62d}"),
+    (0xFD39, "\u{634}\u{62e}"),
+    (0xFD3A, "\u{637}\u{645}"),
+    (0xFD3B, "\u{638}\u{645}"),
+    (0xFD3C, "\u{627}\u{64b}"),
+    (0xFD3D, "\u{627}\u{64b}"),
+    (0xFD50, "\u{62a}\u{62c}\u{645}"),
+    (0xFD51, "\u{62a}\u{62d}\u{62c}"),
+    (0xFD52, "\u{62a}\u{62d}\u{62c}"),
+    (0xFD53, "\u{62a}\u{62d}\u{645}"),
+    (0xFD54, "\u{62a}\u{62e}\u{645}"),
+    (0xFD55, "\u{62a}\u{645}\u{62c}"),
+    (0xFD56, "\u{62a}\u{645}\u{62d}"),
+    (0xFD57, "\u{62a}\u{645}\u{62e}"),
+    (0xFD58, "\u{62c}\u{645}\u{62d}"),
+    (0xFD59, "\u{62c}\u{645}\u{62d}"),
+    (0xFD5A, "\u{62d}\u{645}\u{64a}"),
+    (0xFD5B, "\u{62d}\u{645}\u{649}"),
+    (0xFD5C, "\u{633}\u{62d}\u{62c}"),
+    (0xFD5D, "\u{633}\u{62c}\u{62d}"),
+    (0xFD5E, "\u{633}\u{62c}\u{649}"),
+    (0xFD5F, "\u{633}\u{645}\u{62d}"),
+    (0xFD60, "\u{633}\u{645}\u{62d}"),
+    (0xFD61, "\u{633}\u{645}\u{62c}"),
+    (0xFD62, "\u{633}\u{645}\u{645}"),
+    (0xFD63, "\u{633}\u{645}\u{645}"),
+    (0xFD64, "\u{635}\u{62d}\u{62d}"),
+    (0xFD65, "\u{635}\u{62d}\u{62d}"),
+    (0xFD66, "\u{635}\u{645}\u{645}"),
+    (0xFD67, "\u{634}\u{62d}\u{645}"),
+    (0xFD68, "\u{634}\u{62d}\u{645}"),
+    (0xFD69, "\u{634}\u{62c}\u{64a}"),
+    (0xFD6A, "\u{634}\u{645}\u{62e}"),
+    (0xFD6B, "\u{634}\u{645}\u{62e}"),
+    (0xFD6C, "\u{634}\u{645}\u{645}"),
+    (0xFD6D, "\u{634}\u{645}\u{645}"),
+    (0xFD6E, "\u{636}\u{62d}\u{649}"),
+    (0xFD6F, "\u{636}\u{62e}\u{645}"),
+    (0xFD70, "\u{636}\u{62e}\u{645}"),
+    (0xFD71, "\u{637}\u{645}\u{62d}"),
+    (0xFD72, "\u{637}\u{645}\u{62d}"),
+    (0xFD73, "\u{637}\u{645}\u{645}"),
+    (0xFD74, "\u{637}\u{645}\u{64a}"),
+    (0xFD75, "\u{639}\u{62c}\u{645}"),
+    (0xFD76, "\u{639}\u{645}\u{645}"),
+    (0xFD77, "\u{639}\u{645}\u{645}"),
+    (0xFD78, "\u{639}\u{645}\u{649}"),
+    (0xFD79, "\u{63a}\u{645}\u{645}"),
+    (0xFD7A, "\u{63a}\u{645}\u{64a}"),
+    (0xFD7B, "\u{63a}\u{645}\u{649}"),
+    (0xFD7C, "\u{641}\u{62e}\u{645}"),
+    (0xFD7D, "\u{641}\u{62e}\u{645}"),
+    (0xFD7E, "\u{642}\u{645}\u{62d}"),
+    (0xFD7F, "\u{642}\u{645}\u{645}"),
+    (0xFD80, "\u{644}\u{62d}\u{645}"),
+    (0xFD81, "\u{644}\u{62d}\u{64a}"),
+    (0xFD82, "\u{644}\u{62d}\u{649}"),
+    (0xFD83, "\u{644}\u{62c}\u{62c}"),
+    (0xFD84, "\u{644}\u{62c}\u{62c}"),
+    (0xFD85, "\u{644}\u{62e}\u{645}"),
+    (0xFD86, "\u{644}\u{62e}\u{645}"),
+    (0xFD87, "\u{644}\u{645}\u{62d}"),
+    (0xFD88, "\u{644}\u{645}\u{62d}"),
+    (0xFD89, "\u{645}\u{62d}\u{62c}"),
+    (0xFD8A, "\u{645}\u{62d}\u{645}"),
+    (0xFD8B, "\u{645}\u{62d}\u{64a}"),
+    (0xFD8C, "\u{645}\u{62c}\u{62d}"),
+    (0xFD8D, "\u{645}\u{62c}\u{645}"),
+    (0xFD8E, "\u{645}\u{62e}\u{62c}"),
+    (0xFD8F, "\u{645}\u{62e}\u{645}"),
+    (0xFD92, "\u{645}\u{62c}\u{62e}"),
+    (0xFD93, "\u{647}\u{645}\u{62c}"),
+    (0xFD94, "\u{647}\u{645}\u{645}"),
+    (0xFD95, "\u{646}\u{62d}\u{645}"),
+    (0xFD96, "\u{646}\u{62d}\u{649}"),
+    (0xFD97, "\u{646}\u{62c}\u{645}"),
+    (0xFD98, "\u{646}\u{62c}\u{645}"),
+    (0xFD99, "\u{646}\u{62c}\u{649}"),
+    (0xFD9A, "\u{646}\u{645}\u{64a}"),
+    (0xFD9B, "\u{646}\u{645}\u{649}"),
+    (0xFD9C, "\u{64a}\u{645}\u{645}"),
+    (0xFD9D, "\u{64a}\u{645}\u{645}"),
+    (0xFD9E, "\u{628}\u{62e}\u{64a}"),
+    (0xFD9F, "\u{62a}\u{62c}\u{64a}"),
+    (0xFDA0, "\u{62a}\u{62c}\u{649}"),
+    (0xFDA1, "\u{62a}\u{62e}\u{64a}"),
+    (0xFDA2, "\u{62a}\u{62e}\u{649}"),
+    (0xFDA3, "\u{62a}\u{645}\u{64a}"),
+    (0xFDA4, "\u{62a}\u{645}\u{649}"),
+    (0xFDA5, "\u{62c}\u{645}\u{64a}"),
+    (0xFDA6, "\u{62c}\u{62d}\u{649}"),
+    (0xFDA7, "\u{62c}\u{645}\u{649}"),
+    (0xFDA8, "\u{633}\u{62e}\u{649}"),
+    (0xFDA9, "\u{635}\u{62d}\u{64a}"),
+    (0xFDAA, "\u{634}\u{62d}\u{64a}"),
+    (0xFDAB, "\u{636}\u{62d}\u{64a}"),
+    (0xFDAC, "\u{644}\u{62c}\u{64a}"),
+    (0xFDAD, "\u{644}\u{645}\u{64a}"),
+    (0xFDAE, "\u{64a}\u{62d}\u{64a}"),
+    (0xFDAF, "\u{64a}\u{62c}\u{64a}"),
+    (0xFDB0, "\u{64a}\u{645}\u{64a}"),
+    (0xFDB1, "\u{645}\u{645}\u{64a}"),
+    (0xFDB2, "\u{642}\u{645}\u{64a}"),
+    (0xFDB3, "\u{646}\u{62d}\u{64a}"),
+    (0xFDB4, "\u{642}\u{645}\u{62d}"),
+    (0xFDB5, "\u{644}\u{62d}\u{645}"),
+    (0xFDB6, "\u{639}\u{645}\u{64a}"),
+    (0xFDB7, "\u{643}\u{645}\u{64a}"),
+    (0xFDB8, "\u{646}\u{62c}\u{62d}"),
+    (0xFDB9, "\u{645}\u{62e}\u{64a}"),
+    (0xFDBA, "\u{644}\u{62c}\u{645}"),
+    (0xFDBB, "\u{643}\u{645}\u{645}"),
+    (0xFDBC, "\u{644}\u{62c}\u{645}"),
+    (0xFDBD, "\u{646}\u{62c}\u{62d}"),
+    (0xFDBE, "\u{62c}\u{62d}\u{64a}"),
+    (0xFDBF, "\u{62d}\u{62c}\u{64a}"),
+    (0xFDC0, "\u{645}\u{62c}\u{64a}"),
+    (0xFDC1, "\u{641}\u{645}\u{64a}"),
+    (0xFDC2, "\u{628}\u{62d}\u{64a}"),
+    (0xFDC3, "\u{643}\u{645}\u{645}"),
+    (0xFDC4, "\u{639}\u{62c}\u{645}"),
+    (0xFDC5, "\u{635}\u{645}\u{645}"),
+    (0xFDC6, "\u{633}\u{62e}\u{64a}"),
+    (0xFDC7, "\u{646}\u{62c}\u{64a}"),
+    (0xFDF0, "\u{635}\u{644}\u{6d2}"),
+    (0xFDF1, "\u{642}\u{644}\u{6d2}"),
+    (0xFDF2, "\u{627}\u{644}\u{644}\u{647}"),
+    (0xFDF3, "\u{627}\u{643}\u{628}\u{631}"),
+    (0xFDF4, "\u{645}\u{62d}\u{645}\u{62f}"),
+    (0xFDF5, "\u{635}\u{644}\u{639}\u{645}"),
+    (0xFDF6, "\u{631}\u{633}\u{648}\u{644}"),
+    (0xFDF7, "\u{639}\u{644}\u{64a}\u{647}"),
+    (0xFDF8, "\u{648}\u{633}\u{644}\u{645}"),
+    (0xFDF9, "\u{635}\u{644}\u{649}"),
+    (0xFDFA, "\u{635}\u{644}\u{649}\u{20}\u{627}\u{644}\u{644}\u{647}\u{20}\u{639}\u{644}\u{64a}\u{647}\u{20}\u{648}\u{633}\u{644}\u{645}"),
+    (0xFDFB, "\u{62c}\u{644}\u{20}\u{62c}\u{644}\u{627}\u{644}\u{647}"),
+    (0xFDFC, "\u{631}\u{6cc}\u{627}\u{644}"),
+    (0xFE10, "\u{2c}"),
+    (0xFE11, "\u{3001}"),
+    (0xFE12, "\u{3002}"),
+    (0xFE13, "\u{3a}"),
+    (0xFE14, "\u{3b}"),
+    (0xFE15, "\u{21}"),
+    (0xFE16, "\u{3f}"),
+    (0xFE17, "\u{3016}"),
+    (0xFE18, "\u{3017}"),
+    (0xFE19, "\u{2e}\u{2e}\u{2e}"),
+    (0xFE30, "\u{2e}\u{2e}"),
+    (0xFE31, "\u{2014}"),
+    (0xFE32, "\u{2013}"),
+    (0xFE33, "\u{5f}"),
+    (0xFE34, "\u{5f}"),
+    (0xFE35, "\u{28}"),
+    (0xFE36, "\u{29}"),
+    (0xFE37, "\u{7b}"),
+    (0xFE38, "\u{7d}"),
+    (0xFE39, "\u{3014}"),
+    (0xFE3A, "\u{3015}"),
+    (0xFE3B, "\u{3010}"),
+    (0xFE3C, "\u{3011}"),
+    (0xFE3D, "\u{300a}"),
+    (0xFE3E, "\u{300b}"),
+    (0xFE3F, "\u{3008}"),
+    (0xFE40, "\u{3009}"),
+    (0xFE41, "\u{300c}"),
+    (0xFE42, "\u{300d}"),
+    (0xFE43, "\u{300e}"),
+    (0xFE44, "\u{300f}"),
+    (0xFE47, "\u{5b}"),
+    (0xFE48, "\u{5d}"),
+    (0xFE49, "\u{20}\u{305}"),
+    (0xFE4A, "\u{20}\u{305}"),
+    (0xFE4B, "\u{20}\u{305}"),
+    (0xFE4C, "\u{20}\u{305}"),
+    (0xFE4D, "\u{5f}"),
+    (0xFE4E, "\u{5f}"),
+    (0xFE4F, "\u{5f}"),
+    (0xFE50, "\u{2c}"),
+    (0xFE51, "\u{3001}"),
+    (0xFE52, "\u{2e}"),
+    (0xFE54, "\u{3b}"),
+    (0xFE55, "\u{3a}"),
+    (0xFE56, "\u{3f}"),
+    (0xFE57, "\u{21}"),
+    (0xFE58, "\u{2014}"),
+    (0xFE59, "\u{28}"),
+    (0xFE5A, "\u{29}"),
+    (0xFE5B, "\u{7b}"),
+    (0xFE5C, "\u{7d}"),
+    (0xFE5D, "\u{3014}"),
+    (0xFE5E, "\u{3015}"),
+    (0xFE5F, "\u{23}"),
+    (0xFE60, "\u{26}"),
+    (0xFE61, "\u{2a}"),
+    (0xFE62, "\u{2b}"),
+    (0xFE63, "\u{2d}"),
+    (0xFE64, "\u{3c}"),
+    (0xFE65, "\u{3e}"),
+    (0xFE66, "\u{3d}"),
+    (0xFE68, "\u{5c}"),
+    (0xFE69, "\u{24}"),
+    (0xFE6A, "\u{25}"),
+    (0xFE6B, "\u{40}"),
+    (0xFE70, "\u{20}\u{64b}"),
+    (0xFE71, "\u{640}\u{64b}"),
+    (0xFE72, "\u{20}\u{64c}"),
+    (0xFE74, "\u{20}\u{64d}"),
+    (0xFE76, "\u{20}\u{64e}"),
+    (0xFE77, "\u{640}\u{64e}"),
+    (0xFE78, "\u{20}\u{64f}"),
+    (0xFE79, "\u{640}\u{64f}"),
+    (0xFE7A, "\u{20}\u{650}"),
+    (0xFE7B, "\u{640}\u{650}"),
+    (0xFE7C, "\u{20}\u{651}"),
+    (0xFE7D, "\u{640}\u{651}"),
+    (0xFE7E, "\u{20}\u{652}"),
+    (0xFE7F, "\u{640}\u{652}"),
+    (0xFE80, "\u{621}"),
+    (0xFE81, "\u{627}\u{653}"),
+    (0xFE82, "\u{627}\u{653}"),
+    (0xFE83, "\u{627}\u{654}"),
+    (0xFE84, "\u{627}\u{654}"),
+    (0xFE85, "\u{648}\u{654}"),
+    (0xFE86, "\u{648}\u{654}"),
+    (0xFE87, "\u{627}\u{655}"),
+    (0xFE88, "\u{627}\u{655}"),
+    (0xFE89, "\u{64a}\u{654}"),
+    (0xFE8A, "\u{64a}\u{654}"),
+    (0xFE8B, "\u{64a}\u{654}"),
+    (0xFE8C, "\u{64a}\u{654}"),
+    (0xFE8D, "\u{627}"),
+    (0xFE8E, "\u{627}"),
+    (0xFE8F, "\u{628}"),
+    (0xFE90, "\u{628}"),
+    (0xFE91, "\u{628}"),
+    (0xFE92, "\u{628}"),
+    (0xFE93, "\u{629}"),
+    (0xFE94, "\u{629}"),
+    (0xFE95, "\u{62a}"),
+    (0xFE96, "\u{62a}"),
+    (0xFE97, "\u{62a}"),
+    (0xFE98, "\u{62a}"),
+    (0xFE99, "\u{62b}"),
+    (0xFE9A, "\u{62b}"),
+    (0xFE9B, "\u{62b}"),
+    (0xFE9C, "\u{62b}"),
+    (0xFE9D, "\u{62c}"),
+    (0xFE9E, "\u{62c}"),
+    (0xFE9F, "\u{62c}"),
+    (0xFEA0, "\u{62c}"),
+    (0xFEA1, "\u{62d}"),
+    (0xFEA2, "\u{62d}"),
+    (0xFEA3, "\u{62d}"),
+    (0xFEA4, "\u{62d}"),
+    (0xFEA5, "\u{62e}"),
+    (0xFEA6, "\u{62e}"),
+    (0xFEA7, "\u{62e}"),
+    (0xFEA8, "\u{62e}"),
+    (0xFEA9, "\u{62f}"),
+    (0xFEAA, "\u{62f}"),
+    (0xFEAB, "\u{630}"),
+    (0xFEAC, "\u{630}"),
+    (0xFEAD, "\u{631}"),
+    (0xFEAE, "\u{631}"),
+    (0xFEAF, "\u{632}"),
+    (0xFEB0, "\u{632}"),
+    (0xFEB1, "\u{633}"),
+    (0xFEB2, "\u{633}"),
+    (0xFEB3, "\u{633}"),
+    (0xFEB4, "\u{633}"),
+    (0xFEB5, "\u{634}"),
+    (0xFEB6, "\u{634}"),
+    (0xFEB7, "\u{634}"),
+    (0xFEB8, "\u{634}"),
+    (0xFEB9, "\u{635}"),
+    (0xFEBA, "\u{635}"),
+    (0xFEBB, "\u{635}"),
+    (0xFEBC, "\u{635}"),
+    (0xFEBD, "\u{636}"),
+    (0xFEBE, "\u{636}"),
+    (0xFEBF, "\u{636}"),
+    (0xFEC0, "\u{636}"),
+    (0xFEC1, "\u{637}"),
+    (0xFEC2, "\u{637}"),
+    (0xFEC3, "\u{637}"),
+    (0xFEC4, "\u{637}"),
+    (0xFEC5, "\u{638}"),
+    (0xFEC6, "\u{638}"),
+    (0xFEC7, "\u{638}"),
+    (0xFEC8, "\u{638}"),
+    (0xFEC9, "\u{639}"),
+    (0xFECA, "\u{639}"),
+    (0xFECB, "\u{639}"),
+    (0xFECC, "\u{639}"),
+    (0xFECD, "\u{63a}"),
+    (0xFECE, "\u{63a}"),
+    (0xFECF, "\u{63a}"),
+    (0xFED0, "\u{63a}"),
+    (0xFED1, "\u{641}"),
+    (0xFED2, "\u{641}"),
+    (0xFED3, "\u{641}"),
+    (0xFED4, "\u{641}"),
+    (0xFED5, "\u{642}"),
+    (0xFED6, "\u{642}"),
+    (0xFED7, "\u{642}"),
+    (0xFED8, "\u{642}"),
+    (0xFED9, "\u{643}"),
+    (0xFEDA, "\u{643}"),
+    (0xFEDB, "\u{643}"),
+    (0xFEDC, "\u{643}"),
+    (0xFEDD, "\u{644}"),
+    (0xFEDE, "\u{644}"),
+    (0xFEDF, "\u{644}"),
+    (0xFEE0, "\u{644}"),
+    (0xFEE1, "\u{645}"),
+    (0xFEE2, "\u{645}"),
+    (0xFEE3, "\u{645}"),
+    (0xFEE4, "\u{645}"),
+    (0xFEE5, "\u{646}"),
+    (0xFEE6, "\u{646}"),
+    (0xFEE7, "\u{646}"),
+    (0xFEE8, "\u{646}"),
+    (0xFEE9, "\u{647}"),
+    (0xFEEA, "\u{647}"),
+    (0xFEEB, "\u{647}"),
+    (0xFEEC, "\u{647}"),
+    (0xFEED, "\u{648}"),
+    (0xFEEE, "\u{648}"),
+    (0xFEEF, "\u{649}"),
+    (0xFEF0, "\u{649}"),
+    (0xFEF1, "\u{64a}"),
+    (0xFEF2, "\u{64a}"),
+    (0xFEF3, "\u{64a}"),
+    (0xFEF4, "\u{64a}"),
+    (0xFEF5, "\u{644}\u{627}\u{653}"),
+    (0xFEF6, "\u{644}\u{627}\u{653}"),
+    (0xFEF7, "\u{644}\u{627}\u{654}"),
+    (0xFEF8, "\u{644}\u{627}\u{654}"),
+    (0xFEF9, "\u{644}\u{627}\u{655}"),
+    (0xFEFA, "\u{644}\u{627}\u{655}"),
+    (0xFEFB, "\u{644}\u{627}"),
+    (0xFEFC, "\u{644}\u{627}"),
+    (0xFF01, "\u{21}"),
+    (0xFF02, "\u{22}"),
+    (0xFF03, "\u{23}"),
+    (0xFF04, "\u{24}"),
+    (0xFF05, "\u{25}"),
+    (0xFF06, "\u{26}"),
+    (0xFF07, "\u{27}"),
+    (0xFF08, "\u{28}"),
+    (0xFF09, "\u{29}"),
+    (0xFF0A, "\u{2a}"),
+    (0xFF0B, "\u{2b}"),
+    (0xFF0C, "\u{2c}"),
+    (0xFF0D, "\u{2d}"),
+    (0xFF0E, "\u{2e}"),
+    (0xFF0F, "\u{2f}"),
+    (0xFF10, "\u{30}"),
+    (0xFF11, "\u{31}"),
+    (0xFF12, "\u{32}"),
+    (0xFF13, "\u{33}"),
+    (0xFF14, "\u{34}"),
+    (0xFF15, "\u{35}"),
+    (0xFF16, "\u{36}"),
+    (0xFF17, "\u{37}"),
+    (0xFF18, "\u{38}"),
+    (0xFF19, "\u{39}"),
+    (0xFF1A, "\u{3a}"),
+    (0xFF1B, "\u{3b}"),
+    (0xFF1C, "\u{3c}"),
+    (0xFF1D, "\u{3d}"),
+    (0xFF1E, "\u{3e}"),
+    (0xFF1F, "\u{3f}"),
+    (0xFF20, "\u{40}"),
+    (0xFF21, "\u{41}"),
+    (0xFF22, "\u{42}"),
+    (0xFF23, "\u{43}"),
+    (0xFF24, "\u{44}"),
+    (0xFF25, "\u{45}"),
+    (0xFF26, "\u{46}"),
+    (0xFF27, "\u{47}"),
+    (0xFF28, "\u{48}"),
+    (0xFF29, "\u{49}"),
+    (0xFF2A, "\u{4a}"),
+    (0xFF2B, "\u{4b}"),
+    (0xFF2C, "\u{4c}"),
+    (0xFF2D, "\u{4d}"),
+    (0xFF2E, "\u{4e}"),
+    (0xFF2F, "\u{4f}"),
+    (0xFF30, "\u{50}"),
+    (0xFF31, "\u{51}"),
+    (0xFF32, "\u{52}"),
+    (0xFF33, "\u{53}"),
+    (0xFF34, "\u{54}"),
+    (0xFF35, "\u{55}"),
+    (0xFF36, "\u{56}"),
+    (0xFF37, "\u{57}"),
+    (0xFF38, "\u{58}"),
+    (0xFF39, "\u{59}"),
+    (0xFF3A, "\u{5a}"),
+    (0xFF3B, "\u{5b}"),
+    (0xFF3C, "\u{5c}"),
+    (0xFF3D, "\u{5d}"),
+    (0xFF3E, "\u{5e}"),
+    (0xFF3F, "\u{5f}"),
+    (0xFF40, "\u{60}"),
+    (0xFF41, "\u{61}"),
+    (0xFF42, "\u{62}"),
+    (0xFF43, "\u{63}"),
+    (0xFF44, "\u{64}"),
+    (0xFF45, "\u{65}"),
+    (0xFF46, "\u{66}"),
+    (0xFF47, "\u{67}"),
+    (0xFF48, "\u{68}"),
+    (0xFF49, "\u{69}"),
+    (0xFF4A, "\u{6a}"),
+    (0xFF4B, "\u{6b}"),
+    (0xFF4C, "\u{6c}"),
+    (0xFF4D, "\u{6d}"),
+    (0xFF4E, "\u{6e}"),
+    (0xFF4F, "\u{6f}"),
+    (0xFF50, "\u{70}"),
+    (0xFF51, "\u{71}"),
+    (0xFF52, "\u{72}"),
+    (0xFF53, "\u{73}"),
+    (0xFF54, "\u{74}"),
+    (0xFF55, "\u{75}"),
+    (0xFF56, "\u{76}"),
+    (0xFF57, "\u{77}"),
+    (0xFF58, "\u{78}"),
+    (0xFF59, "\u{79}"),
+    (0xFF5A, "\u{7a}"),
+    (0xFF5B, "\u{7b}"),
+    (0xFF5C, "\u{7c}"),
+    (0xFF5D, "\u{7d}"),
+    (0xFF5E, "\u{7e}"),
+    (0xFF5F, "\u{2985}"),
+    (0xFF60, "\u{2986}"),
+    (0xFF61, "\u{3002}"),
+    (0xFF62, "\u{300c}"),
+    (0xFF63, "\u{300d}"),
+    (0xFF64, "\u{3001}"),
+    (0xFF65, "\u{30fb}"),
+    (0xFF66, "\u{30f2}"),
+    (0xFF67, "\u{30a1}"),
+    (0xFF68, "\u{30a3}"),
+    (0xFF69, "\u{30a5}"),
+    (0xFF6A, "\u{30a7}"),
+    (0xFF6B, "\u{30a9}"),
+    (0xFF6C, "\u{30e3}"),
+    (0xFF6D, "\u{30e5}"),
+    (0xFF6E, "\u{30e7}"),
+    (0xFF6F, "\u{30c3}"),
+    (0xFF70, "\u{30fc}"),
+    (0xFF71, "\u{30a2}"),
+    (0xFF72, "\u{30a4}"),
+    (0xFF73, "\u{30a6}"),
+    (0xFF74, "\u{30a8}"),
+    (0xFF75, "\u{30aa}"),
+    (0xFF76, "\u{30ab}"),
+    (0xFF77, "\u{30ad}"),
+    (0xFF78, "\u{30af}"),
+    (0xFF79, "\u{30b1}"),
+    (0xFF7A, "\u{30b3}"),
+    (0xFF7B, "\u{30b5}"),
+    (0xFF7C, "\u{30b7}"),
+    (0xFF7D, "\u{30b9}"),
+    (0xFF7E, "\u{30bb}"),
+    (0xFF7F, "\u{30bd}"),
+    (0xFF80, "\u{30bf}"),
+    (0xFF81, "\u{30c1}"),
+    (0xFF82, "\u{30c4}"),
+    (0xFF83, "\u{30c6}"),
+    (0xFF84, "\u{30c8}"),
+    (0xFF85, "\u{30ca}"),
+    (0xFF86, "\u{30cb}"),
+    (0xFF87, "\u{30cc}"),
+    (0xFF88, "\u{30cd}"),
+    (0xFF89, "\u{30ce}"),
+    (0xFF8A, "\u{30cf}"),
+    (0xFF8B, "\u{30d2}"),
+    (0xFF8C, "\u{30d5}"),
+    (0xFF8D, "\u{30d8}"),
+    (0xFF8E, "\u{30db}"),
+    (0xFF8F, "\u{30de}"),
+    (0xFF90, "\u{30df}"),
+    (0xFF91, "\u{30e0}"),
+    (0xFF92, "\u{30e1}"),
+    (0xFF93, "\u{30e2}"),
+    (0xFF94, "\u{30e4}"),
+    (0xFF95, "\u{30e6}"),
+    (0xFF96, "\u{30e8}"),
+    (0xFF97, "\u{30e9}"),
+    (0xFF98, "\u{30ea}"),
+    (0xFF99, "\u{30eb}"),
+    (0xFF9A, "\u{30ec}"),
+    (0xFF9B, "\u{30ed}"),
+    (0xFF9C, "\u{30ef}"),
+    (0xFF9D, "\u{30f3}"),
+    (0xFF9E, "\u{3099}"),
+    (0xFF9F, "\u{309a}"),
+    (0xFFA0, "\u{1160}"),
+    (0xFFA1, "\u{1100}"),
+    (0xFFA2, "\u{1101}"),
+    (0xFFA3, "\u{11aa}"),
+    (0xFFA4, "\u{1102}"),
+    (0xFFA5, "\u{11ac}"),
+    (0xFFA6, "\u{11ad}"),
+    (0xFFA7, "\u{1103}"),
+    (0xFFA8, "\u{1104}"),
+    (0xFFA9, "\u{1105}"),
+    (0xFFAA, "\u{11b0}"),
+    (0xFFAB, "\u{11b1}"),
+    (0xFFAC, "\u{11b2}"),
+    (0xFFAD, "\u{11b3}"),
+    (0xFFAE, "\u{11b4}"),
+    (0xFFAF, "\u{11b5}"),
+    (0xFFB0, "\u{111a}"),
+    (0xFFB1, "\u{1106}"),
+    (0xFFB2, "\u{1107}"),
+    (0xFFB3, "\u{1108}"),
+    (0xFFB4, "\u{1121}"),
+    (0xFFB5, "\u{1109}"),
+    (0xFFB6, "\u{110a}"),
+    (0xFFB7, "\u{110b}"),
+    (0xFFB8, "\u{110c}"),
+    (0xFFB9, "\u{110d}"),
+    (0xFFBA, "\u{110e}"),
+    (0xFFBB, "\u{110f}"),
+    (0xFFBC, "\u{1110}"),
+    (0xFFBD, "\u{1111}"),
+    (0xFFBE, "\u{1112}"),
+    (0xFFC2, "\u{1161}"),
+    (0xFFC3, "\u{1162}"),
+    (0xFFC4, "\u{1163}"),
+    (0xFFC5, "\u{1164}"),
+    (0xFFC6, "\u{1165}"),
+    (0xFFC7, "\u{1166}"),
+    (0xFFCA, "\u{1167}"),
+    (0xFFCB, "\u{1168}"),
+    (0xFFCC, "\u{1169}"),
+    (0xFFCD, "\u{116a}"),
+    (0xFFCE, "\u{116b}"),
+    (0xFFCF, "\u{116c}"),
+    (0xFFD2, "\u{116d}"),
+    (0xFFD3, "\u{116e}"),
+    (0xFFD4, "\u{116f}"),
+    (0xFFD5, "\u{1170}"),
+    (0xFFD6, "\u{1171}"),
+    (0xFFD7, "\u{1172}"),
+    (0xFFDA, "\u{1173}"),
+    (0xFFDB, "\u{1174}"),
+    (0xFFDC, "\u{1175}"),
+    (0xFFE0, "\u{a2}"),
+    (0xFFE1, "\u{a3}"),
+    (0xFFE2, "\u{ac}"),
+    (0xFFE3, "\u{20}\u{304}"),
+    (0xFFE4, "\u{a6}"),
+    (0xFFE5, "\u{a5}"),
+    (0xFFE6, "\u{20a9}"),
+    (0xFFE8, "\u{2502}"),
+    (0xFFE9, "\u{2190}"),
+    (0xFFEA, "\u{2191}"),
+    (0xFFEB, "\u{2192}"),
+    (0xFFEC, "\u{2193}"),
+    (0xFFED, "\u{25a0}"),
+    (0xFFEE, "\u{25cb}"),
+    (0x10781, "\u{2d0}"),
+    (0x10782, "\u{2d1}"),
+    (0x10783, "\u{e6}"),
+    (0x10784, "\u{299}"),
+    (0x10785, "\u{253}"),
+    (0x10787, "\u{2a3}"),
+    (0x10788, "\u{ab66}"),
+    (0x10789, "\u{2a5}"),
+    (0x1078A, "\u{2a4}"),
+    (0x1078B, "\u{256}"),
+    (0x1078C, "\u{257}"),
+    (0x1078D, "\u{1d91}"),
+    (0x1078E, "\u{258}"),
+    (0x1078F, "\u{25e}"),
+    (0x10790, "\u{2a9}"),
+    (0x10791, "\u{264}"),
+    (0x10792, "\u{262}"),
+    (0x10793, "\u{260}"),
+    (0x10794, "\u{29b}"),
+    (0x10795, "\u{127}"),
+    (0x10796, "\u{29c}"),
+    (0x10797, "\u{267}"),
+    (0x10798, "\u{284}"),
+    (0x10799, "\u{2aa}"),
+    (0x1079A, "\u{2ab}"),
+    (0x1079B, "\u{26c}"),
+    (0x1079C, "\u{1df04}"),
+    (0x1079D, "\u{a78e}"),
+    (0x1079E, "\u{26e}"),
+    (0x1079F, "\u{1df05}"),
+    (0x107A0, "\u{28e}"),
+    (0x107A1, "\u{1df06}"),
+    (0x107A2, "\u{f8}"),
+    (0x107A3, "\u{276}"),
+    (0x107A4, "\u{277}"),
+    (0x107A5, "\u{71}"),
+    (0x107A6, "\u{27a}"),
+    (0x107A7, "\u{1df08}"),
+    (0x107A8, "\u{27d}"),
+    (0x107A9, "\u{27e}"),
+    (0x107AA, "\u{280}"),
+    (0x107AB, "\u{2a8}"),
+    (0x107AC, "\u{2a6}"),
+    (0x107AD, "\u{ab67}"),
+    (0x107AE, "\u{2a7}"),
+    (0x107AF, "\u{288}"),
+    (0x107B0, "\u{2c71}"),
+    (0x107B2, "\u{28f}"),
+    (0x107B3, "\u{2a1}"),
+    (0x107B4, "\u{2a2}"),
+    (0x107B5, "\u{298}"),
+    (0x107B6, "\u{1c0}"),
+    (0x107B7, "\u{1c1}"),
+    (0x107B8, "\u{1c2}"),
+    (0x107B9, "\u{1df0a}"),
+    (0x107BA, "\u{1df1e}"),
+    (0x1D400, "\u{41}"),
+    (0x1D401, "\u{42}"),
+    (0x1D402, "\u{43}"),
+    (0x1D403, "\u{44}"),
+    (0x1D404, "\u{45}"),
+    (0x1D405, "\u{46}"),
+    (0x1D406, "\u{47}"),
+    (0x1D407, "\u{48}"),
+    (0x1D408, "\u{49}"),
+    (0x1D409, "\u{4a}"),
+    (0x1D40A, "\u{4b}"),
+    (0x1D40B, "\u{4c}"),
+    (0x1D40C, "\u{4d}"),
+    (0x1D40D, "\u{4e}"),
+    (0x1D40E, "\u{4f}"),
+    (0x1D40F, "\u{50}"),
+    (0x1D410, "\u{51}"),
+    (0x1D411, "\u{52}"),
+    (0x1D412, "\u{53}"),
+    (0x1D413, "\u{54}"),
+    (0x1D414, "\u{55}"),
+    (0x1D415, "\u{56}"),
+    (0x1D416, "\u{57}"),
+    (0x1D417, "\u{58}"),
+    (0x1D418, "\u{59}"),
+    (0x1D419, "\u{5a}"),
+    (0x1D41A, "\u{61}"),
+    (0x1D41B, "\u{62}"),
+    (0x1D41C, "\u{63}"),
+    (0x1D41D, "\u{64}"),
+    (0x1D41E, "\u{65}"),
+    (0x1D41F, "\u{66}"),
+    (0x1D420, "\u{67}"),
+    (0x1D421, "\u{68}"),
+    (0x1D422, "\u{69}"),
+    (0x1D423, "\u{6a}"),
+    (0x1D424, "\u{6b}"),
+    (0x1D425, "\u{6c}"),
+    (0x1D426, "\u{6d}"),
+    (0x1D427, "\u{6e}"),
+    (0x1D428, "\u{6f}"),
+    (0x1D429, "\u{70}"),
+    (0x1D42A, "\u{71}"),
+    (0x1D42B, "\u{72}"),
+    (0x1D42C, "\u{73}"),
+    (0x1D42D, "\u{74}"),
+    (0x1D42E, "\u{75}"),
+    (0x1D42F, "\u{76}"),
+    (0x1D430, "\u{77}"),
+    (0x1D431, "\u{78}"),
+    (0x1D432, "\u{79}"),
+    (0x1D433, "\u{7a}"),
+    (0x1D434, "\u{41}"),
+    (0x1D435, "\u{42}"),
+    (0x1D436, "\u{43}"),
+    (0x1D437, "\u{44}"),
+    (0x1D438, "\u{45}"),
+    (0x1D439, "\u{46}"),
+    (0x1D43A, "\u{47}"),
+    (0x1D43B, "\u{48}"),
+    (0x1D43C, "\u{49}"),
+    (0x1D43D, "\u{4a}"),
+    (0x1D43E, "\u{4b}"),
+    (0x1D43F, "\u{4c}"),
+    (0x1D440, "\u{4d}"),
+    (0x1D441, "\u{4e}"),
+    (0x1D442, "\u{4f}"),
+    (0x1D443, "\u{50}"),
+    (0x1D444, "\u{51}"),
+    (0x1D445, "\u{52}"),
+    (0x1D446, "\u{53}"),
+    (0x1D447, "\u{54}"),
+    (0x1D448, "\u{55}"),
+    (0x1D449, "\u{56}"),
+    (0x1D44A, "\u{57}"),
+    (0x1D44B, "\u{58}"),
+    (0x1D44C, "\u{59}"),
+    (0x1D44D, "\u{5a}"),
+    (0x1D44E, "\u{61}"),
+    (0x1D44F, "\u{62}"),
+    (0x1D450, "\u{63}"),
+    (0x1D451, "\u{64}"),
+    (0x1D452, "\u{65}"),
+    (0x1D453, "\u{66}"),
+    (0x1D454, "\u{67}"),
+    (0x1D456, "\u{69}"),
+    (0x1D457, "\u{6a}"),
+    (0x1D458, "\u{6b}"),
+    (0x1D459, "\u{6c}"),
+    (0x1D45A, "\u{6d}"),
+    (0x1D45B, "\u{6e}"),
+    (0x1D45C, "\u{6f}"),
+    (0x1D45D, "\u{70}"),
+    (0x1D45E, "\u{71}"),
+    (0x1D45F, "\u{72}"),
+    (0x1D460, "\u{73}"),
+    (0x1D461, "\u{74}"),
+    (0x1D462, "\u{75}"),
+    (0x1D463, "\u{76}"),
+    (0x1D464, "\u{77}"),
+    (0x1D465, "\u{78}"),
+    (0x1D466, "\u{79}"),
+    (0x1D467, "\u{7a}"),
+    (0x1D468, "\u{41}"),
+    (0x1D469, "\u{42}"),
+    (0x1D46A, "\u{43}"),
+    (0x1D46B, "\u{44}"),
+    (0x1D46C, "\u{45}"),
+    (0x1D46D, "\u{46}"),
+    (0x1D46E, "\u{47}"),
+    (0x1D46F, "\u{48}"),
+    (0x1D470, "\u{49}"),
+    (0x1D471, "\u{4a}"),
+    (0x1D472, "\u{4b}"),
+    (0x1D473, "\u{4c}"),
+    (0x1D474, "\u{4d}"),
+    (0x1D475, "\u{4e}"),
+    (0x1D476, "\u{4f}"),
+    (0x1D477, "\u{50}"),
+    (0x1D478, "\u{51}"),
+    (0x1D479, "\u{52}"),
+    (0x1D47A, "\u{53}"),
+    (0x1D47B, "\u{54}"),
+    (0x1D47C, "\u{55}"),
+    (0x1D47D, "\u{56}"),
+    (0x1D47E, "\u{57}"),
+    (0x1D47F, "\u{58}"),
+    (0x1D480, "\u{59}"),
+    (0x1D481, "\u{5a}"),
+    (0x1D482, "\u{61}"),
+    (0x1D483, "\u{62}"),
+    (0x1D484, "\u{63}"),
+    (0x1D485, "\u{64}"),
+    (0x1D486, "\u{65}"),
+    (0x1D487, "\u{66}"),
+    (0x1D488, "\u{67}"),
+    (0x1D489, "\u{68}"),
+    (0x1D48A, "\u{69}"),
+    (0x1D48B, "\u{6a}"),
+    (0x1D48C, "\u{6b}"),
+    (0x1D48D, "\u{6c}"),
+    (0x1D48E, "\u{6d}"),
+    (0x1D48F, "\u{6e}"),
+    (0x1D490, "\u{6f}"),
+    (0x1D491, "\u{70}"),
+    (0x1D492, "\u{71}"),
+    (0x1D493, "\u{72}"),
+    (0x1D494, "\u{73}"),
+    (0x1D495, "\u{74}"),
+    (0x1D496, "\u{75}"),
+    (0x1D497, "\u{76}"),
+    (0x1D498, "\u{77}"),
+    (0x1D499, "\u{78}"),
+    (0x1D49A, "\u{79}"),
+    (0x1D49B, "\u{7a}"),
+    (0x1D49C, "\u{41}"),
+    (0x1D49E, "\u{43}"),
+    (0x1D49F, "\u{44}"),
+    (0x1D4A2, "\u{47}"),
+    (0x1D4A5, "\u{4a}"),
+    (0x1D4A6, "\u{4b}"),
+    (0x1D4A9, "\u{4e}"),
+    (0x1D4AA, "\u{4f}"),
+    (0x1D4AB, "\u{50}"),
+    (0x1D4AC, "\u{51}"),
+    (0x1D4AE, "\u{53}"),
+    (0x1D4AF, "\u{54}"),
+    (0x1D4B0, "\u{55}"),
+    (0x1D4B1, "\u{56}"),
+    (0x1D4B2, "\u{57}"),
+    (0x1D4B3, "\u{58}"),
+    (0x1D4B4, "\u{59}"),
+    (0x1D4B5, "\u{5a}"),
+    (0x1D4B6, "\u{61}"),
+    (0x1D4B7, "\u{62}"),
+    (0x1D4B8, "\u{63}"),
+    (0x1D4B9, "\u{64}"),
+    (0x1D4BB, "\u{66}"),
+    (0x1D4BD, "\u{68}"),
+    (0x1D4BE, "\u{69}"),
+    (0x1D4BF, "\u{6a}"),
+    (0x1D4C0, "\u{6b}"),
+    (0x1D4C1, "\u{6c}"),
+    (0x1D4C2, "\u{6d}"),
+    (0x1D4C3, "\u{6e}"),
+    (0x1D4C5, "\u{70}"),
+    (0x1D4C6, "\u{71}"),
+    (0x1D4C7, "\u{72}"),
+    (0x1D4C8, "\u{73}"),
+    (0x1D4C9, "\u{74}"),
+    (0x1D4CA, "\u{75}"),
+    (0x1D4CB, "\u{76}"),
+    (0x1D4CC, "\u{77}"),
+    (0x1D4CD, "\u{78}"),
+    (0x1D4CE, "\u{79}"),
+    (0x1D4CF, "\u{7a}"),
+    (0x1D4D0, "\u{41}"),
+    (0x1D4D1, "\u{42}"),
+    (0x1D4D2, "\u{43}"),
+    (0x1D4D3, "\u{44}"),
+    (0x1D4D4, "\u{45}"),
+    (0x1D4D5, "\u{46}"),
+    (0x1D4D6, "\u{47}"),
+    (0x1D4D7, "\u{48}"),
+    (0x1D4D8, "\u{49}"),
+    (0x1D4D9, "\u{4a}"),
+    (0x1D4DA, "\u{4b}"),
+    (0x1D4DB, "\u{4c}"),
+    (0x1D4DC, "\u{4d}"),
+    (0x1D4DD, "\u{4e}"),
+    (0x1D4DE, "\u{4f}"),
+    (0x1D4DF, "\u{50}"),
+    (0x1D4E0, "\u{51}"),
+    (0x1D4E1, "\u{52}"),
+    (0x1D4E2, "\u{53}"),
+    (0x1D4E3, "\u{54}"),
+    (0x1D4E4, "\u{55}"),
+    (0x1D4E5, "\u{56}"),
+    (0x1D4E6, "\u{57}"),
+    (0x1D4E7, "\u{58}"),
+    (0x1D4E8, "\u{59}"),
+    (0x1D4E9, "\u{5a}"),
+    (0x1D4EA, "\u{61}"),
+    (0x1D4EB, "\u{62}"),
+    (0x1D4EC, "\u{63}"),
+    (0x1D4ED, "\u{64}"),
+    (0x1D4EE, "\u{65}"),
+    (0x1D4EF, "\u{66}"),
+    (0x1D4F0, "\u{67}"),
+    (0x1D4F1, "\u{68}"),
+    (0x1D4F2, "\u{69}"),
+    (0x1D4F3, "\u{6a}"),
+    (0x1D4F4, "\u{6b}"),
+    (0x1D4F5, "\u{6c}"),
+    (0x1D4F6, "\u{6d}"),
+    (0x1D4F7, "\u{6e}"),
+    (0x1D4F8, "\u{6f}"),
+    (0x1D4F9, "\u{70}"),
+    (0x1D4FA, "\u{71}"),
+    (0x1D4FB, "\u{72}"),
+    (0x1D4FC, "\u{73}"),
+    (0x1D4FD, "\u{74}"),
+    (0x1D4FE, "\u{75}"),
+    (0x1D4FF, "\u{76}"),
+    (0x1D500, "\u{77}"),
+    (0x1D501, "\u{78}"),
+    (0x1D502, "\u{79}"),
+    (0x1D503, "\u{7a}"),
+    (0x1D504, "\u{41}"),
+    (0x1D505, "\u{42}"),
+    (0x1D507, "\u{44}"),
+    (0x1D508, "\u{45}"),
+    (0x1D509, "\u{46}"),
+    (0x1D50A, "\u{47}"),
+    (0x1D50D, "\u{4a}"),
+    (0x1D50E, "\u{4b}"),
+    (0x1D50F, "\u{4c}"),
+    (0x1D510, "\u{4d}"),
+    (0x1D511, "\u{4e}"),
+    (0x1D512, "\u{4f}"),
+    (0x1D513, "\u{50}"),
+    (0x1D514, "\u{51}"),
+    (0x1D516, "\u{53}"),
+    (0x1D517, "\u{54}"),
+    (0x1D518, "\u{55}"),
+    (0x1D519, "\u{56}"),
+    (0x1D51A, "\u{57}"),
+    (0x1D51B, "\u{58}"),
+    (0x1D51C, "\u{59}"),
+    (0x1D51E, "\u{61}"),
+    (0x1D51F, "\u{62}"),
+    (0x1D520, "\u{63}"),
+    (0x1D521, "\u{64}"),
+    (0x1D522, "\u{65}"),
+    (0x1D523, "\u{66}"),
+    (0x1D524, "\u{67}"),
+    (0x1D525, "\u{68}"),
+    (0x1D526, "\u{69}"),
+    (0x1D527, "\u{6a}"),
+    (0x1D528, "\u{6b}"),
+    (0x1D529, "\u{6c}"),
+    (0x1D52A, "\u{6d}"),
+    (0x1D52B, "\u{6e}"),
+    (0x1D52C, "\u{6f}"),
+    (0x1D52D, "\u{70}"),
+    (0x1D52E, "\u{71}"),
+    (0x1D52F, "\u{72}"),
+    (0x1D530, "\u{73}"),
+    (0x1D531, "\u{74}"),
+    (0x1D532, "\u{75}"),
+    (0x1D533, "\u{76}"),
+    (0x1D534, "\u{77}"),
+    (0x1D535, "\u{78}"),
+    (0x1D536, "\u{79}"),
+    (0x1D537, "\u{7a}"),
+    (0x1D538, "\u{41}"),
+    (0x1D539, "\u{42}"),
+    (0x1D53B, "\u{44}"),
+    (0x1D53C, "\u{45}"),
+    (0x1D53D, "\u{46}"),
+    (0x1D53E, "\u{47}"),
+    (0x1D540, "\u{49}"),
+    (0x1D541, "\u{4a}"),
+    (0x1D542, "\u{4b}"),
+    (0x1D543, "\u{4c}"),
+    (0x1D544, "\u{4d}"),
+    (0x1D546, "\u{4f}"),
+    (0x1D54A, "\u{53}"),
+    (0x1D54B, "\u{54}"),
+    (0x1D54C, "\u{55}"),
+    (0x1D54D, "\u{56}"),
+    (0x1D54E, "\u{57}"),
+    (0x1D54F, "\u{58}"),
+    (0x1D550, "\u{59}"),
+    (0x1D552, "\u{61}"),
+    (0x1D553, "\u{62}"),
+    (0x1D554, "\u{63}"),
+    (0x1D555, "\u{64}"),
+    (0x1D556, "\u{65}"),
+    (0x1D557, "\u{66}"),
+    (0x1D558, "\u{67}"),
+    (0x1D559, "\u{68}"),
+    (0x1D55A, "\u{69}"),
+    (0x1D55B, "\u{6a}"),
+    (0x1D55C, "\u{6b}"),
+    (0x1D55D, "\u{6c}"),
+    (0x1D55E, "\u{6d}"),
+    (0x1D55F, "\u{6e}"),
+    (0x1D560, "\u{6f}"),
+    (0x1D561, "\u{70}"),
+    (0x1D562, "\u{71}"),
+    (0x1D563, "\u{72}"),
+    (0x1D564, "\u{73}"),
+    (0x1D565, "\u{74}"),
+    (0x1D566, "\u{75}"),
+    (0x1D567, "\u{76}"),
+    (0x1D568, "\u{77}"),
+    (0x1D569, "\u{78}"),
+    (0x1D56A, "\u{79}"),
+    (0x1D56B, "\u{7a}"),
+    (0x1D56C, "\u{41}"),
+    (0x1D56D, "\u{42}"),
+    (0x1D56E, "\u{43}"),
+    (0x1D56F, "\u{44}"),
+    (0x1D570, "\u{45}"),
+    (0x1D571, "\u{46}"),
+    (0x1D572, "\u{47}"),
+    (0x1D573, "\u{48}"),
+    (0x1D574, "\u{49}"),
+    (0x1D575, "\u{4a}"),
+    (0x1D576, "\u{4b}"),
+    (0x1D577, "\u{4c}"),
+    (0x1D578, "\u{4d}"),
+    (0x1D579, "\u{4e}"),
+    (0x1D57A, "\u{4f}"),
+    (0x1D57B, "\u{50}"),
+    (0x1D57C, "\u{51}"),
+    (0x1D57D, "\u{52}"),
+    (0x1D57E, "\u{53}"),
+    (0x1D57F, "\u{54}"),
+    (0x1D580, "\u{55}"),
+    (0x1D581, "\u{56}"),
+    (0x1D582, "\u{57}"),
+    (0x1D583, "\u{58}"),
+    (0x1D584, "\u{59}"),
+    (0x1D585, "\u{5a}"),
+    (0x1D586, "\u{61}"),
+    (0x1D587, "\u{62}"),
+    (0x1D588, "\u{63}"),
+    (0x1D589, "\u{64}"),
+    (0x1D58A, "\u{65}"),
+    (0x1D58B, "\u{66}"),
+    (0x1D58C, "\u{67}"),
+    (0x1D58D, "\u{68}"),
+    (0x1D58E, "\u{69}"),
+    (0x1D58F, "\u{6a}"),
+    (0x1D590, "\u{6b}"),
+    (0x1D591, "\u{6c}"),
+    (0x1D592, "\u{6d}"),
+    (0x1D593, "\u{6e}"),
+    (0x1D594, "\u{6f}"),
+    (0x1D595, "\u{70}"),
+    (0x1D596, "\u{71}"),
+    (0x1D597, "\u{72}"),
+    (0x1D598, "\u{73}"),
+    (0x1D599, "\u{74}"),
+    (0x1D59A, "\u{75}"),
+    (0x1D59B, "\u{76}"),
+    (0x1D59C, "\u{77}"),
+    (0x1D59D, "\u{78}"),
+    (0x1D59E, "\u{79}"),
+    (0x1D59F, "\u{7a}"),
+    (0x1D5A0, "\u{41}"),
+    (0x1D5A1, "\u{42}"),
+    (0x1D5A2, "\u{43}"),
+    (0x1D5A3, "\u{44}"),
+    (0x1D5A4, "\u{45}"),
+    (0x1D5A5, "\u{46}"),
+    (0x1D5A6, "\u{47}"),
+    (0x1D5A7, "\u{48}"),
+    (0x1D5A8, "\u{49}"),
+    (0x1D5A9, "\u{4a}"),
+    (0x1D5AA, "\u{4b}"),
+    (0x1D5AB, "\u{4c}"),
+    (0x1D5AC, "\u{4d}"),
+    (0x1D5AD, "\u{4e}"),
+    (0x1D5AE, "\u{4f}"),
+    (0x1D5AF, "\u{50}"),
+    (0x1D5B0, "\u{51}"),
+    (0x1D5B1, "\u{52}"),
+    (0x1D5B2, "\u{53}"),
+    (0x1D5B3, "\u{54}"),
+    (0x1D5B4, "\u{55}"),
+    (0x1D5B5, "\u{56}"),
+    (0x1D5B6, "\u{57}"),
+    (0x1D5B7, "\u{58}"),
+    (0x1D5B8, "\u{59}"),
+    (0x1D5B9, "\u{5a}"),
+    (0x1D5BA, "\u{61}"),
+    (0x1D5BB, "\u{62}"),
+    (0x1D5BC, "\u{63}"),
+    (0x1D5BD, "\u{64}"),
+    (0x1D5BE, "\u{65}"),
+    (0x1D5BF, "\u{66}"),
+    (0x1D5C0, "\u{67}"),
+    (0x1D5C1, "\u{68}"),
+    (0x1D5C2, "\u{69}"),
+    (0x1D5C3, "\u{6a}"),
+    (0x1D5C4, "\u{6b}"),
+    (0x1D5C5, "\u{6c}"),
+    (0x1D5C6, "\u{6d}"),
+    (0x1D5C7, "\u{6e}"),
+    (0x1D5C8, "\u{6f}"),
+    (0x1D5C9, "\u{70}"),
+    (0x1D5CA, "\u{71}"),
+    (0x1D5CB, "\u{72}"),
+    (0x1D5CC, "\u{73}"),
+    (0x1D5CD, "\u{74}"),
+    (0x1D5CE, "\u{75}"),
+    (0x1D5CF, "\u{76}"),
+    (0x1D5D0, "\u{77}"),
+    (0x1D5D1, "\u{78}"),
+    (0x1D5D2, "\u{79}"),
+    (0x1D5D3, "\u{7a}"),
+    (0x1D5D4, "\u{41}"),
+    (0x1D5D5, "\u{42}"),
+    (0x1D5D6, "\u{43}"),
+    (0x1D5D7, "\u{44}"),
+    (0x1D5D8, "\u{45}"),
+    (0x1D5D9, "\u{46}"),
+    (0x1D5DA, "\u{47}"),
+    (0x1D5DB, "\u{48}"),
+    (0x1D5DC, "\u{49}"),
+    (0x1D5DD, "\u{4a}"),
+    (0x1D5DE, "\u{4b}"),
+    (0x1D5DF, "\u{4c}"),
+    (0x1D5E0, "\u{4d}"),
+    (0x1D5E1, "\u{4e}"),
+    (0x1D5E2, "\u{4f}"),
+    (0x1D5E3, "\u{50}"),
+    (0x1D5E4, "\u{51}"),
+    (0x1D5E5, "\u{52}"),
+    (0x1D5E6, "\u{53}"),
+    (0x1D5E7, "\u{54}"),
+    (0x1D5E8, "\u{55}"),
+    (0x1D5E9, "\u{56}"),
+    (0x1D5EA, "\u{57}"),
+    (0x1D5EB, "\u{58}"),
+    (0x1D5EC, "\u{59}"),
+    (0x1D5ED, "\u{5a}"),
+    (0x1D5EE, "\u{61}"),
+    (0x1D5EF, "\u{62}"),
+    (0x1D5F0, "\u{63}"),
+    (0x1D5F1, "\u{64}"),
+    (0x1D5F2, "\u{65}"),
+    (0x1D5F3, "\u{66}"),
+    (0x1D5F4, "\u{67}"),
+    (0x1D5F5, "\u{68}"),
+    (0x1D5F6, "\u{69}"),
+    (0x1D5F7, "\u{6a}"),
+    (0x1D5F8, "\u{6b}"),
+    (0x1D5F9, "\u{6c}"),
+    (0x1D5FA, "\u{6d}"),
+    (0x1D5FB, "\u{6e}"),
+    (0x1D5FC, "\u{6f}"),
+    (0x1D5FD, "\u{70}"),
+    (0x1D5FE, "\u{71}"),
+    (0x1D5FF, "\u{72}"),
+    (0x1D600, "\u{73}"),
+    (0x1D601, "\u{74}"),
+    (0x1D602, "\u{75}"),
+    (0x1D603, "\u{76}"),
+    (0x1D604, "\u{77}"),
+    (0x1D605, "\u{78}"),
+    (0x1D606, "\u{79}"),
+    (0x1D607, "\u{7a}"),
+    (0x1D608, "\u{41}"),
+    (0x1D609, "\u{42}"),
+    (0x1D60A, "\u{43}"),
+    (0x1D60B, "\u{44}"),
+    (0x1D60C, "\u{45}"),
+    (0x1D60D, "\u{46}"),
+    (0x1D60E, "\u{47}"),
+    (0x1D60F, "\u{48}"),
+    (0x1D610, "\u{49}"),
+    (0x1D611, "\u{4a}"),
+    (0x1D612, "\u{4b}"),
+    (0x1D613, "\u{4c}"),
+    (0x1D614, "\u{4d}"),
+    (0x1D615, "\u{4e}"),
+    (0x1D616, "\u{4f}"),
+    (0x1D617, "\u{50}"),
+    (0x1D618, "\u{51}"),
+    (0x1D619, "\u{52}"),
+    (0x1D61A, "\u{53}"),
+    (0x1D61B, "\u{54}"),
+    (0x1D61C, "\u{55}"),
+    (0x1D61D, "\u{56}"),
+    (0x1D61E, "\u{57}"),
+    (0x1D61F, "\u{58}"),
+    (0x1D620, "\u{59}"),
+    (0x1D621, "\u{5a}"),
+    (0x1D622, "\u{61}"),
+    (0x1D623, "\u{62}"),
+    (0x1D624, "\u{63}"),
+    (0x1D625, "\u{64}"),
+    (0x1D626, "\u{65}"),
+    (0x1D627, "\u{66}"),
+    (0x1D628, "\u{67}"),
+    (0x1D629, "\u{68}"),
+    (0x1D62A, "\u{69}"),
+    (0x1D62B, "\u{6a}"),
+    (0x1D62C, "\u{6b}"),
+    (0x1D62D, "\u{6c}"),
+    (0x1D62E, "\u{6d}"),
+    (0x1D62F, "\u{6e}"),
+    (0x1D630, "\u{6f}"),
+    (0x1D631, "\u{70}"),
+    (0x1D632, "\u{71}"),
+    (0x1D633, "\u{72}"),
+    (0x1D634, "\u{73}"),
+    (0x1D635, "\u{74}"),
+    (0x1D636, "\u{75}"),
+    (0x1D637, "\u{76}"),
+    (0x1D638, "\u{77}"),
+    (0x1D639, "\u{78}"),
+    (0x1D63A, "\u{79}"),
+    (0x1D63B, "\u{7a}"),
+    (0x1D63C, "\u{41}"),
+    (0x1D63D, "\u{42}"),
+    (0x1D63E, "\u{43}"),
+    (0x1D63F, "\u{44}"),
+    (0x1D640, "\u{45}"),
+    (0x1D641, "\u{46}"),
+    (0x1D642, "\u{47}"),
+    (0x1D643, "\u{48}"),
+    (0x1D644, "\u{49}"),
+    (0x1D645, "\u{4a}"),
+    (0x1D646, "\u{4b}"),
+    (0x1D647, "\u{4c}"),
+    (0x1D648, "\u{4d}"),
+    (0x1D649, "\u{4e}"),
+    (0x1D64A, "\u{4f}"),
+    (0x1D64B, "\u{50}"),
+    (0x1D64C, "\u{51}"),
+    (0x1D64D, "\u{52}"),
+    (0x1D64E, "\u{53}"),
+    (0x1D64F, "\u{54}"),
+    (0x1D650, "\u{55}"),
+    (0x1D651, "\u{56}"),
+    (0x1D652, "\u{57}"),
+    (0x1D653, "\u{58}"),
+    (0x1D654, "\u{59}"),
+    (0x1D655, "\u{5a}"),
+    (0x1D656, "\u{61}"),
+    (0x1D657, "\u{62}"),
+    (0x1D658, "\u{63}"),
+    (0x1D659, "\u{64}"),
+    (0x1D65A, "\u{65}"),
+    (0x1D65B, "\u{66}"),
+    (0x1D65C, "\u{67}"),
+    (0x1D65D, "\u{68}"),
+    (0x1D65E, "\u{69}"),
+    (0x1D65F, "\u{6a}"),
+    (0x1D660, "\u{6b}"),
+    (0x1D661, "\u{6c}"),
+    (0x1D662, "\u{6d}"),
+    (0x1D663, "\u{6e}"),
+    (0x1D664, "\u{6f}"),
+    (0x1D665, "\u{70}"),
+    (0x1D666, "\u{71}"),
+    (0x1D667, "\u{72}"),
+    (0x1D668, "\u{73}"),
+    (0x1D669, "\u{74}"),
+    (0x1D66A, "\u{75}"),
+    (0x1D66B, "\u{76}"),
+    (0x1D66C, "\u{77}"),
+    (0x1D66D, "\u{78}"),
+    (0x1D66E, "\u{79}"),
+    (0x1D66F, "\u{7a}"),
+    (0x1D670, "\u{41}"),
+    (0x1D671, "\u{42}"),
+    (0x1D672, "\u{43}"),
+    (0x1D673, "\u{44}"),
+    (0x1D674, "\u{45}"),
+    (0x1D675, "\u{46}"),
+    (0x1D676, "\u{47}"),
+    (0x1D677, "\u{48}"),
+    (0x1D678, "\u{49}"),
+    (0x1D679, "\u{4a}"),
+    (0x1D67A, "\u{4b}"),
+    (0x1D67B, "\u{4c}"),
+    (0x1D67C, "\u{4d}"),
+    (0x1D67D, "\u{4e}"),
+    (0x1D67E, "\u{4f}"),
+    (0x1D67F, "\u{50}"),
+    (0x1D680, "\u{51}"),
+    (0x1D681, "\u{52}"),
+    (0x1D682, "\u{53}"),
+    (0x1D683, "\u{54}"),
+    (0x1D684, "\u{55}"),
+    (0x1D685, "\u{56}"),
+    (0x1D686, "\u{57}"),
+    (0x1D687, "\u{58}"),
+    (0x1D688, "\u{59}"),
+    (0x1D689, "\u{5a}"),
+    (0x1D68A, "\u{61}"),
+    (0x1D68B, "\u{62}"),
+    (0x1D68C, "\u{63}"),
+    (0x1D68D, "\u{64}"),
+    (0x1D68E, "\u{65}"),
+    (0x1D68F, "\u{66}"),
+    (0x1D690, "\u{67}"),
+    (0x1D691, "\u{68}"),
+    (0x1D692, "\u{69}"),
+    (0x1D693, "\u{6a}"),
+    (0x1D694, "\u{6b}"),
+    (0x1D695, "\u{6c}"),
+    (0x1D696, "\u{6d}"),
+    (0x1D697, "\u{6e}"),
+    (0x1D698, "\u{6f}"),
+    (0x1D699, "\u{70}"),
+    (0x1D69A, "\u{71}"),
+    (0x1D69B, "\u{72}"),
+    (0x1D69C, "\u{73}"),
+    (0x1D69D, "\u{74}"),
+    (0x1D69E, "\u{75}"),
+    (0x1D69F, "\u{76}"),
+    (0x1D6A0, "\u{77}"),
+    (0x1D6A1, "\u{78}"),
+    (0x1D6A2, "\u{79}"),
+    (0x1D6A3, "\u{7a}"),
+    (0x1D6A4, "\u{131}"),
+    (0x1D6A5, "\u{237}"),
+    (0x1D6A8, "\u{391}"),
+    (0x1D6A9, "\u{392}"),
+    (0x1D6AA, "\u{393}"),
+    (0x1D6AB, "\u{394}"),
+    (0x1D6AC, "\u{395}"),
+    (0x1D6AD, "\u{396}"),
+    (0x1D6AE, "\u{397}"),
+    (0x1D6AF, "\u{398}"),
+    (0x1D6B0, "\u{399}"),
+    (0x1D6B1, "\u{39a}"),
+    (0x1D6B2, "\u{39b}"),
+    (0x1D6B3, "\u{39c}"),
+    (0x1D6B4, "\u{39d}"),
+    (0x1D6B5, "\u{39e}"),
+    (0x1D6B6, "\u{39f}"),
+    (0x1D6B7, "\u{3a0}"),
+    (0x1D6B8, "\u{3a1}"),
+    (0x1D6B9, "\u{398}"),
+    (0x1D6BA, "\u{3a3}"),
+    (0x1D6BB, "\u{3a4}"),
+    (0x1D6BC, "\u{3a5}"),
+    (0x1D6BD, "\u{3a6}"),
+    (0x1D6BE, "\u{3a7}"),
+    (0x1D6BF, "\u{3a8}"),
+    (0x1D6C0, "\u{3a9}"),
+    (0x1D6C1, "\u{2207}"),
+    (0x1D6C2, "\u{3b1}"),
+    (0x1D6C3, "\u{3b2}"),
+    (0x1D6C4, "\u{3b3}"),
+    (0x1D6C5, "\u{3b4}"),
+    (0x1D6C6, "\u{3b5}"),
+    (0x1D6C7, "\u{3b6}"),
+    (0x1D6C8, "\u{3b7}"),
+    (0x1D6C9, "\u{3b8}"),
+    (0x1D6CA, "\u{3b9}"),
+    (0x1D6CB, "\u{3ba}"),
+    (0x1D6CC, "\u{3bb}"),
+    (0x1D6CD, "\u{3bc}"),
+    (0x1D6CE, "\u{3bd}"),
+    (0x1D6CF, "\u{3be}"),
+    (0x1D6D0, "\u{3bf}"),
+    (0x1D6D1, "\u{3c0}"),
+    (0x1D6D2, "\u{3c1}"),
+    (0x1D6D3, "\u{3c2}"),
+    (0x1D6D4, "\u{3c3}"),
+    (0x1D6D5, "\u{3c4}"),
+    (0x1D6D6, "\u{3c5}"),
+    (0x1D6D7, "\u{3c6}"),
+    (0x1D6D8, "\u{3c7}"),
+    (0x1D6D9, "\u{3c8}"),
+    (0x1D6DA, "\u{3c9}"),
+    (0x1D6DB, "\u{2202}"),
+    (0x1D6DC, "\u{3b5}"),
+    (0x1D6DD, "\u{3b8}"),
+    (0x1D6DE, "\u{3ba}"),
+    (0x1D6DF, "\u{3c6}"),
+    (0x1D6E0, "\u{3c1}"),
+    (0x1D6E1, "\u{3c0}"),
+    (0x1D6E2, "\u{391}"),
+    (0x1D6E3, "\u{392}"),
+    (0x1D6E4, "\u{393}"),
+    (0x1D6E5, "\u{394}"),
+    (0x1D6E6, "\u{395}"),
+    (0x1D6E7, "\u{396}"),
+    (0x1D6E8, "\u{397}"),
+    (0x1D6E9, "\u{398}"),
+    (0x1D6EA, "\u{399}"),
+    (0x1D6EB, "\u{39a}"),
+    (0x1D6EC, "\u{39b}"),
+    (0x1D6ED, "\u{39c}"),
+    (0x1D6EE, "\u{39d}"),
+    (0x1D6EF, "\u{39e}"),
+    (0x1D6F0, "\u{39f}"),
+    (0x1D6F1, "\u{3a0}"),
+    (0x1D6F2, "\u{3a1}"),
+    (0x1D6F3, "\u{398}"),
+    (0x1D6F4, "\u{3a3}"),
+    (0x1D6F5, "\u{3a4}"),
+    (0x1D6F6, "\u{3a5}"),
+    (0x1D6F7, "\u{3a6}"),
+    (0x1D6F8, "\u{3a7}"),
+    (0x1D6F9, "\u{3a8}"),
+    (0x1D6FA, "\u{3a9}"),
+    (0x1D6FB, "\u{2207}"),
+    (0x1D6FC, "\u{3b1}"),
+    (0x1D6FD, "\u{3b2}"),
+    (0x1D6FE, "\u{3b3}"),
+    (0x1D6FF, "\u{3b4}"),
+    (0x1D700, "\u{3b5}"),
+    (0x1D701, "\u{3b6}"),
+    (0x1D702, "\u{3b7}"),
+    (0x1D703, "\u{3b8}"),
+    (0x1D704, "\u{3b9}"),
+    (0x1D705, "\u{3ba}"),
+    (0x1D706, "\u{3bb}"),
+    (0x1D707, "\u{3bc}"),
+    (0x1D708, "\u{3bd}"),
+    (0x1D709, "\u{3be}"),
+    (0x1D70A, "\u{3bf}"),
+    (0x1D70B, "\u{3c0}"),
+    (0x1D70C, "\u{3c1}"),
+    (0x1D70D, "\u{3c2}"),
+    (0x1D70E, "\u{3c3}"),
+    (0x1D70F, "\u{3c4}"),
+    (0x1D710, "\u{3c5}"),
+    (0x1D711, "\u{3c6}"),
+    (0x1D712, "\u{3c7}"),
+    (0x1D713, "\u{3c8}"),
+    (0x1D714, "\u{3c9}"),
+    (0x1D715, "\u{2202}"),
+    (0x1D716, "\u{3b5}"),
+    (0x1D717, "\u{3b8}"),
+    (0x1D718, "\u{3ba}"),
+    (0x1D719, "\u{3c6}"),
+    (0x1D71A, "\u{3c1}"),
+    (0x1D71B, "\u{3c0}"),
+    (0x1D71C, "\u{391}"),
+    (0x1D71D, "\u{392}"),
+    (0x1D71E, "\u{393}"),
+    (0x1D71F, "\u{394}"),
+    (0x1D720, "\u{395}"),
+    (0x1D721, "\u{396}"),
+    (0x1D722, "\u{397}"),
+    (0x1D723, "\u{398}"),
+    (0x1D724, "\u{399}"),
+    (0x1D725, "\u{39a}"),
+    (0x1D726, "\u{39b}"),
+    (0x1D727, "\u{39c}"),
+    (0x1D728, "\u{39d}"),
+    (0x1D729, "\u{39e}"),
+    (0x1D72A, "\u{39f}"),
+    (0x1D72B, "\u{3a0}"),
+    (0x1D72C, "\u{3a1}"),
+    (0x1D72D, "\u{398}"),
+    (0x1D72E, "\u{3a3}"),
+    (0x1D72F, "\u{3a4}"),
+    (0x1D730, "\u{3a5}"),
+    (0x1D731, "\u{3a6}"),
+    (0x1D732, "\u{3a7}"),
+    (0x1D733, "\u{3a8}"),
+    (0x1D734, "\u{3a9}"),
+    (0x1D735, "\u{2207}"),
+    (0x1D736, "\u{3b1}"),
+    (0x1D737, "\u{3b2}"),
+    (0x1D738, "\u{3b3}"),
+    (0x1D739, "\u{3b4}"),
+    (0x1D73A, "\u{3b5}"),
+    (0x1D73B, "\u{3b6}"),
+    (0x1D73C, "\u{3b7}"),
+    (0x1D73D, "\u{3b8}"),
+    (0x1D73E, "\u{3b9}"),
+    (0x1D73F, "\u{3ba}"),
+    (0x1D740, "\u{3bb}"),
+    (0x1D741, "\u{3bc}"),
+    (0x1D742, "\u{3bd}"),
+    (0x1D743, "\u{3be}"),
+    (0x1D744, "\u{3bf}"),
+    (0x1D745, "\u{3c0}"),
+    (0x1D746, "\u{3c1}"),
+    (0x1D747, "\u{3c2}"),
+    (0x1D748, "\u{3c3}"),
+    (0x1D749, "\u{3c4}"),
+    (0x1D74A, "\u{3c5}"),
+    (0x1D74B, "\u{3c6}"),
+    (0x1D74C, "\u{3c7}"),
+    (0x1D74D, "\u{3c8}"),
+    (0x1D74E, "\u{3c9}"),
+    (0x1D74F, "\u{2202}"),
+    (0x1D750, "\u{3b5}"),
+    (0x1D751, "\u{3b8}"),
+    (0x1D752, "\u{3ba}"),
+    (0x1D753, "\u{3c6}"),
+    (0x1D754, "\u{3c1}"),
+    (0x1D755, "\u{3c0}"),
+    (0x1D756, "\u{391}"),
+    (0x1D757, "\u{392}"),
+    (0x1D758, "\u{393}"),
+    (0x1D759, "\u{394}"),
+    (0x1D75A, "\u{395}"),
+    (0x1D75B, "\u{396}"),
+    (0x1D75C, "\u{397}"),
+    (0x1D75D, "\u{398}"),
+    (0x1D75E, "\u{399}"),
+    (0x1D75F, "\u{39a}"),
+    (0x1D760, "\u{39b}"),
+    (0x1D761, "\u{39c}"),
+    (0x1D762, "\u{39d}"),
+    (0x1D763, "\u{39e}"),
+    (0x1D764, "\u{39f}"),
+    (0x1D765, "\u{3a0}"),
+    (0x1D766, "\u{3a1}"),
+    (0x1D767, "\u{398}"),
+    (0x1D768, "\u{3a3}"),
+    (0x1D769, "\u{3a4}"),
+    (0x1D76A, "\u{3a5}"),
+    (0x1D76B, "\u{3a6}"),
+    (0x1D76C, "\u{3a7}"),
+    (0x1D76D, "\u{3a8}"),
+    (0x1D76E, "\u{3a9}"),
+    (0x1D76F, "\u{2207}"),
+    (0x1D770, "\u{3b1}"),
+    (0x1D771, "\u{3b2}"),
+    (0x1D772, "\u{3b3}"),
+    (0x1D773, "\u{3b4}"),
+    (0x1D774, "\u{3b5}"),
+    (0x1D775, "\u{3b6}"),
+    (0x1D776, "\u{3b7}"),
+    (0x1D777, "\u{3b8}"),
+    (0x1D778, "\u{3b9}"),
+    (0x1D779, "\u{3ba}"),
+    (0x1D77A, "\u{3bb}"),
+    (0x1D77B, "\u{3bc}"),
+    (0x1D77C, "\u{3bd}"),
+    (0x1D77D, "\u{3be}"),
+    (0x1D77E, "\u{3bf}"),
+    (0x1D77F, "\u{3c0}"),
+    (0x1D780, "\u{3c1}"),
+    (0x1D781, "\u{3c2}"),
+    (0x1D782, "\u{3c3}"),
+    (0x1D783, "\u{3c4}"),
+    (0x1D784, "\u{3c5}"),
+    (0x1D785, "\u{3c6}"),
+    (0x1D786, "\u{3c7}"),
+    (0x1D787, "\u{3c8}"),
+    (0x1D788, "\u{3c9}"),
+    (0x1D789, "\u{2202}"),
+    (0x1D78A, "\u{3b5}"),
+    (0x1D78B, "\u{3b8}"),
+    (0x1D78C, "\u{3ba}"),
+    (0x1D78D, "\u{3c6}"),
+    (0x1D78E, "\u{3c1}"),
+    (0x1D78F, "\u{3c0}"),
+    (0x1D790, "\u{391}"),
+    (0x1D791, "\u{392}"),
+    (0x1D792, "\u{393}"),
+    (0x1D793, "\u{394}"),
+    (0x1D794, "\u{395}"),
+    (0x1D795, "\u{396}"),
+    (0x1D796, "\u{397}"),
+    (0x1D797, "\u{398}"),
+    (0x1D798, "\u{399}"),
+    (0x1D799, "\u{39a}"),
+    (0x1D79A, "\u{39b}"),
+    (0x1D79B, "\u{39c}"),
+    (0x1D79C, "\u{39d}"),
+    (0x1D79D, "\u{39e}"),
+    (0x1D79E, "\u{39f}"),
+    (0x1D79F, "\u{3a0}"),
+    (0x1D7A0, "\u{3a1}"),
+    (0x1D7A1, "\u{398}"),
+    (0x1D7A2, "\u{3a3}"),
+    (0x1D7A3, "\u{3a4}"),
+    (0x1D7A4, "\u{3a5}"),
+    (0x1D7A5, "\u{3a6}"),
+    (0x1D7A6, "\u{3a7}"),
+    (0x1D7A7, "\u{3a8}"),
+    (0x1D7A8, "\u{3a9}"),
+    (0x1D7A9, "\u{2207}"),
+    (0x1D7AA, "\u{3b1}"),
+    (0x1D7AB, "\u{3b2}"),
+    (0x1D7AC, "\u{3b3}"),
+    (0x1D7AD, "\u{3b4}"),
+    (0x1D7AE, "\u{3b5}"),
+    (0x1D7AF, "\u{3b6}"),
+    (0x1D7B0, "\u{3b7}"),
+    (0x1D7B1, "\u{3b8}"),
+    (0x1D7B2, "\u{3b9}"),
+    (0x1D7B3, "\u{3ba}"),
+    (0x1D7B4, "\u{3bb}"),
+    (0x1D7B5, "\u{3bc}"),
+    (0x1D7B6, "\u{3bd}"),
+    (0x1D7B7, "\u{3be}"),
+    (0x1D7B8, "\u{3bf}"),
+    (0x1D7B9, "\u{3c0}"),
+    (0x1D7BA, "\u{3c1}"),
+    (0x1D7BB, "\u{3c2}"),
+    (0x1D7BC, "\u{3c3}"),
+    (0x1D7BD, "\u{3c4}"),
+    (0x1D7BE, "\u{3c5}"),
+    (0x1D7BF, "\u{3c6}"),
+    (0x1D7C0, "\u{3c7}"),
+    (0x1D7C1, "\u{3c8}"),
+    (0x1D7C2, "\u{3c9}"),
+    (0x1D7C3, "\u{2202}"),
+    (0x1D7C4, "\u{3b5}"),
+    (0x1D7C5, "\u{3b8}"),
+    (0x1D7C6, "\u{3ba}"),
+    (0x1D7C7, "\u{3c6}"),
+    (0x1D7C8, "\u{3c1}"),
+    (0x1D7C9, "\u{3c0}"),
+    (0x1D7CA, "\u{3dc}"),
+    (0x1D7CB, "\u{3dd}"),
+    (0x1D7CE, "\u{30}"),
+    (0x1D7CF, "\u{31}"),
+    (0x1D7D0, "\u{32}"),
+    (0x1D7D1, "\u{33}"),
+    (0x1D7D2, "\u{34}"),
+    (0x1D7D3, "\u{35}"),
+    (0x1D7D4, "\u{36}"),
+    (0x1D7D5, "\u{37}"),
+    (0x1D7D6, "\u{38}"),
+    (0x1D7D7, "\u{39}"),
+    (0x1D7D8, "\u{30}"),
+    (0x1D7D9, "\u{31}"),
+    (0x1D7DA, "\u{32}"),
+    (0x1D7DB, "\u{33}"),
+    (0x1D7DC, "\u{34}"),
+    (0x1D7DD, "\u{35}"),
+    (0x1D7DE, "\u{36}"),
+    (0x1D7DF, "\u{37}"),
+    (0x1D7E0, "\u{38}"),
+    (0x1D7E1, "\u{39}"),
+    (0x1D7E2, "\u{30}"),
+    (0x1D7E3, "\u{31}"),
+    (0x1D7E4, "\u{32}"),
+    (0x1D7E5, "\u{33}"),
+    (0x1D7E6, "\u{34}"),
+    (0x1D7E7, "\u{35}"),
+    (0x1D7E8, "\u{36}"),
+    (0x1D7E9, "\u{37}"),
+    (0x1D7EA, "\u{38}"),
+    (0x1D7EB, "\u{39}"),
+    (0x1D7EC, "\u{30}"),
+    (0x1D7ED, "\u{31}"),
+    (0x1D7EE, "\u{32}"),
+    (0x1D7EF, "\u{33}"),
+    (0x1D7F0, "\u{34}"),
+    (0x1D7F1, "\u{35}"),
+    (0x1D7F2, "\u{36}"),
+    (0x1D7F3, "\u{37}"),
+    (0x1D7F4, "\u{38}"),
+    (0x1D7F5, "\u{39}"),
+    (0x1D7F6, "\u{30}"),
+    (0x1D7F7, "\u{31}"),
+    (0x1D7F8, "\u{32}"),
+    (0x1D7F9, "\u{33}"),
+    (0x1D7FA, "\u{34}"),
+    (0x1D7FB, "\u{35}"),
+    (0x1D7FC, "\u{36}"),
+    (0x1D7FD, "\u{37}"),
+    (0x1D7FE, "\u{38}"),
+    (0x1D7FF, "\u{39}"),
+    (0x1EE00, "\u{627}"),
+    (0x1EE01, "\u{628}"),
+    (0x1EE02, "\u{62c}"),
+    (0x1EE03, "\u{62f}"),
+    (0x1EE05, "\u{648}"),
+    (0x1EE06, "\u{632}"),
+    (0x1EE07, "\u{62d}"),
+    (0x1EE08, "\u{637}"),
+    (0x1EE09, "\u{64a}"),
+    (0x1EE0A, "\u{643}"),
+    (0x1EE0B, "\u{644}"),
+    (0x1EE0C, "\u{645}"),
+    (0x1EE0D, "\u{646}"),
+    (0x1EE0E, "\u{633}"),
+    (0x1EE0F, "\u{639}"),
+    (0x1EE10, "\u{641}"),
+    (0x1EE11, "\u{635}"),
+    (0x1EE12, "\u{642}"),
+    (0x1EE13, "\u{631}"),
+    (0x1EE14, "\u{634}"),
+    (0x1EE15, "\u{62a}"),
+    (0x1EE16, "\u{62b}"),
+    (0x1EE17, "\u{62e}"),
+    (0x1EE18, "\u{630}"),
+    (0x1EE19, "\u{636}"),
+    (0x1EE1A, "\u{638}"),
+    (0x1EE1B, "\u{63a}"),
+    (0x1EE1C, "\u{66e}"),
+    (0x1EE1D, "\u{6ba}"),
+    (0x1EE1E, "\u{6a1}"),
+    (0x1EE1F, "\u{66f}"),
+    (0x1EE21, "\u{628}"),
+    (0x1EE22, "\u{62c}"),
+    (0x1EE24, "\u{647}"),
+    (0x1EE27, "\u{62d}"),
+    (0x1EE29, "\u{64a}"),
+    (0x1EE2A, "\u{643}"),
+    (0x1EE2B, "\u{644}"),
+    (0x1EE2C, "\u{645}"),
+    (0x1EE2D, "\u{646}"),
+    (0x1EE2E, "\u{633}"),
+    (0x1EE2F, "\u{639}"),
+    (0x1EE30, "\u{641}"),
+    (0x1EE31, "\u{635}"),
+    (0x1EE32, "\u{642}"),
+    (0x1EE34, "\u{634}"),
+    (0x1EE35, "\u{62a}"),
+    (0x1EE36, "\u{62b}"),
+    (0x1EE37, "\u{62e}"),
+    (0x1EE39, "\u{636}"),
+    (0x1EE3B, "\u{63a}"),
+    (0x1EE42, "\u{62c}"),
+    (0x1EE47, "\u{62d}"),
+    (0x1EE49, "\u{64a}"),
+    (0x1EE4B, "\u{644}"),
+    (0x1EE4D, "\u{646}"),
+    (0x1EE4E, "\u{633}"),
+    (0x1EE4F, "\u{639}"),
+    (0x1EE51, "\u{635}"),
+    (0x1EE52, "\u{642}"),
+    (0x1EE54, "\u{634}"),
+    (0x1EE57, "\u{62e}"),
+    (0x1EE59, "\u{636}"),
+    (0x1EE5B, "\u{63a}"),
+    (0x1EE5D, "\u{6ba}"),
+    (0x1EE5F, "\u{66f}"),
+    (0x1EE61, "\u{628}"),
+    (0x1EE62, "\u{62c}"),
+    (0x1EE64, "\u{647}"),
+    (0x1EE67, "\u{62d}"),
+    (0x1EE68, "\u{637}"),
+    (0x1EE69, "\u{64a}"),
+    (0x1EE6A, "\u{643}"),
+    (0x1EE6C, "\u{645}"),
+    (0x1EE6D, "\u{646}"),
+    (0x1EE6E, "\u{633}"),
+    (0x1EE6F, "\u{639}"),
+    (0x1EE70, "\u{641}"),
+    (0x1EE71, "\u{635}"),
+    (0x1EE72, "\u{642}"),
+    (0x1EE74, "\u{634}"),
+    (0x1EE75, "\u{62a}"),
+    (0x1EE76, "\u{62b}"),
+    (0x1EE77, "\u{62e}"),
+    (0x1EE79, "\u{636}"),
+    (0x1EE7A, "\u{638}"),
+    (0x1EE7B, "\u{63a}"),
+    (0x1EE7C, "\u{66e}"),
+    (0x1EE7E, "\u{6a1}"),
+    (0x1EE80, "\u{627}"),
+    (0x1EE81, "\u{628}"),
+    (0x1EE82, "\u{62c}"),
+    (0x1EE83, "\u{62f}"),
+    (0x1EE84, "\u{647}"),
+    (0x1EE85, "\u{648}"),
+    (0x1EE86, "\u{632}"),
+    (0x1EE87, "\u{62d}"),
+    (0x1EE88, "\u{637}"),
+    (0x1EE89, "\u{64a}"),
+    (0x1EE8B, "\u{644}"),
+    (0x1EE8C, "\u{645}"),
+    (0x1EE8D, "\u{646}"),
+    (0x1EE8E, "\u{633}"),
+    (0x1EE8F, "\u{639}"),
+    (0x1EE90, "\u{641}"),
+    (0x1EE91, "\u{635}"),
+    (0x1EE92, "\u{642}"),
+    (0x1EE93, "\u{631}"),
+    (0x1EE94, "\u{634}"),
+    (0x1EE95, "\u{62a}"),
+    (0x1EE96, "\u{62b}"),
+    (0x1EE97, "\u{62e}"),
+    (0x1EE98, "\u{630}"),
+    (0x1EE99, "\u{636}"),
+    (0x1EE9A, "\u{638}"),
+    (0x1EE9B, "\u{63a}"),
+    (0x1EEA1, "\u{628}"),
+    (0x1EEA2, "\u{62c}"),
+    (0x1EEA3, "\u{62f}"),
+    (0x1EEA5, "\u{648}"),
+    (0x1EEA6, "\u{632}"),
+    (0x1EEA7, "\u{62d}"),
+    (0x1EEA8, "\u{637}"),
+    (0x1EEA9, "\u{64a}"),
+    (0x1EEAB, "\u{644}"),
+    (0x1EEAC, "\u{645}"),
+    (0x1EEAD, "\u{646}"),
+    (0x1EEAE, "\u{633}"),
+    (0x1EEAF, "\u{639}"),
+    (0x1EEB0, "\u{641}"),
+    (0x1EEB1, "\u{635}"),
+    (0x1EEB2, "\u{642}"),
+    (0x1EEB3, "\u{631}"),
+    (0x1EEB4, "\u{634}"),
+    (0x1EEB5, "\u{62a}"),
+    (0x1EEB6, "\u{62b}"),
+    (0x1EEB7, "\u{62e}"),
+    (0x1EEB8, "\u{630}"),
+    (0x1EEB9, "\u{636}"),
+    (0x1EEBA, "\u{638}"),
+    (0x1EEBB, "\u{63a}"),
+    (0x1F100, "\u{30}\u{2e}"),
+    (0x1F101, "\u{30}\u{2c}"),
+    (0x1F102, "\u{31}\u{2c}"),
+    (0x1F103, "\u{32}\u{2c}"),
+    (0x1F104, "\u{33}\u{2c}"),
+    (0x1F105, "\u{34}\u{2c}"),
+    (0x1F106, "\u{35}\u{2c}"),
+    (0x1F107, "\u{36}\u{2c}"),
+    (0x1F108, "\u{37}\u{2c}"),
+    (0x1F109, "\u{38}\u{2c}"),
+    (0x1F10A, "\u{39}\u{2c}"),
+    (0x1F110, "\u{28}\u{41}\u{29}"),
+    (0x1F111, "\u{28}\u{42}\u{29}"),
+    (0x1F112, "\u{28}\u{43}\u{29}"),
+    (0x1F113, "\u{28}\u{44}\u{29}"),
+    (0x1F114, "\u{28}\u{45}\u{29}"),
+    (0x1F115, "\u{28}\u{46}\u{29}"),
+    (0x1F116, "\u{28}\u{47}\u{29}"),
+    (0x1F117, "\u{28}\u{48}\u{29}"),
+    (0x1F118, "\u{28}\u{49}\u{29}"),
+    (0x1F119, "\u{28}\u{4a}\u{29}"),
+    (0x1F11A, "\u{28}\u{4b}\u{29}"),
+    (0x1F11B, "\u{28}\u{4c}\u{29}"),
+    (0x1F11C, "\u{28}\u{4d}\u{29}"),
+    (0x1F11D, "\u{28}\u{4e}\u{29}"),
+    (0x1F11E, "\u{28}\u{4f}\u{29}"),
+    (0x1F11F, "\u{28}\u{50}\u{29}"),
+    (0x1F120, "\u{28}\u{51}\u{29}"),
+    (0x1F121, "\u{28}\u{52}\u{29}"),
+    (0x1F122, "\u{28}\u{53}\u{29}"),
+    (0x1F123, "\u{28}\u{54}\u{29}"),
+    (0x1F124, "\u{28}\u{55}\u{29}"),
+    (0x1F125, "\u{28}\u{56}\u{29}"),
+    (0x1F126, "\u{28}\u{57}\u{29}"),
+    (0x1F127, "\u{28}\u{58}\u{29}"),
+    (0x1F128, "\u{28}\u{59}\u{29}"),
+    (0x1F129, "\u{28}\u{5a}\u{29}"),
+    (0x1F12A, "\u{3014}\u{53}\u{3015}"),
+    (0x1F12B, "\u{43}"),
+    (0x1F12C, "\u{52}"),
+    (0x1F12D, "\u{43}\u{44}"),
+    (0x1F12E, "\u{57}\u{5a}"),
+    (0x1F130, "\u{41}"),
+    (0x1F131, "\u{42}"),
+    (0x1F132, "\u{43}"),
+    (0x1F133, "\u{44}"),
+    (0x1F134, "\u{45}"),
+    (0x1F135, "\u{46}"),
+    (0x1F136, "\u{47}"),
+    (0x1F137, "\u{48}"),
+    (0x1F138, "\u{49}"),
+    (0x1F139, "\u{4a}"),
+    (0x1F13A, "\u{4b}"),
+    (0x1F13B, "\u{4c}"),
+    (0x1F13C, "\u{4d}"),
+    (0x1F13D, "\u{4e}"),
+    (0x1F13E, "\u{4f}"),
+    (0x1F13F, "\u{50}"),
+    (0x1F140, "\u{51}"),
+    (0x1F141, "\u{52}"),
+    (0x1F142, "\u{53}"),
+    (0x1F143, "\u{54}"),
+    (0x1F144, "\u{55}"),
+    (0x1F145, "\u{56}"),
+    (0x1F146, "\u{57}"),
+    (0x1F147, "\u{58}"),
+    (0x1F148, "\u{59}"),
+    (0x1F149, "\u{5a}"),
+    (0x1F14A, "\u{48}\u{56}"),
+    (0x1F14B, "\u{4d}\u{56}"),
+    (0x1F14C, "\u{53}\u{44}"),
+    (0x1F14D, "\u{53}\u{53}"),
+    (0x1F14E, "\u{50}\u{50}\u{56}"),
+    (0x1F14F, "\u{57}\u{43}"),
+    (0x1F16A, "\u{4d}\u{43}"),
+    (0x1F16B, "\u{4d}\u{44}"),
+    (0x1F16C, "\u{4d}\u{52}"),
+    (0x1F190, "\u{44}\u{4a}"),
+    (0x1F200, "\u{307b}\u{304b}"),
+    (0x1F201, "\u{30b3}\u{30b3}"),
+    (0x1F202, "\u{30b5}"),
+    (0x1F210, "\u{624b}"),
+    (0x1F211, "\u{5b57}"),
+    (0x1F212, "\u{53cc}"),
+    (0x1F213, "\u{30c6}\u{3099}"),
+    (0x1F214, "\u{4e8c}"),
+    (0x1F215, "\u{591a}"),
+    (0x1F216, "\u{89e3}"),
+    (0x1F217, "\u{5929}"),
+    (0x1F218, "\u{4ea4}"),
+    (0x1F219, "\u{6620}"),
+    (0x1F21A, "\u{7121}"),
+    (0x1F21B, "\u{6599}"),
+    (0x1F21C, "\u{524d}"),
+    (0x1F21D, "\u{5f8c}"),
+    (0x1F21E, "\u{518d}"),
+    (0x1F21F, "\u{65b0}"),
+    (0x1F220, "\u{521d}"),
+    (0x1F221, "\u{7d42}"),
+    (0x1F222, "\u{751f}"),
+    (0x1F223, "\u{8ca9}"),
+    (0x1F224, "\u{58f0}"),
+    (0x1F225, "\u{5439}"),
+    (0x1F226, "\u{6f14}"),
+    (0x1F227, "\u{6295}"),
+    (0x1F228, "\u{6355}"),
+    (0x1F229, "\u{4e00}"),
+    (0x1F22A, "\u{4e09}"),
+    (0x1F22B, "\u{904a}"),
+    (0x1F22C, "\u{5de6}"),
+    (0x1F22D, "\u{4e2d}"),
+    (0x1F22E, "\u{53f3}"),
+    (0x1F22F, "\u{6307}"),
+    (0x1F230, "\u{8d70}"),
+    (0x1F231, "\u{6253}"),
+    (0x1F232, "\u{7981}"),
+    (0x1F233, "\u{7a7a}"),
+    (0x1F234, "\u{5408}"),
+    (0x1F235, "\u{6e80}"),
+    (0x1F236, "\u{6709}"),
+    (0x1F237, "\u{6708}"),
+    (0x1F238, "\u{7533}"),
+    (0x1F239, "\u{5272}"),
+    (0x1F23A, "\u{55b6}"),
+    (0x1F23B, "\u{914d}"),
+    (0x1F240, "\u{3014}\u{672c}\u{3015}"),
+    (0x1F241, "\u{3014}\u{4e09}\u{3015}"),
+    (0x1F242, "\u{3014}\u{4e8c}\u{3015}"),
+    (0x1F243, "\u{3014}\u{5b89}\u{3015}"),
+    (0x1F244, "\u{3014}\u{70b9}\u{3015}"),
+    (0x1F245, "\u{3014}\u{6253}\u{3015}"),
+    (0x1F246, "\u{3014}\u{76d7}\u{3015}"),
+    (0x1F247, "\u{3014}\u{52dd}\u{3015}"),
+    (0x1F248, "\u{3014}\u{6557}\u{3015}"),
+    (0x1F250, "\u{5f97}"),
+    (0x1F251, "\u{53ef}"),
+    (0x1FBF0, "\u{30}"),
+    (0x1FBF1, "\u{31}"),
+    (0x1FBF2, "\u{32}"),
+    (0x1FBF3, "\u{33}"),
+    (0x1FBF4, "\u{34}"),
+    (0x1FBF5, "\u{35}"),
+    (0x1FBF6, "\u{36}"),
+    (0x1FBF7, "\u{37}"),
+    (0x1FBF8, "\u{38}"),
+    (0x1FBF9, "\u{39}"),
+];
+
+pub const COMPOSITION: &[(u32, u32, u32)] = &[
+    (0x003C, 0x0338, 0x226E),
+    (0x003D, 0x0338, 0x2260),
+    (0x003E, 0x0338, 0x226F),
+    (0x0041, 0x0300, 0x00C0),
+    (0x0041, 0x0301, 0x00C1),
+    (0x0041, 0x0302, 0x00C2),
+    (0x0041, 0x0303, 0x00C3),
+    (0x0041, 0x0304, 0x0100),
+    (0x0041, 0x0306, 0x0102),
+    (0x0041, 0x0307, 0x0226),
+    (0x0041, 0x0308, 0x00C4),
+    (0x0041, 0x0309, 0x1EA2),
+    (0x0041, 0x030A, 0x00C5),
+    (0x0041, 0x030C, 0x01CD),
+    (0x0041, 0x030F, 0x0200),
+    (0x0041, 0x0311, 0x0202),
+    (0x0041, 0x0323, 0x1EA0),
+    (0x0041, 0x0325, 0x1E00),
+    (0x0041, 0x0328, 0x0104),
+    (0x0042, 0x0307, 0x1E02),
+    (0x0042, 0x0323, 0x1E04),
+    (0x0042, 0x0331, 0x1E06),
+    (0x0043, 0x0301, 0x0106),
+    (0x0043, 0x0302, 0x0108),
+    (0x0043, 0x0307, 0x010A),
+    (0x0043, 0x030C, 0x010C),
+    (0x0043, 0x0327, 0x00C7),
+    (0x0044, 0x0307, 0x1E0A),
+    (0x0044, 0x030C, 0x010E),
+    (0x0044, 0x0323, 0x1E0C),
+    (0x0044, 0x0327, 0x1E10),
+    (0x0044, 0x032D, 0x1E12),
+    (0x0044, 0x0331, 0x1E0E),
+    (0x0045, 0x0300, 0x00C8),
+    (0x0045, 0x0301, 0x00C9),
+    (0x0045, 0x0302, 0x00CA),
+    (0x0045, 0x0303, 0x1EBC),
+    (0x0045, 0x0304, 0x0112),
+    (0x0045, 0x0306, 0x0114),
+    (0x0045, 0x0307, 0x0116),
+    (0x0045, 0x0308, 0x00CB),
+    (0x0045, 0x0309, 0x1EBA),
+    (0x0045, 0x030C, 0x011A),
+    (0x0045, 0x030F, 0x0204),
+    (0x0045, 0x0311, 0x0206),
+    (0x0045, 0x0323, 0x1EB8),
+    (0x0045, 0x0327, 0x0228),
+    (0x0045, 0x0328, 0x0118),
+    (0x0045, 0x032D, 0x1E18),
+    (0x0045, 0x0330, 0x1E1A),
+    (0x0046, 0x0307, 0x1E1E),
+    (0x0047, 0x0301, 0x01F4),
+    (0x0047, 0x0302, 0x011C),
+    (0x0047, 0x0304, 0x1E20),
+    (0x0047, 0x0306, 0x011E),
+    (0x0047, 0x0307, 0x0120),
+    (0x0047, 0x030C, 0x01E6),
+    (0x0047, 0x0327, 0x0122),
+    (0x0048, 0x0302, 0x0124),
+    (0x0048, 0x0307, 0x1E22),
+    (0x0048, 0x0308, 0x1E26),
+    (0x0048, 0x030C, 0x021E),
+    (0x0048, 0x0323, 0x1E24),
+    (0x0048, 0x0327, 0x1E28),
+    (0x0048, 0x032E, 0x1E2A),
+    (0x0049, 0x0300, 0x00CC),
+    (0x0049, 0x0301, 0x00CD),
+    (0x0049, 0x0302, 0x00CE),
+    (0x0049, 0x0303, 0x0128),
+    (0x0049, 0x0304, 0x012A),
+    (0x0049, 0x0306, 0x012C),
+    (0x0049, 0x0307, 0x0130),
+    (0x0049, 0x0308, 0x00CF),
+    (0x0049, 0x0309, 0x1EC8),
+    (0x0049, 0x030C, 0x01CF),
+    (0x0049, 0x030F, 0x0208),
+    (0x0049, 0x0311, 0x020A),
+    (0x0049, 0x0323, 0x1ECA),
+    (0x0049, 0x0328, 0x012E),
+    (0x0049, 0x0330, 0x1E2C),
+    (0x004A, 0x0302, 0x0134),
+    (0x004B, 0x0301, 0x1E30),
+    (0x004B, 0x030C, 0x01E8),
+    (0x004B, 0x0323, 0x1E32),
+    (0x004B, 0x0327, 0x0136),
+    (0x004B, 0x0331, 0x1E34),
+    (0x004C, 0x0301, 0x0139),
+    (0x004C, 0x030C, 0x013D),
+    (0x004C, 0x0323, 0x1E36),
+    (0x004C, 0x0327, 0x013B),
+    (0x004C, 0x032D, 0x1E3C),
+    (0x004C, 0x0331, 0x1E3A),
+    (0x004D, 0x0301, 0x1E3E),
+    (0x004D, 0x0307, 0x1E40),
+    (0x004D, 0x0323, 0x1E42),
+    (0x004E, 0x0300, 0x01F8),
+    (0x004E, 0x0301, 0x0143),
+    (0x004E, 0x0303, 0x00D1),
+    (0x004E, 0x0307, 0x1E44),
+    (0x004E, 0x030C, 0x0147),
+    (0x004E, 0x0323, 0x1E46),
+    (0x004E, 0x0327, 0x0145),
+    (0x004E, 0x032D, 0x1E4A),
+    (0x004E, 0x0331, 0x1E48),
+    (0x004F, 0x0300, 0x00D2),
+    (0x004F, 0x0301, 0x00D3),
+    (0x004F, 0x0302, 0x00D4),
+    (0x004F, 0x0303, 0x00D5),
+    (0x004F, 0x0304, 0x014C),
+    (0x004F, 0x0306, 0x014E),
+    (0x004F, 0x0307, 0x022E),
+    (0x004F, 0x0308, 0x00D6),
+    (0x004F, 0x0309, 0x1ECE),
+    (0x004F, 0x030B, 0x0150),
+    (0x004F, 0x030C, 0x01D1),
+    (0x004F, 0x030F, 0x020C),
+    (0x004F, 0x0311, 0x020E),
+    (0x004F, 0x031B, 0x01A0),
+    (0x004F, 0x0323, 0x1ECC),
+    (0x004F, 0x0328, 0x01EA),
+    (0x0050, 0x0301, 0x1E54),
+    (0x0050, 0x0307, 0x1E56),
+    (0x0052, 0x0301, 0x0154),
+    (0x0052, 0x0307, 0x1E58),
+    (0x0052, 0x030C, 0x0158),
+    (0x0052, 0x030F, 0x0210),
+    (0x0052, 0x0311, 0x0212),
+    (0x0052, 0x0323, 0x1E5A),
+    (0x0052, 0x0327, 0x0156),
+    (0x0052, 0x0331, 0x1E5E),
+    (0x0053, 0x0301, 0x015A),
+    (0x0053, 0x0302, 0x015C),
+    (0x0053, 0x0307, 0x1E60),
+    (0x0053, 0x030C, 0x0160),
+    (0x0053, 0x0323, 0x1E62),
+    (0x0053, 0x0326, 0x0218),
+    (0x0053, 0x0327, 0x015E),
+    (0x0054, 0x0307, 0x1E6A),
+    (0x0054, 0x030C, 0x0164),
+    (0x0054, 0x0323, 0x1E6C),
+    (0x0054, 0x0326, 0x021A),
+    (0x0054, 0x0327, 0x0162),
+    (0x0054, 0x032D, 0x1E70),
+    (0x0054, 0x0331, 0x1E6E),
+    (0x0055, 0x0300, 0x00D9),
+    (0x0055, 0x0301, 0x00DA),
+    (0x0055, 0x0302, 0x00DB),
+    (0x0055, 0x0303, 0x0168),
+    (0x0055, 0x0304, 0x016A),
+    (0x0055, 0x0306, 0x016C),
+    (0x0055, 0x0308, 0x00DC),
+    (0x0055, 0x0309, 0x1EE6),
+    (0x0055, 0x030A, 0x016E),
+    (0x0055, 0x030B, 0x0170),
+    (0x0055, 0x030C, 0x01D3),
+    (0x0055, 0x030F, 0x0214),
+    (0x0055, 0x0311, 0x0216),
+    (0x0055, 0x031B, 0x01AF),
+    (0x0055, 0x0323, 0x1EE4),
+    (0x0055, 0x0324, 0x1E72),
+    (0x0055, 0x0328, 0x0172),
+    (0x0055, 0x032D, 0x1E76),
+    (0x0055, 0x0330, 0x1E74),
+    (0x0056, 0x0303, 0x1E7C),
+    (0x0056, 0x0323, 0x1E7E),
+    (0x0057, 0x0300, 0x1E80),
+    (0x0057, 0x0301, 0x1E82),
+    (0x0057, 0x0302, 0x0174),
+    (0x0057, 0x0307, 0x1E86),
+    (0x0057, 0x0308, 0x1E84),
+    (0x0057, 0x0323, 0x1E88),
+    (0x0058, 0x0307, 0x1E8A),
+    (0x0058, 0x0308, 0x1E8C),
+    (0x0059, 0x0300, 0x1EF2),
+    (0x0059, 0x0301, 0x00DD),
+    (0x0059, 0x0302, 0x0176),
+    (0x0059, 0x0303, 0x1EF8),
+    (0x0059, 0x0304, 0x0232),
+    (0x0059, 0x0307, 0x1E8E),
+    (0x0059, 0x0308, 0x0178),
+    (0x0059, 0x0309, 0x1EF6),
+    (0x0059, 0x0323, 0x1EF4),
+    (0x005A, 0x0301, 0x0179),
+    (0x005A, 0x0302, 0x1E90),
+    (0x005A, 0x0307, 0x017B),
+    (0x005A, 0x030C, 0x017D),
+    (0x005A, 0x0323, 0x1E92),
+    (0x005A, 0x0331, 0x1E94),
+    (0x0061, 0x0300, 0x00E0),
+    (0x0061, 0x0301, 0x00E1),
+    (0x0061, 0x0302, 0x00E2),
+    (0x0061, 0x0303, 0x00E3),
+    (0x0061, 0x0304, 0x0101),
+    (0x0061, 0x0306, 0x0103),
+    (0x0061, 0x0307, 0x0227),
+    (0x0061, 0x0308, 0x00E4),
+    (0x0061, 0x0309, 0x1EA3),
+    (0x0061, 0x030A, 0x00E5),
+    (0x0061, 0x030C, 0x01CE),
+    (0x0061, 0x030F, 0x0201),
+    (0x0061, 0x0311, 0x0203),
+    (0x0061, 0x0323, 0x1EA1),
+    (0x0061, 0x0325, 0x1E01),
+    (0x0061, 0x0328, 0x0105),
+    (0x0062, 0x0307, 0x1E03),
+    (0x0062, 0x0323, 0x1E05),
+    (0x0062, 0x0331, 0x1E07),
+    (0x0063, 0x0301, 0x0107),
+    (0x0063, 0x0302, 0x0109),
+    (0x0063, 0x0307, 0x010B),
+    (0x0063, 0x030C, 0x010D),
+    (0x0063, 0x0327, 0x00E7),
+    (0x0064, 0x0307, 0x1E0B),
+    (0x0064, 0x030C, 0x010F),
+    (0x0064, 0x0323, 0x1E0D),
+    (0x0064, 0x0327, 0x1E11),
+    (0x0064, 0x032D, 0x1E13),
+    (0x0064, 0x0331, 0x1E0F),
+    (0x0065, 0x0300, 0x00E8),
+    (0x0065, 0x0301, 0x00E9),
+    (0x0065, 0x0302, 0x00EA),
+    (0x0065, 0x0303, 0x1EBD),
+    (0x0065, 0x0304, 0x0113),
+    (0x0065, 0x0306, 0x0115),
+    (0x0065, 0x0307, 0x0117),
+    (0x0065, 0x0308, 0x00EB),
+    (0x0065, 0x0309, 0x1EBB),
+    (0x0065, 0x030C, 0x011B),
+    (0x0065, 0x030F, 0x0205),
+    (0x0065, 0x0311, 0x0207),
+    (0x0065, 0x0323, 0x1EB9),
+    (0x0065, 0x0327, 0x0229),
+    (0x0065, 0x0328, 0x0119),
+    (0x0065, 0x032D, 0x1E19),
+    (0x0065, 0x0330, 0x1E1B),
+    (0x0066, 0x0307, 0x1E1F),
+    (0x0067, 0x0301, 0x01F5),
+    (0x0067, 0x0302, 0x011D),
+    (0x0067, 0x0304, 0x1E21),
+    (0x0067, 0x0306, 0x011F),
+    (0x0067, 0x0307, 0x0121),
+    (0x0067, 0x030C, 0x01E7),
+    (0x0067, 0x0327, 0x0123),
+    (0x0068, 0x0302, 0x0125),
+    (0x0068, 0x0307, 0x1E23),
+    (0x0068, 0x0308, 0x1E27),
+    (0x0068, 0x030C, 0x021F),
+    (0x0068, 0x0323, 0x1E25),
+    (0x0068, 0x0327, 0x1E29),
+    (0x0068, 0x032E, 0x1E2B),
+    (0x0068, 0x0331, 0x1E96),
+    (0x0069, 0x0300, 0x00EC),
+    (0x0069, 0x0301, 0x00ED),
+    (0x0069, 0x0302, 0x00EE),
+    (0x0069, 0x0303, 0x0129),
+    (0x0069, 0x0304, 0x012B),
+    (0x0069, 0x0306, 0x012D),
+    (0x0069, 0x0308, 0x00EF),
+    (0x0069, 0x0309, 0x1EC9),
+    (0x0069, 0x030C, 0x01D0),
+    (0x0069, 0x030F, 0x0209),
+    (0x0069, 0x0311, 0x020B),
+    (0x0069, 0x0323, 0x1ECB),
+    (0x0069, 0x0328, 0x012F),
+    (0x0069, 0x0330, 0x1E2D),
+    (0x006A, 0x0302, 0x0135),
+    (0x006A, 0x030C, 0x01F0),
+    (0x006B, 0x0301, 0x1E31),
+    (0x006B, 0x030C, 0x01E9),
+    (0x006B, 0x0323, 0x1E33),
+    (0x006B, 0x0327, 0x0137),
+    (0x006B, 0x0331, 0x1E35),
+    (0x006C, 0x0301, 0x013A),
+    (0x006C, 0x030C, 0x013E),
+    (0x006C, 0x0323, 0x1E37),
+    (0x006C, 0x0327, 0x013C),
+    (0x006C, 0x032D, 0x1E3D),
+    (0x006C, 0x0331, 0x1E3B),
+    (0x006D, 0x0301, 0x1E3F),
+    (0x006D, 0x0307, 0x1E41),
+    (0x006D, 0x0323, 0x1E43),
+    (0x006E, 0x0300, 0x01F9),
+    (0x006E, 0x0301, 0x0144),
+    (0x006E, 0x0303, 0x00F1),
+    (0x006E, 0x0307, 0x1E45),
+    (0x006E, 0x030C, 0x0148),
+    (0x006E, 0x0323, 0x1E47),
+    (0x006E, 0x0327, 0x0146),
+    (0x006E, 0x032D, 0x1E4B),
+    (0x006E, 0x0331, 0x1E49),
+    (0x006F, 0x0300, 0x00F2),
+    (0x006F, 0x0301, 0x00F3),
+    (0x006F, 0x0302, 0x00F4),
+    (0x006F, 0x0303, 0x00F5),
+    (0x006F, 0x0304, 0x014D),
+    (0x006F, 0x0306, 0x014F),
+    (0x006F, 0x0307, 0x022F),
+    (0x006F, 0x0308, 0x00F6),
+    (0x006F, 0x0309, 0x1ECF),
+    (0x006F, 0x030B, 0x0151),
+    (0x006F, 0x030C, 0x01D2),
+    (0x006F, 0x030F, 0x020D),
+    (0x006F, 0x0311, 0x020F),
+    (0x006F, 0x031B, 0x01A1),
+    (0x006F, 0x0323, 0x1ECD),
+    (0x006F, 0x0328, 0x01EB),
+    (0x0070, 0x0301, 0x1E55),
+    (0x0070, 0x0307, 0x1E57),
+    (0x0072, 0x0301, 0x0155),
+    (0x0072, 0x0307, 0x1E59),
+    (0x0072, 0x030C, 0x0159),
+    (0x0072, 0x030F, 0x0211),
+    (0x0072, 0x0311, 0x0213),
+    (0x0072, 0x0323, 0x1E5B),
+    (0x0072, 0x0327, 0x0157),
+    (0x0072, 0x0331, 0x1E5F),
+    (0x0073, 0x0301, 0x015B),
+    (0x0073, 0x0302, 0x015D),
+    (0x0073, 0x0307, 0x1E61),
+    (0x0073, 0x030C, 0x0161),
+    (0x0073, 0x0323, 0x1E63),
+    (0x0073, 0x0326, 0x0219),
+    (0x0073, 0x0327, 0x015F),
+    (0x0074, 0x0307, 0x1E6B),
+    (0x0074, 0x0308, 0x1E97),
+    (0x0074, 0x030C, 0x0165),
+    (0x0074, 0x0323, 0x1E6D),
+    (0x0074, 0x0326, 0x021B),
+    (0x0074, 0x0327, 0x0163),
+    (0x0074, 0x032D, 0x1E71),
+    (0x0074, 0x0331, 0x1E6F),
+    (0x0075, 0x0300, 0x00F9),
+    (0x0075, 0x0301, 0x00FA),
+    (0x0075, 0x0302, 0x00FB),
+    (0x0075, 0x0303, 0x0169),
+    (0x0075, 0x0304, 0x016B),
+    (0x0075, 0x0306, 0x016D),
+    (0x0075, 0x0308, 0x00FC),
+    (0x0075, 0x0309, 0x1EE7),
+    (0x0075, 0x030A, 0x016F),
+    (0x0075, 0x030B, 0x0171),
+    (0x0075, 0x030C, 0x01D4),
+    (0x0075, 0x030F, 0x0215),
+    (0x0075, 0x0311, 0x0217),
+    (0x0075, 0x031B, 0x01B0),
+    (0x0075, 0x0323, 0x1EE5),
+    (0x0075, 0x0324, 0x1E73),
+    (0x0075, 0x0328, 0x0173),
+    (0x0075, 0x032D, 0x1E77),
+    (0x0075, 0x0330, 0x1E75),
+    (0x0076, 0x0303, 0x1E7D),
+    (0x0076, 0x0323, 0x1E7F),
+    (0x0077, 0x0300, 0x1E81),
+    (0x0077, 0x0301, 0x1E83),
+    (0x0077, 0x0302, 0x0175),
+    (0x0077, 0x0307, 0x1E87),
+    (0x0077, 0x0308, 0x1E85),
+    (0x0077, 0x030A, 0x1E98),
+    (0x0077, 0x0323, 0x1E89),
+    (0x0078, 0x0307, 0x1E8B),
+    (0x0078, 0x0308, 0x1E8D),
+    (0x0079, 0x0300, 0x1EF3),
+    (0x0079, 0x0301, 0x00FD),
+    (0x0079, 0x0302, 0x0177),
+    (0x0079, 0x0303, 0x1EF9),
+    (0x0079, 0x0304, 0x0233),
+    (0x0079, 0x0307, 0x1E8F),
+    (0x0079, 0x0308, 0x00FF),
+    (0x0079, 0x0309, 0x1EF7),
+    (0x0079, 0x030A, 0x1E99),
+    (0x0079, 0x0323, 0x1EF5),
+    (0x007A, 0x0301, 0x017A),
+    (0x007A, 0x0302, 0x1E91),
+    (0x007A, 0x0307, 0x017C),
+    (0x007A, 0x030C, 0x017E),
+    (0x007A, 0x0323, 0x1E93),
+    (0x007A, 0x0331, 0x1E95),
+    (0x00A8, 0x0300, 0x1FED),
+    (0x00A8, 0x0301, 0x0385),
+    (0x00A8, 0x0342, 0x1FC1),
+    (0x00C2, 0x0300, 0x1EA6),
+    (0x00C2, 0x0301, 0x1EA4),
+    (0x00C2, 0x0303, 0x1EAA),
+    (0x00C2, 0x0309, 0x1EA8),
+    (0x00C4, 0x0304, 0x01DE),
+    (0x00C5, 0x0301, 0x01FA),
+    (0x00C6, 0x0301, 0x01FC),
+    (0x00C6, 0x0304, 0x01E2),
+    (0x00C7, 0x0301, 0x1E08),
+    (0x00CA, 0x0300, 0x1EC0),
+    (0x00CA, 0x0301, 0x1EBE),
+    (0x00CA, 0x0303, 0x1EC4),
+    (0x00CA, 0x0309, 0x1EC2),
+    (0x00CF, 0x0301, 0x1E2E),
+    (0x00D4, 0x0300, 0x1ED2),
+    (0x00D4, 0x0301, 0x1ED0),
+    (0x00D4, 0x0303, 0x1ED6),
+    (0x00D4, 0x0309, 0x1ED4),
+    (0x00D5, 0x0301, 0x1E4C),
+    (0x00D5, 0x0304, 0x022C),
+    (0x00D5, 0x0308, 0x1E4E),
+    (0x00D6, 0x0304, 0x022A),
+    (0x00D8, 0x0301, 0x01FE),
+    (0x00DC, 0x0300, 0x01DB),
+    (0x00DC, 0x0301, 0x01D7),
+    (0x00DC, 0x0304, 0x01D5),
+    (0x00DC, 0x030C, 0x01D9),
+    (0x00E2, 0x0300, 0x1EA7),
+    (0x00E2, 0x0301, 0x1EA5),
+    (0x00E2, 0x0303, 0x1EAB),
+    (0x00E2, 0x0309, 0x1EA9),
+    (0x00E4, 0x0304, 0x01DF),
+    (0x00E5, 0x0301, 0x01FB),
+    (0x00E6, 0x0301, 0x01FD),
+    (0x00E6, 0x0304, 0x01E3),
+    (0x00E7, 0x0301, 0x1E09),
+    (0x00EA, 0x0300, 0x1EC1),
+    (0x00EA, 0x0301, 0x1EBF),
+    (0x00EA, 0x0303, 0x1EC5),
+    (0x00EA, 0x0309, 0x1EC3),
+    (0x00EF, 0x0301, 0x1E2F),
+    (0x00F4, 0x0300, 0x1ED3),
+    (0x00F4, 0x0301, 0x1ED1),
+    (0x00F4, 0x0303, 0x1ED7),
+    (0x00F4, 0x0309, 0x1ED5),
+    (0x00F5, 0x0301, 0x1E4D),
+    (0x00F5, 0x0304, 0x022D),
+    (0x00F5, 0x0308, 0x1E4F),
+    (0x00F6, 0x0304, 0x022B),
+    (0x00F8, 0x0301, 0x01FF),
+    (0x00FC, 0x0300, 0x01DC),
+    (0x00FC, 0x0301, 0x01D8),
+    (0x00FC, 0x0304, 0x01D6),
+    (0x00FC, 0x030C, 0x01DA),
+    (0x0102, 0x0300, 0x1EB0),
+    (0x0102, 0x0301, 0x1EAE),
+    (0x0102, 0x0303, 0x1EB4),
+    (0x0102, 0x0309, 0x1EB2),
+    (0x0103, 0x0300, 0x1EB1),
+    (0x0103, 0x0301, 0x1EAF),
+    (0x0103, 0x0303, 0x1EB5),
+    (0x0103, 0x0309, 0x1EB3),
+    (0x0112, 0x0300, 0x1E14),
+    (0x0112, 0x0301, 0x1E16),
+    (0x0113, 0x0300, 0x1E15),
+    (0x0113, 0x0301, 0x1E17),
+    (0x014C, 0x0300, 0x1E50),
+    (0x014C, 0x0301, 0x1E52),
+    (0x014D, 0x0300, 0x1E51),
+    (0x014D, 0x0301, 0x1E53),
+    (0x015A, 0x0307, 0x1E64),
+    (0x015B, 0x0307, 0x1E65),
+    (0x0160, 0x0307, 0x1E66),
+    (0x0161, 0x0307, 0x1E67),
+    (0x0168, 0x0301, 0x1E78),
+    (0x0169, 0x0301, 0x1E79),
+    (0x016A, 0x0308, 0x1E7A),
+    (0x016B, 0x0308, 0x1E7B),
+    (0x017F, 0x0307, 0x1E9B),
+    (0x01A0, 0x0300, 0x1EDC),
+    (0x01A0, 0x0301, 0x1EDA),
+    (0x01A0, 0x0303, 0x1EE0),
+    (0x01A0, 0x0309, 0x1EDE),
+    (0x01A0, 0x0323, 0x1EE2),
+    (0x01A1, 0x0300, 0x1EDD),
+    (0x01A1, 0x0301, 0x1EDB),
+    (0x01A1, 0x0303, 0x1EE1),
+    (0x01A1, 0x0309, 0x1EDF),
+    (0x01A1, 0x0323, 0x1EE3),
+    (0x01AF, 0x0300, 0x1EEA),
+    (0x01AF, 0x0301, 0x1EE8),
+    (0x01AF, 0x0303, 0x1EEE),
+    (0x01AF, 0x0309, 0x1EEC),
+    (0x01AF, 0x0323, 0x1EF0),
+    (0x01B0, 0x0300, 0x1EEB),
+    (0x01B0, 0x0301, 0x1EE9),
+    (0x01B0, 0x0303, 0x1EEF),
+    (0x01B0, 0x0309, 0x1EED),
+    (0x01B0, 0x0323, 0x1EF1),
+    (0x01B7, 0x030C, 0x01EE),
+    (0x01EA, 0x0304, 0x01EC),
+    (0x01EB, 0x0304, 0x01ED),
+    (0x0226, 0x0304, 0x01E0),
+    (0x0227, 0x0304, 0x01E1),
+    (0x0228, 0x0306, 0x1E1C),
+    (0x0229, 0x0306, 0x1E1D),
+    (0x022E, 0x0304, 0x0230),
+    (0x022F, 0x0304, 0x0231),
+    (0x0292, 0x030C, 0x01EF),
+    (0x0391, 0x0300, 0x1FBA),
+    (0x0391, 0x0301, 0x0386),
+    (0x0391, 0x0304, 0x1FB9),
+    (0x0391, 0x0306, 0x1FB8),
+    (0x0391, 0x0313, 0x1F08),
+    (0x0391, 0x0314, 0x1F09),
+    (0x0391, 0x0345, 0x1FBC),
+    (0x0395, 0x0300, 0x1FC8),
+    (0x0395, 0x0301, 0x0388),
+    (0x0395, 0x0313, 0x1F18),
+    (0x0395, 0x0314, 0x1F19),
+    (0x0397, 0x0300, 0x1FCA),
+    (0x0397, 0x0301, 0x0389),
+    (0x0397, 0x0313, 0x1F28),
+    (0x0397, 0x0314, 0x1F29),
+    (0x0397, 0x0345, 0x1FCC),
+    (0x0399, 0x0300, 0x1FDA),
+    (0x0399, 0x0301, 0x038A),
+    (0x0399, 0x0304, 0x1FD9),
+    (0x0399, 0x0306, 0x1FD8),
+    (0x0399, 0x0308, 0x03AA),
+    (0x0399, 0x0313, 0x1F38),
+    (0x0399, 0x0314, 0x1F39),
+    (0x039F, 0x0300, 0x1FF8),
+    (0x039F, 0x0301, 0x038C),
+    (0x039F, 0x0313, 0x1F48),
+    (0x039F, 0x0314, 0x1F49),
+    (0x03A1, 0x0314, 0x1FEC),
+    (0x03A5, 0x0300, 0x1FEA),
+    (0x03A5, 0x0301, 0x038E),
+    (0x03A5, 0x0304, 0x1FE9),
+    (0x03A5, 0x0306, 0x1FE8),
+    (0x03A5, 0x0308, 0x03AB),
+    (0x03A5, 0x0314, 0x1F59),
+    (0x03A9, 0x0300, 0x1FFA),
+    (0x03A9, 0x0301, 0x038F),
+    (0x03A9, 0x0313, 0x1F68),
+    (0x03A9, 0x0314, 0x1F69),
+    (0x03A9, 0x0345, 0x1FFC),
+    (0x03AC, 0x0345, 0x1FB4),
+    (0x03AE, 0x0345, 0x1FC4),
+    (0x03B1, 0x0300, 0x1F70),
+    (0x03B1, 0x0301, 0x03AC),
+    (0x03B1, 0x0304, 0x1FB1),
+    (0x03B1, 0x0306, 0x1FB0),
+    (0x03B1, 0x0313, 0x1F00),
+    (0x03B1, 0x0314, 0x1F01),
+    (0x03B1, 0x0342, 0x1FB6),
+    (0x03B1, 0x0345, 0x1FB3),
+    (0x03B5, 0x0300, 0x1F72),
+    (0x03B5, 0x0301, 0x03AD),
+    (0x03B5, 0x0313, 0x1F10),
+    (0x03B5, 0x0314, 0x1F11),
+    (0x03B7, 0x0300, 0x1F74),
+    (0x03B7, 0x0301, 0x03AE),
+    (0x03B7, 0x0313, 0x1F20),
+    (0x03B7, 0x0314, 0x1F21),
+    (0x03B7, 0x0342, 0x1FC6),
+    (0x03B7, 0x0345, 0x1FC3),
+    (0x03B9, 0x0300, 0x1F76),
+    (0x03B9, 0x0301, 0x03AF),
+    (0x03B9, 0x0304, 0x1FD1),
+    (0x03B9, 0x0306, 0x1FD0),
+    (0x03B9, 0x0308, 0x03CA),
+    (0x03B9, 0x0313, 0x1F30),
+    (0x03B9, 0x0314, 0x1F31),
+    (0x03B9, 0x0342, 0x1FD6),
+    (0x03BF, 0x0300, 0x1F78),
+    (0x03BF, 0x0301, 0x03CC),
+    (0x03BF, 0x0313, 0x1F40),
+    (0x03BF, 0x0314, 0x1F41),
+    (0x03C1, 0x0313, 0x1FE4),
+    (0x03C1, 0x0314, 0x1FE5),
+    (0x03C5, 0x0300, 0x1F7A),
+    (0x03C5, 0x0301, 0x03CD),
+    (0x03C5, 0x0304, 0x1FE1),
+    (0x03C5, 0x0306, 0x1FE0),
+    (0x03C5, 0x0308, 0x03CB),
+    (0x03C5, 0x0313, 0x1F50),
+    (0x03C5, 0x0314, 0x1F51),
+    (0x03C5, 0x0342, 0x1FE6),
+    (0x03C9, 0x0300, 0x1F7C),
+    (0x03C9, 0x0301, 0x03CE),
+    (0x03C9, 0x0313, 0x1F60),
+    (0x03C9, 0x0314, 0x1F61),
+    (0x03C9, 0x0342, 0x1FF6),
+    (0x03C9, 0x0345, 0x1FF3),
+    (0x03CA, 0x0300, 0x1FD2),
+    (0x03CA, 0x0301, 0x0390),
+    (0x03CA, 0x0342, 0x1FD7),
+    (0x03CB, 0x0300, 0x1FE2),
+    (0x03CB, 0x0301, 0x03B0),
+    (0x03CB, 0x0342, 0x1FE7),
+    (0x03CE, 0x0345, 0x1FF4),
+    (0x03D2, 0x0301, 0x03D3),
+    (0x03D2, 0x0308, 0x03D4),
+    (0x0406, 0x0308, 0x0407),
+    (0x0410, 0x0306, 0x04D0),
+    (0x0410, 0x0308, 0x04D2),
+    (0x0413, 0x0301, 0x0403),
+    (0x0415, 0x0300, 0x0400),
+    (0x0415, 0x0306, 0x04D6),
+    (0x0415, 0x0308, 0x0401),
+    (0x0416, 0x0306, 0x04C1),
+    (0x0416, 0x0308, 0x04DC),
+    (0x0417, 0x0308, 0x04DE),
+    (0x0418, 0x0300, 0x040D),
+    (0x0418, 0x0304, 0x04E2),
+    (0x0418, 0x0306, 0x0419),
+    (0x0418, 0x0308, 0x04E4),
+    (0x041A, 0x0301, 0x040C),
+    (0x041E, 0x0308, 0x04E6),
+    (0x0423, 0x0304, 0x04EE),
+    (0x0423, 0x0306, 0x040E),
+    (0x0423, 0x0308, 0x04F0),
+    (0x0423, 0x030B, 0x04F2),
+    (0x0427, 0x0308, 0x04F4),
+    (0x042B, 0x0308, 0x04F8),
+    (0x042D, 0x0308, 0x04EC),
+    (0x0430, 0x0306, 0x04D1),
+    (0x0430, 0x0308, 0x04D3),
+    (0x0433, 0x0301, 0x0453),
+    (0x0435, 0x0300, 0x0450),
+    (0x0435, 0x0306, 0x04D7),
+    (0x0435, 0x0308, 0x0451),
+    (0x0436, 0x0306, 0x04C2),
+    (0x0436, 0x0308, 0x04DD),
+    (0x0437, 0x0308, 0x04DF),
+    (0x0438, 0x0300, 0x045D),
+    (0x0438, 0x0304, 0x04E3),
+    (0x0438, 0x0306, 0x0439),
+    (0x0438, 0x0308, 0x04E5),
+    (0x043A, 0x0301, 0x045C),
+    (0x043E, 0x0308, 0x04E7),
+    (0x0443, 0x0304, 0x04EF),
+    (0x0443, 0x0306, 0x045E),
+    (0x0443, 0x0308, 0x04F1),
+    (0x0443, 0x030B, 0x04F3),
+    (0x0447, 0x0308, 0x04F5),
+    (0x044B, 0x0308, 0x04F9),
+    (0x044D, 0x0308, 0x04ED),
+    (0x0456, 0x0308, 0x0457),
+    (0x0474, 0x030F, 0x0476),
+    (0x0475, 0x030F, 0x0477),
+    (0x04D8, 0x0308, 0x04DA),
+    (0x04D9, 0x0308, 0x04DB),
+    (0x04E8, 0x0308, 0x04EA),
+    (0x04E9, 0x0308, 0x04EB),
+    (0x0627, 0x0653, 0x0622),
+    (0x0627, 0x0654, 0x0623),
+    (0x0627, 0x0655, 0x0625),
+    (0x0648, 0x0654, 0x0624),
+    (0x064A, 0x0654, 0x0626),
+    (0x06C1, 0x0654, 0x06C2),
+    (0x06D2, 0x0654, 0x06D3),
+    (0x06D5, 0x0654, 0x06C0),
+    (0x0928, 0x093C, 0x0929),
+    (0x0930, 0x093C, 0x0931),
+    (0x0933, 0x093C, 0x0934),
+    (0x09C7, 0x09BE, 0x09CB),
+    (0x09C7, 0x09D7, 0x09CC),
+    (0x0B47, 0x0B3E, 0x0B4B),
+    (0x0B47, 0x0B56, 0x0B48),
+    (0x0B47, 0x0B57, 0x0B4C),
+    (0x0B92, 0x0BD7, 0x0B94),
+    (0x0BC6, 0x0BBE, 0x0BCA),
+    (0x0BC6, 0x0BD7, 0x0BCC),
+    (0x0BC7, 0x0BBE, 0x0BCB),
+    (0x0C46, 0x0C56, 0x0C48),
+    (0x0CBF, 0x0CD5, 0x0CC0),
+    (0x0CC6, 0x0CC2, 0x0CCA),
+    (0x0CC6, 0x0CD5, 0x0CC7),
+    (0x0CC6, 0x0CD6, 0x0CC8),
+    (0x0CCA, 0x0CD5, 0x0CCB),
+    (0x0D46, 0x0D3E, 0x0D4A),
+    (0x0D46, 0x0D57, 0x0D4C),
+    (0x0D47, 0x0D3E, 0x0D4B),
+    (0x0DD9, 0x0DCA, 0x0DDA),
+    (0x0DD9, 0x0DCF, 0x0DDC),
+    (0x0DD9, 0x0DDF, 0x0DDE),
+    (0x0DDC, 0x0DCA, 0x0DDD),
+    (0x1025, 0x102E, 0x1026),
+    (0x1B05, 0x1B35, 0x1B06),
+    (0x1B07, 0x1B35, 0x1B08),
+    (0x1B09, 0x1B35, 0x1B0A),
+    (0x1B0B, 0x1B35, 0x1B0C),
+    (0x1B0D, 0x1B35, 0x1B0E),
+    (0x1B11, 0x1B35, 0x1B12),
+    (0x1B3A, 0x1B35, 0x1B3B),
+    (0x1B3C, 0x1B35, 0x1B3D),
+    (0x1B3E, 0x1B35, 0x1B40),
+    (0x1B3F, 0x1B35, 0x1B41),
+    (0x1B42, 0x1B35, 0x1B43),
+    (0x1E36, 0x0304, 0x1E38),
+    (0x1E37, 0x0304, 0x1E39),
+    (0x1E5A, 0x0304, 0x1E5C),
+    (0x1E5B, 0x0304, 0x1E5D),
+    (0x1E62, 0x0307, 0x1E68),
+    (0x1E63, 0x0307, 0x1E69),
+    (0x1EA0, 0x0302, 0x1EAC),
+    (0x1EA0, 0x0306, 0x1EB6),
+    (0x1EA1, 0x0302, 0x1EAD),
+    (0x1EA1, 0x0306, 0x1EB7),
+    (0x1EB8, 0x0302, 0x1EC6),
+    (0x1EB9, 0x0302, 0x1EC7),
+    (0x1ECC, 0x0302, 0x1ED8),
+    (0x1ECD, 0x0302, 0x1ED9),
+    (0x1F00, 0x0300, 0x1F02),
+    (0x1F00, 0x0301, 0x1F04),
+    (0x1F00, 0x0342, 0x1F06),
+    (0x1F00, 0x0345, 0x1F80),
+    (0x1F01, 0x0300, 0x1F03),
+    (0x1F01, 0x0301, 0x1F05),
+    (0x1F01, 0x0342, 0x1F07),
+    (0x1F01, 0x0345, 0x1F81),
+    (0x1F02, 0x0345, 0x1F82),
+    (0x1F03, 0x0345, 0x1F83),
+    (0x1F04, 0x0345, 0x1F84),
+    (0x1F05, 0x0345, 0x1F85),
+    (0x1F06, 0x0345, 0x1F86),
+    (0x1F07, 0x0345, 0x1F87),
+    (0x1F08, 0x0300, 0x1F0A),
+    (0x1F08, 0x0301, 0x1F0C),
+    (0x1F08, 0x0342, 0x1F0E),
+    (0x1F08, 0x0345, 0x1F88),
+    (0x1F09, 0x0300, 0x1F0B),
+    (0x1F09, 0x0301, 0x1F0D),
+    (0x1F09, 0x0342, 0x1F0F),
+    (0x1F09, 0x0345, 0x1F89),
+    (0x1F0A, 0x0345, 0x1F8A),
+    (0x1F0B, 0x0345, 0x1F8B),
+    (0x1F0C, 0x0345, 0x1F8C),
+    (0x1F0D, 0x0345, 0x1F8D),
+    (0x1F0E, 0x0345, 0x1F8E),
+    (0x1F0F, 0x0345, 0x1F8F),
+    (0x1F10, 0x0300, 0x1F12),
+    (0x1F10, 0x0301, 0x1F14),
+    (0x1F11, 0x0300, 0x1F13),
+    (0x1F11, 0x0301, 0x1F15),
+    (0x1F18, 0x0300, 0x1F1A),
+    (0x1F18, 0x0301, 0x1F1C),
+    (0x1F19, 0x0300, 0x1F1B),
+    (0x1F19, 0x0301, 0x1F1D),
+    (0x1F20, 0x0300, 0x1F22),
+    (0x1F20, 0x0301, 0x1F24),
+    (0x1F20, 0x0342, 0x1F26),
+    (0x1F20, 0x0345, 0x1F90),
+    (0x1F21, 0x0300, 0x1F23),
+    (0x1F21, 0x0301, 0x1F25),
+    (0x1F21, 0x0342, 0x1F27),
+    (0x1F21, 0x0345, 0x1F91),
+    (0x1F22, 0x0345, 0x1F92),
+    (0x1F23, 0x0345, 0x1F93),
+    (0x1F24, 0x0345, 0x1F94),
+    (0x1F25, 0x0345, 0x1F95),
+    (0x1F26, 0x0345, 0x1F96),
+    (0x1F27, 0x0345, 0x1F97),
+    (0x1F28, 0x0300, 0x1F2A),
+    (0x1F28, 0x0301, 0x1F2C),
+    (0x1F28, 0x0342, 0x1F2E),
+    (0x1F28, 0x0345, 0x1F98),
+    (0x1F29, 0x0300, 0x1F2B),
+    (0x1F29, 0x0301, 0x1F2D),
+    (0x1F29, 0x0342, 0x1F2F),
+    (0x1F29, 0x0345, 0x1F99),
+    (0x1F2A, 0x0345, 0x1F9A),
+    (0x1F2B, 0x0345, 0x1F9B),
+    (0x1F2C, 0x0345, 0x1F9C),
+    (0x1F2D, 0x0345, 0x1F9D),
+    (0x1F2E, 0x0345, 0x1F9E),
+    (0x1F2F, 0x0345, 0x1F9F),
+    (0x1F30, 0x0300, 0x1F32),
+    (0x1F30, 0x0301, 0x1F34),
+    (0x1F30, 0x0342, 0x1F36),
+    (0x1F31, 0x0300, 0x1F33),
+    (0x1F31, 0x0301, 0x1F35),
+    (0x1F31, 0x0342, 0x1F37),
+    (0x1F38, 0x0300, 0x1F3A),
+    (0x1F38, 0x0301, 0x1F3C),
+    (0x1F38, 0x0342, 0x1F3E),
+    (0x1F39, 0x0300, 0x1F3B),
+    (0x1F39, 0x0301, 0x1F3D),
+    (0x1F39, 0x0342, 0x1F3F),
+    (0x1F40, 0x0300, 0x1F42),
+    (0x1F40, 0x0301, 0x1F44),
+    (0x1F41, 0x0300, 0x1F43),
+    (0x1F41, 0x0301, 0x1F45),
+    (0x1F48, 0x0300, 0x1F4A),
+    (0x1F48, 0x0301, 0x1F4C),
+    (0x1F49, 0x0300, 0x1F4B),
+    (0x1F49, 0x0301, 0x1F4D),
+    (0x1F50, 0x0300, 0x1F52),
+    (0x1F50, 0x0301, 0x1F54),
+    (0x1F50, 0x0342, 0x1F56),
+    (0x1F51, 0x0300, 0x1F53),
+    (0x1F51, 0x0301, 0x1F55),
+    (0x1F51, 0x0342, 0x1F57),
+    (0x1F59, 0x0300, 0x1F5B),
+    (0x1F59, 0x0301, 0x1F5D),
+    (0x1F59, 0x0342, 0x1F5F),
+    (0x1F60, 0x0300, 0x1F62),
+    (0x1F60, 0x0301, 0x1F64),
+    (0x1F60, 0x0342, 0x1F66),
+    (0x1F60, 0x0345, 0x1FA0),
+    (0x1F61, 0x0300, 0x1F63),
+    (0x1F61, 0x0301, 0x1F65),
+    (0x1F61, 0x0342, 0x1F67),
+    (0x1F61, 0x0345, 0x1FA1),
+    (0x1F62, 0x0345, 0x1FA2),
+    (0x1F63, 0x0345, 0x1FA3),
+    (0x1F64, 0x0345, 0x1FA4),
+    (0x1F65, 0x0345, 0x1FA5),
+    (0x1F66, 0x0345, 0x1FA6),
+    (0x1F67, 0x0345, 0x1FA7),
+    (0x1F68, 0x0300, 0x1F6A),
+    (0x1F68, 0x0301, 0x1F6C),
+    (0x1F68, 0x0342, 0x1F6E),
+    (0x1F68, 0x0345, 0x1FA8),
+    (0x1F69, 0x0300, 0x1F6B),
+    (0x1F69, 0x0301, 0x1F6D),
+    (0x1F69, 0x0342, 0x1F6F),
+    (0x1F69, 0x0345, 0x1FA9),
+    (0x1F6A, 0x0345, 0x1FAA),
+    (0x1F6B, 0x0345, 0x1FAB),
+    (0x1F6C, 0x0345, 0x1FAC),
+    (0x1F6D, 0x0345, 0x1FAD),
+    (0x1F6E, 0x0345, 0x1FAE),
+    (0x1F6F, 0x0345, 0x1FAF),
+    (0x1F70, 0x0345, 0x1FB2),
+    (0x1F74, 0x0345, 0x1FC2),
+    (0x1F7C, 0x0345, 0x1FF2),
+    (0x1FB6, 0x0345, 0x1FB7),
+    (0x1FBF, 0x0300, 0x1FCD),
+    (0x1FBF, 0x0301, 0x1FCE),
+    (0x1FBF, 0x0342, 0x1FCF),
+    (0x1FC6, 0x0345, 0x1FC7),
+    (0x1FF6, 0x0345, 0x1FF7),
+    (0x1FFE, 0x0300, 0x1FDD),
+    (0x1FFE, 0x0301, 0x1FDE),
+    (0x1FFE, 0x0342, 0x1FDF),
+    (0x2190, 0x0338, 0x219A),
+    (0x2192, 0x0338, 0x219B),
+    (0x2194, 0x0338, 0x21AE),
+    (0x21D0, 0x0338, 0x21CD),
+    (0x21D2, 0x0338, 0x21CF),
+    (0x21D4, 0x0338, 0x21CE),
+    (0x2203, 0x0338, 0x2204),
+    (0x2208, 0x0338, 0x2209),
+    (0x220B, 0x0338, 0x220C),
+    (0x2223, 0x0338, 0x2224),
+    (0x2225, 0x0338, 0x2226),
+    (0x223C, 0x0338, 0x2241),
+    (0x2243, 0x0338, 0x2244),
+    (0x2245, 0x0338, 0x2247),
+    (0x2248, 0x0338, 0x2249),
+    (0x224D, 0x0338, 0x226D),
+    (0x2261, 0x0338, 0x2262),
+    (0x2264, 0x0338, 0x2270),
+    (0x2265, 0x0338, 0x2271),
+    (0x2272, 0x0338, 0x2274),
+    (0x2273, 0x0338, 0x2275),
+    (0x2276, 0x0338, 0x2278),
+    (0x2277, 0x0338, 0x2279),
+    (0x227A, 0x0338, 0x2280),
+    (0x227B, 0x0338, 0x2281),
+    (0x227C, 0x0338, 0x22E0),
+    (0x227D, 0x0338, 0x22E1),
+    (0x2282, 0x0338, 0x2284),
+    (0x2283, 0x0338, 0x2285),
+    (0x2286, 0x0338, 0x2288),
+    (0x2287, 0x0338, 0x2289),
+    (0x2291, 0x0338, 0x22E2),
+    (0x2292, 0x0338, 0x22E3),
+    (0x22A2, 0x0338, 0x22AC),
+    (0x22A8, 0x0338, 0x22AD),
+    (0x22A9, 0x0338, 0x22AE),
+    (0x22AB, 0x0338, 0x22AF),
+    (0x22B2, 0x0338, 0x22EA),
+    (0x22B3, 0x0338, 0x22EB),
+    (0x22B4, 0x0338, 0x22EC),
+    (0x22B5, 0x0338, 0x22ED),
+    (0x3046, 0x3099, 0x3094),
+    (0x304B, 0x3099, 0x304C),
+    (0x304D, 0x3099, 0x304E),
+    (0x304F, 0x3099, 0x3050),
+    (0x3051, 0x3099, 0x3052),
+    (0x3053, 0x3099, 0x3054),
+    (0x3055, 0x3099, 0x3056),
+    (0x3057, 0x3099, 0x3058),
+    (0x3059, 0x3099, 0x305A),
+    (0x305B, 0x3099, 0x305C),
+    (0x305D, 0x3099, 0x305E),
+    (0x305F, 0x3099, 0x3060),
+    (0x3061, 0x3099, 0x3062),
+    (0x3064, 0x3099, 0x3065),
+    (0x3066, 0x3099, 0x3067),
+    (0x3068, 0x3099, 0x3069),
+    (0x306F, 0x3099, 0x3070),
+    (0x306F, 0x309A, 0x3071),
+    (0x3072, 0x3099, 0x3073),
+    (0x3072, 0x309A, 0x3074),
+    (0x3075, 0x3099, 0x3076),
+    (0x3075, 0x309A, 0x3077),
+    (0x3078, 0x3099, 0x3079),
+    (0x3078, 0x309A, 0x307A),
+    (0x307B, 0x3099, 0x307C),
+    (0x307B, 0x309A, 0x307D),
+    (0x309D, 0x3099, 0x309E),
+    (0x30A6, 0x3099, 0x30F4),
+    (0x30AB, 0x3099, 0x30AC),
+    (0x30AD, 0x3099, 0x30AE),
+    (0x30AF, 0x3099, 0x30B0),
+    (0x30B1, 0x3099, 0x30B2),
+    (0x30B3, 0x3099, 0x30B4),
+    (0x30B5, 0x3099, 0x30B6),
+    (0x30B7, 0x3099, 0x30B8),
+    (0x30B9, 0x3099, 0x30BA),
+    (0x30BB, 0x3099, 0x30BC),
+    (0x30BD, 0x3099, 0x30BE),
+    (0x30BF, 0x3099, 0x30C0),
+    (0x30C1, 0x3099, 0x30C2),
+    (0x30C4, 0x3099, 0x30C5),
+    (0x30C6, 0x3099, 0x30C7),
+    (0x30C8, 0x3099, 0x30C9),
+    (0x30CF, 0x3099, 0x30D0),
+    (0x30CF, 0x309A, 0x30D1),
+    (0x30D2, 0x3099, 0x30D3),
+    (0x30D2, 0x309A, 0x30D4),
+    (0x30D5, 0x3099, 0x30D6),
+    (0x30D5, 0x309A, 0x30D7),
+    (0x30D8, 0x3099, 0x30D9),
+    (0x30D8, 0x309A, 0x30DA),
+    (0x30DB, 0x3099, 0x30DC),
+    (0x30DB, 0x309A, 0x30DD),
+    (0x30EF, 0x3099, 0x30F7),
+    (0x30F0, 0x3099, 0x30F8),
+    (0x30F1, 0x3099, 0x30F9),
+    (0x30F2, 0x3099, 0x30FA),
+    (0x30FD, 0x3099, 0x30FE),
+    (0x11099, 0x110BA, 0x1109A),
+    (0x1109B, 0x110BA, 0x1109C),
+    (0x110A5, 0x110BA, 0x110AB),
+    (0x11131, 0x11127, 0x1112E),
+    (0x11132, 0x11127, 0x1112F),
+    (0x11347, 0x1133E, 0x1134B),
+    (0x11347, 0x11357, 0x1134C),
+    (0x114B9, 0x114B0, 0x114BC),
+    (0x114B9, 0x114BA, 0x114BB),
+    (0x114B9, 0x114BD, 0x114BE),
+    (0x115B8, 0x115AF, 0x115BA),
+    (0x115B9, 0x115AF, 0x115BB),
+    (0x11935, 0x11930, 0x11938),
+];
+
+pub const COMPOSES_WITH_PREVIOUS: &[(u32, u32)] = &[
+    (0x0300, 0x0304),
+    (0x0306, 0x030C),
+    (0x030F, 0x030F),
+    (0x0311, 0x0311),
+    (0x0313, 0x0314),
+    (0x031B, 0x031B),
+    (0x0323, 0x0328),
+    (0x032D, 0x032E),
+    (0x0330, 0x0331),
+    (0x0338, 0x0338),
+    (0x0342, 0x0342),
+    (0x0345, 0x0345),
+    (0x0653, 0x0655),
+    (0x093C, 0x093C),
+    (0x09BE, 0x09BE),
+    (0x09D7, 0x09D7),
+    (0x0B3E, 0x0B3E),
+    (0x0B56, 0x0B57),
+    (0x0BBE, 0x0BBE),
+    (0x0BD7, 0x0BD7),
+    (0x0C56, 0x0C56),
+    (0x0CC2, 0x0CC2),
+    (0x0CD5, 0x0CD6),
+    (0x0D3E, 0x0D3E),
+    (0x0D57, 0x0D57),
+    (0x0DCA, 0x0DCA),
+    (0x0DCF, 0x0DCF),
+    (0x0DDF, 0x0DDF),
+    (0x102E, 0x102E),
+    (0x1B35, 0x1B35),
+    (0x3099, 0x309A),
+    (0x110BA, 0x110BA),
+    (0x11127, 0x11127),
+    (0x1133E, 0x1133E),
+    (0x11357, 0x11357),
+    (0x114B0, 0x114B0),
+    (0x114BA, 0x114BA),
+    (0x114BD, 0x114BD),
+    (0x115AF, 0x115AF),
+    (0x11930, 0x11930),
+];
+
+pub const CASE_FOLDING: &[(u32, &str)] = &[
+    (0x0041, "\u{61}"),
+    (0x0042, "\u{62}"),
+    (0x0043, "\u{63}"),
+    (0x0044, "\u{64}"),
+    (0x0045, "\u{65}"),
+    (0x0046, "\u{66}"),
+    (0x0047, "\u{67}"),
+    (0x0048, "\u{68}"),
+    (0x0049, "\u{69}"),
+    (0x004A, "\u{6a}"),
+    (0x004B, "\u{6b}"),
+    (0x004C, "\u{6c}"),
+    (0x004D, "\u{6d}"),
+    (0x004E, "\u{6e}"),
+    (0x004F, "\u{6f}"),
+    (0x0050, "\u{70}"),
+    (0x0051, "\u{71}"),
+    (0x0052, "\u{72}"),
+    (0x0053, "\u{73}"),
+    (0x0054, "\u{74}"),
+    (0x0055, "\u{75}"),
+    (0x0056, "\u{76}"),
+    (0x0057, "\u{77}"),
+    (0x0058, "\u{78}"),
+    (0x0059, "\u{79}"),
+    (0x005A, "\u{7a}"),
+    (0x00B5, "\u{3bc}"),
+    (0x00C0, "\u{e0}"),
+    (0x00C1, "\u{e1}"),
+    (0x00C2, "\u{e2}"),
+    (0x00C3, "\u{e3}"),
+    (0x00C4, "\u{e4}"),
+    (0x00C5, "\u{e5}"),
+    (0x00C6, "\u{e6}"),
+    (0x00C7, "\u{e7}"),
+    (0x00C8, "\u{e8}"),
+    (0x00C9, "\u{e9}"),
+    (0x00CA, "\u{ea}"),
+    (0x00CB, "\u{eb}"),
+    (0x00CC, "\u{ec}"),
+    (0x00CD, "\u{ed}"),
+    (0x00CE, "\u{ee}"),
+    (0x00CF, "\u{ef}"),
+    (0x00D0, "\u{f0}"),
+    (0x00D1, "\u{f1}"),
+    (0x00D2, "\u{f2}"),
+    (0x00D3, "\u{f3}"),
+    (0x00D4, "\u{f4}"),
+    (0x00D5, "\u{f5}"),
+    (0x00D6, "\u{f6}"),
+    (0x00D8, "\u{f8}"),
+    (0x00D9, "\u{f9}"),
+    (0x00DA, "\u{fa}"),
+    (0x00DB, "\u{fb}"),
+    (0x00DC, "\u{fc}"),
+    (0x00DD, "\u{fd}"),
+    (0x00DE, "\u{fe}"),
+    (0x00DF, "\u{73}\u{73}"),
+    (0x0100, "\u{101}"),
+    (0x0102, "\u{103}"),
+    (0x0104, "\u{105}"),
+    (0x0106, "\u{107}"),
+    (0x0108, "\u{109}"),
+    (0x010A, "\u{10b}"),
+    (0x010C, "\u{10d}"),
+    (0x010E, "\u{10f}"),
+    (0x0110, "\u{111}"),
+    (0x0112, "\u{113}"),
+    (0x0114, "\u{115}"),
+    (0x0116, "\u{117}"),
+    (0x0118, "\u{119}"),
+    (0x011A, "\u{11b}"),
+    (0x011C, "\u{11d}"),
+    (0x011E, "\u{11f}"),
+    (0x0120, "\u{121}"),
+    (0x0122, "\u{123}"),
+    (0x0124, "\u{125}"),
+    (0x0126, "\u{127}"),
+    (0x0128, "\u{129}"),
+    (0x012A, "\u{12b}"),
+    (0x012C, "\u{12d}"),
+    (0x012E, "\u{12f}"),
+    (0x0130, "\u{69}\u{307}"),
+    (0x0132, "\u{133}"),
+    (0x0134, "\u{135}"),
+    (0x0136, "\u{137}"),
+    (0x0139, "\u{13a}"),
+    (0x013B, "\u{13c}"),
+    (0x013D, "\u{13e}"),
+    (0x013F, "\u{140}"),
+    (0x0141, "\u{142}"),
+    (0x0143, "\u{144}"),
+    (0x0145, "\u{146}"),
+    (0x0147, "\u{148}"),
+    (0x0149, "\u{2bc}\u{6e}"),
+    (0x014A, "\u{14b}"),
+    (0x014C, "\u{14d}"),
+    (0x014E, "\u{14f}"),
+    (0x0150, "\u{151}"),
+    (0x0152, "\u{153}"),
+    (0x0154, "\u{155}"),
+    (0x0156, "\u{157}"),
+    (0x0158, "\u{159}"),
+    (0x015A, "\u{15b}"),
+    (0x015C, "\u{15d}"),
+    (0x015E, "\u{15f}"),
+    (0x0160, "\u{161}"),
+    (0x0162, "\u{163}"),
+    (0x0164, "\u{165}"),
+    (0x0166, "\u{167}"),
+    (0x0168, "\u{169}"),
+    (0x016A, "\u{16b}"),
+    (0x016C, "\u{16d}"),
+    (0x016E, "\u{16f}"),
+    (0x0170, "\u{171}"),
+    (0x0172, "\u{173}"),
+    (0x0174, "\u{175}"),
+    (0x0176, "\u{177}"),
+    (0x0178, "\u{ff}"),
+    (0x0179, "\u{17a}"),
+    (0x017B, "\u{17c}"),
+    (0x017D, "\u{17e}"),
+    (0x017F, "\u{73}"),
+    (0x0181, "\u{253}"),
+    (0x0182, "\u{183}"),
+    (0x0184, "\u{185}"),
+    (0x0186, "\u{254}"),
+    (0x0187, "\u{188}"),
+    (0x0189, "\u{256}"),
+    (0x018A, "\u{257}"),
+    (0x018B, "\u{18c}"),
+    (0x018E, "\u{1dd}"),
+    (0x018F, "\u{259}"),
+    (0x0190, "\u{25b}"),
+    (0x0191, "\u{192}"),
+    (0x0193, "\u{260}"),
+    (0x0194, "\u{263}"),
+    (0x0196, "\u{269}"),
+    (0x0197, "\u{268}"),
+    (0x0198, "\u{199}"),
+    (0x019C, "\u{26f}"),
+    (0x019D, "\u{272}"),
+    (0x019F, "\u{275}"),
+    (0x01A0, "\u{1a1}"),
+    (0x01A2, "\u{1a3}"),
+    (0x01A4, "\u{1a5}"),
+    (0x01A6, "\u{280}"),
+    (0x01A7, "\u{1a8}"),
+    (0x01A9, "\u{283}"),
+    (0x01AC, "\u{1ad}"),
+    (0x01AE, "\u{288}"),
+    (0x01AF, "\u{1b0}"),
+    (0x01B1, "\u{28a}"),
+    (0x01B2, "\u{28b}"),
+    (0x01B3, "\u{1b4}"),
+    (0x01B5, "\u{1b6}"),
+    (0x01B7, "\u{292}"),
+    (0x01B8, "\u{1b9}"),
+    (0x01BC, "\u{1bd}"),
+    (0x01C4, "\u{1c6}"),
+    (0x01C5, "\u{1c6}"),
+    (0x01C7, "\u{1c9}"),
+    (0x01C8, "\u{1c9}"),
+    (0x01CA, "\u{1cc}"),
+    (0x01CB, "\u{1cc}"),
+    (0x01CD, "\u{1ce}"),
+    (0x01CF, "\u{1d0}"),
+    (0x01D1, "\u{1d2}"),
+    (0x01D3, "\u{1d4}"),
+    (0x01D5, "\u{1d6}"),
+    (0x01D7, "\u{1d8}"),
+    (0x01D9, "\u{1da}"),
+    (0x01DB, "\u{1dc}"),
+    (0x01DE, "\u{1df}"),
+    (0x01E0, "\u{1e1}"),
+    (0x01E2, "\u{1e3}"),
+    (0x01E4, "\u{1e5}"),
+    (0x01E6, "\u{1e7}"),
+    (0x01E8, "\u{1e9}"),
+    (0x01EA, "\u{1eb}"),
+    (0x01EC, "\u{1ed}"),
+    (0x01EE, "\u{1ef}"),
+    (0x01F0, "\u{6a}\u{30c}"),
+    (0x01F1, "\u{1f3}"),
+    (0x01F2, "\u{1f3}"),
+    (0x01F4, "\u{1f5}"),
+    (0x01F6, "\u{195}"),
+    (0x01F7, "\u{1bf}"),
+    (0x01F8, "\u{1f9}"),
+    (0x01FA, "\u{1fb}"),
+    (0x01FC, "\u{1fd}"),
+    (0x01FE, "\u{1ff}"),
+    (0x0200, "\u{201}"),
+    (0x0202, "\u{203}"),
+    (0x0204, "\u{205}"),
+    (0x0206, "\u{207}"),
+    (0x0208, "\u{209}"),
+    (0x020A, "\u{20b}"),
+    (0x020C, "\u{20d}"),
+    (0x020E, "\u{20f}"),
+    (0x0210, "\u{211}"),
+    (0x0212, "\u{213}"),
+    (0x0214, "\u{215}"),
+    (0x0216, "\u{217}"),
+    (0x0218, "\u{219}"),
+    (0x021A, "\u{21b}"),
+    (0x021C, "\u{21d}"),
+    (0x021E, "\u{21f}"),
+    (0x0220, "\u{19e}"),
+    (0x0222, "\u{223}"),
+    (0x0224, "\u{225}"),
+    (0x0226, "\u{227}"),
+    (0x0228, "\u{229}"),
+    (0x022A, "\u{22b}"),
+    (0x022C, "\u{22d}"),
+    (0x022E, "\u{22f}"),
+    (0x0230, "\u{231}"),
+    (0x0232, "\u{233}"),
+    (0x023A, "\u{2c65}"),
+    (0x023B, "\u{23c}"),
+    (0x023D, "\u{19a}"),
+    (0x023E, "\u{2c66}"),
+    (0x0241, "\u{242}"),
+    (0x0243, "\u{180}"),
+    (0x0244, "\u{289}"),
+    (0x0245, "\u{28c}"),
+    (0x0246, "\u{247}"),
+    (0x0248, "\u{249}"),
+    (0x024A, "\u{24b}"),
+    (0x024C, "\u{24d}"),
+    (0x024E, "\u{24f}"),
+    (0x0345, "\u{3b9}"),
+    (0x0370, "\u{371}"),
+    (0x0372, "\u{373}"),
+    (0x0376, "\u{377}"),
+    (0x037F, "\u{3f3}"),
+    (0x0386, "\u{3ac}"),
+    (0x0388, "\u{3ad}"),
+    (0x0389, "\u{3ae}"),
+    (0x038A, "\u{3af}"),
+    (0x038C, "\u{3cc}"),
+    (0x038E, "\u{3cd}"),
+    (0x038F, "\u{3ce}"),
+    (0x0390, "\u{3b9}\u{308}\u{301}"),
+    (0x0391, "\u{3b1}"),
+    (0x0392, "\u{3b2}"),
+    (0x0393, "\u{3b3}"),
+    (0x0394, "\u{3b4}"),
+    (0x0395, "\u{3b5}"),
+    (0x0396, "\u{3b6}"),
+    (0x0397, "\u{3b7}"),
+    (0x0398, "\u{3b8}"),
+    (0x0399, "\u{3b9}"),
+    (0x039A, "\u{3ba}"),
+    (0x039B, "\u{3bb}"),
+    (0x039C, "\u{3bc}"),
+    (0x039D, "\u{3bd}"),
+    (0x039E, "\u{3be}"),
+    (0x039F, "\u{3bf}"),
+    (0x03A0, "\u{3c0}"),
+    (0x03A1, "\u{3c1}"),
+    (0x03A3, "\u{3c3}"),
+    (0x03A4, "\u{3c4}"),
+    (0x03A5, "\u{3c5}"),
+    (0x03A6, "\u{3c6}"),
+    (0x03A7, "\u{3c7}"),
+    (0x03A8, "\u{3c8}"),
+    (0x03A9, "\u{3c9}"),
+    (0x03AA, "\u{3ca}"),
+    (0x03AB, "\u{3cb}"),
+    (0x03B0, "\u{3c5}\u{308}\u{301}"),
+    (0x03C2, "\u{3c3}"),
+    (0x03CF, "\u{3d7}"),
+    (0x03D0, "\u{3b2}"),
+    (0x03D1, "\u{3b8}"),
+    (0x03D5, "\u{3c6}"),
+    (0x03D6, "\u{3c0}"),
+    (0x03D8, "\u{3d9}"),
+    (0x03DA, "\u{3db}"),
+    (0x03DC, "\u{3dd}"),
+    (0x03DE, "\u{3df}"),
+    (0x03E0, "\u{3e1}"),
+    (0x03E2, "\u{3e3}"),
+    (0x03E4, "\u{3e5}"),
+    (0x03E6, "\u{3e7}"),
+    (0x03E8, "\u{3e9}"),
+    (0x03EA, "\u{3eb}"),
+    (0x03EC, "\u{3ed}"),
+    (0x03EE, "\u{3ef}"),
+    (0x03F0, "\u{3ba}"),
+    (0x03F1, "\u{3c1}"),
+    (0x03F4, "\u{3b8}"),
+    (0x03F5, "\u{3b5}"),
+    (0x03F7, "\u{3f8}"),
+    (0x03F9, "\u{3f2}"),
+    (0x03FA, "\u{3fb}"),
+    (0x03FD, "\u{37b}"),
+    (0x03FE, "\u{37c}"),
+    (0x03FF, "\u{37d}"),
+    (0x0400, "\u{450}"),
+    (0x0401, "\u{451}"),
+    (0x0402, "\u{452}"),
+    (0x0403, "\u{453}"),
+    (0x0404, "\u{454}"),
+    (0x0405, "\u{455}"),
+    (0x0406, "\u{456}"),
+    (0x0407, "\u{457}"),
+    (0x0408, "\u{458}"),
+    (0x0409, "\u{459}"),
+    (0x040A, "\u{45a}"),
+    (0x040B, "\u{45b}"),
+    (0x040C, "\u{45c}"),
+    (0x040D, "\u{45d}"),
+    (0x040E, "\u{45e}"),
+    (0x040F, "\u{45f}"),
+    (0x0410, "\u{430}"),
+    (0x0411, "\u{431}"),
+    (0x0412, "\u{432}"),
+    (0x0413, "\u{433}"),
+    (0x0414, "\u{434}"),
+    (0x0415, "\u{435}"),
+    (0x0416, "\u{436}"),
+    (0x0417, "\u{437}"),
+    (0x0418, "\u{438}"),
+    (0x0419, "\u{439}"),
+    (0x041A, "\u{43a}"),
+    (0x041B, "\u{43b}"),
+    (0x041C, "\u{43c}"),
+    (0x041D, "\u{43d}"),
+    (0x041E, "\u{43e}"),
+    (0x041F, "\u{43f}"),
+    (0x0420, "\u{440}"),
+    (0x0421, "\u{441}"),
+    (0x0422, "\u{442}"),
+    (0x0423, "\u{443}"),
+    (0x0424, "\u{444}"),
+    (0x0425, "\u{445}"),
+    (0x0426, "\u{446}"),
+    (0x0427, "\u{447}"),
+    (0x0428, "\u{448}"),
+    (0x0429, "\u{449}"),
+    (0x042A, "\u{44a}"),
+    (0x042B, "\u{44b}"),
+    (0x042C, "\u{44c}"),
+    (0x042D, "\u{44d}"),
+    (0x042E, "\u{44e}"),
+    (0x042F, "\u{44f}"),
+    (0x0460, "\u{461}"),
+    (0x0462, "\u{463}"),
+    (0x0464, "\u{465}"),
+    (0x0466, "\u{467}"),
+    (0x0468, "\u{469}"),
+    (0x046A, "\u{46b}"),
+    (0x046C, "\u{46d}"),
+    (0x046E, "\u{46f}"),
+    (0x0470, "\u{471}"),
+    (0x0472, "\u{473}"),
+    (0x0474, "\u{475}"),
+    (0x0476, "\u{477}"),
+    (0x0478, "\u{479}"),
+    (0x047A, "\u{47b}"),
+    (0x047C, "\u{47d}"),
+    (0x047E, "\u{47f}"),
+    (0x0480, "\u{481}"),
+    (0x048A, "\u{48b}"),
+    (0x048C, "\u{48d}"),
+    (0x048E, "\u{48f}"),
+    (0x0490, "\u{491}"),
+    (0x0492, "\u{493}"),
+    (0x0494, "\u{495}"),
+    (0x0496, "\u{497}"),
+    (0x0498, "\u{499}"),
+    (0x049A, "\u{49b}"),
+    (0x049C, "\u{49d}"),
+    (0x049E, "\u{49f}"),
+    (0x04A0, "\u{4a1}"),
+    (0x04A2, "\u{4a3}"),
+    (0x04A4, "\u{4a5}"),
+    (0x04A6, "\u{4a7}"),
+    (0x04A8, "\u{4a9}"),
+    (0x04AA, "\u{4ab}"),
+    (0x04AC, "\u{4ad}"),
+    (0x04AE, "\u{4af}"),
+    (0x04B0, "\u{4b1}"),
+    (0x04B2, "\u{4b3}"),
+    (0x04B4, "\u{4b5}"),
+    (0x04B6, "\u{4b7}"),
+    (0x04B8, "\u{4b9}"),
+    (0x04BA, "\u{4bb}"),
+    (0x04BC, "\u{4bd}"),
+    (0x04BE, "\u{4bf}"),
+    (0x04C0, "\u{4cf}"),
+    (0x04C1, "\u{4c2}"),
+    (0x04C3, "\u{4c4}"),
+    (0x04C5, "\u{4c6}"),
+    (0x04C7, "\u{4c8}"),
+    (0x04C9, "\u{4ca}"),
+    (0x04CB, "\u{4cc}"),
+    (0x04CD, "\u{4ce}"),
+    (0x04D0, "\u{4d1}"),
+    (0x04D2, "\u{4d3}"),
+    (0x04D4, "\u{4d5}"),
+    (0x04D6, "\u{4d7}"),
+    (0x04D8, "\u{4d9}"),
+    (0x04DA, "\u{4db}"),
+    (0x04DC, "\u{4dd}"),
+    (0x04DE, "\u{4df}"),
+    (0x04E0, "\u{4e1}"),
+    (0x04E2, "\u{4e3}"),
+    (0x04E4, "\u{4e5}"),
+    (0x04E6, "\u{4e7}"),
+    (0x04E8, "\u{4e9}"),
+    (0x04EA, "\u{4eb}"),
+    (0x04EC, "\u{4ed}"),
+    (0x04EE, "\u{4ef}"),
+    (0x04F0, "\u{4f1}"),
+    (0x04F2, "\u{4f3}"),
+    (0x04F4, "\u{4f5}"),
+    (0x04F6, "\u{4f7}"),
+    (0x04F8, "\u{4f9}"),
+    (0x04FA, "\u{4fb}"),
+    (0x04FC, "\u{4fd}"),
+    (0x04FE, "\u{4ff}"),
+    (0x0500, "\u{501}"),
+    (0x0502, "\u{503}"),
+    (0x0504, "\u{505}"),
+    (0x0506, "\u{507}"),
+    (0x0508, "\u{509}"),
+    (0x050A, "\u{50b}"),
+    (0x050C, "\u{50d}"),
+    (0x050E, "\u{50f}"),
+    (0x0510, "\u{511}"),
+    (0x0512, "\u{513}"),
+    (0x0514, "\u{515}"),
+    (0x0516, "\u{517}"),
+    (0x0518, "\u{519}"),
+    (0x051A, "\u{51b}"),
+    (0x051C, "\u{51d}"),
+    (0x051E, "\u{51f}"),
+    (0x0520, "\u{521}"),
+    (0x0522, "\u{523}"),
+    (0x0524, "\u{525}"),
+    (0x0526, "\u{527}"),
+    (0x0528, "\u{529}"),
+    (0x052A, "\u{52b}"),
+    (0x052C, "\u{52d}"),
+    (0x052E, "\u{52f}"),
+    (0x0531, "\u{561}"),
+    (0x0532, "\u{562}"),
+    (0x0533, "\u{563}"),
+    (0x0534, "\u{564}"),
+    (0x0535, "\u{565}"),
+    (0x0536, "\u{566}"),
+    (0x0537, "\u{567}"),
+    (0x0538, "\u{568}"),
+    (0x0539, "\u{569}"),
+    (0x053A, "\u{56a}"),
+    (0x053B, "\u{56b}"),
+    (0x053C, "\u{56c}"),
+    (0x053D, "\u{56d}"),
+    (0x053E, "\u{56e}"),
+    (0x053F, "\u{56f}"),
+    (0x0540, "\u{570}"),
+    (0x0541, "\u{571}"),
+    (0x0542, "\u{572}"),
+    (0x0543, "\u{573}"),
+    (0x0544, "\u{574}"),
+    (0x0545, "\u{575}"),
+    (0x0546, "\u{576}"),
+    (0x0547, "\u{577}"),
+    (0x0548, "\u{578}"),
+    (0x0549, "\u{579}"),
+    (0x054A, "\u{57a}"),
+    (0x054B, "\u{57b}"),
+    (0x054C, "\u{57c}"),
+    (0x054D, "\u{57d}"),
+    (0x054E, "\u{57e}"),
+    (0x054F, "\u{57f}"),
+    (0x0550, "\u{580}"),
+    (0x0551, "\u{581}"),
+    (0x0552, "\u{582}"),
+    (0x0553, "\u{583}"),
+    (0x0554, "\u{584}"),
+    (0x0555, "\u{585}"),
+    (0x0556, "\u{586}"),
+    (0x0587, "\u{565}\u{582}"),
+    (0x10A0, "\u{2d00}"),
+    (0x10A1, "\u{2d01}"),
+    (0x10A2, "\u{2d02}"),
+    (0x10A3, "\u{2d03}"),
+    (0x10A4, "\u{2d04}"),
+    (0x10A5, "\u{2d05}"),
+    (0x10A6, "\u{2d06}"),
+    (0x10A7, "\u{2d07}"),
+    (0x10A8, "\u{2d08}"),
+    (0x10A9, "\u{2d09}"),
+    (0x10AA, "\u{2d0a}"),
+    (0x10AB, "\u{2d0b}"),
+    (0x10AC, "\u{2d0c}"),
+    (0x10AD, "\u{2d0d}"),
+    (0x10AE, "\u{2d0e}"),
+    (0x10AF, "\u{2d0f}"),
+    (0x10B0, "\u{2d10}"),
+    (0x10B1, "\u{2d11}"),
+    (0x10B2, "\u{2d12}"),
+    (0x10B3, "\u{2d13}"),
+    (0x10B4, "\u{2d14}"),
+    (0x10B5, "\u{2d15}"),
+    (0x10B6, "\u{2d16}"),
+    (0x10B7, "\u{2d17}"),
+    (0x10B8, "\u{2d18}"),
+    (0x10B9, "\u{2d19}"),
+    (0x10BA, "\u{2d1a}"),
+    (0x10BB, "\u{2d1b}"),
+    (0x10BC, "\u{2d1c}"),
+    (0x10BD, "\u{2d1d}"),
+    (0x10BE, "\u{2d1e}"),
+    (0x10BF, "\u{2d1f}"),
+    (0x10C0, "\u{2d20}"),
+    (0x10C1, "\u{2d21}"),
+    (0x10C2, "\u{2d22}"),
+    (0x10C3, "\u{2d23}"),
+    (0x10C4, "\u{2d24}"),
+    (0x10C5, "\u{2d25}"),
+    (0x10C7, "\u{2d27}"),
+    (0x10CD, "\u{2d2d}"),
+    (0x13F8, "\u{13f0}"),
+    (0x13F9, "\u{13f1}"),
+    (0x13FA, "\u{13f2}"),
+    (0x13FB, "\u{13f3}"),
+    (0x13FC, "\u{13f4}"),
+    (0x13FD, "\u{13f5}"),
+    (0x1C80, "\u{432}"),
+    (0x1C81, "\u{434}"),
+    (0x1C82, "\u{43e}"),
+    (0x1C83, "\u{441}"),
+    (0x1C84, "\u{442}"),
+    (0x1C85, "\u{442}"),
+    (0x1C86, "\u{44a}"),
+    (0x1C87, "\u{463}"),
+    (0x1C88, "\u{a64b}"),
+    (0x1C90, "\u{10d0}"),
+    (0x1C91, "\u{10d1}"),
+    (0x1C92, "\u{10d2}"),
+    (0x1C93, "\u{10d3}"),
+    (0x1C94, "\u{10d4}"),
+    (0x1C95, "\u{10d5}"),
+    (0x1C96, "\u{10d6}"),
+    (0x1C97, "\u{10d7}"),
+    (0x1C98, "\u{10d8}"),
+    (0x1C99, "\u{10d9}"),
+    (0x1C9A, "\u{10da}"),
+    (0x1C9B, "\u{10db}"),
+    (0x1C9C, "\u{10dc}"),
+    (0x1C9D, "\u{10dd}"),
+    (0x1C9E, "\u{10de}"),
+    (0x1C9F, "\u{10df}"),
+    (0x1CA0, "\u{10e0}"),
+    (0x1CA1, "\u{10e1}"),
+    (0x1CA2, "\u{10e2}"),
+    (0x1CA3, "\u{10e3}"),
+    (0x1CA4, "\u{10e4}"),
+    (0x1CA5, "\u{10e5}"),
+    (0x1CA6, "\u{10e6}"),
+    (0x1CA7, "\u{10e7}"),
+    (0x1CA8, "\u{10e8}"),
+    (0x1CA9, "\u{10e9}"),
+    (0x1CAA, "\u{10ea}"),
+    (0x1CAB, "\u{10eb}"),
+    (0x1CAC, "\u{10ec}"),
+    (0x1CAD, "\u{10ed}"),
+    (0x1CAE, "\u{10ee}"),
+    (0x1CAF, "\u{10ef}"),
+    (0x1CB0, "\u{10f0}"),
+    (0x1CB1, "\u{10f1}"),
+    (0x1CB2, "\u{10f2}"),
+    (0x1CB3, "\u{10f3}"),
+    (0x1CB4, "\u{10f4}"),
+    (0x1CB5, "\u{10f5}"),
+    (0x1CB6, "\u{10f6}"),
+    (0x1CB7, "\u{10f7}"),
+    (0x1CB8, "\u{10f8}"),
+    (0x1CB9, "\u{10f9}"),
+    (0x1CBA, "\u{10fa}"),
+    (0x1CBD, "\u{10fd}"),
+    (0x1CBE, "\u{10fe}"),
+    (0x1CBF, "\u{10ff}"),
+    (0x1E00, "\u{1e01}"),
+    (0x1E02, "\u{1e03}"),
+    (0x1E04, "\u{1e05}"),
+    (0x1E06, "\u{1e07}"),
+    (0x1E08, "\u{1e09}"),
+    (0x1E0A, "\u{1e0b}"),
+    (0x1E0C, "\u{1e0d}"),
+    (0x1E0E, "\u{1e0f}"),
+    (0x1E10, "\u{1e11}"),
+    (0x1E12, "\u{1e13}"),
+    (0x1E14, "\u{1e15}"),
+    (0x1E16, "\u{1e17}"),
+    (0x1E18, "\u{1e19}"),
+    (0x1E1A, "\u{1e1b}"),
+    (0x1E1C, "\u{1e1d}"),
+    (0x1E1E, "\u{1e1f}"),
+    (0x1E20, "\u{1e21}"),
+    (0x1E22, "\u{1e23}"),
+    (0x1E24, "\u{1e25}"),
+    (0x1E26, "\u{1e27}"),
+    (0x1E28, "\u{1e29}"),
+    (0x1E2A, "\u{1e2b}"),
+    (0x1E2C, "\u{1e2d}"),
+    (0x1E2E, "\u{1e2f}"),
+    (0x1E30, "\u{1e31}"),
+    (0x1E32, "\u{1e33}"),
+    (0x1E34, "\u{1e35}"),
+    (0x1E36, "\u{1e37}"),
+    (0x1E38, "\u{1e39}"),
+    (0x1E3A, "\u{1e3b}"),
+    (0x1E3C, "\u{1e3d}"),
+    (0x1E3E, "\u{1e3f}"),
+    (0x1E40, "\u{1e41}"),
+    (0x1E42, "\u{1e43}"),
+    (0x1E44, "\u{1e45}"),
+    (0x1E46, "\u{1e47}"),
+    (0x1E48, "\u{1e49}"),
+    (0x1E4A, "\u{1e4b}"),
+    (0x1E4C, "\u{1e4d}"),
+    (0x1E4E, "\u{1e4f}"),
+    (0x1E50, "\u{1e51}"),
+    (0x1E52, "\u{1e53}"),
+    (0x1E54, "\u{1e55}"),
+    (0x1E56, "\u{1e57}"),
+    (0x1E58, "\u{1e59}"),
+    (0x1E5A, "\u{1e5b}"),
+    (0x1E5C, "\u{1e5d}"),
+    (0x1E5E, "\u{1e5f}"),
+    (0x1E60, "\u{1e61}"),
+    (0x1E62, "\u{1e63}"),
+    (0x1E64, "\u{1e65}"),
+    (0x1E66, "\u{1e67}"),
+    (0x1E68, "\u{1e69}"),
+    (0x1E6A, "\u{1e6b}"),
+    (0x1E6C, "\u{1e6d}"),
+    (0x1E6E, "\u{1e6f}"),
+    (0x1E70, "\u{1e71}"),
+    (0x1E72, "\u{1e73}"),
+    (0x1E74, "\u{1e75}"),
+    (0x1E76, "\u{1e77}"),
+    (0x1E78, "\u{1e79}"),
+    (0x1E7A, "\u{1e7b}"),
+    (0x1E7C, "\u{1e7d}"),
+    (0x1E7E, "\u{1e7f}"),
+    (0x1E80, "\u{1e81}"),
+    (0x1E82, "\u{1e83}"),
+    (0x1E84, "\u{1e85}"),
+    (0x1E86, "\u{1e87}"),
+    (0x1E88, "\u{1e89}"),
+    (0x1E8A, "\u{1e8b}"),
+    (0x1E8C, "\u{1e8d}"),
+    (0x1E8E, "\u{1e8f}"),
+    (0x1E90, "\u{1e91}"),
+    (0x1E92, "\u{1e93}"),
+    (0x1E94, "\u{1e95}"),
+    (0x1E96, "\u{68}\u{331}"),
+    (0x1E97, "\u{74}\u{308}"),
+    (0x1E98, "\u{77}\u{30a}"),
+    (0x1E99, "\u{79}\u{30a}"),
+    (0x1E9A, "\u{61}\u{2be}"),
+    (0x1E9B, "\u{1e61}"),
+    (0x1E9E, "\u{73}\u{73}"),
+    (0x1EA0, "\u{1ea1}"),
+    (0x1EA2, "\u{1ea3}"),
+    (0x1EA4, "\u{1ea5}"),
+    (0x1EA6, "\u{1ea7}"),
+    (0x1EA8, "\u{1ea9}"),
+    (0x1EAA, "\u{1eab}"),
+    (0x1EAC, "\u{1ead}"),
+    (0x1EAE, "\u{1eaf}"),
+    (0x1EB0, "\u{1eb1}"),
+    (0x1EB2, "\u{1eb3}"),
+    (0x1EB4, "\u{1eb5}"),
+    (0x1EB6, "\u{1eb7}"),
+    (0x1EB8, "\u{1eb9}"),
+    (0x1EBA, "\u{1ebb}"),
+    (0x1EBC, "\u{1ebd}"),
+    (0x1EBE, "\u{1ebf}"),
+    (0x1EC0, "\u{1ec1}"),
+    (0x1EC2, "\u{1ec3}"),
+    (0x1EC4, "\u{1ec5}"),
+    (0x1EC6, "\u{1ec7}"),
+    (0x1EC8, "\u{1ec9}"),
+    (0x1ECA, "\u{1ecb}"),
+    (0x1ECC, "\u{1ecd}"),
+    (0x1ECE, "\u{1ecf}"),
+    (0x1ED0, "\u{1ed1}"),
+    (0x1ED2, "\u{1ed3}"),
+    (0x1ED4, "\u{1ed5}"),
+    (0x1ED6, "\u{1ed7}"),
+    (0x1ED8, "\u{1ed9}"),
+    (0x1EDA, "\u{1edb}"),
+    (0x1EDC, "\u{1edd}"),
+    (0x1EDE, "\u{1edf}"),
+    (0x1EE0, "\u{1ee1}"),
+    (0x1EE2, "\u{1ee3}"),
+    (0x1EE4, "\u{1ee5}"),
+    (0x1EE6, "\u{1ee7}"),
+    (0x1EE8, "\u{1ee9}"),
+    (0x1EEA, "\u{1eeb}"),
+    (0x1EEC, "\u{1eed}"),
+    (0x1EEE, "\u{1eef}"),
+    (0x1EF0, "\u{1ef1}"),
+    (0x1EF2, "\u{1ef3}"),
+    (0x1EF4, "\u{1ef5}"),
+    (0x1EF6, "\u{1ef7}"),
+    (0x1EF8, "\u{1ef9}"),
+    (0x1EFA, "\u{1efb}"),
+    (0x1EFC, "\u{1efd}"),
+    (0x1EFE, "\u{1eff}"),
+    (0x1F08, "\u{1f00}"),
+    (0x1F09, "\u{1f01}"),
+    (0x1F0A, "\u{1f02}"),
+    (0x1F0B, "\u{1f03}"),
+    (0x1F0C, "\u{1f04}"),
+    (0x1F0D, "\u{1f05}"),
+    (0x1F0E, "\u{1f06}"),
+    (0x1F0F, "\u{1f07}"),
+    (0x1F18, "\u{1f10}"),
+    (0x1F19, "\u{1f11}"),
+    (0x1F1A, "\u{1f12}"),
+    (0x1F1B, "\u{1f13}"),
+    (0x1F1C, "\u{1f14}"),
+    (0x1F1D, "\u{1f15}"),
+    (0x1F28, "\u{1f20}"),
+    (0x1F29, "\u{1f21}"),
+    (0x1F2A, "\u{1f22}"),
+    (0x1F2B, "\u{1f23}"),
+    (0x1F2C, "\u{1f24}"),
+    (0x1F2D, "\u{1f25}"),
+    (0x1F2E, "\u{1f26}"),
+    (0x1F2F, "\u{1f27}"),
+    (0x1F38, "\u{1f30}"),
+    (0x1F39, "\u{1f31}"),
+    (0x1F3A, "\u{1f32}"),
+    (0x1F3B, "\u{1f33}"),
+    (0x1F3C, "\u{1f34}"),
+    (0x1F3D, "\u{1f35}"),
+    (0x1F3E, "\u{1f36}"),
+    (0x1F3F, "\u{1f37}"),
+    (0x1F48, "\u{1f40}"),
+    (0x1F49, "\u{1f41}"),
+    (0x1F4A, "\u{1f42}"),
+    (0x1F4B, "\u{1f43}"),
+    (0x1F4C, "\u{1f44}"),
+    (0x1F4D, "\u{1f45}"),
+    (0x1F50, "\u{3c5}\u{313}"),
+    (0x1F52, "\u{3c5}\u{313}\u{300}"),
+    (0x1F54, "\u{3c5}\u{313}\u{301}"),
+    (0x1F56, "\u{3c5}\u{313}\u{342}"),
+    (0x1F59, "\u{1f51}"),
+    (0x1F5B, "\u{1f53}"),
+    (0x1F5D, "\u{1f55}"),
+    (0x1F5F, "\u{1f57}"),
+    (0x1F68, "\u{1f60}"),
+    (0x1F69, "\u{1f61}"),
+    (0x1F6A, "\u{1f62}"),
+    (0x1F6B, "\u{1f63}"),
+    (0x1F6C, "\u{1f64}"),
+    (0x1F6D, "\u{1f65}"),
+    (0x1F6E, "\u{1f66}"),
+    (0x1F6F, "\u{1f67}"),
+    (0x1F80, "\u{1f00}\u{3b9}"),
+    (0x1F81, "\u{1f01}\u{3b9}"),
+    (0x1F82, "\u{1f02}\u{3b9}"),
+    (0x1F83, "\u{1f03}\u{3b9}"),
+    (0x1F84, "\u{1f04}\u{3b9}"),
+    (0x1F85, "\u{1f05}\u{3b9}"),
+    (0x1F86, "\u{1f06}\u{3b9}"),
+    (0x1F87, "\u{1f07}\u{3b9}"),
+    (0x1F88, "\u{1f00}\u{3b9}"),
+    (0x1F89, "\u{1f01}\u{3b9}"),
+    (0x1F8A, "\u{1f02}\u{3b9}"),
+    (0x1F8B, "\u{1f03}\u{3b9}"),
+    (0x1F8C, "\u{1f04}\u{3b9}"),
+    (0x1F8D, "\u{1f05}\u{3b9}"),
+    (0x1F8E, "\u{1f06}\u{3b9}"),
+    (0x1F8F, "\u{1f07}\u{3b9}"),
+    (0x1F90, "\u{1f20}\u{3b9}"),
+    (0x1F91, "\u{1f21}\u{3b9}"),
+    (0x1F92, "\u{1f22}\u{3b9}"),
+    (0x1F93, "\u{1f23}\u{3b9}"),
+    (0x1F94, "\u{1f24}\u{3b9}"),
+    (0x1F95, "\u{1f25}\u{3b9}"),
+    (0x1F96, "\u{1f26}\u{3b9}"),
+    (0x1F97, "\u{1f27}\u{3b9}"),
+    (0x1F98, "\u{1f20}\u{3b9}"),
+    (0x1F99, "\u{1f21}\u{3b9}"),
+    (0x1F9A, "\u{1f22}\u{3b9}"),
+    (0x1F9B, "\u{1f23}\u{3b9}"),
+    (0x1F9C, "\u{1f24}\u{3b9}"),
+    (0x1F9D, "\u{1f25}\u{3b9}"),
+    (0x1F9E, "\u{1f26}\u{3b9}"),
+    (0x1F9F, "\u{1f27}\u{3b9}"),
+    (0x1FA0, "\u{1f60}\u{3b9}"),
+    (0x1FA1, "\u{1f61}\u{3b9}"),
+    (0x1FA2, "\u{1f62}\u{3b9}"),
+    (0x1FA3, "\u{1f63}\u{3b9}"),
+    (0x1FA4, "\u{1f64}\u{3b9}"),
+    (0x1FA5, "\u{1f65}\u{3b9}"),
+    (0x1FA6, "\u{1f66}\u{3b9}"),
+    (0x1FA7, "\u{1f67}\u{3b9}"),
+    (0x1FA8, "\u{1f60}\u{3b9}"),
+    (0x1FA9, "\u{1f61}\u{3b9}"),
+    (0x1FAA, "\u{1f62}\u{3b9}"),
+    (0x1FAB, "\u{1f63}\u{3b9}"),
+    (0x1FAC, "\u{1f64}\u{3b9}"),
+    (0x1FAD, "\u{1f65}\u{3b9}"),
+    (0x1FAE, "\u{1f66}\u{3b9}"),
+    (0x1FAF, "\u{1f67}\u{3b9}"),
+    (0x1FB2, "\u{1f70}\u{3b9}"),
+    (0x1FB3, "\u{3b1}\u{3b9}"),
+    (0x1FB4, "\u{3ac}\u{3b9}"),
+    (0x1FB6, "\u{3b1}\u{342}"),
+    (0x1FB7, "\u{3b1}\u{342}\u{3b9}"),
+    (0x1FB8, "\u{1fb0}"),
+    (0x1FB9, "\u{1fb1}"),
+    (0x1FBA, "\u{1f70}"),
+    (0x1FBB, "\u{1f71}"),
+    (0x1FBC, "\u{3b1}\u{3b9}"),
+    (0x1FBE, "\u{3b9}"),
+    (0x1FC2, "\u{1f74}\u{3b9}"),
+    (0x1FC3, "\u{3b7}\u{3b9}"),
+    (0x1FC4, "\u{3ae}\u{3b9}"),
+    (0x1FC6, "\u{3b7}\u{342}"),
+    (0x1FC7, "\u{3b7}\u{342}\u{3b9}"),
+    (0x1FC8, "\u{1f72}"),
+    (0x1FC9, "\u{1f73}"),
+    (0x1FCA, "\u{1f74}"),
+    (0x1FCB, "\u{1f75}"),
+    (0x1FCC, "\u{3b7}\u{3b9}"),
+    (0x1FD2, "\u{3b9}\u{308}\u{300}"),
+    (0x1FD3, "\u{3b9}\u{308}\u{301}"),
+    (0x1FD6, "\u{3b9}\u{342}"),
+    (0x1FD7, "\u{3b9}\u{308}\u{342}"),
+    (0x1FD8, "\u{1fd0}"),
+    (0x1FD9, "\u{1fd1}"),
+    (0x1FDA, "\u{1f76}"),
+    (0x1FDB, "\u{1f77}"),
+    (0x1FE2, "\u{3c5}\u{308}\u{300}"),
+    (0x1FE3, "\u{3c5}\u{308}\u{301}"),
+    (0x1FE4, "\u{3c1}\u{313}"),
+    (0x1FE6, "\u{3c5}\u{342}"),
+    (0x1FE7, "\u{3c5}\u{308}\u{342}"),
+    (0x1FE8, "\u{1fe0}"),
+    (0x1FE9, "\u{1fe1}"),
+    (0x1FEA, "\u{1f7a}"),
+    (0x1FEB, "\u{1f7b}"),
+    (0x1FEC, "\u{1fe5}"),
+    (0x1FF2, "\u{1f7c}\u{3b9}"),
+    (0x1FF3, "\u{3c9}\u{3b9}"),
+    (0x1FF4, "\u{3ce}\u{3b9}"),
+    (0x1FF6, "\u{3c9}\u{342}"),
+    (0x1FF7, "\u{3c9}\u{342}\u{3b9}"),
+    (0x1FF8, "\u{1f78}"),
+    (0x1FF9, "\u{1f79}"),
+    (0x1FFA, "\u{1f7c}"),
+    (0x1FFB, "\u{1f7d}"),
+    (0x1FFC, "\u{3c9}\u{3b9}"),
+    (0x2126, "\u{3c9}"),
+    (0x212A, "\u{6b}"),
+    (0x212B, "\u{e5}"),
+    (0x2132, "\u{214e}"),
+    (0x2160, "\u{2170}"),
+    (0x2161, "\u{2171}"),
+    (0x2162, "\u{2172}"),
+    (0x2163, "\u{2173}"),
+    (0x2164, "\u{2174}"),
+    (0x2165, "\u{2175}"),
+    (0x2166, "\u{2176}"),
+    (0x2167, "\u{2177}"),
+    (0x2168, "\u{2178}"),
+    (0x2169, "\u{2179}"),
+    (0x216A, "\u{217a}"),
+    (0x216B, "\u{217b}"),
+    (0x216C, "\u{217c}"),
+    (0x216D, "\u{217d}"),
+    (0x216E, "\u{217e}"),
+    (0x216F, "\u{217f}"),
+    (0x2183, "\u{2184}"),
+    (0x24B6, "\u{24d0}"),
+    (0x24B7, "\u{24d1}"),
+    (0x24B8, "\u{24d2}"),
+    (0x24B9, "\u{24d3}"),
+    (0x24BA, "\u{24d4}"),
+    (0x24BB, "\u{24d5}"),
+    (0x24BC, "\u{24d6}"),
+    (0x24BD, "\u{24d7}"),
+    (0x24BE, "\u{24d8}"),
+    (0x24BF, "\u{24d9}"),
+    (0x24C0, "\u{24da}"),
+    (0x24C1, "\u{24db}"),
+    (0x24C2, "\u{24dc}"),
+    (0x24C3, "\u{24dd}"),
+    (0x24C4, "\u{24de}"),
+    (0x24C5, "\u{24df}"),
+    (0x24C6, "\u{24e0}"),
+    (0x24C7, "\u{24e1}"),
+    (0x24C8, "\u{24e2}"),
+    (0x24C9, "\u{24e3}"),
+    (0x24CA, "\u{24e4}"),
+    (0x24CB, "\u{24e5}"),
+    (0x24CC, "\u{24e6}"),
+    (0x24CD, "\u{24e7}"),
+    (0x24CE, "\u{24e8}"),
+    (0x24CF, "\u{24e9}"),
+    (0x2C00, "\u{2c30}"),
+    (0x2C01, "\u{2c31}"),
+    (0x2C02, "\u{2c32}"),
+    (0x2C03, "\u{2c33}"),
+    (0x2C04, "\u{2c34}"),
+    (0x2C05, "\u{2c35}"),
+    (0x2C06, "\u{2c36}"),
+    (0x2C07, "\u{2c37}"),
+    (0x2C08, "\u{2c38}"),
+    (0x2C09, "\u{2c39}"),
+    (0x2C0A, "\u{2c3a}"),
+    (0x2C0B, "\u{2c3b}"),
+    (0x2C0C, "\u{2c3c}"),
+    (0x2C0D, "\u{2c3d}"),
+    (0x2C0E, "\u{2c3e}"),
+    (0x2C0F, "\u{2c3f}"),
+    (0x2C10, "\u{2c40}"),
+    (0x2C11, "\u{2c41}"),
+    (0x2C12, "\u{2c42}"),
+    (0x2C13, "\u{2c43}"),
+    (0x2C14, "\u{2c44}"),
+    (0x2C15, "\u{2c45}"),
+    (0x2C16, "\u{2c46}"),
+    (0x2C17, "\u{2c47}"),
+    (0x2C18, "\u{2c48}"),
+    (0x2C19, "\u{2c49}"),
+    (0x2C1A, "\u{2c4a}"),
+    (0x2C1B, "\u{2c4b}"),
+    (0x2C1C, "\u{2c4c}"),
+    (0x2C1D, "\u{2c4d}"),
+    (0x2C1E, "\u{2c4e}"),
+    (0x2C1F, "\u{2c4f}"),
+    (0x2C20, "\u{2c50}"),
+    (0x2C21, "\u{2c51}"),
+    (0x2C22, "\u{2c52}"),
+    (0x2C23, "\u{2c53}"),
+    (0x2C24, "\u{2c54}"),
+    (0x2C25, "\u{2c55}"),
+    (0x2C26, "\u{2c56}"),
+    (0x2C27, "\u{2c57}"),
+    (0x2C28, "\u{2c58}"),
+    (0x2C29, "\u{2c59}"),
+    (0x2C2A, "\u{2c5a}"),
+    (0x2C2B, "\u{2c5b}"),
+    (0x2C2C, "\u{2c5c}"),
+    (0x2C2D, "\u{2c5d}"),
+    (0x2C2E, "\u{2c5e}"),
+    (0x2C2F, "\u{2c5f}"),
+    (0x2C60, "\u{2c61}"),
+    (0x2C62, "\u{26b}"),
+    (0x2C63, "\u{1d7d}"),
+    (0x2C64, "\u{27d}"),
+    (0x2C67, "\u{2c68}"),
+    (0x2C69, "\u{2c6a}"),
+    (0x2C6B, "\u{2c6c}"),
+    (0x2C6D, "\u{251}"),
+    (0x2C6E, "\u{271}"),
+    (0x2C6F, "\u{250}"),
+    (0x2C70, "\u{252}"),
+    (0x2C72, "\u{2c73}"),
+    (0x2C75, "\u{2c76}"),
+    (0x2C7E, "\u{23f}"),
+    (0x2C7F, "\u{240}"),
+    (0x2C80, "\u{2c81}"),
+    (0x2C82, "\u{2c83}"),
+    (0x2C84, "\u{2c85}"),
+    (0x2C86, "\u{2c87}"),
+    (0x2C88, "\u{2c89}"),
+    (0x2C8A, "\u{2c8b}"),
+    (0x2C8C, "\u{2c8d}"),
+    (0x2C8E, "\u{2c8f}"),
+    (0x2C90, "\u{2c91}"),
+    (0x2C92, "\u{2c93}"),
+    (0x2C94, "\u{2c95}"),
+    (0x2C96, "\u{2c97}"),
+    (0x2C98, "\u{2c99}"),
+    (0x2C9A, "\u{2c9b}"),
+    (0x2C9C, "\u{2c9d}"),
+    (0x2C9E, "\u{2c9f}"),
+    (0x2CA0, "\u{2ca1}"),
+    (0x2CA2, "\u{2ca3}"),
+    (0x2CA4, "\u{2ca5}"),
+    (0x2CA6, "\u{2ca7}"),
+    (0x2CA8, "\u{2ca9}"),
+    (0x2CAA, "\u{2cab}"),
+    (0x2CAC, "\u{2cad}"),
+    (0x2CAE, "\u{2caf}"),
+    (0x2CB0, "\u{2cb1}"),
+    (0x2CB2, "\u{2cb3}"),
+    (0x2CB4, "\u{2cb5}"),
+    (0x2CB6, "\u{2cb7}"),
+    (0x2CB8, "\u{2cb9}"),
+    (0x2CBA, "\u{2cbb}"),
+    (0x2CBC, "\u{2cbd}"),
+    (0x2CBE, "\u{2cbf}"),
+    (0x2CC0, "\u{2cc1}"),
+    (0x2CC2, "\u{2cc3}"),
+    (0x2CC4, "\u{2cc5}"),
+    (0x2CC6, "\u{2cc7}"),
+    (0x2CC8, "\u{2cc9}"),
+    (0x2CCA, "\u{2ccb}"),
+    (0x2CCC, "\u{2ccd}"),
+    (0x2CCE, "\u{2ccf}"),
+    (0x2CD0, "\u{2cd1}"),
+    (0x2CD2, "\u{2cd3}"),
+    (0x2CD4, "\u{2cd5}"),
+    (0x2CD6, "\u{2cd7}"),
+    (0x2CD8, "\u{2cd9}"),
+    (0x2CDA, "\u{2cdb}"),
+    (0x2CDC, "\u{2cdd}"),
+    (0x2CDE, "\u{2cdf}"),
+    (0x2CE0, "\u{2ce1}"),
+    (0x2CE2, "\u{2ce3}"),
+    (0x2CEB, "\u{2cec}"),
+    (0x2CED, "\u{2cee}"),
+    (0x2CF2, "\u{2cf3}"),
+    (0xA640, "\u{a641}"),
+    (0xA642, "\u{a643}"),
+    (0xA644, "\u{a645}"),
+    (0xA646, "\u{a647}"),
+    (0xA648, "\u{a649}"),
+    (0xA64A, "\u{a64b}"),
+    (0xA64C, "\u{a64d}"),
+    (0xA64E, "\u{a64f}"),
+    (0xA650, "\u{a651}"),
+    (0xA652, "\u{a653}"),
+    (0xA654, "\u{a655}"),
+    (0xA656, "\u{a657}"),
+    (0xA658, "\u{a659}"),
+    (0xA65A, "\u{a65b}"),
+    (0xA65C, "\u{a65d}"),
+    (0xA65E, "\u{a65f}"),
+    (0xA660, "\u{a661}"),
+    (0xA662, "\u{a663}"),
+    (0xA664, "\u{a665}"),
+    (0xA666, "\u{a667}"),
+    (0xA668, "\u{a669}"),
+    (0xA66A, "\u{a66b}"),
+    (0xA66C, "\u{a66d}"),
+    (0xA680, "\u{a681}"),
+    (0xA682, "\u{a683}"),
+    (0xA684, "\u{a685}"),
+    (0xA686, "\u{a687}"),
+    (0xA688, "\u{a689}"),
+    (0xA68A, "\u{a68b}"),
+    (0xA68C, "\u{a68d}"),
+    (0xA68E, "\u{a68f}"),
+    (0xA690, "\u{a691}"),
+    (0xA692, "\u{a693}"),
+    (0xA694, "\u{a695}"),
+    (0xA696, "\u{a697}"),
+    (0xA698, "\u{a699}"),
+    (0xA69A, "\u{a69b}"),
+    (0xA722, "\u{a723}"),
+    (0xA724, "\u{a725}"),
+    (0xA726, "\u{a727}"),
+    (0xA728, "\u{a729}"),
+    (0xA72A, "\u{a72b}"),
+    (0xA72C, "\u{a72d}"),
+    (0xA72E, "\u{a72f}"),
+    (0xA732, "\u{a733}"),
+    (0xA734, "\u{a735}"),
+    (0xA736, "\u{a737}"),
+    (0xA738, "\u{a739}"),
+    (0xA73A, "\u{a73b}"),
+    (0xA73C, "\u{a73d}"),
+    (0xA73E, "\u{a73f}"),
+    (0xA740, "\u{a741}"),
+    (0xA742, "\u{a743}"),
+    (0xA744, "\u{a745}"),
+    (0xA746, "\u{a747}"),
+    (0xA748, "\u{a749}"),
+    (0xA74A, "\u{a74b}"),
+    (0xA74C, "\u{a74d}"),
+    (0xA74E, "\u{a74f}"),
+    (0xA750, "\u{a751}"),
+    (0xA752, "\u{a753}"),
+    (0xA754, "\u{a755}"),
+    (0xA756, "\u{a757}"),
+    (0xA758, "\u{a759}"),
+    (0xA75A, "\u{a75b}"),
+    (0xA75C, "\u{a75d}"),
+    (0xA75E, "\u{a75f}"),
+    (0xA760, "\u{a761}"),
+    (0xA762, "\u{a763}"),
+    (0xA764, "\u{a765}"),
+    (0xA766, "\u{a767}"),
+    (0xA768, "\u{a769}"),
+    (0xA76A, "\u{a76b}"),
+    (0xA76C, "\u{a76d}"),
+    (0xA76E, "\u{a76f}"),
+    (0xA779, "\u{a77a}"),
+    (0xA77B, "\u{a77c}"),
+    (0xA77D, "\u{1d79}"),
+    (0xA77E, "\u{a77f}"),
+    (0xA780, "\u{a781}"),
+    (0xA782, "\u{a783}"),
+    (0xA784, "\u{a785}"),
+    (0xA786, "\u{a787}"),
+    (0xA78B, "\u{a78c}"),
+    (0xA78D, "\u{265}"),
+    (0xA790, "\u{a791}"),
+    (0xA792, "\u{a793}"),
+    (0xA796, "\u{a797}"),
+    (0xA798, "\u{a799}"),
+    (0xA79A, "\u{a79b}"),
+    (0xA79C, "\u{a79d}"),
+    (0xA79E, "\u{a79f}"),
+    (0xA7A0, "\u{a7a1}"),
+    (0xA7A2, "\u{a7a3}"),
+    (0xA7A4, "\u{a7a5}"),
+    (0xA7A6, "\u{a7a7}"),
+    (0xA7A8, "\u{a7a9}"),
+    (0xA7AA, "\u{266}"),
+    (0xA7AB, "\u{25c}"),
+    (0xA7AC, "\u{261}"),
+    (0xA7AD, "\u{26c}"),
+    (0xA7AE, "\u{26a}"),
+    (0xA7B0, "\u{29e}"),
+    (0xA7B1, "\u{287}"),
+    (0xA7B2, "\u{29d}"),
+    (0xA7B3, "\u{ab53}"),
+    (0xA7B4, "\u{a7b5}"),
+    (0xA7B6, "\u{a7b7}"),
+    (0xA7B8, "\u{a7b9}"),
+    (0xA7BA, "\u{a7bb}"),
+    (0xA7BC, "\u{a7bd}"),
+    (0xA7BE, "\u{a7bf}"),
+    (0xA7C0, "\u{a7c1}"),
+    (0xA7C2, "\u{a7c3}"),
+    (0xA7C4, "\u{a794}"),
+    (0xA7C5, "\u{282}"),
+    (0xA7C6, "\u{1d8e}"),
+    (0xA7C7, "\u{a7c8}"),
+    (0xA7C9, "\u{a7ca}"),
+    (0xA7D0, "\u{a7d1}"),
+    (0xA7D6, "\u{a7d7}"),
+    (0xA7D8, "\u{a7d9}"),
+    (0xA7F5, "\u{a7f6}"),
+    (0xAB70, "\u{13a0}"),
+    (0xAB71, "\u{13a1}"),
+    (0xAB72, "\u{13a2}"),
+    (0xAB73, "\u{13a3}"),
+    (0xAB74, "\u{13a4}"),
+    (0xAB75, "\u{13a5}"),
+    (0xAB76, "\u{13a6}"),
+    (0xAB77, "\u{13a7}"),
+    (0xAB78, "\u{13a8}"),
+    (0xAB79, "\u{13a9}"),
+    (0xAB7A, "\u{13aa}"),
+    (0xAB7B, "\u{13ab}"),
+    (0xAB7C, "\u{13ac}"),
+    (0xAB7D, "\u{13ad}"),
+    (0xAB7E, "\u{13ae}"),
+    (0xAB7F, "\u{13af}"),
+    (0xAB80, "\u{13b0}"),
+    (0xAB81, "\u{13b1}"),
+    (0xAB82, "\u{13b2}"),
+    (0xAB83, "\u{13b3}"),
+    (0xAB84, "\u{13b4}"),
+    (0xAB85, "\u{13b5}"),
+    (0xAB86, "\u{13b6}"),
+    (0xAB87, "\u{13b7}"),
+    (0xAB88, "\u{13b8}"),
+    (0xAB89, "\u{13b9}"),
+    (0xAB8A, "\u{13ba}"),
+    (0xAB8B, "\u{13bb}"),
+    (0xAB8C, "\u{13bc}"),
+    (0xAB8D, "\u{13bd}"),
+    (0xAB8E, "\u{13be}"),
+    (0xAB8F, "\u{13bf}"),
+    (0xAB90, "\u{13c0}"),
+    (0xAB91, "\u{13c1}"),
+    (0xAB92, "\u{13c2}"),
+    (0xAB93, "\u{13c3}"),
+    (0xAB94, "\u{13c4}"),
+    (0xAB95, "\u{13c5}"),
+    (0xAB96, "\u{13c6}"),
+    (0xAB97, "\u{13c7}"),
+    (0xAB98, "\u{13c8}"),
+    (0xAB99, "\u{13c9}"),
+    (0xAB9A, "\u{13ca}"),
+    (0xAB9B, "\u{13cb}"),
+    (0xAB9C, "\u{13cc}"),
+    (0xAB9D, "\u{13cd}"),
+    (0xAB9E, "\u{13ce}"),
+    (0xAB9F, "\u{13cf}"),
+    (0xABA0, "\u{13d0}"),
+    (0xABA1, "\u{13d1}"),
+    (0xABA2, "\u{13d2}"),
+    (0xABA3, "\u{13d3}"),
+    (0xABA4, "\u{13d4}"),
+    (0xABA5, "\u{13d5}"),
+    (0xABA6, "\u{13d6}"),
+    (0xABA7, "\u{13d7}"),
+    (0xABA8, "\u{13d8}"),
+    (0xABA9, "\u{13d9}"),
+    (0xABAA, "\u{13da}"),
+    (0xABAB, "\u{13db}"),
+    (0xABAC, "\u{13dc}"),
+    (0xABAD, "\u{13dd}"),
+    (0xABAE, "\u{13de}"),
+    (0xABAF, "\u{13df}"),
+    (0xABB0, "\u{13e0}"),
+    (0xABB1, "\u{13e1}"),
+    (0xABB2, "\u{13e2}"),
+    (0xABB3, "\u{13e3}"),
+    (0xABB4, "\u{13e4}"),
+    (0xABB5, "\u{13e5}"),
+    (0xABB6, "\u{13e6}"),
+    (0xABB7, "\u{13e7}"),
+    (0xABB8, "\u{13e8}"),
+    (0xABB9, "\u{13e9}"),
+    (0xABBA, "\u{13ea}"),
+    (0xABBB, "\u{13eb}"),
+    (0xABBC, "\u{13ec}"),
+    (0xABBD, "\u{13ed}"),
+    (0xABBE, "\u{13ee}"),
+    (0xABBF, "\u{13ef}"),
+    (0xFB00, "\u{66}\u{66}"),
+    (0xFB01, "\u{66}\u{69}"),
+    (0xFB02, "\u{66}\u{6c}"),
+    (0xFB03, "\u{66}\u{66}\u{69}"),
+    (0xFB04, "\u{66}\u{66}\u{6c}"),
+    (0xFB05, "\u{73}\u{74}"),
+    (0xFB06, "\u{73}\u{74}"),
+    (0xFB13, "\u{574}\u{576}"),
+    (0xFB14, "\u{574}\u{565}"),
+    (0xFB15, "\u{574}\u{56b}"),
+    (0xFB16, "\u{57e}\u{576}"),
+    (0xFB17, "\u{574}\u{56d}"),
+    (0xFF21, "\u{ff41}"),
+    (0xFF22, "\u{ff42}"),
+    (0xFF23, "\u{ff43}"),
+    (0xFF24, "\u{ff44}"),
+    (0xFF25, "\u{ff45}"),
+    (0xFF26, "\u{ff46}"),
+    (0xFF27, "\u{ff47}"),
+    (0xFF28, "\u{ff48}"),
+    (0xFF29, "\u{ff49}"),
+    (0xFF2A, "\u{ff4a}"),
+    (0xFF2B, "\u{ff4b}"),
+    (0xFF2C, "\u{ff4c}"),
+    (0xFF2D, "\u{ff4d}"),
+    (0xFF2E, "\u{ff4e}"),
+    (0xFF2F, "\u{ff4f}"),
+    (0xFF30, "\u{ff50}"),
+    (0xFF31, "\u{ff51}"),
+    (0xFF32, "\u{ff52}"),
+    (0xFF33, "\u{ff53}"),
+    (0xFF34, "\u{ff54}"),
+    (0xFF35, "\u{ff55}"),
+    (0xFF36, "\u{ff56}"),
+    (0xFF37, "\u{ff57}"),
+    (0xFF38, "\u{ff58}"),
+    (0xFF39, "\u{ff59}"),
+    (0xFF3A, "\u{ff5a}"),
+    (0x10400, "\u{10428}"),
+    (0x10401, "\u{10429}"),
+    (0x10402, "\u{1042a}"),
+    (0x10403, "\u{1042b}"),
+    (0x10404, "\u{1042c}"),
+    (0x10405, "\u{1042d}"),
+    (0x10406, "\u{1042e}"),
+    (0x10407, "\u{1042f}"),
+    (0x10408, "\u{10430}"),
+    (0x10409, "\u{10431}"),
+    (0x1040A, "\u{10432}"),
+    (0x1040B, "\u{10433}"),
+    (0x1040C, "\u{10434}"),
+    (0x1040D, "\u{10435}"),
+    (0x1040E, "\u{10436}"),
+    (0x1040F, "\u{10437}"),
+    (0x10410, "\u{10438}"),
+    (0x10411, "\u{10439}"),
+    (0x10412, "\u{1043a}"),
+    (0x10413, "\u{1043b}"),
+    (0x10414, "\u{1043c}"),
+    (0x10415, "\u{1043d}"),
+    (0x10416, "\u{1043e}"),
+    (0x10417, "\u{1043f}"),
+    (0x10418, "\u{10440}"),
+    (0x10419, "\u{10441}"),
+    (0x1041A, "\u{10442}"),
+    (0x1041B, "\u{10443}"),
+    (0x1041C, "\u{10444}"),
+    (0x1041D, "\u{10445}"),
+    (0x1041E, "\u{10446}"),
+    (0x1041F, "\u{10447}"),
+    (0x10420, "\u{10448}"),
+    (0x10421, "\u{10449}"),
+    (0x10422, "\u{1044a}"),
+    (0x10423, "\u{1044b}"),
+    (0x10424, "\u{1044c}"),
+    (0x10425, "\u{1044d}"),
+    (0x10426, "\u{1044e}"),
+    (0x10427, "\u{1044f}"),
+    (0x104B0, "\u{104d8}"),
+    (0x104B1, "\u{104d9}"),
+    (0x104B2, "\u{104da}"),
+    (0x104B3, "\u{104db}"),
+    (0x104B4, "\u{104dc}"),
+    (0x104B5, "\u{104dd}"),
+    (0x104B6, "\u{104de}"),
+    (0x104B7, "\u{104df}"),
+    (0x104B8, "\u{104e0}"),
+    (0x104B9, "\u{104e1}"),
+    (0x104BA, "\u{104e2}"),
+    (0x104BB, "\u{104e3}"),
+    (0x104BC, "\u{104e4}"),
+    (0x104BD, "\u{104e5}"),
+    (0x104BE, "\u{104e6}"),
+    (0x104BF, "\u{104e7}"),
+    (0x104C0, "\u{104e8}"),
+    (0x104C1, "\u{104e9}"),
+    (0x104C2, "\u{104ea}"),
+    (0x104C3, "\u{104eb}"),
+    (0x104C4, "\u{104ec}"),
+    (0x104C5, "\u{104ed}"),
+    (0x104C6, "\u{104ee}"),
+    (0x104C7, "\u{104ef}"),
+    (0x104C8, "\u{104f0}"),
+    (0x104C9, "\u{104f1}"),
+    (0x104CA, "\u{104f2}"),
+    (0x104CB, "\u{104f3}"),
+    (0x104CC, "\u{104f4}"),
+    (0x104CD, "\u{104f5}"),
+    (0x104CE, "\u{104f6}"),
+    (0x104CF, "\u{104f7}"),
+    (0x104D0, "\u{104f8}"),
+    (0x104D1, "\u{104f9}"),
+    (0x104D2, "\u{104fa}"),
+    (0x104D3, "\u{104fb}"),
+    (0x10570, "\u{10597}"),
+    (0x10571, "\u{10598}"),
+    (0x10572, "\u{10599}"),
+    (0x10573, "\u{1059a}"),
+    (0x10574, "\u{1059b}"),
+    (0x10575, "\u{1059c}"),
+    (0x10576, "\u{1059d}"),
+    (0x10577, "\u{1059e}"),
+    (0x10578, "\u{1059f}"),
+    (0x10579, "\u{105a0}"),
+    (0x1057A, "\u{105a1}"),
+    (0x1057C, "\u{105a3}"),
+    (0x1057D, "\u{105a4}"),
+    (0x1057E, "\u{105a5}"),
+    (0x1057F, "\u{105a6}"),
+    (0x10580, "\u{105a7}"),
+    (0x10581, "\u{105a8}"),
+    (0x10582, "\u{105a9}"),
+    (0x10583, "\u{105aa}"),
+    (0x10584, "\u{105ab}"),
+    (0x10585, "\u{105ac}"),
+    (0x10586, "\u{105ad}"),
+    (0x10587, "\u{105ae}"),
+    (0x10588, "\u{105af}"),
+    (0x10589, "\u{105b0}"),
+    (0x1058A, "\u{105b1}"),
+    (0x1058C, "\u{105b3}"),
+    (0x1058D, "\u{105b4}"),
+    (0x1058E, "\u{105b5}"),
+    (0x1058F, "\u{105b6}"),
+    (0x10590, "\u{105b7}"),
+    (0x10591, "\u{105b8}"),
+    (0x10592, "\u{105b9}"),
+    (0x10594, "\u{105bb}"),
+    (0x10595, "\u{105bc}"),
+    (0x10C80, "\u{10cc0}"),
+    (0x10C81, "\u{10cc1}"),
+    (0x10C82, "\u{10cc2}"),
+    (0x10C83, "\u{10cc3}"),
+    (0x10C84, "\u{10cc4}"),
+    (0x10C85, "\u{10cc5}"),
+    (0x10C86, "\u{10cc6}"),
+    (0x10C87, "\u{10cc7}"),
+    (0x10C88, "\u{10cc8}"),
+    (0x10C89, "\u{10cc9}"),
+    (0x10C8A, "\u{10cca}"),
+    (0x10C8B, "\u{10ccb}"),
+    (0x10C8C, "\u{10ccc}"),
+    (0x10C8D, "\u{10ccd}"),
+    (0x10C8E, "\u{10cce}"),
+    (0x10C8F, "\u{10ccf}"),
+    (0x10C90, "\u{10cd0}"),
+    (0x10C91, "\u{10cd1}"),
+    (0x10C92, "\u{10cd2}"),
+    (0x10C93, "\u{10cd3}"),
+    (0x10C94, "\u{10cd4}"),
+    (0x10C95, "\u{10cd5}"),
+    (0x10C96, "\u{10cd6}"),
+    (0x10C97, "\u{10cd7}"),
+    (0x10C98, "\u{10cd8}"),
+    (0x10C99, "\u{10cd9}"),
+    (0x10C9A, "\u{10cda}"),
+    (0x10C9B, "\u{10cdb}"),
+    (0x10C9C, "\u{10cdc}"),
+    (0x10C9D, "\u{10cdd}"),
+    (0x10C9E, "\u{10cde}"),
+    (0x10C9F, "\u{10cdf}"),
+    (0x10CA0, "\u{10ce0}"),
+    (0x10CA1, "\u{10ce1}"),
+    (0x10CA2, "\u{10ce2}"),
+    (0x10CA3, "\u{10ce3}"),
+    (0x10CA4, "\u{10ce4}"),
+    (0x10CA5, "\u{10ce5}"),
+    (0x10CA6, "\u{10ce6}"),
+    (0x10CA7, "\u{10ce7}"),
+    (0x10CA8, "\u{10ce8}"),
+    (0x10CA9, "\u{10ce9}"),
+    (0x10CAA, "\u{10cea}"),
+    (0x10CAB, "\u{10ceb}"),
+    (0x10CAC, "\u{10cec}"),
+    (0x10CAD, "\u{10ced}"),
+    (0x10CAE, "\u{10cee}"),
+    (0x10CAF, "\u{10cef}"),
+    (0x10CB0, "\u{10cf0}"),
+    (0x10CB1, "\u{10cf1}"),
+    (0x10CB2, "\u{10cf2}"),
+    (0x118A0, "\u{118c0}"),
+    (0x118A1, "\u{118c1}"),
+    (0x118A2, "\u{118c2}"),
+    (0x118A3, "\u{118c3}"),
+    (0x118A4, "\u{118c4}"),
+    (0x118A5, "\u{118c5}"),
+    (0x118A6, "\u{118c6}"),
+    (0x118A7, "\u{118c7}"),
+    (0x118A8, "\u{118c8}"),
+    (0x118A9, "\u{118c9}"),
+    (0x118AA, "\u{118ca}"),
+    (0x118AB, "\u{118cb}"),
+    (0x118AC, "\u{118cc}"),
+    (0x118AD, "\u{118cd}"),
+    (0x118AE, "\u{118ce}"),
+    (0x118AF, "\u{118cf}"),
+    (0x118B0, "\u{118d0}"),
+    (0x118B1, "\u{118d1}"),
+    (0x118B2, "\u{118d2}"),
+    (0x118B3, "\u{118d3}"),
+    (0x118B4, "\u{118d4}"),
+    (0x118B5, "\u{118d5}"),
+    (0x118B6, "\u{118d6}"),
+    (0x118B7, "\u{118d7}"),
+    (0x118B8, "\u{118d8}"),
+    (0x118B9, "\u{118d9}"),
+    (0x118BA, "\u{118da}"),
+    (0x118BB, "\u{118db}"),
+    (0x118BC, "\u{118dc}"),
+    (0x118BD, "\u{118dd}"),
+    (0x118BE, "\u{118de}"),
+    (0x118BF, "\u{118df}"),
+    (0x16E40, "\u{16e60}"),
+    (0x16E41, "\u{16e61}"),
+    (0x16E42, "\u{16e62}"),
+    (0x16E43, "\u{16e63}"),
+    (0x16E44, "\u{16e64}"),
+    (0x16E45, "\u{16e65}"),
+    (0x16E46, "\u{16e66}"),
+    (0x16E47, "\u{16e67}"),
+    (0x16E48, "\u{16e68}"),
+    (0x16E49, "\u{16e69}"),
+    (0x16E4A, "\u{16e6a}"),
+    (0x16E4B, "\u{16e6b}"),
+    (0x16E4C, "\u{16e6c}"),
+    (0x16E4D, "\u{16e6d}"),
+    (0x16E4E, "\u{16e6e}"),
+    (0x16E4F, "\u{16e6f}"),
+    (0x16E50, "\u{16e70}"),
+    (0x16E51, "\u{16e71}"),
+    (0x16E52, "\u{16e72}"),
+    (0x16E53, "\u{16e73}"),
+    (0x16E54, "\u{16e74}"),
+    (0x16E55, "\u{16e75}"),
+    (0x16E56, "\u{16e76}"),
+    (0x16E57, "\u{16e77}"),
+    (0x16E58, "\u{16e78}"),
+    (0x16E59, "\u{16e79}"),
+    (0x16E5A, "\u{16e7a}"),
+    (0x16E5B, "\u{16e7b}"),
+    (0x16E5C, "\u{16e7c}"),
+    (0x16E5D, "\u{16e7d}"),
+    (0x16E5E, "\u{16e7e}"),
+    (0x16E5F, "\u{16e7f}"),
+    (0x1E900, "\u{1e922}"),
+    (0x1E901, "\u{1e923}"),
+    (0x1E902, "\u{1e924}"),
+    (0x1E903, "\u{1e925}"),
+    (0x1E904, "\u{1e926}"),
+    (0x1E905, "\u{1e927}"),
+    (0x1E906, "\u{1e928}"),
+    (0x1E907, "\u{1e929}"),
+    (0x1E908, "\u{1e92a}"),
+    (0x1E909, "\u{1e92b}"),
+    (0x1E90A, "\u{1e92c}"),
+    (0x1E90B, "\u{1e92d}"),
+    (0x1E90C, "\u{1e92e}"),
+    (0x1E90D, "\u{1e92f}"),
+    (0x1E90E, "\u{1e930}"),
+    (0x1E90F, "\u{1e931}"),
+    (0x1E910, "\u{1e932}"),
+    (0x1E911, "\u{1e933}"),
+    (0x1E912, "\u{1e934}"),
+    (0x1E913, "\u{1e935}"),
+    (0x1E914, "\u{1e936}"),
+    (0x1E915, "\u{1e937}"),
+    (0x1E916, "\u{1e938}"),
+    (0x1E917, "\u{1e939}"),
+    (0x1E918, "\u{1e93a}"),
+    (0x1E919, "\u{1e93b}"),
+    (0x1E91A, "\u{1e93c}"),
+    (0x1E91B, "\u{1e93d}"),
+    (0x1E91C, "\u{1e93e}"),
+    (0x1E91D, "\u{1e93f}"),
+    (0x1E91E, "\u{1e940}"),
+    (0x1E91F, "\u{1e941}"),
+    (0x1E920, "\u{1e942}"),
+    (0x1E921, "\u{1e943}"),
+];
+
+pub const CHAR_WIDTH: &[(u32, u32, u8)] = &[
+    (0x0000, 0x001F, 0),
+    (0x007F, 0x009F, 0),
+    (0x0300, 0x036F, 0),
+    (0x0378, 0x0379, 2),
+    (0x0380, 0x0383, 2),
+    (0x038B, 0x038B, 2),
+    (0x038D, 0x038D, 2),
+    (0x03A2, 0x03A2, 2),
+    (0x0483, 0x0489, 0),
+    (0x0530, 0x0530, 2),
+    (0x0557, 0x0558, 2),
+    (0x058B, 0x058C, 2),
+    (0x0590, 0x0590, 2),
+    (0x0591, 0x05BD, 0),
+    (0x05BF, 0x05BF, 0),
+    (0x05C1, 0x05C2, 0),
+    (0x05C4, 0x05C5, 0),
+    (0x05C7, 0x05C7, 0),
+    (0x05C8, 0x05CF, 2),
+    (0x05EB, 0x05EE, 2),
+    (0x05F5, 0x05FF, 2),
+    (0x0600, 0x0605, 0),
+    (0x0610, 0x061A, 0),
+    (0x061C, 0x061C, 0),
+    (0x064B, 0x065F, 0),
+    (0x0670, 0x0670, 0),
+    (0x06D6, 0x06DD, 0),
+    (0x06DF, 0x06E4, 0),
+    (0x06E7, 0x06E8, 0),
+    (0x06EA, 0x06ED, 0),
+    (0x070E, 0x070E, 2),
+    (0x070F, 0x070F, 0),
+    (0x0711, 0x0711, 0),
+    (0x0730, 0x074A, 0),
+    (0x074B, 0x074C, 2),
+    (0x07A6, 0x07B0, 0),
+    (0x07B2, 0x07BF, 2),
+    (0x07EB, 0x07F3, 0),
+    (0x07FB, 0x07FC, 2),
+    (0x07FD, 0x07FD, 0),
+    (0x0816, 0x0819, 0),
+    (0x081B, 0x0823, 0),
+    (0x0825, 0x0827, 0),
+    (0x0829, 0x082D, 0),
+    (0x082E, 0x082F, 2),
+    (0x083F, 0x083F, 2),
+    (0x0859, 0x085B, 0),
+    (0x085C, 0x085D, 2),
+    (0x085F, 0x085F, 2),
+    (0x086B, 0x086F, 2),
+    (0x088F, 0x088F, 2),
+    (0x0890, 0x0891, 0),
+    (0x0892, 0x0897, 2),
+    (0x0898, 0x089F, 0),
+    (0x08CA, 0x0902, 0),
+    (0x093A, 0x093A, 0),
+    (0x093C, 0x093C, 0),
+    (0x0941, 0x0948, 0),
+    (0x094D, 0x094D, 0),
+    (0x0951, 0x0957, 0),
+    (0x0962, 0x0963, 0),
+    (0x0981, 0x0981, 0),
+    (0x0984, 0x0984, 2),
+    (0x098D, 0x098E, 2),
+    (0x0991, 0x0992, 2),
+    (0x09A9, 0x09A9, 2),
+    (0x09B1, 0x09B1, 2),
+    (0x09B3, 0x09B5, 2),
+    (0x09BA, 0x09BB, 2),
+    (0x09BC, 0x09BC, 0),
+    (0x09C1, 0x09C4, 0),
+    (0x09C5, 0x09C6, 2),
+    (0x09C9, 0x09CA, 2),
+    (0x09CD, 0x09CD, 0),
+    (0x09CF, 0x09D6, 2),
+    (0x09D8, 0x09DB, 2),
+    (0x09DE, 0x09DE, 2),
+    (0x09E2, 0x09E3, 0),
+    (0x09E4, 0x09E5, 2),
+    (0x09FE, 0x09FE, 0),
+    (0x09FF, 0x0A00, 2),
+    (0x0A01, 0x0A02, 0),
+    (0x0A04, 0x0A04, 2),
+    (0x0A0B, 0x0A0E, 2),
+    (0x0A11, 0x0A12, 2),
+    (0x0A29, 0x0A29, 2),
+    (0x0A31, 0x0A31, 2),
+    (0x0A34, 0x0A34, 2),
+    (0x0A37, 0x0A37, 2),
+    (0x0A3A, 0x0A3B, 2),
+    (0x0A3C, 0x0A3C, 0),
+    (0x0A3D, 0x0A3D, 2),
+    (0x0A41, 0x0A42, 0),
+    (0x0A43, 0x0A46, 2),
+    (0x0A47, 0x0A48, 0),
+    (0x0A49, 0x0A4A, 2),
+    (0x0A4B, 0x0A4D, 0),
+    (0x0A4E, 0x0A50, 2),
+    (0x0A51, 0x0A51, 0),
+    (0x0A52, 0x0A58, 2),
+    (0x0A5D, 0x0A5D, 2),
+    (0x0A5F, 0x0A65, 2),
+    (0x0A70, 0x0A71, 0),
+    (0x0A75, 0x0A75, 0),
+    (0x0A77, 0x0A80, 2),
+    (0x0A81, 0x0A82, 0),
+    (0x0A84, 0x0A84, 2),
+    (0x0A8E, 0x0A8E, 2),
+    (0x0A92, 0x0A92, 2),
+    (0x0AA9, 0x0AA9, 2),
+    (0x0AB1, 0x0AB1, 2),
+    (0x0AB4, 0x0AB4, 2),
+    (0x0ABA, 0x0ABB, 2),
+    (0x0ABC, 0x0ABC, 0),
+    (0x0AC1, 0x0AC5, 0),
+    (0x0AC6, 0x0AC6, 2),
+    (0x0AC7, 0x0AC8, 0),
+    (0x0ACA, 0x0ACA, 2),
+    (0x0ACD, 0x0ACD, 0),
+    (0x0ACE, 0x0ACF, 2),
+    (0x0AD1, 0x0ADF, 2),
+    (0x0AE2, 0x0AE3, 0),
+    (0x0AE4, 0x0AE5, 2),
+    (0x0AF2, 0x0AF8, 2),
+    (0x0AFA, 0x0AFF, 0),
+    (0x0B00, 0x0B00, 2),
+    (0x0B01, 0x0B01, 0),
+    (0x0B04, 0x0B04, 2),
+    (0x0B0D, 0x0B0E, 2),
+    (0x0B11, 0x0B12, 2),
+    (0x0B29, 0x0B29, 2),
+    (0x0B31, 0x0B31, 2),
+    (0x0B34, 0x0B34, 2),
+    (0x0B3A, 0x0B3B, 2),
+    (0x0B3C, 0x0B3C, 0),
+    (0x0B3F, 0x0B3F, 0),
+    (0x0B41, 0x0B44, 0),
+    (0x0B45, 0x0B46, 2),
+    (0x0B49, 0x0B4A, 2),
+    (0x0B4D, 0x0B4D, 0),
+    (0x0B4E, 0x0B54, 2),
+    (0x0B55, 0x0B56, 0),
+    (0x0B58, 0x0B5B, 2),
+    (0x0B5E, 0x0B5E, 2),
+    (0x0B62, 0x0B63, 0),
+    (0x0B64, 0x0B65, 2),
+    (0x0B78, 0x0B81, 2),
+    (0x0B82, 0x0B82, 0),
+    (0x0B84, 0x0B84, 2),
+    (0x0B8B, 0x0B8D, 2),
+    (0x0B91, 0x0B91, 2),
+    (0x0B96, 0x0B98, 2),
+    (0x0B9B, 0x0B9B, 2),
+    (0x0B9D, 0x0B9D, 2),
+    (0x0BA0, 0x0BA2, 2),
+    (0x0BA5, 0x0BA7, 2),
+    (0x0BAB, 0x0BAD, 2),
+    (0x0BBA, 0x0BBD, 2),
+    (0x0BC0, 0x0BC0, 0),
+    (0x0BC3, 0x0BC5, 2),
+    (0x0BC9, 0x0BC9, 2),
+    (0x0BCD, 0x0BCD, 0),
+    (0x0BCE, 0x0BCF, 2),
+    (0x0BD1, 0x0BD6, 2),
+    (0x0BD8, 0x0BE5, 2),
+    (0x0BFB, 0x0BFF, 2),
+    (0x0C00, 0x0C00, 0),
+    (0x0C04, 0x0C04, 0),
+    (0x0C0D, 0x0C0D, 2),
+    (0x0C11, 0x0C11, 2),
+    (0x0C29, 0x0C29, 2),
+    (0x0C3A, 0x0C3B, 2),
+    (0x0C3C, 0x0C3C, 0),
+    (0x0C3E, 0x0C40, 0),
+    (0x0C45, 0x0C45, 2),
+    (0x0C46, 0x0C48, 0),
+    (0x0C49, 0x0C49, 2),
+    (0x0C4A, 0x0C4D, 0),
+    (0x0C4E, 0x0C54, 2),
+    (0x0C55, 0x0C56, 0),
+    (0x0C57, 0x0C57, 2),
+    (0x0C5B, 0x0C5C, 2),
+    (0x0C5E, 0x0C5F, 2),
+    (0x0C62, 0x0C63, 0),
+    (0x0C64, 0x0C65, 2),
+    (0x0C70, 0x0C76, 2),
+    (0x0C81, 0x0C81, 0),
+    (0x0C8D, 0x0C8D, 2),
+    (0x0C91, 0x0C91, 2),
+    (0x0CA9, 0x0CA9, 2),
+    (0x0CB4, 0x0CB4, 2),
+    (0x0CBA, 0x0CBB, 2),
+    (0x0CBC, 0x0CBC, 0),
+    (0x0CBF, 0x0CBF, 0),
+    (0x0CC5, 0x0CC5, 2),
+    (0x0CC6, 0x0CC6, 0),
+    (0x0CC9, 0x0CC9, 2),
+    (0x0CCC, 0x0CCD, 0),
+    (0x0CCE, 0x0CD4, 2),
+    (0x0CD7, 0x0CDC, 2),
+    (0x0CDF, 0x0CDF, 2),
+    (0x0CE2, 0x0CE3, 0),
+    (0x0CE4, 0x0CE5, 2),
+    (0x0CF0, 0x0CF0, 2),
+    (0x0CF3, 0x0CFF, 2),
+    (0x0D00, 0x0D01, 0),
+    (0x0D0D, 0x0D0D, 2),
+    (0x0D11, 0x0D11, 2),
+    (0x0D3B, 0x0D3C, 0),
+    (0x0D41, 0x0D44, 0),
+    (0x0D45, 0x0D45, 2),
+    (0x0D49, 0x0D49, 2),
+    (0x0D4D, 0x0D4D, 0),
+    (0x0D50, 0x0D53, 2),
+    (0x0D62, 0x0D63, 0),
+    (0x0D64, 0x0D65, 2),
+    (0x0D80, 0x0D80, 2),
+    (0x0D81, 0x0D81, 0),
+    (0x0D84, 0x0D84, 2),
+    (0x0D97, 0x0D99, 2),
+    (0x0DB2, 0x0DB2, 2),
+    (0x0DBC, 0x0DBC, 2),
+    (0x0DBE, 0x0DBF, 2),
+    (0x0DC7, 0x0DC9, 2),
+    (0x0DCA, 0x0DCA, 0),
+    (0x0DCB, 0x0DCE, 2),
+    (0x0DD2, 0x0DD4, 0),
+    (0x0DD5, 0x0DD5, 2),
+    (0x0DD6, 0x0DD6, 0),
+    (0x0DD7, 0x0DD7, 2),
+    (0x0DE0, 0x0DE5, 2),
+    (0x0DF0, 0x0DF1, 2),
+    (0x0DF5, 0x0E00, 2),
+    (0x0E31, 0x0E31, 0),
+    (0x0E34, 0x0E3A, 0),
+    (0x0E3B, 0x0E3E, 2),
+    (0x0E47, 0x0E4E, 0),
+    (0x0E5C, 0x0E80, 2),
+    (0x0E83, 0x0E83, 2),
+    (0x0E85, 0x0E85, 2),
+    (0x0E8B, 0x0E8B, 2),
+    (0x0EA4, 0x0EA4, 2),
+    (0x0EA6, 0x0EA6, 2),
+    (0x0EB1, 0x0EB1, 0),
+    (0x0EB4, 0x0EBC, 0),
+    (0x0EBE, 0x0EBF, 2),
+    (0x0EC5, 0x0EC5, 2),
+    (0x0EC7, 0x0EC7, 2),
+    (0x0EC8, 0x0ECD, 0),
+    (0x0ECE, 0x0ECF, 2),
+    (0x0EDA, 0x0EDB, 2),
+    (0x0EE0, 0x0EFF, 2),
+    (0x0F18, 0x0F19, 0),
+    (0x0F35, 0x0F35, 0),
+    (0x0F37, 0x0F37, 0),
+    (0x0F39, 0x0F39, 0),
+    (0x0F48, 0x0F48, 2),
+    (0x0F6D, 0x0F70, 2),
+    (0x0F71, 0x0F7E, 0),
+    (0x0F80, 0x0F84, 0),
+    (0x0F86, 0x0F87, 0),
+    (0x0F8D, 0x0F97, 0),
+    (0x0F98, 0x0F98, 2),
+    (0x0F99, 0x0FBC, 0),
+    (0x0FBD, 0x0FBD, 2),
+    (0x0FC6, 0x0FC6, 0),
+    (0x0FCD, 0x0FCD, 2),
+    (0x0FDB, 0x0FFF, 2),
+    (0x102D, 0x1030, 0),
+    (0x1032, 0x1037, 0),
+    (0x1039, 0x103A, 0),
+    (0x103D, 0x103E, 0),
+    (0x1058, 0x1059, 0),
+    (0x105E, 0x1060, 0),
+    (0x1071, 0x1074, 0),
+    (0x1082, 0x1082, 0),
+    (0x1085, 0x1086, 0),
+    (0x108D, 0x108D, 0),
+    (0x109D, 0x109D, 0),
+    (0x10C6, 0x10C6, 2),
+    (0x10C8, 0x10CC, 2),
+    (0x10CE, 0x10CF, 2),
+    (0x1100, 0x115F, 2),
+    (0x1160, 0x11FF, 0),
+    (0x1249, 0x1249, 2),
+    (0x124E, 0x124F, 2),
+    (0x1257, 0x1257, 2),
+    (0x1259, 0x1259, 2),
+    (0x125E, 0x125F, 2),
+    (0x1289, 0x1289, 2),
+    (0x128E, 0x128F, 2),
+    (0x12B1, 0x12B1, 2),
+    (0x12B6, 0x12B7, 2),
+    (0x12BF, 0x12BF, 2),
+    (0x12C1, 0x12C1, 2),
+    (0x12C6, 0x12C7, 2),
+    (0x12D7, 0x12D7, 2),
+    (0x1311, 0x1311, 2),
+    (0x1316, 0x1317, 2),
+    (0x135B, 0x135C, 2),
+    (0x135D, 0x135F, 0),
+    (0x137D, 0x137F, 2),
+    (0x139A, 0x139F, 2),
+    (0x13F6, 0x13F7, 2),
+    (0x13FE, 0x13FF, 2),
+    (0x169D, 0x169F, 2),
+    (0x16F9, 0x16FF, 2),
+    (0x1712, 0x1714, 0),
+    (0x1716, 0x171E, 2),
+    (0x1732, 0x1733, 0),
+    (0x1737, 0x173F, 2),
+    (0x1752, 0x1753, 0),
+    (0x1754, 0x175F, 2),
+    (0x176D, 0x176D, 2),
+    (0x1771, 0x1771, 2),
+    (0x1772, 0x1773, 0),
+    (0x1774, 0x177F, 2),
+    (0x17B4, 0x17B5, 0),
+    (0x17B7, 0x17BD, 0),
+    (0x17C6, 0x17C6, 0),
+    (0x17C9, 0x17D3, 0),
+    (0x17DD, 0x17DD, 0),
+    (0x17DE, 0x17DF, 2),
+    (0x17EA, 0x17EF, 2),
+    (0x17FA, 0x17FF, 2),
+    (0x180B, 0x180F, 0),
+    (0x181A, 0x181F, 2),
+    (0x1879, 0x187F, 2),
+    (0x1885, 0x1886, 0),
+    (0x18A9, 0x18A9, 0),
+    (0x18AB, 0x18AF, 2),
+    (0x18F6, 0x18FF, 2),
+    (0x191F, 0x191F, 2),
+    (0x1920, 0x1922, 0),
+    (0x1927, 0x1928, 0),
+    (0x192C, 0x192F, 2),
+    (0x1932, 0x1932, 0),
+    (0x1939, 0x193B, 0),
+    (0x193C, 0x193F, 2),
+    (0x1941, 0x1943, 2),
+    (0x196E, 0x196F, 2),
+    (0x1975, 0x197F, 2),
+    (0x19AC, 0x19AF, 2),
+    (0x19CA, 0x19CF, 2),
+    (0x19DB, 0x19DD, 2),
+    (0x1A17, 0x1A18, 0),
+    (0x1A1B, 0x1A1B, 0),
+    (0x1A1C, 0x1A1D, 2),
+    (0x1A56, 0x1A56, 0),
+    (0x1A58, 0x1A5E, 0),
+    (0x1A5F, 0x1A5F, 2),
+    (0x1A60, 0x1A60, 0),
+    (0x1A62, 0x1A62, 0),
+    (0x1A65, 0x1A6C, 0),
+    (0x1A73, 0x1A7C, 0),
+    (0x1A7D, 0x1A7E, 2),
+    (0x1A7F, 0x1A7F, 0),
+    (0x1A8A, 0x1A8F, 2),
+    (0x1A9A, 0x1A9F, 2),
+    (0x1AAE, 0x1AAF, 2),
+    (0x1AB0, 0x1ACE, 0),
+    (0x1ACF, 0x1AFF, 2),
+    (0x1B00, 0x1B03, 0),
+    (0x1B34, 0x1B34, 0),
+    (0x1B36, 0x1B3A, 0),
+    (0x1B3C, 0x1B3C, 0),
+    (0x1B42, 0x1B42, 0),
+    (0x1B4D, 0x1B4F, 2),
+    (0x1B6B, 0x1B73, 0),
+    (0x1B7F, 0x1B7F, 2),
+    (0x1B80, 0x1B81, 0),
+    (0x1BA2, 0x1BA5, 0),
+    (0x1BA8, 0x1BA9, 0),
+    (0x1BAB, 0x1BAD, 0),
+    (0x1BE6, 0x1BE6, 0),
+    (0x1BE8, 0x1BE9, 0),
+    (0x1BED, 0x1BED, 0),
+    (0x1BEF, 0x1BF1, 0),
+    (0x1BF4, 0x1BFB, 2),
+    (0x1C2C, 0x1C33, 0),
+    (0x1C36, 0x1C37, 0),
+    (0x1C38, 0x1C3A, 2),
+    (0x1C4A, 0x1C4C, 2),
+    (0x1C89, 0x1C8F, 2),
+    (0x1CBB, 0x1CBC, 2),
+    (0x1CC8, 0x1CCF, 2),
+    (0x1CD0, 0x1CD2, 0),
+    (0x1CD4, 0x1CE0, 0),
+    (0x1CE2, 0x1CE8, 0),
+    (0x1CED, 0x1CED, 0),
+    (0x1CF4, 0x1CF4, 0),
+    (0x1CF8, 0x1CF9, 0),
+    (0x1CFB, 0x1CFF, 2),
+    (0x1DC0, 0x1DFF, 0),
+    (0x1F16, 0x1F17, 2),
+    (0x1F1E, 0x1F1F, 2),
+    (0x1F46, 0x1F47, 2),
+    (0x1F4E, 0x1F4F, 2),
+    (0x1F58, 0x1F58, 2),
+    (0x1F5A, 0x1F5A, 2),
+    (0x1F5C, 0x1F5C, 2),
+    (0x1F5E, 0x1F5E, 2),
+    (0x1F7E, 0x1F7F, 2),
+    (0x1FB5, 0x1FB5, 2),
+    (0x1FC5, 0x1FC5, 2),
+    (0x1FD4, 0x1FD5, 2),
+    (0x1FDC, 0x1FDC, 2),
+    (0x1FF0, 0x1FF1, 2),
+    (0x1FF5, 0x1FF5, 2),
+    (0x1FFF, 0x1FFF, 2),
+    (0x200B, 0x200F, 0),
+    (0x2028, 0x202E, 0),
+    (0x2060, 0x2064, 0),
+    (0x2065, 0x2065, 2),
+    (0x2066, 0x206F, 0),
+    (0x2072, 0x2073, 2),
+    (0x208F, 0x208F, 2),
+    (0x209D, 0x209F, 2),
+    (0x20C1, 0x20CF, 2),
+    (0x20D0, 0x20F0, 0),
+    (0x20F1, 0x20FF, 2),
+    (0x218C, 0x218F, 2),
+    (0x231A, 0x231B, 2),
+    (0x2329, 0x232A, 2),
+    (0x23E9, 0x23EC, 2),
+    (0x23F0, 0x23F0, 2),
+    (0x23F3, 0x23F3, 2),
+    (0x2427, 0x243F, 2),
+    (0x244B, 0x245F, 2),
+    (0x25FD, 0x25FE, 2),
+    (0x2614, 0x2615, 2),
+    (0x2648, 0x2653, 2),
+    (0x267F, 0x267F, 2),
+    (0x2693, 0x2693, 2),
+    (0x26A1, 0x26A1, 2),
+    (0x26AA, 0x26AB, 2),
+    (0x26BD, 0x26BE, 2),
+    (0x26C4, 0x26C5, 2),
+    (0x26CE, 0x26CE, 2),
+    (0x26D4, 0x26D4, 2),
+    (0x26EA, 0x26EA, 2),
+    (0x26F2, 0x26F3, 2),
+    (0x26F5, 0x26F5, 2),
+    (0x26FA, 0x26FA, 2),
+    (0x26FD, 0x26FD, 2),
+    (0x2705, 0x2705, 2),
+    (0x270A, 0x270B, 2),
+    (0x2728, 0x2728, 2),
+    (0x274C, 0x274C, 2),
+    (0x274E, 0x274E, 2),
+    (0x2753, 0x2755, 2),
+    (0x2757, 0x2757, 2),
+    (0x2795, 0x2797, 2),
+    (0x27B0, 0x27B0, 2),
+    (0x27BF, 0x27BF, 2),
+    (0x2B1B, 0x2B1C, 2),
+    (0x2B50, 0x2B50, 2),
+    (0x2B55, 0x2B55, 2),
+    (0x2B74, 0x2B75, 2),
+    (0x2B96, 0x2B96, 2),
+    (0x2CEF, 0x2CF1, 0),
+    (0x2CF4, 0x2CF8, 2),
+    (0x2D26, 0x2D26, 2),
+    (0x2D28, 0x2D2C, 2),
+    (0x2D2E, 0x2D2F, 2),
+    (0x2D68, 0x2D6E, 2),
+    (0x2D71, 0x2D7E, 2),
+    (0x2D7F, 0x2D7F, 0),
+    (0x2D97, 0x2D9F, 2),
+    (0x2DA7, 0x2DA7, 2),
+    (0x2DAF, 0x2DAF, 2),
+    (0x2DB7, 0x2DB7, 2),
+    (0x2DBF, 0x2DBF, 2),
+    (0x2DC7, 0x2DC7, 2),
+    (0x2DCF, 0x2DCF, 2),
+    (0x2DD7, 0x2DD7, 2),
+    (0x2DDF, 0x2DDF, 2),
+    (0x2DE0, 0x2DFF, 0),
+    (0x2E5E, 0x3029, 2),
+    (0x302A, 0x302D, 0),
+    (0x302E, 0x303E, 2),
+    (0x3040, 0x3098, 2),
+    (0x3099, 0x309A, 0),
+    (0x309B, 0x3247, 2),
+    (0x3250, 0x4DBF, 2),
+    (0x4E00, 0xA4CF, 2),
+    (0xA62C, 0xA63F, 2),
+    (0xA66F, 0xA672, 0),
+    (0xA674, 0xA67D, 0),
+    (0xA69E, 0xA69F, 0),
+    (0xA6F0, 0xA6F1, 0),
+    (0xA6F8, 0xA6FF, 2),
+    (0xA7CB, 0xA7CF, 2),
+    (0xA7D2, 0xA7D2, 2),
+    (0xA7D4, 0xA7D4, 2),
+    (0xA7DA, 0xA7F1, 2),
+    (0xA802, 0xA802, 0),
+    (0xA806, 0xA806, 0),
+    (0xA80B, 0xA80B, 0),
+    (0xA825, 0xA826, 0),
+    (0xA82C, 0xA82C, 0),
+    (0xA82D, 0xA82F, 2),
+    (0xA83A, 0xA83F, 2),
+    (0xA878, 0xA87F, 2),
+    (0xA8C4, 0xA8C5, 0),
+    (0xA8C6, 0xA8CD, 2),
+    (0xA8DA, 0xA8DF, 2),
+    (0xA8E0, 0xA8F1, 0),
+    (0xA8FF, 0xA8FF, 0),
+    (0xA926, 0xA92D, 0),
+    (0xA947, 0xA951, 0),
+    (0xA954, 0xA95E, 2),
+    (0xA960, 0xA97F, 2),
+    (0xA980, 0xA982, 0),
+    (0xA9B3, 0xA9B3, 0),
+    (0xA9B6, 0xA9B9, 0),
+    (0xA9BC, 0xA9BD, 0),
+    (0xA9CE, 0xA9CE, 2),
+    (0xA9DA, 0xA9DD, 2),
+    (0xA9E5, 0xA9E5, 0),
+    (0xA9FF, 0xA9FF, 2),
+    (0xAA29, 0xAA2E, 0),
+    (0xAA31, 0xAA32, 0),
+    (0xAA35, 0xAA36, 0),
+    (0xAA37, 0xAA3F, 2),
+    (0xAA43, 0xAA43, 0),
+    (0xAA4C, 0xAA4C, 0),
+    (0xAA4E, 0xAA4F, 2),
+    (0xAA5A, 0xAA5B, 2),
+    (0xAA7C, 0xAA7C, 0),
+    (0xAAB0, 0xAAB0, 0),
+    (0xAAB2, 0xAAB4, 0),
+    (0xAAB7, 0xAAB8, 0),
+    (0xAABE, 0xAABF, 0),
+    (0xAAC1, 0xAAC1, 0),
+    (0xAAC3, 0xAADA, 2),
+    (0xAAEC, 0xAAED, 0),
+    (0xAAF6, 0xAAF6, 0),
+    (0xAAF7, 0xAB00, 2),
+    (0xAB07, 0xAB08, 2),
+    (0xAB0F, 0xAB10, 2),
+    (0xAB17, 0xAB1F, 2),
+    (0xAB27, 0xAB27, 2),
+    (0xAB2F, 0xAB2F, 2),
+    (0xAB6C, 0xAB6F, 2),
+    (0xABE5, 0xABE5, 0),
+    (0xABE8, 0xABE8, 0),
+    (0xABED, 0xABED, 0),
+    (0xABEE, 0xABEF, 2),
+    (0xABFA, 0xD7AF, 2),
+    (0xD7C7, 0xD7CA, 2),
+    (0xD7FC, 0xD7FF, 2),
+    (0xF900, 0xFAFF, 2),
+    (0xFB07, 0xFB12, 2),
+    (0xFB18, 0xFB1C, 2),
+    (0xFB1E, 0xFB1E, 0),
+    (0xFB37, 0xFB37, 2),
+    (0xFB3D, 0xFB3D, 2),
+    (0xFB3F, 0xFB3F, 2),
+    (0xFB42, 0xFB42, 2),
+    (0xFB45, 0xFB45, 2),
+    (0xFBC3, 0xFBD2, 2),
+    (0xFD90, 0xFD91, 2),
+    (0xFDC8, 0xFDCE, 2),
+    (0xFDD0, 0xFDEF, 2),
+    (0xFE00, 0xFE0F, 0),
+    (0xFE10, 0xFE1F, 2),
+    (0xFE20, 0xFE2F, 0),
+    (0xFE30, 0xFE6F, 2),
+    (0xFE75, 0xFE75, 2),
+    (0xFEFD, 0xFEFE, 2),
+    (0xFEFF, 0xFEFF, 0),
+    (0xFF00, 0xFF60, 2),
+    (0xFFBF, 0xFFC1, 2),
+    (0xFFC8, 0xFFC9, 2),
+    (0xFFD0, 0xFFD1, 2),
+    (0xFFD8, 0xFFD9, 2),
+    (0xFFDD, 0xFFE7, 2),
+    (0xFFEF, 0xFFF8, 2),
+    (0xFFF9, 0xFFFB, 0),
+    (0xFFFE, 0xFFFF, 2),
+    (0x1000C, 0x1000C, 2),
+    (0x10027, 0x10027, 2),
+    (0x1003B, 0x1003B, 2),
+    (0x1003E, 0x1003E, 2),
+    (0x1004E, 0x1004F, 2),
+    (0x1005E, 0x1007F, 2),
+    (0x100FB, 0x100FF, 2),
+    (0x10103, 0x10106, 2),
+    (0x10134, 0x10136, 2),
+    (0x1018F, 0x1018F, 2),
+    (0x1019D, 0x1019F, 2),
+    (0x101A1, 0x101CF, 2),
+    (0x101FD, 0x101FD, 0),
+    (0x101FE, 0x1027F, 2),
+    (0x1029D, 0x1029F, 2),
+    (0x102D1, 0x102DF, 2),
+    (0x102E0, 0x102E0, 0),
+    (0x102FC, 0x102FF, 2),
+    (0x10324, 0x1032C, 2),
+    (0x1034B, 0x1034F, 2),
+    (0x10376, 0x1037A, 0),
+    (0x1037B, 0x1037F, 2),
+    (0x1039E, 0x1039E, 2),
+    (0x103C4, 0x103C7, 2),
+    (0x103D6, 0x103FF, 2),
+    (0x1049E, 0x1049F, 2),
+    (0x104AA, 0x104AF, 2),
+    (0x104D4, 0x104D7, 2),
+    (0x104FC, 0x104FF, 2),
+    (0x10528, 0x1052F, 2),
+    (0x10564, 0x1056E, 2),
+    (0x1057B, 0x1057B, 2),
+    (0x1058B, 0x1058B, 2),
+    (0x10593, 0x10593, 2),
+    (0x10596, 0x10596, 2),
+    (0x105A2, 0x105A2, 2),
+    (0x105B2, 0x105B2, 2),
+    (0x105BA, 0x105BA, 2),
+    (0x105BD, 0x105FF, 2),
+    (0x10737, 0x1073F, 2),
+    (0x10756, 0x1075F, 2),
+    (0x10768, 0x1077F, 2),
+    (0x10786, 0x10786, 2),
+    (0x107B1, 0x107B1, 2),
+    (0x107BB, 0x107FF, 2),
+    (0x10806, 0x10807, 2),
+    (0x10809, 0x10809, 2),
+    (0x10836, 0x10836, 2),
+    (0x10839, 0x1083B, 2),
+    (0x1083D, 0x1083E, 2),
+    (0x10856, 0x10856, 2),
+    (0x1089F, 0x108A6, 2),
+    (0x108B0, 0x108DF, 2),
+    (0x108F3, 0x108F3, 2),
+    (0x108F6, 0x108FA, 2),
+    (0x1091C, 0x1091E, 2),
+    (0x1093A, 0x1093E, 2),
+    (0x10940, 0x1097F, 2),
+    (0x109B8, 0x109BB, 2),
+    (0x109D0, 0x109D1, 2),
+    (0x10A01, 0x10A03, 0),
+    (0x10A04, 0x10A04, 2),
+    (0x10A05, 0x10A06, 0),
+    (0x10A07, 0x10A0B, 2),
+    (0x10A0C, 0x10A0F, 0),
+    (0x10A14, 0x10A14, 2),
+    (0x10A18, 0x10A18, 2),
+    (0x10A36, 0x10A37, 2),
+    (0x10A38, 0x10A3A, 0),
+    (0x10A3B, 0x10A3E, 2),
+    (0x10A3F, 0x10A3F, 0),
+    (0x10A49, 0x10A4F, 2),
+    (0x10A59, 0x10A5F, 2),
+    (0x10AA0, 0x10ABF, 2),
+    (0x10AE5, 0x10AE6, 0),
+    (0x10AE7, 0x10AEA, 2),
+    (0x10AF7, 0x10AFF, 2),
+    (0x10B36, 0x10B38, 2),
+    (0x10B56, 0x10B57, 2),
+    (0x10B73, 0x10B77, 2),
+    (0x10B92, 0x10B98, 2),
+    (0x10B9D, 0x10BA8, 2),
+    (0x10BB0, 0x10BFF, 2),
+    (0x10C49, 0x10C7F, 2),
+    (0x10CB3, 0x10CBF, 2),
+    (0x10CF3, 0x10CF9, 2),
+    (0x10D24, 0x10D27, 0),
+    (0x10D28, 0x10D2F, 2),
+    (0x10D3A, 0x10E5F, 2),
+    (0x10E7F, 0x10E7F, 2),
+    (0x10EAA, 0x10EAA, 2),
+    (0x10EAB, 0x10EAC, 0),
+    (0x10EAE, 0x10EAF, 2),
+    (0x10EB2, 0x10EFF, 2),
+    (0x10F28, 0x10F2F, 2),
+    (0x10F46, 0x10F50, 0),
+    (0x10F5A, 0x10F6F, 2),
+    (0x10F82, 0x10F85, 0),
+    (0x10F8A, 0x10FAF, 2),
+    (0x10FCC, 0x10FDF, 2),
+    (0x10FF7, 0x10FFF, 2),
+    (0x11001, 0x11001, 0),
+    (0x11038, 0x11046, 0),
+    (0x1104E, 0x11051, 2),
+    (0x11070, 0x11070, 0),
+    (0x11073, 0x11074, 0),
+    (0x11076, 0x1107E, 2),
+    (0x1107F, 0x11081, 0),
+    (0x110B3, 0x110B6, 0),
+    (0x110B9, 0x110BA, 0),
+    (0x110BD, 0x110BD, 0),
+    (0x110C2, 0x110C2, 0),
+    (0x110C3, 0x110CC, 2),
+    (0x110CD, 0x110CD, 0),
+    (0x110CE, 0x110CF, 2),
+    (0x110E9, 0x110EF, 2),
+    (0x110FA, 0x110FF, 2),
+    (0x11100, 0x11102, 0),
+    (0x11127, 0x1112B, 0),
+    (0x1112D, 0x11134, 0),
+    (0x11135, 0x11135, 2),
+    (0x11148, 0x1114F, 2),
+    (0x11173, 0x11173, 0),
+    (0x11177, 0x1117F, 2),
+    (0x11180, 0x11181, 0),
+    (0x111B6, 0x111BE, 0),
+    (0x111C9, 0x111CC, 0),
+    (0x111CF, 0x111CF, 0),
+    (0x111E0, 0x111E0, 2),
+    (0x111F5, 0x111FF, 2),
+    (0x11212, 0x11212, 2),
+    (0x1122F, 0x11231, 0),
+    (0x11234, 0x11234, 0),
+    (0x11236, 0x11237, 0),
+    (0x1123E, 0x1123E, 0),
+    (0x1123F, 0x1127F, 2),
+    (0x11287, 0x11287, 2),
+    (0x11289, 0x11289, 2),
+    (0x1128E, 0x1128E, 2),
+    (0x1129E, 0x1129E, 2),
+    (0x112AA, 0x112AF, 2),
+    (0x112DF, 0x112DF, 0),
+    (0x112E3, 0x112EA, 0),
+    (0x112EB, 0x112EF, 2),
+    (0x112FA, 0x112FF, 2),
+    (0x11300, 0x11301, 0),
+    (0x11304, 0x11304, 2),
+    (0x1130D, 0x1130E, 2),
+    (0x11311, 0x11312, 2),
+    (0x11329, 0x11329, 2),
+    (0x11331, 0x11331, 2),
+    (0x11334, 0x11334, 2),
+    (0x1133A, 0x1133A, 2),
+    (0x1133B, 0x1133C, 0),
+    (0x11340, 0x11340, 0),
+    (0x11345, 0x11346, 2),
+    (0x11349, 0x1134A, 2),
+    (0x1134E, 0x1134F, 2),
+    (0x11351, 0x11356, 2),
+    (0x11358, 0x1135C, 2),
+    (0x11364, 0x11365, 2),
+    (0x11366, 0x1136C, 0),
+    (0x1136D, 0x1136F, 2),
+    (0x11370, 0x11374, 0),
+    (0x11375, 0x113FF, 2),
+    (0x11438, 0x1143F, 0),
+    (0x11442, 0x11444, 0),
+    (0x11446, 0x11446, 0),
+    (0x1145C, 0x1145C, 2),
+    (0x1145E, 0x1145E, 0),
+    (0x11462, 0x1147F, 2),
+    (0x114B3, 0x114B8, 0),
+    (0x114BA, 0x114BA, 0),
+    (0x114BF, 0x114C0, 0),
+    (0x114C2, 0x114C3, 0),
+    (0x114C8, 0x114CF, 2),
+    (0x114DA, 0x1157F, 2),
+    (0x115B2, 0x115B5, 0),
+    (0x115B6, 0x115B7, 2),
+    (0x115BC, 0x115BD, 0),
+    (0x115BF, 0x115C0, 0),
+    (0x115DC, 0x115DD, 0),
+    (0x115DE, 0x115FF, 2),
+    (0x11633, 0x1163A, 0),
+    (0x1163D, 0x1163D, 0),
+    (0x1163F, 0x11640, 0),
+    (0x11645, 0x1164F, 2),
+    (0x1165A, 0x1165F, 2),
+    (0x1166D, 0x1167F, 2),
+    (0x116AB, 0x116AB, 0),
+    (0x116AD, 0x116AD, 0),
+    (0x116B0, 0x116B5, 0),
+    (0x116B7, 0x116B7, 0),
+    (0x116BA, 0x116BF, 2),
+    (0x116CA, 0x116FF, 2),
+    (0x1171B, 0x1171C, 2),
+    (0x1171D, 0x1171F, 0),
+    (0x11722, 0x11725, 0),
+    (0x11727, 0x1172B, 0),
+    (0x1172C, 0x1172F, 2),
+    (0x11747, 0x117FF, 2),
+    (0x1182F, 0x11837, 0),
+    (0x11839, 0x1183A, 0),
+    (0x1183C, 0x1189F, 2),
+    (0x118F3, 0x118FE, 2),
+    (0x11907, 0x11908, 2),
+    (0x1190A, 0x1190B, 2),
+    (0x11914, 0x11914, 2),
+    (0x11917, 0x11917, 2),
+    (0x11936, 0x11936, 2),
+    (0x11939, 0x1193A, 2),
+    (0x1193B, 0x1193C, 0),
+    (0x1193E, 0x1193E, 0),
+    (0x11943, 0x11943, 0),
+    (0x11947, 0x1194F, 2),
+    (0x1195A, 0x1199F, 2),
+    (0x119A8, 0x119A9, 2),
+    (0x119D4, 0x119D7, 0),
+    (0x119D8, 0x119D9, 2),
+    (0x119DA, 0x119DB, 0),
+    (0x119E0, 0x119E0, 0),
+    (0x119E5, 0x119FF, 2),
+    (0x11A01, 0x11A0A, 0),
+    (0x11A33, 0x11A38, 0),
+    (0x11A3B, 0x11A3E, 0),
+    (0x11A47, 0x11A47, 0),
+    (0x11A48, 0x11A4F, 2),
+    (0x11A51, 0x11A56, 0),
+    (0x11A59, 0x11A5B, 0),
+    (0x11A8A, 0x11A96, 0),
+    (0x11A98, 0x11A99, 0),
+    (0x11AA3, 0x11AAF, 2),
+    (0x11AF9, 0x11BFF, 2),
+    (0x11C09, 0x11C09, 2),
+    (0x11C30, 0x11C36, 0),
+    (0x11C37, 0x11C37, 2),
+    (0x11C38, 0x11C3D, 0),
+    (0x11C3F, 0x11C3F, 0),
+    (0x11C46, 0x11C4F, 2),
+    (0x11C6D, 0x11C6F, 2),
+    (0x11C90, 0x11C91, 2),
+    (0x11C92, 0x11CA7, 0),
+    (0x11CA8, 0x11CA8, 2),
+    (0x11CAA, 0x11CB0, 0),
+    (0x11CB2, 0x11CB3, 0),
+    (0x11CB5, 0x11CB6, 0),
+    (0x11CB7, 0x11CFF, 2),
+    (0x11D07, 0x11D07, 2),
+    (0x11D0A, 0x11D0A, 2),
+    (0x11D31, 0x11D36, 0),
+    (0x11D37, 0x11D39, 2),
+    (0x11D3A, 0x11D3A, 0),
+    (0x11D3B, 0x11D3B, 2),
+    (0x11D3C, 0x11D3D, 0),
+    (0x11D3E, 0x11D3E, 2),
+    (0x11D3F, 0x11D45, 0),
+    (0x11D47, 0x11D47, 0),
+    (0x11D48, 0x11D4F, 2),
+    (0x11D5A, 0x11D5F, 2),
+    (0x11D66, 0x11D66, 2),
+    (0x11D69, 0x11D69, 2),
+    (0x11D8F, 0x11D8F, 2),
+    (0x11D90, 0x11D91, 0),
+    (0x11D92, 0x11D92, 2),
+    (0x11D95, 0x11D95, 0),
+    (0x11D97, 0x11D97, 0),
+    (0x11D99, 0x11D9F, 2),
+    (0x11DAA, 0x11EDF, 2),
+    (0x11EF3, 0x11EF4, 0),
+    (0x11EF9, 0x11FAF, 2),
+    (0x11FB1, 0x11FBF, 2),
+    (0x11FF2, 0x11FFE, 2),
+    (0x1239A, 0x123FF, 2),
+    (0x1246F, 0x1246F, 2),
+    (0x12475, 0x1247F, 2),
+    (0x12544, 0x12F8F, 2),
+    (0x12FF3, 0x12FFF, 2),
+    (0x1342F, 0x1342F, 2),
+    (0x13430, 0x13438, 0),
+    (0x13439, 0x143FF, 2),
+    (0x14647, 0x167FF, 2),
+    (0x16A39, 0x16A3F, 2),
+    (0x16A5F, 0x16A5F, 2),
+    (0x16A6A, 0x16A6D, 2),
+    (0x16ABF, 0x16ABF, 2),
+    (0x16ACA, 0x16ACF, 2),
+    (0x16AEE, 0x16AEF, 2),
+    (0x16AF0, 0x16AF4, 0),
+    (0x16AF6, 0x16AFF, 2),
+    (0x16B30, 0x16B36, 0),
+    (0x16B46, 0x16B4F, 2),
+    (0x16B5A, 0x16B5A, 2),
+    (0x16B62, 0x16B62, 2),
+    (0x16B78, 0x16B7C, 2),
+    (0x16B90, 0x16E3F, 2),
+    (0x16E9B, 0x16EFF, 2),
+    (0x16F4B, 0x16F4E, 2),
+    (0x16F4F, 0x16F4F, 0),
+    (0x16F88, 0x16F8E, 2),
+    (0x16F8F, 0x16F92, 0),
+    (0x16FA0, 0x16FE3, 2),
+    (0x16FE4, 0x16FE4, 0),
+    (0x16FE5, 0x1BBFF, 2),
+    (0x1BC6B, 0x1BC6F, 2),
+    (0x1BC7D, 0x1BC7F, 2),
+    (0x1BC89, 0x1BC8F, 2),
+    (0x1BC9A, 0x1BC9B, 2),
+    (0x1BC9D, 0x1BC9E, 0),
+    (0x1BCA0, 0x1BCA3, 0),
+    (0x1BCA4, 0x1CEFF, 2),
+    (0x1CF00, 0x1CF2D, 0),
+    (0x1CF2E, 0x1CF2F, 2),
+    (0x1CF30, 0x1CF46, 0),
+    (0x1CF47, 0x1CF4F, 2),
+    (0x1CFC4, 0x1CFFF, 2),
+    (0x1D0F6, 0x1D0FF, 2),
+    (0x1D127, 0x1D128, 2),
+    (0x1D167, 0x1D169, 0),
+    (0x1D173, 0x1D182, 0),
+    (0x1D185, 0x1D18B, 0),
+    (0x1D1AA, 0x1D1AD, 0),
+    (0x1D1EB, 0x1D1FF, 2),
+    (0x1D242, 0x1D244, 0),
+    (0x1D246, 0x1D2DF, 2),
+    (0x1D2F4, 0x1D2FF, 2),
+    (0x1D357, 0x1D35F, 2),
+    (0x1D379, 0x1D3FF, 2),
+    (0x1D455, 0x1D455, 2),
+    (0x1D49D, 0x1D49D, 2),
+    (0x1D4A0, 0x1D4A1, 2),
+    (0x1D4A3, 0x1D4A4, 2),
+    (0x1D4A7, 0x1D4A8, 2),
+    (0x1D4AD, 0x1D4AD, 2),
+    (0x1D4BA, 0x1D4BA, 2),
+    (0x1D4BC, 0x1D4BC, 2),
+    (0x1D4C4, 0x1D4C4, 2),
+    (0x1D506, 0x1D506, 2),
+    (0x1D50B, 0x1D50C, 2),
+    (0x1D515, 0x1D515, 2),
+    (0x1D51D, 0x1D51D, 2),
+    (0x1D53A, 0x1D53A, 2),
+    (0x1D53F, 0x1D53F, 2),
+    (0x1D545, 0x1D545, 2),
+    (0x1D547, 0x1D549, 2),
+    (0x1D551, 0x1D551, 2),
+    (0x1D6A6, 0x1D6A7, 2),
+    (0x1D7CC, 0x1D7CD, 2),
+    (0x1DA00, 0x1DA36, 0),
+    (0x1DA3B, 0x1DA6C, 0),
+    (0x1DA75, 0x1DA75, 0),
+    (0x1DA84, 0x1DA84, 0),
+    (0x1DA8C, 0x1DA9A, 2),
+    (0x1DA9B, 0x1DA9F, 0),
+    (0x1DAA0, 0x1DAA0, 2),
+    (0x1DAA1, 0x1DAAF, 0),
+    (0x1DAB0, 0x1DEFF, 2),
+    (0x1DF1F, 0x1DFFF, 2),
+    (0x1E000, 0x1E006, 0),
+    (0x1E007, 0x1E007, 2),
+    (0x1E008, 0x1E018, 0),
+    (0x1E019, 0x1E01A, 2),
+    (0x1E01B, 0x1E021, 0),
+    (0x1E022, 0x1E022, 2),
+    (0x1E023, 0x1E024, 0),
+    (0x1E025, 0x1E025, 2),
+    (0x1E026, 0x1E02A, 0),
+    (0x1E02B, 0x1E0FF, 2),
+    (0x1E12D, 0x1E12F, 2),
+    (0x1E130, 0x1E136, 0),
+    (0x1E13E, 0x1E13F, 2),
+    (0x1E14A, 0x1E14D, 2),
+    (0x1E150, 0x1E28F, 2),
+    (0x1E2AE, 0x1E2AE, 0),
+    (0x1E2AF, 0x1E2BF, 2),
+    (0x1E2EC, 0x1E2EF, 0),
+    (0x1E2FA, 0x1E2FE, 2),
+    (0x1E300, 0x1E7DF, 2),
+    (0x1E7E7, 0x1E7E7, 2),
+    (0x1E7EC, 0x1E7EC, 2),
+    (0x1E7EF, 0x1E7EF, 2),
+    (0x1E7FF, 0x1E7FF, 2),
+    (0x1E8C5, 0x1E8C6, 2),
+    (0x1E8D0, 0x1E8D6, 0),
+    (0x1E8D7, 0x1E8FF, 2),
+    (0x1E944, 0x1E94A, 0),
+    (0x1E94C, 0x1E94F, 2),
+    (0x1E95A, 0x1E95D, 2),
+    (0x1E960, 0x1EC70, 2),
+    (0x1ECB5, 0x1ED00, 2),
+    (0x1ED3E, 0x1EDFF, 2),
+    (0x1EE04, 0x1EE04, 2),
+    (0x1EE20, 0x1EE20, 2),
+    (0x1EE23, 0x1EE23, 2),
+    (0x1EE25, 0x1EE26, 2),
+    (0x1EE28, 0x1EE28, 2),
+    (0x1EE33, 0x1EE33, 2),
+    (0x1EE38, 0x1EE38, 2),
+    (0x1EE3A, 0x1EE3A, 2),
+    (0x1EE3C, 0x1EE41, 2),
+    (0x1EE43, 0x1EE46, 2),
+    (0x1EE48, 0x1EE48, 2),
+    (0x1EE4A, 0x1EE4A, 2),
+    (0x1EE4C, 0x1EE4C, 2),
+    (0x1EE50, 0x1EE50, 2),
+    (0x1EE53, 0x1EE53, 2),
+    (0x1EE55, 0x1EE56, 2),
+    (0x1EE58, 0x1EE58, 2),
+    (0x1EE5A, 0x1EE5A, 2),
+    (0x1EE5C, 0x1EE5C, 2),
+    (0x1EE5E, 0x1EE5E, 2),
+    (0x1EE60, 0x1EE60, 2),
+    (0x1EE63, 0x1EE63, 2),
+    (0x1EE65, 0x1EE66, 2),
+    (0x1EE6B, 0x1EE6B, 2),
+    (0x1EE73, 0x1EE73, 2),
+    (0x1EE78, 0x1EE78, 2),
+    (0x1EE7D, 0x1EE7D, 2),
+    (0x1EE7F, 0x1EE7F, 2),
+    (0x1EE8A, 0x1EE8A, 2),
+    (0x1EE9C, 0x1EEA0, 2),
+    (0x1EEA4, 0x1EEA4, 2),
+    (0x1EEAA, 0x1EEAA, 2),
+    (0x1EEBC, 0x1EEEF, 2),
+    (0x1EEF2, 0x1EFFF, 2),
+    (0x1F004, 0x1F004, 2),
+    (0x1F02C, 0x1F02F, 2),
+    (0x1F094, 0x1F09F, 2),
+    (0x1F0AF, 0x1F0B0, 2),
+    (0x1F0C0, 0x1F0C0, 2),
+    (0x1F0CF, 0x1F0D0, 2),
+    (0x1F0F6, 0x1F0FF, 2),
+    (0x1F18E, 0x1F18E, 2),
+    (0x1F191, 0x1F19A, 2),
+    (0x1F1AE, 0x1F1E5, 2),
+    (0x1F200, 0x1F320, 2),
+    (0x1F32D, 0x1F335, 2),
+    (0x1F337, 0x1F37C, 2),
+    (0x1F37E, 0x1F393, 2),
+    (0x1F3A0, 0x1F3CA, 2),
+    (0x1F3CF, 0x1F3D3, 2),
+    (0x1F3E0, 0x1F3F0, 2),
+    (0x1F3F4, 0x1F3F4, 2),
+    (0x1F3F8, 0x1F43E, 2),
+    (0x1F440, 0x1F440, 2),
+    (0x1F442, 0x1F4FC, 2),
+    (0x1F4FF, 0x1F53D, 2),
+    (0x1F54B, 0x1F54E, 2),
+    (0x1F550, 0x1F567, 2),
+    (0x1F57A, 0x1F57A, 2),
+    (0x1F595, 0x1F596, 2),
+    (0x1F5A4, 0x1F5A4, 2),
+    (0x1F5FB, 0x1F64F, 2),
+    (0x1F680, 0x1F6C5, 2),
+    (0x1F6CC, 0x1F6CC, 2),
+    (0x1F6D0, 0x1F6D2, 2),
+    (0x1F6D5, 0x1F6DF, 2),
+    (0x1F6EB, 0x1F6EF, 2),
+    (0x1F6F4, 0x1F6FF, 2),
+    (0x1F774, 0x1F77F, 2),
+    (0x1F7D9, 0x1F7FF, 2),
+    (0x1F80C, 0x1F80F, 2),
+    (0x1F848, 0x1F84F, 2),
+    (0x1F85A, 0x1F85F, 2),
+    (0x1F888, 0x1F88F, 2),
+    (0x1F8AE, 0x1F8AF, 2),
+    (0x1F8B2, 0x1F8FF, 2),
+    (0x1F90C, 0x1F93A, 2),
+    (0x1F93C, 0x1F945, 2),
+    (0x1F947, 0x1F9FF, 2),
+    (0x1FA54, 0x1FA5F, 2),
+    (0x1FA6E, 0x1FAFF, 2),
+    (0x1FB93, 0x1FB93, 2),
+    (0x1FBCB, 0x1FBEF, 2),
+    (0x1FBFA, 0xE0000, 2),
+    (0xE0001, 0xE0001, 0),
+    (0xE0002, 0xE001F, 2),
+    (0xE0020, 0xE007F, 0),
+    (0xE0080, 0xE00FF, 2),
+    (0xE0100, 0xE01EF, 0),
+    (0xE01F0, 0xEFFFF, 2),
+    (0xFFFFE, 0xFFFFF, 2),
+    (0x10FFFE, 0x10FFFF, 2),
+];
+
+pub const AMBIGUOUS_WIDTH: &[(u32, u32)] = &[
+    (0x00A1, 0x00A1),
+    (0x00A4, 0x00A4),
+    (0x00A7, 0x00A8),
+    (0x00AA, 0x00AA),
+    (0x00AD, 0x00AE),
+    (0x00B0, 0x00B4),
+    (0x00B6, 0x00BA),
+    (0x00BC, 0x00BF),
+    (0x00C6, 0x00C6),
+    (0x00D0, 0x00D0),
+    (0x00D7, 0x00D8),
+    (0x00DE, 0x00E1),
+    (0x00E6, 0x00E6),
+    (0x00E8, 0x00EA),
+    (0x00EC, 0x00ED),
+    (0x00F0, 0x00F0),
+    (0x00F2, 0x00F3),
+    (0x00F7, 0x00FA),
+    (0x00FC, 0x00FC),
+    (0x00FE, 0x00FE),
+    (0x0101, 0x0101),
+    (0x0111, 0x0111),
+    (0x0113, 0x0113),
+    (0x011B, 0x011B),
+    (0x0126, 0x0127),
+    (0x012B, 0x012B),
+    (0x0131, 0x0133),
+    (0x0138, 0x0138),
+    (0x013F, 0x0142),
+    (0x0144, 0x0144),
+    (0x0148, 0x014B),
+    (0x014D, 0x014D),
+    (0x0152, 0x0153),
+    (0x0166, 0x0167),
+    (0x016B, 0x016B),
+    (0x01CE, 0x01CE),
+    (0x01D0, 0x01D0),
+    (0x01D2, 0x01D2),
+    (0x01D4, 0x01D4),
+    (0x01D6, 0x01D6),
+    (0x01D8, 0x01D8),
+    (0x01DA, 0x01DA),
+    (0x01DC, 0x01DC),
+    (0x0251, 0x0251),
+    (0x0261, 0x0261),
+    (0x02C4, 0x02C4),
+    (0x02C7, 0x02C7),
+    (0x02C9, 0x02CB),
+    (0x02CD, 0x02CD),
+    (0x02D0, 0x02D0),
+    (0x02D8, 0x02DB),
+    (0x02DD, 0x02DD),
+    (0x02DF, 0x02DF),
+    (0x0391, 0x03A1),
+    (0x03A3, 0x03A9),
+    (0x03B1, 0x03C1),
+    (0x03C3, 0x03C9),
+    (0x0401, 0x0401),
+    (0x0410, 0x044F),
+    (0x0451, 0x0451),
+    (0x2010, 0x2010),
+    (0x2013, 0x2016),
+    (0x2018, 0x2019),
+    (0x201C, 0x201D),
+    (0x2020, 0x2022),
+    (0x2024, 0x2027),
+    (0x2030, 0x2030),
+    (0x2032, 0x2033),
+    (0x2035, 0x2035),
+    (0x203B, 0x203B),
+    (0x203E, 0x203E),
+    (0x2074, 0x2074),
+    (0x207F, 0x207F),
+    (0x2081, 0x2084),
+    (0x20AC, 0x20AC),
+    (0x2103, 0x2103),
+    (0x2105, 0x2105),
+    (0x2109, 0x2109),
+    (0x2113, 0x2113),
+    (0x2116, 0x2116),
+    (0x2121, 0x2122),
+    (0x2126, 0x2126),
+    (0x212B, 0x212B),
+    (0x2153, 0x2154),
+    (0x215B, 0x215E),
+    (0x2160, 0x216B),
+    (0x2170, 0x2179),
+    (0x2189, 0x2189),
+    (0x2190, 0x2199),
+    (0x21B8, 0x21B9),
+    (0x21D2, 0x21D2),
+    (0x21D4, 0x21D4),
+    (0x21E7, 0x21E7),
+    (0x2200, 0x2200),
+    (0x2202, 0x2203),
+    (0x2207, 0x2208),
+    (0x220B, 0x220B),
+    (0x220F, 0x220F),
+    (0x2211, 0x2211),
+    (0x2215, 0x2215),
+    (0x221A, 0x221A),
+    (0x221D, 0x2220),
+    (0x2223, 0x2223),
+    (0x2225, 0x2225),
+    (0x2227, 0x222C),
+    (0x222E, 0x222E),
+    (0x2234, 0x2237),
+    (0x223C, 0x223D),
+    (0x2248, 0x2248),
+    (0x224C, 0x224C),
+    (0x2252, 0x2252),
+    (0x2260, 0x2261),
+    (0x2264, 0x2267),
+    (0x226A, 0x226B),
+    (0x226E, 0x226F),
+    (0x2282, 0x2283),
+    (0x2286, 0x2287),
+    (0x2295, 0x2295),
+    (0x2299, 0x2299),
+    (0x22A5, 0x22A5),
+    (0x22BF, 0x22BF),
+    (0x2312, 0x2312),
+    (0x2460, 0x24E9),
+    (0x24EB, 0x254B),
+    (0x2550, 0x2573),
+    (0x2580, 0x258F),
+    (0x2592, 0x2595),
+    (0x25A0, 0x25A1),
+    (0x25A3, 0x25A9),
+    (0x25B2, 0x25B3),
+    (0x25B6, 0x25B7),
+    (0x25BC, 0x25BD),
+    (0x25C0, 0x25C1),
+    (0x25C6, 0x25C8),
+    (0x25CB, 0x25CB),
+    (0x25CE, 0x25D1),
+    (0x25E2, 0x25E5),
+    (0x25EF, 0x25EF),
+    (0x2605, 0x2606),
+    (0x2609, 0x2609),
+    (0x260E, 0x260F),
+    (0x261C, 0x261C),
+    (0x261E, 0x261E),
+    (0x2640, 0x2640),
+    (0x2642, 0x2642),
+    (0x2660, 0x2661),
+    (0x2663, 0x2665),
+    (0x2667, 0x266A),
+    (0x266C, 0x266D),
+    (0x266F, 0x266F),
+    (0x269E, 0x269F),
+    (0x26BF, 0x26BF),
+    (0x26C6, 0x26CD),
+    (0x26CF, 0x26D3),
+    (0x26D5, 0x26E1),
+    (0x26E3, 0x26E3),
+    (0x26E8, 0x26E9),
+    (0x26EB, 0x26F1),
+    (0x26F4, 0x26F4),
+    (0x26F6, 0x26F9),
+    (0x26FB, 0x26FC),
+    (0x26FE, 0x26FF),
+    (0x273D, 0x273D),
+    (0x2776, 0x277F),
+    (0x2B56, 0x2B59),
+    (0x3248, 0x324F),
+    (0xE000, 0xF8FF),
+    (0xFFFD, 0xFFFD),
+    (0x1F100, 0x1F10A),
+    (0x1F110, 0x1F12D),
+    (0x1F130, 0x1F169),
+    (0x1F170, 0x1F18D),
+    (0x1F18F, 0x1F190),
+    (0x1F19B, 0x1F1AC),
+    (0xF0000, 0xFFFFD),
+    (0x100000, 0x10FFFD),
+];
@@ -0,0 +1,121 @@
+// How many terminal columns a string takes up, for lining text up in
+// columns. Neither `len` (bytes) nor `chars().count()` (code points) is
+// right: "日本" is two characters but four columns, and "e\u{301}" is
+// two characters but one column.
+//
+//     use more_strings::width::{display_width, pad, Align};
+//
+//     assert_eq!(display_width("日本"), 4);
+//     assert_eq!(pad("日本", 6, Align::Left), "日本  ");
+//
+// Widths follow East Asian Width from UAX #11: wide and fullwidth
+// characters take two columns, combining marks, format and control
+// characters none. Width is counted per grapheme cluster, so an emoji
+// sequence joined with ZWJ counts once, and text is only ever cut
+// between clusters.
+
+use segmentation::UnicodeSegmentation;
+use tables::{AMBIGUOUS_WIDTH, CHAR_WIDTH, EXTENDED_PICTOGRAPHIC};
+use {in_table, lookup};
+
+const EMOJI_PRESENTATION: char = '\u{fe0f}';
+
+// Columns taken by `c` on its own. Characters of ambiguous width, such
+// as "±" or Greek letters, are wide in East Asian contexts only.
+pub fn char_width(c: char, ambiguous_wide: bool) -> usize {
+    let width = lookup(CHAR_WIDTH, c, 1) as usize;
+    if width == 1 && ambiguous_wide && in_table(AMBIGUOUS_WIDTH, c) {
+        2
+    } else {
+        width
+    }
+}
+
+// Columns taken by one grapheme cluster. A cluster is as wide as its
+// widest character, except that a flag and anything asking for emoji
+// presentation with U+FE0F take two columns.
+fn grapheme_width(g: &str, ambiguous_wide: bool) -> usize {
+    let first = match g.chars().next() {
+        Some(c) => c,
+        None => return 0,
+    };
+    let regional_indicator = ('\u{1f1e6}'..='\u{1f1ff}').contains(&first);
+    if regional_indicator && g.chars().count() > 1 {
+        return 2;
+    }
+    let keycap = first.is_ascii_digit() || first == '#' || first == '*';
+    if (in_table(EXTENDED_PICTOGRAPHIC, first) || keycap) && g.contains(EMOJI_PRESENTATION) {
+        return 2;
+    }
+    g.chars()
+        .map(|c| char_width(c, ambiguous_wide))
+        .max()
+        .unwrap_or(0)
+}
+
+pub fn display_width(s: &str) -> usize {
+    s.graphemes(true).map(|g| grapheme_width(g, false)).sum()
+}
+
+// Like `display_width`, but with ambiguous width characters taking two
+// columns, as in terminals set up for Chinese, Japanese or Korean.
+pub fn display_width_cjk(s: &str) -> usize {
+    s.graphemes(true).map(|g| grapheme_width(g, true)).sum()
+}
+
+// The longest prefix of `s` that fits in `max_width` columns. A wide
+// character that would only half fit is left out entirely.
+pub fn truncate(s: &str, max_width: usize) -> &str {
+    let mut width = 0;
+    for (i, g) in s.grapheme_indices(true) {
+        width += grapheme_width(g, false);
+        if width > max_width {
+            return &s[..i];
+        }
+    }
+    s
+}
+
+// `s` unchanged if it fits in `max_width` columns, otherwise cut short
+// and ended with `ellipsis` so that the whole still fits. An ellipsis
+// wider than `max_width` is itself cut down to fit.
+pub fn truncate_with(s: &str, max_width: usize, ellipsis: &str) -> String {
+    if display_width(s) <= max_width {
+        return s.to_string();
+    }
+    let ellipsis = truncate(ellipsis, max_width);
+    let room = max_width - display_width(ellipsis);
+    let mut out = truncate(s, room).to_string();
+    out.push_str(ellipsis);
+    out
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Align {
+    Left,
+    Right,
+    Center,
+}
+
+// `s` filled out with spaces to `width` columns. Text that is already
+// wider is returned as it is; use `truncate` first to cut it down.
+pub fn pad(s: &str, width: usize, align: Align) -> String {
+    let missing = width.saturating_sub(display_width(s));
+    let (left, right) = match align {
+        Align::Left => (0, missing),
+        Align::Right => (missing, 0),
+        Align::Center => (missing / 2, missing - missing / 2),
+    };
+    let mut out = String::with_capacity(s.len() + missing);
+    out.extend((0..left).map(|_| ' '));
+    out.push_str(s);
+    out.extend((0..right).map(|_| ' '));
+    out
+}
+
+// Exactly `width` columns: truncated with `ellipsis` when too wide,
+// padded when too narrow. A wide character cut off at the edge can
+// leave the result one column short, which padding makes up for.
+pub fn fit(s: &str, width: usize, align: Align, ellipsis: &str) -> String {
+    pad(&truncate_with(s, width, ellipsis), width, align)
+}
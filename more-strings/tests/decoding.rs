@@ -0,0 +1,156 @@
+extern crate more_strings;
+
+use more_strings::decoding::{
+    decode, decode_lossy, decode_utf8, detect, detect_bom, DecodeErrorKind, Decoder, Encoding,
+};
+
+// Decodes `bytes` fed to a decoder in pieces of `size` bytes.
+fn in_chunks(bytes: &[u8], size: usize, mut decoder: Decoder) -> (String, Decoder) {
+    let mut text = String::new();
+    for chunk in bytes.chunks(size) {
+        decoder.push(chunk, &mut text);
+    }
+    decoder.finish(&mut text);
+    (text, decoder)
+}
+
+#[test]
+fn strict_utf8_reports_where_and_how_many() {
+    assert_eq!(decode_utf8(b"hello"), Ok("hello"));
+
+    let err = decode_utf8(b"ab\xffcd").unwrap_err();
+    assert_eq!(
+        (err.position, err.len, err.kind),
+        (2, 1, DecodeErrorKind::Invalid)
+    );
+    assert_eq!(
+        err.to_string(),
+        "invalid UTF-8 sequence of 1 byte at byte 2"
+    );
+
+    let err = decode_utf8(b"ab\xe2\x82").unwrap_err();
+    assert_eq!(
+        (err.position, err.len, err.kind),
+        (2, 2, DecodeErrorKind::Truncated)
+    );
+}
+
+#[test]
+fn lossy_utf8_matches_the_standard_library() {
+    let samples: &[&[u8]] = &[
+        b"caf\xc3\xa9",
+        b"caf\xe9",
+        b"\xf0\x9f\x91\x8d\xf0\x9f\x91",
+        b"\xed\xa0\x80 surrogate",
+        b"\xc0\xaf overlong",
+        b"\xf4\x90\x80\x80 too high",
+        b"\xe2\x82\xe2\x82\xac",
+        b"\x80\x80\x80",
+    ];
+    for bytes in samples {
+        let (text, errors) = decode_lossy(bytes, Encoding::Utf8);
+        assert_eq!(text, String::from_utf8_lossy(bytes), "{:?}", bytes);
+        assert_eq!(
+            text.matches('\u{fffd}').count(),
+            errors.len(),
+            "{:?}",
+            bytes
+        );
+    }
+
+    let (_, errors) = decode_lossy(b"a\xffb\xe2\x82", Encoding::Utf8);
+    let found: Vec<(usize, usize)> = errors.iter().map(|e| (e.position, e.len)).collect();
+    assert_eq!(found, [(1, 1), (3, 2)]);
+}
+
+#[test]
+fn utf16_in_both_byte_orders() {
+    let text = "h\u{e9}llo \u{1f44d}";
+    let units: Vec<u16> = text.encode_utf16().collect();
+    let le: Vec<u8> = units.iter().flat_map(|u| u.to_le_bytes()).collect();
+    let be: Vec<u8> = units.iter().flat_map(|u| u.to_be_bytes()).collect();
+    assert_eq!(decode(&le, Encoding::Utf16Le).unwrap(), text);
+    assert_eq!(decode(&be, Encoding::Utf16Be).unwrap(), text);
+}
+
+#[test]
+fn utf16_surrogate_errors() {
+    // A lone low surrogate, then a high surrogate without its low half
+    let units = [0x61, 0xdc00, 0x62, 0xd83d, 0x63];
+    let bytes: Vec<u8> = units.iter().flat_map(|u: &u16| u.to_le_bytes()).collect();
+    let (text, errors) = decode_lossy(&bytes, Encoding::Utf16Le);
+    assert_eq!(text, String::from_utf16_lossy(&units));
+    let found: Vec<usize> = errors.iter().map(|e| e.position).collect();
+    assert_eq!(found, [2, 6]);
+
+    let (text, errors) = decode_lossy(b"a\x00b", Encoding::Utf16Le);
+    assert_eq!(text, "a\u{fffd}");
+    assert_eq!(errors[0].kind, DecodeErrorKind::Truncated);
+}
+
+#[test]
+fn single_byte_encodings() {
+    assert_eq!(decode(b"caf\xe9", Encoding::Latin1).unwrap(), "caf\u{e9}");
+    assert_eq!(
+        decode(b"\x93quoted\x94 \x80", Encoding::Windows1252).unwrap(),
+        "\u{201c}quoted\u{201d} \u{20ac}"
+    );
+    assert_eq!(decode(b"\x80", Encoding::Latin1).unwrap(), "\u{80}");
+}
+
+#[test]
+fn byte_order_marks() {
+    assert_eq!(detect_bom(b"\xef\xbb\xbfhi"), Some((Encoding::Utf8, 3)));
+    assert_eq!(detect_bom(b"\xff\xfeh\x00"), Some((Encoding::Utf16Le, 2)));
+    assert_eq!(detect_bom(b"\xfe\xff\x00h"), Some((Encoding::Utf16Be, 2)));
+    assert_eq!(detect_bom(b"hi"), None);
+
+    // Dropped from the text when decoding
+    assert_eq!(decode(b"\xef\xbb\xbfhi", Encoding::Utf8).unwrap(), "hi");
+    assert_eq!(
+        decode(b"\xfe\xff\x00h\x00i", Encoding::Utf16Be).unwrap(),
+        "hi"
+    );
+}
+
+#[test]
+fn detect_guesses_unknown_encodings() {
+    assert_eq!(detect(b"\xff\xfeh\x00"), Encoding::Utf16Le);
+    assert_eq!(detect("caf\u{e9}".as_bytes()), Encoding::Utf8);
+    assert_eq!(detect(b"caf\xe9"), Encoding::Windows1252);
+}
+
+#[test]
+fn detecting_decoder_follows_the_byte_order_mark() {
+    let (text, decoder) = in_chunks(b"\xff\xfeh\x00i\x00", 1, Decoder::detecting(Encoding::Utf8));
+    assert_eq!(text, "hi");
+    assert_eq!(decoder.encoding(), Some(Encoding::Utf16Le));
+
+    let (text, decoder) = in_chunks(b"hi", 1, Decoder::detecting(Encoding::Windows1252));
+    assert_eq!(text, "hi");
+    assert_eq!(decoder.encoding(), Some(Encoding::Windows1252));
+}
+
+#[test]
+fn chunk_boundaries_do_not_change_the_result() {
+    let mut utf8 = b"\xef\xbb\xbfna\xc3\xafve \xe2\x82\xac \xf0\x9f\x91\x8d".to_vec();
+    utf8.extend_from_slice(b" bad\xff\xe2\x82 x\xf0\x9f\x91");
+    let units: Vec<u16> = "\u{feff}na\u{ef}ve \u{1f44d}".encode_utf16().collect();
+    let mut utf16: Vec<u8> = units.iter().flat_map(|u| u.to_be_bytes()).collect();
+    utf16.extend_from_slice(&[0xdc, 0x00, 0xd8]);
+
+    for &(bytes, encoding) in &[(&utf8, Encoding::Utf8), (&utf16, Encoding::Utf16Be)] {
+        let (whole, errors) = decode_lossy(bytes, encoding);
+        for size in 1..8 {
+            let (text, decoder) = in_chunks(bytes, size, Decoder::new(encoding));
+            assert_eq!(text, whole, "{} in chunks of {}", encoding, size);
+            assert_eq!(
+                decoder.errors(),
+                &errors[..],
+                "{} in chunks of {}",
+                encoding,
+                size
+            );
+        }
+    }
+}
@@ -0,0 +1,96 @@
+extern crate more_strings;
+
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::thread;
+
+use more_strings::interner::{Arena, ConcurrentInterner, Interner};
+
+#[test]
+fn equal_strings_share_a_symbol() {
+    let mut interner = Interner::new();
+    let a = interner.intern("alpha");
+    let b = interner.intern("beta");
+    let owned = String::from("alpha");
+    let a_again = interner.intern(&owned);
+    assert_eq!(a, a_again);
+    assert_ne!(a, b);
+    assert_eq!(interner.len(), 2);
+    assert_eq!(interner.resolve(a), "alpha");
+    assert_eq!(interner.resolve(b), "beta");
+    assert_eq!(interner.get("beta"), Some(b));
+    assert_eq!(interner.get("gamma"), None);
+}
+
+#[test]
+fn symbols_are_consecutive() {
+    let mut interner = Interner::new();
+    let symbols: Vec<u32> = ["x", "y", "x", "z"]
+        .iter()
+        .map(|s| interner.intern(s).as_u32())
+        .collect();
+    assert_eq!(symbols, [0, 1, 0, 2]);
+    let all: Vec<(u32, &str)> = interner
+        .iter()
+        .map(|(s, name)| (s.as_u32(), name))
+        .collect();
+    assert_eq!(all, [(0, "x"), (1, "y"), (2, "z")]);
+}
+
+#[test]
+fn resolved_strings_do_not_move() {
+    let mut interner = Interner::new();
+    let first = interner.intern("first");
+    let address = interner.resolve(first).as_ptr();
+    // Enough to fill many chunks
+    for i in 0..100_000 {
+        interner.intern(&format!("name{}", i));
+    }
+    interner.intern(&"long".repeat(10_000));
+    assert_eq!(interner.resolve(first).as_ptr(), address);
+    assert_eq!(interner.resolve(first), "first");
+}
+
+#[test]
+fn arena_hands_out_lasting_borrows() {
+    let arena = Arena::new();
+    let words: Vec<&str> = (0..1000).map(|i| arena.alloc(&i.to_string())).collect();
+    assert_eq!(words[0], "0");
+    assert_eq!(words[999], "999");
+    assert_eq!(arena.alloc(""), "");
+    assert_eq!(
+        arena.allocated_bytes(),
+        words.iter().map(|w| w.len()).sum::<usize>()
+    );
+}
+
+#[test]
+fn concurrent_interner_agrees_across_threads() {
+    let interner = Arc::new(ConcurrentInterner::new());
+    let handles: Vec<_> = (0..8)
+        .map(|t| {
+            let interner = Arc::clone(&interner);
+            thread::spawn(move || {
+                // Every thread interns the same names, in a different order
+                (0..2000)
+                    .map(|i| {
+                        let name = format!("ident{}", (i * 7 + t * 13) % 2000);
+                        let symbol = interner.intern(&name);
+                        assert_eq!(interner.resolve(symbol), name);
+                        (name, symbol)
+                    })
+                    .collect::<Vec<_>>()
+            })
+        })
+        .collect();
+
+    let mut seen = HashSet::new();
+    for handle in handles {
+        for (name, symbol) in handle.join().unwrap() {
+            assert_eq!(interner.get(&name), Some(symbol));
+            seen.insert(symbol);
+        }
+    }
+    assert_eq!(seen.len(), 2000);
+    assert_eq!(interner.len(), 2000);
+}
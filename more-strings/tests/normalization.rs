@@ -0,0 +1,113 @@
+// Runs the UAX #15 cases in tests/data, a hand-written subset in the
+// format of the UCD's NormalizationTest.txt, plus the case-folded
+// comparisons on top of it.
+
+extern crate more_strings;
+
+use more_strings::normalization::{
+    case_fold, caseless_eq, caseless_key, is_normalized, normalize, Form, Normalized, Normalizer,
+    UnicodeNormalization,
+};
+
+fn parse(field: &str) -> String {
+    field
+        .split_whitespace()
+        .map(|hex| {
+            let code = u32::from_str_radix(hex, 16).expect("bad code point");
+            ::std::char::from_u32(code).expect("not a scalar value")
+        })
+        .collect()
+}
+
+#[test]
+fn normalization_subset() {
+    let data = include_str!("data/NormalizationSubset.txt");
+    let mut failures = Vec::new();
+    for (n, line) in data.lines().enumerate() {
+        let line = line.split('#').next().unwrap().trim();
+        if line.is_empty() || line.starts_with('@') {
+            continue;
+        }
+        let c: Vec<String> = line.split(';').take(5).map(parse).collect();
+        let expected = [
+            (Form::Nfc, [&c[1], &c[1], &c[1], &c[3], &c[3]]),
+            (Form::Nfd, [&c[2], &c[2], &c[2], &c[4], &c[4]]),
+            (Form::Nfkc, [&c[3], &c[3], &c[3], &c[3], &c[3]]),
+            (Form::Nfkd, [&c[4], &c[4], &c[4], &c[4], &c[4]]),
+        ];
+        for &(form, ref outputs) in &expected {
+            for (input, output) in c.iter().zip(outputs.iter()) {
+                let actual = normalize(input, form);
+                if actual != **output {
+                    failures.push(format!(
+                        "line {}: {:?}({:?}) = {:?}, expected {:?}",
+                        n + 1,
+                        form,
+                        input,
+                        actual,
+                        output
+                    ));
+                }
+            }
+        }
+    }
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
+
+#[test]
+fn composed_and_decomposed_compare_equal_once_normalized() {
+    let composed = "\u{e9}";
+    let decomposed = "e\u{301}";
+    assert_ne!(composed, decomposed);
+    assert_eq!(decomposed.nfc().collect::<String>(), composed);
+    assert_eq!(composed.nfd().collect::<String>(), decomposed);
+    assert!(is_normalized(composed, Form::Nfc));
+    assert!(!is_normalized(composed, Form::Nfd));
+}
+
+#[test]
+fn compatibility_forms_fold_presentation_variants() {
+    assert_eq!("\u{fb01}le".nfkc().collect::<String>(), "file");
+    assert_eq!("x\u{b2}".nfkd().collect::<String>(), "x2");
+    assert_eq!("\u{fb01}le".nfc().collect::<String>(), "\u{fb01}le");
+}
+
+#[test]
+fn chunked_input_matches_whole_input() {
+    let text =
+        "Ame\u{301}lie, A\u{30a}ngstro\u{308}m, \u{1100}\u{1161}\u{11a8} and q\u{307}\u{323}";
+    for form in [Form::Nfc, Form::Nfd, Form::Nfkc, Form::Nfkd] {
+        let whole = normalize(text, form);
+        // Split after every character, so that marks arrive one by one
+        let mut normalizer = Normalizer::new(form);
+        let mut out = String::new();
+        for (i, c) in text.char_indices() {
+            normalizer.push_str(&text[i..i + c.len_utf8()], &mut out);
+        }
+        normalizer.finish(&mut out);
+        assert_eq!(out, whole, "{:?}", form);
+    }
+}
+
+#[test]
+fn normalized_accepts_any_char_iterator() {
+    let chars = vec!['A', '\u{30a}', '\u{301}'];
+    let s: String = Normalized::new(chars.into_iter(), Form::Nfc).collect();
+    assert_eq!(s, "\u{1fa}");
+}
+
+#[test]
+fn case_folding_is_full() {
+    assert_eq!(case_fold("Stra\u{df}e"), "strasse");
+    assert_eq!(case_fold("\u{3a3}\u{3c3}\u{3c2}"), "\u{3c3}\u{3c3}\u{3c3}");
+    assert_eq!(case_fold("\u{130}"), "i\u{307}");
+}
+
+#[test]
+fn caseless_eq_ignores_case_and_composition() {
+    assert!(caseless_eq("Ame\u{301}lie", "AM\u{c9}LIE"));
+    assert!(caseless_eq("Stra\u{df}e", "STRASSE"));
+    assert!(caseless_eq("\u{212b}ngstr\u{f6}m", "\u{e5}NGSTRO\u{308}M"));
+    assert!(!caseless_eq("resume", "r\u{e9}sum\u{e9}"));
+    assert_eq!(caseless_key("Jos\u{e9}"), caseless_key("JOSE\u{301}"));
+}
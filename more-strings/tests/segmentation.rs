@@ -0,0 +1,133 @@
+// Runs every case of the UCD break test files in tests/data, copied
+// unchanged from https://www.unicode.org/Public/14.0.0/ucd/auxiliary/
+// (terms in tests/data/LICENSE-UNICODE). Each line is a test case such
+// as `÷ 0061 × 0308 ÷ 0062 ÷`: the code points to join into a string,
+// with `÷` where a boundary is expected and `×` where none is.
+
+extern crate more_strings;
+
+use more_strings::segmentation::UnicodeSegmentation;
+
+// The expected segments of every test case in `data`, with the line
+// number each came from.
+fn cases(data: &str) -> Vec<(usize, Vec<String>)> {
+    let mut out = Vec::new();
+    for (n, line) in data.lines().enumerate() {
+        let line = line.split('#').next().unwrap().trim();
+        if line.is_empty() {
+            continue;
+        }
+        let mut segments = Vec::new();
+        let mut current = String::new();
+        for token in line.split_whitespace() {
+            match token {
+                "÷" => {
+                    if !current.is_empty() {
+                        segments.push(current);
+                        current = String::new();
+                    }
+                }
+                "×" => {}
+                hex => {
+                    let code = u32::from_str_radix(hex, 16).expect("bad code point");
+                    current.push(::std::char::from_u32(code).expect("not a scalar value"));
+                }
+            }
+        }
+        assert!(current.is_empty(), "line {} does not end with ÷", n + 1);
+        out.push((n + 1, segments));
+    }
+    out
+}
+
+fn check<F>(file: &str, data: &str, split: F)
+where
+    F: Fn(&str) -> Vec<&str>,
+{
+    let cases = cases(data);
+    // The files end with the number of cases they hold
+    let lines = data
+        .lines()
+        .find_map(|l| l.strip_prefix("# Lines: "))
+        .expect("no `# Lines:` count");
+    assert_eq!(cases.len().to_string(), lines, "{}", file);
+
+    let mut failures = Vec::new();
+    for (line, expected) in cases {
+        let s = expected.concat();
+        let actual = split(&s);
+        if actual != expected {
+            failures.push(format!(
+                "{}:{}: expected {:?}, got {:?}",
+                file, line, expected, actual
+            ));
+        }
+    }
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
+
+#[test]
+fn grapheme_break_test() {
+    check(
+        "GraphemeBreakTest.txt",
+        include_str!("data/GraphemeBreakTest.txt"),
+        |s| s.graphemes(true).collect(),
+    );
+}
+
+#[test]
+fn word_break_test() {
+    check(
+        "WordBreakTest.txt",
+        include_str!("data/WordBreakTest.txt"),
+        |s| s.split_word_bounds().collect(),
+    );
+}
+
+#[test]
+fn sentence_break_test() {
+    check(
+        "SentenceBreakTest.txt",
+        include_str!("data/SentenceBreakTest.txt"),
+        |s| s.split_sentence_bounds().collect(),
+    );
+}
+
+#[test]
+fn combining_marks_stay_with_their_base() {
+    let s = "u\u{354}n\u{348}\u{330}\u{30e}i\u{319}\u{32e}\u{35a}\u{326}";
+    assert_eq!(s.chars().count(), 11);
+    assert_eq!(s.graphemes(true).count(), 3);
+
+    let decomposed = "e\u{301}";
+    assert_eq!(decomposed.graphemes(true).collect::<Vec<_>>(), ["e\u{301}"]);
+}
+
+#[test]
+fn legacy_clusters_split_spacing_marks() {
+    assert_eq!(
+        "\u{e01}\u{e33}".graphemes(true).collect::<Vec<_>>(),
+        ["\u{e01}\u{e33}"]
+    );
+    assert_eq!(
+        "\u{e01}\u{e33}".graphemes(false).collect::<Vec<_>>(),
+        ["\u{e01}", "\u{e33}"]
+    );
+}
+
+#[test]
+fn grapheme_indices_are_byte_offsets() {
+    let indices: Vec<(usize, &str)> = "a\u{308}b".grapheme_indices(true).collect();
+    assert_eq!(indices, [(0, "a\u{308}"), (3, "b")]);
+}
+
+#[test]
+fn unicode_words_skip_spaces_and_punctuation() {
+    let words: Vec<&str> = "The quick (\"brown\") fox can't jump 32.3 feet, right?"
+        .unicode_words()
+        .collect();
+    assert_eq!(
+        words,
+        ["The", "quick", "brown", "fox", "can't", "jump", "32.3", "feet", "right"]
+    );
+}
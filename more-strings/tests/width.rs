@@ -0,0 +1,97 @@
+extern crate more_strings;
+
+use more_strings::width::{
+    char_width, display_width, display_width_cjk, fit, pad, truncate, truncate_with, Align,
+};
+
+#[test]
+fn ascii_is_one_column_per_char() {
+    assert_eq!(display_width("hello"), 5);
+    assert_eq!(display_width(""), 0);
+}
+
+#[test]
+fn east_asian_wide_characters_take_two_columns() {
+    assert_eq!(display_width("日本語"), 6);
+    assert_eq!(display_width("ｶﾀｶﾅ"), 4);
+    assert_eq!(display_width("ＡＢ"), 4);
+    assert_eq!(display_width("한국어"), 6);
+    // The same syllable spelled with conjoining jamo
+    assert_eq!(display_width("\u{1112}\u{1161}\u{11ab}"), 2);
+}
+
+#[test]
+fn combining_marks_and_format_characters_take_none() {
+    assert_eq!(display_width("e\u{301}"), 1);
+    assert_eq!(display_width("u\u{354}n\u{348}\u{330}\u{30e}"), 2);
+    assert_eq!(display_width("a\u{200b}b"), 2);
+    assert_eq!(char_width('\u{200d}', false), 0);
+}
+
+#[test]
+fn emoji_sequences_count_once() {
+    assert_eq!(display_width("\u{1f44d}"), 2);
+    assert_eq!(display_width("\u{1f44d}\u{1f3fd}"), 2);
+    assert_eq!(
+        display_width("\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}"),
+        2
+    );
+    assert_eq!(display_width("\u{1f1f1}\u{1f1f9}"), 2);
+    // Text style by default, emoji style with U+FE0F
+    assert_eq!(display_width("\u{2764}"), 1);
+    assert_eq!(display_width("\u{2764}\u{fe0f}"), 2);
+    assert_eq!(display_width("1\u{fe0f}\u{20e3}"), 2);
+}
+
+#[test]
+fn ambiguous_characters_are_wide_only_in_cjk_contexts() {
+    assert_eq!(display_width("\u{b1}\u{3b1}"), 2);
+    assert_eq!(display_width_cjk("\u{b1}\u{3b1}"), 4);
+}
+
+#[test]
+fn truncate_never_splits_a_grapheme() {
+    assert_eq!(truncate("hello", 3), "hel");
+    assert_eq!(truncate("hello", 10), "hello");
+    assert_eq!(truncate("e\u{301}e\u{301}", 1), "e\u{301}");
+    assert_eq!(truncate("日本語", 3), "日");
+    assert_eq!(truncate("\u{1f468}\u{200d}\u{1f469}x", 1), "");
+}
+
+#[test]
+fn truncate_with_leaves_room_for_the_ellipsis() {
+    assert_eq!(truncate_with("hello world", 8, "…"), "hello w…");
+    assert_eq!(truncate_with("hello", 5, "…"), "hello");
+    assert_eq!(truncate_with("日本語です", 7, "..."), "日本...");
+}
+
+#[test]
+fn ellipsis_wider_than_the_width_is_cut_too() {
+    assert_eq!(truncate_with("hello world", 2, "..."), "..");
+    assert_eq!(truncate_with("hello world", 0, "..."), "");
+    assert_eq!(truncate_with("日本語", 3, "〜〜"), "〜");
+    for width in 0..4 {
+        let fitted = fit("hello world", width, Align::Right, "[...]");
+        assert_eq!(display_width(&fitted), width, "{:?}", fitted);
+    }
+}
+
+#[test]
+fn pad_fills_out_to_the_width() {
+    assert_eq!(pad("日本", 6, Align::Left), "日本  ");
+    assert_eq!(pad("e\u{301}", 3, Align::Right), "  e\u{301}");
+    assert_eq!(pad("ab", 5, Align::Center), " ab  ");
+    assert_eq!(pad("toolong", 3, Align::Left), "toolong");
+}
+
+#[test]
+fn fit_gives_exact_columns() {
+    for s in &[
+        "Amélie",
+        "日本語のテキスト",
+        "\u{1f44d}\u{1f44d}\u{1f44d}\u{1f44d}",
+        "x",
+    ] {
+        assert_eq!(display_width(&fit(s, 5, Align::Left, "…")), 5, "{:?}", s);
+    }
+}
@@ -0,0 +1,235 @@
+// The `Car` and `Wheel` from main.rs, grown into an inventory. There a
+// wheel holds an `Rc<Car>`, so it knows its car but the car cannot list
+// its wheels. Giving the car `Rc`s to its wheels as well would make a
+// cycle of strong pointers that never reaches a count of zero, and
+// leaks. So here the car owns its wheels, and each wheel only has a
+// `Weak<Car>` back:
+//
+//     Car ──Rc──> RefCell<Wheel>
+//      ^               │
+//      └─────Weak──────┘
+//
+// A `Weak` does not keep the car alive. When the last `Rc<Car>` goes,
+// the car and its list of wheels are freed, and the wheels' `Weak`s
+// stop upgrading: those wheels are orphans.
+//
+//     use ownership::fleet::Fleet;
+//
+//     let mut fleet = Fleet::new();
+//     let car = fleet.add_car("MB");
+//     let wheel = fleet.add_wheel(360);
+//     fleet.attach(&wheel, &car).unwrap();
+//     assert_eq!(wheel.borrow().owner().unwrap().name(), "MB");
+
+use std::cell::RefCell;
+use std::error::Error;
+use std::fmt;
+use std::rc::{Rc, Weak};
+
+pub const WHEELS_PER_CAR: usize = 4;
+
+pub type WheelRef = Rc<RefCell<Wheel>>;
+
+#[derive(Debug)]
+pub struct Wheel {
+    serial: u32,
+    size: i32,
+    owner: Weak<Car>,
+}
+
+impl Wheel {
+    pub fn serial(&self) -> u32 {
+        self.serial
+    }
+
+    pub fn size(&self) -> i32 {
+        self.size
+    }
+
+    // The car the wheel is on, if any. `None` as well once the car has
+    // been dropped, even if nobody took the wheel off.
+    pub fn owner(&self) -> Option<Rc<Car>> {
+        self.owner.upgrade()
+    }
+
+    pub fn is_orphan(&self) -> bool {
+        self.owner().is_none()
+    }
+}
+
+#[derive(Debug)]
+pub struct Car {
+    name: String,
+    // A `RefCell` since cars are shared through `Rc` but still gain and
+    // lose wheels.
+    wheels: RefCell<Vec<WheelRef>>,
+}
+
+impl Car {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn wheels(&self) -> Vec<WheelRef> {
+        self.wheels.borrow().clone()
+    }
+
+    pub fn wheel_count(&self) -> usize {
+        self.wheels.borrow().len()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FleetError {
+    AlreadyAttached { wheel: u32, car: String },
+    NotAttached(u32),
+    CarFull(String),
+}
+
+impl fmt::Display for FleetError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            FleetError::AlreadyAttached { wheel, ref car } => {
+                write!(f, "wheel {} is already on {}", wheel, car)
+            }
+            FleetError::NotAttached(wheel) => write!(f, "wheel {} is not on a car", wheel),
+            FleetError::CarFull(ref car) => {
+                write!(f, "{} already has {} wheels", car, WHEELS_PER_CAR)
+            }
+        }
+    }
+}
+
+impl Error for FleetError {}
+
+// Every car and wheel in the fleet. The fleet keeps its own `Rc` to each
+// wheel, so wheels that come off a car are still accounted for.
+#[derive(Debug, Default)]
+pub struct Fleet {
+    cars: Vec<Rc<Car>>,
+    wheels: Vec<WheelRef>,
+    next_serial: u32,
+}
+
+impl Fleet {
+    pub fn new() -> Fleet {
+        Fleet::default()
+    }
+
+    pub fn add_car(&mut self, name: &str) -> Rc<Car> {
+        let car = Rc::new(Car {
+            name: name.to_string(),
+            wheels: RefCell::new(Vec::new()),
+        });
+        self.cars.push(car.clone());
+        car
+    }
+
+    // Adds a spare wheel, on no car yet.
+    pub fn add_wheel(&mut self, size: i32) -> WheelRef {
+        self.next_serial += 1;
+        let wheel = Rc::new(RefCell::new(Wheel {
+            serial: self.next_serial,
+            size,
+            owner: Weak::new(),
+        }));
+        self.wheels.push(wheel.clone());
+        wheel
+    }
+
+    pub fn cars(&self) -> &[Rc<Car>] {
+        &self.cars
+    }
+
+    pub fn wheels(&self) -> &[WheelRef] {
+        &self.wheels
+    }
+
+    pub fn attach(&self, wheel: &WheelRef, car: &Rc<Car>) -> Result<(), FleetError> {
+        if let Some(owner) = wheel.borrow().owner() {
+            return Err(FleetError::AlreadyAttached {
+                wheel: wheel.borrow().serial,
+                car: owner.name.clone(),
+            });
+        }
+        if car.wheel_count() >= WHEELS_PER_CAR {
+            return Err(FleetError::CarFull(car.name.clone()));
+        }
+        car.wheels.borrow_mut().push(wheel.clone());
+        wheel.borrow_mut().owner = Rc::downgrade(car);
+        Ok(())
+    }
+
+    // Takes the wheel off its car, returning the car.
+    pub fn detach(&self, wheel: &WheelRef) -> Result<Rc<Car>, FleetError> {
+        let car = match wheel.borrow().owner() {
+            Some(car) => car,
+            None => return Err(FleetError::NotAttached(wheel.borrow().serial)),
+        };
+        car.wheels.borrow_mut().retain(|w| !Rc::ptr_eq(w, wheel));
+        wheel.borrow_mut().owner = Weak::new();
+        Ok(car)
+    }
+
+    // Puts the wheel on `to`, taking it off the car it is on, if any.
+    // Nothing changes if `to` is full.
+    pub fn move_wheel(&self, wheel: &WheelRef, to: &Rc<Car>) -> Result<(), FleetError> {
+        if let Some(owner) = wheel.borrow().owner() {
+            if Rc::ptr_eq(&owner, to) {
+                return Ok(());
+            }
+        }
+        if to.wheel_count() >= WHEELS_PER_CAR {
+            return Err(FleetError::CarFull(to.name.clone()));
+        }
+        if !wheel.borrow().is_orphan() {
+            self.detach(wheel)?;
+        }
+        self.attach(wheel, to)
+    }
+
+    // Takes a car out of the fleet. Its wheels come off and stay in the
+    // fleet as spares.
+    pub fn remove_car(&mut self, car: &Rc<Car>) -> bool {
+        let before = self.cars.len();
+        self.cars.retain(|c| !Rc::ptr_eq(c, car));
+        if self.cars.len() == before {
+            return false;
+        }
+        for wheel in car.wheels.borrow_mut().drain(..) {
+            wheel.borrow_mut().owner = Weak::new();
+        }
+        true
+    }
+
+    // Wheels on no car: spares, and wheels taken off or left behind by a
+    // removed car.
+    pub fn orphans(&self) -> Vec<WheelRef> {
+        self.wheels
+            .iter()
+            .filter(|wheel| wheel.borrow().is_orphan())
+            .cloned()
+            .collect()
+    }
+}
+
+impl fmt::Display for Fleet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let serials = |wheels: &[WheelRef]| {
+            wheels
+                .iter()
+                .map(|w| w.borrow().serial.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        for car in &self.cars {
+            writeln!(
+                f,
+                "{}: wheels [{}]",
+                car.name,
+                serials(&car.wheels.borrow())
+            )?;
+        }
+        write!(f, "spare: wheels [{}]", serials(&self.orphans()))
+    }
+}
@@ -0,0 +1,5 @@
+// Ownership patterns that outgrow the examples in main.rs.
+
+pub mod fleet;
+pub mod records;
+pub mod sync_fleet;
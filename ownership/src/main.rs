@@ -1,3 +1,5 @@
+extern crate ownership;
+
 use std::rc::Rc;
 
 fn main() {
@@ -106,6 +108,22 @@ fn main() {
 
     println!("{}", f.x);
 
+    // The same idea with &'a str: records parsed from a log borrow their
+    // text from it instead of copying it into Strings. into_owned copies
+    // out the ones that must outlive the buffer.
+    let kept = {
+        let log = "t1 INFO web status=200\nt2 ERROR db error=\"timed out\"".to_string();
+        let mut errors = Vec::new();
+        for record in ownership::records::parse(&log) {
+            let record = record.unwrap();
+            if record.level() == ownership::records::Level::Error {
+                errors.push(record.into_owned());
+            }
+        }
+        errors
+    };
+    println!("{}", kept[0]);
+
     // Static lifetime
     // it signals that something has lifetime of entire program.
     let x: &'static str = "Yet another hello";
@@ -151,8 +169,51 @@ fn main() {
     // We need to use Rc<T> getting Rc<Car> we can use clone() to make new
     // references.
 
+    // A wheel with an Rc<Car> knows its car, but the car cannot also hold
+    // Rc<Wheel>s without a cycle that never gets freed. With the car
+    // owning its wheels and each wheel holding only a Weak<Car> back,
+    // both directions work:
+    {
+        let mut fleet = ownership::fleet::Fleet::new();
+        let car = fleet.add_car("MB");
+        for _ in 0..4 {
+            let wheel = fleet.add_wheel(360);
+            fleet.attach(&wheel, &car).unwrap();
+        }
+        fleet.add_wheel(360);
+        println!("{}", fleet);
+    }
+
     // Arc<T> could be used to make atomic instructions and be thread-safe
     // counterpar of Rc<T>.
+    //
+    // The same fleet with Arc, Weak from std::sync and locks in place of
+    // RefCell, so threads can move wheels between cars at once:
+    {
+        use std::sync::Arc;
+        use std::thread;
+
+        let fleet = Arc::new(ownership::sync_fleet::Fleet::new());
+        let cars = [fleet.add_car("MB"), fleet.add_car("VW")];
+        let wheels: Vec<_> = (0..4).map(|_| fleet.add_wheel(360)).collect();
+
+        let workers: Vec<_> = (0..2)
+            .map(|n| {
+                let fleet = fleet.clone();
+                let car = cars[n].clone();
+                let wheels = wheels.clone();
+                thread::spawn(move || {
+                    for wheel in &wheels {
+                        fleet.move_wheel(wheel, &car).unwrap();
+                    }
+                })
+            })
+            .collect();
+        for worker in workers {
+            worker.join().unwrap();
+        }
+        println!("{}", fleet);
+    }
 
     // Lifetime elision
     // Rust supports powerful local type inference in function bodies, but it's
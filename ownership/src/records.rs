@@ -0,0 +1,341 @@
+// `Foo<'a>` from main.rs, put to work: records parsed out of a log
+// without copying. Each `Record<'a>` only holds `&'a str`s pointing into
+// the buffer it was parsed from, so reading a log allocates no `String`s
+// at all, and the borrow checker makes sure no record outlives its
+// buffer. A record that has to be kept after the buffer goes, say to
+// collect errors across files, is copied out with `into_owned`.
+//
+// One record per line: a timestamp, a level, a target and then any
+// number of `key=value` fields. Values with spaces are quoted, and may
+// not contain quotes themselves. Blank lines and lines starting with `#`
+// are skipped.
+//
+//     2024-05-01T12:00:00Z INFO web status=200 path="/index.html"
+//
+//     use ownership::records::{self, Level};
+//
+//     let log = std::fs::read_to_string("web.log")?;
+//     for record in records::parse(&log) {
+//         let record = record?;
+//         if record.level() >= Level::Warn {
+//             println!("{}", record.field("path").unwrap_or("-"));
+//         }
+//     }
+
+use std::error::Error;
+use std::fmt;
+use std::str::Lines;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Level {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+impl Level {
+    // Upper or lower case, "INFO" or "info".
+    pub fn from_name(name: &str) -> Option<Level> {
+        let levels = [
+            Level::Trace,
+            Level::Debug,
+            Level::Info,
+            Level::Warn,
+            Level::Error,
+        ];
+        levels
+            .iter()
+            .find(|level| level.name().eq_ignore_ascii_case(name))
+            .cloned()
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Level::Trace => "TRACE",
+            Level::Debug => "DEBUG",
+            Level::Info => "INFO",
+            Level::Warn => "WARN",
+            Level::Error => "ERROR",
+        }
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+// Errors own their text, so they can be reported after the buffer is
+// gone.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    MissingField(&'static str),
+    UnknownLevel(String),
+    InvalidField(String),
+    UnclosedQuote,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub kind: ParseErrorKind,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}, column {}: ", self.line, self.column)?;
+        match self.kind {
+            ParseErrorKind::MissingField(field) => write!(f, "missing {}", field),
+            ParseErrorKind::UnknownLevel(ref level) => write!(f, "unknown level `{}`", level),
+            ParseErrorKind::InvalidField(ref field) => {
+                write!(f, "`{}` is not a key=value field", field)
+            }
+            ParseErrorKind::UnclosedQuote => write!(f, "quoted value is never closed"),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+// Column of `part`, which must be a slice of `line`.
+fn column(line: &str, part: &str) -> usize {
+    part.as_ptr() as usize - line.as_ptr() as usize + 1
+}
+
+// Splits off the first whitespace separated word of `rest`.
+fn next_word(rest: &str) -> (&str, &str) {
+    let rest = rest.trim_start();
+    let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+    rest.split_at(end)
+}
+
+// (key, value, what follows), or on error the part at fault
+type NextField<'a> = Result<Option<(&'a str, &'a str, &'a str)>, (&'a str, ParseErrorKind)>;
+
+// Splits off the first `key=value` field of `rest`.
+fn next_field(rest: &str) -> NextField<'_> {
+    let rest = rest.trim_start();
+    if rest.is_empty() {
+        return Ok(None);
+    }
+    let invalid = || {
+        let (word, _) = next_word(rest);
+        (rest, ParseErrorKind::InvalidField(word.to_string()))
+    };
+    let key_end = match rest.find(|c: char| c == '=' || c.is_whitespace()) {
+        Some(i) if i > 0 && rest[i..].starts_with('=') => i,
+        _ => return Err(invalid()),
+    };
+    let after = &rest[key_end + 1..];
+    let (value, following) = if let Some(quoted) = after.strip_prefix('"') {
+        match quoted.find('"') {
+            Some(end) => (&quoted[..end], &quoted[end + 1..]),
+            None => return Err((after, ParseErrorKind::UnclosedQuote)),
+        }
+    } else {
+        let end = after.find(char::is_whitespace).unwrap_or(after.len());
+        after.split_at(end)
+    };
+    if following.starts_with(|c: char| !c.is_whitespace()) {
+        return Err(invalid());
+    }
+    Ok(Some((&rest[..key_end], value, following)))
+}
+
+// One line of the log, borrowing from it. Every accessor hands out
+// `&'a str` rather than `&str` tied to the record, so a field can be
+// kept after the record itself is dropped, for as long as the buffer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Record<'a> {
+    line: usize,
+    timestamp: &'a str,
+    level: Level,
+    target: &'a str,
+    // Checked when parsed, and split into fields on demand rather than
+    // into a `Vec`, which would allocate after all
+    fields: &'a str,
+}
+
+impl<'a> Record<'a> {
+    // Parses a single record, as line 1.
+    pub fn parse(line: &'a str) -> Result<Record<'a>, ParseError> {
+        Record::parse_line(line, 1)
+    }
+
+    fn parse_line(text: &'a str, line: usize) -> Result<Record<'a>, ParseError> {
+        let error = |part: &str, kind| ParseError {
+            line,
+            column: column(text, part),
+            kind,
+        };
+        let (timestamp, rest) = next_word(text);
+        if timestamp.is_empty() {
+            return Err(error(rest, ParseErrorKind::MissingField("timestamp")));
+        }
+        let (level_name, rest) = next_word(rest);
+        if level_name.is_empty() {
+            return Err(error(rest, ParseErrorKind::MissingField("level")));
+        }
+        let level = Level::from_name(level_name).ok_or_else(|| {
+            error(
+                level_name,
+                ParseErrorKind::UnknownLevel(level_name.to_string()),
+            )
+        })?;
+        let (target, rest) = next_word(rest);
+        if target.is_empty() {
+            return Err(error(rest, ParseErrorKind::MissingField("target")));
+        }
+        let fields = rest.trim();
+        let mut unchecked = fields;
+        while let Some((_, _, following)) =
+            next_field(unchecked).map_err(|(part, kind)| error(part, kind))?
+        {
+            unchecked = following;
+        }
+        Ok(Record {
+            line,
+            timestamp,
+            level,
+            target,
+            fields,
+        })
+    }
+
+    // Line number in the input, from 1.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn timestamp(&self) -> &'a str {
+        self.timestamp
+    }
+
+    pub fn level(&self) -> Level {
+        self.level
+    }
+
+    pub fn target(&self) -> &'a str {
+        self.target
+    }
+
+    // The `key=value` fields in order, quotes removed.
+    pub fn fields(&self) -> Fields<'a> {
+        Fields { rest: self.fields }
+    }
+
+    // Value of the first field named `key`.
+    pub fn field(&self, key: &str) -> Option<&'a str> {
+        self.fields()
+            .find(|&(k, _)| k == key)
+            .map(|(_, value)| value)
+    }
+
+    // Copies the record out of the buffer.
+    pub fn into_owned(self) -> OwnedRecord {
+        OwnedRecord {
+            line: self.line,
+            timestamp: self.timestamp.to_string(),
+            level: self.level,
+            target: self.target.to_string(),
+            fields: self.fields.to_string(),
+        }
+    }
+}
+
+// Writes the record back in the log format, with single spaces and
+// values quoted only where needed.
+impl<'a> fmt::Display for Record<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {} {}", self.timestamp, self.level, self.target)?;
+        for (key, value) in self.fields() {
+            if value.is_empty() || value.contains(char::is_whitespace) {
+                write!(f, " {}=\"{}\"", key, value)?;
+            } else {
+                write!(f, " {}={}", key, value)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+pub struct Fields<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Iterator for Fields<'a> {
+    type Item = (&'a str, &'a str);
+
+    fn next(&mut self) -> Option<(&'a str, &'a str)> {
+        // Errors were ruled out when the record was parsed
+        match next_field(self.rest) {
+            Ok(Some((key, value, following))) => {
+                self.rest = following;
+                Some((key, value))
+            }
+            _ => None,
+        }
+    }
+}
+
+// A `Record` that owns its text, made by `Record::into_owned`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct OwnedRecord {
+    line: usize,
+    timestamp: String,
+    level: Level,
+    target: String,
+    fields: String,
+}
+
+impl OwnedRecord {
+    // Borrows the record again, for its accessors.
+    pub fn as_record(&self) -> Record<'_> {
+        Record {
+            line: self.line,
+            timestamp: &self.timestamp,
+            level: self.level,
+            target: &self.target,
+            fields: &self.fields,
+        }
+    }
+}
+
+impl fmt::Display for OwnedRecord {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.as_record().fmt(f)
+    }
+}
+
+// The records in `input`, one per line. A bad line is reported as an
+// error and parsing carries on with the next one.
+pub fn parse(input: &str) -> Records<'_> {
+    Records {
+        lines: input.lines(),
+        line: 0,
+    }
+}
+
+pub struct Records<'a> {
+    lines: Lines<'a>,
+    line: usize,
+}
+
+impl<'a> Iterator for Records<'a> {
+    type Item = Result<Record<'a>, ParseError>;
+
+    fn next(&mut self) -> Option<Result<Record<'a>, ParseError>> {
+        for text in &mut self.lines {
+            self.line += 1;
+            let trimmed = text.trim_start();
+            if !trimmed.is_empty() && !trimmed.starts_with('#') {
+                return Some(Record::parse_line(text, self.line));
+            }
+        }
+        None
+    }
+}
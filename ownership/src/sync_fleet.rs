@@ -0,0 +1,246 @@
+// The fleet from `fleet`, shared between threads. `Rc` becomes `Arc`,
+// `Weak` becomes `sync::Weak`, and each `RefCell` becomes a lock:
+//
+// - a car's list of wheels is behind a `RwLock`, as it is read more
+//   often than changed
+// - a wheel's owner is behind a `Mutex`
+//
+// The whole `Fleet` goes in an `Arc` and every method takes `&self`, so
+// worker threads can move wheels around at the same time.
+//
+// Moving a wheel has to hold three locks at once: the wheel, the car it
+// comes off and the car it goes on. Two threads taking the same locks
+// in different orders could each wait for the other forever, so every
+// method here takes them in one fixed order, and callers never see the
+// locks at all:
+//
+// 1. the fleet's own lists of cars and wheels
+// 2. a wheel's owner
+// 3. cars' wheel lists, lowest car id first
+//
+// A lock is poisoned when a thread panics holding it; the methods here
+// never panic with a lock held, so they treat poisoning as a bug and
+// panic in turn.
+
+use std::fmt;
+use std::sync::atomic::{AtomicU32, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, RwLock, RwLockWriteGuard, Weak};
+
+pub use fleet::{FleetError, WHEELS_PER_CAR};
+
+// Shared by every fleet, so that ids order cars across fleets too.
+static NEXT_CAR_ID: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Debug)]
+pub struct Wheel {
+    serial: u32,
+    size: i32,
+    owner: Mutex<Weak<Car>>,
+}
+
+impl Wheel {
+    pub fn serial(&self) -> u32 {
+        self.serial
+    }
+
+    pub fn size(&self) -> i32 {
+        self.size
+    }
+
+    pub fn owner(&self) -> Option<Arc<Car>> {
+        self.owner.lock().unwrap().upgrade()
+    }
+
+    pub fn is_orphan(&self) -> bool {
+        self.owner().is_none()
+    }
+}
+
+#[derive(Debug)]
+pub struct Car {
+    id: usize,
+    name: String,
+    wheels: RwLock<Vec<Arc<Wheel>>>,
+}
+
+impl Car {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    // A snapshot: other threads may have moved wheels by the time it is
+    // looked at.
+    pub fn wheels(&self) -> Vec<Arc<Wheel>> {
+        self.wheels.read().unwrap().clone()
+    }
+
+    pub fn wheel_count(&self) -> usize {
+        self.wheels.read().unwrap().len()
+    }
+}
+
+type WheelsGuard<'a> = RwLockWriteGuard<'a, Vec<Arc<Wheel>>>;
+
+// Write locks the wheel lists of `from`, if any, and `to`, in car id
+// order. `from` and `to` must be different cars.
+fn lock_cars<'a>(from: Option<&'a Car>, to: &'a Car) -> (Option<WheelsGuard<'a>>, WheelsGuard<'a>) {
+    match from {
+        Some(from) if from.id < to.id => {
+            let from = from.wheels.write().unwrap();
+            (Some(from), to.wheels.write().unwrap())
+        }
+        Some(from) => {
+            let to = to.wheels.write().unwrap();
+            (Some(from.wheels.write().unwrap()), to)
+        }
+        None => (None, to.wheels.write().unwrap()),
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Fleet {
+    cars: RwLock<Vec<Arc<Car>>>,
+    wheels: RwLock<Vec<Arc<Wheel>>>,
+    next_serial: AtomicU32,
+}
+
+impl Fleet {
+    pub fn new() -> Fleet {
+        Fleet::default()
+    }
+
+    pub fn add_car(&self, name: &str) -> Arc<Car> {
+        let car = Arc::new(Car {
+            id: NEXT_CAR_ID.fetch_add(1, Ordering::Relaxed),
+            name: name.to_string(),
+            wheels: RwLock::new(Vec::new()),
+        });
+        self.cars.write().unwrap().push(car.clone());
+        car
+    }
+
+    pub fn add_wheel(&self, size: i32) -> Arc<Wheel> {
+        let wheel = Arc::new(Wheel {
+            serial: self.next_serial.fetch_add(1, Ordering::Relaxed) + 1,
+            size,
+            owner: Mutex::new(Weak::new()),
+        });
+        self.wheels.write().unwrap().push(wheel.clone());
+        wheel
+    }
+
+    pub fn cars(&self) -> Vec<Arc<Car>> {
+        self.cars.read().unwrap().clone()
+    }
+
+    pub fn wheels(&self) -> Vec<Arc<Wheel>> {
+        self.wheels.read().unwrap().clone()
+    }
+
+    pub fn attach(&self, wheel: &Arc<Wheel>, car: &Arc<Car>) -> Result<(), FleetError> {
+        let mut owner = wheel.owner.lock().unwrap();
+        if let Some(current) = owner.upgrade() {
+            return Err(FleetError::AlreadyAttached {
+                wheel: wheel.serial,
+                car: current.name.clone(),
+            });
+        }
+        let mut wheels = car.wheels.write().unwrap();
+        if wheels.len() >= WHEELS_PER_CAR {
+            return Err(FleetError::CarFull(car.name.clone()));
+        }
+        wheels.push(wheel.clone());
+        *owner = Arc::downgrade(car);
+        Ok(())
+    }
+
+    // Takes the wheel off its car, returning the car.
+    pub fn detach(&self, wheel: &Arc<Wheel>) -> Result<Arc<Car>, FleetError> {
+        let mut owner = wheel.owner.lock().unwrap();
+        let car = owner
+            .upgrade()
+            .ok_or(FleetError::NotAttached(wheel.serial))?;
+        car.wheels
+            .write()
+            .unwrap()
+            .retain(|w| !Arc::ptr_eq(w, wheel));
+        *owner = Weak::new();
+        Ok(car)
+    }
+
+    // Puts the wheel on `to`, taking it off the car it is on, if any, in
+    // one step: no other thread sees it on both cars or on neither.
+    // Nothing changes if `to` is full.
+    pub fn move_wheel(&self, wheel: &Arc<Wheel>, to: &Arc<Car>) -> Result<(), FleetError> {
+        let mut owner = wheel.owner.lock().unwrap();
+        let from = owner.upgrade();
+        if from.as_ref().is_some_and(|from| Arc::ptr_eq(from, to)) {
+            return Ok(());
+        }
+        let (from_wheels, mut to_wheels) = lock_cars(from.as_deref(), to);
+        if to_wheels.len() >= WHEELS_PER_CAR {
+            return Err(FleetError::CarFull(to.name.clone()));
+        }
+        if let Some(mut from_wheels) = from_wheels {
+            from_wheels.retain(|w| !Arc::ptr_eq(w, wheel));
+        }
+        to_wheels.push(wheel.clone());
+        *owner = Arc::downgrade(to);
+        Ok(())
+    }
+
+    // Takes a car out of the fleet. Its wheels come off and stay in the
+    // fleet as spares.
+    pub fn remove_car(&self, car: &Arc<Car>) -> bool {
+        {
+            let mut cars = self.cars.write().unwrap();
+            let before = cars.len();
+            cars.retain(|c| !Arc::ptr_eq(c, car));
+            if cars.len() == before {
+                return false;
+            }
+        }
+        // One wheel at a time, wheel before car, as `detach` does. A
+        // wheel another thread moved off in the meantime is left alone.
+        for wheel in car.wheels() {
+            let mut owner = wheel.owner.lock().unwrap();
+            if owner
+                .upgrade()
+                .is_some_and(|owner| Arc::ptr_eq(&owner, car))
+            {
+                car.wheels
+                    .write()
+                    .unwrap()
+                    .retain(|w| !Arc::ptr_eq(w, &wheel));
+                *owner = Weak::new();
+            }
+        }
+        true
+    }
+
+    pub fn orphans(&self) -> Vec<Arc<Wheel>> {
+        self.wheels
+            .read()
+            .unwrap()
+            .iter()
+            .filter(|wheel| wheel.is_orphan())
+            .cloned()
+            .collect()
+    }
+}
+
+impl fmt::Display for Fleet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let serials = |wheels: &[Arc<Wheel>]| {
+            wheels
+                .iter()
+                .map(|w| w.serial.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        for car in self.cars() {
+            writeln!(f, "{}: wheels [{}]", car.name, serials(&car.wheels()))?;
+        }
+        write!(f, "spare: wheels [{}]", serials(&self.orphans()))
+    }
+}
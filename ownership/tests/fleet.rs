@@ -0,0 +1,141 @@
+extern crate ownership;
+
+use ownership::fleet::{Fleet, FleetError, WheelRef, WHEELS_PER_CAR};
+use std::rc::Rc;
+
+fn serials(wheels: &[WheelRef]) -> Vec<u32> {
+    wheels.iter().map(|w| w.borrow().serial()).collect()
+}
+
+#[test]
+fn cars_list_their_wheels_and_wheels_know_their_car() {
+    let mut fleet = Fleet::new();
+    let car = fleet.add_car("MB");
+    let wheels: Vec<_> = (0..4).map(|_| fleet.add_wheel(360)).collect();
+    for wheel in &wheels {
+        fleet.attach(wheel, &car).unwrap();
+    }
+    assert_eq!(serials(&car.wheels()), [1, 2, 3, 4]);
+    for wheel in &wheels {
+        let owner = wheel.borrow().owner().unwrap();
+        assert!(Rc::ptr_eq(&owner, &car));
+        assert_eq!(wheel.borrow().size(), 360);
+    }
+    assert!(fleet.orphans().is_empty());
+}
+
+#[test]
+fn refuses_double_attachment_and_full_cars() {
+    let mut fleet = Fleet::new();
+    let a = fleet.add_car("A");
+    let b = fleet.add_car("B");
+    let wheels: Vec<_> = (0..WHEELS_PER_CAR + 1)
+        .map(|_| fleet.add_wheel(17))
+        .collect();
+    for wheel in &wheels[..WHEELS_PER_CAR] {
+        fleet.attach(wheel, &a).unwrap();
+    }
+    let extra = &wheels[WHEELS_PER_CAR];
+    assert_eq!(
+        fleet.attach(extra, &a),
+        Err(FleetError::CarFull("A".to_string()))
+    );
+    let error = fleet.attach(&wheels[0], &b).unwrap_err();
+    assert_eq!(error.to_string(), "wheel 1 is already on A");
+    assert_eq!(fleet.detach(extra).unwrap_err(), FleetError::NotAttached(5));
+    assert_eq!(
+        fleet.move_wheel(extra, &a),
+        Err(FleetError::CarFull("A".to_string()))
+    );
+    assert!(extra.borrow().is_orphan());
+}
+
+#[test]
+fn moves_wheels_between_cars() {
+    let mut fleet = Fleet::new();
+    let a = fleet.add_car("A");
+    let b = fleet.add_car("B");
+    let w1 = fleet.add_wheel(16);
+    let w2 = fleet.add_wheel(16);
+    fleet.attach(&w1, &a).unwrap();
+    fleet.attach(&w2, &a).unwrap();
+
+    fleet.move_wheel(&w1, &b).unwrap();
+    assert_eq!(serials(&a.wheels()), [2]);
+    assert_eq!(serials(&b.wheels()), [1]);
+    assert!(Rc::ptr_eq(&w1.borrow().owner().unwrap(), &b));
+
+    // Moving to the car it is already on changes nothing
+    fleet.move_wheel(&w1, &b).unwrap();
+    assert_eq!(serials(&b.wheels()), [1]);
+
+    let from = fleet.detach(&w2).unwrap();
+    assert!(Rc::ptr_eq(&from, &a));
+    assert_eq!(serials(&fleet.orphans()), [2]);
+    fleet.move_wheel(&w2, &b).unwrap();
+    assert_eq!(serials(&b.wheels()), [1, 2]);
+    assert_eq!(
+        fleet.to_string(),
+        "A: wheels []\nB: wheels [1, 2]\nspare: wheels []"
+    );
+}
+
+#[test]
+fn removing_a_car_leaves_its_wheels_as_spares() {
+    let mut fleet = Fleet::new();
+    let a = fleet.add_car("A");
+    let b = fleet.add_car("B");
+    let wheel = fleet.add_wheel(16);
+    fleet.attach(&wheel, &a).unwrap();
+    assert!(fleet.remove_car(&a));
+    assert!(!fleet.remove_car(&a));
+    assert_eq!(a.wheel_count(), 0);
+    assert_eq!(fleet.cars().len(), 1);
+    assert_eq!(serials(&fleet.orphans()), [1]);
+    fleet.attach(&wheel, &b).unwrap();
+}
+
+#[test]
+fn wheels_of_a_dropped_car_become_orphans() {
+    let mut fleet = Fleet::new();
+    let wheel = fleet.add_wheel(16);
+    {
+        let mut other = Fleet::new();
+        let car = other.add_car("borrowed");
+        other.attach(&wheel, &car).unwrap();
+        assert!(!wheel.borrow().is_orphan());
+    }
+    // The car is gone with its fleet, and the weak link no longer upgrades
+    assert!(wheel.borrow().is_orphan());
+    assert_eq!(serials(&fleet.orphans()), [1]);
+    let car = fleet.add_car("own");
+    fleet.attach(&wheel, &car).unwrap();
+}
+
+#[test]
+fn counts_show_nothing_leaks() {
+    let mut fleet = Fleet::new();
+    let car = fleet.add_car("MB");
+    let wheels: Vec<_> = (0..4).map(|_| fleet.add_wheel(360)).collect();
+    for wheel in &wheels {
+        fleet.attach(wheel, &car).unwrap();
+    }
+    // The fleet and `car`; one weak pointer from every wheel
+    assert_eq!(Rc::strong_count(&car), 2);
+    assert_eq!(Rc::weak_count(&car), 4);
+    // The fleet, the car and `wheels`
+    assert_eq!(Rc::strong_count(&wheels[0]), 3);
+    assert_eq!(Rc::weak_count(&wheels[0]), 0);
+
+    fleet.detach(&wheels[0]).unwrap();
+    assert_eq!(Rc::weak_count(&car), 3);
+    assert_eq!(Rc::strong_count(&wheels[0]), 2);
+
+    let weak_car = Rc::downgrade(&car);
+    let weak_wheels: Vec<_> = wheels.iter().map(Rc::downgrade).collect();
+    drop((car, wheels));
+    drop(fleet);
+    // Without a cycle of strong pointers, dropping the fleet frees it all
+    assert!(weak_car.upgrade().is_none());
+    assert!(weak_wheels.iter().all(|wheel| wheel.upgrade().is_none()));
+}
@@ -0,0 +1,115 @@
+extern crate ownership;
+
+use ownership::records::{self, Level, OwnedRecord, ParseError, ParseErrorKind, Record};
+
+const LOG: &str = "\
+# web server, 1 May
+2024-05-01T12:00:00Z INFO web status=200 path=/index.html ms=13
+
+2024-05-01T12:00:01Z warn web status=404 path=\"/no such page\"
+2024-05-01T12:00:02Z ERROR db query=\"select 1\" error=\"\"
+";
+
+fn inside(part: &str, buffer: &str) -> bool {
+    let start = buffer.as_ptr() as usize;
+    let at = part.as_ptr() as usize;
+    at >= start && at + part.len() <= start + buffer.len()
+}
+
+#[test]
+fn records_borrow_from_the_buffer() {
+    let records: Vec<Record> = records::parse(LOG).map(Result::unwrap).collect();
+    assert_eq!(records.len(), 3);
+
+    let first = records[0];
+    assert_eq!(first.timestamp(), "2024-05-01T12:00:00Z");
+    assert_eq!(first.level(), Level::Info);
+    assert_eq!(first.target(), "web");
+    assert_eq!(
+        first.fields().collect::<Vec<_>>(),
+        vec![("status", "200"), ("path", "/index.html"), ("ms", "13")]
+    );
+    for record in &records {
+        assert!(inside(record.timestamp(), LOG));
+        assert!(inside(record.target(), LOG));
+        for (key, value) in record.fields() {
+            assert!(inside(key, LOG) && inside(value, LOG));
+        }
+    }
+}
+
+#[test]
+fn line_numbers_count_skipped_lines() {
+    let lines: Vec<usize> = records::parse(LOG).map(|r| r.unwrap().line()).collect();
+    assert_eq!(lines, vec![2, 4, 5]);
+}
+
+#[test]
+fn quoted_values_may_hold_spaces_or_nothing() {
+    let records: Vec<Record> = records::parse(LOG).map(Result::unwrap).collect();
+    assert_eq!(records[1].level(), Level::Warn);
+    assert_eq!(records[1].field("path"), Some("/no such page"));
+    assert_eq!(records[2].field("query"), Some("select 1"));
+    assert_eq!(records[2].field("error"), Some(""));
+    assert_eq!(records[2].field("missing"), None);
+}
+
+#[test]
+fn fields_outlive_their_record() {
+    let path = {
+        let record = Record::parse("t INFO web path=/a").unwrap();
+        record.field("path").unwrap()
+    };
+    assert_eq!(path, "/a");
+}
+
+#[test]
+fn bad_lines_are_reported_and_skipped() {
+    let log = "t INFO web ok=1\nt LOUD web\nt INFO\nt INFO web a=\"open\nt INFO web =1 b=2\nt INFO web a=\"x\"y\nt INFO web a=1";
+    let results: Vec<Result<Record, ParseError>> = records::parse(log).collect();
+    let errors: Vec<(usize, usize, ParseErrorKind)> = results
+        .iter()
+        .filter_map(|r| r.as_ref().err())
+        .map(|e| (e.line, e.column, e.kind.clone()))
+        .collect();
+    assert_eq!(
+        errors,
+        vec![
+            (2, 3, ParseErrorKind::UnknownLevel("LOUD".to_string())),
+            (3, 7, ParseErrorKind::MissingField("target")),
+            (4, 14, ParseErrorKind::UnclosedQuote),
+            (5, 12, ParseErrorKind::InvalidField("=1".to_string())),
+            (6, 12, ParseErrorKind::InvalidField("a=\"x\"y".to_string())),
+        ]
+    );
+    assert_eq!(results.iter().filter(|r| r.is_ok()).count(), 2);
+    assert_eq!(
+        results[1].as_ref().unwrap_err().to_string(),
+        "line 2, column 3: unknown level `LOUD`"
+    );
+}
+
+#[test]
+fn into_owned_outlives_the_buffer() {
+    let kept: Vec<OwnedRecord> = {
+        let buffer = LOG.to_string();
+        records::parse(&buffer)
+            .map(Result::unwrap)
+            .filter(|record| record.level() >= Level::Warn)
+            .map(Record::into_owned)
+            .collect()
+    };
+    assert_eq!(kept.len(), 2);
+    assert_eq!(kept[0].as_record().field("status"), Some("404"));
+    assert_eq!(kept[1].as_record().line(), 5);
+    assert_eq!(
+        kept[0].to_string(),
+        "2024-05-01T12:00:01Z WARN web status=404 path=\"/no such page\""
+    );
+    let line = kept[1].to_string();
+    let reparsed = Record::parse(&line).unwrap();
+    assert_eq!(
+        reparsed.fields().collect::<Vec<_>>(),
+        kept[1].as_record().fields().collect::<Vec<_>>()
+    );
+}
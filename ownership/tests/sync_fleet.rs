@@ -0,0 +1,147 @@
+extern crate ownership;
+
+use ownership::sync_fleet::{Fleet, FleetError, WHEELS_PER_CAR};
+use std::collections::HashSet;
+use std::sync::mpsc;
+use std::sync::{Arc, Barrier};
+use std::thread;
+use std::time::Duration;
+
+// Small xorshift generator, so that each thread's moves are the same
+// every run. Only the interleaving changes.
+struct Random(u64);
+
+impl Random {
+    fn below(&mut self, n: usize) -> usize {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 % n as u64) as usize
+    }
+}
+
+// Every wheel is on exactly the car it names as its owner, or on none.
+fn check_consistent(fleet: &Fleet) {
+    let mut seen = HashSet::new();
+    for car in fleet.cars() {
+        let wheels = car.wheels();
+        assert!(wheels.len() <= WHEELS_PER_CAR);
+        for wheel in wheels {
+            assert!(
+                seen.insert(wheel.serial()),
+                "wheel {} on two cars",
+                wheel.serial()
+            );
+            assert!(Arc::ptr_eq(&wheel.owner().unwrap(), &car));
+        }
+    }
+    for wheel in fleet.orphans() {
+        assert!(seen.insert(wheel.serial()));
+    }
+    assert_eq!(seen.len(), fleet.wheels().len());
+}
+
+#[test]
+fn behaves_like_the_single_threaded_fleet() {
+    let fleet = Fleet::new();
+    let a = fleet.add_car("A");
+    let b = fleet.add_car("B");
+    let wheel = fleet.add_wheel(16);
+    fleet.attach(&wheel, &a).unwrap();
+    assert_eq!(
+        fleet.attach(&wheel, &b),
+        Err(FleetError::AlreadyAttached {
+            wheel: 1,
+            car: "A".to_string()
+        })
+    );
+    fleet.move_wheel(&wheel, &b).unwrap();
+    assert_eq!((a.wheel_count(), b.wheel_count()), (0, 1));
+    assert!(Arc::ptr_eq(&fleet.detach(&wheel).unwrap(), &b));
+    assert_eq!(fleet.detach(&wheel).err(), Some(FleetError::NotAttached(1)));
+
+    fleet.attach(&wheel, &a).unwrap();
+    assert!(fleet.remove_car(&a));
+    assert!(wheel.is_orphan());
+    assert_eq!(fleet.to_string(), "B: wheels []\nspare: wheels [1]");
+}
+
+#[test]
+fn many_threads_reassign_wheels_without_deadlock() {
+    const THREADS: usize = 8;
+    const MOVES: usize = 5000;
+
+    let fleet = Arc::new(Fleet::new());
+    for name in &["A", "B", "C", "D"] {
+        fleet.add_car(name);
+    }
+    for _ in 0..14 {
+        fleet.add_wheel(16);
+    }
+
+    // A deadlock would hang the test, so the work runs on its own
+    // thread and the test gives up on it after a while.
+    let (done, finished) = mpsc::channel();
+    let worker_fleet = fleet.clone();
+    thread::spawn(move || {
+        let fleet = worker_fleet;
+        let start = Arc::new(Barrier::new(THREADS));
+        let workers: Vec<_> = (0..THREADS)
+            .map(|n| {
+                let fleet = fleet.clone();
+                let start = start.clone();
+                thread::spawn(move || {
+                    let mut random = Random(0x9e37_79b9_7f4a_7c15 ^ (n as u64 + 1));
+                    let cars = fleet.cars();
+                    let wheels = fleet.wheels();
+                    start.wait();
+                    for _ in 0..MOVES {
+                        let wheel = &wheels[random.below(wheels.len())];
+                        let car = &cars[random.below(cars.len())];
+                        let result = match random.below(10) {
+                            0 => fleet.detach(wheel).map(|_| ()),
+                            1 => fleet.attach(wheel, car),
+                            _ => fleet.move_wheel(wheel, car),
+                        };
+                        match result {
+                            Ok(())
+                            | Err(FleetError::CarFull(_))
+                            | Err(FleetError::NotAttached(_))
+                            | Err(FleetError::AlreadyAttached { .. }) => {}
+                        }
+                        assert!(car.wheel_count() <= WHEELS_PER_CAR);
+                    }
+                })
+            })
+            .collect();
+        for worker in workers {
+            worker.join().unwrap();
+        }
+        done.send(()).unwrap();
+    });
+    finished
+        .recv_timeout(Duration::from_secs(60))
+        .expect("worker threads deadlocked or panicked");
+
+    check_consistent(&fleet);
+}
+
+#[test]
+fn dropping_the_fleet_frees_everything() {
+    let fleet = Arc::new(Fleet::new());
+    let car = fleet.add_car("A");
+    let wheel = fleet.add_wheel(16);
+    fleet.attach(&wheel, &car).unwrap();
+
+    let weak_car = Arc::downgrade(&car);
+    let weak_wheel = Arc::downgrade(&wheel);
+    assert_eq!(Arc::weak_count(&car), 2);
+    drop((car, wheel));
+
+    let other = fleet.clone();
+    thread::spawn(move || drop(other)).join().unwrap();
+    assert!(weak_car.upgrade().is_some());
+    drop(fleet);
+    assert!(weak_car.upgrade().is_none());
+    assert!(weak_wheel.upgrade().is_none());
+}
@@ -0,0 +1,602 @@
+// Shell-style patterns for paths, such as `src/**/*.rs`, compiled once
+// into a deterministic automaton. Matching then looks at each character
+// of the path once, with no backtracking, however many stars the
+// pattern has:
+//
+//     use patterns::glob::{Case, Glob};
+//
+//     let rust = Glob::compile("{src,tests}/**/*.rs")?;
+//     assert!(rust.is_match("src/glob.rs"));
+//     assert!(rust.is_match("tests/deep/down/glob.rs"));
+//     assert!(!rust.is_match("target/debug/build.rs"));
+//
+//     let images = Glob::compile_with("*.{png,jp[e]g}", Case::Insensitive)?;
+//     assert!(images.is_match("Photo.JPEG"));
+//
+// Paths are split on `/` only, so Windows paths need their `\`s turned
+// round first. The syntax, as in most tools:
+//
+//     *         any run of characters within one path segment
+//     ?         one character other than `/`
+//     [a-z]     one character in the class; `[!a-z]` or `[^a-z]` negates
+//     {a,b}     either alternative, which may hold any of these, nested
+//     **        as a whole segment, any number of segments:
+//                 `**/x` is `x` at any depth, `a/**/b` includes `a/b`,
+//                 `a/**` is everything inside `a` but not `a` itself
+//     \*        a literal `*`, and so on for other characters
+//
+// Elsewhere `**` is the same as `*`. Classes never match `/`, even
+// when negated, and `,` and `}` outside braces are literals. Dot files
+// are not treated specially.
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Case {
+    Sensitive,
+    Insensitive,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GlobErrorKind {
+    UnclosedClass,
+    UnclosedBrace,
+    InvalidRange(char, char),
+    DanglingEscape,
+    // The automaton would be too big to build, as it can be for some
+    // patterns with many alternatives and stars
+    TooComplex,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlobError {
+    // Of the character at fault, counting from 1
+    pub column: usize,
+    pub kind: GlobErrorKind,
+}
+
+impl fmt::Display for GlobError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "column {}: ", self.column)?;
+        match self.kind {
+            GlobErrorKind::UnclosedClass => write!(f, "`[` is never closed"),
+            GlobErrorKind::UnclosedBrace => write!(f, "`{{` is never closed"),
+            GlobErrorKind::InvalidRange(from, to) => {
+                write!(f, "range `{}-{}` is out of order", from, to)
+            }
+            GlobErrorKind::DanglingEscape => write!(f, "`\\` at the end escapes nothing"),
+            GlobErrorKind::TooComplex => write!(f, "pattern is too complex"),
+        }
+    }
+}
+
+impl Error for GlobError {}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Node {
+    Literal(char),
+    // `?`
+    AnyChar,
+    // `*`, and `**` where it is not a whole segment
+    Star,
+    Class {
+        ranges: Vec<(char, char)>,
+        negated: bool,
+    },
+    Alternatives(Vec<Vec<Node>>),
+    // `**/`: nothing, or anything ending in `/`
+    AnyDirectories,
+    // `/**` at the end: at least one more character
+    AnyInside,
+    // `**` on its own: anything at all
+    Anything,
+}
+
+// Where a sequence begins: `**` spans segments only when it begins one.
+#[derive(Clone, Copy, PartialEq)]
+enum Start {
+    Pattern,
+    Segment,
+    Inside,
+}
+
+struct Parser {
+    chars: Vec<char>,
+    position: usize,
+}
+
+impl Parser {
+    fn error(&self, position: usize, kind: GlobErrorKind) -> GlobError {
+        GlobError {
+            column: position + 1,
+            kind,
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).cloned()
+    }
+
+    // Until the end of the pattern, or of a branch inside braces.
+    fn sequence(&mut self, in_braces: bool, start: Start) -> Result<Vec<Node>, GlobError> {
+        let mut nodes = Vec::new();
+        while let Some(c) = self.peek() {
+            if in_braces && (c == ',' || c == '}') {
+                break;
+            }
+            let at = self.position;
+            self.position += 1;
+            let node = match c {
+                '\\' => match self.peek() {
+                    Some(escaped) => {
+                        self.position += 1;
+                        Node::Literal(escaped)
+                    }
+                    None => return Err(self.error(at, GlobErrorKind::DanglingEscape)),
+                },
+                '?' => Node::AnyChar,
+                '*' if self.peek() == Some('*') => {
+                    self.position += 1;
+                    self.globstar(here(start, &nodes), in_braces)
+                }
+                '*' => Node::Star,
+                '[' => self.class(at)?,
+                '{' => self.alternatives(at, here(start, &nodes))?,
+                c => Node::Literal(c),
+            };
+            nodes.push(node);
+        }
+        Ok(nodes)
+    }
+
+    // What `**` means, given what came before it and after.
+    fn globstar(&mut self, start: Start, in_braces: bool) -> Node {
+        if start == Start::Inside {
+            return Node::Star;
+        }
+        let at_end = match self.peek() {
+            None => true,
+            Some(',') | Some('}') => in_braces,
+            Some(_) => false,
+        };
+        if self.peek() == Some('/') {
+            self.position += 1;
+            Node::AnyDirectories
+        } else if !at_end {
+            Node::Star
+        } else if start == Start::Pattern {
+            Node::Anything
+        } else {
+            Node::AnyInside
+        }
+    }
+
+    fn class(&mut self, open: usize) -> Result<Node, GlobError> {
+        let negated = match self.peek() {
+            Some('!') | Some('^') => {
+                self.position += 1;
+                true
+            }
+            _ => false,
+        };
+        let mut ranges = Vec::new();
+        loop {
+            let at = self.position;
+            let from = match self.peek() {
+                // A `]` straight after the `[` is part of the class
+                Some(']') if !ranges.is_empty() => {
+                    self.position += 1;
+                    return Ok(Node::Class { ranges, negated });
+                }
+                Some(_) => self.class_char(open)?,
+                None => return Err(self.error(open, GlobErrorKind::UnclosedClass)),
+            };
+            let is_range = self.peek() == Some('-')
+                && self.chars.get(self.position + 1).is_some_and(|&c| c != ']');
+            if is_range {
+                self.position += 1;
+                let to = self.class_char(open)?;
+                if from > to {
+                    return Err(self.error(at, GlobErrorKind::InvalidRange(from, to)));
+                }
+                ranges.push((from, to));
+            } else {
+                ranges.push((from, from));
+            }
+        }
+    }
+
+    fn class_char(&mut self, open: usize) -> Result<char, GlobError> {
+        let mut c = self.peek();
+        if c == Some('\\') {
+            self.position += 1;
+            c = self.peek();
+        }
+        self.position += 1;
+        c.ok_or_else(|| self.error(open, GlobErrorKind::UnclosedClass))
+    }
+
+    // Each branch begins where the `{` is.
+    fn alternatives(&mut self, open: usize, start: Start) -> Result<Node, GlobError> {
+        let mut branches = Vec::new();
+        loop {
+            branches.push(self.sequence(true, start)?);
+            let c = self.peek();
+            self.position += 1;
+            match c {
+                Some(',') => {}
+                Some('}') => return Ok(Node::Alternatives(branches)),
+                _ => return Err(self.error(open, GlobErrorKind::UnclosedBrace)),
+            }
+        }
+    }
+}
+
+// Where the next node begins, after `nodes` in a sequence that began at
+// `start`.
+fn here(start: Start, nodes: &[Node]) -> Start {
+    match nodes.last() {
+        None => start,
+        Some(&Node::Literal('/')) => Start::Segment,
+        Some(_) => Start::Inside,
+    }
+}
+
+// Sets of characters as sorted, disjoint, inclusive ranges of code
+// points. Code points rather than `char`s, so that ranges may span the
+// surrogates, which no `str` holds anyway.
+type CharSet = Vec<(u32, u32)>;
+
+const MAX_CHAR: u32 = char::MAX as u32;
+const SLASH: u32 = '/' as u32;
+
+fn normalize(mut set: CharSet) -> CharSet {
+    set.sort_unstable();
+    let mut merged: CharSet = Vec::with_capacity(set.len());
+    for (from, to) in set {
+        match merged.last_mut() {
+            Some(last) if from <= last.1.saturating_add(1) => last.1 = last.1.max(to),
+            _ => merged.push((from, to)),
+        }
+    }
+    merged
+}
+
+fn complement(set: &[(u32, u32)]) -> CharSet {
+    let mut result = Vec::new();
+    let mut next = 0;
+    for &(from, to) in set {
+        if from > next {
+            result.push((next, from - 1));
+        }
+        next = to + 1;
+    }
+    if next <= MAX_CHAR {
+        result.push((next, MAX_CHAR));
+    }
+    result
+}
+
+fn without_slash(set: &[(u32, u32)]) -> CharSet {
+    let mut others = complement(set);
+    others.push((SLASH, SLASH));
+    complement(&normalize(others))
+}
+
+// Input is compared in lower case when case does not matter. Characters
+// whose lower case is more than one character are left alone.
+fn fold(c: char) -> char {
+    let mut lower = c.to_lowercase();
+    match (lower.next(), lower.next()) {
+        (Some(lower), None) => lower,
+        _ => c,
+    }
+}
+
+fn class_set(ranges: &[(char, char)], negated: bool, case: Case) -> CharSet {
+    let mut set: CharSet = ranges
+        .iter()
+        .map(|&(from, to)| (from as u32, to as u32))
+        .collect();
+    if case == Case::Insensitive {
+        // Folded input matches if any character of the class folds to it
+        let folded: Vec<(u32, u32)> = ranges
+            .iter()
+            .flat_map(|&(from, to)| from..=to)
+            .map(fold)
+            .map(|c| (c as u32, c as u32))
+            .collect();
+        set.extend(folded);
+    }
+    let set = normalize(set);
+    without_slash(&if negated { complement(&set) } else { set })
+}
+
+#[derive(Default)]
+struct NfaState {
+    edges: Vec<(CharSet, usize)>,
+    epsilon: Vec<usize>,
+}
+
+// A nondeterministic automaton: the pattern's structure, state for
+// state, before `Dfa::build` makes it fast.
+struct Nfa {
+    states: Vec<NfaState>,
+    case: Case,
+}
+
+impl Nfa {
+    fn state(&mut self) -> usize {
+        self.states.push(NfaState::default());
+        self.states.len() - 1
+    }
+
+    fn edge(&mut self, from: usize, set: CharSet, to: usize) {
+        self.states[from].edges.push((set, to));
+    }
+
+    fn sequence(&mut self, nodes: &[Node], start: usize) -> usize {
+        nodes
+            .iter()
+            .fold(start, |current, node| self.node(node, current))
+    }
+
+    // Adds `node` after state `from`, returning the state it ends in.
+    fn node(&mut self, node: &Node, from: usize) -> usize {
+        let any = vec![(0, MAX_CHAR)];
+        let not_slash = without_slash(&any);
+        match *node {
+            Node::Literal(c) => {
+                let c = match self.case {
+                    Case::Sensitive => c,
+                    Case::Insensitive => fold(c),
+                } as u32;
+                let to = self.state();
+                self.edge(from, vec![(c, c)], to);
+                to
+            }
+            Node::AnyChar => {
+                let to = self.state();
+                self.edge(from, not_slash, to);
+                to
+            }
+            Node::Class {
+                ref ranges,
+                negated,
+            } => {
+                let to = self.state();
+                let set = class_set(ranges, negated, self.case);
+                self.edge(from, set, to);
+                to
+            }
+            Node::Star | Node::Anything => {
+                let set = if *node == Node::Star { not_slash } else { any };
+                let repeat = self.state();
+                self.states[from].epsilon.push(repeat);
+                self.edge(repeat, set, repeat);
+                repeat
+            }
+            Node::AnyDirectories => {
+                // Segments of characters other than `/`, each ended by
+                // a `/`, any number of times
+                let between = self.state();
+                let within = self.state();
+                self.states[from].epsilon.push(between);
+                self.edge(between, not_slash.clone(), within);
+                self.edge(between, vec![(SLASH, SLASH)], between);
+                self.edge(within, not_slash, within);
+                self.edge(within, vec![(SLASH, SLASH)], between);
+                between
+            }
+            Node::AnyInside => {
+                let to = self.state();
+                self.edge(from, any.clone(), to);
+                self.edge(to, any, to);
+                to
+            }
+            Node::Alternatives(ref branches) => {
+                let end = self.state();
+                for branch in branches {
+                    let start = self.state();
+                    self.states[from].epsilon.push(start);
+                    let branch_end = self.sequence(branch, start);
+                    self.states[branch_end].epsilon.push(end);
+                }
+                end
+            }
+        }
+    }
+
+    // `states` and every state reachable from them without reading.
+    fn closure(&self, mut states: Vec<usize>) -> Vec<usize> {
+        let mut stack = states.clone();
+        while let Some(state) = stack.pop() {
+            for &next in &self.states[state].epsilon {
+                if !states.contains(&next) {
+                    states.push(next);
+                    stack.push(next);
+                }
+            }
+        }
+        states.sort_unstable();
+        states
+    }
+}
+
+// Past this, building the automaton is refused rather than left to eat
+// memory.
+const MAX_DFA_STATES: usize = 10_000;
+
+const DEAD: u32 = u32::MAX;
+
+// A deterministic automaton over classes of characters that every
+// pattern treats alike, so the table has a column per class rather than
+// per character.
+#[derive(Debug, Clone)]
+struct Dfa {
+    // Class `i` runs from `bounds[i]` up to `bounds[i + 1]`
+    bounds: Vec<u32>,
+    // The class of each ASCII character, looked up directly
+    ascii: Vec<u32>,
+    // `transitions[state * classes + class]`, or `DEAD`
+    transitions: Vec<u32>,
+    accepting: Vec<bool>,
+}
+
+impl Dfa {
+    // Subset construction: each state here is the set of NFA states the
+    // NFA could be in at once.
+    fn build(nfa: &Nfa, accept: usize) -> Option<Dfa> {
+        let mut bounds = vec![0];
+        for state in &nfa.states {
+            for (set, _) in &state.edges {
+                for &(from, to) in set {
+                    bounds.push(from);
+                    if to < MAX_CHAR {
+                        bounds.push(to + 1);
+                    }
+                }
+            }
+        }
+        bounds.sort_unstable();
+        bounds.dedup();
+
+        let start = nfa.closure(vec![0]);
+        let mut ids: HashMap<Vec<usize>, u32> = HashMap::new();
+        ids.insert(start.clone(), 0);
+        let mut pending = vec![start];
+        let mut transitions = Vec::new();
+        let mut accepting = Vec::new();
+        // States are numbered in the order they are found, and built in
+        // that order too, so `transitions` grows a row at a time
+        let mut next_to_build = 0;
+        while next_to_build < pending.len() {
+            let current = pending[next_to_build].clone();
+            next_to_build += 1;
+            accepting.push(current.contains(&accept));
+            for &first in &bounds {
+                let mut reached: Vec<usize> = Vec::new();
+                for &state in &current {
+                    for (set, to) in &nfa.states[state].edges {
+                        let contains = set.iter().any(|&(from, to)| from <= first && first <= to);
+                        if contains && !reached.contains(to) {
+                            reached.push(*to);
+                        }
+                    }
+                }
+                if reached.is_empty() {
+                    transitions.push(DEAD);
+                    continue;
+                }
+                let reached = nfa.closure(reached);
+                let id = match ids.get(&reached) {
+                    Some(&id) => id,
+                    None => {
+                        if pending.len() >= MAX_DFA_STATES {
+                            return None;
+                        }
+                        let id = pending.len() as u32;
+                        ids.insert(reached.clone(), id);
+                        pending.push(reached);
+                        id
+                    }
+                };
+                transitions.push(id);
+            }
+        }
+        let mut dfa = Dfa {
+            bounds,
+            ascii: Vec::new(),
+            transitions,
+            accepting,
+        };
+        dfa.ascii = (0..128).map(|c| dfa.class_of(c)).collect();
+        Some(dfa)
+    }
+
+    fn class_of(&self, c: u32) -> u32 {
+        (self.bounds.partition_point(|&bound| bound <= c) - 1) as u32
+    }
+
+    fn is_match<I: Iterator<Item = char>>(&self, chars: I) -> bool {
+        let classes = self.bounds.len();
+        let mut state = 0;
+        for c in chars {
+            let c = c as u32;
+            let class = if c < 128 {
+                self.ascii[c as usize]
+            } else {
+                self.class_of(c)
+            };
+            match self.transitions[state * classes + class as usize] {
+                DEAD => return false,
+                next => state = next as usize,
+            }
+        }
+        self.accepting[state]
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Glob {
+    pattern: String,
+    case: Case,
+    dfa: Dfa,
+}
+
+impl Glob {
+    pub fn compile(pattern: &str) -> Result<Glob, GlobError> {
+        Glob::compile_with(pattern, Case::Sensitive)
+    }
+
+    pub fn compile_with(pattern: &str, case: Case) -> Result<Glob, GlobError> {
+        let mut parser = Parser {
+            chars: pattern.chars().collect(),
+            position: 0,
+        };
+        let nodes = parser.sequence(false, Start::Pattern)?;
+        let mut nfa = Nfa {
+            states: Vec::new(),
+            case,
+        };
+        let start = nfa.state();
+        let accept = nfa.sequence(&nodes, start);
+        let dfa = Dfa::build(&nfa, accept).ok_or(GlobError {
+            column: 1,
+            kind: GlobErrorKind::TooComplex,
+        })?;
+        Ok(Glob {
+            pattern: pattern.to_string(),
+            case,
+            dfa,
+        })
+    }
+
+    pub fn is_match(&self, path: &str) -> bool {
+        match self.case {
+            Case::Sensitive => self.dfa.is_match(path.chars()),
+            Case::Insensitive => self.dfa.is_match(path.chars().map(fold)),
+        }
+    }
+
+    pub fn pattern(&self) -> &str {
+        &self.pattern
+    }
+
+    pub fn case(&self) -> Case {
+        self.case
+    }
+
+    // Size of the compiled automaton.
+    pub fn state_count(&self) -> usize {
+        self.dfa.accepting.len()
+    }
+}
+
+impl fmt::Display for Glob {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.pattern)
+    }
+}
@@ -0,0 +1,4 @@
+// Run-time versions of the patterns shown in main.rs.
+
+pub mod glob;
+pub mod rules;
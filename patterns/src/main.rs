@@ -1,3 +1,5 @@
+extern crate patterns;
+
 fn main() {
     // Patterns
 
@@ -29,6 +31,16 @@ fn main() {
       OptionalInt::Missing => println!("No such luck"),
     }
 
+    // When the arms change more often than the code, the same checks can
+    // be rules read at run time, with ranges, | and @ bindings as above:
+    let rules = patterns::rules::RuleSet::parse(r#"
+        rule big     { value: i @ 6.. } => "Got an int bigger than five!"
+        rule small   { value: i }       => "Got an int!"
+        rule missing {}                 => "No such luck"
+    "#).unwrap();
+    let record = patterns::rules::Record::new().with("value", 2922);
+    println!("{}", rules.first_match(&record).unwrap().outcome().unwrap());
+
 
     // If you're matching on a pointer, you can use the
     // same syntax as you declared it with. First, &:
@@ -77,6 +89,13 @@ fn main() {
     //   _ => {},
     // }
 
+    // Shell-style patterns match strings rather than values: * and ?
+    // wildcards, [a-z] classes, {a,b} alternatives and ** for any depth
+    let sources = patterns::glob::Glob::compile("{src,tests}/**/*.rs").unwrap();
+    for path in &["src/main.rs", "src/glob/mod.rs", "target/debug/build.rs"] {
+        println!("{} matches {}: {}", sources, path, sources.is_match(path));
+    }
+
     // Mix matching
     // match x {
     //     Foo { x: Some(ref name), y: None } => ...
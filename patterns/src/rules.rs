@@ -0,0 +1,897 @@
+// The patterns from main.rs, checked at run time instead of compiled
+// into a `match`. A rule names the fields of a record and a pattern for
+// each, like a struct pattern such as `Po { x: x, .. }` with a guard, and
+// rules are data: they can be read from a file and changed without
+// building anything again.
+//
+//     use patterns::rules::{Record, RuleSet};
+//
+//     let rules = RuleSet::parse(r#"
+//         # first match wins, like the arms of a match
+//         rule vip      { tier: "gold" | "platinum", total: t @ 100.. } => "free"
+//         rule regional { country: "LT" | "LV" | "EE", total: 50.. }   => "flat"
+//         rule default  {}                                              => "paid"
+//     "#)?;
+//
+//     let order = Record::new().with("tier", "gold").with("total", 120);
+//     let found = rules.first_match(&order).unwrap();
+//     assert_eq!(found.name(), "vip");
+//     assert_eq!(found.get("t"), Some(&120.into()));
+//
+// Patterns, from main.rs and beyond:
+//
+//     _            anything, even a missing field
+//     3, "LT", true   a literal
+//     3..=10, 3..10, 50.., ..0   integer ranges
+//     > 5, >= 5, < 5, <= 5, != "x"   comparisons, standing in for guards
+//     1 | 2        either
+//     t @ 3..=10   binds the field's value to `t` if the pattern matches
+//     t            binds any value, like `t @ _`
+//     missing      only a field the record does not have
+//
+// `@` binds tighter than `|`, so `t @ 1 | 2` binds only for 1; write
+// `t @ (1 | 2)` for both. Only present values can be bound, so a
+// binding never matches a missing field.
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::ops::{Bound, RangeBounds};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Value {
+    Int(i64),
+    Str(String),
+    Bool(bool),
+}
+
+impl From<i64> for Value {
+    fn from(n: i64) -> Value {
+        Value::Int(n)
+    }
+}
+
+impl From<i32> for Value {
+    fn from(n: i32) -> Value {
+        Value::Int(n.into())
+    }
+}
+
+impl<'a> From<&'a str> for Value {
+    fn from(s: &'a str) -> Value {
+        Value::Str(s.to_string())
+    }
+}
+
+impl From<String> for Value {
+    fn from(s: String) -> Value {
+        Value::Str(s)
+    }
+}
+
+impl From<bool> for Value {
+    fn from(b: bool) -> Value {
+        Value::Bool(b)
+    }
+}
+
+// Strings without quotes. Rules are written back with quotes.
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Value::Int(n) => write!(f, "{}", n),
+            Value::Str(ref s) => f.write_str(s),
+            Value::Bool(b) => write!(f, "{}", b),
+        }
+    }
+}
+
+// A value as it is written in a rule, strings quoted.
+struct Literal<'a>(&'a Value);
+
+impl<'a> fmt::Display for Literal<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match *self.0 {
+            Value::Str(ref s) => s,
+            ref other => return write!(f, "{}", other),
+        };
+        f.write_str("\"")?;
+        for c in s.chars() {
+            match c {
+                '"' => f.write_str("\\\"")?,
+                '\\' => f.write_str("\\\\")?,
+                '\n' => f.write_str("\\n")?,
+                '\t' => f.write_str("\\t")?,
+                c => write!(f, "{}", c)?,
+            }
+        }
+        f.write_str("\"")
+    }
+}
+
+// The fields rules are checked against.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Record {
+    fields: HashMap<String, Value>,
+}
+
+impl Record {
+    pub fn new() -> Record {
+        Record::default()
+    }
+
+    pub fn with<V: Into<Value>>(mut self, field: &str, value: V) -> Record {
+        self.set(field, value);
+        self
+    }
+
+    pub fn set<V: Into<Value>>(&mut self, field: &str, value: V) {
+        self.fields.insert(field.to_string(), value.into());
+    }
+
+    pub fn get(&self, field: &str) -> Option<&Value> {
+        self.fields.get(field)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Compare {
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+    NotEqual,
+}
+
+impl Compare {
+    fn symbol(self) -> &'static str {
+        match self {
+            Compare::Less => "<",
+            Compare::LessOrEqual => "<=",
+            Compare::Greater => ">",
+            Compare::GreaterOrEqual => ">=",
+            Compare::NotEqual => "!=",
+        }
+    }
+
+    // Numbers compare with numbers and strings with strings. Booleans are
+    // only equal or not, and a pair of different types is only ever
+    // unequal.
+    fn holds(self, value: &Value, operand: &Value) -> bool {
+        let ordering = match (value, operand) {
+            (Value::Int(a), Value::Int(b)) => a.cmp(b),
+            (Value::Str(a), Value::Str(b)) => a.cmp(b),
+            (Value::Bool(a), Value::Bool(b)) => return self == Compare::NotEqual && a != b,
+            _ => return self == Compare::NotEqual,
+        };
+        match self {
+            Compare::Less => ordering.is_lt(),
+            Compare::LessOrEqual => ordering.is_le(),
+            Compare::Greater => ordering.is_gt(),
+            Compare::GreaterOrEqual => ordering.is_ge(),
+            Compare::NotEqual => ordering.is_ne(),
+        }
+    }
+}
+
+pub type Bindings = HashMap<String, Value>;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Pattern {
+    Any,
+    Missing,
+    Literal(Value),
+    // Start is `Included` or `Unbounded`, as in Rust's range patterns.
+    // An `Excluded` start built by hand still matches and prints right.
+    Range(Bound<i64>, Bound<i64>),
+    Compare(Compare, Value),
+    Bind(String, Box<Pattern>),
+    Or(Vec<Pattern>),
+}
+
+impl Pattern {
+    pub fn literal<V: Into<Value>>(value: V) -> Pattern {
+        Pattern::Literal(value.into())
+    }
+
+    // `Pattern::range(3..=10)`, `Pattern::range(50..)` and so on. A start
+    // of `Bound::Excluded(n)` is turned into `Bound::Included(n + 1)`.
+    pub fn range<R: RangeBounds<i64>>(range: R) -> Pattern {
+        let start = match range.start_bound().cloned() {
+            Bound::Excluded(start) => Bound::Included(
+                start
+                    .checked_add(1)
+                    .expect("a range starting after i64::MAX is empty"),
+            ),
+            start => start,
+        };
+        Pattern::Range(start, range.end_bound().cloned())
+    }
+
+    pub fn compare<V: Into<Value>>(compare: Compare, value: V) -> Pattern {
+        Pattern::Compare(compare, value.into())
+    }
+
+    pub fn bind(name: &str, pattern: Pattern) -> Pattern {
+        Pattern::Bind(name.to_string(), Box::new(pattern))
+    }
+
+    pub fn or(alternatives: Vec<Pattern>) -> Pattern {
+        Pattern::Or(alternatives)
+    }
+
+    // Whether a field with this value matches, ignoring bindings.
+    pub fn is_match(&self, value: Option<&Value>) -> bool {
+        self.matches(value, &mut Bindings::new())
+    }
+
+    fn matches(&self, value: Option<&Value>, bindings: &mut Bindings) -> bool {
+        match (self, value) {
+            (Pattern::Any, _) => true,
+            (Pattern::Missing, value) => value.is_none(),
+            (Pattern::Or(alternatives), value) => alternatives
+                .iter()
+                .any(|alternative| alternative.matches(value, bindings)),
+            (_, None) => false,
+            (Pattern::Literal(literal), Some(value)) => literal == value,
+            (Pattern::Range(start, end), Some(Value::Int(n))) => (*start, *end).contains(n),
+            (Pattern::Range(..), Some(_)) => false,
+            (Pattern::Compare(compare, operand), Some(value)) => compare.holds(value, operand),
+            (Pattern::Bind(name, pattern), Some(value)) => {
+                // Binds only once the inner pattern has matched, so a
+                // failed alternative leaves nothing behind
+                let matched = pattern.matches(Some(value), bindings);
+                if matched {
+                    bindings.insert(name.clone(), value.clone());
+                }
+                matched
+            }
+        }
+    }
+}
+
+impl fmt::Display for Pattern {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Pattern::Any => f.write_str("_"),
+            Pattern::Missing => f.write_str("missing"),
+            Pattern::Literal(ref value) => write!(f, "{}", Literal(value)),
+            Pattern::Range(start, end) => {
+                match start {
+                    Bound::Included(start) => write!(f, "{}", start)?,
+                    // No range syntax excludes its start, so write the
+                    // next integer instead
+                    Bound::Excluded(start) => write!(f, "{}", i128::from(start) + 1)?,
+                    Bound::Unbounded => {}
+                }
+                match end {
+                    Bound::Included(end) => write!(f, "..={}", end),
+                    Bound::Excluded(end) => write!(f, "..{}", end),
+                    Bound::Unbounded => f.write_str(".."),
+                }
+            }
+            Pattern::Compare(compare, ref value) => {
+                write!(f, "{} {}", compare.symbol(), Literal(value))
+            }
+            Pattern::Bind(ref name, ref pattern) => match **pattern {
+                Pattern::Any => f.write_str(name),
+                Pattern::Or(_) => write!(f, "{} @ ({})", name, pattern),
+                _ => write!(f, "{} @ {}", name, pattern),
+            },
+            Pattern::Or(ref alternatives) => {
+                for (i, alternative) in alternatives.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(" | ")?;
+                    }
+                    write!(f, "{}", alternative)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl FromStr for Pattern {
+    type Err = ParseError;
+
+    fn from_str(source: &str) -> Result<Pattern, ParseError> {
+        let mut parser = Parser::new(source)?;
+        let pattern = parser.pattern()?;
+        parser.end()?;
+        Ok(pattern)
+    }
+}
+
+// Fields and patterns that must all match, and an optional outcome for
+// the caller to act on.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rule {
+    name: String,
+    conditions: Vec<(String, Pattern)>,
+    outcome: Option<Value>,
+}
+
+impl Rule {
+    // A rule with no conditions, which matches every record.
+    pub fn new(name: &str) -> Rule {
+        Rule {
+            name: name.to_string(),
+            conditions: Vec::new(),
+            outcome: None,
+        }
+    }
+
+    pub fn when(mut self, field: &str, pattern: Pattern) -> Rule {
+        self.conditions.push((field.to_string(), pattern));
+        self
+    }
+
+    pub fn then<V: Into<Value>>(mut self, outcome: V) -> Rule {
+        self.outcome = Some(outcome.into());
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn conditions(&self) -> &[(String, Pattern)] {
+        &self.conditions
+    }
+
+    pub fn outcome(&self) -> Option<&Value> {
+        self.outcome.as_ref()
+    }
+
+    // The bindings if every condition matches. A name bound by two
+    // fields keeps the later one.
+    pub fn test(&self, record: &Record) -> Option<Bindings> {
+        let mut bindings = Bindings::new();
+        for (field, pattern) in &self.conditions {
+            if !pattern.matches(record.get(field), &mut bindings) {
+                return None;
+            }
+        }
+        Some(bindings)
+    }
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "rule {} {{", self.name)?;
+        for (i, (field, pattern)) in self.conditions.iter().enumerate() {
+            let separator = if i == 0 { " " } else { ", " };
+            write!(f, "{}{}: {}", separator, field, pattern)?;
+        }
+        if !self.conditions.is_empty() {
+            f.write_str(" ")?;
+        }
+        f.write_str("}")?;
+        if let Some(ref outcome) = self.outcome {
+            write!(f, " => {}", Literal(outcome))?;
+        }
+        Ok(())
+    }
+}
+
+// A rule that matched a record, and what it bound.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Match<'r> {
+    rule: &'r Rule,
+    bindings: Bindings,
+}
+
+impl<'r> Match<'r> {
+    pub fn rule(&self) -> &'r Rule {
+        self.rule
+    }
+
+    pub fn name(&self) -> &'r str {
+        &self.rule.name
+    }
+
+    pub fn outcome(&self) -> Option<&'r Value> {
+        self.rule.outcome.as_ref()
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Value> {
+        self.bindings.get(name)
+    }
+
+    pub fn bindings(&self) -> &Bindings {
+        &self.bindings
+    }
+}
+
+// Rules in order, with unique names.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RuleSet {
+    rules: Vec<Rule>,
+}
+
+impl RuleSet {
+    pub fn new() -> RuleSet {
+        RuleSet::default()
+    }
+
+    pub fn parse(source: &str) -> Result<RuleSet, ParseError> {
+        let mut parser = Parser::new(source)?;
+        let mut rules = RuleSet::new();
+        while !parser.at_end() {
+            let (rule, name_token) = parser.rule()?;
+            if !rules.add(rule) {
+                return Err(name_token.error(ParseErrorKind::DuplicateRule(name_token.to_string())));
+            }
+        }
+        Ok(rules)
+    }
+
+    // Adds a rule after the others. Returns false, leaving the set as it
+    // was, if there is already a rule of that name.
+    pub fn add(&mut self, rule: Rule) -> bool {
+        if self.get(&rule.name).is_some() {
+            return false;
+        }
+        self.rules.push(rule);
+        true
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Rule> {
+        self.rules.iter().find(|rule| rule.name == name)
+    }
+
+    pub fn rules(&self) -> &[Rule] {
+        &self.rules
+    }
+
+    pub fn len(&self) -> usize {
+        self.rules.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    // The first rule to match, as the first matching arm of a `match`.
+    pub fn first_match(&self, record: &Record) -> Option<Match<'_>> {
+        self.rules
+            .iter()
+            .find_map(|rule| rule.test(record).map(|bindings| Match { rule, bindings }))
+    }
+
+    // Every rule that matches, in order.
+    pub fn matches(&self, record: &Record) -> Vec<Match<'_>> {
+        self.rules
+            .iter()
+            .filter_map(|rule| rule.test(record).map(|bindings| Match { rule, bindings }))
+            .collect()
+    }
+}
+
+// One rule per line, in the syntax `parse` reads.
+impl fmt::Display for RuleSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, rule) in self.rules.iter().enumerate() {
+            if i > 0 {
+                f.write_str("\n")?;
+            }
+            write!(f, "{}", rule)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for RuleSet {
+    type Err = ParseError;
+
+    fn from_str(source: &str) -> Result<RuleSet, ParseError> {
+        RuleSet::parse(source)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    Unexpected {
+        expected: &'static str,
+        found: String,
+    },
+    UnclosedString,
+    InvalidNumber(String),
+    EmptyRange,
+    DuplicateRule(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub kind: ParseErrorKind,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}, column {}: ", self.line, self.column)?;
+        match self.kind {
+            ParseErrorKind::Unexpected {
+                expected,
+                ref found,
+            } => write!(f, "expected {}, found {}", expected, found),
+            ParseErrorKind::UnclosedString => write!(f, "string is never closed"),
+            ParseErrorKind::InvalidNumber(ref number) => {
+                write!(f, "`{}` is not a valid number", number)
+            }
+            ParseErrorKind::EmptyRange => write!(f, "range can never match"),
+            ParseErrorKind::DuplicateRule(ref name) => {
+                write!(f, "rule `{}` is defined twice", name)
+            }
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+#[derive(Debug, Clone, PartialEq)]
+enum TokenKind {
+    Ident(String),
+    Int(i64),
+    Str(String),
+    Punct(&'static str),
+    End,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Token {
+    kind: TokenKind,
+    line: usize,
+    column: usize,
+}
+
+impl Token {
+    fn error(&self, kind: ParseErrorKind) -> ParseError {
+        ParseError {
+            line: self.line,
+            column: self.column,
+            kind,
+        }
+    }
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.kind {
+            TokenKind::Ident(ref name) => f.write_str(name),
+            TokenKind::Int(n) => write!(f, "{}", n),
+            TokenKind::Str(ref s) => write!(f, "{}", Literal(&Value::Str(s.clone()))),
+            TokenKind::Punct(punct) => write!(f, "`{}`", punct),
+            TokenKind::End => f.write_str("end of input"),
+        }
+    }
+}
+
+// Longest first, so that `..=` is not read as `..` and `=`
+const PUNCTUATION: [&str; 16] = [
+    "..=", "..", "=>", ">=", "<=", "!=", ">", "<", "{", "}", ":", ",", "|", "@", "(", ")",
+];
+
+fn tokenize(source: &str) -> Result<Vec<Token>, ParseError> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut tokens = Vec::new();
+    let (mut i, mut line, mut column) = (0, 1, 1);
+    // Moves past `n` chars on one line
+    macro_rules! advance {
+        ($n:expr) => {{
+            i += $n;
+            column += $n;
+        }};
+    }
+    while i < chars.len() {
+        let c = chars[i];
+        let at = |kind| Token { kind, line, column };
+        if c == '\n' {
+            i += 1;
+            line += 1;
+            column = 1;
+        } else if c.is_whitespace() {
+            advance!(1);
+        } else if c == '#' {
+            while i < chars.len() && chars[i] != '\n' {
+                advance!(1);
+            }
+        } else if c.is_ascii_digit()
+            || (c == '-' && chars.get(i + 1).is_some_and(|c| c.is_ascii_digit()))
+        {
+            let start = i;
+            let mut end = i + 1;
+            while end < chars.len() && chars[end].is_ascii_digit() {
+                end += 1;
+            }
+            let text: String = chars[start..end].iter().collect();
+            let n = text
+                .parse()
+                .map_err(|_| at(TokenKind::End).error(ParseErrorKind::InvalidNumber(text)))?;
+            tokens.push(at(TokenKind::Int(n)));
+            advance!(end - start);
+        } else if c.is_alphabetic() || c == '_' {
+            let start = i;
+            let mut end = i + 1;
+            while end < chars.len()
+                && (chars[end].is_alphanumeric() || chars[end] == '_' || chars[end] == '.')
+                && !(chars[end] == '.' && chars.get(end + 1) == Some(&'.'))
+            {
+                end += 1;
+            }
+            tokens.push(at(TokenKind::Ident(chars[start..end].iter().collect())));
+            advance!(end - start);
+        } else if c == '"' {
+            let token = at(TokenKind::End);
+            let mut s = String::new();
+            advance!(1);
+            loop {
+                match chars.get(i) {
+                    None | Some('\n') => return Err(token.error(ParseErrorKind::UnclosedString)),
+                    Some('"') => break,
+                    Some('\\') => {
+                        match chars.get(i + 1) {
+                            Some('n') => s.push('\n'),
+                            Some('t') => s.push('\t'),
+                            Some(&c) => s.push(c),
+                            None => return Err(token.error(ParseErrorKind::UnclosedString)),
+                        }
+                        advance!(2);
+                    }
+                    Some(&c) => {
+                        s.push(c);
+                        advance!(1);
+                    }
+                }
+            }
+            advance!(1);
+            tokens.push(Token {
+                kind: TokenKind::Str(s),
+                ..token
+            });
+        } else {
+            let rest: String = chars[i..chars.len().min(i + 3)].iter().collect();
+            match PUNCTUATION.iter().find(|punct| rest.starts_with(**punct)) {
+                Some(punct) => {
+                    tokens.push(at(TokenKind::Punct(punct)));
+                    advance!(punct.len());
+                }
+                None => {
+                    return Err(at(TokenKind::End).error(ParseErrorKind::Unexpected {
+                        expected: "a rule",
+                        found: format!("`{}`", c),
+                    }))
+                }
+            }
+        }
+    }
+    tokens.push(Token {
+        kind: TokenKind::End,
+        line,
+        column,
+    });
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl Parser {
+    fn new(source: &str) -> Result<Parser, ParseError> {
+        Ok(Parser {
+            tokens: tokenize(source)?,
+            position: 0,
+        })
+    }
+
+    fn peek(&self) -> &Token {
+        &self.tokens[self.position]
+    }
+
+    fn next(&mut self) -> Token {
+        let token = self.tokens[self.position].clone();
+        // The `End` token stays put
+        if token.kind != TokenKind::End {
+            self.position += 1;
+        }
+        token
+    }
+
+    fn at_end(&self) -> bool {
+        self.peek().kind == TokenKind::End
+    }
+
+    fn end(&self) -> Result<(), ParseError> {
+        if self.at_end() {
+            Ok(())
+        } else {
+            Err(self.unexpected("end of input"))
+        }
+    }
+
+    fn unexpected(&self, expected: &'static str) -> ParseError {
+        let found = self.peek();
+        found.error(ParseErrorKind::Unexpected {
+            expected,
+            found: found.to_string(),
+        })
+    }
+
+    fn eat(&mut self, punct: &str) -> bool {
+        if matches!(self.peek().kind, TokenKind::Punct(p) if p == punct) {
+            self.next();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, punct: &'static str, expected: &'static str) -> Result<(), ParseError> {
+        if self.eat(punct) {
+            Ok(())
+        } else {
+            Err(self.unexpected(expected))
+        }
+    }
+
+    fn ident(&mut self, expected: &'static str) -> Result<(String, Token), ParseError> {
+        match self.peek().kind {
+            TokenKind::Ident(ref name) => {
+                let name = name.clone();
+                Ok((name, self.next()))
+            }
+            _ => Err(self.unexpected(expected)),
+        }
+    }
+
+    fn int(&mut self) -> Result<(i64, Token), ParseError> {
+        match self.peek().kind {
+            TokenKind::Int(n) => Ok((n, self.next())),
+            _ => Err(self.unexpected("a number")),
+        }
+    }
+
+    // rule NAME { FIELD: PATTERN, ... } => OUTCOME
+    fn rule(&mut self) -> Result<(Rule, Token), ParseError> {
+        match self.ident("`rule`")? {
+            (ref keyword, _) if keyword == "rule" => {}
+            (_, token) => {
+                return Err(token.error(ParseErrorKind::Unexpected {
+                    expected: "`rule`",
+                    found: token.to_string(),
+                }))
+            }
+        }
+        let (name, name_token) = self.ident("a rule name")?;
+        let mut rule = Rule::new(&name);
+        self.expect("{", "`{`")?;
+        while !self.eat("}") {
+            let (field, _) = self.ident("a field name or `}`")?;
+            self.expect(":", "`:`")?;
+            let pattern = self.pattern()?;
+            rule = rule.when(&field, pattern);
+            if !self.eat(",") {
+                self.expect("}", "`,` or `}`")?;
+                break;
+            }
+        }
+        if self.eat("=>") {
+            rule.outcome = Some(self.literal()?);
+        }
+        Ok((rule, name_token))
+    }
+
+    fn pattern(&mut self) -> Result<Pattern, ParseError> {
+        let mut alternatives = vec![self.alternative()?];
+        while self.eat("|") {
+            alternatives.push(self.alternative()?);
+        }
+        Ok(if alternatives.len() == 1 {
+            alternatives.remove(0)
+        } else {
+            Pattern::Or(alternatives)
+        })
+    }
+
+    fn alternative(&mut self) -> Result<Pattern, ParseError> {
+        let is_binding = match (&self.peek().kind, self.tokens.get(self.position + 1)) {
+            (TokenKind::Ident(_), Some(next)) => next.kind == TokenKind::Punct("@"),
+            _ => false,
+        };
+        if is_binding {
+            let (name, _) = self.ident("a name")?;
+            self.next();
+            return Ok(Pattern::bind(&name, self.alternative()?));
+        }
+        let token = self.peek().clone();
+        match token.kind {
+            TokenKind::Ident(ref word) => {
+                self.next();
+                Ok(match word.as_str() {
+                    "_" => Pattern::Any,
+                    "missing" => Pattern::Missing,
+                    "true" => Pattern::literal(true),
+                    "false" => Pattern::literal(false),
+                    name => Pattern::bind(name, Pattern::Any),
+                })
+            }
+            TokenKind::Str(ref s) => {
+                self.next();
+                Ok(Pattern::literal(s.as_str()))
+            }
+            TokenKind::Int(start) => {
+                self.next();
+                if self.peek().kind == TokenKind::Punct("..=")
+                    || self.peek().kind == TokenKind::Punct("..")
+                {
+                    self.range(Bound::Included(start), &token)
+                } else {
+                    Ok(Pattern::literal(start))
+                }
+            }
+            TokenKind::Punct("..") | TokenKind::Punct("..=") => {
+                self.range(Bound::Unbounded, &token)
+            }
+            TokenKind::Punct("(") => {
+                self.next();
+                let pattern = self.pattern()?;
+                self.expect(")", "`)`")?;
+                Ok(pattern)
+            }
+            TokenKind::Punct(symbol) => {
+                let compare = match symbol {
+                    "<" => Compare::Less,
+                    "<=" => Compare::LessOrEqual,
+                    ">" => Compare::Greater,
+                    ">=" => Compare::GreaterOrEqual,
+                    "!=" => Compare::NotEqual,
+                    _ => return Err(self.unexpected("a pattern")),
+                };
+                self.next();
+                Ok(Pattern::Compare(compare, self.literal()?))
+            }
+            TokenKind::End => Err(self.unexpected("a pattern")),
+        }
+    }
+
+    // The rest of a range from `..` or `..=` on. Only `start..` may
+    // leave out the end.
+    fn range(&mut self, start: Bound<i64>, first: &Token) -> Result<Pattern, ParseError> {
+        let end = if self.eat("..=") {
+            Bound::Included(self.int()?.0)
+        } else {
+            self.next();
+            match (self.peek().kind.clone(), start) {
+                (TokenKind::Int(_), _) => Bound::Excluded(self.int()?.0),
+                (_, Bound::Included(_)) => Bound::Unbounded,
+                _ => return Err(self.unexpected("a number")),
+            }
+        };
+        let empty = match (start, end) {
+            (Bound::Included(start), Bound::Included(end)) => start > end,
+            (Bound::Included(start), Bound::Excluded(end)) => start >= end,
+            _ => false,
+        };
+        if empty {
+            return Err(first.error(ParseErrorKind::EmptyRange));
+        }
+        Ok(Pattern::Range(start, end))
+    }
+
+    fn literal(&mut self) -> Result<Value, ParseError> {
+        let value = match self.peek().kind {
+            TokenKind::Int(n) => Value::Int(n),
+            TokenKind::Str(ref s) => Value::Str(s.clone()),
+            TokenKind::Ident(ref word) if word == "true" || word == "false" => {
+                Value::Bool(word == "true")
+            }
+            _ => return Err(self.unexpected("a number, string, `true` or `false`")),
+        };
+        self.next();
+        Ok(value)
+    }
+}
@@ -0,0 +1,147 @@
+extern crate patterns;
+
+use patterns::glob::{Case, Glob, GlobErrorKind};
+
+fn check(pattern: &str, matching: &[&str], other: &[&str]) {
+    let glob = Glob::compile(pattern).unwrap();
+    for path in matching {
+        assert!(glob.is_match(path), "{} should match {}", pattern, path);
+    }
+    for path in other {
+        assert!(
+            !glob.is_match(path),
+            "{} should not match {}",
+            pattern,
+            path
+        );
+    }
+}
+
+#[test]
+fn stars_and_question_marks_stay_in_one_segment() {
+    check(
+        "*.rs",
+        &["main.rs", ".rs", "a.b.rs"],
+        &["src/main.rs", "main.rsx"],
+    );
+    check("src/*", &["src/a", "src/"], &["src", "src/a/b"]);
+    check("?.txt", &["a.txt", "é.txt"], &[".txt", "ab.txt", "/.txt"]);
+    check("a*b*c", &["abc", "axxbyyc", "abcbc"], &["acb", "a/b/c"]);
+    check("a**b", &["ab", "axb"], &["a/b"]);
+}
+
+#[test]
+fn double_stars_cross_segments() {
+    check("**", &["", "a", "a/b/c"], &[]);
+    check(
+        "**/*.rs",
+        &["main.rs", "src/main.rs", "a/b/c.rs"],
+        &["main.c"],
+    );
+    check(
+        "src/**/mod.rs",
+        &["src/mod.rs", "src/a/mod.rs", "src/a/b/mod.rs"],
+        &["src/amod.rs", "mod.rs"],
+    );
+    check(
+        "target/**",
+        &["target/debug", "target/a/b"],
+        &["target", "targets/a"],
+    );
+    check("a/**b", &["a/b", "a/xb"], &["a/x/b"]);
+}
+
+#[test]
+fn classes() {
+    check("[abc].txt", &["a.txt", "c.txt"], &["d.txt", "ab.txt"]);
+    check("file[0-9][0-9]", &["file07"], &["file7", "filea1"]);
+    check("[!a-y]", &["z", "A"], &["b", "/"]);
+    check("[^a]", &["b"], &["a"]);
+    check("[]]", &["]"], &["["]);
+    check("[a-]", &["a", "-"], &["b"]);
+    check("[\\]x]", &["]", "x"], &["\\"]);
+    check("[/]", &[], &["/"]);
+}
+
+#[test]
+fn braces_nest_and_hold_other_syntax() {
+    check("*.{png,jpg}", &["a.png", "b.jpg"], &["c.gif", "d.png.bak"]);
+    check(
+        "{src,tests}/**/*.rs",
+        &["src/a.rs", "tests/x/y.rs"],
+        &["benches/a.rs"],
+    );
+    check("{a,b{c,d}}e", &["ae", "bce", "bde"], &["be", "abe"]);
+    check("x{,y}", &["x", "xy"], &["xyy"]);
+    check(
+        "{**/test,docs}/*.md",
+        &["test/a.md", "a/b/test/c.md", "docs/d.md"],
+        &["a/docs/d.md"],
+    );
+    check("a,b}", &["a,b}"], &["a"]);
+}
+
+#[test]
+fn double_stars_in_braces_depend_on_what_is_before_the_brace() {
+    // Mid-segment, so `**` is a plain star
+    check(
+        "x{**/y,z}",
+        &["x/y", "xa/y", "xz"],
+        &["xy", "xa/b/y", "x/a/y"],
+    );
+    check("a/{**,b}", &["a/b", "a/x/y"], &["a/", "a"]);
+    check("{**,z}", &["", "z", "a/b"], &[]);
+    check("a/{b,**/c}", &["a/b", "a/c", "a/x/y/c"], &["a/xc"]);
+}
+
+#[test]
+fn escapes_make_literals() {
+    check("\\*.txt", &["*.txt"], &["a.txt"]);
+    check("what\\?", &["what?"], &["whats"]);
+    check("\\{a,b\\}", &["{a,b}"], &["a"]);
+}
+
+#[test]
+fn case_insensitive_matching() {
+    let glob = Glob::compile_with("*.{PNG,jp[e]g}", Case::Insensitive).unwrap();
+    for path in &["a.png", "B.PNG", "c.JPEG", "d.jPeG"] {
+        assert!(glob.is_match(path), "{}", path);
+    }
+    assert!(!glob.is_match("e.gif"));
+
+    let upper = Glob::compile_with("[A-C]x", Case::Insensitive).unwrap();
+    assert!(upper.is_match("bX") && upper.is_match("BX"));
+    let not_upper = Glob::compile_with("[!A-C]", Case::Insensitive).unwrap();
+    assert!(!not_upper.is_match("b") && !not_upper.is_match("B"));
+    assert!(not_upper.is_match("d"));
+    let greek = Glob::compile_with("ΣΟΦΊΑ", Case::Insensitive).unwrap();
+    assert!(greek.is_match("σοφία"));
+    assert!(!Glob::compile("*.PNG").unwrap().is_match("a.png"));
+}
+
+#[test]
+fn errors_name_the_column() {
+    let kind = |pattern: &str| {
+        let error = Glob::compile(pattern).unwrap_err();
+        (error.column, error.kind)
+    };
+    assert_eq!(kind("src/[ab"), (5, GlobErrorKind::UnclosedClass));
+    assert_eq!(kind("a{b,c"), (2, GlobErrorKind::UnclosedBrace));
+    assert_eq!(kind("x[z-a]"), (3, GlobErrorKind::InvalidRange('z', 'a')));
+    assert_eq!(kind("end\\"), (4, GlobErrorKind::DanglingEscape));
+    assert_eq!(
+        Glob::compile("[").unwrap_err().to_string(),
+        "column 1: `[` is never closed"
+    );
+}
+
+#[test]
+fn the_automaton_is_small_and_reusable() {
+    let glob = Glob::compile("**/*.rs").unwrap();
+    assert!(glob.state_count() < 10);
+    let paths: Vec<String> = (0..1000)
+        .map(|i| format!("src/m{}/lib.{}", i, if i % 2 == 0 { "rs" } else { "c" }))
+        .collect();
+    assert_eq!(paths.iter().filter(|p| glob.is_match(p)).count(), 500);
+    assert_eq!(glob.to_string(), "**/*.rs");
+}
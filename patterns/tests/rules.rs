@@ -0,0 +1,179 @@
+extern crate patterns;
+
+use patterns::rules::{Compare, ParseErrorKind, Pattern, Record, Rule, RuleSet, Value};
+
+const SHIPPING: &str = r#"
+# first match wins
+rule vip      { tier: "gold" | "platinum", total: t @ 100.. } => "free"
+rule regional { country: c @ ("LT" | "LV" | "EE"), total: 50.. } => "flat"
+rule default  {} => "paid"
+"#;
+
+fn outcome(rules: &RuleSet, record: &Record) -> String {
+    rules
+        .first_match(record)
+        .unwrap()
+        .outcome()
+        .unwrap()
+        .to_string()
+}
+
+#[test]
+fn the_first_matching_rule_wins() {
+    let rules = RuleSet::parse(SHIPPING).unwrap();
+    assert_eq!(rules.len(), 3);
+    let gold = Record::new()
+        .with("tier", "gold")
+        .with("total", 120)
+        .with("country", "LT");
+    assert_eq!(outcome(&rules, &gold), "free");
+    assert_eq!(outcome(&rules, &gold.clone().with("total", 60)), "flat");
+    assert_eq!(outcome(&rules, &gold.clone().with("total", 20)), "paid");
+    assert_eq!(outcome(&rules, &Record::new()), "paid");
+}
+
+#[test]
+fn all_matches_come_with_their_bindings() {
+    let rules = RuleSet::parse(SHIPPING).unwrap();
+    let order = Record::new()
+        .with("tier", "platinum")
+        .with("total", 150)
+        .with("country", "EE");
+    let found = rules.matches(&order);
+    let names: Vec<&str> = found.iter().map(|m| m.name()).collect();
+    assert_eq!(names, vec!["vip", "regional", "default"]);
+    assert_eq!(found[0].get("t"), Some(&Value::Int(150)));
+    assert_eq!(found[1].get("c"), Some(&Value::from("EE")));
+    assert_eq!(found[1].get("t"), None);
+    assert!(found[2].bindings().is_empty());
+}
+
+#[test]
+fn patterns_follow_rust_semantics() {
+    let cases: Vec<(&str, Option<Value>, bool)> = vec![
+        ("1 | 2", Some(2.into()), true),
+        ("3..=10", Some(10.into()), true),
+        ("3..10", Some(10.into()), false),
+        ("..0", Some((-1).into()), true),
+        ("..=-1", Some(0.into()), false),
+        ("50..", Some("50".into()), false),
+        ("> 5", Some(6.into()), true),
+        ("<= \"m\"", Some("apple".into()), true),
+        ("!= \"closed\"", Some(3.into()), true),
+        ("!= \"closed\"", None, false),
+        ("_", None, true),
+        ("missing", None, true),
+        ("missing | 0", Some(0.into()), true),
+        ("missing", Some(false.into()), false),
+        ("x", None, false),
+        ("true", Some(true.into()), true),
+        ("!= true", Some(true.into()), false),
+        ("!= true", Some(false.into()), true),
+        ("!= true", Some(1.into()), true),
+        ("> false", Some(true.into()), false),
+        ("\"say \\\"hi\\\"\"", Some("say \"hi\"".into()), true),
+    ];
+    for (source, value, expected) in cases {
+        let pattern: Pattern = source.parse().unwrap();
+        assert_eq!(
+            pattern.is_match(value.as_ref()),
+            expected,
+            "{} against {:?}",
+            source,
+            value
+        );
+    }
+}
+
+#[test]
+fn binding_binds_tighter_than_or() {
+    let loose = Rule::new("loose").when("n", "x @ 1 | 2".parse().unwrap());
+    let grouped = Rule::new("grouped").when("n", "x @ (1 | 2)".parse().unwrap());
+    let two = Record::new().with("n", 2);
+    assert_eq!(loose.test(&two).unwrap().get("x"), None);
+    assert_eq!(grouped.test(&two).unwrap().get("x"), Some(&Value::Int(2)));
+}
+
+#[test]
+fn rules_built_in_code_match_parsed_ones() {
+    let mut built = RuleSet::new();
+    assert!(built.add(
+        Rule::new("big")
+            .when("total", Pattern::bind("t", Pattern::range(100..)))
+            .when(
+                "status",
+                Pattern::or(vec![
+                    Pattern::literal("new"),
+                    Pattern::compare(Compare::Greater, 3),
+                ])
+            )
+            .then(true)
+    ));
+    assert!(!built.add(Rule::new("big")));
+    assert_eq!(
+        built.to_string(),
+        r#"rule big { total: t @ 100.., status: "new" | > 3 } => true"#
+    );
+    assert_eq!(RuleSet::parse(&built.to_string()).unwrap(), built);
+
+    let parsed = RuleSet::parse(SHIPPING).unwrap();
+    assert_eq!(RuleSet::parse(&parsed.to_string()).unwrap(), parsed);
+}
+
+#[test]
+fn excluded_range_starts_are_written_as_the_next_integer() {
+    use std::ops::Bound::{Excluded, Included, Unbounded};
+
+    let after = Pattern::range((Excluded(3), Unbounded));
+    assert_eq!(after, Pattern::range(4..));
+    assert_eq!(after.to_string(), "4..");
+    assert!(!after.is_match(Some(&Value::Int(3))));
+    assert!(after.is_match(Some(&Value::Int(4))));
+
+    let by_hand = Pattern::Range(Excluded(-1), Included(5));
+    assert_eq!(by_hand.to_string(), "0..=5");
+    assert!(!by_hand.is_match(Some(&Value::Int(-1))));
+    assert_eq!(
+        by_hand.to_string().parse::<Pattern>().unwrap(),
+        Pattern::range(0..=5)
+    );
+}
+
+#[test]
+fn errors_point_at_the_problem() {
+    let error = |source: &str| {
+        let error = RuleSet::parse(source).unwrap_err();
+        (error.line, error.column, error.kind)
+    };
+    assert_eq!(
+        error("rule a { n: 1 }\nrule a {}"),
+        (2, 6, ParseErrorKind::DuplicateRule("a".to_string()))
+    );
+    assert_eq!(
+        error("rule a { n: 10..=3 }"),
+        (1, 13, ParseErrorKind::EmptyRange)
+    );
+    assert_eq!(
+        error("rule a { n: \"open }"),
+        (1, 13, ParseErrorKind::UnclosedString)
+    );
+    assert_eq!(
+        error("rule a { n 1 }"),
+        (
+            1,
+            12,
+            ParseErrorKind::Unexpected {
+                expected: "`:`",
+                found: "1".to_string()
+            }
+        )
+    );
+    assert_eq!(
+        RuleSet::parse("rule a { n: | }").unwrap_err().to_string(),
+        "line 1, column 13: expected a pattern, found `|`"
+    );
+    assert_eq!(
+        RuleSet::parse("rule a { n: 1").unwrap_err().to_string(),
+        "line 1, column 14: expected `,` or `}`, found end of input"
+    );
+}
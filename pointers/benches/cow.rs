@@ -0,0 +1,119 @@
+// Passing a large, rarely changed configuration around: copying it
+// every time, sharing it with `Rc<T>` and cloning by hand to change it,
+// or sharing it with `CowRc<T>`. Then short strings and vectors, on the
+// heap or inline.
+//
+//     cargo bench
+//
+// Times are per round, and only good for comparing lines of one run.
+
+extern crate pointers;
+
+use pointers::cow_rc::CowRc;
+use pointers::small::{SmallString, SmallVec};
+use std::collections::HashMap;
+use std::hint::black_box;
+use std::rc::Rc;
+use std::time::Instant;
+
+type Config = HashMap<String, String>;
+
+const WORKERS: usize = 1000;
+// One worker in this many changes its configuration
+const WRITE_EVERY: usize = 100;
+
+fn config() -> Config {
+    (0..500)
+        .map(|i| (format!("key.{}", i), format!("value number {}", i)))
+        .collect()
+}
+
+fn bench<F: FnMut()>(name: &str, rounds: u32, mut round: F) {
+    round();
+    let start = Instant::now();
+    for _ in 0..rounds {
+        round();
+    }
+    let per_round = start.elapsed() / rounds;
+    println!("{:<40} {:>12?}", name, per_round);
+}
+
+fn main() {
+    let config = config();
+    let rc = Rc::new(config.clone());
+    let cow = CowRc::new(config.clone());
+
+    println!("{} workers reading one key each", WORKERS);
+    bench("Config clone", 20, || {
+        for _ in 0..WORKERS {
+            let mine = config.clone();
+            black_box(mine.get("key.7"));
+        }
+    });
+    bench("Rc<Config> clone", 20, || {
+        for _ in 0..WORKERS {
+            let mine = rc.clone();
+            black_box(mine.get("key.7"));
+        }
+    });
+    bench("CowRc<Config> clone", 20, || {
+        for _ in 0..WORKERS {
+            let mine = cow.clone();
+            black_box(mine.get("key.7"));
+        }
+    });
+
+    println!("\nthe same, one in {} changing a key", WRITE_EVERY);
+    bench("Config clone", 20, || {
+        for i in 0..WORKERS {
+            let mut mine = config.clone();
+            if i % WRITE_EVERY == 0 {
+                mine.insert("key.7".to_string(), "changed".to_string());
+            }
+            black_box(mine.get("key.7"));
+        }
+    });
+    bench("Rc<Config> clone, copied to write", 20, || {
+        for i in 0..WORKERS {
+            let mut mine = rc.clone();
+            if i % WRITE_EVERY == 0 {
+                let mut copy = (*mine).clone();
+                copy.insert("key.7".to_string(), "changed".to_string());
+                mine = Rc::new(copy);
+            }
+            black_box(mine.get("key.7"));
+        }
+    });
+    bench("CowRc<Config> clone, make_mut", 20, || {
+        for i in 0..WORKERS {
+            let mut mine = cow.clone();
+            if i % WRITE_EVERY == 0 {
+                mine.make_mut()
+                    .insert("key.7".to_string(), "changed".to_string());
+            }
+            black_box(mine.get("key.7"));
+        }
+    });
+
+    let names: Vec<String> = (0..WORKERS).map(|i| format!("wheel-{}", i)).collect();
+    println!("\n{} short names", WORKERS);
+    bench("String", 200, || {
+        let copies: Vec<String> = names.iter().map(|name| name.as_str().into()).collect();
+        black_box(copies);
+    });
+    bench("SmallString<22>", 200, || {
+        let copies: Vec<SmallString<22>> = names.iter().map(|name| name.as_str().into()).collect();
+        black_box(copies);
+    });
+
+    println!("\n{} vectors of 4", WORKERS);
+    bench("Vec<u32>", 200, || {
+        let vecs: Vec<Vec<u32>> = (0..WORKERS as u32).map(|i| (i..i + 4).collect()).collect();
+        black_box(vecs);
+    });
+    bench("SmallVec<u32, 4>", 200, || {
+        let vecs: Vec<SmallVec<u32, 4>> =
+            (0..WORKERS as u32).map(|i| (i..i + 4).collect()).collect();
+        black_box(vecs);
+    });
+}
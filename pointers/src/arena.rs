@@ -0,0 +1,276 @@
+// A typed arena: values live in one `Vec` owned by the `Arena`, and
+// everything else refers to them by `Idx<T>` handles instead of
+// pointers. Handles are plain `Copy` numbers, so structures full of
+// links in every direction, such as a tree whose nodes know their
+// parent, need no `Rc`, `RefCell` or lifetimes, and the borrow checker
+// only ever sees one owner: the arena.
+//
+//     use pointers::arena::Arena;
+//
+//     let mut arena = Arena::new();
+//     let a = arena.insert("a");
+//     assert_eq!(arena[a], "a");
+//     arena.remove(a);
+//     assert_eq!(arena.get(a), None);
+//
+// A handle is an index plus the generation of its slot. Removing a
+// value bumps the generation, so a stale handle to a removed value,
+// kept around after its slot is reused, is caught instead of quietly
+// finding the new value.
+
+use std::convert::TryFrom;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::mem;
+use std::ops::{Index, IndexMut};
+
+// A handle to a value in an `Arena<T>`. Only meaningful for the arena
+// that handed it out.
+pub struct Idx<T> {
+    index: u32,
+    generation: u32,
+    // `fn() -> T` keeps `Idx<T>` `Copy`, `Send` and `Sync` whatever `T` is
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T> Idx<T> {
+    // Position of the slot in the arena, for use as a key in side
+    // tables. Reused by later values once this one is removed.
+    pub fn index(self) -> usize {
+        self.index as usize
+    }
+}
+
+// Implemented by hand, since deriving would require `T` to implement
+// them as well.
+impl<T> Clone for Idx<T> {
+    fn clone(&self) -> Idx<T> {
+        *self
+    }
+}
+
+impl<T> Copy for Idx<T> {}
+
+impl<T> PartialEq for Idx<T> {
+    fn eq(&self, other: &Idx<T>) -> bool {
+        self.index == other.index && self.generation == other.generation
+    }
+}
+
+impl<T> Eq for Idx<T> {}
+
+impl<T> Hash for Idx<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.index.hash(state);
+        self.generation.hash(state);
+    }
+}
+
+impl<T> fmt::Debug for Idx<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Idx({}v{})", self.index, self.generation)
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Entry<T> {
+    Occupied {
+        generation: u32,
+        value: T,
+    },
+    // Free slots form a linked list through `next_free`, so inserting
+    // reuses them without a search.
+    Free {
+        generation: u32,
+        next_free: Option<u32>,
+    },
+}
+
+// The handle index of slot `index`. Truncating it would let a new handle
+// alias slot 0 and slip past the generation check.
+fn slot(index: usize) -> u32 {
+    u32::try_from(index).expect("Arena is out of slots")
+}
+
+#[derive(Debug, Clone)]
+pub struct Arena<T> {
+    entries: Vec<Entry<T>>,
+    free_head: Option<u32>,
+    len: usize,
+}
+
+impl<T> Default for Arena<T> {
+    fn default() -> Arena<T> {
+        Arena::new()
+    }
+}
+
+impl<T> Arena<T> {
+    pub fn new() -> Arena<T> {
+        Arena::with_capacity(0)
+    }
+
+    pub fn with_capacity(capacity: usize) -> Arena<T> {
+        Arena {
+            entries: Vec::with_capacity(capacity),
+            free_head: None,
+            len: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn insert(&mut self, value: T) -> Idx<T> {
+        self.len += 1;
+        let (index, generation) = match self.free_head {
+            Some(index) => {
+                let generation = match self.entries[index as usize] {
+                    Entry::Free {
+                        generation,
+                        next_free,
+                    } => {
+                        self.free_head = next_free;
+                        generation
+                    }
+                    Entry::Occupied { .. } => unreachable!("free list points at a value"),
+                };
+                self.entries[index as usize] = Entry::Occupied { generation, value };
+                (index, generation)
+            }
+            None => {
+                let index = slot(self.entries.len());
+                self.entries.push(Entry::Occupied {
+                    generation: 0,
+                    value,
+                });
+                (index, 0)
+            }
+        };
+        Idx {
+            index,
+            generation,
+            marker: PhantomData,
+        }
+    }
+
+    // Whether `idx` still refers to a value, rather than one that has
+    // been removed.
+    pub fn contains(&self, idx: Idx<T>) -> bool {
+        self.get(idx).is_some()
+    }
+
+    pub fn get(&self, idx: Idx<T>) -> Option<&T> {
+        match self.entries.get(idx.index as usize) {
+            Some(&Entry::Occupied {
+                generation,
+                ref value,
+            }) if generation == idx.generation => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn get_mut(&mut self, idx: Idx<T>) -> Option<&mut T> {
+        match self.entries.get_mut(idx.index as usize) {
+            Some(&mut Entry::Occupied {
+                generation,
+                ref mut value,
+            }) if generation == idx.generation => Some(value),
+            _ => None,
+        }
+    }
+
+    // Takes the value out, or `None` if `idx` is stale. Its slot goes to
+    // a later `insert`, under a new generation.
+    pub fn remove(&mut self, idx: Idx<T>) -> Option<T> {
+        self.get(idx)?;
+        // Wrapping would take four billion removals from a single slot
+        let free = Entry::Free {
+            generation: idx.generation.wrapping_add(1),
+            next_free: self.free_head,
+        };
+        let entry = mem::replace(&mut self.entries[idx.index as usize], free);
+        self.free_head = Some(idx.index);
+        self.len -= 1;
+        match entry {
+            Entry::Occupied { value, .. } => Some(value),
+            Entry::Free { .. } => unreachable!("checked by get"),
+        }
+    }
+
+    // Removes every value. Handles from before stay stale for good,
+    // since generations carry on.
+    pub fn clear(&mut self) {
+        let indexes: Vec<Idx<T>> = self.iter().map(|(idx, _)| idx).collect();
+        for idx in indexes {
+            self.remove(idx);
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Idx<T>, &T)> {
+        self.entries
+            .iter()
+            .enumerate()
+            .filter_map(|(index, entry)| match *entry {
+                Entry::Occupied {
+                    generation,
+                    ref value,
+                } => Some((
+                    Idx {
+                        index: slot(index),
+                        generation,
+                        marker: PhantomData,
+                    },
+                    value,
+                )),
+                Entry::Free { .. } => None,
+            })
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Idx<T>, &mut T)> {
+        self.entries
+            .iter_mut()
+            .enumerate()
+            .filter_map(|(index, entry)| match *entry {
+                Entry::Occupied {
+                    generation,
+                    ref mut value,
+                } => Some((
+                    Idx {
+                        index: slot(index),
+                        generation,
+                        marker: PhantomData,
+                    },
+                    value,
+                )),
+                Entry::Free { .. } => None,
+            })
+    }
+}
+
+// Panics on a stale handle, like indexing a `Vec` out of bounds.
+impl<T> Index<Idx<T>> for Arena<T> {
+    type Output = T;
+
+    fn index(&self, idx: Idx<T>) -> &T {
+        match self.get(idx) {
+            Some(value) => value,
+            None => panic!("stale arena handle {:?}", idx),
+        }
+    }
+}
+
+impl<T> IndexMut<Idx<T>> for Arena<T> {
+    fn index_mut(&mut self, idx: Idx<T>) -> &mut T {
+        match self.get_mut(idx) {
+            Some(value) => value,
+            None => panic!("stale arena handle {:?}", idx),
+        }
+    }
+}
@@ -0,0 +1,204 @@
+// A shared pointer that copies on write. Cloning a `CowRc<T>` only bumps
+// a count, like `Rc<T>`, so a large value can be handed to every part of
+// a program that reads it. Asking for `&mut T` with `make_mut` clones the
+// value first if anyone else still shares it, so the change is never seen
+// through the other handles:
+//
+//     use pointers::cow_rc::CowRc;
+//
+//     let defaults = CowRc::new(vec![1, 2, 3]);
+//     let mut mine = defaults.clone();      // shared, nothing copied
+//     mine.make_mut().push(4);              // copied here, once
+//     assert_eq!(*defaults, [1, 2, 3]);
+//     assert_eq!(*mine, [1, 2, 3, 4]);
+//
+// With plain `Rc<T>` the caller has to spot sharing and clone by hand,
+// as `(*rc).clone()`, and easily clones when nothing is shared.
+// `Rc::make_mut` does the same job in the standard library; this one is
+// written out by hand to show the count and the copy at work.
+//
+// The count is a `Cell`, not atomic, so like `Rc` a `CowRc` stays on the
+// thread that made it: the raw pointer inside keeps it from being `Send`
+// or `Sync`.
+
+use std::cell::Cell;
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::mem;
+use std::ops::Deref;
+use std::ptr::NonNull;
+
+struct Inner<T> {
+    count: Cell<usize>,
+    value: T,
+}
+
+pub struct CowRc<T> {
+    ptr: NonNull<Inner<T>>,
+    // Tells the drop checker a `CowRc<T>` may drop an `Inner<T>`
+    marker: PhantomData<Inner<T>>,
+}
+
+impl<T> CowRc<T> {
+    pub fn new(value: T) -> CowRc<T> {
+        let inner = Box::new(Inner {
+            count: Cell::new(1),
+            value,
+        });
+        CowRc {
+            ptr: NonNull::from(Box::leak(inner)),
+            marker: PhantomData,
+        }
+    }
+
+    fn inner(&self) -> &Inner<T> {
+        // The allocation lives for as long as any handle to it
+        unsafe { self.ptr.as_ref() }
+    }
+
+    // Number of handles sharing the value, this one included.
+    pub fn count(this: &CowRc<T>) -> usize {
+        this.inner().count.get()
+    }
+
+    pub fn is_unique(this: &CowRc<T>) -> bool {
+        CowRc::count(this) == 1
+    }
+
+    // Whether both handles share one value, rather than equal copies.
+    pub fn ptr_eq(this: &CowRc<T>, other: &CowRc<T>) -> bool {
+        this.ptr == other.ptr
+    }
+
+    // `&mut T` if no other handle shares the value, without cloning.
+    pub fn get_mut(this: &mut CowRc<T>) -> Option<&mut T> {
+        if CowRc::is_unique(this) {
+            // Unique, and `this` is borrowed mutably, so nothing else
+            // can reach the value while the result lives
+            Some(unsafe { &mut this.ptr.as_mut().value })
+        } else {
+            None
+        }
+    }
+
+    // The value, if no other handle shares it. Otherwise the handle
+    // comes back unchanged.
+    pub fn try_unwrap(this: CowRc<T>) -> Result<T, CowRc<T>> {
+        if !CowRc::is_unique(&this) {
+            return Err(this);
+        }
+        let inner = unsafe { Box::from_raw(this.ptr.as_ptr()) };
+        // The box now owns the allocation, so `this` must not free it
+        mem::forget(this);
+        Ok(inner.value)
+    }
+}
+
+impl<T: Clone> CowRc<T> {
+    // `&mut T`, first cloning the value into a fresh allocation of its
+    // own if other handles share it. They keep the old value.
+    pub fn make_mut(&mut self) -> &mut T {
+        if !CowRc::is_unique(self) {
+            // Clone before touching the count, so a panicking `clone`
+            // leaves everything as it was
+            *self = CowRc::new(self.inner().value.clone());
+        }
+        unsafe { &mut self.ptr.as_mut().value }
+    }
+
+    // The value, cloned only if it is shared.
+    pub fn unwrap_or_clone(this: CowRc<T>) -> T {
+        CowRc::try_unwrap(this).unwrap_or_else(|shared| (*shared).clone())
+    }
+}
+
+impl<T> Clone for CowRc<T> {
+    fn clone(&self) -> CowRc<T> {
+        let count = self.inner().count.get();
+        // Only reachable by leaking handles with `mem::forget`
+        self.inner()
+            .count
+            .set(count.checked_add(1).expect("CowRc count overflowed"));
+        CowRc {
+            ptr: self.ptr,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Drop for CowRc<T> {
+    fn drop(&mut self) {
+        let count = self.inner().count.get() - 1;
+        self.inner().count.set(count);
+        if count == 0 {
+            // The last handle: nothing else can reach the allocation
+            unsafe { drop(Box::from_raw(self.ptr.as_ptr())) }
+        }
+    }
+}
+
+impl<T> Deref for CowRc<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.inner().value
+    }
+}
+
+impl<T> AsRef<T> for CowRc<T> {
+    fn as_ref(&self) -> &T {
+        self
+    }
+}
+
+impl<T> From<T> for CowRc<T> {
+    fn from(value: T) -> CowRc<T> {
+        CowRc::new(value)
+    }
+}
+
+impl<T: Default> Default for CowRc<T> {
+    fn default() -> CowRc<T> {
+        CowRc::new(T::default())
+    }
+}
+
+impl<T: PartialEq> PartialEq for CowRc<T> {
+    fn eq(&self, other: &CowRc<T>) -> bool {
+        **self == **other
+    }
+}
+
+impl<T: Eq> Eq for CowRc<T> {}
+
+impl<T: PartialOrd> PartialOrd for CowRc<T> {
+    fn partial_cmp(&self, other: &CowRc<T>) -> Option<Ordering> {
+        (**self).partial_cmp(&**other)
+    }
+}
+
+impl<T: Ord> Ord for CowRc<T> {
+    fn cmp(&self, other: &CowRc<T>) -> Ordering {
+        (**self).cmp(&**other)
+    }
+}
+
+impl<T: Hash> Hash for CowRc<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (**self).hash(state)
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for CowRc<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for CowRc<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
@@ -0,0 +1,384 @@
+// How types are laid out in memory: the size, alignment and field
+// offsets that `reference_pointer` in main.rs can only hint at by
+// printing addresses with `{:p}`.
+//
+//     #[macro_use]
+//     extern crate pointers;
+//
+//     struct Point {
+//         x: f64,
+//         y: i32,
+//     }
+//
+//     let layout = layout!(Point { x, y });
+//     println!("{}", layout);
+//
+// prints
+//
+//     Point: size 16, align 8, Option<Point> is 24 bytes (no niche)
+//       offset  size  field
+//            0     8  x: f64
+//            8     4  y: i32
+//           12     4  (padding)
+//
+// Rust is free to reorder fields, so offsets are listed in memory order
+// rather than in the order they were declared. A "niche" is a bit
+// pattern a type never uses, such as the null pointer for `Box<T>` or
+// values above 1 for `bool`, which `Option` takes over to mark `None`
+// without growing.
+
+use std::any;
+use std::fmt;
+use std::mem;
+use std::slice;
+
+// Lays out `$ty`, listing the given fields, which may be tuple struct
+// indexes such as `0`.
+#[macro_export]
+macro_rules! layout {
+    ($ty:ty { $($field:tt),* $(,)* }) => {{
+        let layout = $crate::layout::Layout::of::<$ty>();
+        $(
+            let (type_name, size) = $crate::layout::field_info(|value: &$ty| &value.$field);
+            let layout = layout.with_field(
+                stringify!($field),
+                type_name,
+                ::std::mem::offset_of!($ty, $field),
+                size,
+            );
+        )*
+        layout
+    }};
+    ($ty:ty) => {
+        $crate::layout::Layout::of::<$ty>()
+    };
+}
+
+// Type name and size of the field that `get` borrows, for `layout!`.
+#[doc(hidden)]
+pub fn field_info<T, F>(_get: fn(&T) -> &F) -> (String, usize) {
+    (type_name::<F>(), mem::size_of::<F>())
+}
+
+// The name of `T` without module paths, "Box<Option<u8>>" rather than
+// "alloc::boxed::Box<core::option::Option<u8>>".
+fn type_name<T>() -> String {
+    let mut name = String::new();
+    let mut rest = any::type_name::<T>();
+    while let Some(i) = rest.find("::") {
+        name.push_str(&rest[..i]);
+        let path_start = name
+            .trim_end_matches(|c: char| c.is_alphanumeric() || c == '_')
+            .len();
+        name.truncate(path_start);
+        rest = &rest[i + 2..];
+    }
+    name.push_str(rest);
+    name
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Field {
+    pub name: &'static str,
+    pub type_name: String,
+    pub offset: usize,
+    pub size: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Layout {
+    type_name: String,
+    size: usize,
+    align: usize,
+    option_size: usize,
+    // Sorted by offset
+    fields: Vec<Field>,
+}
+
+// A stretch of a value's bytes: a field, padding between fields, or the
+// whole value when no fields are listed.
+#[derive(Debug, Clone, Copy)]
+enum Span<'a> {
+    Field(&'a Field),
+    Padding { offset: usize, size: usize },
+    Whole { size: usize },
+}
+
+impl<'a> Span<'a> {
+    fn offset(self) -> usize {
+        match self {
+            Span::Field(field) => field.offset,
+            Span::Padding { offset, .. } => offset,
+            Span::Whole { .. } => 0,
+        }
+    }
+
+    fn size(self) -> usize {
+        match self {
+            Span::Field(field) => field.size,
+            Span::Padding { size, .. } | Span::Whole { size } => size,
+        }
+    }
+}
+
+impl<'a> fmt::Display for Span<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Span::Field(field) => write!(f, "{}: {}", field.name, field.type_name),
+            Span::Padding { .. } => write!(f, "(padding)"),
+            Span::Whole { .. } => write!(f, "(whole value)"),
+        }
+    }
+}
+
+impl Layout {
+    // Size, alignment and niche of `T`, without any fields. Use the
+    // `layout!` macro to list fields as well.
+    pub fn of<T>() -> Layout {
+        Layout {
+            type_name: type_name::<T>(),
+            size: mem::size_of::<T>(),
+            align: mem::align_of::<T>(),
+            option_size: mem::size_of::<Option<T>>(),
+            fields: Vec::new(),
+        }
+    }
+
+    // Adds a field, as `layout!` does from the struct definition.
+    //
+    // Panics if the field would reach past the end of the type.
+    pub fn with_field(
+        mut self,
+        name: &'static str,
+        type_name: String,
+        offset: usize,
+        size: usize,
+    ) -> Layout {
+        assert!(
+            offset + size <= self.size,
+            "field `{}` at {}..{} is outside {} bytes",
+            name,
+            offset,
+            offset + size,
+            self.size
+        );
+        let at = self
+            .fields
+            .iter()
+            .take_while(|f| f.offset <= offset)
+            .count();
+        self.fields.insert(
+            at,
+            Field {
+                name,
+                type_name,
+                offset,
+                size,
+            },
+        );
+        self
+    }
+
+    pub fn type_name(&self) -> &str {
+        &self.type_name
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn align(&self) -> usize {
+        self.align
+    }
+
+    pub fn fields(&self) -> &[Field] {
+        &self.fields
+    }
+
+    pub fn field(&self, name: &str) -> Option<&Field> {
+        self.fields.iter().find(|field| field.name == name)
+    }
+
+    // Size of `Option<T>`.
+    pub fn option_size(&self) -> usize {
+        self.option_size
+    }
+
+    // Whether `Option<T>` fits in the same space as `T`, by using a bit
+    // pattern `T` never has to mean `None`.
+    pub fn has_niche(&self) -> bool {
+        self.option_size == self.size
+    }
+
+    // Bytes not covered by any listed field. Only meaningful once every
+    // field has been listed.
+    pub fn padding(&self) -> usize {
+        self.spans()
+            .filter_map(|span| match span {
+                Span::Padding { size, .. } => Some(size),
+                _ => None,
+            })
+            .sum()
+    }
+
+    // The fields in memory order with the gaps between them.
+    fn spans(&self) -> impl Iterator<Item = Span<'_>> {
+        let mut spans = Vec::new();
+        if self.fields.is_empty() {
+            if self.size > 0 {
+                spans.push(Span::Whole { size: self.size });
+            }
+            return spans.into_iter();
+        }
+        let mut end = 0;
+        for field in &self.fields {
+            if field.offset > end {
+                spans.push(Span::Padding {
+                    offset: end,
+                    size: field.offset - end,
+                });
+            }
+            spans.push(Span::Field(field));
+            end = end.max(field.offset + field.size);
+        }
+        if self.size > end {
+            spans.push(Span::Padding {
+                offset: end,
+                size: self.size - end,
+            });
+        }
+        spans.into_iter()
+    }
+
+    /// The bytes of `value` as hex, next to the fields they belong to.
+    /// Padding is shown as `..` and never read, since it may not have
+    /// been initialised.
+    ///
+    /// Panics if this is not the layout of `T`.
+    ///
+    /// # Safety
+    ///
+    /// Each field's bytes are read as plain bytes, so every byte of every
+    /// field must be initialised. Integers, floats, references, `Box`,
+    /// `Rc` and `Option<Box<T>>` always are; a struct with padding of its
+    /// own or an enum such as `Option<u32>`, whose `None` leaves bytes
+    /// unset, may not be.
+    pub unsafe fn dump<T>(&self, value: &T) -> Dump {
+        assert!(
+            type_name::<T>() == self.type_name && mem::size_of::<T>() == self.size,
+            "layout of {} used to dump a {}",
+            self.type_name,
+            type_name::<T>()
+        );
+        let start = value as *const T as *const u8;
+        let rows = self
+            .spans()
+            .map(|span| {
+                let bytes = match span {
+                    Span::Padding { .. } => None,
+                    _ => {
+                        Some(slice::from_raw_parts(start.add(span.offset()), span.size()).to_vec())
+                    }
+                };
+                DumpRow {
+                    offset: span.offset(),
+                    size: span.size(),
+                    bytes,
+                    label: span.to_string(),
+                }
+            })
+            .collect();
+        Dump {
+            type_name: self.type_name.clone(),
+            address: start as usize,
+            rows,
+        }
+    }
+}
+
+impl fmt::Display for Layout {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}: size {}, align {}, Option<{}> is {} bytes ({})",
+            self.type_name,
+            self.size,
+            self.align,
+            self.type_name,
+            self.option_size,
+            if self.has_niche() {
+                "uses a niche"
+            } else {
+                "no niche"
+            }
+        )?;
+        if self.fields.is_empty() {
+            return Ok(());
+        }
+        write!(f, "\n  offset  size  field")?;
+        for span in self.spans() {
+            write!(f, "\n  {:>6}  {:>4}  {}", span.offset(), span.size(), span)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct DumpRow {
+    offset: usize,
+    size: usize,
+    // `None` for padding
+    bytes: Option<Vec<u8>>,
+    label: String,
+}
+
+// A value's bytes, as made by `Layout::dump`. Displays eight bytes to a
+// line, in memory order, so little endian numbers read backwards.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dump {
+    type_name: String,
+    address: usize,
+    rows: Vec<DumpRow>,
+}
+
+impl Dump {
+    // Bytes of the named field, `None` if there is no such field.
+    pub fn field_bytes(&self, name: &str) -> Option<&[u8]> {
+        let prefix = format!("{}: ", name);
+        self.rows
+            .iter()
+            .find(|row| row.label.starts_with(&prefix))
+            .and_then(|row| row.bytes.as_ref())
+            .map(|bytes| &bytes[..])
+    }
+}
+
+const BYTES_PER_LINE: usize = 8;
+
+impl fmt::Display for Dump {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} at {:#x}", self.type_name, self.address)?;
+        for row in &self.rows {
+            for line in 0..row.size.div_ceil(BYTES_PER_LINE) {
+                let from = line * BYTES_PER_LINE;
+                let to = row.size.min(from + BYTES_PER_LINE);
+                let mut hex = String::new();
+                for i in from..to {
+                    if i > from {
+                        hex.push(' ');
+                    }
+                    match row.bytes {
+                        Some(ref bytes) => hex.push_str(&format!("{:02x}", bytes[i])),
+                        None => hex.push_str(".."),
+                    }
+                }
+                if line == 0 {
+                    write!(f, "\n  {:>6}  {:<23}  {}", row.offset, hex, row.label)?;
+                } else {
+                    write!(f, "\n  {:>6}  {}", row.offset + from, hex)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,10 @@
+// Pointer-based data structures that build on the pointer types shown
+// in main.rs.
+
+pub mod arena;
+pub mod cow_rc;
+pub mod layout;
+pub mod linked_list;
+pub mod list;
+pub mod small;
+pub mod tree;
@@ -0,0 +1,622 @@
+// A doubly linked list built on the raw pointers from the table in
+// main.rs. Every node points at both its neighbours, which no owning
+// pointer can express: the `next` and `prev` pointers would each want
+// to own the same node. So the nodes are owned by the list as a whole,
+// linked with `*mut Node<T>`, and all the unsafe code stays inside this
+// module behind a safe API.
+//
+//     use pointers::linked_list::DoublyLinkedList;
+//
+//     let mut list: DoublyLinkedList<i32> = (1..5).collect();
+//     let mut cursor = list.cursor_front_mut();
+//     cursor.move_next();
+//     assert_eq!(cursor.remove_current(), Some(2));
+//     cursor.insert_before(9);
+//     assert_eq!(list.iter().collect::<Vec<_>>(), [&1, &9, &3, &4]);
+//
+// A `CursorMut` points at one node and can insert or remove next to it
+// in O(1), without the walk from one end that an index needs. Whole
+// lists are spliced in or split off in O(1) at a cursor as well.
+//
+// Nodes are allocated with `Box::into_raw` and freed only through
+// `Box::from_raw`. The tests avoid anything Miri cannot run, so
+// `cargo +nightly miri test --test linked_list` checks this module for
+// undefined behaviour.
+
+use std::fmt;
+use std::iter::FromIterator;
+use std::marker::PhantomData;
+use std::mem;
+use std::ptr;
+
+struct Node<T> {
+    elem: T,
+    // Both null at the ends of the list
+    prev: *mut Node<T>,
+    next: *mut Node<T>,
+}
+
+// The element of `node`, which must be null or point into a live list.
+// Only the element is borrowed, never the whole node, since neighbours
+// still write to its links through their own pointers.
+unsafe fn elem<'a, T>(node: *mut Node<T>) -> Option<&'a T> {
+    if node.is_null() {
+        None
+    } else {
+        Some(&(*node).elem)
+    }
+}
+
+unsafe fn elem_mut<'a, T>(node: *mut Node<T>) -> Option<&'a mut T> {
+    if node.is_null() {
+        None
+    } else {
+        Some(&mut (*node).elem)
+    }
+}
+
+pub struct DoublyLinkedList<T> {
+    head: *mut Node<T>,
+    tail: *mut Node<T>,
+    len: usize,
+    // Tells the compiler that dropping the list drops `T`s
+    marker: PhantomData<Box<Node<T>>>,
+}
+
+// The list owns its nodes outright, exactly like a `Vec<T>` owns its
+// elements, so it can cross threads whenever `T` can.
+unsafe impl<T: Send> Send for DoublyLinkedList<T> {}
+unsafe impl<T: Sync> Sync for DoublyLinkedList<T> {}
+
+impl<T> DoublyLinkedList<T> {
+    pub fn new() -> DoublyLinkedList<T> {
+        DoublyLinkedList {
+            head: ptr::null_mut(),
+            tail: ptr::null_mut(),
+            len: 0,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    // Links a new node holding `elem` between `prev` and `next`, which
+    // must be neighbours in this list, or null for the ends.
+    unsafe fn insert_between(&mut self, prev: *mut Node<T>, next: *mut Node<T>, elem: T) {
+        let node = Box::into_raw(Box::new(Node { elem, prev, next }));
+        if prev.is_null() {
+            self.head = node;
+        } else {
+            (*prev).next = node;
+        }
+        if next.is_null() {
+            self.tail = node;
+        } else {
+            (*next).prev = node;
+        }
+        self.len += 1;
+    }
+
+    // Unlinks `node`, which must be in this list, and frees it.
+    unsafe fn unlink(&mut self, node: *mut Node<T>) -> T {
+        let node = Box::from_raw(node);
+        if node.prev.is_null() {
+            self.head = node.next;
+        } else {
+            (*node.prev).next = node.next;
+        }
+        if node.next.is_null() {
+            self.tail = node.prev;
+        } else {
+            (*node.next).prev = node.prev;
+        }
+        self.len -= 1;
+        node.elem
+    }
+
+    // Moves all of `other` in between `prev` and `next`, as with
+    // `insert_between`.
+    unsafe fn splice_between(
+        &mut self,
+        prev: *mut Node<T>,
+        next: *mut Node<T>,
+        mut other: DoublyLinkedList<T>,
+    ) {
+        if other.is_empty() {
+            return;
+        }
+        let (first, last) = (other.head, other.tail);
+        self.len += other.len;
+        // `other` must not free the nodes it no longer owns
+        other.head = ptr::null_mut();
+        other.tail = ptr::null_mut();
+        other.len = 0;
+
+        (*first).prev = prev;
+        (*last).next = next;
+        if prev.is_null() {
+            self.head = first;
+        } else {
+            (*prev).next = first;
+        }
+        if next.is_null() {
+            self.tail = last;
+        } else {
+            (*next).prev = last;
+        }
+    }
+
+    // Cuts the list in front of `node`, which must be the node at index
+    // `at`, or null with `at` equal to the length. Returns everything
+    // from `node` on.
+    unsafe fn split_before_node(&mut self, node: *mut Node<T>, at: usize) -> DoublyLinkedList<T> {
+        if node.is_null() {
+            return DoublyLinkedList::new();
+        }
+        let prev = (*node).prev;
+        let back = DoublyLinkedList {
+            head: node,
+            tail: self.tail,
+            len: self.len - at,
+            marker: PhantomData,
+        };
+        (*node).prev = ptr::null_mut();
+        self.tail = prev;
+        if prev.is_null() {
+            self.head = ptr::null_mut();
+        } else {
+            (*prev).next = ptr::null_mut();
+        }
+        self.len = at;
+        back
+    }
+
+    pub fn push_front(&mut self, elem: T) {
+        let head = self.head;
+        unsafe { self.insert_between(ptr::null_mut(), head, elem) }
+    }
+
+    pub fn push_back(&mut self, elem: T) {
+        let tail = self.tail;
+        unsafe { self.insert_between(tail, ptr::null_mut(), elem) }
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.head.is_null() {
+            return None;
+        }
+        let head = self.head;
+        Some(unsafe { self.unlink(head) })
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.tail.is_null() {
+            return None;
+        }
+        let tail = self.tail;
+        Some(unsafe { self.unlink(tail) })
+    }
+
+    pub fn front(&self) -> Option<&T> {
+        unsafe { elem(self.head) }
+    }
+
+    pub fn back(&self) -> Option<&T> {
+        unsafe { elem(self.tail) }
+    }
+
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        unsafe { elem_mut(self.head) }
+    }
+
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        unsafe { elem_mut(self.tail) }
+    }
+
+    pub fn clear(&mut self) {
+        while self.pop_front().is_some() {}
+    }
+
+    // Moves every element of `other` to the end of this list in O(1),
+    // leaving `other` empty.
+    pub fn append(&mut self, other: &mut DoublyLinkedList<T>) {
+        let other = mem::take(other);
+        let tail = self.tail;
+        unsafe { self.splice_between(tail, ptr::null_mut(), other) }
+    }
+
+    // Splits the list in two at index `at`, returning everything from
+    // `at` on. Finding the split point walks from the nearer end.
+    //
+    // Panics if `at` is greater than the length.
+    pub fn split_off(&mut self, at: usize) -> DoublyLinkedList<T> {
+        assert!(at <= self.len, "split index {} out of bounds", at);
+        let node = if at <= self.len / 2 {
+            let mut node = self.head;
+            for _ in 0..at {
+                node = unsafe { (*node).next };
+            }
+            node
+        } else {
+            let mut node = self.tail;
+            for _ in at..self.len - 1 {
+                node = unsafe { (*node).prev };
+            }
+            if at == self.len {
+                ptr::null_mut()
+            } else {
+                node
+            }
+        };
+        unsafe { self.split_before_node(node, at) }
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            head: self.head,
+            tail: self.tail,
+            len: self.len,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            head: self.head,
+            tail: self.tail,
+            len: self.len,
+            marker: PhantomData,
+        }
+    }
+
+    // A cursor at the first element, or at the "ghost" position between
+    // the two ends when the list is empty.
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T> {
+        CursorMut {
+            current: self.head,
+            index: 0,
+            list: self,
+        }
+    }
+
+    pub fn cursor_back_mut(&mut self) -> CursorMut<'_, T> {
+        let index = self.len.saturating_sub(1);
+        CursorMut {
+            current: self.tail,
+            index,
+            list: self,
+        }
+    }
+}
+
+impl<T> Drop for DoublyLinkedList<T> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T> Default for DoublyLinkedList<T> {
+    fn default() -> DoublyLinkedList<T> {
+        DoublyLinkedList::new()
+    }
+}
+
+impl<T: Clone> Clone for DoublyLinkedList<T> {
+    fn clone(&self) -> DoublyLinkedList<T> {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T> FromIterator<T> for DoublyLinkedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> DoublyLinkedList<T> {
+        let mut list = DoublyLinkedList::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<T> Extend<T> for DoublyLinkedList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for elem in iter {
+            self.push_back(elem);
+        }
+    }
+}
+
+impl<T: PartialEq> PartialEq for DoublyLinkedList<T> {
+    fn eq(&self, other: &DoublyLinkedList<T>) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for DoublyLinkedList<T> {}
+
+impl<T: fmt::Debug> fmt::Debug for DoublyLinkedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+// Both ends move inwards; `len` says when they have met.
+pub struct Iter<'a, T: 'a> {
+    head: *mut Node<T>,
+    tail: *mut Node<T>,
+    len: usize,
+    marker: PhantomData<&'a T>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        unsafe {
+            let node = self.head;
+            self.head = (*node).next;
+            elem(node)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<&'a T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        unsafe {
+            let node = self.tail;
+            self.tail = (*node).prev;
+            elem(node)
+        }
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> {}
+
+pub struct IterMut<'a, T: 'a> {
+    head: *mut Node<T>,
+    tail: *mut Node<T>,
+    len: usize,
+    marker: PhantomData<&'a mut T>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        unsafe {
+            let node = self.head;
+            self.head = (*node).next;
+            elem_mut(node)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
+    fn next_back(&mut self) -> Option<&'a mut T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        unsafe {
+            let node = self.tail;
+            self.tail = (*node).prev;
+            elem_mut(node)
+        }
+    }
+}
+
+impl<'a, T> ExactSizeIterator for IterMut<'a, T> {}
+
+pub struct IntoIter<T> {
+    list: DoublyLinkedList<T>,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.list.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.len, Some(self.list.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<T> {
+        self.list.pop_back()
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+impl<T> IntoIterator for DoublyLinkedList<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter { list: self }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a DoublyLinkedList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut DoublyLinkedList<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+// A position in a list that can edit the list around it. Besides the
+// elements there is one "ghost" position, past the back and before the
+// front, so that moving off either end wraps around through it.
+pub struct CursorMut<'a, T: 'a> {
+    // Null at the ghost position
+    current: *mut Node<T>,
+    // Equal to the list's length at the ghost position
+    index: usize,
+    list: &'a mut DoublyLinkedList<T>,
+}
+
+impl<'a, T> CursorMut<'a, T> {
+    // The index of the current element, `None` at the ghost position.
+    pub fn index(&self) -> Option<usize> {
+        if self.current.is_null() {
+            None
+        } else {
+            Some(self.index)
+        }
+    }
+
+    pub fn current(&mut self) -> Option<&mut T> {
+        unsafe { elem_mut(self.current) }
+    }
+
+    fn next_node(&self) -> *mut Node<T> {
+        if self.current.is_null() {
+            self.list.head
+        } else {
+            unsafe { (*self.current).next }
+        }
+    }
+
+    fn prev_node(&self) -> *mut Node<T> {
+        if self.current.is_null() {
+            self.list.tail
+        } else {
+            unsafe { (*self.current).prev }
+        }
+    }
+
+    pub fn move_next(&mut self) {
+        let next = self.next_node();
+        self.index = if self.current.is_null() {
+            0
+        } else {
+            self.index + 1
+        };
+        self.current = next;
+    }
+
+    pub fn move_prev(&mut self) {
+        let prev = self.prev_node();
+        self.index = if prev.is_null() {
+            self.list.len
+        } else if self.current.is_null() {
+            self.list.len - 1
+        } else {
+            self.index - 1
+        };
+        self.current = prev;
+    }
+
+    pub fn peek_next(&mut self) -> Option<&mut T> {
+        unsafe { elem_mut(self.next_node()) }
+    }
+
+    pub fn peek_prev(&mut self) -> Option<&mut T> {
+        unsafe { elem_mut(self.prev_node()) }
+    }
+
+    // Inserts in front of the current element, or at the back of the
+    // list at the ghost position. The cursor stays where it is.
+    pub fn insert_before(&mut self, elem: T) {
+        let prev = self.prev_node();
+        unsafe { self.list.insert_between(prev, self.current, elem) };
+        self.index += 1;
+    }
+
+    // Inserts after the current element, or at the front of the list at
+    // the ghost position. The cursor stays where it is.
+    pub fn insert_after(&mut self, elem: T) {
+        let next = self.next_node();
+        unsafe { self.list.insert_between(self.current, next, elem) };
+        if self.current.is_null() {
+            self.index += 1;
+        }
+    }
+
+    // Removes the current element and moves to the next one. Does
+    // nothing at the ghost position.
+    pub fn remove_current(&mut self) -> Option<T> {
+        if self.current.is_null() {
+            return None;
+        }
+        let node = self.current;
+        self.current = self.next_node();
+        Some(unsafe { self.list.unlink(node) })
+    }
+
+    // Moves every element of `other` in front of the current one.
+    pub fn splice_before(&mut self, other: DoublyLinkedList<T>) {
+        let prev = self.prev_node();
+        self.index += other.len;
+        unsafe { self.list.splice_between(prev, self.current, other) };
+    }
+
+    // Moves every element of `other` after the current one.
+    pub fn splice_after(&mut self, other: DoublyLinkedList<T>) {
+        let next = self.next_node();
+        if self.current.is_null() {
+            self.index += other.len;
+        }
+        unsafe { self.list.splice_between(self.current, next, other) };
+    }
+
+    // Takes off and returns everything after the current element, or
+    // the whole list at the ghost position.
+    pub fn split_after(&mut self) -> DoublyLinkedList<T> {
+        let (next, at) = if self.current.is_null() {
+            (self.list.head, 0)
+        } else {
+            (self.next_node(), self.index + 1)
+        };
+        let back = unsafe { self.list.split_before_node(next, at) };
+        if self.current.is_null() {
+            self.index = 0;
+        }
+        back
+    }
+
+    // Takes off and returns everything before the current element, or
+    // the whole list at the ghost position.
+    pub fn split_before(&mut self) -> DoublyLinkedList<T> {
+        let back = unsafe { self.list.split_before_node(self.current, self.index) };
+        self.index = 0;
+        mem::replace(self.list, back)
+    }
+}
@@ -0,0 +1,210 @@
+// A persistent list: the `List` from `best_practices` in main.rs, but
+// with its tail behind a reference counted pointer instead of a `Box`.
+// A list is never changed in place. `cons` makes a new list that shares
+// the old one as its tail, so keeping every past version around costs
+// one node per version, and cloning a list only bumps a count.
+//
+//     use pointers::list::List;
+//
+//     let empty = List::new();
+//     let one = empty.cons(1);
+//     let two = one.cons(2);
+//     assert_eq!(two.tail(), one);
+//     assert_eq!(two.iter().collect::<Vec<_>>(), [&2, &1]);
+//
+// `list::List` shares its nodes through `Rc`, and `list::sync::List`
+// through `Arc` so that it can be sent to other threads. Both have the
+// same methods.
+
+macro_rules! persistent_list {
+    ($ptr:ident) => {
+        use std::fmt;
+        use std::iter::FromIterator;
+
+        struct Node<T> {
+            elem: T,
+            next: Option<$ptr<Node<T>>>,
+        }
+
+        // Dropping the nodes one by one, so that a long list does not
+        // recurse once per node and overflow the stack. Stops at the
+        // first node another list still shares.
+        impl<T> Drop for Node<T> {
+            fn drop(&mut self) {
+                let mut next = self.next.take();
+                while let Some(mut node) = next.and_then($ptr::into_inner) {
+                    next = node.next.take();
+                }
+            }
+        }
+
+        pub struct List<T> {
+            head: Option<$ptr<Node<T>>>,
+            len: usize,
+        }
+
+        impl<T> List<T> {
+            pub fn new() -> List<T> {
+                List { head: None, len: 0 }
+            }
+
+            // A new list with `elem` in front of this one, which is left
+            // as it was.
+            pub fn cons(&self, elem: T) -> List<T> {
+                List {
+                    head: Some($ptr::new(Node {
+                        elem,
+                        next: self.head.clone(),
+                    })),
+                    len: self.len + 1,
+                }
+            }
+
+            pub fn head(&self) -> Option<&T> {
+                self.head.as_ref().map(|node| &node.elem)
+            }
+
+            // Everything after the head, sharing this list's nodes. The
+            // tail of an empty list is empty.
+            pub fn tail(&self) -> List<T> {
+                match self.head {
+                    Some(ref node) => List {
+                        head: node.next.clone(),
+                        len: self.len - 1,
+                    },
+                    None => List::new(),
+                }
+            }
+
+            pub fn len(&self) -> usize {
+                self.len
+            }
+
+            pub fn is_empty(&self) -> bool {
+                self.head.is_none()
+            }
+
+            pub fn iter(&self) -> Iter<'_, T> {
+                Iter {
+                    next: self.head.as_ref().map(|node| &**node),
+                    len: self.len,
+                }
+            }
+
+            // Whether both lists are the very same nodes, not just equal
+            // elements.
+            pub fn ptr_eq(&self, other: &List<T>) -> bool {
+                match (&self.head, &other.head) {
+                    (Some(a), Some(b)) => $ptr::ptr_eq(a, b),
+                    (None, None) => true,
+                    _ => false,
+                }
+            }
+
+            pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> List<U> {
+                self.iter().map(f).collect()
+            }
+        }
+
+        impl<T: Clone> List<T> {
+            // A new list in reverse order. Nothing can be shared, so the
+            // elements are cloned.
+            pub fn rev(&self) -> List<T> {
+                self.iter()
+                    .fold(List::new(), |list, elem| list.cons(elem.clone()))
+            }
+
+            pub fn filter<P: FnMut(&T) -> bool>(&self, mut predicate: P) -> List<T> {
+                self.iter()
+                    .filter(|elem| predicate(elem))
+                    .cloned()
+                    .collect()
+            }
+        }
+
+        // Cheap: the clone shares every node.
+        impl<T> Clone for List<T> {
+            fn clone(&self) -> List<T> {
+                List {
+                    head: self.head.clone(),
+                    len: self.len,
+                }
+            }
+        }
+
+        impl<T> Default for List<T> {
+            fn default() -> List<T> {
+                List::new()
+            }
+        }
+
+        // Keeps the order of the iterator, so the first element becomes
+        // the head.
+        impl<T> FromIterator<T> for List<T> {
+            fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> List<T> {
+                let elems: Vec<T> = iter.into_iter().collect();
+                let mut list = List::new();
+                for elem in elems.into_iter().rev() {
+                    list = list.cons(elem);
+                }
+                list
+            }
+        }
+
+        impl<T: PartialEq> PartialEq for List<T> {
+            fn eq(&self, other: &List<T>) -> bool {
+                self.len == other.len && self.iter().eq(other.iter())
+            }
+        }
+
+        impl<T: Eq> Eq for List<T> {}
+
+        impl<T: fmt::Debug> fmt::Debug for List<T> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.debug_list().entries(self.iter()).finish()
+            }
+        }
+
+        pub struct Iter<'a, T: 'a> {
+            next: Option<&'a Node<T>>,
+            len: usize,
+        }
+
+        impl<'a, T> Iterator for Iter<'a, T> {
+            type Item = &'a T;
+
+            fn next(&mut self) -> Option<&'a T> {
+                self.next.map(|node| {
+                    self.next = node.next.as_ref().map(|next| &**next);
+                    self.len -= 1;
+                    &node.elem
+                })
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                (self.len, Some(self.len))
+            }
+        }
+
+        impl<'a, T> ExactSizeIterator for Iter<'a, T> {}
+
+        impl<'a, T> IntoIterator for &'a List<T> {
+            type Item = &'a T;
+            type IntoIter = Iter<'a, T>;
+
+            fn into_iter(self) -> Iter<'a, T> {
+                self.iter()
+            }
+        }
+    };
+}
+
+use std::rc::Rc;
+
+persistent_list!(Rc);
+
+pub mod sync {
+    use std::sync::Arc;
+
+    persistent_list!(Arc);
+}
@@ -1,3 +1,6 @@
+#[macro_use]
+extern crate pointers;
+
 fn main() {
     // Rust: use them for a deliberate purpose
 
@@ -28,6 +31,21 @@ fn main() {
     // Arc<T>       Arc pointer             Heap allocated T with many readers + safe sharing across threads
     // *const T     Raw pointer             Unsafe read access to T
     // *mut T       Mutable raw pointer     Unsafe read/write access to T
+    // CowRc<T>     copy-on-write pointer   Like Rc<T>, but writing through a shared one clones T first
+    // SmallVec<T>  small vector            Up to N items inline, on the heap only beyond that
+
+    let defaults = pointers::cow_rc::CowRc::new(vec!["a", "b"]);
+    let mut custom = defaults.clone();
+    custom.make_mut().push("c");
+    println!("{:?} left alone by {:?}", defaults, custom);
+
+    // Raw pointers can express what the others cannot, such as nodes
+    // that point at both neighbours, and can be wrapped in a safe API:
+    let mut recent: pointers::linked_list::DoublyLinkedList<&str> =
+        vec!["a", "b", "c"].into_iter().collect();
+    recent.push_front("d");
+    recent.pop_back();
+    println!("{:?}", recent);
 }
 
 // 1. Basic `reference` pointer
@@ -46,6 +64,11 @@ fn reference_pointer() {
     println!("Dereference z: {}", *z);
     println!("Auto dereference referent z to value: {}", z);
 
+    // The address is only part of the story: layout! shows how big a
+    // pointer is and whether Option can hide None in it for free
+    println!("{}", layout!(&i32));
+    println!("{}", layout!(Option<Box<i32>>));
+
     println!("x + z = {}", x + *z);
 
     let a = 2;
@@ -159,6 +182,20 @@ fn best_practices() {
     // the reference to another List must be a Box because of unknown
     // length of the list. Since length is unknown, size is unknown too
     // and therefore, we need heap to allocate our list
+
+    // With an Rc instead of a Box several lists can share one tail, so
+    // "changing" a list means making a new one in front of the old:
+    let before: pointers::list::List<i32> = vec![2, 3].into_iter().collect();
+    let after = before.cons(1);
+    println!("{:?} is still there after {:?}", before, after);
+
+    // A Box cannot point back at its parent. Keeping the nodes in an
+    // arena and linking them by index can, with one owner for them all:
+    let mut tree = pointers::tree::Tree::new();
+    let root = tree.new_node("src");
+    let file = tree.append_child(root, "main.rs").unwrap();
+    let parent = tree.get(file).unwrap().parent().unwrap();
+    println!("{} is in {}", tree.get(file).unwrap().value(), tree.get(parent).unwrap().value());
 }
 
 fn patterns_and_ref() {
@@ -0,0 +1,385 @@
+// Owned vectors and strings that keep short values inline. A `Vec<T>`
+// or `String` always puts its items on the heap, even one or two of
+// them. `SmallVec<T, N>` keeps up to `N` items inside itself, wherever
+// it lives, and only moves them to a `Vec` on the heap ("spills") once
+// there are more. Most keys, names and tags are short, so most of them
+// never allocate:
+//
+//     use pointers::small::SmallString;
+//
+//     let mut name: SmallString<16> = SmallString::from("wheel");
+//     assert!(!name.spilled());
+//     name.push_str("-front-left-spare");
+//     assert!(name.spilled());
+//
+// The price is size: a `SmallVec` is as big as its inline buffer plus a
+// length, or as a `Vec`, whichever is larger. Check with `layout!`
+// before picking `N`.
+
+use std::borrow::Borrow;
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::iter::FromIterator;
+use std::mem::{self, MaybeUninit};
+use std::ops::{Deref, DerefMut};
+use std::ptr;
+use std::slice;
+use std::str;
+
+enum Data<T, const N: usize> {
+    // Items `..len` are initialised, the rest are not
+    Inline {
+        len: usize,
+        items: [MaybeUninit<T>; N],
+    },
+    // Once spilled, stays on the heap even if it shrinks again
+    Heap(Vec<T>),
+}
+
+pub struct SmallVec<T, const N: usize> {
+    data: Data<T, N>,
+}
+
+impl<T, const N: usize> SmallVec<T, N> {
+    pub fn new() -> SmallVec<T, N> {
+        SmallVec {
+            data: Data::Inline {
+                len: 0,
+                items: [const { MaybeUninit::uninit() }; N],
+            },
+        }
+    }
+
+    // Whether the items have moved to the heap.
+    pub fn spilled(&self) -> bool {
+        match self.data {
+            Data::Inline { .. } => false,
+            Data::Heap(_) => true,
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        match self.data {
+            Data::Inline { .. } => N,
+            Data::Heap(ref vec) => vec.capacity(),
+        }
+    }
+
+    pub fn push(&mut self, item: T) {
+        if let Data::Inline {
+            ref mut len,
+            ref mut items,
+        } = self.data
+        {
+            if *len < N {
+                items[*len].write(item);
+                *len += 1;
+                return;
+            }
+            // Allocate before taking anything out, so a failed
+            // allocation leaves the items in place
+            let mut vec = Vec::with_capacity(N.max(1) * 2);
+            let full = *len;
+            *len = 0;
+            for slot in &items[..full] {
+                // Each initialised item is read exactly once, and `len`
+                // is already 0 so they are not dropped again inline
+                vec.push(unsafe { slot.assume_init_read() });
+            }
+            self.data = Data::Heap(vec);
+        }
+        match self.data {
+            Data::Heap(ref mut vec) => vec.push(item),
+            Data::Inline { .. } => unreachable!("spilled above"),
+        }
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        match self.data {
+            Data::Inline {
+                ref mut len,
+                ref items,
+            } => {
+                if *len == 0 {
+                    return None;
+                }
+                *len -= 1;
+                // No longer counted as initialised, so read only here
+                Some(unsafe { items[*len].assume_init_read() })
+            }
+            Data::Heap(ref mut vec) => vec.pop(),
+        }
+    }
+
+    pub fn clear(&mut self) {
+        match self.data {
+            Data::Inline {
+                ref mut len,
+                ref mut items,
+            } => {
+                let initialised = *len;
+                // Forget the items before dropping them, so a panicking
+                // drop leaks the rest rather than dropping them twice
+                *len = 0;
+                unsafe {
+                    ptr::drop_in_place(ptr::slice_from_raw_parts_mut(
+                        items.as_mut_ptr() as *mut T,
+                        initialised,
+                    ))
+                }
+            }
+            Data::Heap(ref mut vec) => vec.clear(),
+        }
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        match self.data {
+            Data::Inline { len, ref items } => unsafe {
+                slice::from_raw_parts(items.as_ptr() as *const T, len)
+            },
+            Data::Heap(ref vec) => vec,
+        }
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        match self.data {
+            Data::Inline { len, ref mut items } => unsafe {
+                slice::from_raw_parts_mut(items.as_mut_ptr() as *mut T, len)
+            },
+            Data::Heap(ref mut vec) => vec,
+        }
+    }
+
+    pub fn into_vec(mut self) -> Vec<T> {
+        match self.data {
+            Data::Heap(ref mut vec) => mem::take(vec),
+            Data::Inline { .. } => {
+                let mut vec = Vec::with_capacity(self.len());
+                vec.extend(self.drain_inline());
+                vec
+            }
+        }
+    }
+
+    // Moves the inline items out, oldest first, leaving none behind.
+    fn drain_inline(&mut self) -> impl Iterator<Item = T> + '_ {
+        let (full, items) = match self.data {
+            Data::Inline {
+                ref mut len,
+                ref items,
+            } => (mem::replace(len, 0), items),
+            Data::Heap(_) => unreachable!("only called inline"),
+        };
+        // Read each of the previously initialised items once
+        items[..full]
+            .iter()
+            .map(|slot| unsafe { slot.assume_init_read() })
+    }
+}
+
+impl<T, const N: usize> Drop for SmallVec<T, N> {
+    fn drop(&mut self) {
+        // A `Vec` drops its own items
+        self.clear();
+    }
+}
+
+impl<T, const N: usize> Default for SmallVec<T, N> {
+    fn default() -> SmallVec<T, N> {
+        SmallVec::new()
+    }
+}
+
+impl<T, const N: usize> Deref for SmallVec<T, N> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T, const N: usize> DerefMut for SmallVec<T, N> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T, const N: usize> Extend<T> for SmallVec<T, N> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.push(item);
+        }
+    }
+}
+
+impl<T, const N: usize> FromIterator<T> for SmallVec<T, N> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> SmallVec<T, N> {
+        let mut vec = SmallVec::new();
+        vec.extend(iter);
+        vec
+    }
+}
+
+impl<'a, T: Clone, const N: usize> From<&'a [T]> for SmallVec<T, N> {
+    fn from(items: &'a [T]) -> SmallVec<T, N> {
+        items.iter().cloned().collect()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a SmallVec<T, N> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter(self) -> slice::Iter<'a, T> {
+        self.iter()
+    }
+}
+
+// Clones inline again if the items fit, even from a spilled vector.
+impl<T: Clone, const N: usize> Clone for SmallVec<T, N> {
+    fn clone(&self) -> SmallVec<T, N> {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for SmallVec<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq for SmallVec<T, N> {
+    fn eq(&self, other: &SmallVec<T, N>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for SmallVec<T, N> {}
+
+impl<T: PartialOrd, const N: usize> PartialOrd for SmallVec<T, N> {
+    fn partial_cmp(&self, other: &SmallVec<T, N>) -> Option<Ordering> {
+        self.as_slice().partial_cmp(other.as_slice())
+    }
+}
+
+impl<T: Ord, const N: usize> Ord for SmallVec<T, N> {
+    fn cmp(&self, other: &SmallVec<T, N>) -> Ordering {
+        self.as_slice().cmp(other.as_slice())
+    }
+}
+
+impl<T: Hash, const N: usize> Hash for SmallVec<T, N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state)
+    }
+}
+
+// A `SmallVec` of bytes that are always valid UTF-8.
+#[derive(Clone, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SmallString<const N: usize> {
+    bytes: SmallVec<u8, N>,
+}
+
+impl<const N: usize> SmallString<N> {
+    pub fn new() -> SmallString<N> {
+        SmallString {
+            bytes: SmallVec::new(),
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        // Only ever filled from `&str`s and `char`s
+        unsafe { str::from_utf8_unchecked(&self.bytes) }
+    }
+
+    pub fn spilled(&self) -> bool {
+        self.bytes.spilled()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.bytes.capacity()
+    }
+
+    pub fn push_str(&mut self, s: &str) {
+        self.bytes.extend(s.bytes());
+    }
+
+    pub fn push(&mut self, c: char) {
+        self.push_str(c.encode_utf8(&mut [0; 4]));
+    }
+
+    pub fn clear(&mut self) {
+        self.bytes.clear();
+    }
+}
+
+impl<const N: usize> Deref for SmallString<N> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> Borrow<str> for SmallString<N> {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+// Hashes as a `str`, to agree with `Borrow<str>`.
+impl<const N: usize> Hash for SmallString<N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+impl<'a, const N: usize> From<&'a str> for SmallString<N> {
+    fn from(s: &'a str) -> SmallString<N> {
+        let mut string = SmallString::new();
+        string.push_str(s);
+        string
+    }
+}
+
+impl<const N: usize> FromIterator<char> for SmallString<N> {
+    fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> SmallString<N> {
+        let mut string = SmallString::new();
+        for c in iter {
+            string.push(c);
+        }
+        string
+    }
+}
+
+impl<const N: usize> fmt::Write for SmallString<N> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.push_str(s);
+        Ok(())
+    }
+}
+
+impl<const N: usize> PartialEq<str> for SmallString<N> {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<'a, const N: usize> PartialEq<&'a str> for SmallString<N> {
+    fn eq(&self, other: &&'a str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<const N: usize> fmt::Debug for SmallString<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl<const N: usize> fmt::Display for SmallString<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
@@ -0,0 +1,364 @@
+// An n-ary tree kept in an `Arena`. With `Box` a node can own its
+// children but cannot point back at its parent, and with `Rc` the back
+// pointers need `Weak` and `RefCell` everywhere. Here every link, up,
+// down or sideways, is just a `NodeId`, and the tree owns all nodes.
+//
+//     use pointers::tree::Tree;
+//
+//     let mut tree = Tree::new();
+//     let root = tree.new_node("root");
+//     let a = tree.append_child(root, "a").unwrap();
+//     let b = tree.append_child(root, "b").unwrap();
+//     assert_eq!(tree.get(b).unwrap().parent(), Some(root));
+//     assert_eq!(tree.children(root).collect::<Vec<_>>(), [a, b]);
+//
+// A tree may hold several roots, one for every node without a parent.
+// Ids are arena handles, so an id of a removed node is recognised as
+// stale: lookups give `None` and edits are refused.
+
+use arena::{Arena, Idx};
+
+pub type NodeId<T> = Idx<Node<T>>;
+
+#[derive(Debug, Clone)]
+pub struct Node<T> {
+    value: T,
+    parent: Option<NodeId<T>>,
+    first_child: Option<NodeId<T>>,
+    last_child: Option<NodeId<T>>,
+    prev_sibling: Option<NodeId<T>>,
+    next_sibling: Option<NodeId<T>>,
+}
+
+impl<T> Node<T> {
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    pub fn value_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+
+    pub fn parent(&self) -> Option<NodeId<T>> {
+        self.parent
+    }
+
+    pub fn first_child(&self) -> Option<NodeId<T>> {
+        self.first_child
+    }
+
+    pub fn last_child(&self) -> Option<NodeId<T>> {
+        self.last_child
+    }
+
+    pub fn prev_sibling(&self) -> Option<NodeId<T>> {
+        self.prev_sibling
+    }
+
+    pub fn next_sibling(&self) -> Option<NodeId<T>> {
+        self.next_sibling
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Tree<T> {
+    nodes: Arena<Node<T>>,
+}
+
+impl<T> Default for Tree<T> {
+    fn default() -> Tree<T> {
+        Tree::new()
+    }
+}
+
+impl<T> Tree<T> {
+    pub fn new() -> Tree<T> {
+        Tree {
+            nodes: Arena::new(),
+        }
+    }
+
+    // Number of nodes, counting every root and its descendants.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    pub fn contains(&self, id: NodeId<T>) -> bool {
+        self.nodes.contains(id)
+    }
+
+    pub fn get(&self, id: NodeId<T>) -> Option<&Node<T>> {
+        self.nodes.get(id)
+    }
+
+    pub fn get_mut(&mut self, id: NodeId<T>) -> Option<&mut Node<T>> {
+        self.nodes.get_mut(id)
+    }
+
+    // Adds a new root.
+    pub fn new_node(&mut self, value: T) -> NodeId<T> {
+        self.nodes.insert(Node {
+            value,
+            parent: None,
+            first_child: None,
+            last_child: None,
+            prev_sibling: None,
+            next_sibling: None,
+        })
+    }
+
+    // Links the root `id` in under `parent`, between the siblings `prev`
+    // and `next`, either of which is `None` at the ends.
+    fn link(
+        &mut self,
+        id: NodeId<T>,
+        parent: NodeId<T>,
+        prev: Option<NodeId<T>>,
+        next: Option<NodeId<T>>,
+    ) {
+        {
+            let node = &mut self.nodes[id];
+            node.parent = Some(parent);
+            node.prev_sibling = prev;
+            node.next_sibling = next;
+        }
+        match prev {
+            Some(prev) => self.nodes[prev].next_sibling = Some(id),
+            None => self.nodes[parent].first_child = Some(id),
+        }
+        match next {
+            Some(next) => self.nodes[next].prev_sibling = Some(id),
+            None => self.nodes[parent].last_child = Some(id),
+        }
+    }
+
+    // Turns `id` into a root, closing the gap among its old siblings.
+    fn unlink(&mut self, id: NodeId<T>) {
+        let (parent, prev, next) = {
+            let node = &mut self.nodes[id];
+            (
+                node.parent.take(),
+                node.prev_sibling.take(),
+                node.next_sibling.take(),
+            )
+        };
+        let parent = match parent {
+            Some(parent) => parent,
+            None => return,
+        };
+        match prev {
+            Some(prev) => self.nodes[prev].next_sibling = next,
+            None => self.nodes[parent].first_child = next,
+        }
+        match next {
+            Some(next) => self.nodes[next].prev_sibling = prev,
+            None => self.nodes[parent].last_child = prev,
+        }
+    }
+
+    // Adds `value` as the last child of `parent`, or returns `None` if
+    // `parent` is stale.
+    pub fn append_child(&mut self, parent: NodeId<T>, value: T) -> Option<NodeId<T>> {
+        let last = self.get(parent)?.last_child;
+        let id = self.new_node(value);
+        self.link(id, parent, last, None);
+        Some(id)
+    }
+
+    pub fn prepend_child(&mut self, parent: NodeId<T>, value: T) -> Option<NodeId<T>> {
+        let first = self.get(parent)?.first_child;
+        let id = self.new_node(value);
+        self.link(id, parent, None, first);
+        Some(id)
+    }
+
+    // Adds `value` as the sibling right after `sibling`, or returns
+    // `None` if `sibling` is stale or a root.
+    pub fn insert_after(&mut self, sibling: NodeId<T>, value: T) -> Option<NodeId<T>> {
+        let (parent, next) = {
+            let node = self.get(sibling)?;
+            (node.parent?, node.next_sibling)
+        };
+        let id = self.new_node(value);
+        self.link(id, parent, Some(sibling), next);
+        Some(id)
+    }
+
+    pub fn insert_before(&mut self, sibling: NodeId<T>, value: T) -> Option<NodeId<T>> {
+        let (parent, prev) = {
+            let node = self.get(sibling)?;
+            (node.parent?, node.prev_sibling)
+        };
+        let id = self.new_node(value);
+        self.link(id, parent, prev, Some(sibling));
+        Some(id)
+    }
+
+    // Moves `child` and its subtree to the end of `parent`'s children.
+    // Returns false, changing nothing, if either is stale or `parent`
+    // lies inside the subtree, which would make a cycle.
+    pub fn append(&mut self, parent: NodeId<T>, child: NodeId<T>) -> bool {
+        if !self.contains(parent) || !self.contains(child) {
+            return false;
+        }
+        if parent == child || self.ancestors(parent).any(|id| id == child) {
+            return false;
+        }
+        self.unlink(child);
+        let last = self.nodes[parent].last_child;
+        self.link(child, parent, last, None);
+        true
+    }
+
+    // Cuts `id` and its subtree loose from its parent, making it a root.
+    pub fn detach(&mut self, id: NodeId<T>) -> bool {
+        if !self.contains(id) {
+            return false;
+        }
+        self.unlink(id);
+        true
+    }
+
+    // Removes `id` with all of its descendants, returning the value of
+    // `id`. Ids of every removed node become stale.
+    pub fn remove_subtree(&mut self, id: NodeId<T>) -> Option<T> {
+        if !self.contains(id) {
+            return None;
+        }
+        self.unlink(id);
+        let removed: Vec<NodeId<T>> = self.descendants(id).skip(1).collect();
+        for descendant in removed {
+            self.nodes.remove(descendant);
+        }
+        self.nodes.remove(id).map(|node| node.value)
+    }
+
+    // Every node without a parent.
+    pub fn roots(&self) -> impl Iterator<Item = NodeId<T>> + '_ {
+        self.nodes
+            .iter()
+            .filter(|&(_, node)| node.parent.is_none())
+            .map(|(id, _)| id)
+    }
+
+    pub fn children(&self, id: NodeId<T>) -> Siblings<'_, T> {
+        Siblings {
+            tree: self,
+            next: self.get(id).and_then(|node| node.first_child),
+            forwards: true,
+        }
+    }
+
+    // The siblings after `id`, nearest first.
+    pub fn following_siblings(&self, id: NodeId<T>) -> Siblings<'_, T> {
+        Siblings {
+            tree: self,
+            next: self.get(id).and_then(|node| node.next_sibling),
+            forwards: true,
+        }
+    }
+
+    // The siblings before `id`, nearest first.
+    pub fn preceding_siblings(&self, id: NodeId<T>) -> Siblings<'_, T> {
+        Siblings {
+            tree: self,
+            next: self.get(id).and_then(|node| node.prev_sibling),
+            forwards: false,
+        }
+    }
+
+    // The parent of `id`, its parent, and so on up to the root.
+    pub fn ancestors(&self, id: NodeId<T>) -> Ancestors<'_, T> {
+        Ancestors {
+            tree: self,
+            next: self.get(id).and_then(|node| node.parent),
+        }
+    }
+
+    // `id` and everything below it, depth first, parents before their
+    // children.
+    pub fn descendants(&self, id: NodeId<T>) -> Descendants<'_, T> {
+        Descendants {
+            tree: self,
+            root: id,
+            next: if self.contains(id) { Some(id) } else { None },
+        }
+    }
+
+    // Number of ancestors, 0 for a root.
+    pub fn depth(&self, id: NodeId<T>) -> usize {
+        self.ancestors(id).count()
+    }
+}
+
+pub struct Siblings<'a, T: 'a> {
+    tree: &'a Tree<T>,
+    next: Option<NodeId<T>>,
+    forwards: bool,
+}
+
+impl<'a, T> Iterator for Siblings<'a, T> {
+    type Item = NodeId<T>;
+
+    fn next(&mut self) -> Option<NodeId<T>> {
+        let id = self.next?;
+        let node = &self.tree.nodes[id];
+        self.next = if self.forwards {
+            node.next_sibling
+        } else {
+            node.prev_sibling
+        };
+        Some(id)
+    }
+}
+
+pub struct Ancestors<'a, T: 'a> {
+    tree: &'a Tree<T>,
+    next: Option<NodeId<T>>,
+}
+
+impl<'a, T> Iterator for Ancestors<'a, T> {
+    type Item = NodeId<T>;
+
+    fn next(&mut self) -> Option<NodeId<T>> {
+        let id = self.next?;
+        self.next = self.tree.nodes[id].parent;
+        Some(id)
+    }
+}
+
+// Walks the links alone, without a stack: down to the first child if
+// there is one, otherwise to the next sibling of the nearest node on the
+// way back up that has one, stopping at `root`.
+pub struct Descendants<'a, T: 'a> {
+    tree: &'a Tree<T>,
+    root: NodeId<T>,
+    next: Option<NodeId<T>>,
+}
+
+impl<'a, T> Iterator for Descendants<'a, T> {
+    type Item = NodeId<T>;
+
+    fn next(&mut self) -> Option<NodeId<T>> {
+        let id = self.next?;
+        let nodes = &self.tree.nodes;
+        self.next = nodes[id].first_child.or_else(|| {
+            let mut up = id;
+            loop {
+                if up == self.root {
+                    return None;
+                }
+                let node = &nodes[up];
+                if node.next_sibling.is_some() {
+                    return node.next_sibling;
+                }
+                up = node.parent?;
+            }
+        });
+        Some(id)
+    }
+}
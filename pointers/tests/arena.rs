@@ -0,0 +1,76 @@
+extern crate pointers;
+
+use pointers::arena::Arena;
+use std::collections::HashSet;
+
+#[test]
+fn inserts_and_looks_up_values() {
+    let mut arena = Arena::new();
+    let a = arena.insert("a".to_string());
+    let b = arena.insert("b".to_string());
+    assert_ne!(a, b);
+    assert_eq!((arena.len(), &arena[a][..], &arena[b][..]), (2, "a", "b"));
+
+    arena[a].push('!');
+    arena.get_mut(b).unwrap().push('?');
+    assert_eq!(arena.get(a).map(|s| &s[..]), Some("a!"));
+    assert_eq!(arena.get(b).map(|s| &s[..]), Some("b?"));
+}
+
+#[test]
+fn stale_handles_are_caught_after_their_slot_is_reused() {
+    let mut arena = Arena::new();
+    let old = arena.insert(1);
+    assert_eq!(arena.remove(old), Some(1));
+    assert_eq!(arena.remove(old), None);
+    assert!(arena.is_empty());
+
+    let new = arena.insert(2);
+    assert_eq!(new.index(), old.index());
+    assert_ne!(new, old);
+    assert_eq!(arena.get(old), None);
+    assert_eq!(arena.get_mut(old), None);
+    assert!(!arena.contains(old));
+    assert_eq!(arena[new], 2);
+}
+
+#[test]
+#[should_panic(expected = "stale arena handle")]
+fn indexing_with_a_stale_handle_panics() {
+    let mut arena = Arena::new();
+    let idx = arena.insert(1);
+    arena.remove(idx);
+    let _ = arena[idx];
+}
+
+#[test]
+fn reuses_freed_slots_before_growing() {
+    let mut arena = Arena::with_capacity(4);
+    let ids: Vec<_> = (0..4).map(|n| arena.insert(n)).collect();
+    arena.remove(ids[1]);
+    arena.remove(ids[3]);
+    let reused: HashSet<usize> = (0..2).map(|n| arena.insert(n).index()).collect();
+    assert_eq!(reused, [1, 3].iter().cloned().collect());
+    assert_eq!(arena.insert(9).index(), 4);
+}
+
+#[test]
+fn iterates_over_live_values_only() {
+    let mut arena = Arena::new();
+    let ids: Vec<_> = (0..5).map(|n| arena.insert(n)).collect();
+    arena.remove(ids[2]);
+    for (_, value) in arena.iter_mut() {
+        *value *= 10;
+    }
+    let live: Vec<_> = arena.iter().map(|(idx, &value)| (idx, value)).collect();
+    assert_eq!(
+        live,
+        [(ids[0], 0), (ids[1], 10), (ids[3], 30), (ids[4], 40)]
+    );
+
+    arena.clear();
+    assert!(arena.is_empty());
+    assert!(ids.iter().all(|&idx| !arena.contains(idx)));
+    let fresh = arena.insert(7);
+    assert!(!ids.contains(&fresh));
+}
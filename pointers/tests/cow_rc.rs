@@ -0,0 +1,101 @@
+extern crate pointers;
+
+use pointers::cow_rc::CowRc;
+use std::cell::Cell;
+use std::rc::Rc;
+
+// Counts clones and drops of itself, through a shared tally.
+#[derive(Debug)]
+struct Tracked {
+    clones: Rc<Cell<usize>>,
+    drops: Rc<Cell<usize>>,
+    value: i32,
+}
+
+impl Clone for Tracked {
+    fn clone(&self) -> Tracked {
+        self.clones.set(self.clones.get() + 1);
+        Tracked {
+            clones: self.clones.clone(),
+            drops: self.drops.clone(),
+            value: self.value,
+        }
+    }
+}
+
+impl Drop for Tracked {
+    fn drop(&mut self) {
+        self.drops.set(self.drops.get() + 1);
+    }
+}
+
+fn tracked(value: i32) -> (Tracked, Rc<Cell<usize>>, Rc<Cell<usize>>) {
+    let clones = Rc::new(Cell::new(0));
+    let drops = Rc::new(Cell::new(0));
+    let tracked = Tracked {
+        clones: clones.clone(),
+        drops: drops.clone(),
+        value,
+    };
+    (tracked, clones, drops)
+}
+
+#[test]
+fn clones_share_until_written() {
+    let (value, clones, _) = tracked(1);
+    let config = CowRc::new(value);
+    let copies: Vec<CowRc<Tracked>> = (0..10).map(|_| config.clone()).collect();
+    assert_eq!(CowRc::count(&config), 11);
+    assert_eq!(clones.get(), 0);
+    assert!(copies.iter().all(|copy| CowRc::ptr_eq(copy, &config)));
+
+    let mut changed = copies[3].clone();
+    changed.make_mut().value = 2;
+    assert_eq!(clones.get(), 1);
+    assert_eq!((config.value, changed.value), (1, 2));
+    assert!(!CowRc::ptr_eq(&changed, &config));
+    assert_eq!(CowRc::count(&config), 11);
+}
+
+#[test]
+fn unique_values_are_written_in_place() {
+    let (value, clones, _) = tracked(1);
+    let mut config = CowRc::new(value);
+    config.make_mut().value += 1;
+    config.make_mut().value += 1;
+    assert_eq!(config.value, 3);
+    assert_eq!(clones.get(), 0);
+}
+
+#[test]
+fn get_mut_refuses_shared_values() {
+    let mut a = CowRc::new(String::from("a"));
+    let b = a.clone();
+    assert!(CowRc::get_mut(&mut a).is_none());
+    drop(b);
+    CowRc::get_mut(&mut a).unwrap().push('!');
+    assert_eq!(*a, "a!");
+}
+
+#[test]
+fn the_value_is_dropped_once_with_the_last_handle() {
+    let (value, _, drops) = tracked(1);
+    let a = CowRc::new(value);
+    let b = a.clone();
+    drop(a);
+    assert_eq!(drops.get(), 0);
+    drop(b);
+    assert_eq!(drops.get(), 1);
+}
+
+#[test]
+fn unwrapping_clones_only_shared_values() {
+    let (value, clones, drops) = tracked(7);
+    let a = CowRc::new(value);
+    let b = a.clone();
+    let a = CowRc::try_unwrap(a).unwrap_err();
+    assert_eq!(CowRc::unwrap_or_clone(a).value, 7);
+    assert_eq!((clones.get(), drops.get()), (1, 1));
+    assert_eq!(CowRc::unwrap_or_clone(b).value, 7);
+    assert_eq!((clones.get(), drops.get()), (1, 2));
+}
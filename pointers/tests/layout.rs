@@ -0,0 +1,137 @@
+#[macro_use]
+extern crate pointers;
+
+use pointers::layout::Layout;
+use std::mem;
+use std::rc::Rc;
+
+const WORD: usize = mem::size_of::<usize>();
+
+#[allow(dead_code)]
+struct Point {
+    x: f64,
+    y: i32,
+}
+
+#[allow(dead_code)]
+#[repr(C)]
+struct Header {
+    tag: u8,
+    len: u32,
+    flags: u16,
+}
+
+#[allow(dead_code)]
+struct Pair(u8, u64);
+
+#[test]
+fn lists_fields_in_memory_order_with_padding() {
+    let layout = layout!(Point { x, y });
+    assert_eq!((layout.size(), layout.align()), (16, 8));
+    assert_eq!(layout.type_name(), "Point");
+    assert_eq!(layout.padding(), 4);
+    assert_eq!(layout.field("y").unwrap().type_name, "i32");
+    assert_eq!(layout.field("y").unwrap().size, 4);
+    assert_eq!(
+        layout.to_string(),
+        format!(
+            "Point: size 16, align 8, Option<Point> is {} bytes (no niche)\n\
+             \x20 offset  size  field\n\
+             \x20      0     8  x: f64\n\
+             \x20      8     4  y: i32\n\
+             \x20     12     4  (padding)",
+            mem::size_of::<Option<Point>>()
+        )
+    );
+}
+
+#[test]
+fn shows_repr_c_keeping_declaration_order() {
+    let layout = layout!(Header { tag, len, flags });
+    let offsets: Vec<_> = layout.fields().iter().map(|f| (f.name, f.offset)).collect();
+    assert_eq!(offsets, [("tag", 0), ("len", 4), ("flags", 8)]);
+    assert_eq!((layout.size(), layout.padding()), (12, 5));
+    assert!(layout.to_string().contains("\n       1     3  (padding)\n"));
+}
+
+#[test]
+fn handles_tuple_structs() {
+    let layout = layout!(Pair { 0, 1 });
+    assert_eq!(layout.fields().len(), 2);
+    assert_eq!(layout.field("1").unwrap().type_name, "u64");
+    assert_eq!(layout.size(), 16);
+}
+
+#[test]
+fn reports_niches_of_pointer_types() {
+    for layout in &[
+        layout!(&u8),
+        layout!(Box<u8>),
+        layout!(Rc<u8>),
+        Layout::of::<Option<Box<u8>>>(),
+    ] {
+        assert_eq!(layout.size(), WORD, "{}", layout);
+    }
+    assert!(layout!(Box<u8>).has_niche());
+    assert!(layout!(bool).has_niche());
+    assert!(layout!(char).has_niche());
+    assert!(!layout!(u32).has_niche());
+    assert_eq!(layout!(u32).option_size(), 8);
+    // A slice reference is a pointer and a length
+    assert_eq!(layout!(&[u8]).size(), 2 * WORD);
+    assert_eq!(layout!(Box<u8>).type_name(), "Box<u8>");
+    assert_eq!(
+        Layout::of::<Option<Box<u8>>>().type_name(),
+        "Option<Box<u8>>"
+    );
+}
+
+#[test]
+fn dumps_field_bytes_and_skips_padding() {
+    let header = Header {
+        tag: 0xab,
+        len: 0x0102_0304,
+        flags: 0xffee,
+    };
+    let layout = layout!(Header { tag, len, flags });
+    let dump = unsafe { layout.dump(&header) };
+    assert_eq!(dump.field_bytes("tag"), Some(&[0xab][..]));
+    assert_eq!(
+        dump.field_bytes("len"),
+        Some(&0x0102_0304u32.to_ne_bytes()[..])
+    );
+    assert_eq!(
+        dump.field_bytes("flags"),
+        Some(&0xffeeu16.to_ne_bytes()[..])
+    );
+    assert_eq!(dump.field_bytes("nope"), None);
+
+    let text = dump.to_string();
+    let lines: Vec<&str> = text.lines().collect();
+    assert!(lines[0].starts_with("Header at 0x"));
+    assert_eq!(lines[1], "       0  ab                       tag: u8");
+    assert_eq!(lines[2], "       1  .. .. ..                 (padding)");
+    assert_eq!(lines.len(), 6);
+}
+
+#[test]
+fn dumps_whole_values_and_wraps_long_fields() {
+    let boxed: Option<Box<u8>> = None;
+    let dump = unsafe { Layout::of::<Option<Box<u8>>>().dump(&boxed) };
+    let zeros = ["00"; WORD].join(" ");
+    assert!(dump
+        .to_string()
+        .contains(&format!("       0  {}  (whole value)", zeros)));
+
+    let bytes = [7u8; 10];
+    let dump = unsafe { layout!([u8; 10]).dump(&bytes) };
+    let lines: Vec<String> = dump.to_string().lines().map(str::to_string).collect();
+    assert_eq!(lines[1], "       0  07 07 07 07 07 07 07 07  (whole value)");
+    assert_eq!(lines[2], "       8  07 07");
+}
+
+#[test]
+#[should_panic(expected = "layout of u32 used to dump a u64")]
+fn refuses_to_dump_another_type() {
+    unsafe { layout!(u32).dump(&1u64) };
+}
@@ -0,0 +1,282 @@
+extern crate pointers;
+
+use pointers::linked_list::DoublyLinkedList;
+use std::cell::Cell;
+
+fn list(elems: &[i32]) -> DoublyLinkedList<i32> {
+    elems.iter().cloned().collect()
+}
+
+fn contents(list: &DoublyLinkedList<i32>) -> Vec<i32> {
+    let forwards: Vec<i32> = list.iter().cloned().collect();
+    let mut backwards: Vec<i32> = list.iter().rev().cloned().collect();
+    backwards.reverse();
+    assert_eq!(forwards, backwards, "prev and next links disagree");
+    assert_eq!(forwards.len(), list.len());
+    forwards
+}
+
+#[test]
+fn pushes_and_pops_at_both_ends() {
+    let mut list = DoublyLinkedList::new();
+    assert_eq!(list.pop_front(), None);
+    assert_eq!(list.pop_back(), None);
+    list.push_back(2);
+    list.push_front(1);
+    list.push_back(3);
+    assert_eq!(contents(&list), [1, 2, 3]);
+    assert_eq!((list.front(), list.back()), (Some(&1), Some(&3)));
+
+    *list.front_mut().unwrap() = 10;
+    *list.back_mut().unwrap() = 30;
+    assert_eq!(list.pop_front(), Some(10));
+    assert_eq!(list.pop_back(), Some(30));
+    assert_eq!(list.pop_back(), Some(2));
+    assert!(list.is_empty());
+    assert_eq!((list.front(), list.back()), (None, None));
+}
+
+#[test]
+fn iterates_from_either_end() {
+    let mut list = list(&[1, 2, 3, 4]);
+    for elem in list.iter_mut() {
+        *elem *= 10;
+    }
+    let mut iter = list.iter();
+    assert_eq!(iter.len(), 4);
+    assert_eq!(iter.next(), Some(&10));
+    assert_eq!(iter.next_back(), Some(&40));
+    assert_eq!(iter.next(), Some(&20));
+    assert_eq!(iter.next_back(), Some(&30));
+    assert_eq!((iter.next(), iter.next_back()), (None, None));
+
+    let mut iter = list.clone().into_iter();
+    assert_eq!(iter.next_back(), Some(40));
+    assert_eq!(iter.collect::<Vec<_>>(), [10, 20, 30]);
+    assert_eq!(format!("{:?}", list), "[10, 20, 30, 40]");
+}
+
+#[test]
+fn cursor_moves_through_the_ghost_position() {
+    let mut list = list(&[1, 2, 3]);
+    let mut cursor = list.cursor_front_mut();
+    assert_eq!((cursor.index(), cursor.current()), (Some(0), Some(&mut 1)));
+    assert_eq!(cursor.peek_prev(), None);
+    cursor.move_next();
+    cursor.move_next();
+    assert_eq!((cursor.index(), cursor.current()), (Some(2), Some(&mut 3)));
+    cursor.move_next();
+    assert_eq!((cursor.index(), cursor.current()), (None, None));
+    assert_eq!(cursor.peek_next(), Some(&mut 1));
+    assert_eq!(cursor.peek_prev(), Some(&mut 3));
+    cursor.move_next();
+    assert_eq!(cursor.index(), Some(0));
+    cursor.move_prev();
+    cursor.move_prev();
+    assert_eq!((cursor.index(), cursor.current()), (Some(2), Some(&mut 3)));
+
+    let mut empty: DoublyLinkedList<i32> = DoublyLinkedList::new();
+    let mut cursor = empty.cursor_back_mut();
+    cursor.move_next();
+    cursor.move_prev();
+    assert_eq!((cursor.index(), cursor.current()), (None, None));
+}
+
+#[test]
+fn cursor_inserts_and_removes_in_place() {
+    let mut list = list(&[1, 2, 3]);
+    {
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_next();
+        cursor.insert_before(15);
+        cursor.insert_after(25);
+        assert_eq!((cursor.index(), cursor.current()), (Some(2), Some(&mut 2)));
+        assert_eq!(cursor.remove_current(), Some(2));
+        assert_eq!((cursor.index(), cursor.current()), (Some(2), Some(&mut 25)));
+        cursor.move_next();
+        cursor.move_next();
+        assert_eq!(cursor.remove_current(), None);
+        cursor.insert_before(4);
+        cursor.insert_after(0);
+        assert_eq!(cursor.index(), None);
+    }
+    assert_eq!(contents(&list), [0, 1, 15, 25, 3, 4]);
+
+    let mut cursor = list.cursor_back_mut();
+    assert_eq!(cursor.remove_current(), Some(4));
+    assert_eq!(cursor.index(), None);
+    assert_eq!(contents(&list), [0, 1, 15, 25, 3]);
+}
+
+#[test]
+fn cursor_splices_and_splits() {
+    let mut list = list(&[1, 2, 3]);
+    {
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_next();
+        cursor.splice_before(self::list(&[10, 11]));
+        assert_eq!((cursor.index(), cursor.current()), (Some(3), Some(&mut 2)));
+        cursor.splice_after(self::list(&[20, 21]));
+        cursor.splice_after(DoublyLinkedList::new());
+        assert_eq!(cursor.peek_next(), Some(&mut 20));
+    }
+    assert_eq!(contents(&list), [1, 10, 11, 2, 20, 21, 3]);
+
+    let (front, back) = {
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_next();
+        cursor.move_next();
+        cursor.move_next();
+        let back = cursor.split_after();
+        let front = cursor.split_before();
+        assert_eq!((cursor.index(), cursor.current()), (Some(0), Some(&mut 2)));
+        (front, back)
+    };
+    assert_eq!(contents(&front), [1, 10, 11]);
+    assert_eq!(contents(&back), [20, 21, 3]);
+    assert_eq!(contents(&list), [2]);
+
+    let mut cursor = list.cursor_front_mut();
+    cursor.move_prev();
+    let all = cursor.split_after();
+    assert_eq!(cursor.index(), None);
+    assert_eq!(contents(&all), [2]);
+    assert!(list.is_empty());
+}
+
+#[test]
+fn appends_and_splits_off() {
+    let mut a = list(&[1, 2]);
+    let mut b = list(&[3, 4, 5]);
+    a.append(&mut b);
+    assert!(b.is_empty());
+    assert_eq!(contents(&a), [1, 2, 3, 4, 5]);
+    b.append(&mut a);
+    assert_eq!(contents(&b), [1, 2, 3, 4, 5]);
+
+    for at in 0..6 {
+        let mut front = list(&[1, 2, 3, 4, 5]);
+        let back = front.split_off(at);
+        assert_eq!(contents(&front), &[1, 2, 3, 4, 5][..at]);
+        assert_eq!(contents(&back), &[1, 2, 3, 4, 5][at..]);
+    }
+}
+
+#[test]
+#[should_panic(expected = "out of bounds")]
+fn split_off_past_the_end_panics() {
+    list(&[1]).split_off(2);
+}
+
+// Same xorshift generator as elsewhere, so every run is the same.
+struct Random(u64);
+
+impl Random {
+    fn below(&mut self, n: usize) -> usize {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 % n as u64) as usize
+    }
+}
+
+#[test]
+fn cursor_edits_match_a_vec() {
+    let mut random = Random(0x2545_f491_4f6c_dd1d);
+    let mut list = DoublyLinkedList::new();
+    let mut model: Vec<i32> = Vec::new();
+    // Cursor position in the model, `model.len()` being the ghost
+    let mut at = 0;
+    let mut cursor = list.cursor_front_mut();
+    for n in 0..2000 {
+        match random.below(7) {
+            0 => {
+                cursor.move_next();
+                at = if at == model.len() { 0 } else { at + 1 };
+            }
+            1 => {
+                cursor.move_prev();
+                at = if at == 0 { model.len() } else { at - 1 };
+            }
+            2 => {
+                cursor.insert_before(n);
+                model.insert(at, n);
+                at += 1;
+            }
+            3 => {
+                cursor.insert_after(n);
+                if at == model.len() {
+                    model.insert(0, n);
+                    at += 1;
+                } else {
+                    model.insert(at + 1, n);
+                }
+            }
+            4 => {
+                let removed = if at < model.len() {
+                    Some(model.remove(at))
+                } else {
+                    None
+                };
+                assert_eq!(cursor.remove_current(), removed);
+            }
+            5 => {
+                let other: DoublyLinkedList<i32> = (0..random.below(3) as i32).collect();
+                let len = other.len();
+                cursor.splice_before(other);
+                model.splice(at..at, 0..len as i32);
+                at += len;
+            }
+            _ => {
+                let expected = if at == model.len() { None } else { Some(at) };
+                assert_eq!(cursor.index(), expected);
+                assert_eq!(cursor.current().cloned(), model.get(at).cloned());
+            }
+        }
+    }
+    assert_eq!(contents(&list), model);
+}
+
+struct Counted<'a>(&'a Cell<usize>);
+
+impl<'a> Drop for Counted<'a> {
+    fn drop(&mut self) {
+        self.0.set(self.0.get() + 1);
+    }
+}
+
+#[test]
+fn drops_every_element_exactly_once() {
+    let dropped = Cell::new(0);
+    {
+        let mut list: DoublyLinkedList<_> = (0..10).map(|_| Counted(&dropped)).collect();
+        drop(list.pop_front());
+        let back = list.split_off(4);
+        drop(back);
+        assert_eq!(dropped.get(), 6);
+        let mut cursor = list.cursor_front_mut();
+        drop(cursor.remove_current());
+        assert_eq!(dropped.get(), 7);
+        let mut into_iter = list.into_iter();
+        drop(into_iter.next());
+        assert_eq!(dropped.get(), 8);
+    }
+    assert_eq!(dropped.get(), 10);
+}
+
+// Moving an entry to the front is how a least recently used cache
+// records a hit.
+#[test]
+fn moves_an_entry_to_the_front() {
+    let mut lru = list(&[1, 2, 3, 4]);
+    let hit = {
+        let mut cursor = lru.cursor_front_mut();
+        while cursor.current().is_some_and(|key| *key != 3) {
+            cursor.move_next();
+        }
+        cursor.remove_current().unwrap()
+    };
+    lru.push_front(hit);
+    assert_eq!(contents(&lru), [3, 1, 2, 4]);
+    assert_eq!(lru.pop_back(), Some(4));
+}
@@ -0,0 +1,137 @@
+extern crate pointers;
+
+use pointers::list::{self, List};
+use std::cell::Cell;
+use std::rc::Rc;
+use std::thread;
+
+#[test]
+fn cons_leaves_the_old_list_alone() {
+    let empty: List<i32> = List::new();
+    let one = empty.cons(1);
+    let two = one.cons(2);
+    let other = one.cons(3);
+
+    assert!(empty.is_empty());
+    assert_eq!(one.iter().collect::<Vec<_>>(), [&1]);
+    assert_eq!(two.iter().collect::<Vec<_>>(), [&2, &1]);
+    assert_eq!(other.iter().collect::<Vec<_>>(), [&3, &1]);
+    assert_eq!((two.len(), two.head()), (2, Some(&2)));
+    assert_eq!(empty.head(), None);
+}
+
+#[test]
+fn tails_are_shared_not_copied() {
+    let base: List<String> = vec!["b".to_string(), "c".to_string()].into_iter().collect();
+    let a = base.cons("a1".to_string());
+    let b = base.cons("a2".to_string());
+    assert!(a.tail().ptr_eq(&base));
+    assert!(a.tail().ptr_eq(&b.tail()));
+    assert!(!a.ptr_eq(&b));
+    assert!(a.clone().ptr_eq(&a));
+
+    assert_eq!(base.tail().tail(), List::new());
+    assert!(List::<i32>::new().tail().is_empty());
+}
+
+#[test]
+fn collects_in_order() {
+    let list: List<i32> = (1..6).collect();
+    assert_eq!(list.len(), 5);
+    assert_eq!(list.head(), Some(&1));
+    assert_eq!(format!("{:?}", list), "[1, 2, 3, 4, 5]");
+    assert_eq!(list.iter().len(), 5);
+    let mut sum = 0;
+    for n in &list {
+        sum += n;
+    }
+    assert_eq!(sum, 15);
+}
+
+#[test]
+fn rev_map_and_filter_build_new_lists() {
+    let list: List<i32> = (1..6).collect();
+    assert_eq!(list.rev(), (1..6).rev().collect());
+    assert_eq!(
+        list.map(|n| n * 10),
+        vec![10, 20, 30, 40, 50].into_iter().collect()
+    );
+    assert_eq!(
+        list.filter(|n| n % 2 == 1),
+        vec![1, 3, 5].into_iter().collect()
+    );
+    assert_eq!(
+        list.map(|n| n.to_string()).head().map(|s| &s[..]),
+        Some("1")
+    );
+    // The original is untouched
+    assert_eq!(list, (1..6).collect());
+}
+
+#[test]
+fn dropping_a_long_list_does_not_overflow_the_stack() {
+    let list: List<u32> = (0..1_000_000).collect();
+    let longer = list.cons(7);
+    drop(list);
+    assert_eq!(longer.len(), 1_000_001);
+    drop(longer);
+
+    let list: list::sync::List<u32> = (0..1_000_000).collect();
+    drop(list);
+}
+
+struct Counted<'a>(&'a Cell<usize>);
+
+impl<'a> Drop for Counted<'a> {
+    fn drop(&mut self) {
+        self.0.set(self.0.get() + 1);
+    }
+}
+
+#[test]
+fn shared_nodes_live_until_the_last_list_goes() {
+    let dropped = Cell::new(0);
+    let base = List::new().cons(Counted(&dropped)).cons(Counted(&dropped));
+    let a = base.cons(Counted(&dropped));
+    let b = base.cons(Counted(&dropped));
+    drop(base);
+    assert_eq!(dropped.get(), 0);
+    drop(a);
+    assert_eq!(dropped.get(), 1);
+    assert_eq!(b.len(), 3);
+    drop(b);
+    assert_eq!(dropped.get(), 4);
+}
+
+#[test]
+fn works_as_an_undo_history() {
+    let mut history: List<Rc<String>> = List::new().cons(Rc::new(String::new()));
+    for word in &["hello", " ", "world"] {
+        let mut text = (**history.head().unwrap()).clone();
+        text.push_str(word);
+        history = history.cons(Rc::new(text));
+    }
+    let snapshot = history.clone();
+    history = history.tail();
+    assert_eq!(&**history.head().unwrap(), "hello ");
+    assert_eq!(&**snapshot.head().unwrap(), "hello world");
+    assert_eq!(history.len(), 3);
+}
+
+#[test]
+fn sync_lists_share_tails_across_threads() {
+    let base: list::sync::List<u32> = (0..1000).collect();
+    let handles: Vec<_> = (0..4)
+        .map(|i| {
+            let base = base.clone();
+            thread::spawn(move || {
+                let mine = base.cons(i);
+                assert!(mine.tail().ptr_eq(&base));
+                mine.iter().sum::<u32>()
+            })
+        })
+        .collect();
+    let sums: Vec<u32> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+    assert_eq!(sums, [499_500, 499_501, 499_502, 499_503]);
+    assert_eq!(base.len(), 1000);
+}
@@ -0,0 +1,89 @@
+extern crate pointers;
+
+use pointers::small::{SmallString, SmallVec};
+use std::collections::HashSet;
+use std::fmt::Write;
+use std::rc::Rc;
+
+#[test]
+fn short_vectors_stay_inline() {
+    let mut vec: SmallVec<i32, 4> = SmallVec::new();
+    vec.extend(1..5);
+    assert!(!vec.spilled());
+    assert_eq!(vec.capacity(), 4);
+    assert_eq!(&vec[..], &[1, 2, 3, 4]);
+
+    vec.push(5);
+    assert!(vec.spilled());
+    assert_eq!(&vec[..], &[1, 2, 3, 4, 5]);
+    assert_eq!(vec.pop(), Some(5));
+    assert!(vec.spilled());
+    assert!(!vec.clone().spilled());
+}
+
+#[test]
+fn behaves_like_a_vec() {
+    let mut small: SmallVec<u32, 3> = SmallVec::new();
+    let mut vec = Vec::new();
+    let mut seed = 7u32;
+    for _ in 0..500 {
+        seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+        match seed >> 28 {
+            0..=9 => {
+                small.push(seed);
+                vec.push(seed);
+            }
+            10..=14 => assert_eq!(small.pop(), vec.pop()),
+            _ => {
+                small.clear();
+                vec.clear();
+            }
+        }
+        assert_eq!(&small[..], &vec[..]);
+    }
+    small.sort();
+    vec.sort();
+    assert_eq!(small.into_vec(), vec);
+}
+
+#[test]
+fn items_are_dropped_exactly_once() {
+    let item = Rc::new(());
+    for count in 0..6 {
+        let mut vec: SmallVec<Rc<()>, 3> = (0..count).map(|_| item.clone()).collect();
+        assert_eq!(Rc::strong_count(&item), count + 1);
+        vec.pop();
+        assert_eq!(Rc::strong_count(&item), count.max(1));
+        let vec = vec.into_vec();
+        assert_eq!(Rc::strong_count(&item), count.max(1));
+        drop(vec);
+        assert_eq!(Rc::strong_count(&item), 1);
+    }
+}
+
+#[test]
+fn short_strings_stay_inline() {
+    let mut name: SmallString<8> = SmallString::from("wheel");
+    assert!(!name.spilled());
+    name.push('s');
+    name.push('ü');
+    assert!(!name.spilled());
+    assert_eq!(name, "wheelsü");
+    write!(name, "-{}", 42).unwrap();
+    assert!(name.spilled());
+    assert_eq!(name.to_string(), "wheelsü-42");
+    assert_eq!(name.len(), 11);
+}
+
+#[test]
+fn strings_work_as_set_keys() {
+    let set: HashSet<SmallString<16>> = ["front", "rear", "front"]
+        .iter()
+        .map(|&s| SmallString::from(s))
+        .collect();
+    assert_eq!(set.len(), 2);
+    assert!(set.contains("rear"));
+    let mut sorted: Vec<_> = set.into_iter().collect();
+    sorted.sort();
+    assert_eq!(format!("{:?}", sorted), "[\"front\", \"rear\"]");
+}
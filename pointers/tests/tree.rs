@@ -0,0 +1,156 @@
+extern crate pointers;
+
+use pointers::tree::{NodeId, Tree};
+
+fn values(tree: &Tree<&'static str>, ids: Vec<NodeId<&'static str>>) -> Vec<&'static str> {
+    ids.into_iter()
+        .map(|id| *tree.get(id).unwrap().value())
+        .collect()
+}
+
+// root
+// ├── a
+// │   ├── a1
+// │   └── a2
+// ├── b
+// └── c
+//     └── c1
+fn sample() -> (Tree<&'static str>, NodeId<&'static str>) {
+    let mut tree = Tree::new();
+    let root = tree.new_node("root");
+    let a = tree.append_child(root, "a").unwrap();
+    let c = tree.append_child(root, "c").unwrap();
+    tree.insert_before(c, "b").unwrap();
+    tree.append_child(a, "a2").unwrap();
+    tree.prepend_child(a, "a1").unwrap();
+    tree.append_child(c, "c1").unwrap();
+    (tree, root)
+}
+
+fn find(
+    tree: &Tree<&'static str>,
+    root: NodeId<&'static str>,
+    value: &str,
+) -> NodeId<&'static str> {
+    tree.descendants(root)
+        .find(|&id| *tree.get(id).unwrap().value() == value)
+        .unwrap()
+}
+
+#[test]
+fn builds_with_parent_and_sibling_links() {
+    let (tree, root) = sample();
+    assert_eq!(tree.len(), 7);
+    assert_eq!(
+        values(&tree, tree.children(root).collect()),
+        ["a", "b", "c"]
+    );
+    let b = find(&tree, root, "b");
+    let node = tree.get(b).unwrap();
+    assert_eq!(node.parent(), Some(root));
+    assert_eq!(
+        values(
+            &tree,
+            vec![node.prev_sibling().unwrap(), node.next_sibling().unwrap()]
+        ),
+        ["a", "c"]
+    );
+    assert_eq!(node.first_child(), None);
+    assert_eq!(tree.get(root).unwrap().last_child(), node.next_sibling());
+}
+
+#[test]
+fn traverses_in_every_direction() {
+    let (tree, root) = sample();
+    assert_eq!(
+        values(&tree, tree.descendants(root).collect()),
+        ["root", "a", "a1", "a2", "b", "c", "c1"]
+    );
+    let a = find(&tree, root, "a");
+    assert_eq!(
+        values(&tree, tree.descendants(a).collect()),
+        ["a", "a1", "a2"]
+    );
+
+    let a2 = find(&tree, root, "a2");
+    assert_eq!(values(&tree, tree.ancestors(a2).collect()), ["a", "root"]);
+    assert_eq!(tree.depth(a2), 2);
+    assert_eq!(tree.depth(root), 0);
+
+    let b = find(&tree, root, "b");
+    assert_eq!(values(&tree, tree.following_siblings(b).collect()), ["c"]);
+    assert_eq!(values(&tree, tree.preceding_siblings(b).collect()), ["a"]);
+    assert_eq!(tree.roots().collect::<Vec<_>>(), [root]);
+}
+
+#[test]
+fn moves_subtrees_but_refuses_cycles() {
+    let (mut tree, root) = sample();
+    let a = find(&tree, root, "a");
+    let c = find(&tree, root, "c");
+    let a1 = find(&tree, root, "a1");
+
+    assert!(tree.append(c, a));
+    assert_eq!(values(&tree, tree.children(root).collect()), ["b", "c"]);
+    assert_eq!(values(&tree, tree.children(c).collect()), ["c1", "a"]);
+    assert_eq!(
+        values(&tree, tree.ancestors(a1).collect()),
+        ["a", "c", "root"]
+    );
+
+    assert!(!tree.append(a1, c));
+    assert!(!tree.append(a, a));
+    assert_eq!(values(&tree, tree.children(c).collect()), ["c1", "a"]);
+
+    assert!(tree.detach(c));
+    assert_eq!(tree.get(c).unwrap().parent(), None);
+    assert_eq!(values(&tree, tree.children(root).collect()), ["b"]);
+    assert_eq!(tree.roots().count(), 2);
+    assert_eq!(tree.len(), 7);
+}
+
+#[test]
+fn removes_whole_subtrees_and_catches_stale_ids() {
+    let (mut tree, root) = sample();
+    let a = find(&tree, root, "a");
+    let a1 = find(&tree, root, "a1");
+    let b = find(&tree, root, "b");
+
+    assert_eq!(tree.remove_subtree(a), Some("a"));
+    assert_eq!(tree.len(), 4);
+    assert_eq!(values(&tree, tree.children(root).collect()), ["b", "c"]);
+    assert_eq!(tree.get(b).unwrap().prev_sibling(), None);
+
+    // The freed slots are reused, but the old ids stay dead
+    let x = tree.append_child(root, "x").unwrap();
+    assert!(tree.contains(x));
+    for &stale in &[a, a1] {
+        assert!(tree.get(stale).is_none());
+        assert_eq!(tree.append_child(stale, "y"), None);
+        assert!(!tree.append(root, stale));
+        assert!(!tree.detach(stale));
+        assert_eq!(tree.remove_subtree(stale), None);
+        assert_eq!(tree.children(stale).count(), 0);
+        assert_eq!(tree.descendants(stale).count(), 0);
+    }
+    assert_eq!(tree.insert_after(root, "sibling of a root"), None);
+}
+
+#[test]
+fn values_can_be_changed_through_ids() {
+    let (mut tree, root) = sample();
+    let ids: Vec<_> = tree.descendants(root).collect();
+    for id in ids {
+        let node = tree.get_mut(id).unwrap();
+        let kind: &'static str = if node.first_child().is_some() {
+            "parent"
+        } else {
+            "leaf"
+        };
+        *node.value_mut() = kind;
+    }
+    assert_eq!(
+        values(&tree, tree.descendants(root).collect()),
+        ["parent", "parent", "leaf", "leaf", "leaf", "parent", "leaf"]
+    );
+}
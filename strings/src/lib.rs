@@ -0,0 +1,6 @@
+// Text types built on top of `String` for when editing a single
+// growable buffer, as shown with `push_str` in main.rs, gets too slow,
+// and templates for when gluing text together with it gets unwieldy.
+
+pub mod rope;
+pub mod template;
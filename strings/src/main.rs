@@ -1,3 +1,8 @@
+extern crate strings;
+
+use strings::rope::Rope;
+use strings::template::{Context, Escape, Template};
+
 fn main() {
     // All strings are guaranteed to be validly encoded UTF-8 sequences
 
@@ -21,6 +26,30 @@ fn main() {
     s.push_str("llo");
     println!("{}", s);
 
+    // push_str is cheap at the end, but inserting into the middle of a
+    // String moves everything after it. A Rope keeps large text in a
+    // tree of small pieces so edits anywhere stay fast:
+    let mut text = Rope::from("fn main() {\n}\n");
+    let body = text.line_to_char(1);
+    text.insert(body, "    println!(\"hi\");\n");
+    for (number, line) in text.lines().enumerate() {
+        print!("{} {}", number + 1, line);
+    }
+    println!();
+
+    // Building a whole message out of push_str calls gets hard to read.
+    // A template is compiled once and filled in for every reader:
+    let email = Template::compile_with(
+        "Hi {{ name | trim }},\n\
+         {% for item in items %}{{ loop.index }}. {{ item }}\n{% endfor %}",
+        Escape::None,
+    )
+    .unwrap();
+    let context = Context::new()
+        .with("name", " Ann ")
+        .with("items", vec!["tea", "milk"]);
+    print!("{}", email.render(&context).unwrap());
+
 
     // Strings will coerce into &str with an &:
     // &str are reference to another string
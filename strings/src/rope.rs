@@ -0,0 +1,629 @@
+// A rope keeps text in a balanced binary tree of short strings, so that
+// inserting or removing text anywhere costs O(log n) instead of moving
+// everything after it the way `String::insert_str` does. Each node knows
+// how many bytes, chars and line breaks it holds, which lets char and
+// line positions be found by walking down from the root.
+//
+//     use strings::rope::Rope;
+//
+//     let mut rope = Rope::from("Hello\nworld");
+//     rope.insert(5, ", there");
+//     assert_eq!(rope.line(0).to_string(), "Hello, there\n");
+//
+// Positions are counted in chars, never bytes, so no edit can split a
+// UTF-8 sequence. Lines end after each '\n'.
+
+use std::fmt;
+use std::mem;
+use std::ops::{Bound, RangeBounds};
+use std::str;
+
+// Leaves hold at most this many bytes; neighbours that fit together
+// are merged when the tree is joined.
+const MAX_LEAF: usize = 1024;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct Info {
+    bytes: usize,
+    chars: usize,
+    newlines: usize,
+}
+
+impl Info {
+    fn of(text: &str) -> Info {
+        Info {
+            bytes: text.len(),
+            chars: text.chars().count(),
+            newlines: text.bytes().filter(|&b| b == b'\n').count(),
+        }
+    }
+
+    fn add(self, other: Info) -> Info {
+        Info {
+            bytes: self.bytes + other.bytes,
+            chars: self.chars + other.chars,
+            newlines: self.newlines + other.newlines,
+        }
+    }
+}
+
+// AVL-balanced: the heights of the two sides of a branch differ by at
+// most one.
+#[derive(Debug, Clone)]
+enum Node {
+    Leaf(String, Info),
+    Branch {
+        left: Box<Node>,
+        right: Box<Node>,
+        info: Info,
+        height: usize,
+    },
+}
+
+// Byte offset of char `index` in `text`.
+fn byte_offset(text: &str, index: usize) -> usize {
+    text.char_indices()
+        .nth(index)
+        .map_or(text.len(), |(i, _)| i)
+}
+
+impl Node {
+    fn leaf(text: String) -> Node {
+        let info = Info::of(&text);
+        Node::Leaf(text, info)
+    }
+
+    fn branch(left: Node, right: Node) -> Node {
+        Node::Branch {
+            info: left.info().add(right.info()),
+            height: 1 + left.height().max(right.height()),
+            left: Box::new(left),
+            right: Box::new(right),
+        }
+    }
+
+    // A perfectly balanced tree over `text` cut into full leaves.
+    fn build(text: &str) -> Node {
+        let mut leaves = Vec::new();
+        let mut rest = text;
+        while rest.len() > MAX_LEAF {
+            let mut cut = MAX_LEAF;
+            while !rest.is_char_boundary(cut) {
+                cut -= 1;
+            }
+            leaves.push(Node::leaf(rest[..cut].to_string()));
+            rest = &rest[cut..];
+        }
+        leaves.push(Node::leaf(rest.to_string()));
+        Node::build_from(leaves)
+    }
+
+    fn build_from(mut leaves: Vec<Node>) -> Node {
+        if leaves.len() == 1 {
+            return leaves.pop().unwrap();
+        }
+        let right = leaves.split_off(leaves.len() / 2);
+        Node::branch(Node::build_from(leaves), Node::build_from(right))
+    }
+
+    fn info(&self) -> Info {
+        match *self {
+            Node::Leaf(_, info) => info,
+            Node::Branch { info, .. } => info,
+        }
+    }
+
+    fn height(&self) -> usize {
+        match *self {
+            Node::Leaf(..) => 0,
+            Node::Branch { height, .. } => height,
+        }
+    }
+
+    fn into_children(self) -> (Node, Node) {
+        match self {
+            Node::Branch { left, right, .. } => (*left, *right),
+            Node::Leaf(..) => unreachable!("a leaf has no children"),
+        }
+    }
+
+    fn children(&self) -> (&Node, &Node) {
+        match *self {
+            Node::Branch {
+                ref left,
+                ref right,
+                ..
+            } => (left, right),
+            Node::Leaf(..) => unreachable!("a leaf has no children"),
+        }
+    }
+
+    fn rotate_left(self) -> Node {
+        let (left, right) = self.into_children();
+        let (right_left, right_right) = right.into_children();
+        Node::branch(Node::branch(left, right_left), right_right)
+    }
+
+    fn rotate_right(self) -> Node {
+        let (left, right) = self.into_children();
+        let (left_left, left_right) = left.into_children();
+        Node::branch(left_left, Node::branch(left_right, right))
+    }
+
+    // A branch over `left` and `right`, rotated back into balance when
+    // one side is two levels taller than the other.
+    fn balanced(left: Node, right: Node) -> Node {
+        let (hl, hr) = (left.height(), right.height());
+        if hl > hr + 1 {
+            let (left_left, left_right) = left.children();
+            let left = if left_left.height() < left_right.height() {
+                left.rotate_left()
+            } else {
+                left
+            };
+            Node::branch(left, right).rotate_right()
+        } else if hr > hl + 1 {
+            let (right_left, right_right) = right.children();
+            let right = if right_right.height() < right_left.height() {
+                right.rotate_right()
+            } else {
+                right
+            };
+            Node::branch(left, right).rotate_left()
+        } else {
+            Node::branch(left, right)
+        }
+    }
+
+    // Concatenation in O(|height difference|): the shorter tree is hung
+    // off the spine of the taller one and the path rebalanced.
+    fn join(left: Node, right: Node) -> Node {
+        if left.info().bytes == 0 {
+            return right;
+        }
+        if right.info().bytes == 0 {
+            return left;
+        }
+        let (hl, hr) = (left.height(), right.height());
+        match (left, right) {
+            (Node::Leaf(mut a, _), Node::Leaf(b, _)) if a.len() + b.len() <= MAX_LEAF => {
+                a.push_str(&b);
+                Node::leaf(a)
+            }
+            (left, right) => {
+                if hl > hr + 1 {
+                    let (left_left, left_right) = left.into_children();
+                    Node::balanced(left_left, Node::join(left_right, right))
+                } else if hr > hl + 1 {
+                    let (right_left, right_right) = right.into_children();
+                    Node::balanced(Node::join(left, right_left), right_right)
+                } else {
+                    Node::branch(left, right)
+                }
+            }
+        }
+    }
+
+    // The chars before `index` and the chars from `index` on.
+    fn split(self, index: usize) -> (Node, Node) {
+        match self {
+            Node::Leaf(mut text, _) => {
+                let tail = text.split_off(byte_offset(&text, index));
+                (Node::leaf(text), Node::leaf(tail))
+            }
+            Node::Branch { left, right, .. } => {
+                let left_chars = left.info().chars;
+                if index <= left_chars {
+                    let (a, b) = left.split(index);
+                    (a, Node::join(b, *right))
+                } else {
+                    let (a, b) = right.split(index - left_chars);
+                    (Node::join(*left, a), b)
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Rope {
+    root: Node,
+}
+
+impl Default for Rope {
+    fn default() -> Rope {
+        Rope::new()
+    }
+}
+
+impl<'a> From<&'a str> for Rope {
+    fn from(text: &'a str) -> Rope {
+        Rope {
+            root: Node::build(text),
+        }
+    }
+}
+
+impl From<String> for Rope {
+    fn from(text: String) -> Rope {
+        Rope::from(&text[..])
+    }
+}
+
+// Start and end of `range` within `0..len`, panicking when it does not
+// fit like slicing a `str` does.
+fn bounds<R: RangeBounds<usize>>(range: R, len: usize) -> (usize, usize) {
+    let start = match range.start_bound() {
+        Bound::Included(&i) => i,
+        Bound::Excluded(&i) => i + 1,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&i) => i + 1,
+        Bound::Excluded(&i) => i,
+        Bound::Unbounded => len,
+    };
+    assert!(
+        start <= end && end <= len,
+        "char range {}..{} out of bounds for rope of {} chars",
+        start,
+        end,
+        len
+    );
+    (start, end)
+}
+
+impl Rope {
+    pub fn new() -> Rope {
+        Rope {
+            root: Node::leaf(String::new()),
+        }
+    }
+
+    pub fn len_bytes(&self) -> usize {
+        self.root.info().bytes
+    }
+
+    pub fn len_chars(&self) -> usize {
+        self.root.info().chars
+    }
+
+    // One more than the number of line breaks: text ending in '\n' has
+    // an empty last line, as in most editors.
+    pub fn len_lines(&self) -> usize {
+        self.root.info().newlines + 1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len_bytes() == 0
+    }
+
+    pub fn insert(&mut self, char_index: usize, text: &str) {
+        assert!(
+            char_index <= self.len_chars(),
+            "char index {} out of bounds for rope of {} chars",
+            char_index,
+            self.len_chars()
+        );
+        if text.is_empty() {
+            return;
+        }
+        let root = mem::replace(&mut self.root, Node::leaf(String::new()));
+        let (before, after) = root.split(char_index);
+        self.root = Node::join(Node::join(before, Node::build(text)), after);
+    }
+
+    pub fn remove<R: RangeBounds<usize>>(&mut self, char_range: R) {
+        let (start, end) = bounds(char_range, self.len_chars());
+        let root = mem::replace(&mut self.root, Node::leaf(String::new()));
+        let (rest, after) = root.split(end);
+        let (before, _) = rest.split(start);
+        self.root = Node::join(before, after);
+    }
+
+    pub fn append(&mut self, other: Rope) {
+        let root = mem::replace(&mut self.root, Node::leaf(String::new()));
+        self.root = Node::join(root, other.root);
+    }
+
+    // Leaves the chars before `char_index` in place and returns the rest.
+    pub fn split_off(&mut self, char_index: usize) -> Rope {
+        assert!(char_index <= self.len_chars(), "char index out of bounds");
+        let root = mem::replace(&mut self.root, Node::leaf(String::new()));
+        let (before, after) = root.split(char_index);
+        self.root = before;
+        Rope { root: after }
+    }
+
+    pub fn char(&self, char_index: usize) -> char {
+        self.slice(..).char(char_index)
+    }
+
+    // Line of the char at `char_index`. The index may be one past the
+    // end, which is on the last line.
+    pub fn char_to_line(&self, char_index: usize) -> usize {
+        assert!(char_index <= self.len_chars(), "char index out of bounds");
+        let mut node = &self.root;
+        let mut index = char_index;
+        let mut line = 0;
+        loop {
+            match *node {
+                Node::Leaf(ref text, _) => {
+                    return line + text.chars().take(index).filter(|&c| c == '\n').count();
+                }
+                Node::Branch {
+                    ref left,
+                    ref right,
+                    ..
+                } => {
+                    let info = left.info();
+                    if index < info.chars {
+                        node = left;
+                    } else {
+                        index -= info.chars;
+                        line += info.newlines;
+                        node = right;
+                    }
+                }
+            }
+        }
+    }
+
+    // Char index where `line` starts.
+    pub fn line_to_char(&self, line: usize) -> usize {
+        assert!(
+            line < self.len_lines(),
+            "line {} out of bounds for rope of {} lines",
+            line,
+            self.len_lines()
+        );
+        if line == 0 {
+            return 0;
+        }
+        // Find the `line`th '\n' and start right after it
+        let mut node = &self.root;
+        let mut remaining = line;
+        let mut chars = 0;
+        loop {
+            match *node {
+                Node::Leaf(ref text, _) => {
+                    let (i, _) = text
+                        .chars()
+                        .enumerate()
+                        .filter(|&(_, c)| c == '\n')
+                        .nth(remaining - 1)
+                        .expect("line count out of sync");
+                    return chars + i + 1;
+                }
+                Node::Branch {
+                    ref left,
+                    ref right,
+                    ..
+                } => {
+                    let info = left.info();
+                    if remaining <= info.newlines {
+                        node = left;
+                    } else {
+                        remaining -= info.newlines;
+                        chars += info.chars;
+                        node = right;
+                    }
+                }
+            }
+        }
+    }
+
+    // Line `line`, with its '\n' if it has one.
+    pub fn line(&self, line: usize) -> RopeSlice<'_> {
+        let start = self.line_to_char(line);
+        let end = if line + 1 < self.len_lines() {
+            self.line_to_char(line + 1)
+        } else {
+            self.len_chars()
+        };
+        self.slice(start..end)
+    }
+
+    pub fn slice<R: RangeBounds<usize>>(&self, char_range: R) -> RopeSlice<'_> {
+        let (start, end) = bounds(char_range, self.len_chars());
+        RopeSlice {
+            rope: self,
+            start,
+            end,
+        }
+    }
+
+    pub fn chunks(&self) -> Chunks<'_> {
+        self.slice(..).chunks()
+    }
+
+    pub fn chars(&self) -> Chars<'_> {
+        self.slice(..).chars()
+    }
+
+    pub fn lines(&self) -> Lines<'_> {
+        self.slice(..).lines()
+    }
+}
+
+impl fmt::Display for Rope {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.slice(..).fmt(f)
+    }
+}
+
+// A borrowed char range of a rope, for reading parts of it without
+// copying them out.
+#[derive(Debug, Clone, Copy)]
+pub struct RopeSlice<'a> {
+    rope: &'a Rope,
+    start: usize,
+    end: usize,
+}
+
+impl<'a> RopeSlice<'a> {
+    pub fn len_chars(&self) -> usize {
+        self.end - self.start
+    }
+
+    pub fn len_bytes(&self) -> usize {
+        self.chunks().map(str::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    pub fn char(&self, char_index: usize) -> char {
+        assert!(char_index < self.len_chars(), "char index out of bounds");
+        self.rope
+            .slice(self.start + char_index..self.start + char_index + 1)
+            .chars()
+            .next()
+            .unwrap()
+    }
+
+    pub fn slice<R: RangeBounds<usize>>(&self, char_range: R) -> RopeSlice<'a> {
+        let (start, end) = bounds(char_range, self.len_chars());
+        RopeSlice {
+            rope: self.rope,
+            start: self.start + start,
+            end: self.start + end,
+        }
+    }
+
+    // The pieces of text the slice is stored in, in order.
+    pub fn chunks(&self) -> Chunks<'a> {
+        Chunks {
+            stack: vec![(&self.rope.root, 0)],
+            start: self.start,
+            end: self.end,
+        }
+    }
+
+    pub fn chars(&self) -> Chars<'a> {
+        Chars {
+            chunks: self.chunks(),
+            current: "".chars(),
+        }
+    }
+
+    pub fn lines(&self) -> Lines<'a> {
+        let first = self.rope.char_to_line(self.start);
+        let last = self.rope.char_to_line(self.end);
+        Lines {
+            slice: *self,
+            line: first,
+            last,
+        }
+    }
+}
+
+impl<'a> fmt::Display for RopeSlice<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for chunk in self.chunks() {
+            f.write_str(chunk)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a, 'b> PartialEq<&'b str> for RopeSlice<'a> {
+    fn eq(&self, other: &&'b str) -> bool {
+        let mut rest = *other;
+        for chunk in self.chunks() {
+            if !rest.starts_with(chunk) {
+                return false;
+            }
+            rest = &rest[chunk.len()..];
+        }
+        rest.is_empty()
+    }
+}
+
+// Walks the leaves overlapping a char range, left to right.
+#[derive(Debug, Clone)]
+pub struct Chunks<'a> {
+    // Nodes still to visit, with the char offset each starts at
+    stack: Vec<(&'a Node, usize)>,
+    start: usize,
+    end: usize,
+}
+
+impl<'a> Iterator for Chunks<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        while let Some((node, offset)) = self.stack.pop() {
+            let chars = node.info().chars;
+            if offset + chars <= self.start || offset >= self.end || chars == 0 {
+                continue;
+            }
+            match *node {
+                Node::Leaf(ref text, _) => {
+                    let from = self.start.saturating_sub(offset);
+                    let to = (self.end - offset).min(chars);
+                    let from_byte = byte_offset(text, from);
+                    let to_byte = from_byte + byte_offset(&text[from_byte..], to - from);
+                    return Some(&text[from_byte..to_byte]);
+                }
+                Node::Branch {
+                    ref left,
+                    ref right,
+                    ..
+                } => {
+                    self.stack.push((right, offset + left.info().chars));
+                    self.stack.push((left, offset));
+                }
+            }
+        }
+        None
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Chars<'a> {
+    chunks: Chunks<'a>,
+    current: str::Chars<'a>,
+}
+
+impl<'a> Iterator for Chars<'a> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        loop {
+            if let Some(c) = self.current.next() {
+                return Some(c);
+            }
+            self.current = self.chunks.next()?.chars();
+        }
+    }
+}
+
+// The lines of a slice, each a `RopeSlice` ending in its '\n'. A slice
+// ending in '\n' yields a last, empty line.
+#[derive(Debug, Clone)]
+pub struct Lines<'a> {
+    slice: RopeSlice<'a>,
+    line: usize,
+    last: usize,
+}
+
+impl<'a> Iterator for Lines<'a> {
+    type Item = RopeSlice<'a>;
+
+    fn next(&mut self) -> Option<RopeSlice<'a>> {
+        if self.line > self.last {
+            return None;
+        }
+        let rope = self.slice.rope;
+        let start = rope.line_to_char(self.line).max(self.slice.start);
+        let end = if self.line < self.last {
+            rope.line_to_char(self.line + 1)
+        } else {
+            self.slice.end
+        };
+        self.line += 1;
+        Some(RopeSlice { rope, start, end })
+    }
+}
@@ -0,0 +1,808 @@
+// A small template language for building text such as emails and
+// reports, instead of gluing a `String` together with `push_str`.
+//
+//     use strings::template::{Context, Template};
+//
+//     let template = Template::compile("Hi {{ name | trim | upper }}!").unwrap();
+//     let context = Context::new().with("name", " ann ");
+//     assert_eq!(template.render(&context).unwrap(), "Hi ANN!");
+//
+// The syntax:
+//
+// - `{{ user.name }}` prints a variable, looking up `name` in `user`
+// - `{{ note | trim | default("none") }}` passes it through filters:
+//   `upper`, `lower`, `trim`, `default(value)`, `escape` and `safe`
+// - `{% if paid %}...{% elif due %}...{% else %}...{% endif %}` and
+//   `{% if not paid %}`
+// - `{% for item in items %}...{% else %}...{% endfor %}`, with
+//   `loop.index`, `loop.index0`, `loop.first`, `loop.last` and
+//   `loop.length` inside the loop
+// - `{# comments #}`
+//
+// Templates are parsed once by `compile` and can then be rendered any
+// number of times. Printed values are HTML-escaped unless the template
+// was compiled with `Escape::None` or the value is marked `safe`.
+// Printing or looping over a variable that is not defined is an error;
+// an `if` treats it as false.
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Bool(bool),
+    Num(f64),
+    Str(String),
+    List(Vec<Value>),
+    Map(HashMap<String, Value>),
+}
+
+impl Value {
+    fn is_truthy(&self) -> bool {
+        match *self {
+            Value::Bool(b) => b,
+            Value::Num(n) => n != 0.0,
+            Value::Str(ref s) => !s.is_empty(),
+            Value::List(ref items) => !items.is_empty(),
+            Value::Map(ref entries) => !entries.is_empty(),
+        }
+    }
+
+    // How the value prints, `None` for lists and maps.
+    fn to_text(&self) -> Option<Cow<'_, str>> {
+        match *self {
+            Value::Bool(b) => Some(Cow::Borrowed(if b { "true" } else { "false" })),
+            Value::Num(n) => Some(Cow::Owned(n.to_string())),
+            Value::Str(ref s) => Some(Cow::Borrowed(s)),
+            Value::List(_) | Value::Map(_) => None,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Value {
+    fn from(s: &'a str) -> Value {
+        Value::Str(s.to_string())
+    }
+}
+
+impl From<String> for Value {
+    fn from(s: String) -> Value {
+        Value::Str(s)
+    }
+}
+
+impl From<bool> for Value {
+    fn from(b: bool) -> Value {
+        Value::Bool(b)
+    }
+}
+
+impl From<i32> for Value {
+    fn from(n: i32) -> Value {
+        Value::Num(n as f64)
+    }
+}
+
+impl From<u32> for Value {
+    fn from(n: u32) -> Value {
+        Value::Num(n as f64)
+    }
+}
+
+impl From<f64> for Value {
+    fn from(n: f64) -> Value {
+        Value::Num(n)
+    }
+}
+
+impl<T: Into<Value>> From<Vec<T>> for Value {
+    fn from(items: Vec<T>) -> Value {
+        Value::List(items.into_iter().map(Into::into).collect())
+    }
+}
+
+impl From<Context> for Value {
+    fn from(context: Context) -> Value {
+        Value::Map(context.values)
+    }
+}
+
+// The variables a template is rendered with. A `Context` converts into
+// a `Value` too, for nested objects such as `user.name`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Context {
+    values: HashMap<String, Value>,
+}
+
+impl Context {
+    pub fn new() -> Context {
+        Context::default()
+    }
+
+    pub fn with<V: Into<Value>>(mut self, name: &str, value: V) -> Context {
+        self.values.insert(name.to_string(), value.into());
+        self
+    }
+
+    pub fn set<V: Into<Value>>(&mut self, name: &str, value: V) {
+        self.values.insert(name.to_string(), value.into());
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Escape {
+    Html,
+    None,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TemplateErrorKind {
+    // `{{`, `{%` or `{#` without its closing counterpart
+    UnclosedTag,
+    UnknownTag(String),
+    // A tag such as `endfor` or `else` with no block it could belong to
+    UnexpectedTag(String),
+    // A block opened by `if` or `for` that never ends
+    UnclosedBlock(String),
+    UnknownFilter(String),
+    InvalidExpression(String),
+    UndefinedVariable(String),
+    NotIterable(String),
+    // A list or map printed or given to a text filter
+    NotText(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TemplateError {
+    pub line: usize,
+    pub column: usize,
+    pub kind: TemplateErrorKind,
+}
+
+impl fmt::Display for TemplateError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}, column {}: ", self.line, self.column)?;
+        match self.kind {
+            TemplateErrorKind::UnclosedTag => write!(f, "tag is never closed"),
+            TemplateErrorKind::UnknownTag(ref tag) => write!(f, "unknown tag `{}`", tag),
+            TemplateErrorKind::UnexpectedTag(ref tag) => write!(f, "unexpected `{}`", tag),
+            TemplateErrorKind::UnclosedBlock(ref tag) => {
+                write!(f, "`{}` block is never closed", tag)
+            }
+            TemplateErrorKind::UnknownFilter(ref name) => write!(f, "unknown filter `{}`", name),
+            TemplateErrorKind::InvalidExpression(ref reason) => write!(f, "{}", reason),
+            TemplateErrorKind::UndefinedVariable(ref name) => {
+                write!(f, "undefined variable `{}`", name)
+            }
+            TemplateErrorKind::NotIterable(ref name) => write!(f, "`{}` is not a list", name),
+            TemplateErrorKind::NotText(ref name) => {
+                write!(f, "`{}` is a list or map, not text", name)
+            }
+        }
+    }
+}
+
+impl Error for TemplateError {}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Position {
+    line: usize,
+    column: usize,
+}
+
+impl Position {
+    fn of(source: &str, offset: usize) -> Position {
+        let before = &source[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        Position {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+        }
+    }
+
+    fn error(self, kind: TemplateErrorKind) -> TemplateError {
+        TemplateError {
+            line: self.line,
+            column: self.column,
+            kind,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Filter {
+    Upper,
+    Lower,
+    Trim,
+    Default(Value),
+    Escape,
+    Safe,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Operand {
+    Variable(Vec<String>),
+    Literal(Value),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Expr {
+    operand: Operand,
+    filters: Vec<Filter>,
+    position: Position,
+}
+
+impl Expr {
+    // The source form of the operand, for error messages.
+    fn name(&self) -> String {
+        match self.operand {
+            Operand::Variable(ref path) => path.join("."),
+            Operand::Literal(ref value) => format!("{:?}", value),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Condition {
+    negated: bool,
+    expr: Expr,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Node {
+    Text(String),
+    Output(Expr),
+    If {
+        branches: Vec<(Condition, Vec<Node>)>,
+        otherwise: Vec<Node>,
+    },
+    For {
+        variable: String,
+        list: Expr,
+        body: Vec<Node>,
+        otherwise: Vec<Node>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Ident(String),
+    Str(String),
+    Num(f64),
+    Pipe,
+    Open,
+    Close,
+}
+
+fn tokenize(source: &str, position: Position) -> Result<Vec<Token>, TemplateError> {
+    let invalid = |reason: String| position.error(TemplateErrorKind::InvalidExpression(reason));
+    let mut tokens = Vec::new();
+    let mut chars = source.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        match c {
+            ' ' | '\t' | '\n' | '\r' => {
+                chars.next();
+            }
+            '|' | '(' | ')' => {
+                chars.next();
+                tokens.push(match c {
+                    '|' => Token::Pipe,
+                    '(' => Token::Open,
+                    _ => Token::Close,
+                });
+            }
+            '"' => {
+                chars.next();
+                let mut s = String::new();
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') => match chars.next() {
+                            Some(escaped) => s.push(escaped),
+                            None => return Err(invalid("unterminated string".to_string())),
+                        },
+                        Some(c) => s.push(c),
+                        None => return Err(invalid("unterminated string".to_string())),
+                    }
+                }
+                tokens.push(Token::Str(s));
+            }
+            '0'..='9' | '-' => {
+                let mut s = String::new();
+                while let Some(&c) = chars.peek() {
+                    if c.is_ascii_digit() || c == '.' || (c == '-' && s.is_empty()) {
+                        s.push(c);
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+                let n = s
+                    .parse()
+                    .map_err(|_| invalid(format!("invalid number `{}`", s)))?;
+                tokens.push(Token::Num(n));
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                let mut s = String::new();
+                while let Some(&c) = chars.peek() {
+                    if c.is_alphanumeric() || c == '_' || c == '.' {
+                        s.push(c);
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+                tokens.push(Token::Ident(s));
+            }
+            c => return Err(invalid(format!("unexpected `{}`", c))),
+        }
+    }
+    Ok(tokens)
+}
+
+fn parse_expr(tokens: &[Token], position: Position) -> Result<Expr, TemplateError> {
+    let invalid =
+        |reason: &str| position.error(TemplateErrorKind::InvalidExpression(reason.to_string()));
+    let mut tokens = tokens.iter();
+    let operand = match tokens.next() {
+        Some(Token::Ident(name)) if name == "true" || name == "false" => {
+            Operand::Literal(Value::Bool(name == "true"))
+        }
+        Some(Token::Ident(name)) => {
+            let path: Vec<String> = name.split('.').map(str::to_string).collect();
+            if path.iter().any(String::is_empty) {
+                return Err(invalid("empty name in variable path"));
+            }
+            Operand::Variable(path)
+        }
+        Some(Token::Str(s)) => Operand::Literal(Value::Str(s.clone())),
+        Some(&Token::Num(n)) => Operand::Literal(Value::Num(n)),
+        Some(_) => return Err(invalid("expected a variable or a value")),
+        None => return Err(invalid("empty expression")),
+    };
+
+    let mut filters = Vec::new();
+    while let Some(token) = tokens.next() {
+        if *token != Token::Pipe {
+            return Err(invalid("expected `|` before a filter"));
+        }
+        let name = match tokens.next() {
+            Some(Token::Ident(name)) => name,
+            _ => return Err(invalid("expected a filter name after `|`")),
+        };
+        filters.push(match &name[..] {
+            "upper" => Filter::Upper,
+            "lower" => Filter::Lower,
+            "trim" => Filter::Trim,
+            "escape" => Filter::Escape,
+            "safe" => Filter::Safe,
+            "default" => {
+                let value = match (tokens.next(), tokens.next(), tokens.next()) {
+                    (Some(Token::Open), Some(Token::Str(s)), Some(Token::Close)) => {
+                        Value::Str(s.clone())
+                    }
+                    (Some(Token::Open), Some(&Token::Num(n)), Some(Token::Close)) => Value::Num(n),
+                    _ => {
+                        return Err(invalid(
+                            "`default` takes one value, as in default(\"none\")",
+                        ))
+                    }
+                };
+                Filter::Default(value)
+            }
+            _ => return Err(position.error(TemplateErrorKind::UnknownFilter(name.clone()))),
+        });
+    }
+    Ok(Expr {
+        operand,
+        filters,
+        position,
+    })
+}
+
+fn parse_condition(tokens: &[Token], position: Position) -> Result<Condition, TemplateError> {
+    match tokens.split_first() {
+        Some((Token::Ident(word), rest)) if word == "not" => Ok(Condition {
+            negated: true,
+            expr: parse_expr(rest, position)?,
+        }),
+        _ => Ok(Condition {
+            negated: false,
+            expr: parse_expr(tokens, position)?,
+        }),
+    }
+}
+
+// A `{% ... %}` tag, split into its name and the tokens after it.
+#[derive(Debug)]
+struct Tag {
+    name: String,
+    args: Vec<Token>,
+    position: Position,
+}
+
+// Template source turned into text, outputs and tags, before blocks are
+// matched up.
+#[derive(Debug)]
+enum Item {
+    Node(Node),
+    Tag(Tag),
+}
+
+fn scan(source: &str) -> Result<Vec<Item>, TemplateError> {
+    let mut items = Vec::new();
+    let mut rest = 0;
+    while let Some(found) = source[rest..].find('{').map(|i| rest + i) {
+        let close = match source[found + 1..].chars().next() {
+            Some('{') => "}}",
+            Some('%') => "%}",
+            Some('#') => "#}",
+            _ => {
+                items.push(Item::Node(Node::Text(source[rest..found + 1].to_string())));
+                rest = found + 1;
+                continue;
+            }
+        };
+        if found > rest {
+            items.push(Item::Node(Node::Text(source[rest..found].to_string())));
+        }
+        let position = Position::of(source, found);
+        let inner_start = found + 2;
+        let inner_end = match source[inner_start..].find(close) {
+            Some(i) => inner_start + i,
+            None => return Err(position.error(TemplateErrorKind::UnclosedTag)),
+        };
+        let inner = &source[inner_start..inner_end];
+        rest = inner_end + 2;
+
+        match close {
+            "}}" => {
+                let tokens = tokenize(inner, position)?;
+                items.push(Item::Node(Node::Output(parse_expr(&tokens, position)?)));
+            }
+            "%}" => {
+                let mut tokens = tokenize(inner, position)?;
+                let name = match tokens.first() {
+                    Some(Token::Ident(name)) => name.clone(),
+                    _ => {
+                        return Err(position.error(TemplateErrorKind::InvalidExpression(
+                            "expected a tag name".to_string(),
+                        )))
+                    }
+                };
+                tokens.remove(0);
+                items.push(Item::Tag(Tag {
+                    name,
+                    args: tokens,
+                    position,
+                }));
+            }
+            _ => {}
+        }
+    }
+    if rest < source.len() {
+        items.push(Item::Node(Node::Text(source[rest..].to_string())));
+    }
+    Ok(items)
+}
+
+// Matches up block tags, consuming `items` until one of the tags in
+// `ends`, which is returned along with the nodes before it.
+fn parse_block<I: Iterator<Item = Item>>(
+    items: &mut I,
+    ends: &[&str],
+) -> Result<(Vec<Node>, Option<Tag>), TemplateError> {
+    let mut nodes = Vec::new();
+    while let Some(item) = items.next() {
+        let tag = match item {
+            Item::Node(node) => {
+                nodes.push(node);
+                continue;
+            }
+            Item::Tag(tag) => tag,
+        };
+        if ends.contains(&&tag.name[..]) {
+            return Ok((nodes, Some(tag)));
+        }
+        let no_args = |tag: &Tag| {
+            if tag.args.is_empty() {
+                Ok(())
+            } else {
+                Err(tag
+                    .position
+                    .error(TemplateErrorKind::InvalidExpression(format!(
+                        "`{}` takes no arguments",
+                        tag.name
+                    ))))
+            }
+        };
+        match &tag.name[..] {
+            "if" => {
+                let mut branches = Vec::new();
+                let mut condition = parse_condition(&tag.args, tag.position)?;
+                let otherwise = loop {
+                    let (body, end) = parse_block(items, &["elif", "else", "endif"])?;
+                    branches.push((condition, body));
+                    let end = end.ok_or_else(|| {
+                        tag.position
+                            .error(TemplateErrorKind::UnclosedBlock("if".to_string()))
+                    })?;
+                    match &end.name[..] {
+                        "elif" => condition = parse_condition(&end.args, end.position)?,
+                        "else" => {
+                            no_args(&end)?;
+                            let (body, end) = parse_block(items, &["endif"])?;
+                            no_args(&end.ok_or_else(|| {
+                                tag.position
+                                    .error(TemplateErrorKind::UnclosedBlock("if".to_string()))
+                            })?)?;
+                            break body;
+                        }
+                        _ => {
+                            no_args(&end)?;
+                            break Vec::new();
+                        }
+                    }
+                };
+                nodes.push(Node::If {
+                    branches,
+                    otherwise,
+                });
+            }
+            "for" => {
+                let invalid = || {
+                    tag.position.error(TemplateErrorKind::InvalidExpression(
+                        "expected `for name in list`".to_string(),
+                    ))
+                };
+                let variable = match tag.args.first() {
+                    Some(Token::Ident(name)) if !name.contains('.') => name.clone(),
+                    _ => return Err(invalid()),
+                };
+                match tag.args.get(1) {
+                    Some(Token::Ident(word)) if word == "in" => {}
+                    _ => return Err(invalid()),
+                }
+                let list = parse_expr(&tag.args[2..], tag.position)?;
+                let unclosed = || {
+                    tag.position
+                        .error(TemplateErrorKind::UnclosedBlock("for".to_string()))
+                };
+                let (body, end) = parse_block(items, &["else", "endfor"])?;
+                let end = end.ok_or_else(unclosed)?;
+                no_args(&end)?;
+                let otherwise = if end.name == "else" {
+                    let (otherwise, end) = parse_block(items, &["endfor"])?;
+                    no_args(&end.ok_or_else(unclosed)?)?;
+                    otherwise
+                } else {
+                    Vec::new()
+                };
+                nodes.push(Node::For {
+                    variable,
+                    list,
+                    body,
+                    otherwise,
+                });
+            }
+            "elif" | "else" | "endif" | "endfor" => {
+                return Err(tag
+                    .position
+                    .error(TemplateErrorKind::UnexpectedTag(tag.name.clone())))
+            }
+            _ => {
+                return Err(tag
+                    .position
+                    .error(TemplateErrorKind::UnknownTag(tag.name.clone())))
+            }
+        }
+    }
+    Ok((nodes, None))
+}
+
+fn escape_html(text: &str, out: &mut String) {
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(c),
+        }
+    }
+}
+
+// What a name in the template refers to while rendering: a loop
+// variable, or the `loop` object of the innermost loop.
+#[derive(Debug)]
+enum Binding<'a> {
+    Value(&'a Value),
+    Loop { index: usize, length: usize },
+}
+
+#[derive(Debug)]
+struct Renderer<'a> {
+    context: &'a Context,
+    escape: Escape,
+    scopes: Vec<(&'a str, Binding<'a>)>,
+}
+
+impl<'a> Renderer<'a> {
+    fn lookup(&self, path: &[String]) -> Option<Cow<'a, Value>> {
+        let (first, rest) = path.split_first()?;
+        let mut value: Cow<'a, Value> = match self.scopes.iter().rev().find(|s| s.0 == first) {
+            Some(&(_, Binding::Value(value))) => Cow::Borrowed(value),
+            Some(&(_, Binding::Loop { index, length })) => {
+                let field = rest.first()?;
+                if rest.len() > 1 {
+                    return None;
+                }
+                return Some(Cow::Owned(match &field[..] {
+                    "index" => Value::from(index as u32 + 1),
+                    "index0" => Value::from(index as u32),
+                    "first" => Value::Bool(index == 0),
+                    "last" => Value::Bool(index + 1 == length),
+                    "length" => Value::from(length as u32),
+                    _ => return None,
+                }));
+            }
+            None => Cow::Borrowed(self.context.values.get(first)?),
+        };
+        for name in rest {
+            value = match value {
+                Cow::Borrowed(Value::Map(entries)) => Cow::Borrowed(entries.get(name)?),
+                _ => return None,
+            };
+        }
+        Some(value)
+    }
+
+    // The value of `expr` after its filters, `None` when undefined, and
+    // whether it is marked safe from escaping.
+    fn eval(&self, expr: &Expr) -> Result<(Option<Cow<'a, Value>>, bool), TemplateError> {
+        let mut value = match expr.operand {
+            Operand::Variable(ref path) => self.lookup(path),
+            Operand::Literal(ref value) => Some(Cow::Owned(value.clone())),
+        };
+        let mut safe = false;
+        for filter in &expr.filters {
+            let text = |value: &Value| -> Result<String, TemplateError> {
+                value
+                    .to_text()
+                    .map(Cow::into_owned)
+                    .ok_or_else(|| expr.position.error(TemplateErrorKind::NotText(expr.name())))
+            };
+            value = match (filter, value) {
+                (Filter::Default(fallback), None) => Some(Cow::Owned(fallback.clone())),
+                (_, None) => None,
+                (Filter::Upper, Some(v)) => Some(Cow::Owned(Value::Str(text(&v)?.to_uppercase()))),
+                (Filter::Lower, Some(v)) => Some(Cow::Owned(Value::Str(text(&v)?.to_lowercase()))),
+                (Filter::Trim, Some(v)) => {
+                    Some(Cow::Owned(Value::Str(text(&v)?.trim().to_string())))
+                }
+                (Filter::Escape, Some(v)) => {
+                    let mut escaped = String::new();
+                    escape_html(&text(&v)?, &mut escaped);
+                    safe = true;
+                    Some(Cow::Owned(Value::Str(escaped)))
+                }
+                (Filter::Safe, Some(v)) => {
+                    safe = true;
+                    Some(v)
+                }
+                (Filter::Default(_), Some(v)) => Some(v),
+            };
+        }
+        Ok((value, safe))
+    }
+
+    fn is_true(&self, condition: &Condition) -> Result<bool, TemplateError> {
+        let (value, _) = self.eval(&condition.expr)?;
+        let truthy = value.is_some_and(|v| v.is_truthy());
+        Ok(truthy != condition.negated)
+    }
+
+    fn render(&mut self, nodes: &'a [Node], out: &mut String) -> Result<(), TemplateError> {
+        for node in nodes {
+            match *node {
+                Node::Text(ref text) => out.push_str(text),
+                Node::Output(ref expr) => {
+                    let (value, safe) = self.eval(expr)?;
+                    let value = value.ok_or_else(|| {
+                        expr.position
+                            .error(TemplateErrorKind::UndefinedVariable(expr.name()))
+                    })?;
+                    let text = value.to_text().ok_or_else(|| {
+                        expr.position.error(TemplateErrorKind::NotText(expr.name()))
+                    })?;
+                    if safe || self.escape == Escape::None {
+                        out.push_str(&text);
+                    } else {
+                        escape_html(&text, out);
+                    }
+                }
+                Node::If {
+                    ref branches,
+                    ref otherwise,
+                } => {
+                    let mut chosen = &otherwise[..];
+                    for (condition, body) in branches {
+                        if self.is_true(condition)? {
+                            chosen = body;
+                            break;
+                        }
+                    }
+                    self.render(chosen, out)?;
+                }
+                Node::For {
+                    ref variable,
+                    ref list,
+                    ref body,
+                    ref otherwise,
+                } => {
+                    let (value, _) = self.eval(list)?;
+                    let items: &'a [Value] = match value {
+                        Some(Cow::Borrowed(Value::List(items))) => items,
+                        Some(_) => {
+                            return Err(list
+                                .position
+                                .error(TemplateErrorKind::NotIterable(list.name())))
+                        }
+                        None => {
+                            return Err(list
+                                .position
+                                .error(TemplateErrorKind::UndefinedVariable(list.name())))
+                        }
+                    };
+                    if items.is_empty() {
+                        self.render(otherwise, out)?;
+                    }
+                    for (index, item) in items.iter().enumerate() {
+                        let length = items.len();
+                        self.scopes.push(("loop", Binding::Loop { index, length }));
+                        self.scopes.push((variable, Binding::Value(item)));
+                        let rendered = self.render(body, out);
+                        self.scopes.truncate(self.scopes.len() - 2);
+                        rendered?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+// A parsed template, ready to be rendered with any number of contexts.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Template {
+    nodes: Vec<Node>,
+    escape: Escape,
+}
+
+impl Template {
+    // Compiles a template whose output is HTML-escaped.
+    pub fn compile(source: &str) -> Result<Template, TemplateError> {
+        Template::compile_with(source, Escape::Html)
+    }
+
+    pub fn compile_with(source: &str, escape: Escape) -> Result<Template, TemplateError> {
+        let mut items = scan(source)?.into_iter();
+        let (nodes, end) = parse_block(&mut items, &[])?;
+        debug_assert!(end.is_none());
+        Ok(Template { nodes, escape })
+    }
+
+    pub fn render(&self, context: &Context) -> Result<String, TemplateError> {
+        let mut out = String::new();
+        let mut renderer = Renderer {
+            context,
+            escape: self.escape,
+            scopes: Vec::new(),
+        };
+        renderer.render(&self.nodes, &mut out)?;
+        Ok(out)
+    }
+}
@@ -0,0 +1,142 @@
+extern crate strings;
+
+use strings::rope::Rope;
+
+// Small xorshift generator, so that the edits are the same every run.
+struct Random(u64);
+
+impl Random {
+    fn below(&mut self, n: usize) -> usize {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 % n as u64) as usize
+    }
+}
+
+fn byte_index(s: &str, char_index: usize) -> usize {
+    s.char_indices().nth(char_index).map_or(s.len(), |(i, _)| i)
+}
+
+fn check(rope: &Rope, expected: &str) {
+    assert_eq!(rope.to_string(), expected);
+    assert_eq!(rope.len_bytes(), expected.len());
+    assert_eq!(rope.len_chars(), expected.chars().count());
+    assert_eq!(rope.len_lines(), expected.matches('\n').count() + 1);
+    assert_eq!(rope.chunks().collect::<String>(), expected);
+    assert!(rope.chars().eq(expected.chars()));
+}
+
+#[test]
+fn empty_rope() {
+    let rope = Rope::new();
+    check(&rope, "");
+    assert!(rope.is_empty());
+    assert_eq!(
+        rope.lines().map(|l| l.to_string()).collect::<Vec<_>>(),
+        [""]
+    );
+}
+
+#[test]
+fn random_edits_match_a_string() {
+    let pieces = [
+        "a",
+        "héllo",
+        "\n",
+        "日本語\n",
+        "🦀🦀",
+        "",
+        "line one\nline two\n",
+    ];
+    let mut random = Random(0x2545_f491_4f6c_dd1d);
+    let mut rope = Rope::new();
+    let mut expected = String::new();
+
+    for _ in 0..3000 {
+        let len = expected.chars().count();
+        if random.below(3) == 0 && len > 0 {
+            let start = random.below(len);
+            let end = start + random.below(len - start + 1).min(40);
+            rope.remove(start..end);
+            let (from, to) = (byte_index(&expected, start), byte_index(&expected, end));
+            expected.replace_range(from..to, "");
+        } else {
+            let at = random.below(len + 1);
+            let piece = pieces[random.below(pieces.len())];
+            rope.insert(at, piece);
+            expected.insert_str(byte_index(&expected, at), piece);
+        }
+    }
+    check(&rope, &expected);
+
+    // Build the same text in one go as well as through edits
+    check(&Rope::from(&expected[..]), &expected);
+}
+
+#[test]
+fn large_text_spans_many_leaves() {
+    let text: String = (0..5000).map(|i| format!("line {} ✓\n", i)).collect();
+    let mut rope = Rope::from(&text[..]);
+    check(&rope, &text);
+    assert_eq!(rope.len_lines(), 5001);
+    assert_eq!(rope.line(1234).to_string(), "line 1234 ✓\n");
+
+    let mid = rope.len_chars() / 2;
+    let tail = rope.split_off(mid);
+    assert_eq!(rope.len_chars() + tail.len_chars(), text.chars().count());
+    rope.append(tail);
+    check(&rope, &text);
+}
+
+#[test]
+fn char_and_line_positions() {
+    let rope = Rope::from("ab\ncdé\n\nf");
+    assert_eq!(rope.char(4), 'd');
+    assert_eq!(rope.char(5), 'é');
+    assert_eq!(rope.len_lines(), 4);
+    assert_eq!(
+        (0..4).map(|l| rope.line_to_char(l)).collect::<Vec<_>>(),
+        [0, 3, 7, 8]
+    );
+    assert_eq!(
+        (0..=9).map(|c| rope.char_to_line(c)).collect::<Vec<_>>(),
+        [0, 0, 0, 1, 1, 1, 1, 2, 3, 3]
+    );
+}
+
+#[test]
+fn editing_by_line() {
+    let mut rope = Rope::from("one\ntwo\nthree\n");
+    let start = rope.line_to_char(1);
+    let end = rope.line_to_char(2);
+    rope.remove(start..end);
+    rope.insert(rope.line_to_char(1), "2\n");
+    assert_eq!(rope.to_string(), "one\n2\nthree\n");
+}
+
+#[test]
+fn slices_display_and_iterate() {
+    let rope = Rope::from("first line\nsecond line\nthird");
+    let slice = rope.slice(6..17);
+    assert_eq!(slice.to_string(), "line\nsecond");
+    assert_eq!(slice, "line\nsecond");
+    assert_eq!(slice.len_chars(), 11);
+    assert_eq!(slice.slice(5..).to_string(), "second");
+    assert_eq!(
+        slice.lines().map(|l| l.to_string()).collect::<Vec<_>>(),
+        ["line\n", "second"]
+    );
+    assert_eq!(
+        rope.lines().map(|l| l.to_string()).collect::<Vec<_>>(),
+        ["first line\n", "second line\n", "third"]
+    );
+    assert_eq!(format!("[{}]", rope.line(2)), "[third]");
+}
+
+#[test]
+#[should_panic]
+fn removing_past_the_end_panics() {
+    let mut rope = Rope::from("abc");
+    rope.remove(2..5);
+}
@@ -0,0 +1,222 @@
+extern crate strings;
+
+use strings::template::{Context, Escape, Template, TemplateErrorKind, Value};
+
+fn render(source: &str, context: &Context) -> String {
+    Template::compile(source).unwrap().render(context).unwrap()
+}
+
+fn compile_error(source: &str) -> (usize, usize, TemplateErrorKind) {
+    let error = Template::compile(source).unwrap_err();
+    (error.line, error.column, error.kind)
+}
+
+#[test]
+fn substitutes_variables() {
+    let user = Context::new().with("name", "Ann").with("age", 31);
+    let context = Context::new().with("user", user).with("greeting", "Hi");
+    assert_eq!(
+        render("{{greeting}} {{ user.name }} ({{ user.age }})", &context),
+        "Hi Ann (31)"
+    );
+    assert_eq!(render("no tags { at all }", &context), "no tags { at all }");
+    assert_eq!(render("{# hidden #}shown", &context), "shown");
+}
+
+#[test]
+fn applies_filters_in_order() {
+    let context = Context::new().with("name", "  Ann Lee ");
+    assert_eq!(render("[{{ name | trim | upper }}]", &context), "[ANN LEE]");
+    assert_eq!(render("[{{ name | lower }}]", &context), "[  ann lee ]");
+    assert_eq!(
+        render("{{ missing | default(\"none\") }}", &context),
+        "none"
+    );
+    assert_eq!(render("{{ missing | upper | default(0) }}", &context), "0");
+    assert_eq!(
+        render("{{ name | trim | default(\"none\") }}", &context),
+        "Ann Lee"
+    );
+}
+
+#[test]
+fn escapes_html_unless_told_otherwise() {
+    let context = Context::new().with("comment", "<b>\"Tom\" & 'Jerry'</b>");
+    assert_eq!(
+        render("{{ comment }}", &context),
+        "&lt;b&gt;&quot;Tom&quot; &amp; &#39;Jerry&#39;&lt;/b&gt;"
+    );
+    assert_eq!(
+        render("{{ comment | safe }}", &context),
+        "<b>\"Tom\" & 'Jerry'</b>"
+    );
+    // Escaping twice would turn `&lt;` into `&amp;lt;`
+    assert_eq!(
+        render("{{ comment | escape }}", &context),
+        render("{{ comment }}", &context)
+    );
+
+    let plain = Template::compile_with("{{ comment }}", Escape::None).unwrap();
+    assert_eq!(plain.render(&context).unwrap(), "<b>\"Tom\" & 'Jerry'</b>");
+    let plain = Template::compile_with("{{ comment | escape }}", Escape::None).unwrap();
+    assert!(plain.render(&context).unwrap().starts_with("&lt;b&gt;"));
+}
+
+#[test]
+fn picks_if_branches() {
+    let source = "{% if paid %}paid{% elif due %}due {{ due }}{% else %}open{% endif %}";
+    let template = Template::compile(source).unwrap();
+    let render = |context: Context| template.render(&context).unwrap();
+
+    assert_eq!(render(Context::new().with("paid", true)), "paid");
+    assert_eq!(
+        render(Context::new().with("paid", false).with("due", "May")),
+        "due May"
+    );
+    // Undefined variables, empty text and zero are all false
+    assert_eq!(render(Context::new()), "open");
+    assert_eq!(
+        render(Context::new().with("paid", 0).with("due", "")),
+        "open"
+    );
+
+    let negated = Template::compile("{% if not items %}empty{% endif %}").unwrap();
+    let empty: Vec<Value> = Vec::new();
+    assert_eq!(
+        negated
+            .render(&Context::new().with("items", empty))
+            .unwrap(),
+        "empty"
+    );
+    assert_eq!(
+        negated
+            .render(&Context::new().with("items", vec![1]))
+            .unwrap(),
+        ""
+    );
+}
+
+#[test]
+fn loops_over_lists() {
+    let context = Context::new().with("names", vec!["Ann", "Bob", "Cy"]);
+    assert_eq!(
+        render(
+            "{% for name in names %}{{ loop.index }}.{{ name }}{% if not loop.last %}, {% endif %}{% endfor %}",
+            &context
+        ),
+        "1.Ann, 2.Bob, 3.Cy"
+    );
+    assert_eq!(
+        render("{% for n in names %}{% if loop.first %}{{ loop.length }}:{% endif %}{{ loop.index0 }}{% endfor %}", &context),
+        "3:012"
+    );
+
+    let nobody: Vec<&str> = Vec::new();
+    let context = Context::new().with("names", nobody);
+    assert_eq!(
+        render(
+            "{% for name in names %}{{ name }}{% else %}nobody{% endfor %}",
+            &context
+        ),
+        "nobody"
+    );
+}
+
+#[test]
+fn nested_loops_see_outer_variables() {
+    let orders = vec![
+        Value::from(
+            Context::new()
+                .with("id", 1)
+                .with("items", vec!["tea", "milk"]),
+        ),
+        Value::from(Context::new().with("id", 2).with("items", vec!["bread"])),
+    ];
+    let context = Context::new().with("orders", orders);
+    let source = "{% for order in orders %}{% for item in order.items %}\
+                  {{ order.id }}-{{ loop.index }}:{{ item }} {% endfor %}{% endfor %}";
+    assert_eq!(render(source, &context), "1-1:tea 1-2:milk 2-1:bread ");
+}
+
+#[test]
+fn compiles_once_and_renders_many_times() {
+    let template = Template::compile("Dear {{ name }},").unwrap();
+    for name in &["Ann", "Bob"] {
+        let context = Context::new().with("name", *name);
+        assert_eq!(
+            template.render(&context).unwrap(),
+            format!("Dear {},", name)
+        );
+    }
+}
+
+#[test]
+fn reports_undefined_variables_where_they_are_used() {
+    let template = Template::compile("Hello\n  {{ user.nmae }}!").unwrap();
+    let user = Context::new().with("name", "Ann");
+    let error = template
+        .render(&Context::new().with("user", user))
+        .unwrap_err();
+    assert_eq!(
+        error.kind,
+        TemplateErrorKind::UndefinedVariable("user.nmae".to_string())
+    );
+    assert_eq!((error.line, error.column), (2, 3));
+    assert_eq!(
+        error.to_string(),
+        "line 2, column 3: undefined variable `user.nmae`"
+    );
+
+    let template = Template::compile("{% for x in xs %}{% endfor %}").unwrap();
+    let error = template.render(&Context::new()).unwrap_err();
+    assert_eq!(
+        error.kind,
+        TemplateErrorKind::UndefinedVariable("xs".to_string())
+    );
+    let error = template
+        .render(&Context::new().with("xs", "abc"))
+        .unwrap_err();
+    assert_eq!(error.kind, TemplateErrorKind::NotIterable("xs".to_string()));
+
+    let template = Template::compile("{{ xs }}").unwrap();
+    let error = template
+        .render(&Context::new().with("xs", vec![1]))
+        .unwrap_err();
+    assert_eq!(error.kind, TemplateErrorKind::NotText("xs".to_string()));
+}
+
+#[test]
+fn rejects_malformed_templates() {
+    assert_eq!(
+        compile_error("a {{ b"),
+        (1, 3, TemplateErrorKind::UnclosedTag)
+    );
+    assert_eq!(
+        compile_error("{% if a %}\n{% for x in xs %}{% endif %}").2,
+        TemplateErrorKind::UnexpectedTag("endif".to_string())
+    );
+    assert_eq!(
+        compile_error("{% if a %}\nyes"),
+        (1, 1, TemplateErrorKind::UnclosedBlock("if".to_string()))
+    );
+    assert_eq!(
+        compile_error("{% endfor %}").2,
+        TemplateErrorKind::UnexpectedTag("endfor".to_string())
+    );
+    assert_eq!(
+        compile_error("{% include \"x\" %}").2,
+        TemplateErrorKind::UnknownTag("include".to_string())
+    );
+    assert_eq!(
+        compile_error("{{ a | shout }}").2,
+        TemplateErrorKind::UnknownFilter("shout".to_string())
+    );
+    match compile_error("{% for x of xs %}{% endfor %}").2 {
+        TemplateErrorKind::InvalidExpression(_) => {}
+        kind => panic!("unexpected {:?}", kind),
+    }
+    match compile_error("{{ }}").2 {
+        TemplateErrorKind::InvalidExpression(_) => {}
+        kind => panic!("unexpected {:?}", kind),
+    }
+}